@@ -1,11 +1,11 @@
 // FILE: examples/demo.rs - Interactive demonstration of ratatui-notifications crate features
-// VERSION: 2.3.0
-// WCTX: Adding code generation feature
-// CLOG: Fixed generate_code for all demos, added success notification on file write
+// VERSION: 2.7.0
+// WCTX: Scene code generation
+// CLOG: Added generate_scene_code
 
 use ratatui_notifications::{
-    generate_code, NotificationBuilder, Notifications,
-    Anchor, Animation, Level, Overflow,
+    generate_code_with_context, generate_scene_code, NotificationBuilder, Notifications,
+    Anchor, Animation, Layer, Level, Overflow,
     SlideDirection, Timing, SizeConstraint,
 };
 use color_eyre::Result;
@@ -16,7 +16,7 @@ use crossterm::{
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout, Position as RatatuiPosition, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     prelude::*,
     style::{Color, Modifier, Style},
     text::{Line, Span},
@@ -75,7 +75,6 @@ struct App {
     notifications: Notifications,
     should_quit: bool,
     log_messages: VecDeque<String>,
-    last_frame_area: Rect,
     current_border_type: BorderType,
     demo_index: usize,
     overflow_count: u32,
@@ -96,7 +95,6 @@ impl App {
             notifications,
             should_quit: false,
             log_messages: VecDeque::with_capacity(MAX_LOG_MESSAGES),
-            last_frame_area: Rect::default(),
             current_border_type: BorderType::Rounded,
             demo_index: 0,
             overflow_count: 0,
@@ -146,7 +144,7 @@ impl App {
 
         match notification {
             Ok(n) => {
-                self.last_notification_code = generate_code(&n);
+                self.last_notification_code = generate_code_with_context(&n, self.notifications.manager_defaults());
                 if let Ok(id) = self.notifications.add(n) {
                     self.add_log(format!("{} → ID {}", anchor_name, id));
                 }
@@ -183,7 +181,7 @@ impl App {
                 .build();
 
             if let Ok(n) = notification {
-                self.last_notification_code = generate_code(&n);
+                self.last_notification_code = generate_code_with_context(&n, self.notifications.manager_defaults());
                 self.notifications.add(n).ok();
             }
         }
@@ -206,7 +204,7 @@ impl App {
 
         match notification {
             Ok(n) => {
-                self.last_notification_code = generate_code(&n);
+                self.last_notification_code = generate_code_with_context(&n, self.notifications.manager_defaults());
                 if let Ok(id) = self.notifications.add(n) {
                     self.add_log(format!("Expand → ID {}", id));
                 }
@@ -236,7 +234,7 @@ impl App {
 
         match notification {
             Ok(n) => {
-                self.last_notification_code = generate_code(&n);
+                self.last_notification_code = generate_code_with_context(&n, self.notifications.manager_defaults());
                 if let Ok(id) = self.notifications.add(n) {
                     self.add_log(format!("Fade → ID {}", id));
                 }
@@ -269,7 +267,7 @@ impl App {
 
         match notification {
             Ok(n) => {
-                self.last_notification_code = generate_code(&n);
+                self.last_notification_code = generate_code_with_context(&n, self.notifications.manager_defaults());
                 if let Ok(id) = self.notifications.add(n) {
                     self.add_log(format!("Path → ID {}", id));
                 }
@@ -298,7 +296,7 @@ impl App {
 
         match notification {
             Ok(n) => {
-                self.last_notification_code = generate_code(&n);
+                self.last_notification_code = generate_code_with_context(&n, self.notifications.manager_defaults());
                 if let Ok(id) = self.notifications.add(n) {
                     self.add_log(format!("Success → ID {}", id));
                 }
@@ -325,7 +323,7 @@ impl App {
 
         match notification {
             Ok(n) => {
-                self.last_notification_code = generate_code(&n);
+                self.last_notification_code = generate_code_with_context(&n, self.notifications.manager_defaults());
                 if let Ok(id) = self.notifications.add(n) {
                     self.add_log(format!("Warning → ID {}", id));
                 }
@@ -354,7 +352,7 @@ impl App {
 
         match notification {
             Ok(n) => {
-                self.last_notification_code = generate_code(&n);
+                self.last_notification_code = generate_code_with_context(&n, self.notifications.manager_defaults());
                 if let Ok(id) = self.notifications.add(n) {
                     self.add_log(format!("Error → ID {}", id));
                 }
@@ -390,7 +388,7 @@ impl App {
                 .build();
 
             if let Ok(n) = notification {
-                self.last_notification_code = generate_code(&n);
+                self.last_notification_code = generate_code_with_context(&n, self.notifications.manager_defaults());
                 self.notifications.add(n).ok();
             }
         }
@@ -413,7 +411,7 @@ impl App {
                 .build();
 
             if let Ok(n) = notification {
-                self.last_notification_code = generate_code(&n);
+                self.last_notification_code = generate_code_with_context(&n, self.notifications.manager_defaults());
                 self.notifications.add(n).ok();
             }
         }
@@ -438,7 +436,7 @@ impl App {
 
         match notification {
             Ok(n) => {
-                self.last_notification_code = generate_code(&n);
+                self.last_notification_code = generate_code_with_context(&n, self.notifications.manager_defaults());
                 if let Ok(id) = self.notifications.add(n) {
                     self.add_log(format!("Slide+Fade → ID {}", id));
                 }
@@ -474,7 +472,7 @@ impl App {
 
         match notification {
             Ok(n) => {
-                self.last_notification_code = generate_code(&n);
+                self.last_notification_code = generate_code_with_context(&n, self.notifications.manager_defaults());
                 if let Ok(id) = self.notifications.add(n) {
                     self.add_log(format!("{:?} from {:?} → ID {}", anchor, direction, id));
                 }
@@ -488,23 +486,7 @@ impl App {
     // ═══════════════════════════════════════════════════════════════════════════
 
     fn demo_custom_path(&mut self) {
-        let frame_area = self.last_frame_area;
-        if frame_area.width == 0 || frame_area.height == 0 {
-            self.add_log("Frame not ready yet");
-            return;
-        }
-
-        // Calculate custom positions: start left, end right, same height
-        let start_x = (frame_area.width as f32 * 0.15).round() as u16;
-        let start_y = frame_area.height / 2;
-        let start_pos = RatatuiPosition::new(
-            start_x.min(frame_area.right().saturating_sub(1)),
-            start_y,
-        );
-
-        let end_x = (frame_area.width as f32 * 0.60).round() as u16;
-        let end_pos = RatatuiPosition::new(end_x.max(frame_area.x), start_y);
-
+        // Start left, end right, same height; resolved against the frame each render
         let notification = NotificationBuilder::new(
             "Custom entry → exit path\nwith fade effect!\nSlides across screen",
         )
@@ -513,8 +495,8 @@ impl App {
         .border_type(self.current_border_type)
         .border_style(Style::new().fg(Color::Rgb(255, 165, 0)))
         .slide_direction(SlideDirection::FromLeft)
-        .entry_position(start_pos)
-        .exit_position(end_pos)
+        .entry_position_pct(15.0, 50.0)
+        .exit_position_pct(60.0, 50.0)
         .fade(true)
         .timing(
             Timing::Fixed(Duration::from_millis(800)),
@@ -525,7 +507,7 @@ impl App {
 
         match notification {
             Ok(n) => {
-                self.last_notification_code = generate_code(&n);
+                self.last_notification_code = generate_code_with_context(&n, self.notifications.manager_defaults());
                 if let Ok(id) = self.notifications.add(n) {
                     self.add_log(format!("Custom path+fade → ID {}", id));
                 }
@@ -561,7 +543,7 @@ impl App {
 
         match notification {
             Ok(n) => {
-                self.last_notification_code = generate_code(&n);
+                self.last_notification_code = generate_code_with_context(&n, self.notifications.manager_defaults());
                 if let Ok(id) = self.notifications.add(n) {
                     self.add_log(format!("Overflow #{} → ID {}", self.overflow_count, id));
                 }
@@ -683,6 +665,10 @@ fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App)
                                 app.show_code_modal = true;
                             }
                         }
+                        KeyCode::Char('I') => {
+                            app.last_notification_code = generate_scene_code(&app.notifications);
+                            app.show_code_modal = true;
+                        }
 
                         // ═══ HELP MODAL ═══
                         KeyCode::Char('?') => {
@@ -745,7 +731,6 @@ fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App)
 }
 
 fn ui(f: &mut Frame<'_>, app: &mut App) {
-    app.last_frame_area = f.area();
     let frame_area = f.area();
 
     // Layout: menu in center, log at bottom
@@ -766,8 +751,8 @@ fn ui(f: &mut Frame<'_>, app: &mut App) {
     render_menu(f, centered_menu, app);
     render_log(f, log_area, app);
 
-    // Render notifications on top of everything
-    app.notifications.render(f, frame_area);
+    // Render notifications over the app, but under its modals
+    app.notifications.render_layer(f, frame_area, Layer::Below);
 
     // Render modals on top if visible
     if app.show_code_modal {
@@ -776,6 +761,9 @@ fn ui(f: &mut Frame<'_>, app: &mut App) {
     if app.show_help_modal {
         render_help_modal(f, frame_area);
     }
+
+    // Notifications that opted into Layer::Above stay over the modals
+    app.notifications.render_layer(f, frame_area, Layer::Above);
 }
 
 fn render_menu(f: &mut Frame<'_>, area: Rect, app: &App) {
@@ -867,6 +855,7 @@ fn render_menu(f: &mut Frame<'_>, area: Rect, app: &App) {
             Span::styled(format!("{:?}", app.current_border_type), dim_style),
         ]),
         Line::from(vec![Span::styled("i", key_style), Span::raw(" show code")]),
+        Line::from(vec![Span::styled("I", key_style), Span::raw(" scene code")]),
         Line::from(vec![Span::styled("?", key_style), Span::raw(" help")]),
         Line::raw(""),
         Line::from(vec![Span::styled("q", key_style), Span::raw(" quit")]),
@@ -979,6 +968,10 @@ fn render_help_modal(f: &mut Frame<'_>, frame_area: Rect) {
             Span::styled("i", key_style),
             Span::raw("       Show generated code for last notification"),
         ]),
+        Line::from(vec![
+            Span::styled("I", key_style),
+            Span::raw("       Show generated code for the whole scene"),
+        ]),
         Line::from(vec![
             Span::styled("w", key_style),
             Span::raw("       (in code modal) Write code to file"),
@@ -995,4 +988,4 @@ fn render_help_modal(f: &mut Frame<'_>, frame_area: Rect) {
 }
 
 // FILE: examples/demo.rs - Interactive demonstration of ratatui-notifications crate features
-// END OF VERSION: 2.3.0
+// END OF VERSION: 2.7.0
@@ -0,0 +1,113 @@
+// FILE: examples/scenario.rs - Plays a TOML scenario file live or headless
+// VERSION: 1.0.0
+// WCTX: TOML scenario player
+// CLOG: Initial creation
+//
+// Scenario player: a timed sequence of notifications described in TOML.
+// Run with: cargo run --example scenario --features scenario -- [FILE] [--headless]
+//
+// FILE defaults to examples/scenarios/deploy.toml. Live mode plays the
+// scenario in the terminal (r restarts, q quits); --headless prints every
+// frame as text, exactly as the regression tests see them.
+
+use ratatui_notifications::{Notifications, Scenario, ScenarioPlayer};
+
+use color_eyre::{eyre::eyre, Result};
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Paragraph},
+};
+use std::{
+    io,
+    time::{Duration, Instant},
+};
+
+const DEFAULT_FILE: &str = "examples/scenarios/deploy.toml";
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let headless = args.iter().any(|arg| arg == "--headless");
+    let path = args
+        .iter()
+        .find(|arg| !arg.starts_with("--"))
+        .map_or(DEFAULT_FILE, String::as_str);
+    let scenario = Scenario::load(&std::fs::read_to_string(path)?).map_err(|err| eyre!("{path}: {err}"))?;
+
+    if headless {
+        let (width, height) = crossterm::terminal::size()
+            .ok()
+            .filter(|&(width, height)| width > 0 && height > 0)
+            .unwrap_or((80, 24));
+        for frame in scenario.run_headless(width, height) {
+            println!("--- {:?}", frame.at);
+            for line in frame.lines() {
+                println!("{line}");
+            }
+        }
+        return Ok(());
+    }
+
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, cursor::Hide)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+
+    let res = play(&mut terminal, &scenario, path);
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen, cursor::Show)?;
+    res
+}
+
+/// Plays the scenario in real time until the user quits.
+fn play(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, scenario: &Scenario, path: &str) -> Result<()> {
+    let tick_rate = Duration::from_millis(16);
+    let mut manager = scenario.configure(Notifications::new());
+    let mut player = ScenarioPlayer::new(scenario);
+    let mut last_tick = Instant::now();
+
+    loop {
+        let delta = last_tick.elapsed();
+        last_tick = Instant::now();
+        player.advance(delta);
+        player.add_due(&mut manager)?;
+        manager.tick(delta);
+
+        terminal.draw(|frame| {
+            let status = if player.is_finished() { "finished" } else { "playing" };
+            let help = format!(
+                " {path}: {status} at {:.1}s / {:.1}s  (r restart, q quit)",
+                player.elapsed().as_secs_f32(),
+                scenario.duration.as_secs_f32(),
+            );
+            frame.render_widget(Paragraph::new(help).block(Block::bordered().title(" Scenario ")), frame.area());
+            manager.render(frame, frame.area());
+        })?;
+
+        if event::poll(tick_rate)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                        KeyCode::Char('r') => {
+                            manager = scenario.configure(Notifications::new());
+                            player = ScenarioPlayer::new(scenario);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+}
+
+// FILE: examples/scenario.rs - Plays a TOML scenario file live or headless
+// END OF VERSION: 1.0.0
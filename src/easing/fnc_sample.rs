@@ -0,0 +1,43 @@
+// FILE: src/easing/fnc_sample.rs - Evenly spaced samples of an easing curve
+// VERSION: 1.0.0
+// WCTX: Public easing module
+// CLOG: Initial creation
+
+use crate::notifications::Easing;
+
+/// Samples an easing curve at evenly spaced points, for drawing previews.
+///
+/// Samples span `0.0..=1.0` inclusive, so the first is the curve at `0.0`
+/// and the last the curve at `1.0`. A single sample is taken at `1.0`.
+///
+/// # Arguments
+///
+/// * `easing` - The curve to sample
+/// * `steps` - Number of samples
+///
+/// # Returns
+///
+/// `steps` eased values, each in `0.0..=1.0`
+///
+/// # Example
+///
+/// ```
+/// use ratatui_notifications::easing::{sample, Easing};
+///
+/// assert_eq!(sample(Easing::Linear, 5), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+/// assert_eq!(sample(Easing::EaseInQuad, 3), vec![0.0, 0.25, 1.0]);
+/// assert!(sample(Easing::Linear, 0).is_empty());
+/// ```
+pub fn sample(easing: Easing, steps: usize) -> Vec<f32> {
+    match steps {
+        0 => Vec::new(),
+        1 => vec![easing.apply(1.0)],
+        _ => {
+            let last = (steps - 1) as f32;
+            (0..steps).map(|i| easing.apply(i as f32 / last)).collect()
+        }
+    }
+}
+
+// FILE: src/easing/fnc_sample.rs - Evenly spaced samples of an easing curve
+// END OF VERSION: 1.0.0
@@ -0,0 +1,30 @@
+// FILE: src/easing/mod.rs - Public easing curves and preview sampling
+// VERSION: 1.0.0
+// WCTX: Public easing module
+// CLOG: Initial creation
+
+//! Easing curves used by the built-in animations.
+//!
+//! All functions clamp their progress parameter to `0.0..=1.0`; none
+//! extrapolate. [`sample`] turns a curve into points for a preview, e.g.
+//! the data of a ratatui `Sparkline`.
+//!
+//! ```
+//! use ratatui_notifications::easing::{sample, Easing};
+//!
+//! let bars: Vec<u64> = sample(Easing::EaseOutQuad, 20)
+//!     .into_iter()
+//!     .map(|y| (y * 8.0).round() as u64)
+//!     .collect();
+//! assert_eq!(bars.len(), 20);
+//! assert_eq!(bars[19], 8);
+//! ```
+
+mod fnc_sample;
+
+pub use crate::notifications::Easing;
+pub use crate::shared_utils::math::{ease_in_out_quad, ease_in_quad, ease_out_quad, lerp};
+pub use fnc_sample::sample;
+
+// FILE: src/easing/mod.rs - Public easing curves and preview sampling
+// END OF VERSION: 1.0.0
@@ -1,7 +1,7 @@
 // FILE: src/lib.rs - Ratatui Notifications library root
-// VERSION: 2.1.0
-// WCTX: Adding code generation feature
-// CLOG: Added generate_code export
+// VERSION: 2.41.0
+// WCTX: Absolute TTL
+// CLOG: Exported DismissReason
 
 //! # Ratatui Notifications
 //!
@@ -19,6 +19,46 @@
 //! - **Customizable appearance**: Icons, colors, borders, and styling
 //! - **Level-based styling**: Info, Success, Warning, Error with distinct visual cues
 //!
+//! ## Feature Flags
+//!
+//! - `std` (default): the [`Notifications`] manager and the [`SystemClock`] time
+//!   source. Without it only the layout core is built (types, layout and
+//!   animation functions, stacking, rendering helpers), which never reads the
+//!   current time and takes durations as plain values.
+//! - `crossterm` (default): enables ratatui's crossterm backend and
+//!   [`Notifications::handle_event`], which dismisses notifications on key presses.
+//! - `std-thread`: [`Notifications::spawn_ticker`], which ticks a shared
+//!   `Arc<Mutex<Notifications>>` from a background thread for apps without a
+//!   render loop.
+//! - `wasm`: replaces the default clock with a `performance.now()`-backed
+//!   [`PerformanceClock`] for `wasm32-unknown-unknown`. Drive
+//!   [`Notifications::tick`] from `requestAnimationFrame`, passing the
+//!   difference between consecutive callback timestamps as the delta.
+//! - `serde`: `Serialize`/`Deserialize` for [`Notification`], its
+//!   configuration types and [`SavedNotifications`], so persistent
+//!   notifications can survive an app restart via
+//!   [`Notifications::save_state`] and [`Notifications::restore_state`].
+//! - `center`: the `NotificationCenter` widget, a scrollable list of the
+//!   manager's history (see [`Notifications::history_limit`]) rendered into
+//!   any `Rect`.
+//! - `test-clock`: `TestClock`, a clock that only moves when advanced. Pass
+//!   it to [`Notifications::with_clock`] for deterministic timestamps in tests.
+//! - `scenario`: `Scenario`, a timed sequence of notifications loaded from
+//!   TOML, played live with `ScenarioPlayer` or rendered frame by frame with
+//!   `Scenario::run_headless` for regression tests. Implies `serde` and
+//!   `test-clock`.
+//!
+//! ## ratatui Compatibility
+//!
+//! The minimum supported ratatui version is 0.30. Rendering needs only a
+//! `Buffer` and a `Rect`: [`Notifications::render_buffer`] and its layer and
+//! filtered variants draw into any buffer, and the `Frame`-taking
+//! [`Notifications::render`] family is a thin convenience over them. Apart
+//! from `Buffer` and `Frame` in the render entry points, the public API uses
+//! only long-stable ratatui types: `Rect`, `Position`, `Style`, `Color`,
+//! `Text` and its `Line`s, `BorderType` and `Padding` (plus `Block` for the
+//! `center` widget).
+//!
 //! ## Quick Start
 //!
 //! ```no_run
@@ -100,40 +140,112 @@
 //!     .unwrap();
 //! ```
 
+#[cfg(all(feature = "std", target_arch = "wasm32", not(feature = "wasm")))]
+compile_error!("`Instant::now()` is unavailable on wasm32; enable the `wasm` feature");
+
+pub mod easing;
 pub mod notifications;
 pub(crate) mod shared_utils;
+pub mod styles;
 
 // Re-export public API at crate root for ergonomic imports
 pub use notifications::{
     // Core types
     Notification,
     NotificationBuilder,
-    Notifications,
+    NotificationInfo,
+    NotificationRecord,
+
+    // Styling
+    LevelTheme,
+    Theme,
+
+    // Localization
+    StringArgs,
+    StringKey,
+    Strings,
 
     // Configuration enums
     Anchor,
+    AnchorCoord,
     Animation,
+    AnimationLayer,
     AutoDismiss,
+    CatchUpPolicy,
+    ColorClamp,
+    ColorMode,
+    CornerSlideAxis,
+    DismissReason,
+    Easing,
+    Evictability,
+    ExpandOrigin,
+    FadeScope,
+    Layer,
+    LayerTransform,
     Level,
     Overflow,
+    PathPoint,
+    PlaceholderStyle,
     SizeConstraint,
     SlideDirection,
+    SortBy,
+    StackLayout,
     Timing,
+    TruncateMode,
+    VerticalAlign,
 
-    // Error type
+    // Error and warning types
+    BuildWarning,
+    BuildWarningKind,
     NotificationError,
 
+    // Time source
+    Clock,
+    Timestamp,
+
+    // Feedback on show
+    Notifier,
+
+    // Custom managers
+    StateUpdate,
+
     // Layout utilities (for custom positioning)
     calculate_anchor_position,
     calculate_rect,
     calculate_size,
+    measure,
+    measure_lines,
+    split_to_fit,
+    MeasureOptions,
 
     // Code generation utility
     generate_code,
 };
 
+#[cfg(feature = "std")]
+pub use notifications::{
+    generate_code_with_context, generate_scene_code, BellNotifier, ManagerDefaults,
+    NotificationMut, NotificationState, NotificationStateBuilder, Notifications,
+    NotificationsWidget, SavedNotification, SavedNotifications,
+};
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+pub use notifications::SystemClock;
+#[cfg(feature = "wasm")]
+pub use notifications::PerformanceClock;
+#[cfg(feature = "std-thread")]
+pub use notifications::TickerHandle;
+#[cfg(feature = "scenario")]
+pub use notifications::{
+    FrameSnapshot, NotificationConfig, Scenario, ScenarioError, ScenarioEvent, ScenarioPlayer,
+    ScenarioSettings,
+};
+#[cfg(feature = "center")]
+pub use notifications::{NotificationCenter, NotificationCenterState};
+#[cfg(feature = "crossterm")]
+pub use notifications::DismissOnInput;
+
 // Re-export ratatui Position for custom positioning
 pub use ratatui::layout::Position;
 
 // FILE: src/lib.rs - Ratatui Notifications library root
-// END OF VERSION: 2.1.0
+// END OF VERSION: 2.41.0
@@ -1,13 +1,22 @@
 // FILE: src/notifications/classes/cls_notification.rs - Notification class with builder
-// VERSION: 2.2.0
-// WCTX: Adding code generation support
-// CLOG: Added content() and title() getters for code generation feature
+// VERSION: 2.39.0
+// WCTX: Dismiss on input
+// CLOG: Added require_ack
 
 use ratatui::prelude::*;
+use core::time::Duration;
+use std::borrow::Cow;
+use std::sync::Arc;
 use ratatui::widgets::{BorderType, Padding};
 
+use crate::notifications::functions::fnc_big_text::big_text;
+use crate::notifications::functions::fnc_check_strict_fit::check_strict_fit;
+use crate::notifications::functions::fnc_fill_template::fill_template;
+use crate::notifications::functions::fnc_lint_notification::lint_notification;
 use crate::notifications::types::{
-    Anchor, Animation, AutoDismiss, Level, NotificationError, SlideDirection, SizeConstraint, Timing,
+    Anchor, Animation, AnimationLayer, AutoDismiss, BuildWarning, Easing, Evictability, ExpandOrigin, FadeScope, Layer, Level,
+    LiveContent, NotificationError, PathPoint, PlaceholderStyle, SlideDirection, SizeConstraint,
+    Timing, TruncateMode, VerticalAlign,
 };
 
 /// Maximum allowed characters in notification content.
@@ -30,11 +39,20 @@ const MAX_CONTENT_CHARS: usize = 1000;
 ///     .unwrap();
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Notification {
-    /// The notification content (body text).
-    pub(crate) content: Text<'static>,
+    /// The notification content (body text), shared between clones.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::notifications::functions::fnc_serde_text::shared_text")
+    )]
+    pub(crate) content: Arc<Text<'static>>,
 
     /// Optional title line displayed at the top.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::notifications::functions::fnc_serde_text::optional_line")
+    )]
     pub(crate) title: Option<Line<'static>>,
 
     /// Severity level affecting visual styling.
@@ -43,9 +61,16 @@ pub struct Notification {
     /// Screen position from which notification expands.
     pub(crate) anchor: Anchor,
 
+    /// Whether the anchor was chosen explicitly; unset anchors take the
+    /// manager's `default_anchor`.
+    pub(crate) anchor_set: bool,
+
     /// Animation style for entry and exit.
     pub(crate) animation: Animation,
 
+    /// Transforms composed in place of the animation's own; empty uses the animation.
+    pub(crate) animation_layers: Vec<AnimationLayer>,
+
     /// Direction from which notification slides (for Slide animation).
     pub(crate) slide_direction: SlideDirection,
 
@@ -61,12 +86,33 @@ pub struct Notification {
     /// Auto-dismissal behavior.
     pub(crate) auto_dismiss: AutoDismiss,
 
+    /// Maximum age from when the notification was added.
+    pub(crate) ttl: Option<Duration>,
+
+    /// Whether `DismissOnInput::Any` leaves the notification on screen.
+    pub(crate) require_ack: bool,
+
     /// Maximum width constraint.
     pub(crate) max_width: Option<SizeConstraint>,
 
     /// Maximum height constraint.
     pub(crate) max_height: Option<SizeConstraint>,
 
+    /// Minimum width constraint.
+    pub(crate) min_width: Option<SizeConstraint>,
+
+    /// Minimum height constraint.
+    pub(crate) min_height: Option<SizeConstraint>,
+
+    /// Vertical placement of content when the box has spare rows.
+    pub(crate) vertical_align: VerticalAlign,
+
+    /// Where lines too wide for the box are cut; `None` wraps them.
+    pub(crate) truncate_mode: Option<TruncateMode>,
+
+    /// Marker for the part removed by truncation.
+    pub(crate) ellipsis: String,
+
     /// Inner padding around content.
     pub(crate) padding: Padding,
 
@@ -82,17 +128,89 @@ pub struct Notification {
     /// Title style.
     pub(crate) title_style: Option<Style>,
 
+    /// Style patched over the others once the exit begins.
+    pub(crate) exit_style: Option<Style>,
+
     /// Border type (single, double, thick, etc.).
     pub(crate) border_type: Option<BorderType>,
 
     /// Custom entry position (overrides anchor-based calculation).
-    pub(crate) custom_entry_position: Option<Position>,
+    pub(crate) custom_entry_position: Option<PathPoint>,
 
     /// Custom exit position (overrides anchor-based calculation).
-    pub(crate) custom_exit_position: Option<Position>,
+    pub(crate) custom_exit_position: Option<PathPoint>,
+
+    /// Whether the exit slides back the way the entry came.
+    pub(crate) exit_reverse: bool,
 
     /// Whether to apply fade effect during animation.
     pub(crate) fade_effect: bool,
+
+    /// Which parts of the notification a fade affects.
+    pub(crate) fade_scope: FadeScope,
+
+    /// Whether to draw a horizontal rule between the title and the content.
+    pub(crate) title_separator: bool,
+
+    /// Point that stays fixed during the expand/collapse animation.
+    pub(crate) expand_origin: ExpandOrigin,
+
+    /// Curve the expand/collapse size follows.
+    pub(crate) expand_easing: Easing,
+
+    /// Whether the content is drawn in the built-in block font.
+    pub(crate) big_text: bool,
+
+    /// Producer that replaces the content on every manager tick.
+    /// Closures are not serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) live_content: Option<LiveContent>,
+
+    /// Whether content text is recolored for readability on the background.
+    pub(crate) auto_contrast: bool,
+
+    /// Whether the area behind the notification is left uncleared.
+    pub(crate) transparent: bool,
+
+    /// Whether the seconds left before auto-dismiss are shown in the title.
+    pub(crate) show_countdown: bool,
+
+    /// Whether the time since the notification was added is shown after the content.
+    pub(crate) show_elapsed: bool,
+
+    /// Animation speed multiplier; 2.0 runs twice as fast.
+    pub(crate) speed: f32,
+
+    /// Whether `speed` also scales the display time.
+    pub(crate) speed_affects_dwell: bool,
+
+    /// Severity ranks of priority lost per second of dwelling.
+    pub(crate) decay: f32,
+
+    /// How willingly the notification gives up its slot under the limits.
+    pub(crate) evictable: Evictability,
+
+    /// Contents cycled through while dwelling; empty for a single page.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::notifications::functions::fnc_serde_text::text_vec")
+    )]
+    pub(crate) pages: Vec<Text<'static>>,
+
+    /// How long each page is shown.
+    pub(crate) page_interval: Duration,
+
+    /// Whether the display time is one full cycle through the pages.
+    pub(crate) dismiss_after_cycle: bool,
+
+    /// Time spent in `Pending` before the entry animation starts.
+    pub(crate) delay: Duration,
+
+    /// Shown instead of the content until `update_content` supplies it.
+    pub(crate) placeholder: Option<PlaceholderStyle>,
+
+    /// Which `render_layer` pass draws the notification.
+    pub(crate) layer: Layer,
 }
 
 impl Notification {
@@ -144,6 +262,11 @@ impl Notification {
         self.animation
     }
 
+    /// Returns the composed animation layers; empty when the animation plays alone.
+    pub fn animation_layers(&self) -> &[AnimationLayer] {
+        &self.animation_layers
+    }
+
     /// Returns the notification's slide direction.
     pub fn slide_direction(&self) -> SlideDirection {
         self.slide_direction
@@ -169,6 +292,16 @@ impl Notification {
         self.auto_dismiss
     }
 
+    /// Returns the maximum age, if one was set.
+    pub fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+
+    /// Returns whether any-key dismissal leaves the notification on screen.
+    pub fn require_ack(&self) -> bool {
+        self.require_ack
+    }
+
     /// Returns the maximum width constraint.
     pub fn max_width(&self) -> Option<SizeConstraint> {
         self.max_width
@@ -179,6 +312,31 @@ impl Notification {
         self.max_height
     }
 
+    /// Returns the minimum width constraint.
+    pub fn min_width(&self) -> Option<SizeConstraint> {
+        self.min_width
+    }
+
+    /// Returns the minimum height constraint.
+    pub fn min_height(&self) -> Option<SizeConstraint> {
+        self.min_height
+    }
+
+    /// Returns the vertical content alignment.
+    pub fn vertical_align(&self) -> VerticalAlign {
+        self.vertical_align
+    }
+
+    /// Returns where long lines are truncated, or `None` if they wrap.
+    pub fn truncate_mode(&self) -> Option<TruncateMode> {
+        self.truncate_mode
+    }
+
+    /// Returns the string that marks truncated text.
+    pub fn ellipsis(&self) -> &str {
+        &self.ellipsis
+    }
+
     /// Returns the inner padding.
     pub fn padding(&self) -> Padding {
         self.padding
@@ -194,46 +352,238 @@ impl Notification {
         self.border_type
     }
 
-    /// Returns the custom entry position.
+    /// Returns the custom entry position, if it was set in absolute cells.
+    ///
+    /// Use [`entry_point`](Self::entry_point) to also see percent positions.
     pub fn custom_entry_position(&self) -> Option<Position> {
-        self.custom_entry_position
+        match self.custom_entry_position {
+            Some(PathPoint::Absolute(position)) => Some(position),
+            _ => None,
+        }
     }
 
-    /// Returns the custom exit position.
+    /// Returns the custom exit position, if it was set in absolute cells.
+    ///
+    /// Use [`exit_point`](Self::exit_point) to also see percent positions.
     pub fn custom_exit_position(&self) -> Option<Position> {
+        match self.custom_exit_position {
+            Some(PathPoint::Absolute(position)) => Some(position),
+            _ => None,
+        }
+    }
+
+    /// Returns the custom entry point, absolute or percent.
+    pub fn entry_point(&self) -> Option<PathPoint> {
+        self.custom_entry_position
+    }
+
+    /// Returns the custom exit point, absolute or percent.
+    pub fn exit_point(&self) -> Option<PathPoint> {
         self.custom_exit_position
     }
 
+    /// Returns whether the exit slides back the way the entry came.
+    pub fn exit_reverse(&self) -> bool {
+        self.exit_reverse
+    }
+
     /// Returns whether fade effect is enabled.
     pub fn fade_effect(&self) -> bool {
         self.fade_effect
     }
+
+    /// Returns which parts of the notification a fade affects.
+    pub fn fade_scope(&self) -> FadeScope {
+        self.fade_scope
+    }
+
+    /// Returns whether a title separator was requested.
+    pub fn title_separator(&self) -> bool {
+        self.title_separator
+    }
+
+    /// Returns the expand/collapse origin.
+    pub fn expand_origin(&self) -> ExpandOrigin {
+        self.expand_origin
+    }
+
+    /// Returns the curve the expand/collapse size follows.
+    pub fn expand_easing(&self) -> Easing {
+        self.expand_easing
+    }
+
+    /// Returns whether the content is drawn in the block font.
+    pub fn big_text(&self) -> bool {
+        self.big_text
+    }
+
+    /// Returns whether content text is recolored to contrast with the background.
+    pub fn auto_contrast(&self) -> bool {
+        self.auto_contrast
+    }
+
+    /// Returns whether content beneath shows through unfilled cells.
+    pub fn transparent(&self) -> bool {
+        self.transparent
+    }
+
+    /// Returns whether the seconds left before auto-dismiss are shown.
+    pub fn show_countdown(&self) -> bool {
+        self.show_countdown
+    }
+
+    /// Returns whether the time since the notification was added is shown.
+    pub fn show_elapsed(&self) -> bool {
+        self.show_elapsed
+    }
+
+    /// Returns the animation speed multiplier.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Returns whether the speed multiplier also scales the display time.
+    pub fn speed_affects_dwell(&self) -> bool {
+        self.speed_affects_dwell
+    }
+
+    /// Returns the priority lost per second of dwelling.
+    pub fn decay(&self) -> f32 {
+        self.decay
+    }
+
+    /// Returns how willingly the notification gives up its slot.
+    pub fn evictable(&self) -> Evictability {
+        self.evictable
+    }
+
+    /// Returns the pages cycled through while dwelling; empty for a single page.
+    pub fn pages(&self) -> &[Text<'static>] {
+        &self.pages
+    }
+
+    /// Returns how long each page is shown.
+    pub fn page_interval(&self) -> Duration {
+        self.page_interval
+    }
+
+    /// Returns whether the display time is one full cycle through the pages.
+    pub fn dismiss_after_cycle(&self) -> bool {
+        self.dismiss_after_cycle
+    }
+
+    /// Returns how long the notification waits before its entry animation.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Returns what is shown until the content is supplied, if anything.
+    pub fn placeholder(&self) -> Option<&PlaceholderStyle> {
+        self.placeholder.as_ref()
+    }
+
+    /// Returns the layer the notification is drawn in.
+    pub fn layer(&self) -> Layer {
+        self.layer
+    }
+
+    /// Returns the style patched over the notification while it exits.
+    pub fn exit_style(&self) -> Option<Style> {
+        self.exit_style
+    }
+
+    /// Returns whether the content is recomputed on every tick.
+    pub fn has_live_content(&self) -> bool {
+        self.live_content.is_some()
+    }
+
+    /// Returns the content as it is laid out: expanded to the block font
+    /// when `big_text` is set, otherwise as given.
+    pub(crate) fn layout_content(&self) -> Cow<'_, Text<'static>> {
+        self.layout_text(&self.content)
+    }
+
+    /// Returns `text` as it is laid out, like [`layout_content`](Self::layout_content).
+    pub(crate) fn layout_text<'a>(&self, text: &'a Text<'static>) -> Cow<'a, Text<'static>> {
+        if self.big_text {
+            Cow::Owned(big_text(text))
+        } else {
+            Cow::Borrowed(text)
+        }
+    }
+
+    /// Returns whether the title separator row is actually drawn.
+    ///
+    /// The rule only appears when there is both a title and content.
+    pub(crate) fn shows_title_separator(&self) -> bool {
+        self.title_separator && self.title.is_some() && self.content.width() > 0
+    }
+
+    /// Gives a notification without an explicit anchor the manager's default.
+    #[cfg(feature = "std")] // Only the manager and its states resolve anchors
+    pub(crate) fn resolve_anchor(&mut self, default_anchor: Anchor) {
+        if !self.anchor_set {
+            self.anchor = default_anchor;
+            self.anchor_set = true;
+        }
+    }
 }
 
 impl Default for Notification {
     fn default() -> Self {
         Self {
-            content: Text::from(""),
+            content: Arc::default(),
             title: None,
             level: Some(Level::Info),
             anchor: Anchor::default(),
+            anchor_set: false,
             animation: Animation::default(),
+            animation_layers: Vec::new(),
             slide_direction: SlideDirection::default(),
             slide_in_timing: Timing::default(),
             dwell_timing: Timing::default(),
             slide_out_timing: Timing::default(),
             auto_dismiss: AutoDismiss::default(),
+            ttl: None,
+            require_ack: false,
             max_width: Some(SizeConstraint::Percentage(0.4)),
             max_height: Some(SizeConstraint::Percentage(0.2)),
+            min_width: None,
+            min_height: None,
+            vertical_align: VerticalAlign::default(),
+            truncate_mode: None,
+            ellipsis: "…".to_string(),
             padding: Padding::horizontal(1),
             exterior_margin: 0,
             block_style: None,
             border_style: None,
             title_style: None,
+            exit_style: None,
             border_type: Some(BorderType::Rounded),
             custom_entry_position: None,
             custom_exit_position: None,
+            exit_reverse: false,
             fade_effect: false,
+            fade_scope: FadeScope::default(),
+            title_separator: false,
+            expand_origin: ExpandOrigin::default(),
+            expand_easing: Easing::EaseOutQuad,
+            big_text: false,
+            live_content: None,
+            auto_contrast: false,
+            transparent: false,
+            show_countdown: false,
+            show_elapsed: false,
+            speed: 1.0,
+            speed_affects_dwell: false,
+            decay: 0.0,
+            evictable: Evictability::default(),
+            pages: Vec::new(),
+            page_interval: Duration::from_secs(2),
+            dismiss_after_cycle: false,
+            delay: Duration::ZERO,
+            placeholder: None,
+            layer: Layer::default(),
         }
     }
 }
@@ -256,6 +606,9 @@ impl Default for Notification {
 #[derive(Debug, Clone)]
 pub struct NotificationBuilder {
     notification: Notification,
+    strict_fit: bool,
+    template: Option<(String, Vec<(String, String)>)>,
+    strict_template: bool,
 }
 
 impl NotificationBuilder {
@@ -275,196 +628,1173 @@ impl NotificationBuilder {
     pub fn new(content: impl Into<Text<'static>>) -> Self {
         Self {
             notification: Notification {
-                content: content.into(),
+                content: Arc::new(content.into()),
                 ..Default::default()
             },
+            strict_fit: false,
+            template: None,
+            strict_template: false,
+        }
+    }
+
+    /// Creates a new builder around content shared with other notifications.
+    ///
+    /// Every notification built from the same `Arc` borrows that one
+    /// allocation instead of holding its own copy, which keeps floods of
+    /// identical notifications (heartbeats, repeated log lines) cheap.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The shared body text
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::sync::Arc;
+    /// use ratatui::text::Text;
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    ///
+    /// let heartbeat = Arc::new(Text::from("heartbeat OK"));
+    /// let notification = NotificationBuilder::content_arc(Arc::clone(&heartbeat))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn content_arc(content: Arc<Text<'static>>) -> Self {
+        Self {
+            notification: Notification { content, ..Default::default() },
+            strict_fit: false,
+            template: None,
+            strict_template: false,
         }
     }
 
-    /// Sets the notification title.
+    /// Creates a builder preset for a compact badge, e.g. an unread count.
+    ///
+    /// The box fits the text exactly: a plain border, no padding and no
+    /// title, anchored top-right. Any preset can be overridden by chaining
+    /// further builder methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The badge text
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    ///
+    /// // Renders as a 3x3 box: ┌─┐ │3│ └─┘
+    /// let badge = NotificationBuilder::badge("3").build().unwrap();
+    /// ```
+    pub fn badge(text: impl Into<Text<'static>>) -> Self {
+        let text = text.into();
+        let width = SizeConstraint::Absolute(text.width() as u16 + 2);
+        let height = SizeConstraint::Absolute(text.height() as u16 + 2);
+
+        Self::new(text)
+            .anchor(Anchor::TopRight)
+            .padding(Padding::ZERO)
+            .border_type(BorderType::Plain)
+            .min_size(width, height)
+            .max_size(width, height)
+    }
+
+    /// Creates a builder whose content is filled in from a template.
+    ///
+    /// `{name}` placeholders are replaced with the matching field value when
+    /// the notification is built; `{{` and `}}` produce literal braces.
+    /// Unmatched placeholders are left as written unless
+    /// [`strict_template`](Self::strict_template) is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - Content template with `{name}` placeholders
+    /// * `fields` - `(name, value)` pairs to substitute
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    ///
+    /// let notification = NotificationBuilder::template(
+    ///     "{user} logged in at {time}",
+    ///     &[("user", "alice"), ("time", "14:30")],
+    /// )
+    /// .build()
+    /// .unwrap();
+    /// ```
+    pub fn template(template: impl Into<String>, fields: &[(&str, &str)]) -> Self {
+        let mut builder = Self::new("");
+        builder.template = Some((
+            template.into(),
+            fields
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        ));
+        builder
+    }
+
+    /// Makes unmatched template placeholders a build error.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - When true, `build()` returns
+    ///   `NotificationError::MissingTemplateField` for a placeholder with no field
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    ///
+    /// let result = NotificationBuilder::template("Hello {user}", &[])
+    ///     .strict_template(true)
+    ///     .build();
+    /// assert!(result.is_err());
+    /// ```
+    pub fn strict_template(mut self, enable: bool) -> Self {
+        self.strict_template = enable;
+        self
+    }
+
+    /// Sets the notification title.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Title text displayed at the top of the notification
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    ///
+    /// let notification = NotificationBuilder::new("Content")
+    ///     .title("My Title")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn title(mut self, title: impl Into<Line<'static>>) -> Self {
+        self.notification.title = Some(title.into());
+        self
+    }
+
+    /// Sets the notification severity level.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - Severity level (Info, Warn, Error, Debug, Trace)
+    pub fn level(mut self, level: Level) -> Self {
+        self.notification.level = Some(level);
+        self
+    }
+
+    /// Sets the screen anchor position.
+    ///
+    /// Without this the manager's `ManagerDefaults::default_anchor` applies,
+    /// which is `BottomRight` unless changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `anchor` - Position from which notification expands
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.notification.anchor = anchor;
+        self.notification.anchor_set = true;
+        self
+    }
+
+    /// Sets the animation type.
+    ///
+    /// # Arguments
+    ///
+    /// * `animation` - Animation style (Slide, ExpandCollapse, Fade)
+    pub fn animation(mut self, animation: Animation) -> Self {
+        self.notification.animation = animation;
+        self
+    }
+
+    /// Plays several animation transforms at once.
+    ///
+    /// Each layer contributes an offset, scale or opacity and the renderer
+    /// composes them, replacing the rect and fade of `animation`. The
+    /// `animation` still picks the phases and timings, so set the timings
+    /// you want the layers to share. An empty list restores the animation's
+    /// own transforms.
+    ///
+    /// # Arguments
+    ///
+    /// * `layers` - The transforms to compose, e.g. `[Slide, Scale]`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{AnimationLayer, NotificationBuilder};
+    ///
+    /// let notification = NotificationBuilder::new("Zooming in")
+    ///     .animation_layers([AnimationLayer::Slide, AnimationLayer::Scale])
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn animation_layers(mut self, layers: impl IntoIterator<Item = AnimationLayer>) -> Self {
+        self.notification.animation_layers = layers.into_iter().collect();
+        self
+    }
+
+    /// Sets the slide direction.
+    ///
+    /// # Arguments
+    ///
+    /// * `direction` - Direction from which notification slides in
+    pub fn slide_direction(mut self, direction: SlideDirection) -> Self {
+        self.notification.slide_direction = direction;
+        self
+    }
+
+    /// Sets the animation timings.
+    ///
+    /// # Arguments
+    ///
+    /// * `slide_in` - Duration for slide-in animation
+    /// * `dwell` - Duration notification remains visible
+    /// * `slide_out` - Duration for slide-out animation
+    pub fn timing(mut self, slide_in: Timing, dwell: Timing, slide_out: Timing) -> Self {
+        self.notification.slide_in_timing = slide_in;
+        self.notification.dwell_timing = dwell;
+        self.notification.slide_out_timing = slide_out;
+        self
+    }
+
+    /// Sets auto-dismiss behavior.
+    ///
+    /// # Arguments
+    ///
+    /// * `auto_dismiss` - When to automatically dismiss the notification
+    pub fn auto_dismiss(mut self, auto_dismiss: AutoDismiss) -> Self {
+        self.notification.auto_dismiss = auto_dismiss;
+        self
+    }
+
+    /// Sets a maximum age, measured from when the notification is added.
+    ///
+    /// Unlike `AutoDismiss::After`, which counts down only while the
+    /// notification dwells, the TTL runs on the manager's clock from
+    /// `add()` regardless of phase. Once it passes, the notification is
+    /// dismissed with its exit animation; if it has not been shown yet it is
+    /// dropped without appearing. Whichever of the TTL and auto-dismiss comes
+    /// first wins. The dismissal is reported as `DismissReason::Expired`, or
+    /// `DismissReason::Stale` for a notification that was never shown.
+    ///
+    /// A frozen notification's TTL is not checked: one that passes while
+    /// frozen dismisses it on the first tick after `unfreeze()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl` - Maximum age
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    /// use std::time::Duration;
+    ///
+    /// // A price quote is worthless after 10 seconds, however it is shown
+    /// let notification = NotificationBuilder::new("BTC 64,210")
+    ///     .ttl(Duration::from_secs(10))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.notification.ttl = Some(ttl);
+        self
+    }
+
+    /// Keeps the notification on screen when any key press would dismiss it.
+    ///
+    /// Under `DismissOnInput::Any`, `Notifications::handle_event` skips
+    /// notifications that require acknowledgement, so a stray keystroke
+    /// does not clear an alert the user has not read. Keys listed in
+    /// `DismissOnInput::Keys` still dismiss them, as do their timers and
+    /// every other way out.
+    ///
+    /// # Arguments
+    ///
+    /// * `require` - Whether any-key dismissal is ignored (default `false`)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{AutoDismiss, Level, NotificationBuilder};
+    ///
+    /// // Stays until Esc, not whatever key the user was typing
+    /// let notification = NotificationBuilder::new("Unsaved changes were lost")
+    ///     .level(Level::Error)
+    ///     .auto_dismiss(AutoDismiss::Never)
+    ///     .require_ack(true)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn require_ack(mut self, require: bool) -> Self {
+        self.notification.require_ack = require;
+        self
+    }
+
+    /// Sets maximum size constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Maximum width constraint
+    /// * `height` - Maximum height constraint
+    pub fn max_size(mut self, width: SizeConstraint, height: SizeConstraint) -> Self {
+        self.notification.max_width = Some(width);
+        self.notification.max_height = Some(height);
+        self
+    }
+
+    /// Sets minimum size constraints.
+    ///
+    /// The box grows to at least this size even when the content needs less.
+    /// Maximum constraints still win when the two conflict.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Minimum width constraint
+    /// * `height` - Minimum height constraint
+    pub fn min_size(mut self, width: SizeConstraint, height: SizeConstraint) -> Self {
+        self.notification.min_width = Some(width);
+        self.notification.min_height = Some(height);
+        self
+    }
+
+    /// Sets where content sits vertically when the box is taller than it.
+    ///
+    /// Defaults to `VerticalAlign::Top`.
+    ///
+    /// # Arguments
+    ///
+    /// * `align` - Top, center or bottom placement
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{
+    ///     NotificationBuilder, SizeConstraint, VerticalAlign,
+    /// };
+    ///
+    /// let notification = NotificationBuilder::new("Saved")
+    ///     .min_size(SizeConstraint::Absolute(20), SizeConstraint::Absolute(7))
+    ///     .vertical_align(VerticalAlign::Center)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn vertical_align(mut self, align: VerticalAlign) -> Self {
+        self.notification.vertical_align = align;
+        self
+    }
+
+    /// Truncates content lines that are too wide instead of wrapping them.
+    ///
+    /// Each line stays on one row; the cut part is replaced with the
+    /// [`ellipsis`](Self::ellipsis). `TruncateMode::Middle` keeps both ends,
+    /// which suits file paths. Lines wrap by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Keep the end (`Start`), both ends (`Middle`) or the start (`End`)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{NotificationBuilder, TruncateMode};
+    ///
+    /// let notification = NotificationBuilder::new("/home/user/projects/app/src/main.rs")
+    ///     .title("Saved")
+    ///     .truncate_mode(TruncateMode::Middle)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn truncate_mode(mut self, mode: TruncateMode) -> Self {
+        self.notification.truncate_mode = Some(mode);
+        self
+    }
+
+    /// Sets the string that replaces truncated text.
+    ///
+    /// Defaults to `"…"`; use `"..."` for fonts without the ellipsis glyph.
+    /// Only used with [`truncate_mode`](Self::truncate_mode).
+    ///
+    /// # Arguments
+    ///
+    /// * `ellipsis` - The marker string
+    pub fn ellipsis(mut self, ellipsis: impl Into<String>) -> Self {
+        self.notification.ellipsis = ellipsis.into();
+        self
+    }
+
+    /// Sets inner padding.
+    ///
+    /// # Arguments
+    ///
+    /// * `padding` - Padding around content
+    pub fn padding(mut self, padding: Padding) -> Self {
+        self.notification.padding = padding;
+        self
+    }
+
+    /// Sets exterior margin.
+    ///
+    /// # Arguments
+    ///
+    /// * `margin` - Margin from screen edge
+    pub fn margin(mut self, margin: u16) -> Self {
+        self.notification.exterior_margin = margin;
+        self
+    }
+
+    /// Sets block style.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - Overall block style
+    pub fn style(mut self, style: Style) -> Self {
+        self.notification.block_style = Some(style);
+        self
+    }
+
+    /// Sets border style.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - Border style
+    pub fn border_style(mut self, style: Style) -> Self {
+        self.notification.border_style = Some(style);
+        self
+    }
+
+    /// Sets title style.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - Title text style
+    pub fn title_style(mut self, style: Style) -> Self {
+        self.notification.title_style = Some(style);
+        self
+    }
+
+    /// Sets a style patched over the block, border and title once the exit
+    /// animation begins, so dismissed notifications visibly recede.
+    ///
+    /// With fading on (`fade(true)` or `Animation::Fade`) the color blends
+    /// in over the first 20% of the exit; otherwise it applies at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `style` - Style patch for the exit, e.g. dim gray
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui::style::{Color, Modifier, Style};
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    ///
+    /// let notification = NotificationBuilder::new("Saved")
+    ///     .exit_style(Style::new().fg(Color::DarkGray).add_modifier(Modifier::DIM))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn exit_style(mut self, style: Style) -> Self {
+        self.notification.exit_style = Some(style);
+        self
+    }
+
+    /// Sets border type.
+    ///
+    /// # Arguments
+    ///
+    /// * `border_type` - Border type (Single, Double, Thick, etc.)
+    pub fn border_type(mut self, border_type: BorderType) -> Self {
+        self.notification.border_type = Some(border_type);
+        self
+    }
+
+    /// Sets custom entry position.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Custom position for notification entry
+    pub fn entry_position(mut self, position: Position) -> Self {
+        self.notification.custom_entry_position = Some(PathPoint::Absolute(position));
+        self
+    }
+
+    /// Sets custom exit position.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - Custom position for notification exit
+    pub fn exit_position(mut self, position: Position) -> Self {
+        self.notification.custom_exit_position = Some(PathPoint::Absolute(position));
+        self
+    }
+
+    /// Sets custom entry position as percentages of the frame.
+    ///
+    /// Resolved against the frame on every render, so the slide keeps its
+    /// proportions across terminal sizes. Values outside `0.0..=100.0` start
+    /// the slide off screen.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Horizontal position of the top-left corner, in percent of the frame width
+    /// * `y` - Vertical position of the top-left corner, in percent of the frame height
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    ///
+    /// // Rise from below the middle of the screen, whatever its size
+    /// let notification = NotificationBuilder::new("Hello")
+    ///     .entry_position_pct(50.0, 110.0)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn entry_position_pct(mut self, x: f32, y: f32) -> Self {
+        self.notification.custom_entry_position = Some(PathPoint::Percent { x, y });
+        self
+    }
+
+    /// Sets custom exit position as percentages of the frame.
+    ///
+    /// Resolved against the frame on every render, like
+    /// [`entry_position_pct`](Self::entry_position_pct).
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - Horizontal position of the top-left corner, in percent of the frame width
+    /// * `y` - Vertical position of the top-left corner, in percent of the frame height
+    pub fn exit_position_pct(mut self, x: f32, y: f32) -> Self {
+        self.notification.custom_exit_position = Some(PathPoint::Percent { x, y });
+        self
+    }
+
+    /// Makes the exit slide back the way the entry came.
+    ///
+    /// The exit returns to the entry's start: its custom entry position, or
+    /// the side it slid in from. The side is fixed when the entry starts, so
+    /// a notification later moved to another anchor still leaves through it.
+    /// Conflicts with an exit position; `build()` rejects both together.
+    ///
+    /// # Arguments
+    ///
+    /// * `reverse` - Whether the exit reverses the entry (default `false`)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{NotificationBuilder, SlideDirection};
+    ///
+    /// // In from the left, back out to the left
+    /// let notification = NotificationBuilder::new("Hello")
+    ///     .slide_direction(SlideDirection::FromLeft)
+    ///     .exit_reverse(true)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn exit_reverse(mut self, reverse: bool) -> Self {
+        self.notification.exit_reverse = reverse;
+        self
+    }
+
+    /// Enables or disables fade effect.
+    ///
+    /// Layers a fade over the animation; with `animation_layers` set this
+    /// is the same as adding `AnimationLayer::Fade`.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Whether to apply fade effect during animation
+    pub fn fade(mut self, enable: bool) -> Self {
+        self.notification.fade_effect = enable;
+        self
+    }
+
+    /// Limits a fade to the frame or the content.
+    ///
+    /// Applies to `Animation::Fade` and to `fade(true)` on the other
+    /// animations. With `FadeScope::ContentOnly` the border and title appear
+    /// at full color (or slide in solid) while only the text fades.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Which parts fade; defaults to `FadeScope::All`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Animation, FadeScope, NotificationBuilder};
+    ///
+    /// let notification = NotificationBuilder::new("12 files changed, 340 insertions")
+    ///     .animation(Animation::Fade)
+    ///     .fade_scope(FadeScope::ContentOnly)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn fade_scope(mut self, scope: FadeScope) -> Self {
+        self.notification.fade_scope = scope;
+        self
+    }
+
+    /// Draws a horizontal rule between the title and the content.
+    ///
+    /// The rule uses the border's horizontal glyph and takes one extra row.
+    /// It is only drawn when the notification has both a title and content.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Whether to draw the separator
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    ///
+    /// let notification = NotificationBuilder::new("Build finished in 42s")
+    ///     .title("CI")
+    ///     .title_separator(true)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn title_separator(mut self, enable: bool) -> Self {
+        self.notification.title_separator = enable;
+        self
+    }
+
+    /// Picks black or white content text to stay readable on the background.
+    ///
+    /// Only applies when the block style (or theme) sets a background color,
+    /// and overrides the content foreground. Content that is fading in or
+    /// out keeps its fade colors until it settles.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Whether to recolor the content
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui::style::{Color, Style};
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    ///
+    /// let notification = NotificationBuilder::new("Readable on yellow")
+    ///     .style(Style::new().bg(Color::Yellow))
+    ///     .auto_contrast(true)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn auto_contrast(mut self, enable: bool) -> Self {
+        self.notification.auto_contrast = enable;
+        self
+    }
+
+    /// Skips clearing the area behind the notification.
+    ///
+    /// The border and text are still drawn, but padding and other cells the
+    /// text does not reach keep whatever was rendered beneath, for a glass
+    /// overlay look. Style colors still apply to those cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Whether to leave the area uncleared
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    ///
+    /// let notification = NotificationBuilder::new("Over the log view")
+    ///     .transparent(true)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn transparent(mut self, enable: bool) -> Self {
+        self.notification.transparent = enable;
+        self
+    }
+
+    /// Shows the whole seconds left before auto-dismiss in the title.
+    ///
+    /// The count (e.g. "5s") is appended to the title, or becomes the title
+    /// if there is none, and updates as the display time runs down. It is
+    /// hidden once the exit animation starts. Requires
+    /// `AutoDismiss::After`; `build_with_warnings` flags other settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Whether to show the countdown
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{AutoDismiss, NotificationBuilder};
+    /// use std::time::Duration;
+    ///
+    /// let notification = NotificationBuilder::new("Connection lost")
+    ///     .title("Retrying in")
+    ///     .auto_dismiss(AutoDismiss::After(Duration::from_secs(5)))
+    ///     .show_countdown(true)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn show_countdown(mut self, enable: bool) -> Self {
+        self.notification.show_countdown = enable;
+        self
+    }
+
+    /// Shows the time since the notification was added after the content.
+    ///
+    /// The time (e.g. "00:42", minutes and seconds) is appended to the last
+    /// content line and counts up on every tick, read from the manager's
+    /// clock. Pairs with `AutoDismiss::Never` and `Notifications::remove`
+    /// once the tracked work is done.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Whether to show the elapsed time
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{AutoDismiss, NotificationBuilder};
+    ///
+    /// // Shows "Running for 00:42" after 42 seconds
+    /// let notification = NotificationBuilder::new("Running for")
+    ///     .auto_dismiss(AutoDismiss::Never)
+    ///     .show_elapsed(true)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn show_elapsed(mut self, enable: bool) -> Self {
+        self.notification.show_elapsed = enable;
+        self
+    }
+
+    /// Multiplies the speed of the entry, exit and move animations.
+    ///
+    /// `0.5` runs twice as slow, `2.0` twice as fast. Timings are scaled as
+    /// the notification updates; the configured durations are unchanged.
+    /// The display time is not affected unless
+    /// [`speed_affects_dwell`](Self::speed_affects_dwell) is set. Multiplies
+    /// with the manager's `global_speed`. Defaults to `1.0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `speed` - Multiplier, greater than zero; `build` rejects others
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    ///
+    /// // Slow motion while inspecting the animation
+    /// let notification = NotificationBuilder::new("Saved")
+    ///     .speed(0.25)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.notification.speed = speed;
+        self
+    }
+
+    /// Sets whether the speed multiplier also scales the display time.
+    ///
+    /// Off by default, so only the motion changes speed.
+    ///
+    /// # Arguments
+    ///
+    /// * `enable` - Whether dwelling runs at the same speed as the animations
+    pub fn speed_affects_dwell(mut self, enable: bool) -> Self {
+        self.notification.speed_affects_dwell = enable;
+        self
+    }
+
+    /// Lowers the notification's priority the longer it dwells.
+    ///
+    /// Under `Overflow::DiscardLowestPriority` the priority starts at the
+    /// level's severity rank (`Error` 5 down to `Trace` 1) and drops by
+    /// `rate` for every second spent dwelling, so an old error eventually
+    /// gives way to fresh, lower-level notifications. Defaults to 0.0 (no
+    /// decay); `build` rejects negative or non-finite rates.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate` - Severity ranks lost per second of dwelling
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Level, NotificationBuilder};
+    ///
+    /// // Ranks like a warning after 1s, like info after 2s
+    /// let notification = NotificationBuilder::new("Disk almost full")
+    ///     .level(Level::Error)
+    ///     .decay(1.0)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn decay(mut self, rate: f32) -> Self {
+        self.notification.decay = rate;
+        self
+    }
+
+    /// Sets how willingly the notification gives up its slot when
+    /// `max_concurrent` or `max_total` is reached.
+    ///
+    /// Evictability trumps the overflow policy and priority: `First`
+    /// notifications are evicted before any `Normal` one, and `Never`
+    /// notifications are not evicted at all. Defaults to
+    /// `Evictability::Normal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `evictability` - The notification's eviction class
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Evictability, NotificationBuilder};
+    ///
+    /// let notification = NotificationBuilder::new("You have unsaved changes")
+    ///     .evictable(Evictability::Never)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn evictable(mut self, evictability: Evictability) -> Self {
+        self.notification.evictable = evictability;
+        self
+    }
+
+    /// Waits before starting the entry animation.
+    ///
+    /// The notification stays in `AnimationPhase::Pending`, undrawn, until
+    /// ticks add up to `delay`, then enters as usual. It still counts towards
+    /// `max_concurrent`, and a `ttl` runs from when it was added. For
+    /// notifications added with `add_hidden`, the delay starts at `show`.
+    /// Defaults to no delay.
     ///
     /// # Arguments
     ///
-    /// * `title` - Title text displayed at the top of the notification
+    /// * `delay` - Time to wait before appearing
     ///
     /// # Example
     ///
     /// ```no_run
     /// use ratatui_notifications::notifications::NotificationBuilder;
+    /// use std::time::Duration;
     ///
-    /// let notification = NotificationBuilder::new("Content")
-    ///     .title("My Title")
+    /// // Show this 2 seconds from now
+    /// let notification = NotificationBuilder::new("Still working...")
+    ///     .delay(Duration::from_secs(2))
     ///     .build()
     ///     .unwrap();
     /// ```
-    pub fn title(mut self, title: impl Into<Line<'static>>) -> Self {
-        self.notification.title = Some(title.into());
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.notification.delay = delay;
         self
     }
 
-    /// Sets the notification severity level.
+    /// Shows a placeholder instead of the content until it is supplied.
+    ///
+    /// For content that depends on a slow lookup: the box appears at once
+    /// and the placeholder is replaced by the first
+    /// `Notifications::update_content` (or `NotificationMut::set_content`),
+    /// with the box easing to its new size. The box is sized for the
+    /// placeholder, so give a `Skeleton` room with `min_size`.
     ///
     /// # Arguments
     ///
-    /// * `level` - Severity level (Info, Warn, Error, Debug, Trace)
-    pub fn level(mut self, level: Level) -> Self {
-        self.notification.level = Some(level);
-        self
-    }
-
-    /// Sets the screen anchor position.
+    /// * `style` - What to show meanwhile
     ///
-    /// # Arguments
+    /// # Example
     ///
-    /// * `anchor` - Position from which notification expands
-    pub fn anchor(mut self, anchor: Anchor) -> Self {
-        self.notification.anchor = anchor;
-        self
-    }
-
-    /// Sets the animation type.
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{
+    ///     NotificationBuilder, Notifications, PlaceholderStyle, SizeConstraint,
+    /// };
     ///
-    /// # Arguments
+    /// let mut manager = Notifications::new();
+    /// let notification = NotificationBuilder::new("")
+    ///     .title("Host")
+    ///     .placeholder(PlaceholderStyle::Skeleton)
+    ///     .min_size(SizeConstraint::Absolute(30), SizeConstraint::Absolute(4))
+    ///     .build()
+    ///     .unwrap();
+    /// let id = manager.add(notification).unwrap();
     ///
-    /// * `animation` - Animation style (Slide, ExpandCollapse, Fade)
-    pub fn animation(mut self, animation: Animation) -> Self {
-        self.notification.animation = animation;
+    /// // Once the lookup resolves
+    /// manager.update_content(id, "example.org → 93.184.216.34");
+    /// ```
+    pub fn placeholder(mut self, style: PlaceholderStyle) -> Self {
+        self.notification.placeholder = Some(style);
         self
     }
 
-    /// Sets the slide direction.
+    /// Sets which `Notifications::render_layer` pass draws the notification.
+    ///
+    /// Stacking still considers both layers, so an `Above` notification
+    /// never overlaps a `Below` one at the same anchor. Defaults to
+    /// `Layer::Below`.
     ///
     /// # Arguments
     ///
-    /// * `direction` - Direction from which notification slides in
-    pub fn slide_direction(mut self, direction: SlideDirection) -> Self {
-        self.notification.slide_direction = direction;
-        self
-    }
-
-    /// Sets the animation timings.
+    /// * `layer` - Below or above the app's overlays
     ///
-    /// # Arguments
+    /// # Example
     ///
-    /// * `slide_in` - Duration for slide-in animation
-    /// * `dwell` - Duration notification remains visible
-    /// * `slide_out` - Duration for slide-out animation
-    pub fn timing(mut self, slide_in: Timing, dwell: Timing, slide_out: Timing) -> Self {
-        self.notification.slide_in_timing = slide_in;
-        self.notification.dwell_timing = dwell;
-        self.notification.slide_out_timing = slide_out;
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Layer, Level, NotificationBuilder};
+    ///
+    /// // Stays visible over the app's dialogs
+    /// let notification = NotificationBuilder::new("Disk full")
+    ///     .level(Level::Error)
+    ///     .layer(Layer::Above)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn layer(mut self, layer: Layer) -> Self {
+        self.notification.layer = layer;
         self
     }
 
-    /// Sets auto-dismiss behavior.
+    /// Cycles through several contents in one notification.
+    ///
+    /// Replaces the content with the first page. While dwelling, the next
+    /// page is shown every [`page_interval`](Self::page_interval), wrapping
+    /// around, with a "2/3" indicator in the bottom border. The box is sized
+    /// to fit the largest page. `Notifications::next_page` flips pages by
+    /// hand. A single page behaves like plain content.
     ///
     /// # Arguments
     ///
-    /// * `auto_dismiss` - When to automatically dismiss the notification
-    pub fn auto_dismiss(mut self, auto_dismiss: AutoDismiss) -> Self {
-        self.notification.auto_dismiss = auto_dismiss;
-        self
-    }
-
-    /// Sets maximum size constraints.
+    /// * `pages` - The contents, in display order
     ///
-    /// # Arguments
+    /// # Example
     ///
-    /// * `width` - Maximum width constraint
-    /// * `height` - Maximum height constraint
-    pub fn max_size(mut self, width: SizeConstraint, height: SizeConstraint) -> Self {
-        self.notification.max_width = Some(width);
-        self.notification.max_height = Some(height);
+    /// ```no_run
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    /// use std::time::Duration;
+    ///
+    /// let notification = NotificationBuilder::new("")
+    ///     .title("3 new messages")
+    ///     .pages(["alice: lunch?", "bob: build is green", "carol: PTO tomorrow"])
+    ///     .page_interval(Duration::from_secs(3))
+    ///     .dismiss_after_cycle(true)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn pages(mut self, pages: impl IntoIterator<Item = impl Into<Text<'static>>>) -> Self {
+        self.notification.pages = pages.into_iter().map(Into::into).collect();
+        if let Some(first) = self.notification.pages.first() {
+            self.notification.content = Arc::new(first.clone());
+        }
         self
     }
 
-    /// Sets inner padding.
+    /// Sets how long each page is shown. Defaults to 2 seconds.
     ///
     /// # Arguments
     ///
-    /// * `padding` - Padding around content
-    pub fn padding(mut self, padding: Padding) -> Self {
-        self.notification.padding = padding;
+    /// * `interval` - Time per page, greater than zero; `build` rejects zero
+    ///   for notifications with several pages
+    pub fn page_interval(mut self, interval: Duration) -> Self {
+        self.notification.page_interval = interval;
         self
     }
 
-    /// Sets exterior margin.
+    /// Sets whether the display time is one full cycle through the pages.
+    ///
+    /// When enabled, a notification with several pages is dismissed once
+    /// the last page has been shown for its interval, whatever its
+    /// `auto_dismiss`. Off by default.
     ///
     /// # Arguments
     ///
-    /// * `margin` - Margin from screen edge
-    pub fn margin(mut self, margin: u16) -> Self {
-        self.notification.exterior_margin = margin;
+    /// * `enable` - Whether to dismiss after showing every page once
+    pub fn dismiss_after_cycle(mut self, enable: bool) -> Self {
+        self.notification.dismiss_after_cycle = enable;
         self
     }
 
-    /// Sets block style.
+    /// Draws the content in a built-in block font, centered.
+    ///
+    /// Each line becomes three rows of half-block glyphs, three cells wide
+    /// with a one-cell gap, so sizing grows to match. The font covers A–Z
+    /// (lowercase uses the same glyphs), 0–9 and `! . , : - ' ?`; other
+    /// characters render as `?`. Span styles are dropped; line styles are
+    /// kept.
     ///
     /// # Arguments
     ///
-    /// * `style` - Overall block style
-    pub fn style(mut self, style: Style) -> Self {
-        self.notification.block_style = Some(style);
-        self
-    }
-
-    /// Sets border style.
+    /// * `enable` - Whether to use the block font
     ///
-    /// # Arguments
+    /// # Example
     ///
-    /// * `style` - Border style
-    pub fn border_style(mut self, style: Style) -> Self {
-        self.notification.border_style = Some(style);
+    /// ```no_run
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    ///
+    /// let notification = NotificationBuilder::new("DONE!")
+    ///     .big_text(true)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn big_text(mut self, enable: bool) -> Self {
+        self.notification.big_text = enable;
         self
     }
 
-    /// Sets title style.
+    /// Recomputes the content from a closure on every manager tick.
+    ///
+    /// `Notifications::tick` calls `producer` for each live notification that
+    /// has not finished and replaces the content when the result differs,
+    /// resizing the box on the next render. The content passed to `new` is
+    /// shown until the first tick. Clones of the notification share the
+    /// closure; each clone added to a manager calls it once per tick.
     ///
     /// # Arguments
     ///
-    /// * `style` - Title text style
-    pub fn title_style(mut self, style: Style) -> Self {
-        self.notification.title_style = Some(style);
+    /// * `producer` - Returns the current content
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::NotificationBuilder;
+    /// use std::time::Instant;
+    ///
+    /// let started = Instant::now();
+    /// let notification = NotificationBuilder::new("Uploading...")
+    ///     .live_content(Box::new(move || {
+    ///         format!("Uploading... {}s", started.elapsed().as_secs())
+    ///     }))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn live_content(mut self, producer: Box<dyn FnMut() -> String + Send>) -> Self {
+        self.notification.live_content = Some(LiveContent::new(producer));
         self
     }
 
-    /// Sets border type.
+    /// Sets the point the expand/collapse animation grows from.
+    ///
+    /// Defaults to `ExpandOrigin::Center`. Only affects
+    /// `Animation::ExpandCollapse`.
     ///
     /// # Arguments
     ///
-    /// * `border_type` - Border type (Single, Double, Thick, etc.)
-    pub fn border_type(mut self, border_type: BorderType) -> Self {
-        self.notification.border_type = Some(border_type);
+    /// * `origin` - The point that stays fixed while the notification resizes
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{
+    ///     Anchor, Animation, ExpandOrigin, NotificationBuilder,
+    /// };
+    ///
+    /// let notification = NotificationBuilder::new("Saved")
+    ///     .anchor(Anchor::BottomRight)
+    ///     .animation(Animation::ExpandCollapse)
+    ///     .expand_origin(ExpandOrigin::Anchor)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn expand_origin(mut self, origin: ExpandOrigin) -> Self {
+        self.notification.expand_origin = origin;
         self
     }
 
-    /// Sets custom entry position.
+    /// Sets the curve the expand/collapse size follows.
+    ///
+    /// Defaults to `Easing::EaseOutQuad`: the box grows quickly and settles
+    /// into its full size, and the collapse plays the curve backwards.
+    /// `Easing::Linear` resizes at a constant rate. Only affects
+    /// `Animation::ExpandCollapse`.
     ///
     /// # Arguments
     ///
-    /// * `position` - Custom position for notification entry
-    pub fn entry_position(mut self, position: Position) -> Self {
-        self.notification.custom_entry_position = Some(position);
+    /// * `easing` - The curve mapping animation progress to size
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Animation, Easing, NotificationBuilder};
+    ///
+    /// let notification = NotificationBuilder::new("Saved")
+    ///     .animation(Animation::ExpandCollapse)
+    ///     .expand_easing(Easing::EaseInOutQuad)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn expand_easing(mut self, easing: Easing) -> Self {
+        self.notification.expand_easing = easing;
         self
     }
 
-    /// Sets custom exit position.
+    /// Applies the "polished" preset: a sliding entrance with a fade,
+    /// unhurried timings, and a rounded border.
     ///
-    /// # Arguments
+    /// This is a starting point only; any setter called afterwards overrides
+    /// the value chosen here.
     ///
-    /// * `position` - Custom position for notification exit
-    pub fn exit_position(mut self, position: Position) -> Self {
-        self.notification.custom_exit_position = Some(position);
-        self
+    /// Sets:
+    /// * `animation` - `Animation::Slide`
+    /// * `fade` - `true`
+    /// * `timing` - 400ms entry, automatic dwell, 500ms exit
+    /// * `border_type` - `BorderType::Rounded`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{NotificationBuilder, SlideDirection};
+    ///
+    /// let notification = NotificationBuilder::new("Saved")
+    ///     .polished()
+    ///     .slide_direction(SlideDirection::FromTop)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn polished(self) -> Self {
+        self.animation(Animation::Slide)
+            .fade(true)
+            .timing(
+                Timing::Fixed(core::time::Duration::from_millis(400)),
+                Timing::Auto,
+                Timing::Fixed(core::time::Duration::from_millis(500)),
+            )
+            .border_type(BorderType::Rounded)
     }
 
-    /// Enables or disables fade effect.
+    /// Rejects content that cannot fit inside `max_size` instead of clipping it.
+    ///
+    /// When enabled, `build()` wraps the content at the maximum width and fails
+    /// with `ContentTooLarge(required_rows, allowed_rows)` if the result is
+    /// taller than the maximum height. Only `SizeConstraint::Absolute` limits
+    /// can be checked; percentage limits depend on the frame and are skipped.
     ///
     /// # Arguments
     ///
-    /// * `enable` - Whether to apply fade effect during animation
-    pub fn fade(mut self, enable: bool) -> Self {
-        self.notification.fade_effect = enable;
+    /// * `enable` - Whether to validate fit at build time
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{NotificationBuilder, SizeConstraint};
+    ///
+    /// let result = NotificationBuilder::new("Far too much text for a tiny box")
+    ///     .max_size(SizeConstraint::Absolute(10), SizeConstraint::Absolute(3))
+    ///     .strict_fit(true)
+    ///     .build();
+    /// assert!(result.is_err());
+    /// ```
+    pub fn strict_fit(mut self, enable: bool) -> Self {
+        self.strict_fit = enable;
         self
     }
 
@@ -474,11 +1804,50 @@ impl NotificationBuilder {
     ///
     /// * `Ok(Notification)` if validation passes
     /// * `Err(NotificationError::ContentTooLarge)` if content exceeds limit
+    /// * `Err(NotificationError::MissingTemplateField)` if a strict template
+    ///   has an unmatched placeholder
     ///
     /// # Errors
     ///
-    /// Returns error if content exceeds `MAX_CONTENT_CHARS` (1000) characters.
-    pub fn build(self) -> Result<Notification, NotificationError> {
+    /// Returns error if content or any page exceeds `MAX_CONTENT_CHARS` (1000)
+    /// characters, if `strict_fit` is enabled and the content does not fit
+    /// `max_size`, or if `strict_template` is enabled and a template field is
+    /// missing, if `speed` or the interval of a paged notification is not
+    /// greater than zero, or if `exit_reverse` is combined with an exit
+    /// position.
+    pub fn build(mut self) -> Result<Notification, NotificationError> {
+        if !(self.notification.speed > 0.0 && self.notification.speed.is_finite()) {
+            return Err(NotificationError::InvalidConfig(format!(
+                "speed must be a finite number greater than 0, got {}",
+                self.notification.speed
+            )));
+        }
+
+        if !(self.notification.decay >= 0.0 && self.notification.decay.is_finite()) {
+            return Err(NotificationError::InvalidConfig(format!(
+                "decay must be a finite number of at least 0, got {}",
+                self.notification.decay
+            )));
+        }
+
+        if self.notification.exit_reverse && self.notification.custom_exit_position.is_some() {
+            return Err(NotificationError::InvalidConfig(
+                "exit_reverse conflicts with an exit position".to_string(),
+            ));
+        }
+
+        if self.notification.pages.len() > 1 && self.notification.page_interval.is_zero() {
+            return Err(NotificationError::InvalidConfig(
+                "page interval must be greater than 0".to_string(),
+            ));
+        }
+
+        // Fill in templated content
+        if let Some((template, fields)) = &self.template {
+            let content = fill_template(template, fields, self.strict_template)?;
+            self.notification.content = Arc::new(Text::from(content));
+        }
+
         // Validate content size
         let content_str = self.notification.content.to_string();
         let char_count = content_str.chars().count();
@@ -489,9 +1858,56 @@ impl NotificationBuilder {
                 MAX_CONTENT_CHARS,
             ));
         }
+        for page in &self.notification.pages {
+            let page_chars = page.to_string().chars().count();
+            if page_chars > MAX_CONTENT_CHARS {
+                return Err(NotificationError::ContentTooLarge(page_chars, MAX_CONTENT_CHARS));
+            }
+        }
+
+        if self.strict_fit {
+            check_strict_fit(&self.notification)?;
+        }
 
         Ok(self.notification)
     }
+
+    /// Builds the notification and lints its configuration.
+    ///
+    /// Validates exactly like [`build`](Self::build), then reports settings
+    /// that are legal but probably unintended, such as a slide that crosses
+    /// the whole screen or `fade(true)` on `Animation::Fade`. `build` runs
+    /// the same validation and ignores the lints.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Notification, warnings))` if validation passes; `warnings` is
+    ///   empty for a clean configuration
+    /// * `Err(NotificationError)` in the same cases as `build`
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`build`](Self::build).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Animation, BuildWarningKind, NotificationBuilder};
+    ///
+    /// let (notification, warnings) = NotificationBuilder::new("Saved")
+    ///     .animation(Animation::Fade)
+    ///     .fade(true)
+    ///     .build_with_warnings()
+    ///     .unwrap();
+    /// assert_eq!(warnings[0].kind, BuildWarningKind::RedundantFade);
+    /// ```
+    pub fn build_with_warnings(
+        self,
+    ) -> Result<(Notification, Vec<BuildWarning>), NotificationError> {
+        let notification = self.build()?;
+        let warnings = lint_notification(&notification);
+        Ok((notification, warnings))
+    }
 }
 
 #[cfg(test)]
@@ -510,7 +1926,7 @@ mod tests {
         assert_eq!(notification.auto_dismiss, AutoDismiss::After(Duration::from_secs(4)));
         assert_eq!(notification.level, Some(Level::Info));
         assert_eq!(notification.title, None);
-        assert_eq!(notification.fade_effect, false);
+        assert!(!notification.fade_effect);
         assert_eq!(notification.exterior_margin, 0);
         assert_eq!(notification.max_width, Some(SizeConstraint::Percentage(0.4)));
         assert_eq!(notification.max_height, Some(SizeConstraint::Percentage(0.2)));
@@ -695,7 +2111,7 @@ mod tests {
             .build()
             .unwrap();
 
-        assert_eq!(notification.custom_entry_position, Some(pos));
+        assert_eq!(notification.custom_entry_position(), Some(pos));
     }
 
     #[test]
@@ -707,7 +2123,7 @@ mod tests {
             .build()
             .unwrap();
 
-        assert_eq!(notification.custom_exit_position, Some(pos));
+        assert_eq!(notification.custom_exit_position(), Some(pos));
     }
 
     #[test]
@@ -717,7 +2133,7 @@ mod tests {
             .build()
             .unwrap();
 
-        assert_eq!(notification.fade_effect, true);
+        assert!(notification.fade_effect);
     }
 
     #[test]
@@ -756,7 +2172,7 @@ mod tests {
 
         // Verify all fields
         assert_eq!(notification.content.to_string(), "Full config test");
-        assert_eq!(notification.title.unwrap().to_string(), "Test Title");
+        assert_eq!(notification.title.as_ref().unwrap().to_string(), "Test Title");
         assert_eq!(notification.level, Some(Level::Warn));
         assert_eq!(notification.anchor, Anchor::TopCenter);
         assert_eq!(notification.animation, Animation::ExpandCollapse);
@@ -770,9 +2186,9 @@ mod tests {
         assert_eq!(notification.border_style, Some(border_style));
         assert_eq!(notification.title_style, Some(title_style));
         assert_eq!(notification.border_type, Some(BorderType::Thick));
-        assert_eq!(notification.custom_entry_position, Some(entry_pos));
-        assert_eq!(notification.custom_exit_position, Some(exit_pos));
-        assert_eq!(notification.fade_effect, true);
+        assert_eq!(notification.custom_entry_position(), Some(entry_pos));
+        assert_eq!(notification.custom_exit_position(), Some(exit_pos));
+        assert!(notification.fade_effect);
     }
 
     #[test]
@@ -812,6 +2228,79 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_polished_sets_slide_with_fade() {
+        let notification = NotificationBuilder::new("Polished").polished().build().unwrap();
+
+        assert!(notification.fade_effect());
+        assert_eq!(notification.animation(), Animation::Slide);
+        assert_eq!(notification.border_type(), Some(BorderType::Rounded));
+        assert_eq!(
+            notification.slide_in_timing(),
+            Timing::Fixed(Duration::from_millis(400))
+        );
+    }
+
+    #[test]
+    fn test_setters_after_polished_override_preset() {
+        let notification = NotificationBuilder::new("Polished")
+            .polished()
+            .fade(false)
+            .animation(Animation::ExpandCollapse)
+            .border_type(BorderType::Double)
+            .build()
+            .unwrap();
+
+        assert!(!notification.fade_effect());
+        assert_eq!(notification.animation(), Animation::ExpandCollapse);
+        assert_eq!(notification.border_type(), Some(BorderType::Double));
+    }
+
+    #[test]
+    fn test_strict_fit_rejects_content_exceeding_max_size() {
+        let result = NotificationBuilder::new("This sentence is far too long for the box")
+            .max_size(SizeConstraint::Absolute(12), SizeConstraint::Absolute(4))
+            .strict_fit(true)
+            .build();
+
+        match result {
+            Err(NotificationError::ContentTooLarge(required, allowed)) => {
+                assert!(required > allowed);
+                assert_eq!(allowed, 4);
+            }
+            _ => panic!("Expected ContentTooLarge error"),
+        }
+    }
+
+    #[test]
+    fn test_strict_fit_disabled_clips_silently() {
+        let result = NotificationBuilder::new("This sentence is far too long for the box")
+            .max_size(SizeConstraint::Absolute(12), SizeConstraint::Absolute(4))
+            .build();
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_strict_fit_accepts_content_that_fits() {
+        let result = NotificationBuilder::new("Short")
+            .max_size(SizeConstraint::Absolute(20), SizeConstraint::Absolute(3))
+            .strict_fit(true)
+            .build();
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_strict_fit_skips_percentage_constraints() {
+        let result = NotificationBuilder::new("a ".repeat(200))
+            .max_size(SizeConstraint::Percentage(0.1), SizeConstraint::Percentage(0.1))
+            .strict_fit(true)
+            .build();
+
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn test_notification_implements_debug() {
         let notification = NotificationBuilder::new("Test")
@@ -874,7 +2363,40 @@ mod tests {
 
         assert_eq!(notification.content.to_string(), "");
     }
+
+    #[test]
+    fn test_template_substitutes_fields() {
+        let notification = NotificationBuilder::template(
+            "{user} logged in at {time}",
+            &[("user", "alice"), ("time", "14:30")],
+        )
+        .build()
+        .unwrap();
+
+        assert_eq!(notification.content.to_string(), "alice logged in at 14:30");
+    }
+
+    #[test]
+    fn test_template_missing_field_left_literal_by_default() {
+        let notification = NotificationBuilder::template("Hi {user}, {{ok}}", &[])
+            .build()
+            .unwrap();
+
+        assert_eq!(notification.content.to_string(), "Hi {user}, {ok}");
+    }
+
+    #[test]
+    fn test_strict_template_missing_field_errors() {
+        let result = NotificationBuilder::template("Hi {user} from {host}", &[("user", "bob")])
+            .strict_template(true)
+            .build();
+
+        assert_eq!(
+            result.unwrap_err(),
+            NotificationError::MissingTemplateField("host".to_string())
+        );
+    }
 }
 
 // FILE: src/notifications/classes/cls_notification.rs - Notification class with builder
-// END OF VERSION: 2.2.0
+// END OF VERSION: 2.39.0
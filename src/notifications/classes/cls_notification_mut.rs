@@ -0,0 +1,87 @@
+// FILE: src/notifications/classes/cls_notification_mut.rs - Restricted mutable handle to a managed notification
+// VERSION: 1.3.0
+// WCTX: Predicate pruning and bulk mutation
+// CLOG: retain and for_each_mut dismiss as DismissReason::Retained
+
+use super::cls_notification_state::NotificationState;
+use crate::notifications::types::{DismissReason, Level, NotificationInfo, Timestamp};
+use ratatui::text::Text;
+use std::time::Duration;
+
+/// Mutable handle to a managed notification, passed to
+/// `Notifications::for_each_mut`.
+///
+/// Exposes only changes that keep the manager consistent: changes that
+/// affect the box size make the next render re-measure it, and stacking
+/// and animation state stay under the manager's control.
+#[derive(Debug)]
+pub struct NotificationMut<'a> {
+    state: &'a mut NotificationState,
+    now: Timestamp,
+}
+
+impl<'a> NotificationMut<'a> {
+    /// Creates a handle for one state.
+    ///
+    /// # Arguments
+    /// * `state` - The state to mutate
+    /// * `now` - Current time from the manager's clock
+    pub(crate) fn new(state: &'a mut NotificationState, now: Timestamp) -> Self {
+        Self { state, now }
+    }
+
+    /// Returns the notification's ID.
+    pub fn id(&self) -> u64 {
+        self.state.id
+    }
+
+    /// Returns a read-only snapshot of the notification.
+    pub fn info(&self) -> NotificationInfo {
+        self.state.info(self.now)
+    }
+
+    /// Adds time to the display countdown.
+    ///
+    /// Has no effect on notifications that do not count down
+    /// (`AutoDismiss::Never` or `AtDeadline`) or that are already exiting.
+    ///
+    /// # Arguments
+    /// * `extra` - Time to add
+    pub fn extend_display_time(&mut self, extra: Duration) {
+        if let Some(remaining) = &mut self.state.remaining_display_time {
+            *remaining = remaining.saturating_add(extra);
+        }
+    }
+
+    /// Changes the notification's level.
+    ///
+    /// Level styling and theme title prefixes follow on the next render.
+    ///
+    /// # Arguments
+    /// * `level` - The new level
+    pub fn set_level(&mut self, level: Level) {
+        self.state.notification.level = Some(level);
+        self.state.invalidate_size();
+    }
+
+    /// Replaces the notification's content, ending any placeholder.
+    ///
+    /// The box is resized to fit on the next render.
+    ///
+    /// # Arguments
+    /// * `content` - The new content
+    pub fn set_content(&mut self, content: impl Into<Text<'static>>) {
+        self.state.set_content(content.into());
+    }
+
+    /// Dismisses the notification with its exit animation.
+    ///
+    /// A notification that is still entering reverses from where it is. The
+    /// dismissal is reported as `DismissReason::Retained`.
+    pub fn dismiss(&mut self) {
+        self.state.dismiss(DismissReason::Retained);
+    }
+}
+
+// FILE: src/notifications/classes/cls_notification_mut.rs - Restricted mutable handle to a managed notification
+// END OF VERSION: 1.3.0
@@ -1,23 +1,54 @@
 // FILE: src/notifications/classes/cls_notification_state.rs - NotificationState internal state management
-// VERSION: 1.2.0
-// WCTX: Fixing custom entry/exit position handling
-// CLOG: Copy custom_entry_position and custom_exit_position from Notification to NotificationState; Added tests
+// VERSION: 1.54.0
+// WCTX: Absolute TTL
+// CLOG: Added DismissReason to dismissals, snapshots, history and the notifier
 
 use super::cls_notification::Notification;
-use crate::notifications::types::{AnimationPhase, Timing, AutoDismiss};
+use super::cls_notification_state_builder::NotificationStateBuilder;
+use super::cls_saved_notifications::SavedNotification;
+use crate::notifications::functions::fnc_level_severity::level_severity;
+use crate::notifications::functions::fnc_move_calculate_rect::move_calculate_rect;
+use crate::notifications::traits::default_clock;
+use crate::shared_utils::math::clamp_progress;
+use crate::notifications::functions::fnc_slide_resolve_direction::{resolve_slide_direction, resolve_slide_direction_on_axis};
+use crate::notifications::types::{
+    Anchor, AnimationLayer, AnimationPhase, Timing, AutoDismiss, CornerSlideAxis, DismissCondition, DismissReason,
+    LayerTransform, NotificationInfo, PathPoint, PlaceholderStyle, SizeConstraint, SlideDirection, StateUpdate, Timestamp,
+};
 use ratatui::prelude::*;
-use std::time::{Duration, Instant};
+use std::borrow::Cow;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 
-/// Manager-level defaults for notification timing.
+/// Manager-level defaults for notification timing and placement.
 ///
 /// Provides fallback durations when notifications use `Timing::Auto`
-/// or `AutoDismiss::After(Duration::ZERO)`.
-#[derive(Debug, Clone, Copy)]
-pub(crate) struct ManagerDefaults {
+/// or `AutoDismiss::After(Duration::ZERO)`, and the anchor for
+/// notifications that do not set one.
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui_notifications::notifications::ManagerDefaults;
+/// use std::time::Duration;
+///
+/// let defaults = ManagerDefaults {
+///     default_display_time: Duration::from_secs(8),
+///     ..ManagerDefaults::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ManagerDefaults {
+    /// Entry animation duration for `Timing::Auto` (default 500ms)
     pub default_entry_duration: Duration,
+    /// Dwell duration for `Timing::Auto` (default 3s)
     pub default_dwell_duration: Duration,
+    /// Exit animation duration for `Timing::Auto` (default 750ms)
     pub default_exit_duration: Duration,
+    /// Display time for `AutoDismiss::After(Duration::ZERO)` (default 4s)
     pub default_display_time: Duration,
+    /// Anchor for notifications built without `anchor()` (default BottomRight)
+    pub default_anchor: Anchor,
 }
 
 impl Default for ManagerDefaults {
@@ -27,24 +58,42 @@ impl Default for ManagerDefaults {
             default_dwell_duration: Duration::from_secs(3),
             default_exit_duration: Duration::from_millis(750),
             default_display_time: Duration::from_secs(4),
+            default_anchor: Anchor::default(),
         }
     }
 }
 
-/// Internal state for a single notification (pub(crate)).
+/// Runtime state for a single notification.
+///
+/// Tracks animation progress, timing, and lifecycle for a notification.
+/// The `Notifications` manager owns one per notification; custom managers can
+/// create and advance states directly with [`update`](Self::update).
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui_notifications::notifications::{
+///     AnimationPhase, ManagerDefaults, NotificationBuilder, NotificationState,
+/// };
+/// use std::time::Duration;
+///
+/// let notification = NotificationBuilder::new("Saved").build().unwrap();
+/// let mut state = NotificationState::new(1, notification, &ManagerDefaults::default());
 ///
-/// Tracks animation progress, timing, and lifecycle for a notification
-/// being managed by the AnimatedNotificationManager.
+/// let update = state.update(Duration::from_millis(16));
+/// assert!(update.phase_changed);
+/// assert_eq!(state.phase(), AnimationPhase::SlidingIn);
+/// ```
 #[derive(Debug)]
-pub(crate) struct NotificationState {
+pub struct NotificationState {
     /// Unique identifier for this notification
     pub(crate) id: u64,
 
     /// The original notification configuration
     pub(crate) notification: Notification,
 
-    /// When this notification was created
-    pub(crate) created_at: Instant,
+    /// When this notification was created (read from the manager's clock)
+    pub(crate) created_at: Timestamp,
 
     /// Current animation phase
     pub(crate) current_phase: AnimationPhase,
@@ -52,6 +101,10 @@ pub(crate) struct NotificationState {
     /// Progress through current animation (0.0 to 1.0)
     pub(crate) animation_progress: f32,
 
+    /// Time spent in the current entry/exit animation; progress derives
+    /// from it so phases end on the exact tick
+    pub(crate) phase_elapsed: Duration,
+
     /// Target position/size (updated by render)
     pub(crate) full_rect: Rect,
 
@@ -62,29 +115,134 @@ pub(crate) struct NotificationState {
     pub(crate) actual_entry_duration: Duration,
 
     /// Resolved dwell duration
+    #[allow(dead_code)] // Dwell countdown runs on remaining_display_time; kept for introspection
     pub(crate) actual_dwell_duration: Duration,
 
     /// Resolved exit animation duration
     pub(crate) actual_exit_duration: Duration,
 
     /// Custom entry position override (for slide animations)
-    pub(crate) custom_entry_pos: Option<(f32, f32)>,
+    pub(crate) custom_entry_pos: Option<PathPoint>,
 
     /// Custom exit position override (for slide animations)
-    pub(crate) custom_exit_pos: Option<(f32, f32)>,
+    pub(crate) custom_exit_pos: Option<PathPoint>,
+
+    /// Display time resolved at creation, restored when a duplicate merges in
+    pub(crate) initial_display_time: Option<Duration>,
+
+    /// Number of identical notifications merged into this one
+    pub(crate) multiplicity: u32,
+
+    /// Count suffix appended to the content once duplicates have merged
+    pub(crate) merge_suffix: Option<String>,
+
+    /// Note appended to the content of a notification released late by
+    /// `Notifications::suppress`
+    pub(crate) delayed_suffix: Option<String>,
+
+    /// Rect an in-progress anchor move started from
+    pub(crate) move_from: Option<Rect>,
+
+    /// Progress through the anchor move (0.0 to 1.0)
+    pub(crate) move_progress: f32,
+
+    /// Duration of the anchor move
+    pub(crate) move_duration: Duration,
+
+    /// Whether the notification waits in `Pending` until shown
+    pub(crate) hidden: bool,
+
+    /// Whether time is halted for this notification
+    pub(crate) frozen: bool,
+
+    /// Manager-wide speed multiplier, applied on top of the notification's
+    pub(crate) global_speed: f32,
+
+    /// Axis a corner anchor slides along when the direction is `Default`
+    pub(crate) corner_slide_axis: CornerSlideAxis,
+
+    /// Index of the page currently shown
+    pub(crate) page: usize,
+
+    /// Time the current page has been shown
+    pub(crate) page_elapsed: Duration,
+
+    /// Time left in `Pending` before the entry animation starts
+    pub(crate) remaining_delay: Duration,
+
+    /// Display-clock time spent dwelling, which `decay` lowers the priority by
+    pub(crate) dwell_elapsed: Duration,
+
+    /// Time since `created_at` as of the last tick
+    pub(crate) elapsed: Duration,
+
+    /// Predicate that dismisses the notification once it returns true
+    pub(crate) dismiss_condition: Option<DismissCondition>,
+
+    /// Why the notification started leaving, once it has
+    pub(crate) dismiss_reason: Option<DismissReason>,
+
+    /// Whether the placeholder is shown because no content has arrived yet
+    pub(crate) awaiting_content: bool,
+
+    /// Content size and the frame area it was measured for, reused by
+    /// stacking until the content, level or merge count changes
+    pub(crate) measured_size: Option<(Rect, (u16, u16))>,
+
+    /// Point the exit collapses into instead of the animation's own exit
+    pub(crate) exit_toward: Option<Position>,
+
+    /// Whether content follows lines from push_line, showing the newest
+    pub(crate) tail_content: bool,
+
+    /// Frame area of the last layout, used to cap the pushed line buffer
+    pub(crate) frame_area: Rect,
+
+    /// Slide direction the entry came from, for `exit_reverse`
+    pub(crate) entered_from: Option<SlideDirection>,
+
+    /// Plain-text title for snapshots, built on first use
+    plain_title: OnceLock<Option<Arc<str>>>,
+
+    /// Plain-text content for snapshots, built on first use and reset when
+    /// the content changes
+    plain_content: OnceLock<Arc<str>>,
 }
 
 impl NotificationState {
-    /// Creates a new notification state.
+    /// Creates a new notification state, timestamped now.
     ///
     /// Resolves all timing durations based on the notification's configuration
-    /// and the manager's defaults.
+    /// and the manager's defaults. The creation time is read from the default
+    /// clock (`SystemClock`, or `PerformanceClock` on wasm32).
+    ///
+    /// # Arguments
+    /// * `id` - Unique identifier for this notification
+    /// * `notification` - The notification configuration
+    /// * `defaults` - Manager-level default durations
+    pub fn new(id: u64, notification: Notification, defaults: &ManagerDefaults) -> Self {
+        Self::new_at(id, notification, defaults, default_clock().now())
+    }
+
+    /// Creates a new notification state with an explicit creation timestamp.
+    ///
+    /// The manager uses this with the timestamp read from its
+    /// [`Clock`](crate::notifications::traits::Clock), so no code below the
+    /// manager layer reads the current time itself.
     ///
     /// # Arguments
     /// * `id` - Unique identifier for this notification
     /// * `notification` - The notification configuration
     /// * `defaults` - Manager-level default durations
-    pub(crate) fn new(id: u64, notification: Notification, defaults: &ManagerDefaults) -> Self {
+    /// * `created_at` - Creation timestamp used for stacking order
+    pub fn new_at(
+        id: u64,
+        mut notification: Notification,
+        defaults: &ManagerDefaults,
+        created_at: Timestamp,
+    ) -> Self {
+        notification.resolve_anchor(defaults.default_anchor);
+
         // Resolve actual durations from Timing enum
         let actual_entry_duration = match notification.slide_in_timing {
             Timing::Fixed(d) => d,
@@ -103,25 +261,37 @@ impl NotificationState {
 
         // Resolve remaining display time from AutoDismiss
         let remaining_display_time = match notification.auto_dismiss {
-            AutoDismiss::Never => None,
+            AutoDismiss::Never | AutoDismiss::AtDeadline(_) => None,
             AutoDismiss::After(d) if d > Duration::ZERO => Some(d),
             AutoDismiss::After(_) => Some(defaults.default_display_time),
         };
 
-        // Copy custom positions from notification (convert Position to (f32, f32))
-        let custom_entry_pos = notification
-            .custom_entry_position
-            .map(|p| (p.x as f32, p.y as f32));
-        let custom_exit_pos = notification
-            .custom_exit_position
-            .map(|p| (p.x as f32, p.y as f32));
+        // Paged notifications start at the first page; a cycle can replace
+        // the display time
+        let page_count = notification.pages.len();
+        if let Some(first) = notification.pages.first() {
+            notification.content = Arc::new(first.clone());
+        }
+        let remaining_display_time = if page_count > 1 && notification.dismiss_after_cycle {
+            let pages = u32::try_from(page_count).unwrap_or(u32::MAX);
+            Some(notification.page_interval.saturating_mul(pages))
+        } else {
+            remaining_display_time
+        };
+
+        // Copy custom positions from notification; they resolve per frame
+        let custom_entry_pos = notification.custom_entry_position;
+        let custom_exit_pos = notification.custom_exit_position;
+        let remaining_delay = notification.delay;
+        let awaiting_content = notification.placeholder.is_some();
 
         Self {
             id,
             notification,
-            created_at: Instant::now(),
+            created_at,
             current_phase: AnimationPhase::Pending,
             animation_progress: 0.0,
+            phase_elapsed: Duration::ZERO,
             full_rect: Rect::default(),
             remaining_display_time,
             actual_entry_duration,
@@ -129,56 +299,377 @@ impl NotificationState {
             actual_exit_duration,
             custom_entry_pos,
             custom_exit_pos,
+            initial_display_time: remaining_display_time,
+            multiplicity: 1,
+            merge_suffix: None,
+            delayed_suffix: None,
+            move_from: None,
+            move_progress: 0.0,
+            move_duration: Duration::ZERO,
+            hidden: false,
+            frozen: false,
+            global_speed: 1.0,
+            corner_slide_axis: CornerSlideAxis::default(),
+            page: 0,
+            page_elapsed: Duration::ZERO,
+            remaining_delay,
+            dwell_elapsed: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            dismiss_condition: None,
+            dismiss_reason: None,
+            awaiting_content,
+            measured_size: None,
+            exit_toward: None,
+            tail_content: false,
+            frame_area: Rect::default(),
+            entered_from: None,
+            plain_title: OnceLock::new(),
+            plain_content: OnceLock::new(),
+        }
+    }
+
+    /// Starts building a state at an arbitrary phase and progress, for
+    /// `Notifications::adopt`.
+    ///
+    /// # Arguments
+    /// * `notification` - The notification configuration
+    pub fn builder(notification: Notification) -> NotificationStateBuilder {
+        NotificationStateBuilder::new(notification)
+    }
+
+    /// Returns the notification's ID.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the notification configuration.
+    pub fn notification(&self) -> &Notification {
+        &self.notification
+    }
+
+    /// Returns when the notification was created.
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+
+    /// Returns the current animation phase.
+    pub fn phase(&self) -> AnimationPhase {
+        self.current_phase
+    }
+
+    /// Returns progress through the current entry/exit animation (0.0 to 1.0).
+    pub fn progress(&self) -> f32 {
+        self.animation_progress
+    }
+
+    /// Returns the remaining display time, or `None` if the notification does
+    /// not count down.
+    pub fn remaining_display_time(&self) -> Option<Duration> {
+        self.remaining_display_time
+    }
+
+    /// Returns the inner rect where content was drawn on the last render.
+    ///
+    /// Derived from the full (settled) rect minus the border, the padding and
+    /// the title separator row when one is shown.
+    ///
+    /// # Returns
+    /// * `Some(Rect)` - The content rect from the last render
+    /// * `None` - If the notification has not been rendered yet
+    pub fn content_rect(&self) -> Option<Rect> {
+        if self.full_rect.is_empty() {
+            return None;
         }
+        let mut padding = self.notification.padding;
+        if self.notification.shows_title_separator() {
+            padding.top += 1;
+        }
+        let block = ratatui::widgets::Block::bordered().padding(padding);
+        Some(block.inner(self.full_rect))
+    }
+
+    /// Returns how many identical notifications this one represents.
+    ///
+    /// Starts at 1 and grows as `Overflow::MergeDuplicates` merges duplicates.
+    pub fn multiplicity(&self) -> u32 {
+        self.multiplicity
+    }
+
+    /// Returns whether this notification can absorb a duplicate.
+    ///
+    /// Exiting and finished notifications cannot, so a repeat of a message
+    /// that is already leaving shows up as a fresh notification.
+    pub(crate) fn accepts_merge(&self) -> bool {
+        !self.hidden
+            && matches!(
+                self.current_phase,
+                AnimationPhase::Pending
+                    | AnimationPhase::SlidingIn
+                    | AnimationPhase::FadingIn
+                    | AnimationPhase::Expanding
+                    | AnimationPhase::Dwelling
+            )
+    }
+
+    /// Absorbs a duplicate notification.
+    ///
+    /// Increments the multiplicity, stores the count suffix to display and
+    /// restarts the display countdown and any priority decay.
+    ///
+    /// # Arguments
+    /// * `suffix` - Formatted count suffix, e.g. "(x3)"
+    pub(crate) fn merge_duplicate(&mut self, suffix: String) {
+        self.multiplicity = self.multiplicity.saturating_add(1);
+        self.merge_suffix = Some(suffix);
+        self.remaining_display_time = self.initial_display_time;
+        self.dwell_elapsed = Duration::ZERO;
+        self.measured_size = None;
+    }
+
+    /// Notes how long the notification was held back before being shown.
+    ///
+    /// # Arguments
+    /// * `suffix` - Formatted note, e.g. "(delayed 12s)"
+    pub(crate) fn set_delayed_suffix(&mut self, suffix: String) {
+        self.delayed_suffix = Some(suffix);
+        self.measured_size = None;
     }
 
-    /// Updates the notification state based on elapsed time.
+    /// Returns the priority `Overflow::DiscardLowestPriority` compares.
     ///
-    /// Advances animation phases and progress based on timing configuration.
-    /// Entry/exit animations use progress-based timing, while dwelling uses
-    /// remaining_display_time countdown.
+    /// The level's severity rank (`Error` highest), lowered by the
+    /// notification's `decay` rate for every second spent dwelling.
+    pub fn effective_priority(&self) -> f32 {
+        let severity = f32::from(level_severity(self.notification.level));
+        severity - self.notification.decay * self.dwell_elapsed.as_secs_f32()
+    }
+
+    /// Returns the content as displayed, including any merge count suffix
+    /// and elapsed time, or the placeholder while the content is awaited.
+    pub(crate) fn display_content(&self) -> Cow<'_, Text<'static>> {
+        if let Some(placeholder) = self.placeholder() {
+            return Cow::Owned(match placeholder {
+                // Drawn by the renderer to fill the content area
+                PlaceholderStyle::Skeleton => Text::default(),
+                PlaceholderStyle::Spinner => {
+                    let frame = self.elapsed.as_millis() / SPINNER_FRAME_MS;
+                    Text::raw(SPINNER_FRAMES[frame as usize % SPINNER_FRAMES.len()])
+                }
+                PlaceholderStyle::Text(text) => Text::raw(text.clone()),
+            });
+        }
+        if self.merge_suffix.is_none() && self.delayed_suffix.is_none() && !self.notification.show_elapsed {
+            return Cow::Borrowed(&self.notification.content);
+        }
+        let mut content = Text::clone(&self.notification.content);
+        let elapsed = self.notification.show_elapsed.then(|| format_elapsed(self.elapsed));
+        let suffixes = self.merge_suffix.iter().chain(&self.delayed_suffix).cloned();
+        for suffix in suffixes.chain(elapsed) {
+            let suffix_span = Span::raw(format!(" {}", suffix));
+            match content.lines.last_mut() {
+                Some(line) => line.spans.push(suffix_span),
+                None => content.lines.push(Line::from(suffix_span)),
+            }
+        }
+        Cow::Owned(content)
+    }
+
+    /// Returns the placeholder shown while the content is awaited.
+    pub(crate) fn placeholder(&self) -> Option<&PlaceholderStyle> {
+        self.notification.placeholder.as_ref().filter(|_| self.awaiting_content)
+    }
+
+    /// Replaces the content, ending any placeholder.
+    ///
+    /// # Arguments
+    /// * `content` - The new content
+    pub(crate) fn set_content(&mut self, content: Text<'static>) {
+        self.notification.content = Arc::new(content);
+        self.awaiting_content = false;
+        self.invalidate_size();
+    }
+
+    /// Appends a line to the content and switches it to following the
+    /// newest lines.
+    ///
+    /// Lines beyond what the content area can show at its maximum height
+    /// are dropped, oldest first.
+    ///
+    /// # Arguments
+    /// * `line` - The line to append
+    pub(crate) fn push_line(&mut self, line: Line<'static>) {
+        let capacity = self.line_capacity();
+        let content = Arc::make_mut(&mut self.notification.content);
+        // A tail started from empty content should not keep its blank line
+        if !self.tail_content && content.width() == 0 {
+            content.lines.clear();
+        }
+        content.lines.push(line);
+        if let Some(capacity) = capacity {
+            let excess = content.lines.len().saturating_sub(capacity);
+            content.lines.drain(..excess);
+        }
+        self.tail_content = true;
+        self.awaiting_content = false;
+        self.invalidate_size();
+    }
+
+    /// Content rows available at the maximum height, if known yet.
+    ///
+    /// A percentage height needs the frame area, known from the first render.
+    fn line_capacity(&self) -> Option<usize> {
+        let max_height = match self.notification.max_height {
+            Some(SizeConstraint::Absolute(height)) => height,
+            Some(constraint) if !self.frame_area.is_empty() => constraint.resolve(self.frame_area.height),
+            None if !self.frame_area.is_empty() => self.frame_area.height,
+            _ => return None,
+        };
+        let border = if self.notification.border_type.is_some() { 2 } else { 0 };
+        let padding = self.notification.padding;
+        let chrome = border
+            + padding.top
+            + padding.bottom
+            + u16::from(self.notification.shows_title_separator());
+        Some(usize::from(max_height.saturating_sub(chrome).max(1)))
+    }
+
+    /// Records the time since the notification was added, for `show_elapsed`
+    /// and the spinner placeholder.
+    ///
+    /// # Arguments
+    /// * `now` - Current time from the manager's clock
+    pub(crate) fn track_elapsed(&mut self, now: Timestamp) {
+        self.elapsed = now.saturating_duration_since(self.created_at);
+    }
+
+    /// Moves the notification to a different anchor.
+    ///
+    /// The notification eases from where it is currently drawn to its
+    /// resting rect at the new anchor over `duration`. Before the first
+    /// render, or with a zero duration, it jumps straight there.
+    ///
+    /// # Arguments
+    /// * `anchor` - The new anchor
+    /// * `duration` - How long the move takes
+    pub(crate) fn begin_move(&mut self, anchor: Anchor, duration: Duration) {
+        // Start from the in-flight position when re-targeting a move; every
+        // interpolated rect lies within the union, so it never clips
+        let from = match self.move_from {
+            Some(from) => {
+                let bounds = from.union(self.full_rect);
+                move_calculate_rect(from, self.full_rect, bounds, self.move_progress)
+            }
+            None => self.full_rect,
+        };
+
+        self.notification.anchor = anchor;
+        if from.is_empty() || duration.is_zero() {
+            self.move_from = None;
+            return;
+        }
+        self.move_from = Some(from);
+        self.move_progress = 0.0;
+        self.move_duration = duration;
+    }
+
+    /// Advances the notification by the elapsed time.
+    ///
+    /// Performs the complete per-state transition: starts the entry animation
+    /// from `Pending` once any delay has passed, advances entry/exit progress, moves to `Dwelling` when
+    /// the entry completes, counts down the display time and starts the exit
+    /// animation matching the notification's `Animation` when it expires, and
+    /// reaches `Finished` when the exit completes. Animations advance by
+    /// `delta` times the speed multiplier. A frozen notification does not
+    /// change.
+    ///
+    /// Time accumulates as `Duration`, so phases end on the exact tick
+    /// however small the ticks are. When the entry ends partway through
+    /// `delta`, only the remainder counts against the display time.
     ///
     /// # Arguments
     /// * `delta` - Time elapsed since last update
-    pub(crate) fn update(&mut self, delta: Duration) {
-        use crate::notifications::types::Animation;
+    ///
+    /// # Returns
+    /// Whether the phase changed and whether the notification finished
+    pub fn update(&mut self, delta: Duration) -> StateUpdate {
+        let phase_before = self.current_phase;
+
+        // Frozen notifications hold their phase, progress and timers
+        if self.frozen {
+            return StateUpdate::default();
+        }
+
+        // Animations run at the speed multiplier; dwelling only if asked to
+        let speed = self.notification.speed * self.global_speed;
+        let mut dwell_delta = if self.notification.speed_affects_dwell {
+            scale_delta(delta, speed)
+        } else {
+            delta
+        };
+        let mut delta = scale_delta(delta, speed);
+
+        // Advance an anchor move independently of the animation phase
+        if self.move_from.is_some() {
+            let step = delta.as_secs_f64() / self.move_duration.as_secs_f64();
+            self.move_progress = clamp_progress((f64::from(self.move_progress) + step) as f32);
+            if self.move_progress >= 1.0 {
+                self.move_from = None;
+            }
+        }
+
+        // Hidden notifications wait in Pending until shown
+        if self.hidden && self.current_phase == AnimationPhase::Pending {
+            return StateUpdate::default();
+        }
+
+        // Delayed notifications wait in Pending, on the display clock; only
+        // the time past the delay advances the entry
+        if self.current_phase == AnimationPhase::Pending && !self.remaining_delay.is_zero() {
+            if dwell_delta < self.remaining_delay {
+                self.remaining_delay -= dwell_delta;
+                return StateUpdate::default();
+            }
+            let past_delay = dwell_delta - self.remaining_delay;
+            delta = mul_duration(delta, past_delay.as_secs_f64() / dwell_delta.as_secs_f64());
+            dwell_delta = past_delay;
+            self.remaining_delay = Duration::ZERO;
+        }
 
         // Start animation if still pending
         if self.current_phase == AnimationPhase::Pending {
-            self.current_phase = match self.notification.animation {
-                Animation::Slide => AnimationPhase::SlidingIn,
-                Animation::ExpandCollapse => AnimationPhase::Expanding,
-                Animation::Fade => AnimationPhase::FadingIn,
-            };
+            self.set_phase(AnimationPhase::entry(self.notification.animation));
             self.animation_progress = 0.0;
+            self.entered_from = Some(resolve_slide_direction(self.slide_direction(), self.notification.anchor));
         }
 
         // Update animation progress for entry/exit phases (NOT dwelling)
-        let phase_duration = match self.current_phase {
-            AnimationPhase::SlidingIn | AnimationPhase::FadingIn | AnimationPhase::Expanding => {
-                self.actual_entry_duration
-            }
-            AnimationPhase::SlidingOut | AnimationPhase::FadingOut | AnimationPhase::Collapsing => {
-                self.actual_exit_duration
-            }
-            // Dwelling phase uses remaining_display_time, not animation_progress
-            _ => Duration::ZERO,
-        };
+        let phase_duration = self.phase_duration();
 
+        // Display time left for dwelling once any animation in this update ends
+        let mut dwell_left = dwell_delta;
         let mut progress_updated = false;
-        if phase_duration > Duration::ZERO
-            && matches!(
-                self.current_phase,
-                AnimationPhase::SlidingIn
-                    | AnimationPhase::FadingIn
-                    | AnimationPhase::Expanding
-                    | AnimationPhase::SlidingOut
-                    | AnimationPhase::FadingOut
-                    | AnimationPhase::Collapsing
-            )
-        {
-            let delta_progress = delta.as_secs_f32() / phase_duration.as_secs_f32();
-            self.animation_progress = (self.animation_progress + delta_progress).min(1.0);
+        if matches!(
+            self.current_phase,
+            AnimationPhase::SlidingIn
+                | AnimationPhase::FadingIn
+                | AnimationPhase::Expanding
+                | AnimationPhase::SlidingOut
+                | AnimationPhase::FadingOut
+                | AnimationPhase::Collapsing
+        ) {
+            // Accumulate whole Durations rather than f32 progress, so rounding
+            // never ends a phase a tick early or late. A zero-length animation
+            // completes on the first update.
+            self.phase_elapsed = self.phase_elapsed.saturating_add(delta);
+            if self.phase_elapsed >= phase_duration {
+                let overshoot = self.phase_elapsed - phase_duration;
+                dwell_left = display_share(overshoot, delta, dwell_delta);
+                self.animation_progress = 1.0;
+            } else {
+                dwell_left = Duration::ZERO;
+                self.animation_progress =
+                    (self.phase_elapsed.as_secs_f64() / phase_duration.as_secs_f64()) as f32;
+            }
             progress_updated = true;
         }
 
@@ -187,32 +678,410 @@ impl NotificationState {
             match self.current_phase {
                 // Entry animation complete → Dwelling
                 AnimationPhase::SlidingIn | AnimationPhase::Expanding | AnimationPhase::FadingIn => {
-                    self.current_phase = AnimationPhase::Dwelling;
-                    self.animation_progress = 0.0;
+                    self.set_phase(AnimationPhase::Dwelling);
+                    self.set_progress(0.0);
                 }
                 // Exit animation complete → Finished
                 AnimationPhase::SlidingOut | AnimationPhase::Collapsing | AnimationPhase::FadingOut => {
-                    self.current_phase = AnimationPhase::Finished;
+                    self.set_phase(AnimationPhase::Finished);
                 }
                 _ => {}
             }
         }
 
-        // Handle dwelling phase timer (separate from animation progress)
+        // Handle dwelling phase timer (separate from animation progress); only
+        // the time after an entry that ended in this update counts
         if self.current_phase == AnimationPhase::Dwelling {
+            self.dwell_elapsed = self.dwell_elapsed.saturating_add(dwell_left);
             if let Some(remaining) = self.remaining_display_time.as_mut() {
-                *remaining = remaining.saturating_sub(delta);
+                *remaining = remaining.saturating_sub(dwell_left);
                 if remaining.is_zero() {
                     // Timer expired, transition to exit animation
-                    self.current_phase = match self.notification.animation {
-                        Animation::Slide => AnimationPhase::SlidingOut,
-                        Animation::ExpandCollapse => AnimationPhase::Collapsing,
-                        Animation::Fade => AnimationPhase::FadingOut,
-                    };
-                    self.animation_progress = 0.0;
+                    self.begin_exit(DismissReason::Timeout);
                 }
             }
             // If remaining_display_time is None, notification stays dwelling indefinitely
+
+            // Pages cycle only while dwelling, on the display clock
+            if self.current_phase == AnimationPhase::Dwelling {
+                self.advance_pages(dwell_left);
+            }
+        }
+
+        debug_assert!(
+            self.animation_progress.is_finite() && self.move_progress.is_finite(),
+            "non-finite progress for notification {}",
+            self.id
+        );
+        StateUpdate {
+            phase_changed: self.current_phase != phase_before,
+            finished: self.current_phase == AnimationPhase::Finished,
+        }
+    }
+
+    /// Advances the page timer, flipping to later pages as intervals pass.
+    fn advance_pages(&mut self, delta: Duration) {
+        let count = self.notification.pages.len();
+        let interval = self.notification.page_interval.as_nanos();
+        if count < 2 || interval == 0 {
+            return;
+        }
+        let elapsed = self.page_elapsed.as_nanos() + delta.as_nanos();
+        let flips = elapsed / interval;
+        self.page_elapsed = Duration::from_nanos((elapsed % interval) as u64);
+        if flips > 0 {
+            self.show_page((self.page + (flips % count as u128) as usize) % count);
+        }
+    }
+
+    /// Shows the page at `index`.
+    fn show_page(&mut self, index: usize) {
+        if let Some(page) = self.notification.pages.get(index) {
+            self.notification.content = Arc::new(page.clone());
+            self.plain_content.take();
+            self.page = index;
+        }
+    }
+
+    /// Flips to the next page, wrapping around, and restarts its interval.
+    ///
+    /// # Returns
+    /// `false` if the notification has fewer than two pages
+    pub(crate) fn next_page(&mut self) -> bool {
+        let count = self.notification.pages.len();
+        if count < 2 {
+            return false;
+        }
+        self.show_page((self.page + 1) % count);
+        self.page_elapsed = Duration::ZERO;
+        true
+    }
+
+    /// Starts the exit animation if the notification is dwelling.
+    ///
+    /// Does nothing in any other phase, so entry animations always complete
+    /// and exits are never restarted.
+    ///
+    /// # Arguments
+    /// * `reason` - Why the notification is leaving
+    pub(crate) fn begin_exit(&mut self, reason: DismissReason) {
+        if self.current_phase != AnimationPhase::Dwelling {
+            return;
+        }
+        self.dismiss_reason.get_or_insert(reason);
+        self.set_phase(AnimationPhase::exit(self.notification.animation));
+        self.set_progress(0.0);
+    }
+
+    /// Dismisses the notification with its exit animation, from any phase.
+    ///
+    /// A pending notification finishes without being shown. One that is
+    /// still entering reverses from its current position instead of
+    /// completing the entry first. Exiting and finished notifications are
+    /// left alone, keeping the reason they started leaving with.
+    ///
+    /// # Arguments
+    /// * `reason` - Why the notification is leaving
+    pub(crate) fn dismiss(&mut self, reason: DismissReason) {
+        let entry_progress = self.animation_progress.clamp(0.0, 1.0);
+        match self.current_phase {
+            AnimationPhase::Pending => {
+                self.dismiss_reason.get_or_insert(reason);
+                self.set_phase(AnimationPhase::Finished);
+            }
+            AnimationPhase::SlidingIn | AnimationPhase::FadingIn | AnimationPhase::Expanding => {
+                self.dismiss_reason.get_or_insert(reason);
+                self.set_phase(AnimationPhase::exit(self.notification.animation));
+                self.set_progress(1.0 - entry_progress);
+            }
+            AnimationPhase::Dwelling => self.begin_exit(reason),
+            _ => {}
+        }
+    }
+
+    /// Dismisses the notification, collapsing it into `target` while fading.
+    ///
+    /// Overrides the exit of the notification's own animation for this
+    /// dismissal only. Follows the same rules as `dismiss`: a pending
+    /// notification finishes unseen, and exiting or finished ones are left
+    /// alone.
+    ///
+    /// # Arguments
+    /// * `target` - Screen cell to collapse into
+    pub(crate) fn dismiss_toward(&mut self, target: Position) {
+        let showing = matches!(
+            self.current_phase,
+            AnimationPhase::SlidingIn
+                | AnimationPhase::FadingIn
+                | AnimationPhase::Expanding
+                | AnimationPhase::Dwelling
+        );
+        if showing {
+            self.exit_toward = Some(target);
+        }
+        self.dismiss(DismissReason::Manual);
+    }
+
+    /// The collapse target while exiting toward a point.
+    fn exit_target(&self) -> Option<Position> {
+        let exiting = self.current_phase == AnimationPhase::exit(self.notification.animation);
+        self.exit_toward.filter(|_| exiting)
+    }
+
+    /// Whether `layer` is one of the composed animation layers.
+    fn has_layer(&self, layer: AnimationLayer) -> bool {
+        self.exit_target().is_none() && self.notification.animation_layers.contains(&layer)
+    }
+
+    /// Length of the current entry or exit animation; zero in other phases.
+    fn phase_duration(&self) -> Duration {
+        match self.current_phase {
+            AnimationPhase::SlidingIn | AnimationPhase::FadingIn | AnimationPhase::Expanding => {
+                self.actual_entry_duration
+            }
+            AnimationPhase::SlidingOut | AnimationPhase::FadingOut | AnimationPhase::Collapsing => {
+                self.actual_exit_duration
+            }
+            // Dwelling phase uses remaining_display_time, not animation_progress
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// Jumps to `progress` through the current phase's animation.
+    pub(crate) fn set_progress(&mut self, progress: f32) {
+        debug_assert!(progress.is_finite(), "non-finite progress {progress}");
+        self.animation_progress = clamp_progress(progress);
+        self.phase_elapsed = mul_duration(self.phase_duration(), f64::from(self.animation_progress));
+    }
+
+    /// Moves to another phase, checking the edge against the transition table.
+    ///
+    /// Every phase change goes through here. An illegal edge panics in debug
+    /// builds and is logged as a warning in release builds, where the phase
+    /// still changes.
+    fn set_phase(&mut self, next: AnimationPhase) {
+        let animation = self.notification.animation;
+        if !self.current_phase.can_transition_to(next, animation) {
+            let message = format!(
+                "illegal phase transition {:?} -> {:?} for {:?} notification {}",
+                self.current_phase, next, animation, self.id
+            );
+            if cfg!(debug_assertions) {
+                panic!("{message}");
+            }
+            log::warn!("{message}");
+        }
+        self.current_phase = next;
+    }
+
+    /// Forgets the recorded layout after a change that affects the size.
+    ///
+    /// Stacking always re-measures the content; this only stops
+    /// `content_rect` reporting the stale rect until the next render.
+    pub(crate) fn invalidate_size(&mut self) {
+        self.full_rect = Rect::default();
+        self.measured_size = None;
+        self.plain_content.take();
+    }
+
+    /// Measures the displayed content with `calculate_size`.
+    fn measure_content(&self, frame_area: Rect) -> (u16, u16) {
+        use crate::notifications::functions::fnc_calculate_size::calculate_size;
+
+        #[cfg(test)]
+        tests::MEASUREMENTS.with(|count| count.set(count.get() + 1));
+
+        if self.merge_suffix.is_some()
+            || self.delayed_suffix.is_some()
+            || self.notification.show_elapsed
+            || self.awaiting_content
+        {
+            let mut displayed = self.notification.clone();
+            displayed.content = Arc::new(self.display_content().into_owned());
+            calculate_size(&displayed, frame_area)
+        } else {
+            calculate_size(&self.notification, frame_area)
+        }
+    }
+
+    /// Polls the live content producer and swaps in the result if it changed.
+    ///
+    /// Does nothing for finished notifications or ones without live content.
+    pub(crate) fn refresh_live_content(&mut self) {
+        if self.current_phase == AnimationPhase::Finished {
+            return;
+        }
+        let Some(content) = self.notification.live_content.as_ref().and_then(|live| live.poll()) else {
+            return;
+        };
+        let content = Text::from(content);
+        if content != *self.notification.content {
+            self.set_content(content);
+        }
+    }
+
+    /// Returns a read-only snapshot of the notification.
+    ///
+    /// # Arguments
+    /// * `now` - Current time from the manager's clock, used for the age
+    pub(crate) fn info(&self, now: Timestamp) -> NotificationInfo {
+        let title = self.plain_title.get_or_init(|| {
+            self.notification.title.as_ref().map(|title| plain_line(title).into())
+        });
+        let content = self.plain_content.get_or_init(|| {
+            let lines: Vec<String> = self.notification.content.lines.iter().map(plain_line).collect();
+            lines.join("\n").into()
+        });
+        NotificationInfo {
+            id: self.id,
+            title: title.clone(),
+            content: Arc::clone(content),
+            level: self.notification.level,
+            anchor: self.notification.anchor,
+            phase: self.current_phase,
+            progress: self.animation_progress,
+            age: now.saturating_duration_since(self.created_at),
+            remaining: self.remaining_display_time,
+            rect: (!self.full_rect.is_empty()).then_some(self.full_rect),
+            dismiss_reason: self.dismiss_reason,
+        }
+    }
+
+    /// Returns the notification's configuration and timers for saving.
+    ///
+    /// Timers that depend on the clock are stored as time left, since the
+    /// restored notification runs on a new clock.
+    ///
+    /// # Arguments
+    /// * `now` - Current time from the manager's clock
+    ///
+    /// # Returns
+    /// `None` once the notification is exiting or finished.
+    pub(crate) fn saved(&self, now: Timestamp) -> Option<SavedNotification> {
+        if !matches!(
+            self.current_phase,
+            AnimationPhase::Pending
+                | AnimationPhase::SlidingIn
+                | AnimationPhase::FadingIn
+                | AnimationPhase::Expanding
+                | AnimationPhase::Dwelling
+        ) {
+            return None;
+        }
+
+        let mut notification = self.notification.clone();
+        let mut remaining_display_time = self.remaining_display_time;
+        if let AutoDismiss::AtDeadline(deadline) = notification.auto_dismiss {
+            // After(ZERO) means the default display time, so keep at least 1ms
+            let left = deadline.saturating_duration_since(now).max(Duration::from_millis(1));
+            notification.auto_dismiss = AutoDismiss::After(left);
+            remaining_display_time = Some(left);
+        }
+        if let Some(ttl) = notification.ttl {
+            notification.ttl = Some(ttl.saturating_sub(now.saturating_duration_since(self.created_at)));
+        }
+
+        Some(SavedNotification {
+            notification,
+            remaining_display_time,
+            hidden: self.hidden,
+        })
+    }
+
+    /// Returns the time left until the exit animation starts.
+    ///
+    /// # Returns
+    /// * `Some(Duration::ZERO)` - If the notification is already exiting or finished
+    /// * `Some(remaining)` - Remaining entry animation plus display time
+    /// * `None` - If the notification does not count down (e.g. `AutoDismiss::Never`)
+    pub(crate) fn time_until_exit(&self) -> Option<Duration> {
+        let remaining_display = self.remaining_display_time?;
+        match self.current_phase {
+            AnimationPhase::Pending => {
+                Some(
+                    self.remaining_delay
+                        .saturating_add(self.actual_entry_duration)
+                        .saturating_add(remaining_display),
+                )
+            }
+            AnimationPhase::SlidingIn | AnimationPhase::FadingIn | AnimationPhase::Expanding => {
+                let entry_left = self.actual_entry_duration.saturating_sub(self.phase_elapsed);
+                Some(entry_left.saturating_add(remaining_display))
+            }
+            AnimationPhase::Dwelling => Some(remaining_display),
+            _ => Some(Duration::ZERO),
+        }
+    }
+
+    /// Starts the exit animation if an `AtDeadline` deadline or the TTL has
+    /// passed.
+    ///
+    /// An expired TTL dismisses from any phase: a pending notification
+    /// finishes without being shown and is reported as
+    /// [`DismissReason::Stale`], any other as [`DismissReason::Expired`].
+    /// Call before [`update`](Self::update) each tick when driving states
+    /// directly.
+    ///
+    /// # Arguments
+    /// * `now` - Current time from the manager's clock
+    pub fn check_deadline(&mut self, now: Timestamp) {
+        if let Some(ttl) = self.notification.ttl {
+            if now.saturating_duration_since(self.created_at) >= ttl {
+                let reason = if self.current_phase == AnimationPhase::Pending {
+                    DismissReason::Stale
+                } else {
+                    DismissReason::Expired
+                };
+                self.dismiss(reason);
+                return;
+            }
+        }
+        if let AutoDismiss::AtDeadline(deadline) = self.notification.auto_dismiss {
+            if now >= deadline {
+                self.begin_exit(DismissReason::Timeout);
+            }
+        }
+    }
+
+    /// Slide direction with the manager's corner slide axis applied.
+    ///
+    /// A reversed exit uses the side the entry came from.
+    fn slide_direction(&self) -> SlideDirection {
+        if let Some(entered_from) = self.entered_from.filter(|_| self.reverses_exit()) {
+            return entered_from;
+        }
+        resolve_slide_direction_on_axis(
+            self.notification.slide_direction,
+            self.notification.anchor,
+            self.corner_slide_axis,
+        )
+    }
+
+    /// Whether the notification is exiting back the way it came.
+    fn reverses_exit(&self) -> bool {
+        self.notification.exit_reverse
+            && self.current_phase == AnimationPhase::exit(self.notification.animation)
+    }
+
+    /// Custom point the exit ends at: the entry point when reversing.
+    fn exit_point(&self) -> Option<PathPoint> {
+        if self.notification.exit_reverse {
+            self.custom_entry_pos
+        } else {
+            self.custom_exit_pos
+        }
+    }
+
+    /// Dismisses the notification once its `dismiss_when` condition holds.
+    ///
+    /// The condition is dropped after it fires, so it is not called again.
+    pub(crate) fn check_dismiss_condition(&mut self) {
+        let Some(condition) = &mut self.dismiss_condition else {
+            return;
+        };
+        if condition.holds() {
+            self.dismiss_condition = None;
+            self.dismiss(DismissReason::Condition);
         }
     }
 }
@@ -227,7 +1096,7 @@ impl crate::notifications::orc_stacking::StackableNotification for NotificationS
         self.current_phase
     }
 
-    fn created_at(&self) -> Instant {
+    fn created_at(&self) -> Timestamp {
         self.created_at
     }
 
@@ -240,10 +1109,53 @@ impl crate::notifications::orc_stacking::StackableNotification for NotificationS
     }
 
     fn calculate_content_size(&self, frame_area: ratatui::prelude::Rect) -> (u16, u16) {
-        crate::notifications::functions::fnc_calculate_size::calculate_size(&self.notification, frame_area)
+        match self.measured_size {
+            Some((area, size)) if area == frame_area => size,
+            _ => self.measure_content(frame_area),
+        }
     }
 }
 
+// Braille frames of the spinner placeholder and how long each is shown
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const SPINNER_FRAME_MS: u128 = 100;
+
+/// Formats `show_elapsed` time as zero-padded minutes and seconds
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    format!("{:02}:{:02}", secs / 60, secs % 60)
+}
+
+/// Display-clock time matching `part` of the (speed-scaled) `delta`.
+fn display_share(part: Duration, delta: Duration, dwell_delta: Duration) -> Duration {
+    if delta == dwell_delta {
+        part
+    } else if delta.is_zero() {
+        Duration::ZERO
+    } else {
+        mul_duration(dwell_delta, part.as_secs_f64() / delta.as_secs_f64())
+    }
+}
+
+/// `duration * factor`, saturating at `Duration::MAX` instead of panicking
+/// the way `Duration::mul_f64` does when rounding overflows.
+fn mul_duration(duration: Duration, factor: f64) -> Duration {
+    Duration::try_from_secs_f64(duration.as_secs_f64() * factor).unwrap_or(Duration::MAX)
+}
+
+/// Scales elapsed time by a speed multiplier, saturating instead of overflowing
+fn scale_delta(delta: Duration, speed: f32) -> Duration {
+    if speed == 1.0 {
+        return delta;
+    }
+    mul_duration(delta, f64::from(speed))
+}
+
+/// Concatenates a line's spans into plain text.
+fn plain_line(line: &Line<'_>) -> String {
+    line.spans.iter().map(|span| span.content.as_ref()).collect()
+}
+
 // Implement RenderableNotification trait for render orchestrator
 impl crate::notifications::orc_render::RenderableNotification for NotificationState {
     fn level(&self) -> Option<crate::notifications::types::Level> {
@@ -254,8 +1166,14 @@ impl crate::notifications::orc_render::RenderableNotification for NotificationSt
         self.notification.title.clone()
     }
 
-    fn content(&self) -> ratatui::prelude::Text<'static> {
-        self.notification.content.clone()
+    fn content(&self) -> Cow<'_, Text<'static>> {
+        use crate::notifications::functions::fnc_big_text::big_text;
+
+        if self.notification.big_text {
+            Cow::Owned(big_text(&self.display_content()))
+        } else {
+            self.display_content()
+        }
     }
 
     fn border_type(&self) -> ratatui::widgets::BorderType {
@@ -263,7 +1181,66 @@ impl crate::notifications::orc_render::RenderableNotification for NotificationSt
     }
 
     fn fade_effect(&self) -> bool {
-        self.notification.fade_effect
+        self.notification.fade_effect || self.exit_target().is_some() || self.has_layer(AnimationLayer::Fade)
+    }
+
+    fn title_separator(&self) -> bool {
+        self.notification.shows_title_separator()
+    }
+
+    fn auto_contrast(&self) -> bool {
+        self.notification.auto_contrast
+    }
+
+    fn transparent(&self) -> bool {
+        self.notification.transparent
+    }
+
+    fn skeleton(&self) -> bool {
+        self.placeholder() == Some(&PlaceholderStyle::Skeleton)
+    }
+
+    fn tail_content(&self) -> bool {
+        self.tail_content
+    }
+
+    fn countdown(&self) -> Option<Duration> {
+        if !self.notification.show_countdown {
+            return None;
+        }
+        match self.current_phase {
+            AnimationPhase::Pending
+            | AnimationPhase::SlidingIn
+            | AnimationPhase::FadingIn
+            | AnimationPhase::Expanding
+            | AnimationPhase::Dwelling => self.remaining_display_time,
+            _ => None,
+        }
+    }
+
+    fn truncate_mode(&self) -> Option<crate::notifications::types::TruncateMode> {
+        self.notification.truncate_mode
+    }
+
+    fn page(&self) -> Option<(usize, usize)> {
+        let count = self.notification.pages.len();
+        (count > 1).then_some((self.page + 1, count))
+    }
+
+    fn ellipsis(&self) -> &str {
+        &self.notification.ellipsis
+    }
+
+    fn layer(&self) -> crate::notifications::types::Layer {
+        self.notification.layer
+    }
+
+    fn vertical_align(&self) -> crate::notifications::types::VerticalAlign {
+        self.notification.vertical_align
+    }
+
+    fn fade_scope(&self) -> crate::notifications::types::FadeScope {
+        self.notification.fade_scope
     }
 
     fn animation_type(&self) -> crate::notifications::types::Animation {
@@ -282,6 +1259,10 @@ impl crate::notifications::orc_render::RenderableNotification for NotificationSt
         self.notification.border_style
     }
 
+    fn exit_style(&self) -> Option<ratatui::prelude::Style> {
+        self.notification.exit_style
+    }
+
     fn title_style(&self) -> Option<ratatui::prelude::Style> {
         self.notification.title_style
     }
@@ -294,38 +1275,117 @@ impl crate::notifications::orc_render::RenderableNotification for NotificationSt
         self.full_rect = rect;
     }
 
+    fn cache_content_size(&mut self, frame_area: ratatui::prelude::Rect) {
+        // show_elapsed content changes every second, so it is never cached
+        self.frame_area = frame_area;
+        if self.notification.show_elapsed
+            || self.measured_size.is_some_and(|(area, _)| area == frame_area)
+        {
+            return;
+        }
+        self.measured_size = Some((frame_area, self.measure_content(frame_area)));
+    }
+
     fn calculate_animation_rect(&self, frame_area: ratatui::prelude::Rect) -> ratatui::prelude::Rect {
         use crate::notifications::types::Animation;
 
-        match self.notification.animation {
-            Animation::Slide => {
-                crate::notifications::functions::fnc_slide_calculate_rect::slide_calculate_rect(
-                    self.full_rect,
-                    frame_area,
-                    self.animation_progress,
-                    self.current_phase,
-                    self.notification.anchor,
-                    self.notification.slide_direction,
-                    self.custom_entry_pos,
-                    self.custom_exit_pos,
-                )
-            }
-            Animation::ExpandCollapse => {
-                crate::notifications::functions::fnc_expand_calculate_rect::calculate_rect(
-                    self.full_rect,
-                    frame_area,
-                    self.current_phase,
-                    self.animation_progress,
-                )
+        // A targeted dismissal replaces the animation's own exit
+        let rect = if let Some(target) = self.exit_target() {
+            crate::notifications::functions::fnc_expand_calculate_rect::calculate_rect_toward(
+                self.full_rect,
+                frame_area,
+                target,
+                self.animation_progress,
+            )
+        } else {
+            match self.notification.animation {
+                Animation::Slide => {
+                    crate::notifications::functions::fnc_slide_calculate_rect::slide_calculate_rect(
+                        self.full_rect,
+                        frame_area,
+                        self.animation_progress,
+                        self.current_phase,
+                        self.notification.anchor,
+                        self.slide_direction(),
+                        self.custom_entry_pos.map(|point| point.resolve(frame_area)),
+                        self.exit_point().map(|point| point.resolve(frame_area)),
+                    )
+                }
+                Animation::ExpandCollapse => {
+                    crate::notifications::functions::fnc_expand_calculate_rect::calculate_rect_eased(
+                        self.full_rect,
+                        frame_area,
+                        self.current_phase,
+                        self.animation_progress,
+                        self.notification.expand_origin,
+                        self.notification.anchor,
+                        self.notification.expand_easing,
+                    )
+                }
+                Animation::Fade => {
+                    crate::notifications::functions::fnc_fade_calculate_rect::calculate_rect(
+                        self.full_rect,
+                        frame_area,
+                        self.current_phase,
+                        self.animation_progress,
+                    )
+                }
+                // Box stays at full size; orc_render reveals the lines
+                Animation::RollDown => self.full_rect,
             }
-            Animation::Fade => {
-                crate::notifications::functions::fnc_fade_calculate_rect::calculate_rect(
-                    self.full_rect,
-                    frame_area,
-                    self.current_phase,
-                    self.animation_progress,
-                )
+        };
+
+        match self.move_from {
+            Some(from) => move_calculate_rect(from, rect, frame_area, self.move_progress),
+            None => rect,
+        }
+    }
+
+    fn animation_layers(&self) -> &[AnimationLayer] {
+        // A targeted dismissal replaces the layers like the animation's own exit
+        if self.exit_target().is_some() {
+            &[]
+        } else {
+            &self.notification.animation_layers
+        }
+    }
+
+    fn layer_transform(&self, layer: AnimationLayer, frame_area: ratatui::prelude::Rect) -> LayerTransform {
+        use crate::notifications::functions::fnc_slide_offscreen_position::slide_offscreen_position;
+
+        let animation = self.notification.animation;
+        // How far along the notification is toward fully shown
+        let shown = if self.current_phase == AnimationPhase::entry(animation) {
+            self.animation_progress
+        } else if self.current_phase == AnimationPhase::exit(animation) {
+            1.0 - self.animation_progress
+        } else {
+            return LayerTransform::IDENTITY;
+        };
+
+        match layer {
+            AnimationLayer::Slide => {
+                let entering = self.current_phase == AnimationPhase::entry(animation);
+                let custom = if entering { self.custom_entry_pos } else { self.exit_point() };
+                let (x, y) = custom.map(|point| point.resolve(frame_area)).unwrap_or_else(|| {
+                    let direction = resolve_slide_direction(self.slide_direction(), self.notification.anchor);
+                    slide_offscreen_position(self.notification.anchor, direction, self.full_rect, frame_area)
+                });
+                LayerTransform {
+                    offset_x: (x - self.full_rect.x as f32) * (1.0 - shown),
+                    offset_y: (y - self.full_rect.y as f32) * (1.0 - shown),
+                    ..LayerTransform::IDENTITY
+                }
             }
+            AnimationLayer::Scale => LayerTransform { scale: shown, ..LayerTransform::IDENTITY },
+            AnimationLayer::Fade => LayerTransform { opacity: shown, ..LayerTransform::IDENTITY },
+        }
+    }
+
+    fn settled_rect(&self, frame_area: ratatui::prelude::Rect) -> ratatui::prelude::Rect {
+        match self.move_from {
+            Some(from) => move_calculate_rect(from, self.full_rect, frame_area, self.move_progress),
+            None => self.full_rect,
         }
     }
 
@@ -338,16 +1398,16 @@ impl crate::notifications::orc_render::RenderableNotification for NotificationSt
         use crate::notifications::types::Animation;
 
         match self.notification.animation {
-            Animation::Slide => {
+            Animation::Slide if self.exit_target().is_none() && self.animation_layers().is_empty() => {
                 crate::notifications::functions::fnc_slide_apply_border_effect::slide_apply_border_effect(
                     block,
                     self.notification.anchor,
-                    self.notification.slide_direction,
+                    self.slide_direction(),
                     self.animation_progress,
                     self.current_phase,
                     self.full_rect,
-                    self.custom_entry_pos,
-                    self.custom_exit_pos,
+                    self.custom_entry_pos.map(|point| point.resolve(frame_area)),
+                    self.exit_point().map(|point| point.resolve(frame_area)),
                     frame_area,
                     base_set,
                 )
@@ -369,7 +1429,7 @@ impl crate::notifications::orc_render::RenderableNotification for NotificationSt
             Animation::Fade => {
                 FadeHandler.interpolate_frame_foreground(base_fg, phase, progress)
             }
-            _ if self.notification.fade_effect => {
+            _ if self.fade_effect() => {
                 FadeHandler.interpolate_frame_foreground(base_fg, phase, progress)
             }
             _ => base_fg,
@@ -389,7 +1449,7 @@ impl crate::notifications::orc_render::RenderableNotification for NotificationSt
             Animation::Fade => {
                 FadeHandler.interpolate_content_foreground(base_fg, phase, progress)
             }
-            _ if self.notification.fade_effect => {
+            _ if self.fade_effect() => {
                 FadeHandler.interpolate_content_foreground(base_fg, phase, progress)
             }
             _ => base_fg.or(Some(ratatui::prelude::Color::White)),
@@ -397,16 +1457,42 @@ impl crate::notifications::orc_render::RenderableNotification for NotificationSt
     }
 }
 
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::notifications::types::{Timing, AutoDismiss};
+    use crate::notifications::types::{Timing, AutoDismiss, Level};
+    use crate::notifications::{NotificationBuilder, Notifications, NotificationsWidget};
+    use ratatui::widgets::StatefulWidget;
+    use std::cell::Cell;
+    use std::time::Instant;
+
+    thread_local! {
+        /// Number of content measurements made on this test's thread
+        pub(super) static MEASUREMENTS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Renders the manager into a fresh buffer of `area`
+    fn render(manager: &mut Notifications, area: Rect) {
+        NotificationsWidget.render(area, &mut Buffer::empty(area), manager);
+    }
+
+    /// Manager holding one settled notification, with the measurement count reset
+    fn settled(builder: NotificationBuilder) -> (Notifications, u64) {
+        let mut manager = Notifications::new();
+        let id = manager.add(builder.build().unwrap()).unwrap();
+        manager.tick(Duration::from_secs(1));
+        MEASUREMENTS.with(|count| count.set(0));
+        (manager, id)
+    }
+
+    fn measurements() -> usize {
+        MEASUREMENTS.with(Cell::get)
+    }
 
     fn create_test_notification() -> Notification {
         // Use Default to create a simple test notification
         Notification {
-            content: Text::raw("Test notification"),
+            content: Arc::new(Text::raw("Test notification")),
             ..Default::default()
         }
     }
@@ -444,6 +1530,7 @@ mod tests {
             default_dwell_duration: Duration::from_secs(3),
             default_exit_duration: Duration::from_millis(800),
             default_display_time: Duration::from_secs(5),
+            default_anchor: Anchor::default(),
         };
         let mut notification = create_test_notification();
         notification.slide_in_timing = Timing::Auto;
@@ -479,6 +1566,7 @@ mod tests {
             default_dwell_duration: Duration::from_secs(3),
             default_exit_duration: Duration::from_millis(750),
             default_display_time: Duration::from_secs(7),
+            default_anchor: Anchor::default(),
         };
         let mut notification = create_test_notification();
         notification.auto_dismiss = AutoDismiss::After(Duration::ZERO);
@@ -535,13 +1623,13 @@ mod tests {
 
         let defaults = ManagerDefaults::default();
         let mut notification = create_test_notification();
-        notification.custom_entry_position = Some(Position::new(10, 20));
-        notification.custom_exit_position = Some(Position::new(100, 50));
+        notification.custom_entry_position = Some(PathPoint::Absolute(Position::new(10, 20)));
+        notification.custom_exit_position = Some(PathPoint::Absolute(Position::new(100, 50)));
 
         let state = NotificationState::new(1, notification, &defaults);
 
-        assert_eq!(state.custom_entry_pos, Some((10.0, 20.0)));
-        assert_eq!(state.custom_exit_pos, Some((100.0, 50.0)));
+        assert_eq!(state.custom_entry_pos, Some(PathPoint::Absolute(Position::new(10, 20))));
+        assert_eq!(state.custom_exit_pos, Some(PathPoint::Absolute(Position::new(100, 50))));
     }
 
     #[test]
@@ -558,7 +1646,95 @@ mod tests {
         assert_eq!(state.actual_dwell_duration, Duration::from_millis(200));
         assert_eq!(state.actual_exit_duration, Duration::from_millis(300));
     }
+
+    #[test]
+    fn test_time_until_exit_counts_entry_then_dwell() {
+        let defaults = ManagerDefaults::default();
+        let mut notification = create_test_notification();
+        notification.slide_in_timing = Timing::Fixed(Duration::from_millis(400));
+        notification.slide_out_timing = Timing::Fixed(Duration::from_millis(400));
+        notification.auto_dismiss = AutoDismiss::After(Duration::from_secs(2));
+
+        let mut state = NotificationState::new(1, notification, &defaults);
+        assert_eq!(state.time_until_exit(), Some(Duration::from_millis(2400)));
+
+        state.update(Duration::from_millis(200));
+        assert_eq!(state.time_until_exit().map(|d| d.as_millis()), Some(2200));
+
+        state.update(Duration::from_millis(200));
+        assert_eq!(state.current_phase, AnimationPhase::Dwelling);
+        assert!(state.time_until_exit() <= Some(Duration::from_millis(2000)));
+
+        state.update(Duration::from_secs(2));
+        assert_eq!(state.time_until_exit(), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_static_notification_is_measured_once() {
+        let (mut manager, _) = settled(NotificationBuilder::new("Saved").title("Editor"));
+        let area = Rect::new(0, 0, 80, 24);
+
+        for _ in 0..5 {
+            render(&mut manager, area);
+            manager.tick(Duration::from_millis(16));
+        }
+        assert_eq!(measurements(), 1);
+    }
+
+    #[test]
+    fn test_update_content_remeasures_once() {
+        let (mut manager, id) = settled(NotificationBuilder::new("Uploading"));
+        let area = Rect::new(0, 0, 80, 24);
+        render(&mut manager, area);
+
+        assert!(manager.update_content(id, "Uploaded 12 files to the archive"));
+        render(&mut manager, area);
+        render(&mut manager, area);
+        assert_eq!(measurements(), 2);
+    }
+
+    #[test]
+    fn test_new_frame_area_remeasures() {
+        let (mut manager, _) = settled(NotificationBuilder::new("Saved"));
+
+        render(&mut manager, Rect::new(0, 0, 80, 24));
+        render(&mut manager, Rect::new(0, 0, 40, 12));
+        render(&mut manager, Rect::new(0, 0, 40, 12));
+        assert_eq!(measurements(), 2);
+    }
+
+    #[test]
+    fn test_level_change_remeasures() {
+        let (mut manager, _) = settled(NotificationBuilder::new("Saved"));
+        let area = Rect::new(0, 0, 80, 24);
+        render(&mut manager, area);
+
+        manager.for_each_mut(|mut notification| notification.set_level(Level::Warn));
+        render(&mut manager, area);
+        assert_eq!(measurements(), 2);
+    }
+
+    #[test]
+    fn test_show_elapsed_is_never_cached() {
+        let (mut manager, _) = settled(NotificationBuilder::new("Build").show_elapsed(true));
+        let area = Rect::new(0, 0, 80, 24);
+
+        for _ in 0..3 {
+            render(&mut manager, area);
+        }
+        assert!(measurements() >= 3);
+    }
+
+    #[test]
+    fn test_time_until_exit_none_when_never_dismissed() {
+        let defaults = ManagerDefaults::default();
+        let mut notification = create_test_notification();
+        notification.auto_dismiss = AutoDismiss::Never;
+
+        let state = NotificationState::new(1, notification, &defaults);
+        assert_eq!(state.time_until_exit(), None);
+    }
 }
 
 // FILE: src/notifications/classes/cls_notification_state.rs - NotificationState internal state management
-// END OF VERSION: 1.2.0
+// END OF VERSION: 1.54.0
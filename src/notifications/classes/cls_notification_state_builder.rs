@@ -0,0 +1,168 @@
+// FILE: src/notifications/classes/cls_notification_state_builder.rs - Builder for states at an arbitrary point in their lifecycle
+// VERSION: 1.1.0
+// WCTX: Exact dwell timing
+// CLOG: Set progress through set_progress
+
+use super::cls_notification::Notification;
+use super::cls_notification_state::{ManagerDefaults, NotificationState};
+use crate::notifications::traits::default_clock;
+use crate::notifications::types::{AnimationPhase, NotificationError, Timestamp};
+use std::time::Duration;
+
+/// Builder for a [`NotificationState`] placed anywhere in its lifecycle.
+///
+/// For replay and embedding tools that reconstruct notifications mid-flight.
+/// Hand the result to `Notifications::adopt`, which assigns its ID; from
+/// then on it ticks and renders like any other notification.
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui_notifications::notifications::traits::{Clock, SystemClock};
+/// use ratatui_notifications::notifications::{
+///     AnimationPhase, NotificationBuilder, NotificationState, Notifications,
+/// };
+///
+/// let notification = NotificationBuilder::new("Deploy finished").build().unwrap();
+/// let state = NotificationState::builder(notification)
+///     .phase(AnimationPhase::SlidingOut)
+///     .progress(0.5)
+///     .created_at(SystemClock.now())
+///     .build()
+///     .unwrap();
+///
+/// let mut manager = Notifications::new();
+/// let id = manager.adopt(state).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct NotificationStateBuilder {
+    notification: Notification,
+    defaults: ManagerDefaults,
+    phase: AnimationPhase,
+    progress: f32,
+    remaining_display_time: Option<Option<Duration>>,
+    created_at: Option<Timestamp>,
+}
+
+impl NotificationStateBuilder {
+    /// Creates a builder for a pending state of `notification`.
+    ///
+    /// # Arguments
+    ///
+    /// * `notification` - The notification the state wraps
+    pub(crate) fn new(notification: Notification) -> Self {
+        Self {
+            notification,
+            defaults: ManagerDefaults::default(),
+            phase: AnimationPhase::Pending,
+            progress: 0.0,
+            remaining_display_time: None,
+            created_at: None,
+        }
+    }
+
+    /// Sets the defaults `Timing::Auto` and `AutoDismiss::After(ZERO)` resolve against.
+    ///
+    /// # Arguments
+    ///
+    /// * `defaults` - Manager-level default durations
+    pub fn defaults(mut self, defaults: ManagerDefaults) -> Self {
+        self.defaults = defaults;
+        self
+    }
+
+    /// Sets the phase the state starts in.
+    ///
+    /// Must be `Pending`, `Dwelling`, `Finished` or the entry or exit phase
+    /// of the notification's animation. Defaults to `Pending`.
+    ///
+    /// # Arguments
+    ///
+    /// * `phase` - Starting phase
+    pub fn phase(mut self, phase: AnimationPhase) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// Sets the progress through the starting phase's animation.
+    ///
+    /// # Arguments
+    ///
+    /// * `progress` - Progress from 0.0 to 1.0
+    pub fn progress(mut self, progress: f32) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Sets the display time left before auto-dismiss; `None` never counts down.
+    ///
+    /// Defaults to the full display time resolved from the notification's
+    /// `AutoDismiss`.
+    ///
+    /// # Arguments
+    ///
+    /// * `remaining` - Display time left
+    pub fn remaining_display_time(mut self, remaining: Option<Duration>) -> Self {
+        self.remaining_display_time = Some(remaining);
+        self
+    }
+
+    /// Sets the creation time, used for stacking order, `ttl` and `show_elapsed`.
+    ///
+    /// Read it from the same [`Clock`](crate::notifications::traits::Clock)
+    /// as the manager that adopts the state. Defaults to the default clock's
+    /// current time.
+    ///
+    /// # Arguments
+    ///
+    /// * `created_at` - Creation timestamp
+    pub fn created_at(mut self, created_at: Timestamp) -> Self {
+        self.created_at = Some(created_at);
+        self
+    }
+
+    /// Builds the state.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(NotificationState)` with ID 0 until adopted
+    /// * `Err(NotificationError::InvalidConfig)` if the phase is not one the
+    ///   notification's animation plays, or the progress is not within 0.0 to 1.0
+    pub fn build(self) -> Result<NotificationState, NotificationError> {
+        let animation = self.notification.animation;
+        let phase = self.phase;
+        let plays = matches!(
+            phase,
+            AnimationPhase::Pending | AnimationPhase::Dwelling | AnimationPhase::Finished
+        ) || phase == AnimationPhase::entry(animation)
+            || phase == AnimationPhase::exit(animation);
+        if !plays {
+            return Err(NotificationError::InvalidConfig(format!(
+                "{:?} is not a phase of the {:?} animation",
+                phase, animation
+            )));
+        }
+        if !(0.0..=1.0).contains(&self.progress) {
+            return Err(NotificationError::InvalidConfig(format!(
+                "progress must be between 0 and 1, got {}",
+                self.progress
+            )));
+        }
+
+        let created_at = self.created_at.unwrap_or_else(|| default_clock().now());
+        let mut state = NotificationState::new_at(0, self.notification, &self.defaults, created_at);
+        state.current_phase = phase;
+        state.set_progress(self.progress);
+        if let Some(remaining) = self.remaining_display_time {
+            state.remaining_display_time = remaining;
+        }
+        // A delay only applies before the entry starts
+        if phase != AnimationPhase::Pending {
+            state.remaining_delay = Duration::ZERO;
+        }
+        Ok(state)
+    }
+}
+
+// FILE: src/notifications/classes/cls_notification_state_builder.rs - Builder for states at an arbitrary point in their lifecycle
+// END OF VERSION: 1.1.0
@@ -0,0 +1,55 @@
+// FILE: src/notifications/classes/cls_saved_notifications.rs - Serializable snapshot of manager state
+// VERSION: 1.0.0
+// WCTX: Save and restore manager state
+// CLOG: Initial creation
+
+use super::cls_notification::Notification;
+use std::time::Duration;
+
+/// Snapshot of a manager's notifications for persisting across restarts.
+///
+/// Produced by `Notifications::save_state` and consumed by
+/// `Notifications::restore_state`. With the `serde` feature it implements
+/// `Serialize` and `Deserialize`.
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui_notifications::notifications::{AutoDismiss, NotificationBuilder, Notifications};
+///
+/// let mut manager = Notifications::new();
+/// let notif = NotificationBuilder::new("Unsaved changes")
+///     .auto_dismiss(AutoDismiss::Never)
+///     .build()
+///     .unwrap();
+/// manager.add(notif).unwrap();
+///
+/// let saved = manager.save_state();
+/// // ... persist `saved`, restart, load it back ...
+/// let mut restored = Notifications::new();
+/// restored.restore_state(saved);
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SavedNotifications {
+    /// Saved notifications, oldest first.
+    pub notifications: Vec<SavedNotification>,
+}
+
+/// One notification in a [`SavedNotifications`] snapshot.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SavedNotification {
+    /// The notification's configuration. A `ttl` holds the time it had left
+    /// and an `AtDeadline` dismissal is stored as `After` the time left.
+    pub notification: Notification,
+
+    /// Display time left before auto-dismiss; `None` dwells until dismissed.
+    pub remaining_display_time: Option<Duration>,
+
+    /// Whether the notification was added hidden and not yet shown.
+    pub hidden: bool,
+}
+
+// FILE: src/notifications/classes/cls_saved_notifications.rs - Serializable snapshot of manager state
+// END OF VERSION: 1.0.0
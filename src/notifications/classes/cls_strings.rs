@@ -0,0 +1,245 @@
+// FILE: src/notifications/classes/cls_strings.rs - Localizable built-in strings
+// VERSION: 1.6.0
+// WCTX: Suppressed notifications
+// CLOG: Added the delayed suffix
+
+use crate::notifications::functions::fnc_fill_template::fill_template;
+use crate::notifications::types::{Anchor, Level, StringArgs, StringKey};
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+/// Resolver used by [`Strings::from_fn`].
+type Resolver = Arc<dyn Fn(StringKey, &StringArgs<'_>) -> String + Send + Sync>;
+
+/// User-visible strings the crate injects, as templates.
+///
+/// Each field is a template whose `{name}` placeholders are filled from the
+/// string's arguments (see [`StringKey`] for which arguments each receives).
+/// `Strings::default()` is English. Replace individual fields for small
+/// tweaks, or use [`Strings::from_fn`] for full programmatic localization.
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui_notifications::notifications::{Notifications, Strings};
+///
+/// let mut strings = Strings::default();
+/// strings.level_error = "Fehler".into();
+/// strings.describe_heading = "{level}-Meldung, {anchor}:".into();
+///
+/// let manager = Notifications::new().strings(strings);
+/// ```
+#[derive(Clone)]
+pub struct Strings {
+    /// Name of `Level::Info` in sentences.
+    pub level_info: Cow<'static, str>,
+    /// Name of `Level::Warn` in sentences.
+    pub level_warn: Cow<'static, str>,
+    /// Name of `Level::Error` in sentences.
+    pub level_error: Cow<'static, str>,
+    /// Name of `Level::Debug` in sentences.
+    pub level_debug: Cow<'static, str>,
+    /// Name of `Level::Trace` in sentences.
+    pub level_trace: Cow<'static, str>,
+
+    /// Spoken name of `Anchor::TopLeft`.
+    pub anchor_top_left: Cow<'static, str>,
+    /// Spoken name of `Anchor::TopCenter`.
+    pub anchor_top_center: Cow<'static, str>,
+    /// Spoken name of `Anchor::TopRight`.
+    pub anchor_top_right: Cow<'static, str>,
+    /// Spoken name of `Anchor::MiddleLeft`.
+    pub anchor_middle_left: Cow<'static, str>,
+    /// Spoken name of `Anchor::MiddleCenter`.
+    pub anchor_middle_center: Cow<'static, str>,
+    /// Spoken name of `Anchor::MiddleRight`.
+    pub anchor_middle_right: Cow<'static, str>,
+    /// Spoken name of `Anchor::BottomLeft`.
+    pub anchor_bottom_left: Cow<'static, str>,
+    /// Spoken name of `Anchor::BottomCenter`.
+    pub anchor_bottom_center: Cow<'static, str>,
+    /// Spoken name of `Anchor::BottomRight`.
+    pub anchor_bottom_right: Cow<'static, str>,
+    /// Spoken name of `Anchor::Custom`.
+    pub anchor_custom: Cow<'static, str>,
+
+    /// `describe()` opening with a level. Args: `level`, `anchor`.
+    pub describe_heading: Cow<'static, str>,
+    /// `describe()` opening without a level. Args: `anchor`.
+    pub describe_heading_no_level: Cow<'static, str>,
+    /// Countdown when `n` is 1. Args: `n`.
+    pub dismisses_in_one: Cow<'static, str>,
+    /// Countdown for any other `n`. Args: `n`.
+    pub dismisses_in_other: Cow<'static, str>,
+    /// Sentence for a notification that is leaving.
+    pub dismissing: Cow<'static, str>,
+
+    /// Theme title prefix. Args: `prefix`.
+    pub title_prefix: Cow<'static, str>,
+
+    /// Count suffix for merged duplicates. Args: `n`.
+    pub merge_count: Cow<'static, str>,
+
+    /// Suffix on notifications released by `suppress(false)`. Args: `n`.
+    pub delayed: Cow<'static, str>,
+
+    /// Countdown appended to the title. Args: `n`.
+    pub countdown: Cow<'static, str>,
+
+    /// Page indicator in the bottom border. Args: `page`, `count`.
+    pub page_indicator: Cow<'static, str>,
+
+    /// Notification center age under a minute. Args: `n`.
+    pub age_seconds: Cow<'static, str>,
+    /// Notification center age under an hour. Args: `n`.
+    pub age_minutes: Cow<'static, str>,
+    /// Notification center age of an hour or more. Args: `n`.
+    pub age_hours: Cow<'static, str>,
+
+    resolver: Option<Resolver>,
+}
+
+impl Strings {
+    /// English strings.
+    pub const ENGLISH: Strings = Strings {
+        level_info: Cow::Borrowed("Info"),
+        level_warn: Cow::Borrowed("Warning"),
+        level_error: Cow::Borrowed("Error"),
+        level_debug: Cow::Borrowed("Debug"),
+        level_trace: Cow::Borrowed("Trace"),
+        anchor_top_left: Cow::Borrowed("top left"),
+        anchor_top_center: Cow::Borrowed("top center"),
+        anchor_top_right: Cow::Borrowed("top right"),
+        anchor_middle_left: Cow::Borrowed("middle left"),
+        anchor_middle_center: Cow::Borrowed("center"),
+        anchor_middle_right: Cow::Borrowed("middle right"),
+        anchor_bottom_left: Cow::Borrowed("bottom left"),
+        anchor_bottom_center: Cow::Borrowed("bottom center"),
+        anchor_bottom_right: Cow::Borrowed("bottom right"),
+        anchor_custom: Cow::Borrowed("custom position"),
+        describe_heading: Cow::Borrowed("{level} notification, {anchor}:"),
+        describe_heading_no_level: Cow::Borrowed("Notification, {anchor}:"),
+        dismisses_in_one: Cow::Borrowed("Dismisses in {n} second."),
+        dismisses_in_other: Cow::Borrowed("Dismisses in {n} seconds."),
+        dismissing: Cow::Borrowed("Dismissing."),
+        title_prefix: Cow::Borrowed("{prefix}"),
+        merge_count: Cow::Borrowed("(x{n})"),
+        delayed: Cow::Borrowed("(delayed {n}s)"),
+        countdown: Cow::Borrowed("{n}s"),
+        page_indicator: Cow::Borrowed("{page}/{count}"),
+        age_seconds: Cow::Borrowed("{n}s ago"),
+        age_minutes: Cow::Borrowed("{n}m ago"),
+        age_hours: Cow::Borrowed("{n}h ago"),
+        resolver: None,
+    };
+
+    /// Creates strings resolved entirely by a function.
+    ///
+    /// The function receives every key with its arguments and returns the
+    /// final text; template fields are ignored. Call
+    /// `Strings::default().format(key, args.pairs())` inside it to fall back
+    /// to English for keys you do not translate.
+    ///
+    /// # Arguments
+    ///
+    /// * `resolver` - Maps a key and its arguments to text
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{StringKey, Strings};
+    ///
+    /// let strings = Strings::from_fn(|key, args| match key {
+    ///     StringKey::Dismissing => "Wird geschlossen.".to_string(),
+    ///     other => Strings::default().format(other, args.pairs()),
+    /// });
+    /// ```
+    pub fn from_fn(
+        resolver: impl Fn(StringKey, &StringArgs<'_>) -> String + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            resolver: Some(Arc::new(resolver)),
+            ..Self::ENGLISH
+        }
+    }
+
+    /// Formats a string with its arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Which string to produce
+    /// * `args` - `(name, value)` pairs for the string's placeholders
+    ///
+    /// # Returns
+    ///
+    /// The resolved text; unmatched placeholders are left as written
+    pub fn format(&self, key: StringKey, args: &[(&str, &str)]) -> String {
+        if let Some(resolver) = &self.resolver {
+            return resolver(key, &StringArgs::new(args));
+        }
+
+        let template: &str = match key {
+            StringKey::Level(level) => match level {
+                Level::Info => &self.level_info,
+                Level::Warn => &self.level_warn,
+                Level::Error => &self.level_error,
+                Level::Debug => &self.level_debug,
+                Level::Trace => &self.level_trace,
+            },
+            StringKey::Anchor(anchor) => match anchor {
+                Anchor::TopLeft => &self.anchor_top_left,
+                Anchor::TopCenter => &self.anchor_top_center,
+                Anchor::TopRight => &self.anchor_top_right,
+                Anchor::MiddleLeft => &self.anchor_middle_left,
+                Anchor::MiddleCenter => &self.anchor_middle_center,
+                Anchor::MiddleRight => &self.anchor_middle_right,
+                Anchor::BottomLeft => &self.anchor_bottom_left,
+                Anchor::BottomCenter => &self.anchor_bottom_center,
+                Anchor::BottomRight => &self.anchor_bottom_right,
+                Anchor::Custom { .. } => &self.anchor_custom,
+            },
+            StringKey::DescribeHeading => &self.describe_heading,
+            StringKey::DescribeHeadingNoLevel => &self.describe_heading_no_level,
+            StringKey::DismissesIn => {
+                if StringArgs::new(args).get("n") == Some("1") {
+                    &self.dismisses_in_one
+                } else {
+                    &self.dismisses_in_other
+                }
+            }
+            StringKey::Dismissing => &self.dismissing,
+            StringKey::TitlePrefix(_) => &self.title_prefix,
+            StringKey::MergeCount => &self.merge_count,
+            StringKey::Delayed => &self.delayed,
+            StringKey::Countdown => &self.countdown,
+            StringKey::PageIndicator => &self.page_indicator,
+            StringKey::AgeSeconds => &self.age_seconds,
+            StringKey::AgeMinutes => &self.age_minutes,
+            StringKey::AgeHours => &self.age_hours,
+        };
+
+        // Lenient fill never errors
+        fill_template(template, args, false).unwrap_or_else(|_| template.to_string())
+    }
+}
+
+impl Default for Strings {
+    fn default() -> Self {
+        Self::ENGLISH
+    }
+}
+
+impl fmt::Debug for Strings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Strings")
+            .field("describe_heading", &self.describe_heading)
+            .field("dismisses_in_other", &self.dismisses_in_other)
+            .field("title_prefix", &self.title_prefix)
+            .field("resolver", &self.resolver.as_ref().map(|_| "Fn"))
+            .finish_non_exhaustive()
+    }
+}
+
+// FILE: src/notifications/classes/cls_strings.rs - Localizable built-in strings
+// END OF VERSION: 1.6.0
@@ -0,0 +1,215 @@
+// FILE: src/notifications/classes/cls_theme.rs - Level styling theme
+// VERSION: 1.2.0
+// WCTX: NO_COLOR support
+// CLOG: Added Theme::without_colors
+
+use crate::notifications::types::Level;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::BorderType;
+
+/// Styling applied to every notification of one level.
+///
+/// Per-notification overrides (`border_style`, `title_style`, ...) still take
+/// precedence over these values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelTheme {
+    /// Border style; also patched into the title.
+    pub border_style: Style,
+
+    /// Extra title style patched over the border style.
+    pub title_style: Style,
+
+    /// Border type that replaces the notification's own, if set.
+    pub border_type: Option<BorderType>,
+
+    /// Text shown at the start of the title (e.g. `"ERROR:"`), if set.
+    pub title_prefix: Option<&'static str>,
+}
+
+impl LevelTheme {
+    /// Creates a level theme that only sets the border color.
+    const fn colored(color: Color) -> Self {
+        Self {
+            border_style: Style::new().fg(color),
+            title_style: Style::new(),
+            border_type: None,
+            title_prefix: None,
+        }
+    }
+}
+
+/// Visual theme mapping each [`Level`] to its styling.
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui_notifications::notifications::{Notifications, Theme};
+///
+/// let manager = Notifications::new().theme(Theme::high_contrast());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Border style for notifications without a level.
+    pub default_border_style: Style,
+
+    /// Styling for `Level::Info`.
+    pub info: LevelTheme,
+
+    /// Styling for `Level::Warn`.
+    pub warn: LevelTheme,
+
+    /// Styling for `Level::Error`.
+    pub error: LevelTheme,
+
+    /// Styling for `Level::Debug`.
+    pub debug: LevelTheme,
+
+    /// Styling for `Level::Trace`.
+    pub trace: LevelTheme,
+}
+
+impl Theme {
+    /// The default theme: levels differ by border color only.
+    pub const DEFAULT: Theme = Theme {
+        default_border_style: Style::new().fg(Color::DarkGray),
+        info: LevelTheme::colored(Color::Green),
+        warn: LevelTheme::colored(Color::Yellow),
+        error: LevelTheme::colored(Color::Red),
+        debug: LevelTheme::colored(Color::Blue),
+        trace: LevelTheme::colored(Color::Magenta),
+    };
+
+    /// High-contrast theme for color-blind users and monochrome terminals.
+    ///
+    /// Every level is distinguishable without color: by border type, title
+    /// modifiers, and an ASCII prefix in the title.
+    ///
+    /// | Level | Border | Title | Prefix |
+    /// |-------|--------|-------|--------|
+    /// | Error | Double | Bold | `ERROR:` |
+    /// | Warn | Thick | Bold + Italic | `WARN:` |
+    /// | Info | Plain | — | `INFO:` |
+    /// | Debug | Rounded | Italic | `DEBUG:` |
+    /// | Trace | Plain | Dim | `TRACE:` |
+    pub fn high_contrast() -> Self {
+        Self {
+            default_border_style: Style::new().fg(Color::White),
+            info: LevelTheme {
+                border_style: Style::new().fg(Color::LightGreen),
+                title_style: Style::new(),
+                border_type: Some(BorderType::Plain),
+                title_prefix: Some("INFO:"),
+            },
+            warn: LevelTheme {
+                border_style: Style::new().fg(Color::LightYellow),
+                title_style: Style::new().add_modifier(Modifier::BOLD.union(Modifier::ITALIC)),
+                border_type: Some(BorderType::Thick),
+                title_prefix: Some("WARN:"),
+            },
+            error: LevelTheme {
+                border_style: Style::new().fg(Color::LightRed),
+                title_style: Style::new().add_modifier(Modifier::BOLD),
+                border_type: Some(BorderType::Double),
+                title_prefix: Some("ERROR:"),
+            },
+            debug: LevelTheme {
+                border_style: Style::new().fg(Color::LightCyan),
+                title_style: Style::new().add_modifier(Modifier::ITALIC),
+                border_type: Some(BorderType::Rounded),
+                title_prefix: Some("DEBUG:"),
+            },
+            trace: LevelTheme {
+                border_style: Style::new().fg(Color::Gray),
+                title_style: Style::new().add_modifier(Modifier::DIM),
+                border_type: Some(BorderType::Plain),
+                title_prefix: Some("TRACE:"),
+            },
+        }
+    }
+
+    /// Returns the styling for a level.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - The notification level
+    pub fn level(&self, level: Level) -> &LevelTheme {
+        match level {
+            Level::Info => &self.info,
+            Level::Warn => &self.warn,
+            Level::Error => &self.error,
+            Level::Debug => &self.debug,
+            Level::Trace => &self.trace,
+        }
+    }
+
+    /// Returns the styling for a level for modification.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - The notification level
+    pub fn level_mut(&mut self, level: Level) -> &mut LevelTheme {
+        match level {
+            Level::Info => &mut self.info,
+            Level::Warn => &mut self.warn,
+            Level::Error => &mut self.error,
+            Level::Debug => &mut self.debug,
+            Level::Trace => &mut self.trace,
+        }
+    }
+}
+
+impl LevelTheme {
+    /// Returns this level theme with fg/bg colors removed, keeping modifiers.
+    fn without_colors(self) -> Self {
+        Self {
+            border_style: strip_colors(self.border_style),
+            title_style: strip_colors(self.title_style),
+            ..self
+        }
+    }
+}
+
+impl Theme {
+    /// Returns this theme with every fg/bg color removed.
+    ///
+    /// Modifiers, border types, and title prefixes are kept, so levels stay
+    /// distinguishable under [`Theme::high_contrast`]. Used when honoring
+    /// `NO_COLOR`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Theme;
+    ///
+    /// let mono = Theme::high_contrast().without_colors();
+    /// assert_eq!(mono.error.border_style.fg, None);
+    /// ```
+    pub fn without_colors(self) -> Self {
+        Self {
+            default_border_style: strip_colors(self.default_border_style),
+            info: self.info.without_colors(),
+            warn: self.warn.without_colors(),
+            error: self.error.without_colors(),
+            debug: self.debug.without_colors(),
+            trace: self.trace.without_colors(),
+        }
+    }
+}
+
+/// Removes the fg/bg colors from a style, keeping its modifiers.
+fn strip_colors(style: Style) -> Style {
+    Style {
+        fg: None,
+        bg: None,
+        ..style
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+// FILE: src/notifications/classes/cls_theme.rs - Level styling theme
+// END OF VERSION: 1.2.0
@@ -1,16 +1,33 @@
 // FILE: src/notifications/classes/mod.rs - Classes module
-// VERSION: 1.1.0
-// WCTX: OFPF migration - Notification class
-// CLOG: Export Notification and NotificationBuilder publicly
+// VERSION: 1.8.0
+// WCTX: Adopting externally built states
+// CLOG: Added cls_notification_state_builder
 
 pub(crate) mod cls_notification;
+pub(crate) mod cls_strings;
+pub(crate) mod cls_theme;
+#[cfg(feature = "std")]
 pub(crate) mod cls_notification_state;
+#[cfg(feature = "std")]
+pub(crate) mod cls_notification_state_builder;
+#[cfg(feature = "std")]
+pub(crate) mod cls_notification_mut;
+#[cfg(feature = "std")]
+pub(crate) mod cls_saved_notifications;
 
 // Public exports
 pub use cls_notification::{Notification, NotificationBuilder};
+pub use cls_strings::Strings;
+pub use cls_theme::{LevelTheme, Theme};
 
-// Internal exports
-pub(crate) use cls_notification_state::{NotificationState, ManagerDefaults};
+#[cfg(feature = "std")]
+pub use cls_notification_state::{ManagerDefaults, NotificationState};
+#[cfg(feature = "std")]
+pub use cls_notification_state_builder::NotificationStateBuilder;
+#[cfg(feature = "std")]
+pub use cls_notification_mut::NotificationMut;
+#[cfg(feature = "std")]
+pub use cls_saved_notifications::{SavedNotification, SavedNotifications};
 
 // FILE: src/notifications/classes/mod.rs - Classes module
-// END OF VERSION: 1.1.0
+// END OF VERSION: 1.8.0
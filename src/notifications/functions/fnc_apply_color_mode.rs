@@ -0,0 +1,72 @@
+// FILE: src/notifications/functions/fnc_apply_color_mode.rs - Degrade rendered colors to the terminal's capability
+// VERSION: 1.0.0
+// WCTX: Color output degradation for limited terminals
+// CLOG: Initial creation
+
+use crate::notifications::types::{ColorMode, Level};
+use crate::shared_utils::math::{ansi256_to_rgb, rgb_to_ansi16, rgb_to_ansi256};
+use ratatui::buffer::Buffer;
+use ratatui::prelude::*;
+
+/// Rewrites the colors of every cell in `area` to fit the given color mode.
+///
+/// Runs after a notification has been drawn, so it covers crate styles, fade
+/// colors, and styled content spans alike.
+///
+/// # Arguments
+///
+/// * `buf` - Buffer holding the rendered notification
+/// * `area` - Region occupied by the notification
+/// * `mode` - Target color capability
+/// * `level` - Notification level, used for `Mono` modifiers
+///
+/// # Examples
+///
+/// ```ignore
+/// // Internal function - called by the render orchestrator
+/// apply_color_mode(frame.buffer_mut(), rect, ColorMode::Ansi16, Some(Level::Info));
+/// ```
+pub fn apply_color_mode(buf: &mut Buffer, area: Rect, mode: ColorMode, level: Option<Level>) {
+    if mode == ColorMode::TrueColor {
+        return;
+    }
+
+    let level_modifier = match level {
+        Some(Level::Error) => Modifier::BOLD,
+        Some(Level::Trace) => Modifier::DIM,
+        _ => Modifier::empty(),
+    };
+
+    let area = area.intersection(buf.area);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            if let Some(cell) = buf.cell_mut((x, y)) {
+                cell.fg = quantize_color(cell.fg, mode);
+                cell.bg = quantize_color(cell.bg, mode);
+                cell.underline_color = quantize_color(cell.underline_color, mode);
+                if mode == ColorMode::Mono {
+                    cell.modifier.insert(level_modifier);
+                }
+            }
+        }
+    }
+}
+
+/// Maps a single color to the closest color available in `mode`.
+fn quantize_color(color: Color, mode: ColorMode) -> Color {
+    match (mode, color) {
+        (_, Color::Reset) | (ColorMode::TrueColor, _) => color,
+        (ColorMode::Mono, _) => Color::Reset,
+        (ColorMode::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(rgb_to_ansi256(r, g, b)),
+        (ColorMode::Ansi256, _) => color,
+        (ColorMode::Ansi16, Color::Indexed(index)) => {
+            let (r, g, b) = ansi256_to_rgb(index);
+            rgb_to_ansi16(r, g, b)
+        }
+        (ColorMode::Ansi16, Color::Rgb(r, g, b)) => rgb_to_ansi16(r, g, b),
+        (ColorMode::Ansi16, _) => color,
+    }
+}
+
+// FILE: src/notifications/functions/fnc_apply_color_mode.rs - Degrade rendered colors to the terminal's capability
+// END OF VERSION: 1.0.0
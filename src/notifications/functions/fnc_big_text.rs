@@ -0,0 +1,155 @@
+// FILE: src/notifications/functions/fnc_big_text.rs - Block-font rendering for big text
+// VERSION: 1.0.0
+// WCTX: Big block-font text
+// CLOG: Initial creation
+
+use ratatui::layout::Alignment;
+use ratatui::text::{Line, Text};
+
+/// Rows each source line expands to.
+pub const BIG_TEXT_ROWS: usize = 3;
+
+/// Blank cell inside glyphs; a non-breaking space so wrapping never trims it.
+const BLANK: char = '\u{a0}';
+
+/// 3x5 pixel bitmaps, one row per byte, most significant of the low 3 bits on the left.
+fn glyph_bitmap(c: char) -> [u8; 5] {
+    match c.to_ascii_uppercase() {
+        'A' => [0b010, 0b101, 0b111, 0b101, 0b101],
+        'B' => [0b110, 0b101, 0b110, 0b101, 0b110],
+        'C' => [0b011, 0b100, 0b100, 0b100, 0b011],
+        'D' => [0b110, 0b101, 0b101, 0b101, 0b110],
+        'E' => [0b111, 0b100, 0b110, 0b100, 0b111],
+        'F' => [0b111, 0b100, 0b110, 0b100, 0b100],
+        'G' => [0b011, 0b100, 0b101, 0b101, 0b011],
+        'H' => [0b101, 0b101, 0b111, 0b101, 0b101],
+        'I' => [0b111, 0b010, 0b010, 0b010, 0b111],
+        'J' => [0b001, 0b001, 0b001, 0b101, 0b010],
+        'K' => [0b101, 0b101, 0b110, 0b101, 0b101],
+        'L' => [0b100, 0b100, 0b100, 0b100, 0b111],
+        'M' => [0b101, 0b111, 0b111, 0b101, 0b101],
+        'N' => [0b110, 0b101, 0b101, 0b101, 0b101],
+        'O' => [0b010, 0b101, 0b101, 0b101, 0b010],
+        'P' => [0b110, 0b101, 0b110, 0b100, 0b100],
+        'Q' => [0b010, 0b101, 0b101, 0b110, 0b011],
+        'R' => [0b110, 0b101, 0b110, 0b101, 0b101],
+        'S' => [0b011, 0b100, 0b010, 0b001, 0b110],
+        'T' => [0b111, 0b010, 0b010, 0b010, 0b010],
+        'U' => [0b101, 0b101, 0b101, 0b101, 0b111],
+        'V' => [0b101, 0b101, 0b101, 0b101, 0b010],
+        'W' => [0b101, 0b101, 0b111, 0b111, 0b101],
+        'X' => [0b101, 0b101, 0b010, 0b101, 0b101],
+        'Y' => [0b101, 0b101, 0b010, 0b010, 0b010],
+        'Z' => [0b111, 0b001, 0b010, 0b100, 0b111],
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b110, 0b001, 0b010, 0b100, 0b111],
+        '3' => [0b110, 0b001, 0b010, 0b001, 0b110],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b110, 0b001, 0b110],
+        '6' => [0b011, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b010, 0b010, 0b010],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b110],
+        ' ' => [0b000, 0b000, 0b000, 0b000, 0b000],
+        '!' => [0b010, 0b010, 0b010, 0b000, 0b010],
+        '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+        ',' => [0b000, 0b000, 0b000, 0b010, 0b100],
+        ':' => [0b000, 0b010, 0b000, 0b010, 0b000],
+        '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+        '\'' => [0b010, 0b010, 0b000, 0b000, 0b000],
+        // '?' and anything outside the font
+        _ => [0b110, 0b001, 0b010, 0b000, 0b010],
+    }
+}
+
+/// Renders one glyph as three rows of half-block cells.
+///
+/// # Arguments
+///
+/// * `c` - The character; lowercase letters use the uppercase glyph and
+///   characters outside A–Z, 0–9 and `! . , : - ' ?` render as `?`
+///
+/// # Returns
+///
+/// Three rows, each three cells wide
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_notifications::notifications::functions::fnc_big_text::big_glyph;
+///
+/// let rows: Vec<String> = big_glyph('H')
+///     .iter()
+///     .map(|row| row.replace('\u{a0}', " "))
+///     .collect();
+/// assert_eq!(rows, ["█ █", "█▀█", "▀ ▀"]);
+/// ```
+pub fn big_glyph(c: char) -> [String; BIG_TEXT_ROWS] {
+    let bitmap = glyph_bitmap(c);
+    let pixel = |row: usize, col: usize| bitmap.get(row).is_some_and(|bits| bits & (0b100 >> col) != 0);
+
+    // Each cell covers two pixel rows: upper and lower half
+    core::array::from_fn(|cell_row| {
+        (0..3)
+            .map(|col| match (pixel(cell_row * 2, col), pixel(cell_row * 2 + 1, col)) {
+                (true, true) => '█',
+                (true, false) => '▀',
+                (false, true) => '▄',
+                (false, false) => BLANK,
+            })
+            .collect()
+    })
+}
+
+/// Expands text into the built-in block font, centered.
+///
+/// Each source line becomes [`BIG_TEXT_ROWS`] lines; glyphs are three cells
+/// wide with one blank cell between them. Line styles carry over, span
+/// styles do not.
+///
+/// # Arguments
+///
+/// * `text` - The text to expand
+///
+/// # Returns
+///
+/// The block-font text, each line centered
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::text::Text;
+/// use ratatui_notifications::notifications::functions::fnc_big_text::big_text;
+///
+/// let big = big_text(&Text::from("OK"));
+/// assert_eq!(big.lines.len(), 3);
+/// assert_eq!(big.width(), 7);
+/// ```
+pub fn big_text(text: &Text<'_>) -> Text<'static> {
+    let mut lines = Vec::with_capacity(text.lines.len() * BIG_TEXT_ROWS);
+    for line in &text.lines {
+        let glyphs: Vec<[String; BIG_TEXT_ROWS]> = line
+            .spans
+            .iter()
+            .flat_map(|span| span.content.chars())
+            .map(big_glyph)
+            .collect();
+
+        for row in 0..BIG_TEXT_ROWS {
+            let cells: Vec<&str> = glyphs.iter().map(|glyph| glyph[row].as_str()).collect();
+            lines.push(
+                Line::from(cells.join(&BLANK.to_string()))
+                    .style(line.style)
+                    .alignment(Alignment::Center),
+            );
+        }
+    }
+
+    let mut big = Text::from(lines);
+    big.style = text.style;
+    big
+}
+
+// FILE: src/notifications/functions/fnc_big_text.rs - Block-font rendering for big text
+// END OF VERSION: 1.0.0
@@ -1,7 +1,7 @@
 // FILE: src/notifications/functions/fnc_calculate_anchor_position.rs - Calculate anchor position from frame area
-// VERSION: 1.0.0
-// WCTX: Implementing layout functions for ratatui-notifications using TDD
-// CLOG: Initial creation - calculates anchor position based on frame area and anchor type
+// VERSION: 1.2.0
+// WCTX: Zero-sized render areas
+// CLOG: Empty areas and frames render nothing
 
 use crate::notifications::types::Anchor;
 use ratatui::layout::{Position, Rect};
@@ -10,6 +10,7 @@ use ratatui::layout::{Position, Rect};
 ///
 /// Given an anchor point and a frame area, this function returns the exact
 /// position (x, y) that corresponds to that anchor point within the frame.
+/// An empty frame has no cells, so every anchor resolves to its origin.
 ///
 /// # Arguments
 ///
@@ -32,6 +33,10 @@ use ratatui::layout::{Position, Rect};
 /// assert_eq!(pos, Position::new(0, 0));
 /// ```
 pub fn calculate_anchor_position(anchor: Anchor, frame_area: Rect) -> Position {
+    // An empty frame has no cells to anchor to; keep to its origin
+    if frame_area.is_empty() {
+        return frame_area.as_position();
+    }
     match anchor {
         Anchor::TopLeft => Position::new(frame_area.x, frame_area.y),
         Anchor::TopCenter => Position::new(frame_area.x + frame_area.width / 2, frame_area.y),
@@ -54,8 +59,12 @@ pub fn calculate_anchor_position(anchor: Anchor, frame_area: Rect) -> Position {
             frame_area.right().saturating_sub(1),
             frame_area.bottom().saturating_sub(1),
         ),
+        Anchor::Custom { x, y } => Position::new(
+            x.resolve(frame_area.x, frame_area.width),
+            y.resolve(frame_area.y, frame_area.height),
+        ),
     }
 }
 
 // FILE: src/notifications/functions/fnc_calculate_anchor_position.rs - Calculate anchor position from frame area
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.2.0
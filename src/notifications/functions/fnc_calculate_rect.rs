@@ -1,7 +1,7 @@
 // FILE: src/notifications/functions/fnc_calculate_rect.rs - Calculate notification rectangle from anchor and size
-// VERSION: 1.0.0
-// WCTX: Implementing layout functions for ratatui-notifications using TDD
-// CLOG: Initial creation - calculates final rect position with anchor alignment and exterior padding
+// VERSION: 1.3.0
+// WCTX: Zero-sized render areas
+// CLOG: Empty areas and frames render nothing
 
 use crate::notifications::types::Anchor;
 use ratatui::layout::{Position, Rect};
@@ -14,6 +14,12 @@ use ratatui::layout::{Position, Rect};
 /// - Exterior padding (offset from screen edges)
 /// - Frame boundary clamping (ensures rect stays within frame)
 ///
+/// The placement math runs in `i32`, so nothing wraps near the frame edges. A
+/// rect pushed past an edge, by padding at least as large as the frame or an
+/// anchor position outside it, stops flush against that edge; one larger than
+/// the frame is shrunk to the frame and placed at its origin. An empty frame
+/// yields an empty rect at its origin.
+///
 /// # Arguments
 ///
 /// * `anchor` - The anchor type (determines alignment behavior)
@@ -47,18 +53,23 @@ pub fn calculate_rect(
     frame_area: Rect,
     exterior_padding: u16,
 ) -> Rect {
-    let mut x = anchor_pos.x;
-    let mut y = anchor_pos.y;
+    // Nothing fits in an empty frame
+    if frame_area.is_empty() {
+        return Rect::new(frame_area.x, frame_area.y, 0, 0);
+    }
+    let (width, height) = (i32::from(width), i32::from(height));
+    let mut x = i32::from(anchor_pos.x);
+    let mut y = i32::from(anchor_pos.y);
 
     // Adjust x based on horizontal anchor alignment
     match anchor {
         Anchor::TopCenter | Anchor::MiddleCenter | Anchor::BottomCenter => {
             // Center-aligned: offset by half width
-            x = x.saturating_sub(width / 2);
+            x -= width / 2;
         }
         Anchor::TopRight | Anchor::MiddleRight | Anchor::BottomRight => {
             // Right-aligned: offset by width minus 1
-            x = x.saturating_sub(width.saturating_sub(1));
+            x -= (width - 1).max(0);
         }
         _ => {}
     }
@@ -67,69 +78,54 @@ pub fn calculate_rect(
     match anchor {
         Anchor::MiddleLeft | Anchor::MiddleCenter | Anchor::MiddleRight => {
             // Middle-aligned: offset by half height
-            y = y.saturating_sub(height / 2);
+            y -= height / 2;
         }
         Anchor::BottomLeft | Anchor::BottomCenter | Anchor::BottomRight => {
             // Bottom-aligned: offset by height minus 1
-            y = y.saturating_sub(height.saturating_sub(1));
+            y -= (height - 1).max(0);
         }
         _ => {}
     }
 
-    // Apply exterior padding based on anchor position
-    match anchor {
-        Anchor::TopLeft => {
-            x = x.saturating_add(exterior_padding);
-            y = y.saturating_add(exterior_padding);
-        }
-        Anchor::TopCenter => {
-            y = y.saturating_add(exterior_padding);
-        }
-        Anchor::TopRight => {
-            x = x.saturating_sub(exterior_padding);
-            y = y.saturating_add(exterior_padding);
-        }
-        Anchor::MiddleLeft => {
-            x = x.saturating_add(exterior_padding);
-        }
-        Anchor::MiddleCenter => {
-            // No padding for center
-        }
-        Anchor::MiddleRight => {
-            x = x.saturating_sub(exterior_padding);
-        }
-        Anchor::BottomLeft => {
-            x = x.saturating_add(exterior_padding);
-            y = y.saturating_sub(exterior_padding);
-        }
-        Anchor::BottomCenter => {
-            y = y.saturating_sub(exterior_padding);
-        }
-        Anchor::BottomRight => {
-            x = x.saturating_sub(exterior_padding);
-            y = y.saturating_sub(exterior_padding);
-        }
-    }
+    // Apply exterior padding, pushing away from the anchored edges
+    let padding = i32::from(exterior_padding);
+    let (dx, dy) = match anchor {
+        Anchor::TopLeft => (1, 1),
+        Anchor::TopCenter => (0, 1),
+        Anchor::TopRight => (-1, 1),
+        Anchor::MiddleLeft => (1, 0),
+        // No padding for center or an explicit position
+        Anchor::MiddleCenter | Anchor::Custom { .. } => (0, 0),
+        Anchor::MiddleRight => (-1, 0),
+        Anchor::BottomLeft => (1, -1),
+        Anchor::BottomCenter => (0, -1),
+        Anchor::BottomRight => (-1, -1),
+    };
+    x += dx * padding;
+    y += dy * padding;
 
     // Clamp dimensions to frame size
-    let clamped_width = width.min(frame_area.width);
-    let clamped_height = height.min(frame_area.height);
-
-    // Clamp position to frame bounds
-    let final_x = x
-        .max(frame_area.x)
-        .min(frame_area.right().saturating_sub(clamped_width));
+    let clamped_width = width.min(i32::from(frame_area.width));
+    let clamped_height = height.min(i32::from(frame_area.height));
 
-    let final_y = y
-        .max(frame_area.y)
-        .min(frame_area.bottom().saturating_sub(clamped_height));
+    // Clamp position to frame bounds; anything pushed past an edge stops on it
+    let max_x = (i32::from(frame_area.right()) - clamped_width).max(i32::from(frame_area.x));
+    let max_y = (i32::from(frame_area.bottom()) - clamped_height).max(i32::from(frame_area.y));
+    let final_x = x.clamp(i32::from(frame_area.x), max_x);
+    let final_y = y.clamp(i32::from(frame_area.y), max_y);
 
-    // Ensure we don't go negative (double-check frame bounds)
-    let final_x = final_x.max(frame_area.x);
-    let final_y = final_y.max(frame_area.y);
+    Rect::new(
+        to_u16(final_x),
+        to_u16(final_y),
+        to_u16(clamped_width),
+        to_u16(clamped_height),
+    )
+}
 
-    Rect::new(final_x, final_y, clamped_width, clamped_height)
+/// Helper to narrow a value already clamped into the frame
+fn to_u16(value: i32) -> u16 {
+    u16::try_from(value.max(0)).unwrap_or(u16::MAX)
 }
 
 // FILE: src/notifications/functions/fnc_calculate_rect.rs - Calculate notification rectangle from anchor and size
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.3.0
@@ -1,18 +1,19 @@
 // FILE: src/notifications/functions/fnc_calculate_size.rs - Calculate notification size
-// VERSION: 1.0.0
-// WCTX: TDD implementation of deferred functions
-// CLOG: Initial creation - extracts size calculation logic from layout.rs
+// VERSION: 1.7.0
+// WCTX: Public measure API
+// CLOG: Delegate the layout to measure
 
 use crate::notifications::classes::Notification;
-use crate::notifications::types::SizeConstraint;
+use crate::notifications::functions::fnc_measure::measure_text;
+use crate::notifications::types::{AutoDismiss, MeasureOptions};
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, BorderType, Borders, Paragraph, Widget, Wrap};
-use ratatui::buffer::{Buffer, Cell};
 
 /// Calculates the size of a notification based on its content and constraints.
 ///
 /// This function determines the width and height needed to display a notification,
 /// taking into account borders, padding, content wrapping, and size constraints.
+/// The layout itself is [`measure`](super::fnc_measure::measure)'s; this adds
+/// the title extras (countdown, page indicator) and big text.
 ///
 /// # Arguments
 ///
@@ -21,7 +22,8 @@ use ratatui::buffer::{Buffer, Cell};
 ///
 /// # Returns
 ///
-/// A tuple `(width, height)` representing the calculated notification dimensions
+/// A tuple `(width, height)` representing the calculated notification dimensions.
+/// A notification with several pages is sized to fit the largest one.
 ///
 /// # Examples
 ///
@@ -36,98 +38,51 @@ use ratatui::buffer::{Buffer, Cell};
 /// let (width, height) = calculate_size(&notification, frame_area);
 /// ```
 pub fn calculate_size(notification: &Notification, frame_area: Rect) -> (u16, u16) {
-    // 1. Get border dimensions based on border_type
-    let border_v_offset = match notification.border_type {
-        Some(BorderType::Double) => 2,
-        Some(_) => 2,
-        None => 0,
-    };
-    let border_h_offset = match notification.border_type {
-        Some(BorderType::Double) => 2,
-        Some(_) => 2,
-        None => 0,
-    };
-
-    // 2. Get padding dimensions
-    let h_padding = notification.padding.left + notification.padding.right;
-    let v_padding = notification.padding.top + notification.padding.bottom;
-
-    // 3. Calculate minimum size (at least 3x3)
-    let min_width = (1 + h_padding + border_h_offset).max(3);
-    let min_height = (1 + v_padding + border_v_offset).max(3);
-
-    // 4. Apply max_width constraint (Percentage or Absolute)
-    let max_width_constraint = notification
-        .max_width
-        .map(|c| match c {
-            SizeConstraint::Absolute(w) => w.min(frame_area.width),
-            SizeConstraint::Percentage(p) => {
-                ((frame_area.width as f32 * p.clamp(0.0, 1.0)) as u16).max(1)
-            }
-        })
-        .unwrap_or(frame_area.width)
-        .max(min_width);
-
-    // 5. Calculate intrinsic width from content
-    let content_max_line_width = notification
-        .content
-        .lines
-        .iter()
-        .map(|l| l.width())
-        .max()
-        .unwrap_or(0) as u16;
-
+    let options = MeasureOptions::of(notification, frame_area);
     let title_width = notification.title.as_ref().map_or(0, |t| t.width()) as u16;
+    let title_width =
+        (title_width + countdown_width(notification)).max(page_indicator_width(notification));
 
-    let intrinsic_width =
-        (content_max_line_width.max(title_width) + border_h_offset + h_padding).max(min_width);
-
-    let final_width = intrinsic_width.min(max_width_constraint);
-
-    // 6. Apply max_height constraint
-    let max_height_constraint = notification
-        .max_height
-        .map(|c| match c {
-            SizeConstraint::Absolute(h) => h.min(frame_area.height),
-            SizeConstraint::Percentage(p) => {
-                ((frame_area.height as f32 * p.clamp(0.0, 1.0)) as u16).max(1)
-            }
+    let current = measure_text(notification.layout_content(), &options, title_width);
+    if notification.pages.len() < 2 {
+        return current;
+    }
+    // Fit every page so the box keeps its size while cycling
+    notification
+        .pages
+        .iter()
+        .fold(current, |(width, height), page| {
+            let (page_width, page_height) =
+                measure_text(notification.layout_text(page), &options, title_width);
+            (width.max(page_width), height.max(page_height))
         })
-        .unwrap_or(frame_area.height)
-        .max(min_height);
+}
 
-    // 7. Render content to buffer to measure actual height with wrapping
-    let mut temp_block = Block::default();
-    if let Some(border_type) = notification.border_type {
-        temp_block = temp_block.borders(Borders::ALL).border_type(border_type);
-    }
-    if let Some(title) = &notification.title {
-        temp_block = temp_block.title(title.clone());
+/// Title cells taken by the `show_countdown` text at its widest.
+///
+/// Measured with the English "{n}s" form; longer localized wording is
+/// clipped when the content does not make the box wide enough.
+fn page_indicator_width(notification: &Notification) -> u16 {
+    let count = notification.pages.len();
+    if count < 2 {
+        return 0;
     }
-    temp_block = temp_block.padding(notification.padding);
-
-    let temp_paragraph = Paragraph::new(notification.content.clone())
-        .wrap(Wrap { trim: true })
-        .block(temp_block);
-
-    let buffer_height = max_height_constraint;
-    let mut buffer = Buffer::empty(Rect::new(0, 0, final_width, buffer_height));
-    temp_paragraph.render(buffer.area, &mut buffer);
-
-    let default_cell = Cell::default();
-    let measured_height = buffer
-        .content
-        .iter()
-        .enumerate()
-        .filter(|(_, cell)| *cell != &default_cell)
-        .map(|(idx, _)| buffer.pos_of(idx).1)
-        .max()
-        .map_or(0, |row_index| row_index + 1);
+    // " {page}/{count} " with the default strings, at the widest page number
+    (count.to_string().len() * 2 + 3) as u16
+}
 
-    // 8. Return (width, height) tuple
-    let final_height = measured_height.max(min_height).min(max_height_constraint);
-    (final_width, final_height)
+fn countdown_width(notification: &Notification) -> u16 {
+    let AutoDismiss::After(display_time) = notification.auto_dismiss else {
+        return 0;
+    };
+    if !notification.show_countdown {
+        return 0;
+    }
+    // After(ZERO) uses the manager's default display time, unknown here
+    let secs = display_time.as_millis().div_ceil(1000).max(10);
+    let separator = if notification.title.is_some() { 1 } else { 2 };
+    (secs.to_string().len() + 1 + separator) as u16
 }
 
 // FILE: src/notifications/functions/fnc_calculate_size.rs - Calculate notification size
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.7.0
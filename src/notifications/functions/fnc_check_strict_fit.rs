@@ -0,0 +1,74 @@
+// FILE: src/notifications/functions/fnc_check_strict_fit.rs - Build-time check that content fits its max size
+// VERSION: 1.3.0
+// WCTX: Wrapped line measurement
+// CLOG: Wrap through the shared wrapped helper
+
+use crate::notifications::classes::Notification;
+use crate::notifications::types::{NotificationError, SizeConstraint};
+use crate::notifications::functions::fnc_wrap_text::wrapped;
+
+/// Verifies that a notification's content fits inside its absolute size limits.
+///
+/// Width is allowed to overflow into wrapping; the check fails only when the
+/// wrapped content needs more rows than `max_height` allows. Percentage
+/// constraints depend on the frame size and cannot be checked at build time,
+/// so they always pass.
+///
+/// # Arguments
+///
+/// * `notification` - The notification to validate
+///
+/// # Returns
+///
+/// * `Ok(())` if the content fits
+/// * `Err(NotificationError::ContentTooLarge(required_rows, allowed_rows))` otherwise
+///
+/// # Examples
+///
+/// ```ignore
+/// // Internal function - use through NotificationBuilder::strict_fit
+/// let result = check_strict_fit(&notification);
+/// ```
+pub fn check_strict_fit(notification: &Notification) -> Result<(), NotificationError> {
+    let border = if notification.border_type.is_some() { 2 } else { 0 };
+    let h_padding = notification.padding.left + notification.padding.right;
+    let separator_rows = u16::from(notification.shows_title_separator());
+    let v_padding = notification.padding.top + notification.padding.bottom + separator_rows;
+
+    // Width the content is laid out at: the absolute limit, or its natural width
+    let content = notification.layout_content();
+    let content_width = content
+        .lines
+        .iter()
+        .map(|l| l.width())
+        .max()
+        .unwrap_or(0) as u16;
+    let title_width = notification.title.as_ref().map_or(0, |t| t.width()) as u16;
+    let natural_width = content_width.max(title_width) + border + h_padding;
+    let layout_width = match notification.max_width {
+        Some(SizeConstraint::Absolute(w)) => w.min(natural_width),
+        _ => natural_width,
+    };
+
+    let allowed_height = match notification.max_height {
+        Some(SizeConstraint::Absolute(h)) => h,
+        _ => return Ok(()),
+    };
+
+    let inner_width = layout_width.saturating_sub(border + h_padding).max(1);
+    let content_rows = wrapped(content.into_owned())
+        .line_count(inner_width) as u16;
+    let required_height = content_rows.max(1) + border + v_padding;
+
+    if required_height > allowed_height {
+        return Err(NotificationError::ContentTooLarge(
+            required_height as usize,
+            allowed_height as usize,
+        ));
+    }
+
+    Ok(())
+}
+
+// FILE: src/notifications/functions/fnc_check_strict_fit.rs - Build-time check that content fits its max size
+// END OF VERSION: 1.3.0
@@ -0,0 +1,118 @@
+// FILE: src/notifications/functions/fnc_describe_notification.rs - Plain-text sentence for one notification
+// VERSION: 1.1.0
+// WCTX: Localizable built-in strings
+// CLOG: Wording comes from Strings
+
+use crate::notifications::classes::Strings;
+use crate::notifications::types::{Anchor, Level, StringKey};
+use core::time::Duration;
+use ratatui::text::{Line, Text};
+
+/// Builds a plain-text sentence describing a notification.
+///
+/// Intended for screen readers, braille lines and external announcers, so
+/// the output contains no icons or styling and its wording is stable. All
+/// fixed wording comes from `strings`.
+///
+/// # Arguments
+///
+/// * `level` - Optional notification level
+/// * `anchor` - Screen anchor of the notification
+/// * `title` - Optional title
+/// * `content` - Notification content
+/// * `dismisses_in` - Time until dismissal; `None` if it never auto-dismisses,
+///   zero if it is already leaving
+/// * `strings` - Wording for levels, anchors and countdowns
+///
+/// # Returns
+///
+/// A sentence such as
+/// `"Error notification, top right: Connection failed. Dismisses in 4 seconds."`
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_notifications::notifications::functions::fnc_describe_notification::describe_notification;
+/// use ratatui_notifications::notifications::types::{Anchor, Level};
+/// use ratatui_notifications::notifications::Strings;
+/// use ratatui::text::Text;
+/// use std::time::Duration;
+///
+/// let sentence = describe_notification(
+///     Some(Level::Error),
+///     Anchor::TopRight,
+///     None,
+///     &Text::from("Connection failed"),
+///     Some(Duration::from_secs(4)),
+///     &Strings::default(),
+/// );
+/// assert_eq!(sentence, "Error notification, top right: Connection failed. Dismisses in 4 seconds.");
+/// ```
+pub fn describe_notification(
+    level: Option<Level>,
+    anchor: Anchor,
+    title: Option<&Line<'_>>,
+    content: &Text<'_>,
+    dismisses_in: Option<Duration>,
+    strings: &Strings,
+) -> String {
+    let anchor_name = strings.format(StringKey::Anchor(anchor), &[]);
+    let mut sentence = match level {
+        Some(lvl) => {
+            let level_name = strings.format(StringKey::Level(lvl), &[]);
+            strings.format(
+                StringKey::DescribeHeading,
+                &[("level", &level_name), ("anchor", &anchor_name)],
+            )
+        }
+        None => strings.format(StringKey::DescribeHeadingNoLevel, &[("anchor", &anchor_name)]),
+    };
+
+    let title_text = title.map(line_to_plain).unwrap_or_default();
+    let content_text = content
+        .lines
+        .iter()
+        .map(line_to_plain)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    for part in [title_text, content_text] {
+        if !part.is_empty() {
+            sentence.push(' ');
+            sentence.push_str(&part);
+            if !part.ends_with(['.', '!', '?']) {
+                sentence.push('.');
+            }
+        }
+    }
+
+    let countdown = match dismisses_in {
+        Some(remaining) if remaining.is_zero() => Some(strings.format(StringKey::Dismissing, &[])),
+        Some(remaining) => {
+            // Round up so a notification is never announced as dismissing in 0 seconds
+            let secs = remaining.as_millis().div_ceil(1000).to_string();
+            Some(strings.format(StringKey::DismissesIn, &[("n", &secs)]))
+        }
+        None => None,
+    };
+    if let Some(countdown) = countdown {
+        sentence.push(' ');
+        sentence.push_str(&countdown);
+    }
+
+    sentence
+}
+
+/// Concatenates a line's spans and trims surrounding whitespace.
+fn line_to_plain(line: &Line<'_>) -> String {
+    line.spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+// FILE: src/notifications/functions/fnc_describe_notification.rs - Plain-text sentence for one notification
+// END OF VERSION: 1.1.0
@@ -1,10 +1,10 @@
 // FILE: src/notifications/functions/fnc_expand_calculate_rect.rs - Expand/Collapse animation rect calculation
-// VERSION: 1.0.0
-// WCTX: TDD implementation of animation function extraction
-// CLOG: Extracted from expand_collapse.rs with rect interpolation logic
+// VERSION: 1.6.0
+// WCTX: Eased expand
+// CLOG: Added calculate_rect_eased
 
-use crate::notifications::types::AnimationPhase;
-use crate::shared_utils::math::lerp;
+use crate::notifications::types::{Anchor, AnimationPhase, Easing, ExpandOrigin};
+use crate::shared_utils::math::{clamp_progress, lerp, round_to_u16};
 use ratatui::prelude::*;
 
 // Minimum dimensions for expand/collapse animation
@@ -19,7 +19,7 @@ const MIN_HEIGHT: u16 = 3;
 /// # Arguments
 ///
 /// * `full_rect` - The full rectangle of the notification when fully expanded
-/// * `_frame_area` - The frame area (ignored for expand/collapse animations)
+/// * `frame_area` - The frame area; the animated rect is clipped to it
 /// * `phase` - The current animation phase
 /// * `progress` - The animation progress (0.0 to 1.0)
 ///
@@ -47,11 +47,130 @@ const MIN_HEIGHT: u16 = 3;
 /// ```
 pub fn calculate_rect(
     full_rect: Rect,
-    _frame_area: Rect,
+    frame_area: Rect,
     phase: AnimationPhase,
     progress: f32,
 ) -> Rect {
-    let progress = progress.clamp(0.0, 1.0);
+    calculate_rect_from_origin(
+        full_rect,
+        frame_area,
+        phase,
+        progress,
+        ExpandOrigin::Center,
+        Anchor::default(),
+    )
+}
+
+/// Calculates the visible rectangle for an expand/collapse animation that
+/// keeps the given origin fixed.
+///
+/// Width and height interpolate exactly as in [`calculate_rect`]; the
+/// origin decides which point of the full rect stays in place, so an
+/// `ExpandOrigin::Anchor` toast at `BottomRight` grows out of its
+/// bottom-right corner.
+///
+/// # Arguments
+///
+/// * `full_rect` - The full rectangle of the notification when fully expanded
+/// * `frame_area` - The frame area; the animated rect is clipped to it
+/// * `phase` - The current animation phase
+/// * `progress` - The animation progress (0.0 to 1.0)
+/// * `origin` - The point that stays fixed while the size changes
+/// * `anchor` - The notification's anchor, used by `ExpandOrigin::Anchor`
+///
+/// # Returns
+///
+/// The interpolated rectangle at the current animation progress
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_notifications::notifications::functions::fnc_expand_calculate_rect::calculate_rect_from_origin;
+/// use ratatui_notifications::notifications::types::{Anchor, AnimationPhase, ExpandOrigin};
+///
+/// let full_rect = Rect::new(10, 20, 33, 13);
+/// let frame_area = Rect::new(0, 0, 100, 100);
+///
+/// // Bottom-right corner stays at (43, 33) while expanding
+/// let result = calculate_rect_from_origin(
+///     full_rect,
+///     frame_area,
+///     AnimationPhase::Expanding,
+///     0.0,
+///     ExpandOrigin::Anchor,
+///     Anchor::BottomRight,
+/// );
+/// assert_eq!(result, Rect::new(40, 30, 3, 3));
+/// ```
+pub fn calculate_rect_from_origin(
+    full_rect: Rect,
+    frame_area: Rect,
+    phase: AnimationPhase,
+    progress: f32,
+    origin: ExpandOrigin,
+    anchor: Anchor,
+) -> Rect {
+    calculate_rect_eased(full_rect, frame_area, phase, progress, origin, anchor, Easing::Linear)
+}
+
+/// Calculates the visible rectangle for an expand/collapse animation whose
+/// size follows an easing curve.
+///
+/// Expanding applies `easing` to the progress; collapsing plays the same
+/// curve backwards, so `Easing::EaseOutQuad` grows quickly and settles into
+/// full size, then shrinks slowly at first and accelerates away. With
+/// `Easing::Linear` this is [`calculate_rect_from_origin`].
+///
+/// # Arguments
+///
+/// * `full_rect` - The full rectangle of the notification when fully expanded
+/// * `frame_area` - The frame area; the animated rect is clipped to it
+/// * `phase` - The current animation phase
+/// * `progress` - The linear animation progress (0.0 to 1.0)
+/// * `origin` - The point that stays fixed while the size changes
+/// * `anchor` - The notification's anchor, used by `ExpandOrigin::Anchor`
+/// * `easing` - The curve the size follows
+///
+/// # Returns
+///
+/// The interpolated rectangle at the current animation progress
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_notifications::notifications::functions::fnc_expand_calculate_rect::calculate_rect_eased;
+/// use ratatui_notifications::notifications::types::{Anchor, AnimationPhase, Easing, ExpandOrigin};
+///
+/// let full_rect = Rect::new(0, 0, 43, 23);
+/// let frame_area = Rect::new(0, 0, 100, 100);
+/// let at_half = |easing| {
+///     calculate_rect_eased(
+///         full_rect,
+///         frame_area,
+///         AnimationPhase::Expanding,
+///         0.5,
+///         ExpandOrigin::Center,
+///         Anchor::default(),
+///         easing,
+///     )
+/// };
+///
+/// // Halfway through, the linear size is halfway; ease-out is 75% there
+/// assert_eq!(at_half(Easing::Linear).width, 23);
+/// assert_eq!(at_half(Easing::EaseOutQuad).width, 33);
+/// ```
+pub fn calculate_rect_eased(
+    full_rect: Rect,
+    frame_area: Rect,
+    phase: AnimationPhase,
+    progress: f32,
+    origin: ExpandOrigin,
+    anchor: Anchor,
+    easing: Easing,
+) -> Rect {
+    let progress = clamp_progress(progress);
 
     let (start_width, start_height, end_width, end_height) = match phase {
         AnimationPhase::Expanding => (
@@ -70,29 +189,131 @@ pub fn calculate_rect(
         _ => return full_rect,
     };
 
+    // Collapsing mirrors the expand curve in time
+    let eased = if phase == AnimationPhase::Collapsing {
+        1.0 - easing.apply(1.0 - progress)
+    } else {
+        easing.apply(progress)
+    };
+
     // Interpolate dimensions
-    let current_width_f32 = lerp(start_width, end_width, progress);
-    let current_height_f32 = lerp(start_height, end_height, progress);
+    let current_width_f32 = lerp(start_width, end_width, eased);
+    let current_height_f32 = lerp(start_height, end_height, eased);
 
     // Round dimensions, ensuring they are at least 1x1 if progress > 0
-    let current_width = (current_width_f32.round() as u16).max(if progress > 0.0 { 1 } else { 0 });
+    let current_width = round_to_u16(current_width_f32).max(if progress > 0.0 { 1 } else { 0 });
     let current_height =
-        (current_height_f32.round() as u16).max(if progress > 0.0 { 1 } else { 0 });
+        round_to_u16(current_height_f32).max(if progress > 0.0 { 1 } else { 0 });
 
-    // Calculate top-left position to keep the rectangle centered around the full_rect's center
-    let center_x_full = full_rect.x as f32 + (full_rect.width as f32 / 2.0);
-    let center_y_full = full_rect.y as f32 + (full_rect.height as f32 / 2.0);
+    // Keep the origin at the same relative spot in both rects:
+    // 0.0 pins the left/top edge, 0.5 the center, 1.0 the right/bottom edge
+    let (origin_x, origin_y) = origin_fractions(full_rect, origin, anchor);
+    let fixed_x = full_rect.x as f32 + origin_x * full_rect.width as f32;
+    let fixed_y = full_rect.y as f32 + origin_y * full_rect.height as f32;
 
-    let current_x = (center_x_full - (current_width as f32 / 2.0)).round() as u16;
-    let current_y = (center_y_full - (current_height as f32 / 2.0)).round() as u16;
+    // Saturate at the screen origin rather than wrapping
+    let current_x = round_to_u16(fixed_x - origin_x * current_width as f32);
+    let current_y = round_to_u16(fixed_y - origin_y * current_height as f32);
 
     // Ensure dimensions are valid
     if current_width == 0 || current_height == 0 {
+        return Rect::default();
+    }
+
+    // Rounding can push the rect a cell past the frame edge; clip it
+    let clipped = Rect::new(current_x, current_y, current_width, current_height).intersection(frame_area);
+    if clipped.is_empty() {
         Rect::default()
     } else {
-        Rect::new(current_x, current_y, current_width, current_height)
+        clipped
+    }
+}
+
+/// Calculates the visible rectangle for a collapse toward a fixed point.
+///
+/// Used when a notification is dismissed toward a screen position (e.g. a
+/// bell icon in a status bar): every edge interpolates from `full_rect` to a
+/// 1x1 rect at `target`, so the box shrinks while travelling there.
+///
+/// # Arguments
+///
+/// * `full_rect` - The rect the collapse starts from
+/// * `frame_area` - The frame area; the animated rect is clipped to it
+/// * `target` - The cell the notification collapses into
+/// * `progress` - The exit progress (0.0 to 1.0)
+///
+/// # Returns
+///
+/// The interpolated rectangle, or an empty rect if it lies outside the frame
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_notifications::notifications::functions::fnc_expand_calculate_rect::calculate_rect_toward;
+///
+/// let full_rect = Rect::new(10, 20, 33, 13);
+/// let frame_area = Rect::new(0, 0, 100, 100);
+/// let bell = Position::new(99, 0);
+///
+/// assert_eq!(calculate_rect_toward(full_rect, frame_area, bell, 0.0), full_rect);
+/// assert_eq!(calculate_rect_toward(full_rect, frame_area, bell, 1.0), Rect::new(99, 0, 1, 1));
+/// ```
+pub fn calculate_rect_toward(
+    full_rect: Rect,
+    frame_area: Rect,
+    target: Position,
+    progress: f32,
+) -> Rect {
+    let progress = clamp_progress(progress);
+
+    let x = round_to_u16(lerp(full_rect.x as f32, target.x as f32, progress));
+    let y = round_to_u16(lerp(full_rect.y as f32, target.y as f32, progress));
+    let width = round_to_u16(lerp(full_rect.width as f32, 1.0, progress)).max(1);
+    let height = round_to_u16(lerp(full_rect.height as f32, 1.0, progress)).max(1);
+
+    let clipped = Rect::new(x, y, width, height).intersection(frame_area);
+    if clipped.is_empty() {
+        Rect::default()
+    } else {
+        clipped
+    }
+}
+
+/// Helper to express the origin as fractions of the full rect's width/height
+fn origin_fractions(full_rect: Rect, origin: ExpandOrigin, anchor: Anchor) -> (f32, f32) {
+    match origin {
+        ExpandOrigin::Center => (0.5, 0.5),
+        ExpandOrigin::Anchor => {
+            let x = match anchor {
+                Anchor::TopLeft | Anchor::MiddleLeft | Anchor::BottomLeft => 0.0,
+                Anchor::Custom { .. } => 0.0,
+                Anchor::TopCenter | Anchor::MiddleCenter | Anchor::BottomCenter => 0.5,
+                Anchor::TopRight | Anchor::MiddleRight | Anchor::BottomRight => 1.0,
+            };
+            let y = match anchor {
+                Anchor::TopLeft | Anchor::TopCenter | Anchor::TopRight => 0.0,
+                Anchor::Custom { .. } => 0.0,
+                Anchor::MiddleLeft | Anchor::MiddleCenter | Anchor::MiddleRight => 0.5,
+                Anchor::BottomLeft | Anchor::BottomCenter | Anchor::BottomRight => 1.0,
+            };
+            (x, y)
+        }
+        ExpandOrigin::Position(position) => {
+            let fraction = |offset: f32, length: u16| {
+                if length == 0 {
+                    0.0
+                } else {
+                    (offset / length as f32).clamp(0.0, 1.0)
+                }
+            };
+            (
+                fraction(position.x as f32 - full_rect.x as f32, full_rect.width),
+                fraction(position.y as f32 - full_rect.y as f32, full_rect.height),
+            )
+        }
     }
 }
 
 // FILE: src/notifications/functions/fnc_expand_calculate_rect.rs - Expand/Collapse animation rect calculation
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.6.0
@@ -1,10 +1,10 @@
 // FILE: src/notifications/functions/fnc_fade_interpolate_color.rs - Fade animation color interpolation
-// VERSION: 1.1.0
-// WCTX: Fixing slide+fade combined animation
-// CLOG: Handle SlidingIn/SlidingOut/Expanding/Collapsing phases for combined animations
+// VERSION: 1.4.0
+// WCTX: Non-finite progress guards
+// CLOG: Clamp progress and rect math against non-finite values
 
-use crate::notifications::types::AnimationPhase;
-use crate::shared_utils::math::{color_to_rgb, ease_in_quad, ease_out_quad, lerp};
+use crate::notifications::types::{AnimationPhase, ColorClamp, FadeScope};
+use crate::shared_utils::math::{clamp_progress, color_to_rgb, ease_in_quad, ease_out_quad};
 use ratatui::style::Color;
 
 // Target color when fully faded out
@@ -40,33 +40,66 @@ pub fn interpolate_color(
     progress: f32,
     is_fading_in: bool,
 ) -> Option<Color> {
-    let linear_progress = progress.clamp(0.0, 1.0);
+    let easing = if is_fading_in { ease_out_quad } else { ease_in_quad };
+    interpolate_color_eased(from, to, progress, easing, ColorClamp::Range)
+}
+
+/// Interpolates between two colors with a caller-supplied easing.
+///
+/// Snaps at the linear midpoint when either color has no RGB value.
+///
+/// # Arguments
+///
+/// * `from` - The starting color
+/// * `to` - The ending color
+/// * `progress` - Linear progress value; clamped to `0.0..=1.0` before easing
+/// * `easing` - Maps linear to eased progress; may overshoot `0.0..=1.0`
+/// * `clamp` - Whether overshoot is clamped to the `from`..`to` range
+///
+/// # Returns
+///
+/// The interpolated color, or a snapped color if RGB interpolation is not possible
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::style::Color;
+/// use ratatui_notifications::notifications::functions::fnc_fade_interpolate_color::interpolate_color_eased;
+/// use ratatui_notifications::notifications::ColorClamp;
+///
+/// // An easing that overshoots by 20% at this point
+/// let overshoot = |_t: f32| 1.2;
+/// let gray = Some(Color::Rgb(100, 100, 100));
+/// let light = Some(Color::Rgb(200, 200, 200));
+///
+/// let range = interpolate_color_eased(gray, light, 0.8, overshoot, ColorClamp::Range);
+/// assert_eq!(range, Some(Color::Rgb(200, 200, 200)));
+///
+/// let channel = interpolate_color_eased(gray, light, 0.8, overshoot, ColorClamp::Channel);
+/// assert_eq!(channel, Some(Color::Rgb(220, 220, 220)));
+/// ```
+pub fn interpolate_color_eased(
+    from: Option<Color>,
+    to: Option<Color>,
+    progress: f32,
+    easing: impl Fn(f32) -> f32,
+    clamp: ColorClamp,
+) -> Option<Color> {
+    let linear_progress = clamp_progress(progress);
 
     if let (Some((r1, g1, b1)), Some((r2, g2, b2))) = (color_to_rgb(from), color_to_rgb(to)) {
-        let eased_progress = if is_fading_in {
-            ease_out_quad(linear_progress)
-        } else {
-            ease_in_quad(linear_progress)
+        let eased_progress = match clamp {
+            ColorClamp::Range => clamp_progress(easing(linear_progress)),
+            ColorClamp::Channel => easing(linear_progress),
         };
 
-        // Interpolate RGB components using eased progress
-        let r_f = lerp(r1 as f32, r2 as f32, eased_progress);
-        let g_f = lerp(g1 as f32, g2 as f32, eased_progress);
-        let b_f = lerp(b1 as f32, b2 as f32, eased_progress);
-
-        // Clamp results to the min/max range of the start/end points
-        let min_r = r1.min(r2);
-        let max_r = r1.max(r2);
-        let min_g = g1.min(g2);
-        let max_g = g1.max(g2);
-        let min_b = b1.min(b2);
-        let max_b = b1.max(b2);
-
-        let r = (r_f.round() as u8).clamp(min_r, max_r);
-        let g = (g_f.round() as u8).clamp(min_g, max_g);
-        let b = (b_f.round() as u8).clamp(min_b, max_b);
+        // Interpolate RGB components; `lerp` clamps its parameter, so extrapolate here
+        let channel = |start: u8, end: u8| {
+            let value = start as f32 + eased_progress * (end as f32 - start as f32);
+            value.round().clamp(0.0, 255.0) as u8
+        };
 
-        Some(Color::Rgb(r, g, b))
+        Some(Color::Rgb(channel(r1, r2), channel(g1, g2), channel(b1, b2)))
     } else {
         // Fall back to snapping at the midpoint
         if linear_progress < 0.5 {
@@ -144,7 +177,59 @@ impl FadeHandler {
         };
         interpolate_color(start_fg, end_fg, progress, is_fading_in)
     }
+
+    /// Scope-aware [`interpolate_frame_foreground`](Self::interpolate_frame_foreground).
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Which parts of the notification fade
+    /// * `base_fg` - The base foreground color
+    /// * `phase` - The current animation phase
+    /// * `progress` - Animation progress (0.0 to 1.0)
+    ///
+    /// # Returns
+    ///
+    /// `base_fg` unchanged when the scope keeps the frame solid, otherwise the
+    /// interpolated frame color
+    pub fn interpolate_frame_foreground_scoped(
+        &self,
+        scope: FadeScope,
+        base_fg: Option<Color>,
+        phase: AnimationPhase,
+        progress: f32,
+    ) -> Option<Color> {
+        if !scope.fades_frame() {
+            return base_fg;
+        }
+        self.interpolate_frame_foreground(base_fg, phase, progress)
+    }
+
+    /// Scope-aware [`interpolate_content_foreground`](Self::interpolate_content_foreground).
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Which parts of the notification fade
+    /// * `base_fg` - The base foreground color
+    /// * `phase` - The current animation phase
+    /// * `progress` - Animation progress (0.0 to 1.0)
+    ///
+    /// # Returns
+    ///
+    /// `base_fg` unchanged when the scope keeps the content solid, otherwise
+    /// the interpolated content color
+    pub fn interpolate_content_foreground_scoped(
+        &self,
+        scope: FadeScope,
+        base_fg: Option<Color>,
+        phase: AnimationPhase,
+        progress: f32,
+    ) -> Option<Color> {
+        if !scope.fades_content() {
+            return base_fg;
+        }
+        self.interpolate_content_foreground(base_fg, phase, progress)
+    }
 }
 
 // FILE: src/notifications/functions/fnc_fade_interpolate_color.rs - Fade animation color interpolation
-// END OF VERSION: 1.1.0
+// END OF VERSION: 1.4.0
@@ -0,0 +1,94 @@
+// FILE: src/notifications/functions/fnc_fill_template.rs - Named placeholder substitution
+// VERSION: 1.0.0
+// WCTX: Content templates with field substitution
+// CLOG: Initial creation
+
+use crate::notifications::types::NotificationError;
+
+/// Substitutes `{name}` placeholders in a template with field values.
+///
+/// `{{` and `}}` produce literal braces. An unterminated `{` is kept
+/// literally. Field values are inserted verbatim and never re-scanned for
+/// placeholders.
+///
+/// # Arguments
+///
+/// * `template` - Template text containing `{name}` placeholders
+/// * `fields` - `(name, value)` pairs; the first match for a name wins
+/// * `strict` - Whether a placeholder without a field is an error
+///
+/// # Returns
+///
+/// * `Ok(String)` - The filled-in text; in non-strict mode unmatched
+///   placeholders are left as written
+/// * `Err(NotificationError::MissingTemplateField)` - In strict mode, for the
+///   first placeholder without a field
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_notifications::notifications::functions::fnc_fill_template::fill_template;
+///
+/// let text = fill_template("{user} logged in at {time}", &[("user", "alice"), ("time", "14:30")], true);
+/// assert_eq!(text.unwrap(), "alice logged in at 14:30");
+///
+/// let text = fill_template("{{literal}} {missing}", &[("user", "alice")], false);
+/// assert_eq!(text.unwrap(), "{literal} {missing}");
+///
+/// assert!(fill_template("{missing}", &[("user", "alice")], true).is_err());
+/// ```
+pub fn fill_template<K, V>(
+    template: &str,
+    fields: &[(K, V)],
+    strict: bool,
+) -> Result<String, NotificationError>
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(pos) = rest.find(['{', '}']) {
+        out.push_str(&rest[..pos]);
+        let tail = &rest[pos..];
+
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            out.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+
+        if let Some(after) = tail.strip_prefix('}') {
+            // Lone closing brace
+            out.push('}');
+            rest = after;
+            continue;
+        }
+
+        match tail[1..].find(['{', '}']) {
+            Some(end) if tail[1 + end..].starts_with('}') => {
+                let name = &tail[1..1 + end];
+                match fields.iter().find(|(key, _)| key.as_ref() == name) {
+                    Some((_, value)) => out.push_str(value.as_ref()),
+                    None if strict => {
+                        return Err(NotificationError::MissingTemplateField(name.to_string()))
+                    }
+                    None => out.push_str(&tail[..end + 2]),
+                }
+                rest = &tail[end + 2..];
+            }
+            _ => {
+                // Unterminated placeholder: keep the brace literally
+                out.push('{');
+                rest = &tail[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    Ok(out)
+}
+
+// FILE: src/notifications/functions/fnc_fill_template.rs - Named placeholder substitution
+// END OF VERSION: 1.0.0
@@ -1,14 +1,23 @@
 // FILE: src/notifications/functions/fnc_generate_code.rs - Code generation for notifications
-// VERSION: 1.0.0
-// WCTX: Adding "show code" feature to demo
-// CLOG: Initial creation
+// VERSION: 1.30.0
+// WCTX: Dismiss on input
+// CLOG: Emit require_ack
 
-use std::time::Duration;
+use core::time::Duration;
 
 use ratatui::widgets::Padding;
 
 use crate::notifications::classes::cls_notification::Notification;
-use crate::notifications::types::{AutoDismiss, SizeConstraint, Timing};
+#[cfg(feature = "std")]
+use crate::notifications::classes::ManagerDefaults;
+#[cfg(feature = "std")]
+use crate::notifications::orc_manager::Notifications;
+#[cfg(feature = "std")]
+use crate::notifications::types::AnimationPhase;
+use crate::notifications::types::{
+    Anchor, AnchorCoord, AutoDismiss, ExpandOrigin, PathPoint, PlaceholderStyle, SizeConstraint,
+    Timing,
+};
 
 /// Generates Rust code to recreate the given notification configuration.
 ///
@@ -59,7 +68,7 @@ pub fn generate_code(notification: &Notification) -> String {
 
     // Anchor - default is BottomRight
     if notification.anchor() != defaults.anchor {
-        lines.push(format!("    .anchor(Anchor::{:?})", notification.anchor()));
+        lines.push(format!("    .anchor({})", format_anchor(notification.anchor())));
     }
 
     // Animation - default is Slide
@@ -70,6 +79,16 @@ pub fn generate_code(notification: &Notification) -> String {
         ));
     }
 
+    // Animation layers - default is none
+    if !notification.animation_layers().is_empty() {
+        let layers: Vec<String> = notification
+            .animation_layers()
+            .iter()
+            .map(|layer| format!("AnimationLayer::{layer:?}"))
+            .collect();
+        lines.push(format!("    .animation_layers([{}])", layers.join(", ")));
+    }
+
     // SlideDirection - default is Default
     if notification.slide_direction() != defaults.slide_direction {
         lines.push(format!(
@@ -98,6 +117,16 @@ pub fn generate_code(notification: &Notification) -> String {
         ));
     }
 
+    // TTL - default is none
+    if let Some(ttl) = notification.ttl() {
+        lines.push(format!("    .ttl({})", format_duration(ttl)));
+    }
+
+    // Acknowledgement - default is false
+    if notification.require_ack() {
+        lines.push("    .require_ack(true)".to_string());
+    }
+
     // MaxSize - default is Percentage(0.4), Percentage(0.2)
     let size_changed = notification.max_width() != defaults.max_width
         || notification.max_height() != defaults.max_height;
@@ -112,6 +141,31 @@ pub fn generate_code(notification: &Notification) -> String {
         }
     }
 
+    // MinSize - default is None
+    if let (Some(w), Some(h)) = (notification.min_width(), notification.min_height()) {
+        lines.push(format!(
+            "    .min_size({}, {})",
+            format_size_constraint(w),
+            format_size_constraint(h)
+        ));
+    }
+
+    // VerticalAlign - default is Top
+    if notification.vertical_align() != defaults.vertical_align {
+        lines.push(format!(
+            "    .vertical_align(VerticalAlign::{:?})",
+            notification.vertical_align()
+        ));
+    }
+
+    // Truncation - default wraps, with "…" as the ellipsis
+    if let Some(mode) = notification.truncate_mode() {
+        lines.push(format!("    .truncate_mode(TruncateMode::{:?})", mode));
+    }
+    if notification.ellipsis() != defaults.ellipsis() {
+        lines.push(format!("    .ellipsis({:?})", notification.ellipsis()));
+    }
+
     // Padding - default is Padding::horizontal(1)
     if notification.padding() != defaults.padding {
         lines.push(format!(
@@ -133,13 +187,18 @@ pub fn generate_code(notification: &Notification) -> String {
     }
 
     // Custom entry position - default is None
-    if let Some(pos) = notification.custom_entry_position() {
-        lines.push(format!("    .entry_position(Position::new({}, {}))", pos.x, pos.y));
+    if let Some(point) = notification.entry_point() {
+        lines.push(format!("    .{}", format_path_point("entry_position", point)));
     }
 
     // Custom exit position - default is None
-    if let Some(pos) = notification.custom_exit_position() {
-        lines.push(format!("    .exit_position(Position::new({}, {}))", pos.x, pos.y));
+    if let Some(point) = notification.exit_point() {
+        lines.push(format!("    .{}", format_path_point("exit_position", point)));
+    }
+
+    // Reverse exit - default is false
+    if notification.exit_reverse() {
+        lines.push("    .exit_reverse(true)".to_string());
     }
 
     // Fade effect - default is false
@@ -147,12 +206,253 @@ pub fn generate_code(notification: &Notification) -> String {
         lines.push(format!("    .fade({})", notification.fade_effect()));
     }
 
+    // Fade scope - default is All
+    if notification.fade_scope() != defaults.fade_scope {
+        lines.push(format!("    .fade_scope(FadeScope::{:?})", notification.fade_scope()));
+    }
+
+    // Layer - default is Below
+    if notification.layer() != defaults.layer {
+        lines.push(format!("    .layer(Layer::{:?})", notification.layer()));
+    }
+
+    // Title separator - default is false
+    if notification.title_separator() != defaults.title_separator {
+        lines.push(format!("    .title_separator({})", notification.title_separator()));
+    }
+
+    // Auto contrast - default is false
+    if notification.auto_contrast() {
+        lines.push("    .auto_contrast(true)".to_string());
+    }
+
+    // Transparent - default is false
+    if notification.transparent() {
+        lines.push("    .transparent(true)".to_string());
+    }
+
+    // Countdown - default is false
+    if notification.show_countdown() {
+        lines.push("    .show_countdown(true)".to_string());
+    }
+
+    // Elapsed time - default is false
+    if notification.show_elapsed() {
+        lines.push("    .show_elapsed(true)".to_string());
+    }
+
+    // Speed - default is 1.0, not applied to dwelling
+    if notification.speed() != defaults.speed() {
+        lines.push(format!("    .speed({:?})", notification.speed()));
+    }
+    if notification.speed_affects_dwell() {
+        lines.push("    .speed_affects_dwell(true)".to_string());
+    }
+
+    // Priority decay - default is none
+    if notification.decay() != defaults.decay() {
+        lines.push(format!("    .decay({:?})", notification.decay()));
+    }
+    if notification.evictable() != defaults.evictable() {
+        lines.push(format!("    .evictable(Evictability::{:?})", notification.evictable()));
+    }
+
+    // Delay - default is none
+    if !notification.delay().is_zero() {
+        lines.push(format!("    .delay({})", format_duration(notification.delay())));
+    }
+
+    // Placeholder - default is none
+    if let Some(placeholder) = notification.placeholder() {
+        lines.push(format!("    .placeholder({})", format_placeholder(placeholder)));
+    }
+
+    // Pages - default is the content alone, 2s per page, dismissed by auto_dismiss
+    if notification.pages().len() > 1 {
+        let pages: Vec<String> = notification
+            .pages()
+            .iter()
+            .map(|page| format!("\"{}\"", escape_string(&page.to_string())))
+            .collect();
+        lines.push(format!("    .pages([{}])", pages.join(", ")));
+    }
+    if notification.page_interval() != defaults.page_interval() {
+        let interval = format_duration(notification.page_interval());
+        lines.push(format!("    .page_interval({})", interval));
+    }
+    if notification.dismiss_after_cycle() {
+        lines.push("    .dismiss_after_cycle(true)".to_string());
+    }
+
+    // Expand origin - default is Center
+    if notification.expand_origin() != defaults.expand_origin {
+        lines.push(format!(
+            "    .expand_origin({})",
+            format_expand_origin(notification.expand_origin())
+        ));
+    }
+    if notification.expand_easing() != defaults.expand_easing {
+        lines.push(format!("    .expand_easing(Easing::{:?})", notification.expand_easing()));
+    }
+
+    // Big text - default is false
+    if notification.big_text() {
+        lines.push("    .big_text(true)".to_string());
+    }
+
+    // Live content - closures cannot be reproduced, so emit a placeholder
+    if notification.has_live_content() {
+        lines.push("    .live_content(Box::new(|| /* content */ String::new()))".to_string());
+    }
+
     // End with build()
     lines.push("    .build()".to_string());
 
     lines.join("\n")
 }
 
+/// Generates Rust code that recreates the notification as a manager with
+/// `defaults` shows it.
+///
+/// `generate_code` leaves out values the builder defaults to, but a manager
+/// with custom [`ManagerDefaults`] fills those in differently. This variant
+/// first applies `defaults` (the anchor of a notification built without one,
+/// `Timing::Auto` durations and `AutoDismiss::After(Duration::ZERO)`), then
+/// emits every value that differs from a plain manager's, so the snippet
+/// behaves the same in a fresh project.
+///
+/// # Arguments
+///
+/// * `notification` - The notification to generate code for
+/// * `defaults` - The defaults of the manager showing it
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui_notifications::notifications::{
+///     generate_code_with_context, Anchor, ManagerDefaults, Notification,
+/// };
+///
+/// let defaults = ManagerDefaults { default_anchor: Anchor::TopRight, ..ManagerDefaults::default() };
+/// let notif = Notification::new("Hello!").build().unwrap();
+///
+/// let code = generate_code_with_context(&notif, &defaults);
+/// assert!(code.contains(".anchor(Anchor::TopRight)"));
+/// ```
+#[cfg(feature = "std")]
+pub fn generate_code_with_context(notification: &Notification, defaults: &ManagerDefaults) -> String {
+    let plain = ManagerDefaults::default();
+    let resolve = |timing: Timing, default: Duration, plain: Duration| match timing {
+        Timing::Auto if default != plain => Timing::Fixed(default),
+        timing => timing,
+    };
+
+    let mut effective = notification.clone();
+    effective.resolve_anchor(defaults.default_anchor);
+    effective.slide_in_timing = resolve(
+        effective.slide_in_timing,
+        defaults.default_entry_duration,
+        plain.default_entry_duration,
+    );
+    effective.dwell_timing = resolve(
+        effective.dwell_timing,
+        defaults.default_dwell_duration,
+        plain.default_dwell_duration,
+    );
+    effective.slide_out_timing = resolve(
+        effective.slide_out_timing,
+        defaults.default_exit_duration,
+        plain.default_exit_duration,
+    );
+    if effective.auto_dismiss == AutoDismiss::After(Duration::ZERO)
+        && defaults.default_display_time != plain.default_display_time
+    {
+        effective.auto_dismiss = AutoDismiss::After(defaults.default_display_time);
+    }
+
+    generate_code(&effective)
+}
+
+/// Generates Rust code that recreates the manager's current scene.
+///
+/// Emits the manager setup (limits, overflow, layout and color settings that
+/// differ from `Notifications::new()`), then one `add` call per active
+/// notification, oldest first, each built with
+/// [`generate_code_with_context`]. Hidden, parked and finished notifications
+/// are left out. Themes, strings and clocks are not reproduced.
+///
+/// # Arguments
+///
+/// * `manager` - The manager whose notifications to capture
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui_notifications::notifications::{generate_scene_code, Notification, Notifications};
+///
+/// let mut manager = Notifications::new().max_concurrent(Some(3));
+/// manager.add(Notification::new("Saved").build().unwrap()).unwrap();
+///
+/// let code = generate_scene_code(&manager);
+/// assert!(code.contains(".max_concurrent(Some(3))"));
+/// assert!(code.contains("manager.add("));
+/// ```
+#[cfg(feature = "std")]
+pub fn generate_scene_code(manager: &Notifications) -> String {
+    let plain = Notifications::new();
+    let mut setup = Vec::new();
+
+    if manager.max_concurrent != plain.max_concurrent {
+        setup.push(format!("    .max_concurrent({:?})", manager.max_concurrent));
+    }
+    if manager.max_total != plain.max_total {
+        setup.push(format!("    .max_total({:?})", manager.max_total));
+    }
+    if manager.overflow != plain.overflow {
+        setup.push(format!("    .overflow(Overflow::{:?})", manager.overflow));
+    }
+    if manager.count_exiting != plain.count_exiting {
+        setup.push(format!("    .count_exiting({})", manager.count_exiting));
+    }
+    if manager.sort_by != plain.sort_by {
+        setup.push(format!("    .render_sort(SortBy::{:?})", manager.sort_by));
+    }
+    if manager.stack_layout != plain.stack_layout {
+        setup.push(format!("    .stack_layout(StackLayout::{:?})", manager.stack_layout));
+    }
+    if manager.color_mode != plain.color_mode {
+        setup.push(format!("    .color_mode(ColorMode::{:?})", manager.color_mode));
+    }
+
+    let mut lines = Vec::new();
+    if setup.is_empty() {
+        lines.push("let mut manager = Notifications::new();".to_string());
+    } else {
+        lines.push("let mut manager = Notifications::new()".to_string());
+        lines.extend(setup);
+        if let Some(last) = lines.last_mut() {
+            last.push(';');
+        }
+    }
+
+    for id in manager.ids_oldest_first() {
+        let state = &manager.states[&id];
+        if state.hidden || state.current_phase == AnimationPhase::Finished {
+            continue;
+        }
+        let code = generate_code_with_context(&state.notification, &manager.defaults);
+        lines.push(String::new());
+        lines.push("manager.add(".to_string());
+        lines.extend(code.lines().map(|line| format!("    {line}")));
+        if let Some(last) = lines.last_mut() {
+            last.push_str("?,");
+        }
+        lines.push(")?;".to_string());
+    }
+
+    lines.join("\n")
+}
+
 /// Escapes a string for use in Rust code.
 fn escape_string(s: &str) -> String {
     s.replace('\\', "\\\\")
@@ -162,6 +462,31 @@ fn escape_string(s: &str) -> String {
         .replace('\t', "\\t")
 }
 
+/// Formats an Anchor value as Rust code.
+fn format_anchor(anchor: Anchor) -> String {
+    match anchor {
+        Anchor::Custom { x, y } => format!(
+            "Anchor::Custom {{ x: {}, y: {} }}",
+            format_anchor_coord(x),
+            format_anchor_coord(y)
+        ),
+        other => format!("Anchor::{:?}", other),
+    }
+}
+
+/// Formats an AnchorCoord value as Rust code.
+fn format_anchor_coord(coord: AnchorCoord) -> String {
+    format!("AnchorCoord::{:?}", coord)
+}
+
+/// Formats a PathPoint as a call to `method` or its `_pct` variant.
+fn format_path_point(method: &str, point: PathPoint) -> String {
+    match point {
+        PathPoint::Absolute(pos) => format!("{}(Position::new({}, {}))", method, pos.x, pos.y),
+        PathPoint::Percent { x, y } => format!("{}_pct({:?}, {:?})", method, x, y),
+    }
+}
+
 /// Formats a Timing value as Rust code.
 fn format_timing(timing: Timing) -> String {
     match timing {
@@ -172,30 +497,43 @@ fn format_timing(timing: Timing) -> String {
 
 /// Formats a Duration as Timing::Fixed code.
 fn format_duration_as_timing(d: Duration) -> String {
+    format!("Timing::Fixed({})", format_duration(d))
+}
+
+/// Formats a Duration as Rust code.
+fn format_duration(d: Duration) -> String {
     let millis = d.as_millis();
-    if millis % 1000 == 0 {
-        format!("Timing::Fixed(Duration::from_secs({}))", millis / 1000)
+    if millis.is_multiple_of(1000) {
+        format!("Duration::from_secs({})", millis / 1000)
     } else {
-        format!("Timing::Fixed(Duration::from_millis({}))", millis)
+        format!("Duration::from_millis({})", millis)
     }
 }
 
 /// Formats an AutoDismiss value as Rust code.
+///
+/// Deadlines are runtime instants and cannot be reproduced literally, so
+/// they are emitted as a `todo!` the user must replace; pasted as is, the
+/// code panics rather than dismissing the notification at once.
 fn format_auto_dismiss(ad: AutoDismiss) -> String {
     match ad {
         AutoDismiss::Never => "AutoDismiss::Never".to_string(),
-        AutoDismiss::After(d) => {
-            let millis = d.as_millis();
-            if millis % 1000 == 0 {
-                format!("AutoDismiss::After(Duration::from_secs({}))", millis / 1000)
-            } else {
-                format!("AutoDismiss::After(Duration::from_millis({}))", millis)
-            }
-        }
+        AutoDismiss::AtDeadline(_) => "AutoDismiss::AtDeadline(todo!(\"deadline\"))".to_string(),
+        AutoDismiss::After(d) => format!("AutoDismiss::After({})", format_duration(d)),
     }
 }
 
 /// Formats a SizeConstraint as Rust code.
+fn format_placeholder(placeholder: &PlaceholderStyle) -> String {
+    match placeholder {
+        PlaceholderStyle::Skeleton => "PlaceholderStyle::Skeleton".to_string(),
+        PlaceholderStyle::Spinner => "PlaceholderStyle::Spinner".to_string(),
+        PlaceholderStyle::Text(text) => {
+            format!("PlaceholderStyle::Text(\"{}\".to_string())", escape_string(text))
+        }
+    }
+}
+
 fn format_size_constraint(sc: SizeConstraint) -> String {
     match sc {
         SizeConstraint::Absolute(n) => format!("SizeConstraint::Absolute({})", n),
@@ -203,6 +541,17 @@ fn format_size_constraint(sc: SizeConstraint) -> String {
     }
 }
 
+/// Formats an ExpandOrigin as Rust code.
+fn format_expand_origin(origin: ExpandOrigin) -> String {
+    match origin {
+        ExpandOrigin::Center => "ExpandOrigin::Center".to_string(),
+        ExpandOrigin::Anchor => "ExpandOrigin::Anchor".to_string(),
+        ExpandOrigin::Position(pos) => {
+            format!("ExpandOrigin::Position(Position::new({}, {}))", pos.x, pos.y)
+        }
+    }
+}
+
 /// Formats a Padding as Rust code.
 fn format_padding(p: Padding) -> String {
     // Padding fields are: top, right, bottom, left
@@ -220,4 +569,4 @@ fn format_padding(p: Padding) -> String {
 }
 
 // FILE: src/notifications/functions/fnc_generate_code.rs - Code generation for notifications
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.30.0
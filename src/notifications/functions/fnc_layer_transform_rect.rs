@@ -0,0 +1,70 @@
+// FILE: src/notifications/functions/fnc_layer_transform_rect.rs - Applies a composed layer transform to a rect
+// VERSION: 1.0.0
+// WCTX: Composed animation layers
+// CLOG: Initial creation
+
+use crate::notifications::types::LayerTransform;
+use crate::shared_utils::math::{clamp_progress, round_to_u16};
+use ratatui::prelude::*;
+
+/// Applies a layer transform to a settled rect.
+///
+/// The rect is scaled around its center, then offset, then clipped to the
+/// frame. Scale is clamped to 0.0..=1.0 and non-finite offsets count as
+/// zero. Any scale above zero keeps at least one cell.
+///
+/// # Arguments
+///
+/// * `rect` - The settled rect of the notification
+/// * `frame_area` - The visible frame area
+/// * `transform` - The composed transform of every animation layer
+///
+/// # Returns
+///
+/// The transformed rect clipped to the frame, or an empty rect when nothing
+/// is visible
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::prelude::Rect;
+/// use ratatui_notifications::notifications::functions::fnc_layer_transform_rect::layer_transform_rect;
+/// use ratatui_notifications::notifications::types::LayerTransform;
+///
+/// let rect = Rect::new(10, 10, 20, 8);
+/// let frame_area = Rect::new(0, 0, 80, 24);
+/// let half = LayerTransform { scale: 0.5, offset_x: 5.0, ..LayerTransform::IDENTITY };
+/// assert_eq!(layer_transform_rect(rect, frame_area, half), Rect::new(20, 12, 10, 4));
+/// ```
+pub fn layer_transform_rect(rect: Rect, frame_area: Rect, transform: LayerTransform) -> Rect {
+    let scale = clamp_progress(transform.scale);
+    let finite = |offset: f32| if offset.is_finite() { offset } else { 0.0 };
+
+    let min_size = if scale > 0.0 { 1.0 } else { 0.0 };
+    let width = (rect.width as f32 * scale).round().max(min_size);
+    let height = (rect.height as f32 * scale).round().max(min_size);
+    let x1 = rect.x as f32 + (rect.width as f32 - width) / 2.0 + finite(transform.offset_x);
+    let y1 = rect.y as f32 + (rect.height as f32 - height) / 2.0 + finite(transform.offset_y);
+
+    // Clip in f32 so boxes partly left of or above the frame keep their visible part
+    let left = x1.max(frame_area.x as f32);
+    let top = y1.max(frame_area.y as f32);
+    let right = (x1 + width).min(frame_area.right() as f32);
+    let bottom = (y1 + height).min(frame_area.bottom() as f32);
+
+    let clipped = Rect::new(
+        round_to_u16(left),
+        round_to_u16(top),
+        round_to_u16(right - left),
+        round_to_u16(bottom - top),
+    )
+    .intersection(frame_area);
+    if clipped.is_empty() {
+        Rect::default()
+    } else {
+        clipped
+    }
+}
+
+// FILE: src/notifications/functions/fnc_layer_transform_rect.rs - Applies a composed layer transform to a rect
+// END OF VERSION: 1.0.0
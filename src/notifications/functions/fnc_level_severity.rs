@@ -0,0 +1,43 @@
+// FILE: src/notifications/functions/fnc_level_severity.rs - Severity rank for notification level
+// VERSION: 1.0.0
+// WCTX: Severity-ordered rendering across anchors
+// CLOG: Initial creation
+
+use crate::notifications::types::Level;
+
+/// Returns the severity rank of a notification level.
+///
+/// Higher ranks are more severe. Notifications without a level rank below
+/// `Trace`.
+///
+/// # Arguments
+///
+/// * `level` - Optional notification level
+///
+/// # Returns
+///
+/// Rank from 0 (no level) to 5 (`Error`)
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_notifications::notifications::functions::fnc_level_severity::level_severity;
+/// use ratatui_notifications::notifications::types::Level;
+///
+/// assert!(level_severity(Some(Level::Error)) > level_severity(Some(Level::Warn)));
+/// assert!(level_severity(Some(Level::Warn)) > level_severity(Some(Level::Info)));
+/// assert!(level_severity(Some(Level::Trace)) > level_severity(None));
+/// ```
+pub fn level_severity(level: Option<Level>) -> u8 {
+    match level {
+        None => 0,
+        Some(Level::Trace) => 1,
+        Some(Level::Debug) => 2,
+        Some(Level::Info) => 3,
+        Some(Level::Warn) => 4,
+        Some(Level::Error) => 5,
+    }
+}
+
+// FILE: src/notifications/functions/fnc_level_severity.rs - Severity rank for notification level
+// END OF VERSION: 1.0.0
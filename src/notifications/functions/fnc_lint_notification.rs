@@ -0,0 +1,133 @@
+// FILE: src/notifications/functions/fnc_lint_notification.rs - Non-fatal configuration lints
+// VERSION: 1.1.0
+// WCTX: Countdown display
+// CLOG: Lint show_countdown without a timer
+
+use crate::notifications::classes::Notification;
+use crate::notifications::types::{
+    Anchor, Animation, AutoDismiss, BuildWarning, BuildWarningKind, SizeConstraint, SlideDirection, Timing,
+};
+
+/// Finds configurations that are legal but almost certainly unintended.
+///
+/// Percentage sizes and `Timing::Auto` durations depend on the frame or the
+/// manager and are not checked.
+///
+/// # Arguments
+///
+/// * `notification` - The notification to check
+///
+/// # Returns
+///
+/// One warning per problem found; empty for clean configurations
+///
+/// # Examples
+///
+/// ```ignore
+/// // Internal function - use through NotificationBuilder::build_with_warnings
+/// let warnings = lint_notification(&notification);
+/// ```
+pub fn lint_notification(notification: &Notification) -> Vec<BuildWarning> {
+    let mut warnings = Vec::new();
+    let mut warn = |kind, message: String| warnings.push(BuildWarning { kind, message });
+
+    // Slides entering from the far side of the screen
+    if notification.animation == Animation::Slide
+        && notification.custom_entry_position.is_none()
+        && slide_opposes_anchor(notification.slide_direction, notification.anchor)
+    {
+        warn(
+            BuildWarningKind::SlideCrossesScreen,
+            format!(
+                "{:?} slide with a {:?} anchor crosses the whole screen; \
+                 set a custom entry position or pick a direction from the anchor's side",
+                notification.slide_direction, notification.anchor
+            ),
+        );
+    }
+
+    // Absolute limits too small for a single cell of content
+    let border = if notification.border_type.is_some() { 2 } else { 0 };
+    let padding = notification.padding;
+    let min_width = 1 + border + padding.left + padding.right;
+    let min_height = 1 + border + padding.top + padding.bottom;
+    for (axis, constraint, minimum) in [
+        ("width", notification.max_width, min_width),
+        ("height", notification.max_height, min_height),
+    ] {
+        if let Some(SizeConstraint::Absolute(max)) = constraint {
+            if max < minimum {
+                warn(
+                    BuildWarningKind::MaxSizeBelowMinimum,
+                    format!(
+                        "max {} {} is below the {} needed for border, padding and one cell of content",
+                        axis, max, minimum
+                    ),
+                );
+            }
+        }
+    }
+
+    // Dwell shorter than the animations around it
+    if let (Timing::Fixed(entry), Timing::Fixed(dwell), Timing::Fixed(exit)) = (
+        notification.slide_in_timing,
+        notification.dwell_timing,
+        notification.slide_out_timing,
+    ) {
+        if dwell < entry + exit {
+            warn(
+                BuildWarningKind::DwellShorterThanAnimations,
+                format!(
+                    "dwell of {}ms is shorter than the {}ms entry plus {}ms exit",
+                    dwell.as_millis(),
+                    entry.as_millis(),
+                    exit.as_millis()
+                ),
+            );
+        }
+    }
+
+    // Fade flag on an animation that already fades
+    if notification.fade_effect && notification.animation == Animation::Fade {
+        warn(
+            BuildWarningKind::RedundantFade,
+            "fade(true) has no effect with Animation::Fade, which always fades".to_string(),
+        );
+    }
+
+    // Countdown with nothing to count down
+    if notification.show_countdown && !matches!(notification.auto_dismiss, AutoDismiss::After(_)) {
+        warn(
+            BuildWarningKind::CountdownWithoutTimer,
+            "show_countdown(true) has no effect without AutoDismiss::After".to_string(),
+        );
+    }
+
+    warnings
+}
+
+/// Whether a slide enters from the side of the frame opposite the anchor.
+fn slide_opposes_anchor(direction: SlideDirection, anchor: Anchor) -> bool {
+    use SlideDirection::*;
+
+    let from_left = matches!(direction, FromLeft | FromTopLeft | FromBottomLeft);
+    let from_right = matches!(direction, FromRight | FromTopRight | FromBottomRight);
+    let from_top = matches!(direction, FromTop | FromTopLeft | FromTopRight);
+    let from_bottom = matches!(direction, FromBottom | FromBottomLeft | FromBottomRight);
+
+    let anchor_left = matches!(anchor, Anchor::TopLeft | Anchor::MiddleLeft | Anchor::BottomLeft);
+    let anchor_right = matches!(anchor, Anchor::TopRight | Anchor::MiddleRight | Anchor::BottomRight);
+    let anchor_top = matches!(anchor, Anchor::TopLeft | Anchor::TopCenter | Anchor::TopRight);
+    let anchor_bottom = matches!(
+        anchor,
+        Anchor::BottomLeft | Anchor::BottomCenter | Anchor::BottomRight
+    );
+
+    (from_left && anchor_right)
+        || (from_right && anchor_left)
+        || (from_top && anchor_bottom)
+        || (from_bottom && anchor_top)
+}
+
+// FILE: src/notifications/functions/fnc_lint_notification.rs - Non-fatal configuration lints
+// END OF VERSION: 1.1.0
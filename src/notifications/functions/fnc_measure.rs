@@ -0,0 +1,254 @@
+// FILE: src/notifications/functions/fnc_measure.rs - Measure text as a notification would lay it out
+// VERSION: 1.2.0
+// WCTX: Wrapped line measurement
+// CLOG: Added measure_lines and split_to_fit
+
+use crate::notifications::functions::fnc_truncate_text::truncate_text;
+use crate::notifications::types::MeasureOptions;
+use ratatui::prelude::*;
+use crate::notifications::functions::fnc_wrap_text::{wrapped, wrapped_rows};
+use ratatui::widgets::{Block, Borders, Padding};
+use std::borrow::Cow;
+
+/// Measures the box a notification with `content` would take.
+///
+/// Uses the same layout as the manager, so callers can size panels or
+/// reserve space without building a notification. `calculate_size` delegates
+/// here, so the two always agree.
+///
+/// # Arguments
+///
+/// * `content` - Message text; newlines start new lines
+/// * `options` - Constraints, border, padding, title and wrap mode
+///
+/// # Returns
+///
+/// A tuple `(width, height)` including borders and padding.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::layout::Rect;
+/// use ratatui_notifications::notifications::{measure, MeasureOptions, SizeConstraint};
+///
+/// let options = MeasureOptions {
+///     max_width: Some(SizeConstraint::Absolute(40)),
+///     ..MeasureOptions::new(Rect::new(0, 0, 120, 40))
+/// };
+/// // Ten columns of text, one column of padding each side, two border cells
+/// assert_eq!(measure("Build done", &options), (14, 3));
+/// ```
+///
+/// Long text wraps at the maximum width:
+///
+/// ```
+/// use ratatui::layout::Rect;
+/// use ratatui_notifications::notifications::{measure, MeasureOptions, SizeConstraint};
+///
+/// let options = MeasureOptions {
+///     max_width: Some(SizeConstraint::Absolute(10)),
+///     border_type: None,
+///     ..MeasureOptions::new(Rect::new(0, 0, 120, 40))
+/// };
+/// // Eight columns inside the padding: one word per line
+/// assert_eq!(measure("alpha beta gamma", &options), (10, 3));
+/// ```
+pub fn measure(content: &str, options: &MeasureOptions) -> (u16, u16) {
+    measure_text(
+        Cow::Owned(Text::raw(content.to_string())),
+        options,
+        title_width(options),
+    )
+}
+
+/// Returns the rows `content` wraps to inside the box [`measure`] sizes.
+///
+/// The rows come from the renderer's own wrapping, at the content width the
+/// box gets, so an app can see how tall a message will be before adding it.
+/// Truncated lines (see `truncate_mode`) appear as drawn, ellipsis included.
+///
+/// # Arguments
+///
+/// * `content` - Message text; newlines start new lines
+/// * `options` - Constraints, border, padding, title and wrap mode
+///
+/// # Returns
+///
+/// One string per content row, without borders or padding
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::layout::Rect;
+/// use ratatui_notifications::notifications::{measure_lines, MeasureOptions, SizeConstraint};
+///
+/// let options = MeasureOptions {
+///     max_width: Some(SizeConstraint::Absolute(10)),
+///     border_type: None,
+///     ..MeasureOptions::new(Rect::new(0, 0, 120, 40))
+/// };
+/// assert_eq!(measure_lines("alpha beta gamma", &options), vec!["alpha", "beta", "gamma"]);
+/// ```
+pub fn measure_lines(content: &str, options: &MeasureOptions) -> Vec<String> {
+    let laid_out = lay_out(Cow::Owned(Text::raw(content.to_string())), options, title_width(options));
+    wrapped_rows(&laid_out.content, laid_out.inner_width)
+}
+
+/// Splits `content` into pieces whose boxes are at most `max_height` tall.
+///
+/// The content is wrapped as [`measure_lines`] reports it, then cut between
+/// rows, so each piece measures at most `max_height` with the same options.
+/// A `max_height` too small for the border and padding still puts one row in
+/// each piece.
+///
+/// # Arguments
+///
+/// * `content` - Message text; newlines start new lines
+/// * `options` - Constraints, border, padding, title and wrap mode
+/// * `max_height` - Tallest box a piece may need, borders and padding included
+///
+/// # Returns
+///
+/// The pieces in order, each one notification's content
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::layout::Rect;
+/// use ratatui_notifications::notifications::{split_to_fit, MeasureOptions, SizeConstraint};
+///
+/// let options = MeasureOptions {
+///     max_width: Some(SizeConstraint::Absolute(10)),
+///     border_type: None,
+///     ..MeasureOptions::new(Rect::new(0, 0, 120, 40))
+/// };
+/// // Without a border, a two-row box holds two of the three rows
+/// assert_eq!(split_to_fit("alpha beta gamma", &options, 2), vec!["alpha\nbeta", "gamma"]);
+/// ```
+pub fn split_to_fit(content: &str, options: &MeasureOptions, max_height: u16) -> Vec<String> {
+    let laid_out = lay_out(Cow::Owned(Text::raw(content.to_string())), options, title_width(options));
+    let chrome = laid_out.border_offset + laid_out.padding.top + laid_out.padding.bottom;
+    let rows_per_piece = usize::from(max_height.saturating_sub(chrome).max(1));
+    wrapped_rows(&laid_out.content, laid_out.inner_width)
+        .chunks(rows_per_piece)
+        .map(|rows| rows.join("\n"))
+        .collect()
+}
+
+/// Cells the title needs on the top border.
+fn title_width(options: &MeasureOptions) -> u16 {
+    options.title.as_ref().map_or(0, |t| t.width()) as u16
+}
+
+/// Box width and content as the layout settles them, before the height.
+struct LaidOut {
+    width: u16,
+    inner_width: u16,
+    border_offset: u16,
+    padding: Padding,
+    content: Text<'static>,
+}
+
+/// Helper to size the box width and fit the content to it
+fn lay_out(content: Cow<'_, Text<'static>>, options: &MeasureOptions, title_width: u16) -> LaidOut {
+    let frame_area = options.frame_area;
+
+    // 1. Get border dimensions based on border_type
+    let border_offset = if options.border_type.is_some() { 2 } else { 0 };
+
+    // 2. Get padding dimensions (the title separator takes one extra top row,
+    //    and only appears when there is both a title and content)
+    let separator = options.title_separator && options.title.is_some() && content.width() > 0;
+    let mut padding = options.padding;
+    padding.top += u16::from(separator);
+    let h_padding = padding.left + padding.right;
+
+    // 3. Calculate minimum width (at least 3)
+    let min_width = (1 + h_padding + border_offset).max(3);
+
+    // 4. Apply max_width constraint (Percentage or Absolute, see SizeConstraint::resolve)
+    let max_width_constraint = options
+        .max_width
+        .map(|c| c.resolve(frame_area.width))
+        .unwrap_or(frame_area.width)
+        .max(min_width);
+
+    // 5. Calculate intrinsic width from content
+    let content_max_line_width = content.lines.iter().map(|l| l.width()).max().unwrap_or(0) as u16;
+
+    let requested_min_width = options
+        .min_width
+        .map_or(0, |c| c.resolve(frame_area.width));
+
+    let intrinsic_width = (content_max_line_width.max(title_width) + border_offset + h_padding)
+        .max(min_width)
+        .max(requested_min_width);
+
+    let width = intrinsic_width.min(max_width_constraint);
+
+    // Wrapping uses the width inside the border and padding
+    let inner_width = width.saturating_sub(border_offset + h_padding).max(1);
+
+    // Truncated lines take one row each
+    let content = match options.truncate_mode {
+        Some(mode) => truncate_text(&content, inner_width, mode, &options.ellipsis),
+        None => content.into_owned(),
+    };
+
+    LaidOut { width, inner_width, border_offset, padding, content }
+}
+
+/// Measures the box for laid-out content.
+///
+/// # Arguments
+///
+/// * `content` - Content as laid out (e.g. after big text)
+/// * `options` - Layout settings
+/// * `title_width` - Cells the top border needs for the title and its extras
+pub(crate) fn measure_text(
+    content: Cow<'_, Text<'static>>,
+    options: &MeasureOptions,
+    title_width: u16,
+) -> (u16, u16) {
+    let frame_area = options.frame_area;
+    let LaidOut { width, inner_width, border_offset, padding, content } =
+        lay_out(content, options, title_width);
+
+    // 6. Apply max_height constraint (at least 3)
+    let min_height = (1 + padding.top + padding.bottom + border_offset).max(3);
+    let max_height_constraint = options
+        .max_height
+        .map(|c| c.resolve(frame_area.height))
+        .unwrap_or(frame_area.height)
+        .max(min_height);
+
+    // 7. Measure wrapped height, including borders and padding
+    let mut temp_block = Block::default();
+    if let Some(border_type) = options.border_type {
+        temp_block = temp_block.borders(Borders::ALL).border_type(border_type);
+    }
+    if let Some(title) = &options.title {
+        temp_block = temp_block.title(title.clone());
+    }
+    temp_block = temp_block.padding(padding);
+
+    // line_count adds the block's vertical space but wraps at the width it is
+    // given, so pass the inner width
+    let measured_height = wrapped(content)
+        .block(temp_block)
+        .line_count(inner_width)
+        .min(u16::MAX as usize) as u16;
+
+    // 8. Return (width, height) tuple; min_size grows, max constraints still cap
+    let requested_min_height = options
+        .min_height
+        .map_or(0, |c| c.resolve(frame_area.height));
+    let final_height = measured_height
+        .max(min_height)
+        .max(requested_min_height)
+        .min(max_height_constraint);
+    (width, final_height)
+}
+
+// FILE: src/notifications/functions/fnc_measure.rs - Measure text as a notification would lay it out
+// END OF VERSION: 1.2.0
@@ -0,0 +1,56 @@
+// FILE: src/notifications/functions/fnc_move_calculate_rect.rs - Rect calculation for anchor moves
+// VERSION: 1.1.0
+// WCTX: Non-finite progress guards
+// CLOG: Clamp progress and rect math against non-finite values
+
+use crate::shared_utils::math::{clamp_progress, ease_out_quad, lerp, round_to_u16};
+use ratatui::prelude::*;
+
+/// Calculates the visible rectangle while a notification moves between anchors.
+///
+/// Position and size ease out from `from` (where the notification was drawn
+/// when the move started) to `to` (its resting rect at the new anchor). The
+/// result is clipped to the frame.
+///
+/// # Arguments
+///
+/// * `from` - The rect the move starts from
+/// * `to` - The rect the move ends at
+/// * `frame_area` - The frame area; the result is clipped to it
+/// * `progress` - The move progress (0.0 to 1.0)
+///
+/// # Returns
+///
+/// The interpolated rectangle, or an empty rect if it lies outside the frame
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::prelude::*;
+/// use ratatui_notifications::notifications::functions::fnc_move_calculate_rect::move_calculate_rect;
+///
+/// let from = Rect::new(60, 40, 20, 5);
+/// let to = Rect::new(0, 0, 20, 5);
+/// let frame_area = Rect::new(0, 0, 80, 45);
+///
+/// assert_eq!(move_calculate_rect(from, to, frame_area, 0.0), from);
+/// assert_eq!(move_calculate_rect(from, to, frame_area, 1.0), to);
+/// ```
+pub fn move_calculate_rect(from: Rect, to: Rect, frame_area: Rect, progress: f32) -> Rect {
+    let eased = ease_out_quad(clamp_progress(progress));
+
+    let x = round_to_u16(lerp(from.x as f32, to.x as f32, eased));
+    let y = round_to_u16(lerp(from.y as f32, to.y as f32, eased));
+    let width = round_to_u16(lerp(from.width as f32, to.width as f32, eased));
+    let height = round_to_u16(lerp(from.height as f32, to.height as f32, eased));
+
+    let clipped = Rect::new(x, y, width, height).intersection(frame_area);
+    if clipped.is_empty() {
+        Rect::default()
+    } else {
+        clipped
+    }
+}
+
+// FILE: src/notifications/functions/fnc_move_calculate_rect.rs - Rect calculation for anchor moves
+// END OF VERSION: 1.1.0
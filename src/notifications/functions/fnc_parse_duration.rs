@@ -0,0 +1,44 @@
+// FILE: src/notifications/functions/fnc_parse_duration.rs - Human-readable duration parsing
+// VERSION: 1.0.0
+// WCTX: TOML scenario player
+// CLOG: Initial creation
+
+use core::time::Duration;
+
+/// Parses a duration written as a number with an `s` or `ms` suffix.
+///
+/// Used for the times in scenario files (`at = "0.5s"`). Fractions are
+/// allowed for both units; surrounding whitespace is ignored.
+///
+/// # Arguments
+///
+/// * `text` - The duration, e.g. `"1.5s"` or `"300ms"`
+///
+/// # Returns
+///
+/// The duration, or `None` if the text is not a finite, non-negative number
+/// followed by `s` or `ms`
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ratatui_notifications::notifications::functions::fnc_parse_duration::parse_duration;
+///
+/// assert_eq!(parse_duration("0.5s"), Some(Duration::from_millis(500)));
+/// assert_eq!(parse_duration("250ms"), Some(Duration::from_millis(250)));
+/// assert_eq!(parse_duration("5"), None);
+/// ```
+pub fn parse_duration(text: &str) -> Option<Duration> {
+    let text = text.trim();
+    let (number, scale) = if let Some(number) = text.strip_suffix("ms") {
+        (number, 0.001)
+    } else {
+        (text.strip_suffix('s')?, 1.0)
+    };
+    let value: f64 = number.trim().parse().ok()?;
+    Duration::try_from_secs_f64(value * scale).ok()
+}
+
+// FILE: src/notifications/functions/fnc_parse_duration.rs - Human-readable duration parsing
+// END OF VERSION: 1.0.0
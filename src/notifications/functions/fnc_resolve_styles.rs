@@ -1,25 +1,21 @@
 // FILE: src/notifications/functions/fnc_resolve_styles.rs - Resolves notification styles based on level and custom overrides
-// VERSION: 1.0.0
-// WCTX: TDD implementation of OFPF notification functions
-// CLOG: Initial creation
+// VERSION: 1.2.0
+// WCTX: Public level styles
+// CLOG: Resolve the level icon
 
-use crate::notifications::types::Level;
-use ratatui::style::{Color, Style};
+use crate::notifications::classes::Theme;
+use crate::notifications::functions::fnc_get_level_icon::get_level_icon;
+use crate::notifications::types::{Level, ResolvedStyles};
+use ratatui::style::Style;
 
 // Default styles
 const DEFAULT_BLOCK_STYLE: Style = Style::new();
 const DEFAULT_TITLE_STYLE: Style = Style::new();
-const DEFAULT_BORDER_STYLE: Style = Style::new().fg(Color::DarkGray);
-
-// Level-based border styles
-const INFO_BORDER_STYLE: Style = Style::new().fg(Color::Green);
-const WARN_BORDER_STYLE: Style = Style::new().fg(Color::Yellow);
-const ERROR_BORDER_STYLE: Style = Style::new().fg(Color::Red);
-const DEBUG_BORDER_STYLE: Style = Style::new().fg(Color::Blue);
-const TRACE_BORDER_STYLE: Style = Style::new().fg(Color::Magenta);
 
 /// Resolves the final styles for block, border, and title based on level and custom overrides.
 ///
+/// Uses the default theme; see [`resolve_themed_styles`] for custom themes.
+///
 /// # Arguments
 ///
 /// * `level` - Optional notification level that determines default border/title colors
@@ -44,41 +40,74 @@ pub fn resolve_styles(
     border_style: Option<Style>,
     title_style: Option<Style>,
 ) -> (Style, Style, Style) {
-    let mut final_block_style = DEFAULT_BLOCK_STYLE;
-    let mut final_border_style = DEFAULT_BORDER_STYLE;
-    let mut final_title_style = DEFAULT_TITLE_STYLE;
+    let resolved =
+        resolve_themed_styles(level, &Theme::DEFAULT, block_style, border_style, title_style);
+    (resolved.block, resolved.border, resolved.title)
+}
+
+/// Resolves the final styles for a notification against a theme.
+///
+/// Follows the same order as [`resolve_styles`], taking level styling from
+/// `theme`, and additionally reports the theme's border type and title prefix
+/// and icon for the level.
+///
+/// # Arguments
+///
+/// * `level` - Optional notification level
+/// * `theme` - Theme providing level styling
+/// * `block_style` - Optional custom block style
+/// * `border_style` - Optional custom border style
+/// * `title_style` - Optional custom title style
+///
+/// # Returns
+///
+/// The resolved styles, border-type override, title prefix, and icon
+pub fn resolve_themed_styles(
+    level: Option<Level>,
+    theme: &Theme,
+    block_style: Option<Style>,
+    border_style: Option<Style>,
+    title_style: Option<Style>,
+) -> ResolvedStyles {
+    let mut resolved = ResolvedStyles {
+        block: DEFAULT_BLOCK_STYLE,
+        border: theme.default_border_style,
+        title: DEFAULT_TITLE_STYLE,
+        border_type: None,
+        title_prefix: None,
+        icon: get_level_icon(level),
+    };
 
     // Apply level-based styling
     if let Some(lvl) = level {
-        let level_border_style = match lvl {
-            Level::Info => INFO_BORDER_STYLE,
-            Level::Warn => WARN_BORDER_STYLE,
-            Level::Error => ERROR_BORDER_STYLE,
-            Level::Debug => DEBUG_BORDER_STYLE,
-            Level::Trace => TRACE_BORDER_STYLE,
-        };
-        final_border_style = level_border_style;
-        final_title_style = final_title_style.patch(level_border_style);
+        let level_theme = theme.level(lvl);
+        resolved.border = level_theme.border_style;
+        resolved.title = resolved
+            .title
+            .patch(level_theme.border_style)
+            .patch(level_theme.title_style);
+        resolved.border_type = level_theme.border_type;
+        resolved.title_prefix = level_theme.title_prefix;
     }
 
     // Apply custom block style
     if let Some(bs) = block_style {
-        final_block_style = bs;
+        resolved.block = bs;
     }
 
     // Apply custom border style (and patch title)
     if let Some(bs) = border_style {
-        final_border_style = bs;
-        final_title_style = final_title_style.patch(bs);
+        resolved.border = bs;
+        resolved.title = resolved.title.patch(bs);
     }
 
     // Apply custom title style (final override)
     if let Some(ts) = title_style {
-        final_title_style = ts;
+        resolved.title = ts;
     }
 
-    (final_block_style, final_border_style, final_title_style)
+    resolved
 }
 
 // FILE: src/notifications/functions/fnc_resolve_styles.rs - Resolves notification styles based on level and custom overrides
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.2.0
@@ -0,0 +1,47 @@
+// FILE: src/notifications/functions/fnc_rolldown_visible_lines.rs - Visible line count for the roll-down animation
+// VERSION: 1.1.0
+// WCTX: Non-finite progress guards
+// CLOG: Clamp progress and rect math against non-finite values
+
+use crate::notifications::types::AnimationPhase;
+use crate::shared_utils::math::clamp_progress;
+
+/// Returns how many content lines a roll-down notification shows.
+///
+/// During entry lines appear one per progress increment,
+/// `ceil(total_lines * progress)`; during exit they roll back up in reverse.
+/// Every other phase shows all lines.
+///
+/// # Arguments
+///
+/// * `total_lines` - Number of content lines
+/// * `phase` - The current animation phase
+/// * `progress` - The animation progress (0.0 to 1.0)
+///
+/// # Returns
+///
+/// Number of leading lines to draw, at most `total_lines`
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_notifications::notifications::functions::fnc_rolldown_visible_lines::rolldown_visible_lines;
+/// use ratatui_notifications::notifications::types::AnimationPhase;
+///
+/// assert_eq!(rolldown_visible_lines(4, AnimationPhase::SlidingIn, 0.5), 2);
+/// assert_eq!(rolldown_visible_lines(4, AnimationPhase::SlidingIn, 0.6), 3);
+/// assert_eq!(rolldown_visible_lines(4, AnimationPhase::SlidingOut, 0.5), 2);
+/// assert_eq!(rolldown_visible_lines(4, AnimationPhase::Dwelling, 0.0), 4);
+/// ```
+pub fn rolldown_visible_lines(total_lines: usize, phase: AnimationPhase, progress: f32) -> usize {
+    let progress = clamp_progress(progress);
+    let shown = match phase {
+        AnimationPhase::SlidingIn => progress,
+        AnimationPhase::SlidingOut => 1.0 - progress,
+        _ => return total_lines,
+    };
+    ((total_lines as f32 * shown).ceil() as usize).min(total_lines)
+}
+
+// FILE: src/notifications/functions/fnc_rolldown_visible_lines.rs - Visible line count for the roll-down animation
+// END OF VERSION: 1.1.0
@@ -0,0 +1,145 @@
+// FILE: src/notifications/functions/fnc_serde_text.rs - Serde helpers for ratatui text fields
+// VERSION: 1.2.0
+// WCTX: Shared notification content
+// CLOG: Replaced text helpers with shared_text
+
+use ratatui::layout::Alignment;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span, Text};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serialized form of a `Span`.
+#[derive(Serialize, Deserialize)]
+struct SpanData {
+    content: String,
+    #[serde(default)]
+    style: Style,
+}
+
+/// Serialized form of a `Line`.
+#[derive(Serialize, Deserialize)]
+struct LineData {
+    spans: Vec<SpanData>,
+    #[serde(default)]
+    style: Style,
+    #[serde(default)]
+    alignment: Option<Alignment>,
+}
+
+impl From<&Line<'_>> for LineData {
+    fn from(line: &Line<'_>) -> Self {
+        Self {
+            spans: line
+                .spans
+                .iter()
+                .map(|span| SpanData {
+                    content: span.content.to_string(),
+                    style: span.style,
+                })
+                .collect(),
+            style: line.style,
+            alignment: line.alignment,
+        }
+    }
+}
+
+impl From<LineData> for Line<'static> {
+    fn from(data: LineData) -> Self {
+        let spans: Vec<Span<'static>> = data
+            .spans
+            .into_iter()
+            .map(|span| Span::styled(span.content, span.style))
+            .collect();
+        let mut line = Line::from(spans).style(data.style);
+        line.alignment = data.alignment;
+        line
+    }
+}
+
+/// Serialized form of a `Text`.
+#[derive(Serialize, Deserialize)]
+struct TextData {
+    lines: Vec<LineData>,
+    #[serde(default)]
+    style: Style,
+    #[serde(default)]
+    alignment: Option<Alignment>,
+}
+
+impl From<&Text<'_>> for TextData {
+    fn from(text: &Text<'_>) -> Self {
+        Self {
+            lines: text.lines.iter().map(LineData::from).collect(),
+            style: text.style,
+            alignment: text.alignment,
+        }
+    }
+}
+
+impl From<TextData> for Text<'static> {
+    fn from(data: TextData) -> Self {
+        let lines: Vec<Line<'static>> = data.lines.into_iter().map(Line::from).collect();
+        let mut text = Text::from(lines).style(data.style);
+        text.alignment = data.alignment;
+        text
+    }
+}
+
+/// `#[serde(with = "...")]` helpers for shared `Arc<Text<'static>>` content.
+pub(crate) mod shared_text {
+    use super::*;
+    use std::sync::Arc;
+
+    pub(crate) fn serialize<S: Serializer>(
+        text: &Arc<Text<'static>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        TextData::from(&**text).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Arc<Text<'static>>, D::Error> {
+        Ok(Arc::new(TextData::deserialize(deserializer)?.into()))
+    }
+}
+
+/// `#[serde(with = "...")]` helpers for `Vec<Text<'static>>`.
+pub(crate) mod text_vec {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        texts: &[Text<'static>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        texts.iter().map(TextData::from).collect::<Vec<_>>().serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Text<'static>>, D::Error> {
+        let data = Vec::<TextData>::deserialize(deserializer)?;
+        Ok(data.into_iter().map(Text::from).collect())
+    }
+}
+
+/// `#[serde(with = "...")]` helpers for `Option<Line<'static>>`.
+pub(crate) mod optional_line {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        line: &Option<Line<'static>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        line.as_ref().map(LineData::from).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Line<'static>>, D::Error> {
+        Ok(Option::<LineData>::deserialize(deserializer)?.map(Line::from))
+    }
+}
+
+// FILE: src/notifications/functions/fnc_serde_text.rs - Serde helpers for ratatui text fields
+// END OF VERSION: 1.2.0
@@ -1,11 +1,12 @@
 // FILE: src/notifications/functions/fnc_slide_apply_border_effect.rs - Applies vanishing edge border effect during slide animation
-// VERSION: 1.0.0
-// WCTX: Implementing slide animation functions with TDD
-// CLOG: Extracted from slide.rs:150-448, initial creation
+// VERSION: 1.1.0
+// WCTX: Non-finite progress guards
+// CLOG: Clamp progress and rect math against non-finite values
 
 use crate::notifications::functions::fnc_slide_offscreen_position::slide_offscreen_position;
 use crate::notifications::functions::fnc_slide_resolve_direction::resolve_slide_direction;
 use crate::notifications::types::{Anchor, AnimationPhase, SlideDirection};
+use crate::shared_utils::math::clamp_progress;
 use ratatui::{prelude::*, symbols::border, widgets::Block};
 
 /// Applies vanishing edge effect to block borders during slide animation.
@@ -74,6 +75,7 @@ pub fn slide_apply_border_effect<'a>(
     base_set: &'a border::Set,
 ) -> Block<'a> {
     const PROGRESS_OFFSET: f32 = 0.0;
+    let progress = clamp_progress(progress);
 
     if full_rect.width == 0 || full_rect.height == 0 {
         return block;
@@ -347,4 +349,4 @@ pub fn slide_apply_border_effect<'a>(
 }
 
 // FILE: src/notifications/functions/fnc_slide_apply_border_effect.rs - Applies vanishing edge border effect during slide animation
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.1.0
@@ -1,12 +1,12 @@
 // FILE: src/notifications/functions/fnc_slide_calculate_rect.rs - Calculates visible rect during slide animation
-// VERSION: 1.0.0
-// WCTX: Implementing slide animation functions with TDD
-// CLOG: Extracted from slide.rs:77-148, initial creation
+// VERSION: 1.2.0
+// WCTX: Non-finite progress guards
+// CLOG: Clamp progress and rect math against non-finite values
 
 use crate::notifications::functions::fnc_slide_offscreen_position::slide_offscreen_position;
 use crate::notifications::functions::fnc_slide_resolve_direction::resolve_slide_direction;
 use crate::notifications::types::{Anchor, AnimationPhase, SlideDirection};
-use crate::shared_utils::math::lerp;
+use crate::shared_utils::math::{clamp_progress, lerp, round_to_u16};
 use ratatui::prelude::Rect;
 
 /// Calculates the visible rectangle during slide animation.
@@ -61,7 +61,7 @@ pub fn slide_calculate_rect(
     custom_slide_in_start_pos: Option<(f32, f32)>,
     custom_slide_out_end_pos: Option<(f32, f32)>,
 ) -> Rect {
-    let progress = progress.clamp(0.0, 1.0);
+    let progress = clamp_progress(progress);
 
     let (start_x_f32, start_y_f32, end_x_f32, end_y_f32) = match phase {
         AnimationPhase::SlidingIn => {
@@ -99,16 +99,16 @@ pub fn slide_calculate_rect(
     let intersect_width = (intersect_x2 - intersect_x1).max(0.0);
     let intersect_height = (intersect_y2 - intersect_y1).max(0.0);
 
-    let final_x = intersect_x1.round() as u16;
-    let final_y = intersect_y1.round() as u16;
-    let final_width = intersect_width.round() as u16;
-    let final_height = intersect_height.round() as u16;
+    let final_x = round_to_u16(intersect_x1);
+    let final_y = round_to_u16(intersect_y1);
+    let final_width = round_to_u16(intersect_width);
+    let final_height = round_to_u16(intersect_height);
 
     let final_rect = Rect {
         x: final_x,
         y: final_y,
-        width: final_width.min(frame_area.width.saturating_sub(final_x)),
-        height: final_height.min(frame_area.height.saturating_sub(final_y)),
+        width: final_width.min(frame_area.right().saturating_sub(final_x)),
+        height: final_height.min(frame_area.bottom().saturating_sub(final_y)),
     };
 
     if final_rect.width > 0 && final_rect.height > 0 {
@@ -119,4 +119,4 @@ pub fn slide_calculate_rect(
 }
 
 // FILE: src/notifications/functions/fnc_slide_calculate_rect.rs - Calculates visible rect during slide animation
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.2.0
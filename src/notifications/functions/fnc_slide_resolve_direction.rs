@@ -1,9 +1,9 @@
 // FILE: src/notifications/functions/fnc_slide_resolve_direction.rs - Resolves slide direction based on config and anchor
-// VERSION: 1.0.0
-// WCTX: Implementing slide animation functions with TDD
-// CLOG: Extracted from slide.rs:19-34, initial creation
+// VERSION: 1.2.0
+// WCTX: Corner slide axis
+// CLOG: Added the corner slide axis
 
-use crate::notifications::types::{Anchor, SlideDirection};
+use crate::notifications::types::{Anchor, CornerSlideAxis, SlideDirection};
 
 /// Resolves the actual slide direction based on config and anchor.
 ///
@@ -53,8 +53,65 @@ pub fn resolve_slide_direction(direction: SlideDirection, anchor: Anchor) -> Sli
         Anchor::BottomLeft => SlideDirection::FromBottomLeft,
         Anchor::BottomCenter => SlideDirection::FromBottom,
         Anchor::BottomRight => SlideDirection::FromBottomRight,
+        Anchor::Custom { .. } => SlideDirection::FromTop,
+    }
+}
+
+/// Resolves the slide direction, sliding corner anchors along `axis` by default.
+///
+/// Like [`resolve_slide_direction`], but a `Default` direction at a corner
+/// anchor becomes the horizontal or vertical part of the diagonal when
+/// `axis` asks for it.
+///
+/// # Arguments
+///
+/// * `direction` - The configured slide direction (may be Default)
+/// * `anchor` - The anchor position of the notification
+/// * `axis` - Axis corner anchors slide along by default
+///
+/// # Returns
+///
+/// The resolved slide direction (never Default)
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_notifications::notifications::functions::fnc_slide_resolve_direction::resolve_slide_direction_on_axis;
+/// use ratatui_notifications::notifications::types::{Anchor, CornerSlideAxis, SlideDirection};
+///
+/// assert_eq!(
+///     resolve_slide_direction_on_axis(SlideDirection::Default, Anchor::TopLeft, CornerSlideAxis::Horizontal),
+///     SlideDirection::FromLeft
+/// );
+///
+/// // Explicit directions are kept
+/// assert_eq!(
+///     resolve_slide_direction_on_axis(SlideDirection::FromTopLeft, Anchor::TopLeft, CornerSlideAxis::Vertical),
+///     SlideDirection::FromTopLeft
+/// );
+/// ```
+pub fn resolve_slide_direction_on_axis(
+    direction: SlideDirection,
+    anchor: Anchor,
+    axis: CornerSlideAxis,
+) -> SlideDirection {
+    if direction != SlideDirection::Default {
+        return direction;
+    }
+    let diagonal = resolve_slide_direction(direction, anchor);
+    let (horizontal, vertical) = match diagonal {
+        SlideDirection::FromTopLeft => (SlideDirection::FromLeft, SlideDirection::FromTop),
+        SlideDirection::FromTopRight => (SlideDirection::FromRight, SlideDirection::FromTop),
+        SlideDirection::FromBottomLeft => (SlideDirection::FromLeft, SlideDirection::FromBottom),
+        SlideDirection::FromBottomRight => (SlideDirection::FromRight, SlideDirection::FromBottom),
+        _ => return diagonal,
+    };
+    match axis {
+        CornerSlideAxis::Diagonal => diagonal,
+        CornerSlideAxis::Horizontal => horizontal,
+        CornerSlideAxis::Vertical => vertical,
     }
 }
 
 // FILE: src/notifications/functions/fnc_slide_resolve_direction.rs - Resolves slide direction based on config and anchor
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.2.0
@@ -0,0 +1,134 @@
+// FILE: src/notifications/functions/fnc_truncate_text.rs - Truncate content lines to a width with an ellipsis
+// VERSION: 1.0.0
+// WCTX: Content truncation
+// CLOG: Initial creation
+
+use crate::notifications::types::TruncateMode;
+use ratatui::prelude::*;
+
+/// Truncates every line of a text to fit a width.
+///
+/// Lines that already fit are kept as they are. See [`truncate_line`].
+///
+/// # Arguments
+///
+/// * `text` - The text to truncate
+/// * `width` - Maximum width of each line in cells
+/// * `mode` - Which part of a long line is replaced
+/// * `ellipsis` - String that marks the removed part
+///
+/// # Returns
+///
+/// A text with the same number of lines, none wider than `width`
+pub fn truncate_text(text: &Text<'_>, width: u16, mode: TruncateMode, ellipsis: &str) -> Text<'static> {
+    let mut truncated = Text::from(
+        text.lines
+            .iter()
+            .map(|line| truncate_line(line, width, mode, ellipsis))
+            .collect::<Vec<_>>(),
+    );
+    truncated.style = text.style;
+    truncated.alignment = text.alignment;
+    truncated
+}
+
+/// Truncates a line to fit a width, replacing the cut part with an ellipsis.
+///
+/// Span styles are kept; the ellipsis takes the style of the first grapheme
+/// it replaces. In `Middle` mode an odd spare cell goes to the end, so file
+/// names survive. If the ellipsis itself does not fit, it is clipped.
+///
+/// # Arguments
+///
+/// * `line` - The line to truncate
+/// * `width` - Maximum width in cells
+/// * `mode` - Which part of a long line is replaced
+/// * `ellipsis` - String that marks the removed part
+///
+/// # Returns
+///
+/// The line, at most `width` cells wide
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::text::Line;
+/// use ratatui_notifications::notifications::functions::fnc_truncate_text::truncate_line;
+/// use ratatui_notifications::notifications::TruncateMode;
+///
+/// let path = Line::from("/home/user/projects/app/src/main.rs");
+/// assert_eq!(truncate_line(&path, 16, TruncateMode::Middle, "…").to_string(), "/home/u…/main.rs");
+/// assert_eq!(truncate_line(&path, 10, TruncateMode::End, "...").to_string(), "/home/u...");
+/// assert_eq!(truncate_line(&path, 10, TruncateMode::Start, "…").to_string(), "…c/main.rs");
+/// ```
+pub fn truncate_line(line: &Line<'_>, width: u16, mode: TruncateMode, ellipsis: &str) -> Line<'static> {
+    let graphemes: Vec<(String, Style, usize)> = line
+        .spans
+        .iter()
+        .flat_map(|span| span.styled_graphemes(Style::default()))
+        .map(|g| (g.symbol.to_string(), g.style, Span::raw(g.symbol).width()))
+        .collect();
+    let total: usize = graphemes.iter().map(|(_, _, w)| w).sum();
+    let width = width as usize;
+
+    let rebuild = |spans| Line { style: line.style, alignment: line.alignment, spans };
+    if total <= width {
+        return rebuild(merge(graphemes.into_iter().map(|(s, style, _)| (s, style))));
+    }
+
+    let ellipsis_width = Span::raw(ellipsis).width();
+    let budget = width.saturating_sub(ellipsis_width);
+    let (head_width, tail_width) = match mode {
+        TruncateMode::Start => (0, budget),
+        TruncateMode::Middle => (budget / 2, budget - budget / 2),
+        TruncateMode::End => (budget, 0),
+    };
+
+    let head = take_width(graphemes.iter(), head_width);
+    let tail = take_width(graphemes.iter().rev(), tail_width);
+    let ellipsis_style = graphemes.get(head).map_or(Style::default(), |(_, style, _)| *style);
+
+    let mut kept: Vec<(String, Style)> =
+        graphemes[..head].iter().map(|(s, style, _)| (s.clone(), *style)).collect();
+    let mut used = 0;
+    for grapheme in Span::raw(ellipsis).styled_graphemes(Style::default()) {
+        let grapheme_width = Span::raw(grapheme.symbol).width();
+        if used + grapheme_width > width {
+            break;
+        }
+        used += grapheme_width;
+        kept.push((grapheme.symbol.to_string(), ellipsis_style));
+    }
+    kept.extend(
+        graphemes[graphemes.len() - tail..]
+            .iter()
+            .map(|(s, style, _)| (s.clone(), *style)),
+    );
+    rebuild(merge(kept.into_iter()))
+}
+
+/// Counts the leading graphemes that fit in `width` cells
+fn take_width<'a>(graphemes: impl Iterator<Item = &'a (String, Style, usize)>, width: usize) -> usize {
+    let mut used = 0;
+    graphemes
+        .take_while(|(_, _, w)| {
+            used += w;
+            used <= width
+        })
+        .count()
+}
+
+/// Joins consecutive graphemes of the same style into spans
+fn merge(graphemes: impl Iterator<Item = (String, Style)>) -> Vec<Span<'static>> {
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    for (symbol, style) in graphemes {
+        match spans.last_mut() {
+            Some(last) if last.style == style => last.content.to_mut().push_str(&symbol),
+            _ => spans.push(Span::styled(symbol, style)),
+        }
+    }
+    spans
+}
+
+// FILE: src/notifications/functions/fnc_truncate_text.rs - Truncate content lines to a width with an ellipsis
+// END OF VERSION: 1.0.0
@@ -0,0 +1,48 @@
+// FILE: src/notifications/functions/fnc_update_states.rs - Advance a set of notification states
+// VERSION: 1.0.0
+// WCTX: Public single-state stepping
+// CLOG: Initial creation as a loop over NotificationState::update
+
+use crate::notifications::classes::NotificationState;
+use core::time::Duration;
+use std::collections::HashMap;
+
+/// Advances every notification state by the elapsed time.
+///
+/// A thin loop over [`NotificationState::update`]; finished states are left
+/// in the map for the caller to remove.
+///
+/// # Arguments
+///
+/// * `states` - Notification states keyed by ID
+/// * `delta` - Time elapsed since the last update
+///
+/// # Returns
+///
+/// IDs of the notifications that are finished after this update
+///
+/// # Examples
+///
+/// ```no_run
+/// use ratatui_notifications::notifications::functions::fnc_update_states::update_states;
+/// use ratatui_notifications::notifications::{ManagerDefaults, NotificationBuilder, NotificationState};
+/// use std::collections::HashMap;
+/// use std::time::Duration;
+///
+/// let mut states = HashMap::new();
+/// let notification = NotificationBuilder::new("Saved").build().unwrap();
+/// states.insert(1, NotificationState::new(1, notification, &ManagerDefaults::default()));
+///
+/// for id in update_states(&mut states, Duration::from_millis(16)) {
+///     states.remove(&id);
+/// }
+/// ```
+pub fn update_states(states: &mut HashMap<u64, NotificationState>, delta: Duration) -> Vec<u64> {
+    states
+        .iter_mut()
+        .filter_map(|(id, state)| state.update(delta).finished.then_some(*id))
+        .collect()
+}
+
+// FILE: src/notifications/functions/fnc_update_states.rs - Advance a set of notification states
+// END OF VERSION: 1.0.0
@@ -0,0 +1,74 @@
+// FILE: src/notifications/functions/fnc_wrap_text.rs - Content wrapping shared by layout and rendering
+// VERSION: 1.1.0
+// WCTX: Buffer-only rendering
+// CLOG: Made wrapped crate-private
+
+use ratatui::buffer::Buffer;
+use ratatui::prelude::*;
+use ratatui::widgets::{Paragraph, Wrap};
+
+/// Wraps content the way every notification draws it.
+///
+/// Rendering, measuring and the strict-fit check all build their paragraph
+/// here, so the rows they count are the rows that get drawn.
+///
+/// # Arguments
+///
+/// * `text` - The content to wrap
+///
+/// # Returns
+///
+/// A word-wrapping `Paragraph` that trims leading whitespace on wrapped rows
+pub(crate) fn wrapped<'a>(text: impl Into<Text<'a>>) -> Paragraph<'a> {
+    Paragraph::new(text).wrap(Wrap { trim: true })
+}
+
+/// Returns the rows `text` wraps to at `width`, as drawn.
+///
+/// The text is rendered through [`wrapped`] into a scratch buffer and read
+/// back, so each row matches the renderer cell for cell. Trailing blank
+/// cells are dropped and wide graphemes appear once.
+///
+/// # Arguments
+///
+/// * `text` - The content to wrap
+/// * `width` - Width of the content area in cells
+///
+/// # Returns
+///
+/// One string per drawn row
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::text::Text;
+/// use ratatui_notifications::notifications::functions::fnc_wrap_text::wrapped_rows;
+///
+/// let rows = wrapped_rows(&Text::raw("alpha beta gamma"), 10);
+/// assert_eq!(rows, vec!["alpha beta", "gamma"]);
+/// ```
+pub fn wrapped_rows(text: &Text<'_>, width: u16) -> Vec<String> {
+    let width = width.max(1);
+    let paragraph = wrapped(text.clone());
+    let height = u16::try_from(paragraph.line_count(width)).unwrap_or(u16::MAX);
+    let area = Rect::new(0, 0, width, height);
+    let mut buffer = Buffer::empty(area);
+    paragraph.render(area, &mut buffer);
+
+    (0..height)
+        .map(|y| {
+            let mut row = String::new();
+            let mut x = 0;
+            while x < width {
+                let symbol = buffer[(x, y)].symbol();
+                row.push_str(symbol);
+                x += u16::try_from(Span::raw(symbol).width()).unwrap_or(1).max(1);
+            }
+            row.truncate(row.trim_end().len());
+            row
+        })
+        .collect()
+}
+
+// FILE: src/notifications/functions/fnc_wrap_text.rs - Content wrapping shared by layout and rendering
+// END OF VERSION: 1.1.0
@@ -1,21 +1,39 @@
 // FILE: src/notifications/functions/mod.rs - Functions module
-// VERSION: 1.14.0
-// WCTX: Adding code generation feature
-// CLOG: Added fnc_generate_code module
+// VERSION: 1.30.0
+// WCTX: TOML scenario player
+// CLOG: Added fnc_parse_duration
 
+pub mod fnc_apply_color_mode;
+pub mod fnc_big_text;
 pub mod fnc_calculate_anchor_position;
 pub mod fnc_calculate_rect;
 pub mod fnc_calculate_size;
+pub mod fnc_check_strict_fit;
+pub mod fnc_describe_notification;
 pub mod fnc_expand_calculate_rect;
 pub mod fnc_fade_calculate_rect;
 pub mod fnc_fade_interpolate_color;
 pub mod fnc_generate_code;
+pub mod fnc_fill_template;
 pub mod fnc_get_level_icon;
+pub mod fnc_layer_transform_rect;
+pub mod fnc_level_severity;
+pub mod fnc_lint_notification;
+pub mod fnc_measure;
+pub mod fnc_move_calculate_rect;
+pub mod fnc_parse_duration;
 pub mod fnc_resolve_styles;
+pub mod fnc_rolldown_visible_lines;
+#[cfg(feature = "serde")]
+pub(crate) mod fnc_serde_text;
 pub mod fnc_slide_apply_border_effect;
 pub mod fnc_slide_calculate_rect;
 pub mod fnc_slide_offscreen_position;
 pub mod fnc_slide_resolve_direction;
+pub mod fnc_truncate_text;
+#[cfg(feature = "std")]
+pub mod fnc_update_states;
+pub mod fnc_wrap_text;
 
 // FILE: src/notifications/functions/mod.rs - Functions module
-// END OF VERSION: 1.14.0
+// END OF VERSION: 1.30.0
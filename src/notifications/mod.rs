@@ -1,30 +1,68 @@
 // FILE: src/notifications/mod.rs - Notifications module
-// VERSION: 1.7.0
-// WCTX: Adding code generation feature
-// CLOG: Added generate_code re-export
+// VERSION: 1.44.0
+// WCTX: Absolute TTL
+// CLOG: Exported DismissReason
 
 pub mod types;
+pub mod traits;
 pub mod functions;
 pub(crate) mod classes;
 pub mod orc_stacking;
 pub mod orc_render;
+#[cfg(feature = "std")]
 pub mod orc_manager;
+#[cfg(feature = "scenario")]
+pub mod orc_scenario;
+#[cfg(feature = "std-thread")]
+pub mod orc_ticker;
+#[cfg(feature = "center")]
+pub mod orc_center;
 
 // Re-export main types for convenient access
-pub use classes::{Notification, NotificationBuilder};
-pub use orc_manager::Notifications;
+pub use classes::{LevelTheme, Notification, NotificationBuilder, Strings, Theme};
+#[cfg(feature = "std")]
+pub use classes::{
+    ManagerDefaults, NotificationMut, NotificationState, NotificationStateBuilder,
+    SavedNotification, SavedNotifications,
+};
+#[cfg(feature = "std")]
+pub use orc_manager::{Notifications, NotificationsWidget};
+#[cfg(feature = "scenario")]
+pub use orc_scenario::{FrameSnapshot, Scenario, ScenarioEvent, ScenarioPlayer, ScenarioSettings};
+#[cfg(feature = "std-thread")]
+pub use orc_ticker::TickerHandle;
+#[cfg(feature = "center")]
+pub use orc_center::{NotificationCenter, NotificationCenterState};
+pub use traits::Clock;
+pub use traits::Notifier;
+#[cfg(feature = "std")]
+pub use traits::BellNotifier;
+#[cfg(feature = "wasm")]
+pub use traits::PerformanceClock;
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+pub use traits::SystemClock;
 pub use types::{
-    Anchor, Animation, AnimationPhase, AutoDismiss, Level,
-    NotificationError, Overflow, SlideDirection, SizeConstraint, Timing,
+    Anchor, AnchorCoord, Animation, AnimationLayer, AnimationPhase, AutoDismiss, BuildWarning,
+    BuildWarningKind, CatchUpPolicy, ColorClamp, ColorMode, CornerSlideAxis, DismissReason, Easing,
+    Evictability, ExpandOrigin, FadeScope, Layer, LayerTransform, Level, MeasureOptions, NotificationError, NotificationInfo, NotificationRecord, Overflow,
+    PathPoint, PlaceholderStyle, ResolvedStyles, SlideDirection, SizeConstraint, SortBy,
+    StackLayout, StateUpdate, StringArgs, StringKey, Timestamp, Timing, TruncateMode, VerticalAlign,
 };
+#[cfg(feature = "crossterm")]
+pub use types::DismissOnInput;
+#[cfg(feature = "scenario")]
+pub use types::{NotificationConfig, ScenarioError};
 
 // Re-export layout utilities for custom positioning
 pub use functions::fnc_calculate_anchor_position::calculate_anchor_position;
 pub use functions::fnc_calculate_rect::calculate_rect;
 pub use functions::fnc_calculate_size::calculate_size;
+pub use functions::fnc_measure::{measure, measure_lines, split_to_fit};
 
 // Re-export code generation utility
 pub use functions::fnc_generate_code::generate_code;
+#[cfg(feature = "std")]
+pub use functions::fnc_generate_code::{generate_code_with_context, generate_scene_code};
 
 // FILE: src/notifications/mod.rs - Notifications module
-// END OF VERSION: 1.7.0
+// END OF VERSION: 1.44.0
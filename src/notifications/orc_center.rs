@@ -0,0 +1,232 @@
+// FILE: src/notifications/orc_center.rs - Notification center widget over the history
+// VERSION: 1.0.0
+// WCTX: Notification center widget
+// CLOG: Initial creation with NotificationCenter and NotificationCenterState
+
+use crate::notifications::classes::{Strings, Theme};
+use crate::notifications::functions::fnc_get_level_icon::get_level_icon;
+use crate::notifications::types::{NotificationRecord, StringKey, Timestamp};
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, HighlightSpacing, List, ListItem, ListState, StatefulWidget, Widget},
+};
+
+/// Scrollable list of past notifications, newest first.
+///
+/// Each row shows the level icon, title, first content line and how long ago
+/// the notification was added. Icons and titles use the [`Theme`] colors of
+/// the record's level; relative timestamps come from [`Strings`].
+///
+/// Requires the `center` feature.
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui::widgets::{Block, StatefulWidget};
+/// use ratatui_notifications::notifications::{NotificationCenterState, Notifications};
+///
+/// let manager = Notifications::new().history_limit(100);
+/// let mut state = NotificationCenterState::default();
+///
+/// // Inside terminal.draw(|frame| { ... }):
+/// # let area = ratatui::layout::Rect::new(0, 0, 60, 20);
+/// # let mut buf = ratatui::buffer::Buffer::empty(area);
+/// manager
+///     .notification_center()
+///     .block(Block::bordered().title("Notifications"))
+///     .render(area, &mut buf, &mut state);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NotificationCenter<'a> {
+    records: Vec<&'a NotificationRecord>,
+    now: Timestamp,
+    theme: Theme,
+    strings: Strings,
+    block: Option<Block<'a>>,
+    highlight_style: Style,
+    dismissed: bool,
+    shown: bool,
+}
+
+impl<'a> NotificationCenter<'a> {
+    /// Creates a notification center over history records.
+    ///
+    /// # Arguments
+    /// * `records` - History records, oldest first (as returned by
+    ///   `Notifications::history`)
+    /// * `now` - Current time, used for the relative timestamps
+    pub fn new(records: impl IntoIterator<Item = &'a NotificationRecord>, now: Timestamp) -> Self {
+        let mut records: Vec<&'a NotificationRecord> = records.into_iter().collect();
+        records.reverse();
+        Self {
+            records,
+            now,
+            theme: Theme::default(),
+            strings: Strings::default(),
+            block: None,
+            highlight_style: Style::new().add_modifier(Modifier::REVERSED),
+            dismissed: true,
+            shown: true,
+        }
+    }
+
+    /// Sets the theme used for level icons and titles.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Sets the wording of relative timestamps.
+    pub fn strings(mut self, strings: Strings) -> Self {
+        self.strings = strings;
+        self
+    }
+
+    /// Wraps the list in a block.
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the selected row (default reversed).
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Whether to list notifications that have been dismissed (default `true`).
+    pub fn dismissed(mut self, include: bool) -> Self {
+        self.dismissed = include;
+        self
+    }
+
+    /// Whether to list notifications that are still shown (default `true`).
+    pub fn shown(mut self, include: bool) -> Self {
+        self.shown = include;
+        self
+    }
+
+    /// Returns the record at the state's selection, after filtering.
+    ///
+    /// # Arguments
+    /// * `state` - The state passed to `render`
+    pub fn selected_record(&self, state: &NotificationCenterState) -> Option<&'a NotificationRecord> {
+        self.visible().nth(state.selected()?)
+    }
+
+    /// Records passing the `dismissed`/`shown` filters, newest first
+    fn visible(&self) -> impl Iterator<Item = &'a NotificationRecord> + '_ {
+        self.records.iter().copied().filter(|record| {
+            if record.is_dismissed() {
+                self.dismissed
+            } else {
+                self.shown
+            }
+        })
+    }
+
+    /// Builds the single-line row for a record
+    fn row(&self, record: &NotificationRecord) -> ListItem<'a> {
+        let level_style = match record.level {
+            Some(level) => self.theme.level(level).border_style,
+            None => self.theme.default_border_style,
+        };
+
+        let mut spans: Vec<Span<'a>> = Vec::new();
+        if let Some(icon) = get_level_icon(record.level) {
+            spans.push(Span::styled(format!("{} ", icon.trim_start()), level_style));
+        }
+        if let Some(title) = &record.title {
+            let title: String = title.spans.iter().map(|span| span.content.as_ref()).collect();
+            spans.push(Span::styled(title, level_style.add_modifier(Modifier::BOLD)));
+            spans.push(Span::raw(" "));
+        }
+        if let Some(line) = record.content.lines.first() {
+            spans.extend(
+                line.spans
+                    .iter()
+                    .map(|span| Span::styled(span.content.to_string(), span.style)),
+            );
+        }
+        spans.push(Span::styled(
+            format!(" · {}", self.age(record.added_at)),
+            Style::new().add_modifier(Modifier::DIM),
+        ));
+
+        ListItem::new(Line::from(spans))
+    }
+
+    /// Formats time since `added_at` in the largest whole unit
+    fn age(&self, added_at: Timestamp) -> String {
+        let secs = self.now.saturating_duration_since(added_at).as_secs();
+        let (key, n) = match secs {
+            0..=59 => (StringKey::AgeSeconds, secs),
+            60..=3599 => (StringKey::AgeMinutes, secs / 60),
+            _ => (StringKey::AgeHours, secs / 3600),
+        };
+        self.strings.format(key, &[("n", &n.to_string())])
+    }
+}
+
+/// Selection and scroll position of a [`NotificationCenter`].
+///
+/// Indices count rows after filtering, newest first. Out-of-range
+/// selections are clamped on render.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NotificationCenterState {
+    list: ListState,
+}
+
+impl NotificationCenterState {
+    /// Returns the selected row, if any.
+    pub fn selected(&self) -> Option<usize> {
+        self.list.selected()
+    }
+
+    /// Selects a row, or clears the selection with `None`.
+    pub fn select(&mut self, index: Option<usize>) {
+        self.list.select(index);
+    }
+
+    /// Selects the next (older) row, or the first row if none is selected.
+    pub fn select_next(&mut self) {
+        self.list.select_next();
+    }
+
+    /// Selects the previous (newer) row, or the last row if none is selected.
+    pub fn select_previous(&mut self) {
+        self.list.select_previous();
+    }
+
+    /// Returns the index of the first visible row.
+    pub fn offset(&self) -> usize {
+        self.list.offset()
+    }
+}
+
+impl StatefulWidget for NotificationCenter<'_> {
+    type State = NotificationCenterState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let items: Vec<ListItem<'_>> = self.visible().map(|record| self.row(record)).collect();
+        let mut list = List::new(items)
+            .highlight_style(self.highlight_style)
+            .highlight_spacing(HighlightSpacing::Never);
+        if let Some(block) = self.block {
+            list = list.block(block);
+        }
+        StatefulWidget::render(list, area, buf, &mut state.list);
+    }
+}
+
+impl Widget for NotificationCenter<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        StatefulWidget::render(self, area, buf, &mut NotificationCenterState::default());
+    }
+}
+
+// FILE: src/notifications/orc_center.rs - Notification center widget over the history
+// END OF VERSION: 1.0.0
@@ -1,17 +1,50 @@
 // FILE: src/notifications/orc_manager.rs - Notifications manager orchestrator
-// VERSION: 1.0.0
-// WCTX: Implementing Notifications manager orchestrator using TDD
-// CLOG: Initial creation with manager coordination logic
-
-use crate::notifications::classes::{Notification, NotificationState, ManagerDefaults};
-use crate::notifications::orc_render::render_notifications;
-use crate::notifications::types::{Anchor, NotificationError, Overflow};
-use ratatui::prelude::{Frame, Rect};
-use std::collections::HashMap;
+// VERSION: 1.69.0
+// WCTX: Suppressed notifications
+// CLOG: Restored notifications keep their display time while parked
+
+use crate::notifications::classes::{
+    Notification, NotificationBuilder, NotificationMut, NotificationState, ManagerDefaults,
+    SavedNotifications, Strings, Theme,
+};
+use crate::notifications::functions::fnc_describe_notification::describe_notification;
+use crate::notifications::functions::fnc_level_severity::level_severity;
+use crate::notifications::functions::fnc_update_states::update_states;
+#[cfg(feature = "center")]
+use crate::notifications::orc_center::NotificationCenter;
+use crate::notifications::orc_render::{render_notifications, RenderOptions};
+#[cfg(feature = "std-thread")]
+use crate::notifications::orc_ticker::{spawn_ticker, TickerHandle};
+use crate::notifications::traits::{default_clock, Clock, Notifier};
+use crate::notifications::types::{
+    Anchor, Animation, AnimationPhase, CatchUpPolicy, ColorMode, CornerSlideAxis, DismissCondition, DismissReason, Evictability,
+    Layer, Level, NotificationError, NotificationInfo, NotificationRecord, Overflow, Preset, SortBy, StackLayout,
+    StringKey, Timestamp,
+};
+#[cfg(feature = "crossterm")]
+use crate::notifications::types::DismissOnInput;
+#[cfg(feature = "crossterm")]
+use crossterm::event::{Event, KeyEventKind};
+use ratatui::prelude::{Buffer, Frame, Margin, Position, Rect};
+use ratatui::style::Color;
+use ratatui::text::{Line, Text};
+use ratatui::widgets::StatefulWidget;
+use std::collections::{vec_deque, HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+#[cfg(feature = "std-thread")]
+use std::sync::Mutex;
 use std::time::Duration;
 
 /// Manager for animated notifications.
 ///
+/// # IDs
+///
+/// Every notification gets a `u64` ID that is unique for the manager's
+/// lifetime. IDs count up and never wrap: once `u64::MAX` has been assigned,
+/// adding returns `NotificationError::IdsExhausted` instead of starting over
+/// at 0. An ID therefore never collides with an active notification, nor
+/// with one that has finished.
+///
 /// # Example
 /// ```no_run
 /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder, Level};
@@ -31,22 +64,126 @@ use std::time::Duration;
 #[derive(Debug)]
 pub struct Notifications {
     /// Active notification states keyed by ID
-    states: HashMap<u64, NotificationState>,
+    pub(crate) states: HashMap<u64, NotificationState>,
 
     /// Notifications grouped by anchor position
     by_anchor: HashMap<Anchor, Vec<u64>>,
 
-    /// Next available ID for new notifications
-    next_id: u64,
+    /// Lowest ID never assigned (None once u64::MAX has been used)
+    next_id: Option<u64>,
 
     /// Default timing values for notifications
-    defaults: ManagerDefaults,
+    pub(crate) defaults: ManagerDefaults,
 
     /// Maximum concurrent notifications per anchor (None = unlimited)
-    max_concurrent: Option<usize>,
+    pub(crate) max_concurrent: Option<usize>,
+
+    /// Maximum notifications across all anchors (None = unlimited)
+    pub(crate) max_total: Option<usize>,
+
+    /// Overflow behavior when max_concurrent or max_total is reached
+    pub(crate) overflow: Overflow,
+
+    /// Whether exiting notifications still take up a slot under the limits
+    pub(crate) count_exiting: bool,
+
+    /// Most notifications laid out and drawn per frame (None = unlimited)
+    render_budget: Option<usize>,
+
+    /// Inset from every edge of the render area that notifications stay out of
+    safe_area: Margin,
+
+    /// Time source for creation timestamps
+    clock: Box<dyn Clock>,
+
+    /// Told about each notification as it is shown
+    notifier: Option<Box<dyn Notifier>>,
+
+    /// Color capability of the target terminal
+    pub(crate) color_mode: ColorMode,
+
+    /// Level styling applied when rendering
+    theme: Theme,
+
+    /// Whether NO_COLOR strips crate colors
+    respect_no_color: bool,
+
+    /// Whether NO_COLOR was set (non-empty) when the manager was built
+    no_color_env: bool,
+
+    /// Keep colors even when NO_COLOR is set
+    force_colors: bool,
+
+    /// Also strip colors from user-provided styles under NO_COLOR
+    strip_user_colors: bool,
+
+    /// Draw order across anchors
+    pub(crate) sort_by: SortBy,
+
+    /// Direction notifications sharing an anchor stack in
+    pub(crate) stack_layout: StackLayout,
+
+    /// IDs already reported by describe_changes()
+    described: HashSet<u64>,
+
+    /// User-visible wording injected by the crate
+    strings: Strings,
+
+    /// Duration of move_to_anchor() transitions
+    move_duration: Duration,
+
+    /// Recently added notifications, oldest first
+    history: VecDeque<NotificationRecord>,
+
+    /// Maximum history entries kept (0 = history disabled)
+    history_limit: usize,
+
+    /// Least severe level add() accepts; less severe notifications are dropped
+    min_level: Level,
+
+    /// Whether notifications dropped by min_level are recorded in the history
+    record_filtered: bool,
+
+    /// Speed multiplier applied to every notification's animations
+    global_speed: f32,
+
+    /// Named styles instantiated by add_preset()
+    presets: HashMap<String, Preset>,
+
+    /// Largest delta a single tick applies (None = unlimited)
+    max_delta: Option<Duration>,
+
+    /// What happens to time cut off by max_delta
+    catch_up: CatchUpPolicy,
+
+    /// Axis corner anchors slide along when the direction is Default
+    corner_slide_axis: CornerSlideAxis,
+
+    /// Time cut off by max_delta still to be applied (Spread only)
+    catch_up_debt: Duration,
+
+    /// Whether add() parks notifications instead of showing them
+    suppressed: bool,
 
-    /// Overflow behavior when max_concurrent is reached
-    overflow: Overflow,
+    /// Notifications added while suppressed, in the order they were added
+    parked: Vec<ParkedNotification>,
+
+    /// Whether notifications released by suppress(false) note how long they waited
+    annotate_delayed: bool,
+
+    /// Key presses handle_event() answers by dismissing visible notifications
+    #[cfg(feature = "crossterm")]
+    dismiss_on_input: DismissOnInput,
+}
+
+/// A notification added while suppressed, waiting to be shown.
+#[derive(Debug)]
+struct ParkedNotification {
+    id: u64,
+    notification: Notification,
+    parked_at: Timestamp,
+    /// Display time left from `restore_state`, applied on release
+    remaining_display_time: Option<Duration>,
 }
 
 impl Notifications {
@@ -60,10 +197,42 @@ impl Notifications {
         Self {
             states: HashMap::new(),
             by_anchor: HashMap::new(),
-            next_id: 0,
+            next_id: Some(0),
             defaults: ManagerDefaults::default(),
             max_concurrent: None,
+            max_total: None,
             overflow: Overflow::default(),
+            count_exiting: false,
+            render_budget: None,
+            safe_area: Margin::new(0, 0),
+            clock: default_clock(),
+            notifier: None,
+            color_mode: ColorMode::default(),
+            theme: Theme::default(),
+            respect_no_color: false,
+            no_color_env: no_color_set(|key| std::env::var_os(key)),
+            force_colors: false,
+            strip_user_colors: false,
+            sort_by: SortBy::default(),
+            stack_layout: StackLayout::default(),
+            described: HashSet::new(),
+            strings: Strings::default(),
+            move_duration: Duration::from_millis(300),
+            history: VecDeque::new(),
+            history_limit: 0,
+            min_level: Level::Trace,
+            record_filtered: false,
+            global_speed: 1.0,
+            presets: HashMap::new(),
+            max_delta: None,
+            catch_up: CatchUpPolicy::default(),
+            corner_slide_axis: CornerSlideAxis::default(),
+            catch_up_debt: Duration::ZERO,
+            suppressed: false,
+            parked: Vec::new(),
+            annotate_delayed: false,
+            #[cfg(feature = "crossterm")]
+            dismiss_on_input: DismissOnInput::default(),
         }
     }
 
@@ -73,7 +242,210 @@ impl Notifications {
     pub fn has_notification(&self) -> bool {
         self.states
             .iter()
-            .any(|(_, v)| v.current_phase != crate::notifications::types::AnimationPhase::Finished)
+            .any(|(_, v)| v.current_phase != AnimationPhase::Finished)
+    }
+
+    /// Returns the current animation phase of a notification.
+    ///
+    /// # Arguments
+    /// * `id` - The notification ID
+    ///
+    /// # Returns
+    /// * `Some(AnimationPhase)` - If the notification is still managed
+    /// * `None` - If the ID is unknown or the notification has been removed
+    pub fn phase(&self, id: u64) -> Option<AnimationPhase> {
+        self.states.get(&id).map(|state| state.current_phase)
+    }
+
+    /// Returns the animation a notification plays, for debugging or an
+    /// animation inspector.
+    ///
+    /// The animation fixes the phases the notification moves through:
+    ///
+    /// | Animation | Entry | Exit |
+    /// |---|---|---|
+    /// | `Slide`, `RollDown` | `SlidingIn` | `SlidingOut` |
+    /// | `ExpandCollapse` | `Expanding` | `Collapsing` |
+    /// | `Fade` | `FadingIn` | `FadingOut` |
+    ///
+    /// Every notification runs `Pending`, entry, `Dwelling`, exit,
+    /// `Finished`; see [`AnimationPhase::entry`] and [`AnimationPhase::exit`].
+    ///
+    /// # Arguments
+    /// * `id` - The notification ID
+    ///
+    /// # Returns
+    /// * `Some(Animation)` - The notification's configured animation
+    /// * `None` - If the ID is unknown or the notification has been removed
+    pub fn animation_of(&self, id: u64) -> Option<Animation> {
+        self.states.get(&id).map(|state| state.notification.animation())
+    }
+
+    /// Returns the anchor a notification is stacked at.
+    ///
+    /// # Arguments
+    /// * `id` - The notification ID
+    ///
+    /// # Returns
+    /// * `Some(Anchor)` - The notification's current anchor
+    /// * `None` - If the ID is unknown or the notification has been removed
+    pub fn anchor(&self, id: u64) -> Option<Anchor> {
+        self.states.get(&id).map(|state| state.notification.anchor)
+    }
+
+    /// Flips a notification with pages to its next page.
+    ///
+    /// Wraps around after the last page and restarts the page interval, so
+    /// the new page is shown for a full interval. The display countdown is
+    /// unaffected.
+    ///
+    /// # Arguments
+    /// * `id` - The notification ID
+    ///
+    /// # Returns
+    /// `false` if the ID is unknown or the notification has fewer than two pages
+    pub fn next_page(&mut self, id: u64) -> bool {
+        self.states.get_mut(&id).is_some_and(|state| state.next_page())
+    }
+
+    /// Returns a read-only snapshot of a notification.
+    ///
+    /// The snapshot is detached from the manager: changing it has no effect,
+    /// and it does not follow later ticks.
+    ///
+    /// # Arguments
+    /// * `id` - The notification ID
+    ///
+    /// # Returns
+    /// * `Some(NotificationInfo)` - If the notification is still managed
+    /// * `None` - If the ID is unknown
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{NotificationBuilder, Notifications};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let id = manager.add(NotificationBuilder::new("Saved").build().unwrap()).unwrap();
+    ///
+    /// let info = manager.get(id).unwrap();
+    /// assert_eq!(&*info.content, "Saved");
+    /// ```
+    pub fn get(&self, id: u64) -> Option<NotificationInfo> {
+        let now = self.clock.now();
+        self.states.get(&id).map(|state| state.info(now))
+    }
+
+    /// Returns a snapshot of every managed notification, oldest first.
+    ///
+    /// Includes notifications still pending or exiting. Snapshots are sorted
+    /// by ID, the order [`add`](Self::add) assigned them, so the order is
+    /// deterministic.
+    pub fn infos(&self) -> Vec<NotificationInfo> {
+        let now = self.clock.now();
+        self.ids_oldest_first()
+            .into_iter()
+            .map(|id| self.states[&id].info(now))
+            .collect()
+    }
+
+    /// Returns the inner rect where a notification's content was drawn.
+    ///
+    /// Useful for custom content renderers and hit-testing. The rect comes
+    /// from the last render: the notification's full rect minus its border,
+    /// padding and title separator row.
+    ///
+    /// # Arguments
+    /// * `id` - The notification ID
+    ///
+    /// # Returns
+    /// * `Some(Rect)` - The content rect from the last render
+    /// * `None` - If the ID is unknown or the notification has not been rendered
+    pub fn content_rect(&self, id: u64) -> Option<Rect> {
+        self.states.get(&id)?.content_rect()
+    }
+
+    /// Describes every visible notification as a plain-text sentence.
+    ///
+    /// Intended for screen readers and status lines where animated toasts are
+    /// unusable. Sentences are ordered by severity (errors first), then by
+    /// recency (newest first), and are recomputed from live state on each call.
+    ///
+    /// # Returns
+    /// One sentence per visible notification, e.g.
+    /// `"Error notification, top right: Connection failed. Dismisses in 4 seconds."`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Level, NotificationBuilder, Notifications};
+    /// use std::time::Duration;
+    ///
+    /// let mut manager = Notifications::new();
+    /// let notif = NotificationBuilder::new("Connection failed")
+    ///     .level(Level::Error)
+    ///     .build()
+    ///     .unwrap();
+    /// manager.add(notif).unwrap();
+    /// manager.tick(Duration::from_millis(16));
+    ///
+    /// for line in manager.describe() {
+    ///     println!("{}", line);
+    /// }
+    /// ```
+    pub fn describe(&self) -> Vec<String> {
+        self.visible_by_severity()
+            .into_iter()
+            .map(|state| self.describe_state(state))
+            .collect()
+    }
+
+    /// Describes only notifications that became visible since the last call.
+    ///
+    /// Suited to event-stream consumers (announcers, braille displays) that
+    /// should hear each notification once. Uses the same wording and ordering
+    /// as [`describe`](Self::describe).
+    ///
+    /// # Returns
+    /// Sentences for newly shown notifications; empty if nothing is new
+    pub fn describe_changes(&mut self) -> Vec<String> {
+        let visible = self.visible_by_severity();
+        let lines = visible
+            .iter()
+            .filter(|state| !self.described.contains(&state.id))
+            .map(|state| self.describe_state(state))
+            .collect();
+        self.described = visible.iter().map(|state| state.id).collect();
+        lines
+    }
+
+    /// Visible states ordered by severity, then recency.
+    fn visible_by_severity(&self) -> Vec<&NotificationState> {
+        let mut visible: Vec<&NotificationState> = self
+            .states
+            .values()
+            .filter(|state| {
+                !matches!(state.current_phase, AnimationPhase::Pending | AnimationPhase::Finished)
+            })
+            .collect();
+        visible.sort_by(|a, b| {
+            level_severity(b.notification.level)
+                .cmp(&level_severity(a.notification.level))
+                .then(b.created_at.cmp(&a.created_at))
+                .then(b.id.cmp(&a.id))
+        });
+        visible
+    }
+
+    /// Formats one state via describe_notification.
+    fn describe_state(&self, state: &NotificationState) -> String {
+        let notification = &state.notification;
+        describe_notification(
+            notification.level,
+            notification.anchor,
+            notification.title.as_ref(),
+            &state.display_content(),
+            state.time_until_exit(),
+            &self.strings,
+        )
     }
 
     /// Sets the maximum number of concurrent notifications per anchor.
@@ -93,232 +465,2361 @@ impl Notifications {
         self
     }
 
-    /// Sets the overflow behavior when max_concurrent is reached.
+    /// Sets the maximum number of notifications across all anchors.
+    ///
+    /// Applies on top of the per-anchor `max_concurrent` limit. When adding
+    /// would exceed the cap, the configured overflow behavior evicts the
+    /// oldest or newest notification regardless of its anchor.
     ///
     /// # Arguments
-    /// * `behavior` - Overflow behavior (DiscardOldest or DiscardNewest)
+    /// * `max` - Maximum total notifications (None = unlimited)
     ///
     /// # Example
     /// ```no_run
-    /// use ratatui_notifications::notifications::{Notifications, Overflow};
+    /// use ratatui_notifications::notifications::Notifications;
     ///
     /// let manager = Notifications::new()
-    ///     .overflow(Overflow::DiscardNewest);
+    ///     .max_concurrent(Some(3))
+    ///     .max_total(Some(5));
     /// ```
-    pub fn overflow(mut self, behavior: Overflow) -> Self {
-        self.overflow = behavior;
+    pub fn max_total(mut self, max: Option<usize>) -> Self {
+        self.max_total = max;
         self
     }
 
-    /// Adds a notification and returns its unique ID.
+    /// Sets whether exiting notifications count towards the limits.
     ///
-    /// If max_concurrent limit is reached for the notification's anchor,
-    /// applies the configured overflow behavior.
+    /// By default a notification that is already leaving frees its slot, so
+    /// an `add` during a burst takes that slot instead of evicting a toast
+    /// that is still showing; the stack reflows once the exit finishes. Pass
+    /// `true` to count exiting notifications until they are gone.
     ///
     /// # Arguments
-    /// * `notification` - The notification to add
-    ///
-    /// # Returns
-    /// * `Ok(u64)` - The unique ID assigned to the notification
-    /// * `Err(NotificationError)` - If the notification is invalid
+    /// * `count` - Whether exiting notifications occupy a slot
     ///
     /// # Example
     /// ```no_run
-    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    /// use ratatui_notifications::notifications::Notifications;
     ///
-    /// let mut manager = Notifications::new();
-    /// let notif = NotificationBuilder::new("Hello!").build().unwrap();
-    /// let id = manager.add(notif).unwrap();
+    /// let manager = Notifications::new()
+    ///     .max_concurrent(Some(3))
+    ///     .count_exiting(true);
     /// ```
-    pub fn add(&mut self, notification: Notification) -> Result<u64, NotificationError> {
-        // Generate ID
-        let id = self.next_id;
-        self.next_id = self.next_id.checked_add(1).unwrap_or(0);
-
-        let anchor = notification.anchor;
-
-        // Check and enforce limits
-        self.enforce_limit(anchor);
-
-        // Create state
-        let state = NotificationState::new(id, notification, &self.defaults);
-
-        // Add to maps
-        self.states.insert(id, state);
-        self.by_anchor.entry(anchor).or_default().push(id);
-
-        Ok(id)
+    pub fn count_exiting(mut self, count: bool) -> Self {
+        self.count_exiting = count;
+        self
     }
 
-    /// Removes a notification by ID.
+    /// Caps how many notifications each render lays out and draws.
     ///
-    /// # Arguments
-    /// * `id` - The notification ID to remove
+    /// A safeguard for floods: unlike `max_concurrent` nothing is dismissed,
+    /// but past the budget only the most relevant notifications (highest
+    /// effective priority, then newest) are stacked and drawn each frame.
+    /// The rest stay managed and tick as usual.
     ///
-    /// # Returns
-    /// * `true` - If the notification existed and was removed
-    /// * `false` - If the notification didn't exist
+    /// # Arguments
+    /// * `max_visible` - Most notifications per frame (None = unlimited)
     ///
     /// # Example
     /// ```no_run
-    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    /// use ratatui_notifications::notifications::Notifications;
     ///
-    /// let mut manager = Notifications::new();
-    /// let notif = NotificationBuilder::new("Test").build().unwrap();
-    /// let id = manager.add(notif).unwrap();
-    /// assert!(manager.remove(id));
+    /// let manager = Notifications::new().render_budget(Some(50));
     /// ```
-    pub fn remove(&mut self, id: u64) -> bool {
-        if let Some(state) = self.states.remove(&id) {
-            // Remove from anchor map
-            let anchor = state.notification.anchor;
-            if let Some(ids) = self.by_anchor.get_mut(&anchor) {
-                ids.retain(|&existing_id| existing_id != id);
-            }
-            true
-        } else {
-            false
-        }
+    pub fn render_budget(mut self, max_visible: Option<usize>) -> Self {
+        self.render_budget = max_visible;
+        self
     }
 
-    /// Removes all notifications.
+    /// Keeps notifications inside an inset of the render area (default none).
+    ///
+    /// Anchoring, stacking, clamping and slide paths all work within the
+    /// area shrunk by `margin`, for terminals with rounded corners or apps
+    /// with overlays along the edges. An area too small for the inset draws
+    /// nothing.
+    ///
+    /// # Arguments
+    /// * `margin` - Cells kept clear on the left and right (`horizontal`)
+    ///   and on the top and bottom (`vertical`)
     ///
     /// # Example
     /// ```no_run
+    /// use ratatui::layout::Margin;
     /// use ratatui_notifications::notifications::Notifications;
     ///
-    /// let mut manager = Notifications::new();
-    /// // ... add notifications ...
-    /// manager.clear();
+    /// let manager = Notifications::new().safe_area(Margin::new(2, 1));
     /// ```
-    pub fn clear(&mut self) {
-        self.states.clear();
-        self.by_anchor.clear();
+    pub fn safe_area(mut self, margin: Margin) -> Self {
+        self.safe_area = margin;
+        self
     }
 
-    /// Updates all notification animations.
+    /// Sets how many notifications the history keeps.
     ///
-    /// Call this once per frame with the elapsed time since the last update.
+    /// Every added notification is recorded, and stays in the history after
+    /// it is dismissed, until `limit` newer ones push it out. The default of
+    /// `0` records nothing.
     ///
     /// # Arguments
-    /// * `delta` - Time elapsed since last tick
+    /// * `limit` - Maximum history entries
     ///
     /// # Example
     /// ```no_run
     /// use ratatui_notifications::notifications::Notifications;
-    /// use std::time::Duration;
     ///
-    /// let mut manager = Notifications::new();
-    /// manager.tick(Duration::from_millis(16)); // ~60 FPS
+    /// let manager = Notifications::new().history_limit(100);
     /// ```
-    pub fn tick(&mut self, delta: Duration) {
-        // Update all notification states
-        let states_to_update: Vec<u64> = self.states.keys().copied().collect();
-
-        for id in states_to_update {
-            if let Some(state) = self.states.get_mut(&id) {
-                state.update(delta);
-            }
-        }
-
-        // Remove finished notifications
-        let finished: Vec<u64> = self.states
-            .iter()
-            .filter_map(|(id, state)| {
-                if state.current_phase == crate::notifications::types::AnimationPhase::Finished {
-                    Some(*id)
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        for id in finished {
-            self.remove(id);
-        }
+    pub fn history_limit(mut self, limit: usize) -> Self {
+        self.history_limit = limit;
+        self.history.truncate(limit);
+        self
     }
 
-    /// Renders all active notifications to the frame.
+    /// Sets whether notifications dropped by [`min_level`](Self::min_level)
+    /// are recorded in the history.
+    ///
+    /// Their records have `filtered` set and are already dismissed. Needs a
+    /// non-zero [`history_limit`](Self::history_limit). Defaults to `false`.
     ///
     /// # Arguments
-    /// * `frame` - The frame to render to
-    /// * `area` - The area to render within
+    /// * `record` - Whether to record filtered notifications
     ///
     /// # Example
     /// ```no_run
     /// use ratatui_notifications::notifications::Notifications;
-    /// use ratatui::backend::TestBackend;
-    /// use ratatui::Terminal;
     ///
-    /// let mut manager = Notifications::new();
-    /// let backend = TestBackend::new(80, 24);
-    /// let mut terminal = Terminal::new(backend).unwrap();
-    ///
-    /// terminal.draw(|frame| {
-    ///     manager.render(frame, frame.area());
-    /// }).unwrap();
+    /// let manager = Notifications::new().history_limit(100).record_filtered(true);
     /// ```
-    pub fn render(&mut self, frame: &mut Frame<'_>, _area: Rect) {
-        render_notifications(&mut self.states, &self.by_anchor, frame, self.max_concurrent);
+    pub fn record_filtered(mut self, record: bool) -> Self {
+        self.record_filtered = record;
+        self
     }
 
-    /// Enforces max_concurrent limit for the given anchor.
+    /// Returns the notification history, oldest first.
     ///
-    /// Removes oldest or newest notification as needed based on overflow behavior.
-    fn enforce_limit(&mut self, anchor: Anchor) {
-        if let Some(max) = self.max_concurrent {
-            let current_count = self.by_anchor
-                .get(&anchor)
-                .map_or(0, |ids| ids.len());
-
-            if current_count >= max {
-                // Remove one notification based on overflow behavior
-                let id_to_remove = match self.overflow {
-                    Overflow::DiscardOldest => self.find_oldest_at_anchor(anchor),
-                    Overflow::DiscardNewest => self.find_newest_at_anchor(anchor),
-                };
-
-                if let Some(id) = id_to_remove {
-                    self.remove(id);
-                }
-            }
-        }
+    /// Merged duplicates are recorded once. See
+    /// [`history_limit`](Self::history_limit).
+    pub fn history(&self) -> vec_deque::Iter<'_, NotificationRecord> {
+        self.history.iter()
     }
 
-    /// Finds the oldest notification at the given anchor.
-    fn find_oldest_at_anchor(&self, anchor: Anchor) -> Option<u64> {
-        self.by_anchor
-            .get(&anchor)?
-            .iter()
-            .filter_map(|id| {
-                self.states
-                    .get(id)
-                    .map(|state| (id, state.created_at))
-            })
-            .min_by_key(|&(_, created_at)| created_at)
-            .map(|(&id, _)| id)
+    /// Creates a [`NotificationCenter`] over this manager's history.
+    ///
+    /// Uses the manager's theme, strings and clock. Requires the `center`
+    /// feature and a non-zero [`history_limit`](Self::history_limit).
+    #[cfg(feature = "center")]
+    pub fn notification_center(&self) -> NotificationCenter<'_> {
+        NotificationCenter::new(self.history(), self.clock.now())
+            .theme(self.theme)
+            .strings(self.strings.clone())
     }
 
-    /// Finds the newest notification at the given anchor.
-    fn find_newest_at_anchor(&self, anchor: Anchor) -> Option<u64> {
-        self.by_anchor
-            .get(&anchor)?
-            .iter()
-            .filter_map(|id| {
-                self.states
-                    .get(id)
-                    .map(|state| (id, state.created_at))
-            })
-            .max_by_key(|&(_, created_at)| created_at)
-            .map(|(&id, _)| id)
+    /// Sets the overflow behavior when max_concurrent or max_total is reached.
+    ///
+    /// # Arguments
+    /// * `behavior` - Overflow behavior (DiscardOldest, DiscardNewest,
+    ///   MergeDuplicates or DiscardLowestPriority)
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, Overflow};
+    ///
+    /// let manager = Notifications::new()
+    ///     .overflow(Overflow::DiscardNewest);
+    /// ```
+    pub fn overflow(mut self, behavior: Overflow) -> Self {
+        self.overflow = behavior;
+        self
     }
-}
 
-impl Default for Notifications {
-    fn default() -> Self {
-        Self::new()
+    /// Sets the color capability of the target terminal.
+    ///
+    /// Every color the notifications draw is degraded to fit: quantized to the
+    /// 256-color palette, mapped to the 16 named colors, or stripped in favour
+    /// of modifiers for `Mono`.
+    ///
+    /// # Arguments
+    /// * `mode` - Color mode (default `TrueColor`)
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{ColorMode, Notifications};
+    ///
+    /// let manager = Notifications::new().color_mode(ColorMode::Ansi16);
+    /// ```
+    pub fn color_mode(mut self, mode: ColorMode) -> Self {
+        self.color_mode = mode;
+        self
+    }
+
+    /// Sets the theme used to style notifications by level.
+    ///
+    /// Per-notification style overrides still take precedence over the theme.
+    ///
+    /// # Arguments
+    /// * `theme` - Theme (default `Theme::default()`, color-only)
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{ColorMode, Notifications, Theme};
+    ///
+    /// let manager = Notifications::new()
+    ///     .color_mode(ColorMode::Mono)
+    ///     .theme(Theme::high_contrast());
+    /// ```
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Sets the order notifications are drawn in across all anchors.
+    ///
+    /// Notifications drawn later appear on top where they overlap. With
+    /// `SortBy::Level`, errors are drawn above warnings above infos
+    /// regardless of anchor.
+    ///
+    /// # Arguments
+    /// * `sort_by` - Draw order (default `SortBy::Age`, newest on top)
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, SortBy};
+    ///
+    /// let manager = Notifications::new().render_sort(SortBy::Level);
+    /// ```
+    pub fn render_sort(mut self, sort_by: SortBy) -> Self {
+        self.sort_by = sort_by;
+        self
+    }
+
+    /// Sets whether notifications sharing an anchor stack vertically or
+    /// side by side.
+    ///
+    /// Horizontal layouts keep the oldest notification at the anchor and
+    /// place newer ones beside it in the chosen direction, so right-hand
+    /// anchors usually want `StackLayout::RightToLeft`.
+    ///
+    /// # Arguments
+    /// * `layout` - Stack direction (default `StackLayout::Vertical`)
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, StackLayout};
+    ///
+    /// // A row of toasts along the bottom edge, growing leftward
+    /// let manager = Notifications::new().stack_layout(StackLayout::RightToLeft);
+    /// ```
+    pub fn stack_layout(mut self, layout: StackLayout) -> Self {
+        self.stack_layout = layout;
+        self
+    }
+
+    /// Replaces the user-visible wording the crate injects.
+    ///
+    /// Used for `describe()` sentences and theme title prefixes.
+    ///
+    /// # Arguments
+    /// * `strings` - Strings (default English)
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, Strings};
+    ///
+    /// let mut strings = Strings::default();
+    /// strings.dismissing = "Wird geschlossen.".into();
+    /// let manager = Notifications::new().strings(strings);
+    /// ```
+    pub fn strings(mut self, strings: Strings) -> Self {
+        self.strings = strings;
+        self
+    }
+
+    /// Sets how long [`move_to_anchor`](Self::move_to_anchor) transitions take.
+    ///
+    /// Defaults to 300ms. `Duration::ZERO` makes moves instant.
+    ///
+    /// # Arguments
+    /// * `duration` - Length of the move transition
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    /// use std::time::Duration;
+    ///
+    /// let manager = Notifications::new().move_duration(Duration::from_millis(500));
+    /// ```
+    pub fn move_duration(mut self, duration: Duration) -> Self {
+        self.move_duration = duration;
+        self
+    }
+
+    /// Notes how long notifications held back by [`suppress`](Self::suppress)
+    /// waited, e.g. "(delayed 12s)" after the content (default `false`).
+    ///
+    /// The wording comes from [`Strings`] (`StringKey::Delayed`).
+    ///
+    /// # Arguments
+    /// * `annotate` - Whether released notifications carry the note
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let manager = Notifications::new().annotate_delayed(true);
+    /// ```
+    pub fn annotate_delayed(mut self, annotate: bool) -> Self {
+        self.annotate_delayed = annotate;
+        self
+    }
+
+    /// Sets the fallbacks for `Timing::Auto`, `AutoDismiss::After(Duration::ZERO)`
+    /// and notifications built without an anchor.
+    ///
+    /// Applies to notifications added afterwards.
+    ///
+    /// # Arguments
+    /// * `defaults` - The manager-level defaults
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Anchor, ManagerDefaults, Notifications};
+    ///
+    /// let manager = Notifications::new().defaults(ManagerDefaults {
+    ///     default_anchor: Anchor::TopRight,
+    ///     ..ManagerDefaults::default()
+    /// });
+    /// ```
+    pub fn defaults(mut self, defaults: ManagerDefaults) -> Self {
+        self.defaults = defaults;
+        self
+    }
+
+    /// Returns the manager-level defaults; pass them to
+    /// [`generate_code_with_context`](crate::notifications::generate_code_with_context)
+    /// to reproduce what the manager shows.
+    pub fn manager_defaults(&self) -> &ManagerDefaults {
+        &self.defaults
+    }
+
+    /// Caps how much time a single [`tick`](Self::tick) applies.
+    ///
+    /// After a stall the next tick receives the whole stalled time, which
+    /// can carry notifications through their entry, display and exit in one
+    /// frame. With a cap, larger deltas are cut to `max` and the excess is
+    /// handled per [`catch_up`](Self::catch_up). Display deadlines from
+    /// `ttl` follow the clock and are not delayed. Defaults to `None`
+    /// (unlimited); 250ms suits most apps.
+    ///
+    /// # Arguments
+    /// * `max` - Largest delta applied per tick (`None` = unlimited)
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{CatchUpPolicy, Notifications};
+    /// use std::time::Duration;
+    ///
+    /// let manager = Notifications::new()
+    ///     .max_delta(Some(Duration::from_millis(250)))
+    ///     .catch_up(CatchUpPolicy::Spread);
+    /// ```
+    pub fn max_delta(mut self, max: Option<Duration>) -> Self {
+        self.max_delta = max;
+        self
+    }
+
+    /// Sets what happens to time cut off by [`max_delta`](Self::max_delta).
+    ///
+    /// `Clamp` (default) drops it. `Spread` applies it over the following
+    /// ticks, at most `max_delta` per tick.
+    ///
+    /// # Arguments
+    /// * `policy` - The catch-up policy
+    pub fn catch_up(mut self, policy: CatchUpPolicy) -> Self {
+        self.catch_up = policy;
+        self
+    }
+
+    /// Sets the axis corner anchors slide along by default.
+    ///
+    /// Applies to notifications at corner anchors whose slide direction is
+    /// `SlideDirection::Default`: `Diagonal` (default) slides in from the
+    /// corner, `Horizontal` from the side edge and `Vertical` from the top
+    /// or bottom edge. Notifications already added keep their axis.
+    ///
+    /// # Arguments
+    /// * `axis` - The default slide axis for corners
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{CornerSlideAxis, Notifications};
+    ///
+    /// // TopLeft notifications slide in from the left
+    /// let manager = Notifications::new().corner_slide_axis(CornerSlideAxis::Horizontal);
+    /// ```
+    pub fn corner_slide_axis(mut self, axis: CornerSlideAxis) -> Self {
+        self.corner_slide_axis = axis;
+        self
+    }
+
+    /// Sets which key presses `handle_event` answers by dismissing every
+    /// visible notification.
+    ///
+    /// Requires the `crossterm` feature.
+    ///
+    /// # Arguments
+    /// * `policy` - Keys that dismiss (default `DismissOnInput::Off`)
+    ///
+    /// # Example
+    /// ```no_run
+    /// use crossterm::event::KeyCode;
+    /// use ratatui_notifications::notifications::{DismissOnInput, Notifications};
+    ///
+    /// let manager = Notifications::new()
+    ///     .dismiss_on_input(DismissOnInput::Keys(vec![KeyCode::Esc]));
+    /// ```
+    #[cfg(feature = "crossterm")]
+    pub fn dismiss_on_input(mut self, policy: DismissOnInput) -> Self {
+        self.dismiss_on_input = policy;
+        self
+    }
+
+    /// Multiplies the animation speed of every notification.
+    ///
+    /// Combines with each notification's own `speed`: `0.5` runs everything
+    /// twice as slow, `2.0` twice as fast. Takes effect on the next tick,
+    /// including for notifications already shown. Display time is only
+    /// scaled for notifications with `speed_affects_dwell`. Defaults to `1.0`.
+    ///
+    /// # Arguments
+    /// * `speed` - Multiplier, greater than zero
+    ///
+    /// # Errors
+    /// Returns `NotificationError::InvalidConfig` if `speed` is not a finite
+    /// number greater than zero; the current speed is kept.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let mut manager = Notifications::new();
+    ///
+    /// // Turbo mode for power users
+    /// manager.global_speed(3.0).unwrap();
+    /// ```
+    pub fn global_speed(&mut self, speed: f32) -> Result<(), NotificationError> {
+        if !(speed > 0.0 && speed.is_finite()) {
+            return Err(NotificationError::InvalidConfig(format!(
+                "global speed must be a finite number greater than 0, got {speed}"
+            )));
+        }
+        self.global_speed = speed;
+        for state in self.states.values_mut() {
+            state.global_speed = speed;
+        }
+        Ok(())
+    }
+
+    /// Overrides the border and title colors of one level in the active theme.
+    ///
+    /// Only the foreground colors change; modifiers, border type and title
+    /// prefix of the level are kept, as is the styling of every other level.
+    /// Takes effect on the next render.
+    ///
+    /// # Arguments
+    /// * `level` - Level to restyle
+    /// * `border` - New border color
+    /// * `title` - New title color
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui::style::Color;
+    /// use ratatui_notifications::notifications::{Level, Notifications};
+    ///
+    /// let mut manager = Notifications::new();
+    ///
+    /// // Make debug output stand out while chasing a bug
+    /// manager.set_level_colors(Level::Debug, Color::LightMagenta, Color::Magenta);
+    /// ```
+    pub fn set_level_colors(&mut self, level: Level, border: Color, title: Color) {
+        let level_theme = self.theme.level_mut(level);
+        level_theme.border_style = level_theme.border_style.fg(border);
+        level_theme.title_style = level_theme.title_style.fg(title);
+    }
+
+    /// Sets the least severe level shown, e.g. `Level::Warn` hides info,
+    /// debug and trace notifications app-wide.
+    ///
+    /// Levels are ordered `Trace < Debug < Info < Warn < Error`. Only
+    /// notifications added afterwards are affected: `add` drops one below
+    /// the floor without showing it and still returns a fresh ID, which
+    /// `phase` reports as gone. Notifications without a level are never
+    /// dropped. Defaults to `Level::Trace`, which shows everything. See
+    /// [`record_filtered`](Self::record_filtered) to keep dropped ones in the
+    /// history.
+    ///
+    /// # Arguments
+    /// * `level` - Least severe level shown
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Level, Notifications};
+    ///
+    /// let mut manager = Notifications::new();
+    ///
+    /// // Quiet mode
+    /// manager.min_level(Level::Warn);
+    /// ```
+    pub fn min_level(&mut self, level: Level) {
+        self.min_level = level;
+    }
+
+    /// Replaces the time source used for notification timestamps.
+    ///
+    /// Defaults to `SystemClock` (or `PerformanceClock` on wasm32). Supply a
+    /// different [`Clock`] on targets without a usable `Instant::now()` or to
+    /// control time in tests.
+    ///
+    /// # Arguments
+    /// * `clock` - The clock to read timestamps from
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    /// use ratatui_notifications::notifications::traits::SystemClock;
+    ///
+    /// let manager = Notifications::new().with_clock(SystemClock);
+    /// ```
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Registers a [`Notifier`] told about each notification as it is shown.
+    ///
+    /// `tick` calls it once per notification, with its level and ID, when the
+    /// notification leaves `Pending`. Replaces any notifier set before.
+    ///
+    /// # Arguments
+    /// * `notifier` - The notifier, e.g. [`BellNotifier`](crate::notifications::traits::BellNotifier)
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{BellNotifier, Notifications};
+    ///
+    /// let mut manager = Notifications::new();
+    /// manager.set_notifier(Box::new(BellNotifier));
+    /// ```
+    pub fn set_notifier(&mut self, notifier: Box<dyn Notifier>) {
+        self.notifier = Some(notifier);
+    }
+
+    /// Honors the `NO_COLOR` environment variable (default `false`).
+    ///
+    /// When enabled and `NO_COLOR` is set to a non-empty value, the colors the
+    /// crate resolves (theme, fade, auto-contrast) are stripped while their
+    /// modifiers are kept. Styles set on a notification pass through unless
+    /// [`strip_user_colors`](Self::strip_user_colors) is also enabled.
+    /// `NO_COLOR` is read once by [`new`](Self::new).
+    ///
+    /// # Arguments
+    /// * `respect` - Whether `NO_COLOR` strips colors
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let manager = Notifications::new().respect_no_color(true);
+    /// ```
+    pub fn respect_no_color(mut self, respect: bool) -> Self {
+        self.respect_no_color = respect;
+        self
+    }
+
+    /// Keeps colors even when `NO_COLOR` is set, e.g. for a `--color=always`
+    /// flag (default `false`).
+    ///
+    /// # Arguments
+    /// * `force` - Whether to ignore `NO_COLOR`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let manager = Notifications::new().respect_no_color(true).force_colors(true);
+    /// ```
+    pub fn force_colors(mut self, force: bool) -> Self {
+        self.force_colors = force;
+        self
+    }
+
+    /// Also strips colors from user-provided styles and content spans when
+    /// `NO_COLOR` applies, as [`ColorMode::Mono`] does (default `false`).
+    ///
+    /// # Arguments
+    /// * `strip` - Whether user colors are stripped too
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let manager = Notifications::new().respect_no_color(true).strip_user_colors(true);
+    /// ```
+    pub fn strip_user_colors(mut self, strip: bool) -> Self {
+        self.strip_user_colors = strip;
+        self
+    }
+
+    /// Re-reads `NO_COLOR` through `lookup` instead of the process
+    /// environment.
+    ///
+    /// Lets tests and embedders decide the environment check without
+    /// mutating the real environment.
+    ///
+    /// # Arguments
+    /// * `lookup` - Returns the value of an environment variable, if set
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let manager = Notifications::new()
+    ///     .respect_no_color(true)
+    ///     .with_env_lookup(|key| (key == "NO_COLOR").then(|| "1".into()));
+    /// ```
+    pub fn with_env_lookup(mut self, lookup: impl Fn(&str) -> Option<std::ffi::OsString>) -> Self {
+        self.no_color_env = no_color_set(lookup);
+        self
+    }
+
+    /// Whether colors are currently stripped because of `NO_COLOR`.
+    pub fn colors_stripped(&self) -> bool {
+        self.respect_no_color && self.no_color_env && !self.force_colors
+    }
+
+    /// Returns the ID the next [`add`](Self::add) will assign, without
+    /// consuming it.
+    ///
+    /// Lets an app register handlers for a notification before adding it.
+    /// `add_hidden` and `restore_state` assign the same ID. With
+    /// `Overflow::MergeDuplicates`, an `add` merged into an active duplicate
+    /// returns that notification's ID instead and leaves this one unused.
+    ///
+    /// # Returns
+    /// * `Some(u64)` - The next ID
+    /// * `None` - If every `u64` ID has been assigned
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{NotificationBuilder, Notifications};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let id = manager.peek_next_id().unwrap();
+    /// // Register handlers for `id`...
+    /// let notif = NotificationBuilder::new("Upload started").build().unwrap();
+    /// assert_eq!(manager.add(notif), Ok(id));
+    /// ```
+    pub fn peek_next_id(&self) -> Option<u64> {
+        self.next_id
+    }
+
+    /// Adds a notification and returns its unique ID.
+    ///
+    /// If max_concurrent limit is reached for the notification's anchor, or
+    /// max_total across all anchors, applies the configured overflow
+    /// behavior. With `Overflow::MergeDuplicates`, a notification identical
+    /// in content and anchor to an active one is merged into it instead of
+    /// being added.
+    ///
+    /// IDs count up from 0 and are never reused within the manager's
+    /// lifetime, even after the notification holding one is removed, so they
+    /// can key long-lived maps.
+    ///
+    /// Adding clones of the same notification gives independent instances,
+    /// each with its own ID, timers and animation. Closures carried by the
+    /// notification (`live_content`) are shared between the clones and
+    /// called once per instance on every tick. Under
+    /// `Overflow::MergeDuplicates` a second clone merges into the first like
+    /// any other duplicate.
+    ///
+    /// # Arguments
+    /// * `notification` - The notification to add
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - The unique ID assigned to the notification, or the ID of
+    ///   the notification it was merged into
+    /// * `Err(NotificationError::LimitReached)` - If a limit is reached and
+    ///   every notification holding a slot is `Evictability::Never`
+    /// * `Err(NotificationError::IdsExhausted)` - If every `u64` ID is used
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let notif = NotificationBuilder::new("Hello!").build().unwrap();
+    /// let id = manager.add(notif).unwrap();
+    /// ```
+    pub fn add(&mut self, notification: Notification) -> Result<u64, NotificationError> {
+        self.insert(notification, false, None)
+    }
+
+    /// Adds a notification under a caller-chosen ID.
+    ///
+    /// IDs are unique for the lifetime of the manager: the ID must be higher
+    /// than every ID assigned so far, including those of notifications that
+    /// have since finished. Later `add` calls continue after it. The
+    /// notification is never merged as a duplicate.
+    ///
+    /// # Arguments
+    /// * `id` - The ID to assign
+    /// * `notification` - The notification to add
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - `id`
+    /// * `Err(NotificationError::IdAlreadyUsed)` - If `id` is not above every
+    ///   ID assigned so far
+    /// * `Err(NotificationError::IdsExhausted)` - If every `u64` ID is used
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{NotificationBuilder, Notifications};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let notif = NotificationBuilder::new("Job 1000 started").build().unwrap();
+    /// manager.add_with_id(1000, notif).unwrap();
+    ///
+    /// let next = NotificationBuilder::new("Hello").build().unwrap();
+    /// assert_eq!(manager.add(next).unwrap(), 1001);
+    /// ```
+    pub fn add_with_id(
+        &mut self,
+        id: u64,
+        notification: Notification,
+    ) -> Result<u64, NotificationError> {
+        self.insert(notification, false, Some(id))
+    }
+
+    /// Registers a named notification style.
+    ///
+    /// The preset receives a builder holding the call site's content and
+    /// applies the styling and timing shared by every notification of that
+    /// kind. Registering a name again replaces the earlier preset.
+    ///
+    /// # Arguments
+    /// * `name` - Name passed to [`add_preset`](Self::add_preset)
+    /// * `preset` - Applies the style to a builder
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Anchor, Level, Notifications};
+    ///
+    /// let mut manager = Notifications::new();
+    /// manager.register_preset("error", |builder| {
+    ///     builder.level(Level::Error).anchor(Anchor::TopCenter).title("Error")
+    /// });
+    ///
+    /// manager.add_preset("error", "Connection lost").unwrap();
+    /// ```
+    pub fn register_preset(
+        &mut self,
+        name: impl Into<String>,
+        preset: impl Fn(NotificationBuilder) -> NotificationBuilder + Send + Sync + 'static,
+    ) {
+        self.presets.insert(name.into(), Preset::new(Box::new(preset)));
+    }
+
+    /// Adds a notification styled by a registered preset.
+    ///
+    /// # Arguments
+    /// * `name` - Name the preset was registered under
+    /// * `content` - The notification's content
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - The unique ID assigned to the notification
+    /// * `Err(NotificationError::UnknownPreset)` - If no preset has that name
+    /// * `Err(NotificationError)` - If the preset's configuration fails to build
+    pub fn add_preset(
+        &mut self,
+        name: &str,
+        content: impl Into<Text<'static>>,
+    ) -> Result<u64, NotificationError> {
+        let preset = self
+            .presets
+            .get(name)
+            .ok_or_else(|| NotificationError::UnknownPreset(name.to_string()))?;
+        let notification = preset.apply(NotificationBuilder::new(content)).build()?;
+        self.add(notification)
+    }
+
+    /// Adds a notification that stays hidden until [`show`](Self::show) is
+    /// called.
+    ///
+    /// The notification waits in `AnimationPhase::Pending` across ticks: it
+    /// is not drawn and its entry animation and display countdown do not
+    /// start. It still counts towards `max_concurrent`, and a `ttl` still
+    /// runs from now. Hidden notifications are never merged as duplicates.
+    ///
+    /// # Arguments
+    /// * `notification` - The notification to add
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - The unique ID assigned to the notification
+    /// * `Err(NotificationError::IdsExhausted)` - If every `u64` ID is used
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{NotificationBuilder, Notifications};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let notif = NotificationBuilder::new("Build finished").build().unwrap();
+    /// let id = manager.add_hidden(notif).unwrap();
+    ///
+    /// // Later, when the user switches back to the window
+    /// manager.show(id);
+    /// ```
+    pub fn add_hidden(&mut self, notification: Notification) -> Result<u64, NotificationError> {
+        self.insert(notification, true, None)
+    }
+
+    /// Reveals a notification added with [`add_hidden`](Self::add_hidden).
+    ///
+    /// Its entry animation starts on the next tick.
+    ///
+    /// # Arguments
+    /// * `id` - The notification ID
+    ///
+    /// # Returns
+    /// `true` if the notification was hidden; `false` if it is unknown or
+    /// already shown.
+    pub fn show(&mut self, id: u64) -> bool {
+        match self.states.get_mut(&id) {
+            Some(state) if state.hidden => {
+                state.hidden = false;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Holds back new notifications, or releases those held back.
+    ///
+    /// While suppressed, [`add`](Self::add) accepts notifications and returns
+    /// their IDs but parks them: they are not drawn and no timer of theirs
+    /// runs. `suppress(false)` promotes them in the order they were added,
+    /// applying `max_concurrent`, `max_total` and the overflow policy as if
+    /// each were added then; their entry animation starts on the next tick
+    /// and their display time runs from there. Parked notifications are not
+    /// merged as duplicates.
+    ///
+    /// A `ttl` keeps running while parked, from the `add` call: a parked
+    /// notification whose TTL passes is dropped without appearing and
+    /// reported as [`DismissReason::Stale`], and one released in time keeps
+    /// its age.
+    ///
+    /// A parked notification is never dropped for lack of room: if only
+    /// `Evictability::Never` notifications hold its slot, it stays parked
+    /// and each [`tick`](Self::tick) retries it, until a slot frees up or it
+    /// is [`remove`](Self::remove)d.
+    ///
+    /// Unlike [`freeze`](Self::freeze), which halts notifications already on
+    /// screen, suppression leaves visible notifications running and only
+    /// delays new ones. Notifications from [`add_hidden`](Self::add_hidden)
+    /// are not parked.
+    ///
+    /// # Arguments
+    /// * `suppressed` - Whether to hold back new notifications
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{NotificationBuilder, Notifications};
+    ///
+    /// let mut manager = Notifications::new();
+    ///
+    /// // Opening a full-screen file picker
+    /// manager.suppress(true);
+    /// let notif = NotificationBuilder::new("Download finished").build().unwrap();
+    /// manager.add(notif).unwrap();
+    ///
+    /// // Back in the main view: the toast appears now
+    /// manager.suppress(false);
+    /// ```
+    pub fn suppress(&mut self, suppressed: bool) {
+        self.suppressed = suppressed;
+        if suppressed {
+            return;
+        }
+        self.release_parked();
+    }
+
+    /// Promotes parked notifications that have room, in the order they
+    /// were added. Those blocked by `Evictability::Never` notifications
+    /// stay parked, in order, for a later tick.
+    fn release_parked(&mut self) {
+        let now = self.clock.now();
+        self.drop_stale_parked(now);
+        for parked in std::mem::take(&mut self.parked) {
+            if self.no_room(parked.notification.anchor) {
+                self.parked.push(parked);
+                continue;
+            }
+            // Age and TTL run from the add() call, not the release
+            let waited = now.saturating_duration_since(parked.parked_at);
+            self.place(parked.id, parked.notification, false, parked.parked_at);
+            if let Some(remaining) = parked.remaining_display_time {
+                if let Some(state) = self.states.get_mut(&parked.id) {
+                    state.remaining_display_time = Some(remaining);
+                }
+            }
+            if self.annotate_delayed {
+                let secs = waited.as_secs().to_string();
+                let suffix = self.strings.format(StringKey::Delayed, &[("n", &secs)]);
+                if let Some(state) = self.states.get_mut(&parked.id) {
+                    state.set_delayed_suffix(suffix);
+                }
+            }
+        }
+    }
+
+    /// Drops parked notifications whose TTL passed while they waited.
+    ///
+    /// They are recorded in the history as added when parked and reported
+    /// as [`DismissReason::Stale`].
+    fn drop_stale_parked(&mut self, now: Timestamp) {
+        let (stale, waiting): (Vec<ParkedNotification>, Vec<ParkedNotification>) =
+            std::mem::take(&mut self.parked).into_iter().partition(|parked| {
+                parked
+                    .notification
+                    .ttl
+                    .is_some_and(|ttl| now.saturating_duration_since(parked.parked_at) >= ttl)
+            });
+        self.parked = waiting;
+        for parked in stale {
+            self.record_history(parked.id, &parked.notification, parked.parked_at, false);
+            self.mark_dismissed(&[(parked.id, parked.notification.level, DismissReason::Stale)]);
+        }
+    }
+
+    /// Returns whether new notifications are being held back by
+    /// [`suppress`](Self::suppress).
+    pub fn is_suppressed(&self) -> bool {
+        self.suppressed
+    }
+
+    /// Returns how many notifications are waiting for
+    /// [`suppress(false)`](Self::suppress), or for a free slot after it.
+    pub fn parked_count(&self) -> usize {
+        self.parked.len()
+    }
+
+    /// Halts time for one notification while the others keep animating.
+    ///
+    /// Its phase, animation progress and display countdown stop advancing,
+    /// and a `ttl` or `AtDeadline` deadline is not checked, until
+    /// [`unfreeze`](Self::unfreeze). Live content keeps updating and
+    /// [`remove`](Self::remove) still applies; one dismissed through
+    /// `retain` or `for_each_mut` holds at the start of its exit.
+    ///
+    /// # Arguments
+    /// * `id` - The notification ID
+    ///
+    /// # Returns
+    /// `true` if the notification exists
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Level, NotificationBuilder, Notifications};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let alert = NotificationBuilder::new("Disk almost full")
+    ///     .level(Level::Error)
+    ///     .build()
+    ///     .unwrap();
+    /// let id = manager.add(alert).unwrap();
+    ///
+    /// // Keep the alert up while transient notifications come and go
+    /// manager.freeze(id);
+    /// ```
+    pub fn freeze(&mut self, id: u64) -> bool {
+        self.set_frozen(id, true)
+    }
+
+    /// Lets time run again for a notification halted by
+    /// [`freeze`](Self::freeze).
+    ///
+    /// It resumes where it stopped; a deadline that passed while frozen
+    /// dismisses it on the next tick.
+    ///
+    /// # Arguments
+    /// * `id` - The notification ID
+    ///
+    /// # Returns
+    /// `true` if the notification exists
+    pub fn unfreeze(&mut self, id: u64) -> bool {
+        self.set_frozen(id, false)
+    }
+
+    /// Dismisses a notification once `condition` returns true.
+    ///
+    /// The condition is checked at the start of every tick, alongside the
+    /// notification's deadlines; when it returns true the notification
+    /// exits as with its time running out, or leaves its entry early. It
+    /// complements time-based dismissal: whichever comes first wins. The
+    /// condition is not checked while the notification is frozen, and is
+    /// dropped once it fires. Registering another replaces it.
+    ///
+    /// # Arguments
+    /// * `id` - The notification ID
+    /// * `condition` - Returns true when the notification should go
+    ///
+    /// # Returns
+    /// `true` if the notification exists
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{AutoDismiss, NotificationBuilder, Notifications};
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// let connected = Arc::new(AtomicBool::new(false));
+    /// let mut manager = Notifications::new();
+    /// let offline = NotificationBuilder::new("Connection lost")
+    ///     .auto_dismiss(AutoDismiss::Never)
+    ///     .build()
+    ///     .unwrap();
+    /// let id = manager.add(offline).unwrap();
+    ///
+    /// let flag = Arc::clone(&connected);
+    /// manager.dismiss_when(id, move || flag.load(Ordering::Relaxed));
+    /// // Later, from the network code
+    /// connected.store(true, Ordering::Relaxed);
+    /// ```
+    pub fn dismiss_when(
+        &mut self,
+        id: u64,
+        condition: impl FnMut() -> bool + Send + Sync + 'static,
+    ) -> bool {
+        match self.states.get_mut(&id) {
+            Some(state) => {
+                state.dismiss_condition = Some(DismissCondition::new(Box::new(condition)));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Shared implementation of `freeze` and `unfreeze`
+    fn set_frozen(&mut self, id: u64, frozen: bool) -> bool {
+        match self.states.get_mut(&id) {
+            Some(state) => {
+                state.frozen = frozen;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Shared implementation of `add`, `add_with_id` and `add_hidden`
+    fn insert(
+        &mut self,
+        mut notification: Notification,
+        hidden: bool,
+        requested_id: Option<u64>,
+    ) -> Result<u64, NotificationError> {
+        notification.resolve_anchor(self.defaults.default_anchor);
+
+        // Drop notifications below the severity floor
+        if notification.level.is_some_and(|level| level < self.min_level) {
+            let id = self.claim_id(requested_id)?;
+            if self.record_filtered {
+                let now = self.clock.now();
+                self.record_history(id, &notification, now, true);
+            }
+            return Ok(id);
+        }
+
+        // Merge into an active duplicate instead of adding
+        if !hidden
+            && !self.suppressed
+            && requested_id.is_none()
+            && self.overflow == Overflow::MergeDuplicates
+        {
+            if let Some(id) = self.find_duplicate(&notification) {
+                if let Some(state) = self.states.get_mut(&id) {
+                    let count = (state.multiplicity + 1).to_string();
+                    let suffix = self.strings.format(StringKey::MergeCount, &[("n", &count)]);
+                    state.merge_duplicate(suffix);
+                }
+                return Ok(id);
+            }
+        }
+
+        // Nothing may give up its slot; parked notifications wait for one
+        let parking = self.suppressed && !hidden;
+        if !parking && self.no_room(notification.anchor) {
+            return Err(NotificationError::LimitReached);
+        }
+
+        let id = self.claim_id(requested_id)?;
+        let now = self.clock.now();
+
+        // Park until suppress(false)
+        if parking {
+            self.parked.push(ParkedNotification {
+                id,
+                notification,
+                parked_at: now,
+                remaining_display_time: None,
+            });
+            return Ok(id);
+        }
+
+        self.place(id, notification, hidden, now);
+        Ok(id)
+    }
+
+    /// Makes room under the limits and starts managing a notification.
+    ///
+    /// `added_at` is when `add` was called, which its age and TTL run from.
+    fn place(&mut self, id: u64, notification: Notification, hidden: bool, added_at: Timestamp) {
+        let anchor = notification.anchor;
+
+        // Check and enforce limits
+        self.enforce_limit(anchor);
+        self.enforce_total_limit();
+
+        // Create state
+        self.record_history(id, &notification, added_at, false);
+        let mut state = NotificationState::new_at(id, notification, &self.defaults, added_at);
+        state.hidden = hidden;
+        state.global_speed = self.global_speed;
+        state.corner_slide_axis = self.corner_slide_axis;
+
+        // Add to maps
+        self.states.insert(id, state);
+        self.by_anchor.entry(anchor).or_default().push(id);
+    }
+
+    /// Takes the next ID, or `requested_id` if it has not been handed out yet.
+    fn claim_id(&mut self, requested_id: Option<u64>) -> Result<u64, NotificationError> {
+        // IDs are never reused, so the counter only moves up
+        let next_id = self.next_id.ok_or(NotificationError::IdsExhausted)?;
+        let id = match requested_id {
+            Some(id) if id < next_id => return Err(NotificationError::IdAlreadyUsed(id)),
+            Some(id) => id,
+            None => next_id,
+        };
+        self.next_id = id.checked_add(1);
+        debug_assert!(!self.states.contains_key(&id), "ID {id} is still in use");
+        Ok(id)
+    }
+
+    /// Takes over a state built with [`NotificationState::builder`].
+    ///
+    /// The state keeps its phase, progress, display time and creation time
+    /// but gets a fresh ID. Unlike `add` it skips duplicate merging and the
+    /// `max_concurrent` / total limits; it is recorded in the history and
+    /// ticks and renders like any other notification.
+    ///
+    /// # Arguments
+    /// * `state` - The state to take over
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - The ID assigned to the state
+    /// * `Err(NotificationError::IdsExhausted)` - If every ID has been used
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{
+    ///     AnimationPhase, NotificationBuilder, NotificationState, Notifications,
+    /// };
+    ///
+    /// let notification = NotificationBuilder::new("Replayed").build().unwrap();
+    /// let state = NotificationState::builder(notification)
+    ///     .phase(AnimationPhase::Dwelling)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mut manager = Notifications::new();
+    /// let id = manager.adopt(state).unwrap();
+    /// assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    /// ```
+    pub fn adopt(&mut self, mut state: NotificationState) -> Result<u64, NotificationError> {
+        let id = self.claim_id(None)?;
+        state.id = id;
+        state.global_speed = self.global_speed;
+        state.corner_slide_axis = self.corner_slide_axis;
+        self.record_history(id, &state.notification, state.created_at, false);
+
+        let anchor = state.notification.anchor;
+        self.states.insert(id, state);
+        self.by_anchor.entry(anchor).or_default().push(id);
+
+        Ok(id)
+    }
+
+    /// Removes a notification by ID.
+    ///
+    /// Also drops a notification parked by [`suppress`](Self::suppress).
+    ///
+    /// # Arguments
+    /// * `id` - The notification ID to remove
+    ///
+    /// # Returns
+    /// * `true` - If the notification existed and was removed
+    /// * `false` - If the notification didn't exist
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Notifications, NotificationBuilder};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let notif = NotificationBuilder::new("Test").build().unwrap();
+    /// let id = manager.add(notif).unwrap();
+    /// assert!(manager.remove(id));
+    /// ```
+    pub fn remove(&mut self, id: u64) -> bool {
+        self.remove_with(id, DismissReason::Manual)
+    }
+
+    /// Shared implementation of `remove` and the other ways out.
+    ///
+    /// A notification that already started leaving keeps the reason it
+    /// started with; otherwise it is reported as dismissed for `reason`.
+    fn remove_with(&mut self, id: u64, reason: DismissReason) -> bool {
+        if let Some(state) = self.states.remove(&id) {
+            // Remove from anchor map
+            let anchor = state.notification.anchor;
+            if let Some(ids) = self.by_anchor.get_mut(&anchor) {
+                ids.retain(|&existing_id| existing_id != id);
+            }
+            let reason = state.dismiss_reason.unwrap_or(reason);
+            self.mark_dismissed(&[(id, state.notification.level, reason)]);
+            true
+        } else if let Some(index) = self.parked.iter().position(|parked| parked.id == id) {
+            let parked = self.parked.remove(index);
+            self.mark_dismissed(&[(id, parked.notification.level, reason)]);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Replaces a notification's content, ending any placeholder.
+    ///
+    /// If the new content changes the box size, the box eases from where
+    /// it is drawn now to its new rect over the manager's
+    /// [`move_duration`](Self::move_duration), like an anchor move.
+    ///
+    /// # Arguments
+    /// * `id` - The notification ID
+    /// * `content` - The new content
+    ///
+    /// # Returns
+    /// `true` if the notification exists
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{
+    ///     NotificationBuilder, Notifications, PlaceholderStyle,
+    /// };
+    ///
+    /// let mut manager = Notifications::new();
+    /// let notification = NotificationBuilder::new("")
+    ///     .placeholder(PlaceholderStyle::Spinner)
+    ///     .build()
+    ///     .unwrap();
+    /// let id = manager.add(notification).unwrap();
+    ///
+    /// manager.update_content(id, "3 files uploaded");
+    /// ```
+    pub fn update_content(&mut self, id: u64, content: impl Into<Text<'static>>) -> bool {
+        let Some(state) = self.states.get_mut(&id) else {
+            return false;
+        };
+        let anchor = state.notification.anchor;
+        state.begin_move(anchor, self.move_duration);
+        state.set_content(content.into());
+        true
+    }
+
+    /// Appends a line to a notification, turning it into a live tail.
+    ///
+    /// The notification keeps only as many lines as its content area shows
+    /// at its maximum height, dropping the oldest, and always shows the
+    /// newest lines that fit. Until the box reaches its maximum height it
+    /// grows with each line, easing like [`update_content`](Self::update_content).
+    ///
+    /// # Arguments
+    /// * `id` - The notification ID
+    /// * `line` - The line to append
+    ///
+    /// # Returns
+    /// `true` if the notification exists
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{
+    ///     AutoDismiss, NotificationBuilder, Notifications, SizeConstraint,
+    /// };
+    ///
+    /// let mut manager = Notifications::new();
+    /// let tail = NotificationBuilder::new("")
+    ///     .title("cargo build")
+    ///     .auto_dismiss(AutoDismiss::Never)
+    ///     .max_size(SizeConstraint::Absolute(60), SizeConstraint::Absolute(8))
+    ///     .build()
+    ///     .unwrap();
+    /// let id = manager.add(tail).unwrap();
+    ///
+    /// manager.push_line(id, "   Compiling ratatui v0.29.0");
+    /// ```
+    pub fn push_line(&mut self, id: u64, line: impl Into<Line<'static>>) -> bool {
+        let Some(state) = self.states.get_mut(&id) else {
+            return false;
+        };
+        let anchor = state.notification.anchor;
+        state.begin_move(anchor, self.move_duration);
+        state.push_line(line.into());
+        true
+    }
+
+    /// Moves a notification to a different anchor.
+    ///
+    /// The notification eases from where it is drawn now to its resting
+    /// place at the new anchor over the manager's
+    /// [`move_duration`](Self::move_duration), and stacks with the new
+    /// anchor's notifications from then on. The old anchor's stack closes
+    /// the gap immediately. A notification that has not been rendered yet
+    /// simply appears at the new anchor.
+    ///
+    /// # Arguments
+    /// * `id` - The notification ID
+    /// * `anchor` - The anchor to move to
+    ///
+    /// # Returns
+    /// * `true` - If the notification exists (including when already at `anchor`)
+    /// * `false` - If the ID is unknown
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Anchor, NotificationBuilder, Notifications};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let notif = NotificationBuilder::new("Connection lost").build().unwrap();
+    /// let id = manager.add(notif).unwrap();
+    ///
+    /// // Later, when it becomes urgent:
+    /// manager.move_to_anchor(id, Anchor::MiddleCenter);
+    /// ```
+    pub fn move_to_anchor(&mut self, id: u64, anchor: Anchor) -> bool {
+        let Some(state) = self.states.get_mut(&id) else {
+            return false;
+        };
+        let old_anchor = state.notification.anchor;
+        if old_anchor == anchor {
+            return true;
+        }
+
+        state.begin_move(anchor, self.move_duration);
+        if let Some(ids) = self.by_anchor.get_mut(&old_anchor) {
+            ids.retain(|&existing_id| existing_id != id);
+        }
+        self.by_anchor.entry(anchor).or_default().push(id);
+        true
+    }
+
+    /// Dismisses a notification by collapsing it into a screen position.
+    ///
+    /// Instead of its animation's usual exit, the notification shrinks from
+    /// where it rests to a single cell at `target` while fading, e.g. into
+    /// a bell icon in the app's status bar. The exit takes the
+    /// notification's exit duration and finishes like any other. A pending
+    /// notification finishes without being shown, and one that is already
+    /// leaving keeps its current exit.
+    ///
+    /// # Arguments
+    /// * `id` - The notification ID
+    /// * `target` - Screen cell to collapse into
+    ///
+    /// # Returns
+    /// * `true` - If the notification exists
+    /// * `false` - If the ID is unknown
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui::layout::Position;
+    /// use ratatui_notifications::notifications::{NotificationBuilder, Notifications};
+    ///
+    /// let mut manager = Notifications::new();
+    /// let notif = NotificationBuilder::new("New message").build().unwrap();
+    /// let id = manager.add(notif).unwrap();
+    ///
+    /// // The user acted on it; send it to the bell at the top-right
+    /// manager.dismiss_toward(id, Position::new(79, 0));
+    /// ```
+    pub fn dismiss_toward(&mut self, id: u64, target: Position) -> bool {
+        match self.states.get_mut(&id) {
+            Some(state) => {
+                state.dismiss_toward(target);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes all notifications, including those parked by
+    /// [`suppress`](Self::suppress).
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let mut manager = Notifications::new();
+    /// // ... add notifications ...
+    /// manager.clear();
+    /// ```
+    pub fn clear(&mut self) {
+        let mut dismissed: Vec<(u64, Option<Level>, DismissReason)> = self
+            .states
+            .values()
+            .map(|state| {
+                let reason = state.dismiss_reason.unwrap_or(DismissReason::Manual);
+                (state.id, state.notification.level, reason)
+            })
+            .collect();
+        dismissed.extend(
+            self.parked
+                .iter()
+                .map(|parked| (parked.id, parked.notification.level, DismissReason::Manual)),
+        );
+        dismissed.sort_unstable_by_key(|&(id, _, _)| id);
+        self.mark_dismissed(&dismissed);
+        self.states.clear();
+        self.by_anchor.clear();
+        self.parked.clear();
+    }
+
+    /// Removes all notifications and returns their configurations.
+    ///
+    /// Animation progress and other transient state are discarded; the
+    /// returned notifications can be added to this or another manager as
+    /// fresh notifications.
+    ///
+    /// # Returns
+    /// The removed notifications, oldest first
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{NotificationBuilder, Notifications};
+    ///
+    /// let mut old_manager = Notifications::new();
+    /// old_manager.add(NotificationBuilder::new("Saved").build().unwrap()).unwrap();
+    ///
+    /// let mut new_manager = Notifications::new();
+    /// for notification in old_manager.drain() {
+    ///     new_manager.add(notification).unwrap();
+    /// }
+    /// ```
+    pub fn drain(&mut self) -> Vec<Notification> {
+        self.by_anchor.clear();
+        let mut states: Vec<NotificationState> =
+            self.states.drain().map(|(_, state)| state).collect();
+        states.sort_by_key(|state| state.id);
+        let dismissed: Vec<(u64, Option<Level>, DismissReason)> = states
+            .iter()
+            .map(|state| {
+                let reason = state.dismiss_reason.unwrap_or(DismissReason::Manual);
+                (state.id, state.notification.level, reason)
+            })
+            .collect();
+        self.mark_dismissed(&dismissed);
+        states.into_iter().map(|state| state.notification).collect()
+    }
+
+    /// Snapshots the notifications that are still showing or waiting to show.
+    ///
+    /// Exiting and finished notifications are left out. Animation progress
+    /// is not saved; the display time left, a `ttl`'s time left and whether
+    /// the notification is hidden are. Live content closures are not saved
+    /// (with the `serde` feature they are dropped on serialization).
+    ///
+    /// # Returns
+    /// The saved notifications, oldest first
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let manager = Notifications::new();
+    /// let saved = manager.save_state();
+    /// // With the `serde` feature: serde_json::to_string(&saved)
+    /// ```
+    pub fn save_state(&self) -> SavedNotifications {
+        let now = self.clock.now();
+        SavedNotifications {
+            notifications: self
+                .ids_oldest_first()
+                .into_iter()
+                .filter_map(|id| self.states[&id].saved(now))
+                .collect(),
+        }
+    }
+
+    /// Re-adds notifications from a [`save_state`](Self::save_state) snapshot.
+    ///
+    /// Each notification gets a new ID and a fresh entry animation, then
+    /// dwells for the display time it had left. Hidden notifications stay
+    /// hidden until [`show`](Self::show). Limits and duplicate merging apply
+    /// as for [`add`](Self::add). While [`suppress`](Self::suppress)ed they
+    /// are parked like added ones, and still dwell for the time they had
+    /// left once released.
+    ///
+    /// # Arguments
+    /// * `saved` - The snapshot to restore
+    ///
+    /// # Returns
+    /// The new IDs, in the snapshot's order
+    pub fn restore_state(&mut self, saved: SavedNotifications) -> Vec<u64> {
+        let mut ids = Vec::with_capacity(saved.notifications.len());
+        for entry in saved.notifications {
+            let fresh_id = self.next_id;
+            let Ok(id) = self.insert(entry.notification, entry.hidden, None) else {
+                continue;
+            };
+            // Merged duplicates keep their own timers; parked ones get theirs
+            // on release
+            if Some(id) == fresh_id {
+                if let Some(state) = self.states.get_mut(&id) {
+                    state.remaining_display_time = entry.remaining_display_time;
+                } else if let Some(parked) = self.parked.iter_mut().find(|parked| parked.id == id) {
+                    parked.remaining_display_time = entry.remaining_display_time;
+                }
+            }
+            ids.push(id);
+        }
+        ids
+    }
+
+    /// Dismisses every notification for which `keep` returns `false`.
+    ///
+    /// Dismissed notifications leave with their exit animation; ones still
+    /// entering reverse from where they are, and ones not yet shown are
+    /// removed immediately. `keep` sees each notification once, oldest
+    /// first, including those already exiting. The dismissals are reported
+    /// as [`DismissReason::Retained`].
+    ///
+    /// # Arguments
+    /// * `keep` - Returns whether a notification should stay
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Level, Notifications};
+    ///
+    /// let mut manager = Notifications::new();
+    /// // ... add notifications ...
+    ///
+    /// // Quiet mode: drop debug and trace toasts
+    /// manager.retain(|info| !matches!(info.level, Some(Level::Debug | Level::Trace)));
+    /// ```
+    pub fn retain(&mut self, mut keep: impl FnMut(&NotificationInfo) -> bool) {
+        let now = self.clock.now();
+        for id in self.ids_oldest_first() {
+            let state = self.states.get_mut(&id).expect("id from states");
+            if !keep(&state.info(now)) {
+                state.dismiss(DismissReason::Retained);
+                if state.current_phase == AnimationPhase::Finished {
+                    self.remove_with(id, DismissReason::Retained);
+                }
+            }
+        }
+    }
+
+    /// Brings every anchor within `max_concurrent`, and the manager within
+    /// `max_total`, right away.
+    ///
+    /// Limits are otherwise only applied when a notification is added, so
+    /// call this after lowering them. Excess notifications are chosen by the
+    /// overflow policy (the newest with `Overflow::DiscardNewest`, the lowest
+    /// priority with `Overflow::DiscardLowestPriority`, the oldest otherwise)
+    /// and leave with their exit animation. Notifications already leaving
+    /// count towards the limits only with
+    /// [`count_exiting(true)`](Self::count_exiting), as in `add`; they are
+    /// never chosen, since they are on their way out.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    ///
+    /// let mut manager = Notifications::new();
+    /// // ... five notifications are showing ...
+    ///
+    /// manager = manager.max_concurrent(Some(2));
+    /// manager.enforce_limits();
+    /// ```
+    pub fn enforce_limits(&mut self) {
+        // Notifications holding a slot, in the order the policy dismisses them
+        let mut staying: Vec<(Timestamp, u64, Anchor)> = self
+            .states
+            .values()
+            .filter(|state| self.occupies_slot(state))
+            .map(|state| (state.created_at, state.id, state.notification.anchor))
+            .collect();
+        staying.sort_unstable_by_key(|&(created_at, id, _)| (created_at, id));
+        match self.overflow {
+            Overflow::DiscardNewest => staying.reverse(),
+            // Stable, so equal priorities stay oldest first
+            Overflow::DiscardLowestPriority => staying.sort_by(|a, b| {
+                let priority = |id| self.states[id].effective_priority();
+                priority(&a.1).total_cmp(&priority(&b.1))
+            }),
+            Overflow::DiscardOldest | Overflow::MergeDuplicates => {}
+        }
+        // Evictability trumps the policy; stable, so the policy orders each class
+        let evictable = |id: &u64| self.states[id].notification.evictable;
+        staying.sort_by_key(|(_, id, _)| evictable(id).rank());
+        // Exiting notifications may hold a slot but are already going
+        let choosable = |id: &u64| evictable(id) != Evictability::Never && !leaving(&self.states[id]);
+
+        let mut excess = Vec::new();
+        if let Some(max) = self.max_concurrent {
+            let mut counts: HashMap<Anchor, usize> = HashMap::new();
+            for &(_, _, anchor) in &staying {
+                *counts.entry(anchor).or_default() += 1;
+            }
+            for &(_, id, anchor) in &staying {
+                let count = counts.get_mut(&anchor).expect("counted above");
+                if *count > max && choosable(&id) {
+                    *count -= 1;
+                    excess.push(id);
+                }
+            }
+        }
+        if let Some(max) = self.max_total {
+            let mut remaining = staying.len() - excess.len();
+            for &(_, id, _) in &staying {
+                if remaining <= max {
+                    break;
+                }
+                if !excess.contains(&id) && choosable(&id) {
+                    excess.push(id);
+                    remaining -= 1;
+                }
+            }
+        }
+
+        for id in excess {
+            let state = self.states.get_mut(&id).expect("id from states");
+            state.dismiss(DismissReason::Evicted);
+            if state.current_phase == AnimationPhase::Finished {
+                self.remove_with(id, DismissReason::Evicted);
+            }
+        }
+    }
+
+    /// Applies the `dismiss_on_input` policy to a terminal event.
+    ///
+    /// A key press the policy covers dismisses every visible notification
+    /// with its exit animation; entering ones reverse from where they are.
+    /// Notifications still waiting to show or already leaving are left
+    /// alone, and under [`DismissOnInput::Any`] so are those built with
+    /// `require_ack(true)`. The dismissals are reported as
+    /// [`DismissReason::UserInput`]. Requires the `crossterm` feature.
+    ///
+    /// # Arguments
+    /// * `event` - Event read from the terminal
+    ///
+    /// # Returns
+    /// `true` if the event dismissed at least one notification and should
+    /// not be handled further; `false` if nothing was visible, the event is
+    /// not a key press, or the policy does not cover the key.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use crossterm::event;
+    /// use ratatui_notifications::notifications::{DismissOnInput, Notifications};
+    ///
+    /// let mut manager = Notifications::new().dismiss_on_input(DismissOnInput::Any);
+    ///
+    /// let event = event::read().unwrap();
+    /// if !manager.handle_event(&event) {
+    ///     // ... the app handles the key ...
+    /// }
+    /// ```
+    #[cfg(feature = "crossterm")]
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        let Event::Key(key) = event else {
+            return false;
+        };
+        if key.kind == KeyEventKind::Release || !self.dismiss_on_input.dismisses(key.code) {
+            return false;
+        }
+        let any_key = self.dismiss_on_input == DismissOnInput::Any;
+        let mut consumed = false;
+        for state in self.states.values_mut() {
+            let phase = state.current_phase;
+            let showing = !matches!(phase, AnimationPhase::Pending | AnimationPhase::Finished)
+                && phase != AnimationPhase::exit(state.notification.animation);
+            if showing && !(any_key && state.notification.require_ack) {
+                state.dismiss(DismissReason::UserInput);
+                consumed = true;
+            }
+        }
+        consumed
+    }
+
+    /// Calls `f` with a mutable handle to every notification, oldest first.
+    ///
+    /// The [`NotificationMut`] handle allows changes that keep the manager
+    /// consistent, such as extending the display time, changing the level or
+    /// content, or dismissing.
+    ///
+    /// # Arguments
+    /// * `f` - Called once per notification
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Level, Notifications};
+    /// use std::time::Duration;
+    ///
+    /// let mut manager = Notifications::new();
+    /// // ... add notifications ...
+    ///
+    /// // Give errors more time to be read
+    /// manager.for_each_mut(|mut notification| {
+    ///     if notification.info().level == Some(Level::Error) {
+    ///         notification.extend_display_time(Duration::from_secs(5));
+    ///     }
+    /// });
+    /// ```
+    pub fn for_each_mut(&mut self, mut f: impl FnMut(NotificationMut<'_>)) {
+        let now = self.clock.now();
+        let mut dismissed = Vec::new();
+        for id in self.ids_oldest_first() {
+            let state = self.states.get_mut(&id).expect("id from states");
+            f(NotificationMut::new(state, now));
+            if state.current_phase == AnimationPhase::Finished {
+                dismissed.push(id);
+            }
+        }
+        for id in dismissed {
+            self.remove_with(id, DismissReason::Retained);
+        }
+    }
+
+    /// IDs of all states in insertion order.
+    pub(crate) fn ids_oldest_first(&self) -> Vec<u64> {
+        let mut ids: Vec<u64> = self.states.keys().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Updates all notification animations.
+    ///
+    /// Call this once per frame with the elapsed time since the last update.
+    /// Notifications built with `live_content` have their content recomputed
+    /// first. Deltas above [`max_delta`](Self::max_delta) are clamped.
+    ///
+    /// # Arguments
+    /// * `delta` - Time elapsed since last tick
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    /// use std::time::Duration;
+    ///
+    /// let mut manager = Notifications::new();
+    /// manager.tick(Duration::from_millis(16)); // ~60 FPS
+    /// ```
+    pub fn tick(&mut self, delta: Duration) {
+        let now = self.clock.now();
+        if !self.parked.is_empty() {
+            self.drop_stale_parked(now);
+        }
+
+        // Refresh live content and start exits for passed deadlines, then
+        // advance every state
+        for state in self.states.values_mut() {
+            state.refresh_live_content();
+            state.track_elapsed(now);
+            if !state.frozen {
+                state.check_deadline(now);
+                state.check_dismiss_condition();
+            }
+        }
+        let pending = self.pending_ids();
+        let delta = self.budget_delta(delta);
+        let finished = update_states(&mut self.states, delta);
+        self.notify_shown(&pending);
+
+        // Remove finished notifications
+        for id in finished {
+            self.remove_with(id, DismissReason::Timeout);
+        }
+
+        // Freed slots go to notifications still waiting since suppress(false)
+        if !self.suppressed && !self.parked.is_empty() {
+            self.release_parked();
+        }
+    }
+
+    /// IDs still pending, oldest first, when a notifier wants to hear about them.
+    fn pending_ids(&self) -> Vec<u64> {
+        if self.notifier.is_none() {
+            return Vec::new();
+        }
+        let mut pending: Vec<u64> = self
+            .states
+            .values()
+            .filter(|state| state.current_phase == AnimationPhase::Pending)
+            .map(|state| state.id)
+            .collect();
+        pending.sort_unstable();
+        pending
+    }
+
+    /// Tells the notifier about the formerly pending notifications now shown.
+    ///
+    /// Ticking only moves a pending notification into its entry, so any
+    /// that left `Pending` was shown, even if it also finished in the same
+    /// tick; finished states are removed only after this runs.
+    fn notify_shown(&mut self, pending: &[u64]) {
+        let Some(notifier) = self.notifier.as_mut() else {
+            return;
+        };
+        for id in pending {
+            let Some(state) = self.states.get(id) else {
+                continue;
+            };
+            if state.current_phase != AnimationPhase::Pending {
+                notifier.notify(state.notification.level, *id);
+            }
+        }
+    }
+
+    /// Applies `max_delta` and the catch-up policy to a tick's delta.
+    fn budget_delta(&mut self, delta: Duration) -> Duration {
+        let Some(max) = self.max_delta else {
+            return delta;
+        };
+        // With nothing to animate nothing falls behind, so later
+        // notifications are not rushed
+        let spread = self.catch_up == CatchUpPolicy::Spread && !self.states.is_empty();
+        let owed = if spread { delta.saturating_add(self.catch_up_debt) } else { delta };
+        let applied = owed.min(max);
+        self.catch_up_debt = if spread { owed - applied } else { Duration::ZERO };
+        applied
+    }
+
+    /// Ticks a shared manager from a background thread.
+    ///
+    /// For apps without a render loop. Every `interval` the thread locks the
+    /// manager and calls [`tick`](Self::tick) with the time elapsed on the
+    /// manager's clock (see [`with_clock`](Self::with_clock)), so a
+    /// `TestClock` controls how far each tick advances.
+    /// Other threads keep adding and removing through the same mutex; ticks
+    /// only hold the lock for the duration of one `tick`. Rendering is not
+    /// done for you: `render` must still be called from the UI thread
+    /// (locking the manager) whenever you draw.
+    ///
+    /// Requires the `std-thread` feature.
+    ///
+    /// # Arguments
+    /// * `manager` - The shared manager to tick
+    /// * `interval` - Time between ticks
+    ///
+    /// # Returns
+    /// A [`TickerHandle`]; stopping or dropping it stops the thread.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{NotificationBuilder, Notifications};
+    /// use std::sync::{Arc, Mutex};
+    /// use std::time::Duration;
+    ///
+    /// let manager = Arc::new(Mutex::new(Notifications::new()));
+    /// let ticker = Notifications::spawn_ticker(&manager, Duration::from_millis(16));
+    ///
+    /// let notif = NotificationBuilder::new("Job queued").build().unwrap();
+    /// manager.lock().unwrap().add(notif).unwrap();
+    ///
+    /// // ... later, from the UI thread:
+    /// // terminal.draw(|frame| manager.lock().unwrap().render(frame, frame.area()))?;
+    ///
+    /// ticker.stop();
+    /// ```
+    #[cfg(feature = "std-thread")]
+    pub fn spawn_ticker(manager: &Arc<Mutex<Self>>, interval: Duration) -> TickerHandle {
+        spawn_ticker(Arc::clone(manager), interval)
+    }
+
+    /// Reads the manager's clock, for the ticker thread.
+    #[cfg(feature = "std-thread")]
+    pub(crate) fn now(&self) -> Timestamp {
+        self.clock.now()
+    }
+
+    /// Renders all active notifications to the frame.
+    ///
+    /// Output depends only on the state as of the last [`tick`](Self::tick)
+    /// and the frame's area, so rendering several times per frame (e.g. into
+    /// layered buffers) draws the same thing each time. The only thing
+    /// render records is each notification's laid-out rect, used by
+    /// [`content_rect`](Self::content_rect).
+    ///
+    /// Notifications are laid out over the whole frame; render
+    /// [`NotificationsWidget`] to confine them to part of it. An empty `area`
+    /// (e.g. a collapsed pane) draws nothing; [`tick`](Self::tick) still
+    /// advances every timer. A thin convenience over
+    /// [`render_buffer`](Self::render_buffer).
+    ///
+    /// # Arguments
+    /// * `frame` - The frame to render to
+    /// * `area` - The area to render within
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    /// use ratatui::backend::TestBackend;
+    /// use ratatui::Terminal;
+    ///
+    /// let mut manager = Notifications::new();
+    /// let backend = TestBackend::new(80, 24);
+    /// let mut terminal = Terminal::new(backend).unwrap();
+    ///
+    /// terminal.draw(|frame| {
+    ///     manager.render(frame, frame.area());
+    /// }).unwrap();
+    /// ```
+    pub fn render(&mut self, frame: &mut Frame<'_>, area: Rect) {
+        if area.is_empty() {
+            return;
+        }
+        let area = frame.area();
+        self.render_buffer(area, frame.buffer_mut());
+    }
+
+    /// Renders all active notifications into a buffer, anchored and stacked
+    /// within `area`.
+    ///
+    /// Needs only a [`Buffer`] and a [`Rect`], so it works from any widget,
+    /// custom backend or ratatui fork that can hand out a buffer. Like
+    /// [`render`](Self::render), an empty `area` draws nothing.
+    ///
+    /// # Arguments
+    /// * `area` - The area notifications are anchored and stacked within
+    /// * `buf` - The buffer to draw into
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::Notifications;
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    ///
+    /// let mut manager = Notifications::new();
+    /// let area = Rect::new(0, 0, 80, 24);
+    /// let mut buf = Buffer::empty(area);
+    ///
+    /// manager.render_buffer(area, &mut buf);
+    /// ```
+    pub fn render_buffer(&mut self, area: Rect, buf: &mut Buffer) {
+        self.render_into(area, buf, None, None);
+    }
+
+    /// Renders only the notifications in one [`Layer`].
+    ///
+    /// Call it twice per frame around the app's own overlays: `Below` before
+    /// drawing modals, `Above` after. Both passes lay out every notification,
+    /// so the two layers stack together without overlapping.
+    ///
+    /// # Arguments
+    /// * `frame` - The frame to render to
+    /// * `area` - The area to render within
+    /// * `layer` - The layer to draw
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Layer, Notifications};
+    /// use ratatui::backend::TestBackend;
+    /// use ratatui::Terminal;
+    ///
+    /// let mut manager = Notifications::new();
+    /// let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    ///
+    /// terminal.draw(|frame| {
+    ///     manager.render_layer(frame, frame.area(), Layer::Below);
+    ///     // ... draw the app's modals ...
+    ///     manager.render_layer(frame, frame.area(), Layer::Above);
+    /// }).unwrap();
+    /// ```
+    pub fn render_layer(&mut self, frame: &mut Frame<'_>, area: Rect, layer: Layer) {
+        if area.is_empty() {
+            return;
+        }
+        let area = frame.area();
+        self.render_layer_buffer(area, frame.buffer_mut(), layer);
+    }
+
+    /// Renders only the notifications in one [`Layer`] into a buffer.
+    ///
+    /// The buffer counterpart of [`render_layer`](Self::render_layer);
+    /// notifications are anchored and stacked within `area`.
+    ///
+    /// # Arguments
+    /// * `area` - The area notifications are anchored and stacked within
+    /// * `buf` - The buffer to draw into
+    /// * `layer` - The layer to draw
+    pub fn render_layer_buffer(&mut self, area: Rect, buf: &mut Buffer, layer: Layer) {
+        self.render_into(area, buf, Some(layer), None);
+    }
+
+    /// Renders only the notifications matching `predicate`, anchored within
+    /// `area`.
+    ///
+    /// Stacking considers only the matching notifications, so a subset
+    /// rendered into its own region has no gaps left by the others. Useful
+    /// for split-screen layouts, e.g. errors in one pane and everything else
+    /// in another.
+    ///
+    /// # Arguments
+    /// * `frame` - The frame to render to
+    /// * `area` - The area notifications are anchored and stacked within
+    /// * `predicate` - Returns whether a notification is drawn
+    ///
+    /// # Example
+    /// ```no_run
+    /// use ratatui_notifications::notifications::{Level, Notifications};
+    /// use ratatui::backend::TestBackend;
+    /// use ratatui::layout::{Constraint, Layout};
+    /// use ratatui::Terminal;
+    ///
+    /// let mut manager = Notifications::new();
+    /// let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    ///
+    /// terminal.draw(|frame| {
+    ///     let [left, right] = Layout::horizontal([Constraint::Fill(1); 2]).areas(frame.area());
+    ///     manager.render_filtered(frame, left, |info| info.level == Some(Level::Error));
+    ///     manager.render_filtered(frame, right, |info| info.level != Some(Level::Error));
+    /// }).unwrap();
+    /// ```
+    pub fn render_filtered(
+        &mut self,
+        frame: &mut Frame<'_>,
+        area: Rect,
+        predicate: impl FnMut(&NotificationInfo) -> bool,
+    ) {
+        self.render_filtered_buffer(area, frame.buffer_mut(), predicate);
+    }
+
+    /// Renders only the notifications matching `predicate` into a buffer.
+    ///
+    /// The buffer counterpart of [`render_filtered`](Self::render_filtered).
+    ///
+    /// # Arguments
+    /// * `area` - The area notifications are anchored and stacked within
+    /// * `buf` - The buffer to draw into
+    /// * `predicate` - Returns whether a notification is drawn
+    pub fn render_filtered_buffer(
+        &mut self,
+        area: Rect,
+        buf: &mut Buffer,
+        mut predicate: impl FnMut(&NotificationInfo) -> bool,
+    ) {
+        let now = self.clock.now();
+        let by_anchor: HashMap<Anchor, Vec<u64>> = self
+            .by_anchor
+            .iter()
+            .map(|(&anchor, ids)| {
+                let matching = ids
+                    .iter()
+                    .copied()
+                    .filter(|id| self.states.get(id).is_some_and(|state| predicate(&state.info(now))))
+                    .collect();
+                (anchor, matching)
+            })
+            .collect();
+        self.render_into(area, buf, None, Some(&by_anchor));
+    }
+
+    /// Lays out every notification within `area` and draws those in `layer`,
+    /// or all of them for `None`.
+    ///
+    /// `by_anchor` replaces the manager's own anchor lists, restricting both
+    /// layout and drawing to the IDs it holds.
+    fn render_into(
+        &mut self,
+        area: Rect,
+        buf: &mut Buffer,
+        layer: Option<Layer>,
+        by_anchor: Option<&HashMap<Anchor, Vec<u64>>>,
+    ) {
+        // Nothing fits, and layout would only produce empty rects
+        let area = area.inner(self.safe_area);
+        if area.is_empty() {
+            return;
+        }
+        let strip_colors = self.colors_stripped();
+        let options = RenderOptions {
+            max_concurrent: self.max_concurrent,
+            color_mode: if strip_colors && self.strip_user_colors { ColorMode::Mono } else { self.color_mode },
+            theme: self.theme,
+            sort_by: self.sort_by,
+            stack_layout: self.stack_layout,
+            layer,
+            strings: &self.strings,
+            strip_colors,
+        };
+        let by_anchor = by_anchor.unwrap_or(&self.by_anchor);
+        let budgeted = self.within_budget(by_anchor);
+        let by_anchor = budgeted.as_ref().unwrap_or(by_anchor);
+        render_notifications(&mut self.states, by_anchor, area, buf, &options);
+    }
+
+    /// The anchor lists cut down to the render budget, or `None` if they fit.
+    ///
+    /// Keeps the highest effective priorities, the newest among equals, in
+    /// their stacking order.
+    fn within_budget(&self, by_anchor: &HashMap<Anchor, Vec<u64>>) -> Option<HashMap<Anchor, Vec<u64>>> {
+        let max = self.render_budget?;
+        let mut states: Vec<&NotificationState> =
+            by_anchor.values().flatten().filter_map(|id| self.states.get(id)).collect();
+        if states.len() <= max {
+            return None;
+        }
+        states.sort_by(|a, b| {
+            b.effective_priority()
+                .total_cmp(&a.effective_priority())
+                .then(b.created_at.cmp(&a.created_at))
+                .then(b.id.cmp(&a.id))
+        });
+        let kept: HashSet<u64> = states.iter().take(max).map(|state| state.id).collect();
+        Some(
+            by_anchor
+                .iter()
+                .map(|(&anchor, ids)| (anchor, ids.iter().copied().filter(|id| kept.contains(id)).collect()))
+                .collect(),
+        )
+    }
+
+    /// Appends a history entry, dropping the oldest beyond the limit.
+    ///
+    /// A filtered entry is recorded as already dismissed.
+    fn record_history(
+        &mut self,
+        id: u64,
+        notification: &Notification,
+        now: Timestamp,
+        filtered: bool,
+    ) {
+        if self.history_limit == 0 {
+            return;
+        }
+        if self.history.len() >= self.history_limit {
+            self.history.pop_front();
+        }
+        self.history.push_back(NotificationRecord {
+            id,
+            level: notification.level,
+            title: notification.title.clone(),
+            content: Text::clone(&notification.content),
+            added_at: now,
+            dismissed_at: filtered.then_some(now),
+            filtered,
+            dismiss_reason: None,
+        });
+    }
+
+    /// Stamps the history entries of removed notifications and tells the
+    /// notifier they are gone.
+    ///
+    /// # Arguments
+    /// * `dismissed` - ID, level and dismiss reason of each removed notification
+    fn mark_dismissed(&mut self, dismissed: &[(u64, Option<Level>, DismissReason)]) {
+        if let Some(notifier) = self.notifier.as_mut() {
+            for &(id, level, reason) in dismissed {
+                notifier.dismissed(level, id, reason);
+            }
+        }
+        if self.history.is_empty() {
+            return;
+        }
+        let now = self.clock.now();
+        for record in self.history.iter_mut().filter(|record| record.dismissed_at.is_none()) {
+            if let Some(&(_, _, reason)) = dismissed.iter().find(|(id, _, _)| *id == record.id) {
+                record.dismissed_at = Some(now);
+                record.dismiss_reason = Some(reason);
+            }
+        }
+    }
+
+    /// Enforces max_concurrent limit for the given anchor.
+    ///
+    /// Removes oldest or newest notification as needed based on overflow behavior.
+    fn enforce_limit(&mut self, anchor: Anchor) {
+        if let Some(max) = self.max_concurrent {
+            let occupying: Vec<&NotificationState> = self
+                .by_anchor
+                .get(&anchor)
+                .into_iter()
+                .flatten()
+                .filter_map(|id| self.states.get(id))
+                .filter(|state| self.occupies_slot(state))
+                .collect();
+
+            if occupying.len() >= max {
+                let id_to_remove = self.overflow_victim(occupying.into_iter());
+                if let Some(id) = id_to_remove {
+                    self.remove_with(id, DismissReason::Evicted);
+                }
+            }
+        }
+    }
+
+    /// Enforces max_total limit across all anchors.
+    ///
+    /// Removes the oldest or newest notification overall based on overflow
+    /// behavior.
+    fn enforce_total_limit(&mut self) {
+        if let Some(max) = self.max_total {
+            let occupying: Vec<&NotificationState> =
+                self.states.values().filter(|state| self.occupies_slot(state)).collect();
+
+            if occupying.len() >= max {
+                let id_to_remove = self.overflow_victim(occupying.into_iter());
+                if let Some(id) = id_to_remove {
+                    self.remove_with(id, DismissReason::Evicted);
+                }
+            }
+        }
+    }
+
+    /// Whether a notification takes up a slot under the limits.
+    fn occupies_slot(&self, state: &NotificationState) -> bool {
+        self.count_exiting || !leaving(state)
+    }
+
+    /// Whether a limit is reached with nothing the overflow may evict.
+    fn no_room(&self, anchor: Anchor) -> bool {
+        if let Some(max) = self.max_concurrent {
+            let occupying: Vec<&NotificationState> = self
+                .by_anchor
+                .get(&anchor)
+                .into_iter()
+                .flatten()
+                .filter_map(|id| self.states.get(id))
+                .filter(|state| self.occupies_slot(state))
+                .collect();
+            // Evicting at the anchor also frees a slot under max_total
+            if occupying.len() >= max {
+                return self.overflow_victim(occupying.into_iter()).is_none();
+            }
+        }
+        self.max_total.is_some_and(|max| {
+            let occupying: Vec<&NotificationState> =
+                self.states.values().filter(|state| self.occupies_slot(state)).collect();
+            occupying.len() >= max && self.overflow_victim(occupying.into_iter()).is_none()
+        })
+    }
+
+    /// The notification the overflow behavior evicts from the candidates.
+    ///
+    /// Evictability comes first: only the most evictable class present is
+    /// considered, and `Never` notifications are not candidates at all.
+    fn overflow_victim<'a>(&self, candidates: impl Iterator<Item = &'a NotificationState>) -> Option<u64> {
+        let candidates: Vec<&NotificationState> = candidates
+            .filter(|state| state.notification.evictable != Evictability::Never)
+            .collect();
+        let rank = candidates.iter().map(|state| state.notification.evictable.rank()).min()?;
+        let candidates = candidates
+            .into_iter()
+            .filter(|state| state.notification.evictable.rank() == rank);
+        match self.overflow {
+            Overflow::DiscardOldest | Overflow::MergeDuplicates => {
+                candidates.map(|state| (state.created_at, state.id)).min().map(|(_, id)| id)
+            }
+            Overflow::DiscardNewest => candidates.map(|state| (state.created_at, state.id)).max().map(|(_, id)| id),
+            Overflow::DiscardLowestPriority => lowest_priority(candidates),
+        }
+    }
+
+    /// Finds an active notification with the same content and anchor.
+    fn find_duplicate(&self, notification: &Notification) -> Option<u64> {
+        self.by_anchor
+            .get(&notification.anchor)?
+            .iter()
+            .copied()
+            .find(|id| {
+                self.states.get(id).is_some_and(|state| {
+                    state.accepts_merge()
+                        && (Arc::ptr_eq(&state.notification.content, &notification.content)
+                            || state.notification.content == notification.content)
+                })
+            })
+    }
+}
+
+/// The state with the lowest effective priority, the oldest among equals.
+fn lowest_priority<'a>(states: impl Iterator<Item = &'a NotificationState>) -> Option<u64> {
+    states
+        .min_by(|a, b| {
+            a.effective_priority()
+                .total_cmp(&b.effective_priority())
+                .then(a.created_at.cmp(&b.created_at))
+                .then(a.id.cmp(&b.id))
+        })
+        .map(|state| state.id)
+}
+
+/// Whether a notification is already on its way out.
+fn leaving(state: &NotificationState) -> bool {
+    let phase = state.current_phase;
+    phase == AnimationPhase::Finished || phase == AnimationPhase::exit(state.notification.animation)
+}
+
+/// Whether `NO_COLOR` is set to a non-empty value, per no-color.org.
+fn no_color_set(lookup: impl Fn(&str) -> Option<std::ffi::OsString>) -> bool {
+    lookup("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+impl Default for Notifications {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Widget that draws a [`Notifications`] manager passed as its state.
+///
+/// The widget form of [`Notifications::render_buffer`], so it composes with
+/// layout code: unlike [`Notifications::render`], notifications are anchored
+/// and stacked within the area given to the widget rather than the whole
+/// frame. The manager is the state because
+/// rendering records each notification's laid-out rect.
+///
+/// # Example
+/// ```no_run
+/// use ratatui_notifications::notifications::{Notifications, NotificationsWidget};
+/// use ratatui::backend::TestBackend;
+/// use ratatui::layout::{Constraint, Layout};
+/// use ratatui::Terminal;
+///
+/// let mut manager = Notifications::new();
+/// let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+///
+/// terminal.draw(|frame| {
+///     let [_, sidebar] = Layout::horizontal([Constraint::Fill(1), Constraint::Length(30)])
+///         .areas(frame.area());
+///     frame.render_stateful_widget(NotificationsWidget, sidebar, &mut manager);
+/// }).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NotificationsWidget;
+
+impl StatefulWidget for NotificationsWidget {
+    type State = Notifications;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.render_buffer(area, buf);
     }
 }
 
 // FILE: src/notifications/orc_manager.rs - Notifications manager orchestrator
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.69.0
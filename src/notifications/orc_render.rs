@@ -1,21 +1,38 @@
 // FILE: src/notifications/orc_render.rs - Orchestrates notification rendering
-// VERSION: 1.1.0
-// WCTX: Fixing fade animation overshoot bug
-// CLOG: Fixed fade discontinuity by applying fade interpolation during Dwelling phase
-
-use crate::notifications::functions::fnc_get_level_icon::get_level_icon;
-use crate::notifications::functions::fnc_resolve_styles::resolve_styles;
-use crate::notifications::orc_stacking::calculate_stacking_positions;
-use crate::notifications::types::{Anchor, AnimationPhase, Level};
+// VERSION: 1.27.0
+// WCTX: Idempotent render
+// CLOG: Documented why render records sizes and rects
+
+use crate::notifications::functions::fnc_apply_color_mode::apply_color_mode;
+use crate::notifications::functions::fnc_fade_interpolate_color::interpolate_color;
+use crate::notifications::functions::fnc_layer_transform_rect::layer_transform_rect;
+use crate::notifications::functions::fnc_level_severity::level_severity;
+use crate::notifications::classes::{Strings, Theme};
+use crate::notifications::functions::fnc_resolve_styles::resolve_themed_styles;
+use crate::notifications::functions::fnc_rolldown_visible_lines::rolldown_visible_lines;
+use crate::notifications::functions::fnc_truncate_text::{truncate_line, truncate_text};
+use crate::notifications::functions::fnc_wrap_text::wrapped;
+use crate::notifications::orc_stacking::{
+    calculate_stacking_positions_with_layout, StackedNotification,
+};
+use crate::shared_utils::math::contrasting_fg;
+use crate::notifications::types::{
+    Anchor, AnimationLayer, AnimationPhase, ColorMode, FadeScope, Layer, LayerTransform, Level,
+    SortBy, StackLayout, StringKey, TruncateMode, VerticalAlign,
+};
 use ratatui::{
     layout::Alignment,
     prelude::*,
     symbols::border,
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+    widgets::{Block, BorderType, Borders, Clear, Padding},
 };
+use std::borrow::Cow;
 use std::collections::HashMap;
 
+// Share of the exit over which a fading notification blends in its exit style
+const EXIT_STYLE_BLEND: f32 = 0.2;
+
 /// Trait for renderable notification state.
 ///
 /// This trait defines the interface for notification states that can be rendered.
@@ -23,43 +40,136 @@ use std::collections::HashMap;
 pub trait RenderableNotification: crate::notifications::orc_stacking::StackableNotification {
     fn level(&self) -> Option<Level>;
     fn title(&self) -> Option<Line<'static>>;
-    fn content(&self) -> Text<'static>;
+    fn content(&self) -> Cow<'_, Text<'static>>;
     fn border_type(&self) -> BorderType;
     fn fade_effect(&self) -> bool;
+    fn fade_scope(&self) -> FadeScope;
+    fn title_separator(&self) -> bool;
+    fn auto_contrast(&self) -> bool;
+    /// Whether the area behind is left uncleared.
+    fn transparent(&self) -> bool;
+    /// Whether a skeleton placeholder fills the content area.
+    fn skeleton(&self) -> bool;
+    /// Whether only the newest lines that fit are shown (pushed log lines).
+    fn tail_content(&self) -> bool;
+    /// Display time left to show in the title, if the countdown is on.
+    fn countdown(&self) -> Option<core::time::Duration>;
+    /// Where content lines too wide for the box are cut; `None` wraps them.
+    fn truncate_mode(&self) -> Option<TruncateMode>;
+    /// Current page (from 1) and page count, for notifications with several pages.
+    fn page(&self) -> Option<(usize, usize)>;
+    /// Marker for text removed by truncation.
+    fn ellipsis(&self) -> &str;
+    /// Which render layer pass draws the notification.
+    fn layer(&self) -> Layer;
+    fn vertical_align(&self) -> VerticalAlign;
     fn animation_type(&self) -> crate::notifications::types::Animation;
     fn animation_progress(&self) -> f32;
     fn block_style(&self) -> Option<Style>;
     fn border_style(&self) -> Option<Style>;
     fn title_style(&self) -> Option<Style>;
+    /// Style patched over the others during the exit phase.
+    fn exit_style(&self) -> Option<Style>;
     fn padding(&self) -> ratatui::widgets::Padding;
+    /// Records the stacked position; the same area always yields the same rect.
     fn set_full_rect(&mut self, rect: Rect);
+    /// Measures the content for the frame ahead of stacking, if not cached.
+    fn cache_content_size(&mut self, frame_area: Rect);
 
     // Animation handler methods - avoid dyn compatibility issues by including them directly
     fn calculate_animation_rect(&self, frame_area: Rect) -> Rect;
+    /// Transforms composed in place of `calculate_animation_rect`; empty when unused.
+    fn animation_layers(&self) -> &[AnimationLayer];
+    /// What one animation layer contributes at the current progress.
+    fn layer_transform(&self, layer: AnimationLayer, frame_area: Rect) -> LayerTransform;
+    /// The rect the layer transforms apply to: the stacked rect, mid-move if restacking.
+    fn settled_rect(&self, frame_area: Rect) -> Rect;
     fn apply_animation_block_effect<'a>(&self, block: Block<'a>, frame_area: Rect, base_set: &'a border::Set) -> Block<'a>;
     fn interpolate_frame_foreground(&self, base_fg: Option<Color>, phase: AnimationPhase, progress: f32) -> Option<Color>;
     fn interpolate_content_foreground(&self, base_fg: Option<Color>, phase: AnimationPhase, progress: f32) -> Option<Color>;
 }
 
-/// Renders all notifications to the frame.
+/// Manager-level settings that affect how notifications are drawn.
+#[derive(Debug, Clone, Copy)]
+pub struct RenderOptions<'a> {
+    /// Optional limit on concurrent visible notifications per anchor
+    pub max_concurrent: Option<usize>,
+
+    /// Color capability of the target terminal
+    pub color_mode: ColorMode,
+
+    /// Level styling (colors, border types, title prefixes)
+    pub theme: Theme,
+
+    /// Draw order across anchors
+    pub sort_by: SortBy,
+
+    /// Direction notifications sharing an anchor stack in
+    pub stack_layout: StackLayout,
+
+    /// Only draw notifications in this layer; `None` draws every layer
+    pub layer: Option<Layer>,
+
+    /// Wording for injected text such as title prefixes
+    pub strings: &'a Strings,
+
+    /// Strip the colors the crate resolves (theme, fade, auto-contrast),
+    /// keeping modifiers; user-provided styles are left untouched
+    pub strip_colors: bool,
+}
+
+/// English strings for `RenderOptions::default()`.
+static DEFAULT_STRINGS: Strings = Strings::ENGLISH;
+
+impl Default for RenderOptions<'_> {
+    fn default() -> Self {
+        Self {
+            max_concurrent: None,
+            color_mode: ColorMode::default(),
+            theme: Theme::default(),
+            sort_by: SortBy::default(),
+            stack_layout: StackLayout::default(),
+            layer: None,
+            strings: &DEFAULT_STRINGS,
+            strip_colors: false,
+        }
+    }
+}
+
+/// Renders all notifications into a buffer.
 ///
 /// This is the main orchestration function that:
 /// 1. Iterates through each anchor's notifications
-/// 2. Calls calculate_stacking_positions for each anchor
-/// 3. For each stacked notification:
-///    - Updates state.full_rect with stacked position
-///    - Gets animation handler and calculates current rect
-///    - Resolves styles
+/// 2. Calls calculate_stacking_positions for each anchor and updates each
+///    state.full_rect with its stacked position
+/// 3. Keeps only the requested layer (`options.layer`), if any, and sorts
+///    the stacked notifications into draw order (`options.sort_by`)
+/// 4. For each stacked notification, in draw order:
+///    - Gets animation handler and calculates current rect, composing the
+///      animation layers' transforms when the notification has any
+///    - Resolves styles against the theme
 ///    - Applies fade effect if enabled
 ///    - Builds Block with border, title, icon
-///    - Renders Clear at stacked position, then Paragraph at animated position
+///    - Renders Clear at stacked position (unless transparent), then Paragraph
+///      at animated position
+///    - Degrades the drawn colors to the configured color mode
+///
+/// Rendering writes two things back into each state: the measured content
+/// size with the frame area (`cache_content_size`) and the stacked rect
+/// (`set_full_rect`). Both depend on the frame area, which only rendering
+/// knows, so `tick()` cannot compute them. Both are derived from the
+/// state as of the last tick and the area alone, so rendering the same
+/// area again before the next tick writes the same values and draws the
+/// same cells; nothing that animates is advanced here.
 ///
 /// # Arguments
 ///
 /// * `notifications` - Mutable HashMap of all notification states
 /// * `notifications_by_anchor` - Mapping of anchors to notification IDs
-/// * `frame` - The frame to render to
-/// * `max_concurrent` - Optional limit on concurrent visible notifications
+/// * `frame_area` - The area notifications are laid out in
+/// * `buf` - The buffer to draw into
+/// * `options` - Render settings (concurrency limit, color mode, theme, draw order, layer,
+///   strings)
 ///
 /// # Type Parameters
 ///
@@ -67,102 +177,266 @@ pub trait RenderableNotification: crate::notifications::orc_stacking::StackableN
 pub fn render_notifications<T: RenderableNotification>(
     notifications: &mut HashMap<u64, T>,
     notifications_by_anchor: &HashMap<Anchor, Vec<u64>>,
-    frame: &mut Frame<'_>,
-    max_concurrent: Option<usize>,
+    frame_area: Rect,
+    buf: &mut Buffer,
+    options: &RenderOptions<'_>,
 ) {
-    let frame_area = frame.area();
+    let mut draw_list: Vec<StackedNotification> = Vec::new();
 
     for (anchor, ids_at_anchor) in notifications_by_anchor.iter() {
         if ids_at_anchor.is_empty() {
             continue;
         }
 
+        // Measure changed notifications once; stacking reuses the result
+        for id in ids_at_anchor {
+            if let Some(state) = notifications.get_mut(id) {
+                state.cache_content_size(frame_area);
+            }
+        }
+
         // Calculate stacking positions for this anchor
-        let stacked_notifications = calculate_stacking_positions(
+        let stacked_notifications = calculate_stacking_positions_with_layout(
             notifications,
             *anchor,
             ids_at_anchor,
             frame_area,
-            max_concurrent,
+            options.max_concurrent,
+            options.stack_layout,
         );
 
-        // Render each stacked notification
+        // Update each state's full_rect with its stacked position
         for stacked in stacked_notifications {
             if let Some(state) = notifications.get_mut(&stacked.id) {
-                // Update the state's full_rect with stacked position
                 state.set_full_rect(stacked.rect);
+                draw_list.push(stacked);
+            }
+        }
+    }
 
-                // Calculate current rect using animation
-                let current_rect = state.calculate_animation_rect(frame_area);
+    // Stacking saw every layer, so the layers never overlap each other
+    if let Some(layer) = options.layer {
+        draw_list.retain(|s| notifications[&s.id].layer() == layer);
+    }
 
-                if current_rect.width == 0 || current_rect.height == 0 {
-                    continue;
-                }
+    let theme = if options.strip_colors { options.theme.without_colors() } else { options.theme };
 
-                // Resolve styles
-                let (base_block_style, base_border_style, base_title_style) = resolve_styles(
-                    state.level(),
-                    state.block_style(),
-                    state.border_style(),
-                    state.title_style(),
-                );
+    // Later draws land on top
+    match options.sort_by {
+        SortBy::Age => draw_list.sort_by_key(|s| notifications[&s.id].created_at()),
+        SortBy::Level => draw_list.sort_by_key(|s| {
+            let state = &notifications[&s.id];
+            (level_severity(state.level()), state.created_at())
+        }),
+    }
 
-                // Apply fade effect if enabled
-                let (final_block_style, final_border_style, final_title_style, final_content_style) =
-                    apply_fade_if_needed(
-                        state,
-                        base_block_style,
-                        base_border_style,
-                        base_title_style,
-                    );
-
-                // Build the block
-                let mut block = Block::default()
-                    .style(final_block_style)
-                    .borders(Borders::ALL)
-                    .border_type(state.border_type())
-                    .border_style(final_border_style)
-                    .padding(state.padding());
-
-                // Add title with icon if present
-                if let Some(mut title_line) = state.title() {
-                    if let Some(icon_str) = get_level_icon(state.level()) {
-                        let icon_span = Span::styled(icon_str, final_border_style);
-                        title_line.spans.insert(0, icon_span);
-                    }
-                    block = block.title(title_line.alignment(Alignment::Center).style(final_title_style));
-                }
+    // Render each stacked notification
+    for stacked in draw_list {
+        if let Some(state) = notifications.get(&stacked.id) {
+            // Calculate current rect using animation, or its composed layers
+            let transform = composed_layer_transform(state, frame_area);
+            let current_rect = match transform {
+                Some(transform) => layer_transform_rect(state.settled_rect(frame_area), frame_area, transform),
+                None => state.calculate_animation_rect(frame_area),
+            };
+
+            if current_rect.width == 0 || current_rect.height == 0 {
+                continue;
+            }
 
-                // Apply block effect from animation
-                let border_set = get_border_set(state.border_type());
-                block = state.apply_animation_block_effect(block, frame_area, &border_set);
+            // Resolve styles
+            let resolved = resolve_themed_styles(
+                state.level(),
+                &theme,
+                state.block_style(),
+                state.border_style(),
+                state.title_style(),
+            );
+            let border_type = resolved.border_type.unwrap_or(state.border_type());
+            let (block_style, border_style, title_style) =
+                apply_exit_style_if_needed(state, resolved.block, resolved.border, resolved.title);
 
-                // Create the paragraph
-                let paragraph = Paragraph::new(state.content())
-                    .wrap(Wrap { trim: true })
-                    .style(final_content_style)
-                    .block(block);
+            // Apply fade effect if enabled
+            let (final_block_style, final_border_style, final_title_style, final_content_style) =
+                apply_fade_if_needed(state, block_style, border_style, title_style, transform, options.strip_colors);
 
-                // Render: Clear at stacked position, then Paragraph at animated position
-                if stacked.rect.width > 0 && stacked.rect.height > 0 {
-                    frame.render_widget(Clear, stacked.rect.intersection(frame_area));
+            // Build the block
+            let mut block = Block::default()
+                .style(final_block_style)
+                .borders(Borders::ALL)
+                .border_type(border_type)
+                .border_style(final_border_style)
+                .padding(aligned_padding(state, stacked.rect));
+
+            // Add title with icon and theme prefix if present
+            let border_set = get_border_set(border_type);
+            let prefix = resolved
+                .title_prefix
+                .zip(state.level())
+                .map(|(prefix, level)| {
+                    options
+                        .strings
+                        .format(StringKey::TitlePrefix(level), &[("prefix", prefix)])
+                });
+            let title = match (state.title(), prefix) {
+                (Some(mut title_line), Some(prefix)) => {
+                    title_line.spans.insert(0, Span::raw(format!(" {} ", prefix)));
+                    Some(title_line)
+                }
+                (None, Some(prefix)) => Some(Line::raw(format!(" {} ", prefix))),
+                (title, None) => title,
+            };
+            let title = append_countdown(title, state.countdown(), options.strings);
+            if let Some(mut title_line) = title {
+                if let Some(icon_str) = resolved.icon {
+                    let icon_span = Span::styled(icon_str, final_border_style);
+                    title_line.spans.insert(0, icon_span);
                 }
-                frame.render_widget(paragraph, current_rect);
+                let title_line = fit_title(title_line, stacked.rect.width, &border_set, state.ellipsis());
+                block = block.title(title_line.alignment(Alignment::Center).style(final_title_style));
+            }
+
+            // Page indicator in the bottom border
+            if let Some((page, count)) = state.page() {
+                let (page, count) = (page.to_string(), count.to_string());
+                let indicator = options
+                    .strings
+                    .format(StringKey::PageIndicator, &[("page", &page), ("count", &count)]);
+                let indicator = Line::raw(format!(" {indicator} ")).style(final_border_style);
+                block = block.title_bottom(indicator.alignment(Alignment::Right));
+            }
+
+            // Apply block effect from animation
+            block = state.apply_animation_block_effect(block, frame_area, &border_set);
+
+            // Create the paragraph
+            let paragraph = wrapped(visible_content(state, stacked.rect))
+                .style(final_content_style)
+                .block(block);
+
+            // Render: Clear at stacked position, then Paragraph at animated position
+            if stacked.rect.width > 0 && stacked.rect.height > 0 && !state.transparent() {
+                Clear.render(stacked.rect.intersection(frame_area), buf);
+            }
+            paragraph.render(current_rect, buf);
+            if state.title_separator() {
+                draw_title_separator(
+                    buf,
+                    current_rect.intersection(frame_area),
+                    current_rect,
+                    border_set.horizontal_top,
+                    final_border_style,
+                );
             }
+            apply_color_mode(buf, current_rect, options.color_mode, state.level());
         }
     }
 }
 
+/// Helper to cut a title too wide for the top border
+///
+/// The room is taken from the settled width, so the title does not shift while
+/// the box animates. A title that fits between the corner glyphs is returned as
+/// it is; a longer one is cut to leave one space on each side of it.
+fn fit_title(title: Line<'static>, full_width: u16, border_set: &border::Set<'_>, ellipsis: &str) -> Line<'static> {
+    let corners = Span::raw(border_set.top_left).width() + Span::raw(border_set.top_right).width();
+    let between = usize::from(full_width).saturating_sub(corners);
+    if title.width() <= between {
+        return title;
+    }
+    let room = u16::try_from(between.saturating_sub(2)).unwrap_or(u16::MAX);
+    let mut fitted = truncate_line(&title, room, TruncateMode::End, ellipsis);
+    fitted.spans.insert(0, Span::raw(" "));
+    fitted.spans.push(Span::raw(" "));
+    fitted
+}
+
+/// Helper to append the `show_countdown` seconds to the title
+fn append_countdown(
+    title: Option<Line<'static>>,
+    remaining: Option<core::time::Duration>,
+    strings: &Strings,
+) -> Option<Line<'static>> {
+    let Some(remaining) = remaining else {
+        return title;
+    };
+    let secs = remaining.as_millis().div_ceil(1000).to_string();
+    let text = strings.format(StringKey::Countdown, &[("n", &secs)]);
+    match title {
+        Some(mut title_line) => {
+            title_line.spans.push(Span::raw(format!(" {}", text)));
+            Some(title_line)
+        }
+        None => Some(Line::raw(format!(" {} ", text))),
+    }
+}
+
+/// Helper to patch the exit style over the resolved styles during the exit
+/// phase, blending its color in over the first part of the exit when fading
+fn apply_exit_style_if_needed<T: RenderableNotification>(
+    state: &T,
+    base_block_style: Style,
+    base_border_style: Style,
+    base_title_style: Style,
+) -> (Style, Style, Style) {
+    let is_exiting = matches!(
+        state.current_phase(),
+        AnimationPhase::SlidingOut | AnimationPhase::FadingOut | AnimationPhase::Collapsing
+    );
+    let Some(exit_style) = state.exit_style().filter(|_| is_exiting) else {
+        return (base_block_style, base_border_style, base_title_style);
+    };
+
+    let fades = fades(state);
+    let patch = |base: Style| {
+        let patched = base.patch(exit_style);
+        match exit_style.fg {
+            Some(exit_fg) if fades => {
+                let blend = (state.animation_progress() / EXIT_STYLE_BLEND).min(1.0);
+                patched.fg(interpolate_color(base.fg, Some(exit_fg), blend, false).unwrap_or(exit_fg))
+            }
+            _ => patched,
+        }
+    };
+    (patch(base_block_style), patch(base_border_style), patch(base_title_style))
+}
+
+/// Composes every animation layer's transform; `None` when the notification has no layers
+fn composed_layer_transform<T: RenderableNotification>(state: &T, frame_area: Rect) -> Option<LayerTransform> {
+    let layers = state.animation_layers();
+    (!layers.is_empty()).then(|| {
+        layers
+            .iter()
+            .fold(LayerTransform::IDENTITY, |acc, &layer| acc.then(state.layer_transform(layer, frame_area)))
+    })
+}
+
+/// Whether the notification fades: `fade(true)`, a fade layer, or a bare `Animation::Fade`
+fn fades<T: RenderableNotification>(state: &T) -> bool {
+    use crate::notifications::types::Animation;
+
+    state.fade_effect()
+        || (state.animation_layers().is_empty() && matches!(state.animation_type(), Animation::Fade))
+}
+
 /// Helper to apply fade effect if needed
+///
+/// With animation layers the composed opacity drives the fade in place of
+/// the phase progress. With `strip_colors` neither the fade nor auto-contrast
+/// adds colors.
 fn apply_fade_if_needed<T: RenderableNotification>(
     state: &T,
     base_block_style: Style,
     base_border_style: Style,
     base_title_style: Style,
+    transform: Option<LayerTransform>,
+    strip_colors: bool,
 ) -> (Style, Style, Style, Style) {
-    use crate::notifications::types::Animation;
+    if strip_colors {
+        return (base_block_style, base_border_style, base_title_style, base_block_style);
+    }
 
-    let apply_fade = state.fade_effect() || matches!(state.animation_type(), Animation::Fade);
+    let apply_fade = fades(state);
     let is_in_anim_phase = matches!(
         state.current_phase(),
         AnimationPhase::FadingIn
@@ -178,37 +452,187 @@ fn apply_fade_if_needed<T: RenderableNotification>(
         let phase = state.current_phase();
         // For dwelling phase, use progress=1.0 to get the final interpolated color
         // This prevents a jarring discontinuity when transitioning from FadingIn to Dwelling
-        let progress = if is_dwelling { 1.0 } else { state.animation_progress() };
+        let progress = match transform {
+            Some(transform) => transform.opacity,
+            None if is_dwelling => 1.0,
+            None => state.animation_progress(),
+        };
         // Use FadingIn phase for dwelling to get the "fully visible" colors
-        let effective_phase = if is_dwelling { AnimationPhase::FadingIn } else { phase };
+        let effective_phase = if is_dwelling || transform.is_some() { AnimationPhase::FadingIn } else { phase };
 
         let effective_base_frame_fg = base_title_style
             .fg
             .or(base_border_style.fg)
             .or(base_block_style.fg);
 
-        let frame_fg = state.interpolate_frame_foreground(effective_base_frame_fg, effective_phase, progress);
-        let content_fg = state.interpolate_content_foreground(None, effective_phase, progress);
-
-        let frame_fade_override = Style::default().fg(frame_fg.unwrap_or(Color::Reset));
-        let content_fade_override = Style::default().fg(content_fg.unwrap_or(Color::Reset));
+        // Parts outside the fade scope keep their base styles untouched
+        let scope = state.fade_scope();
+        let (block_style, border_style, title_style) = if scope.fades_frame() {
+            let frame_fg = state.interpolate_frame_foreground(effective_base_frame_fg, effective_phase, progress);
+            let frame_fade_override = Style::default().fg(frame_fg.unwrap_or(Color::Reset));
+            (
+                base_block_style.patch(frame_fade_override),
+                base_border_style.patch(frame_fade_override),
+                base_title_style.patch(frame_fade_override),
+            )
+        } else {
+            (base_block_style, base_border_style, base_title_style)
+        };
+        let content_style = if scope.fades_content() {
+            let content_fg = state.interpolate_content_foreground(None, effective_phase, progress);
+            let faded = base_block_style.patch(Style::default().fg(content_fg.unwrap_or(Color::Reset)));
+            // Once faded in, contrast takes over from the fade's final color
+            if is_dwelling {
+                contrast_content(state, faded)
+            } else {
+                faded
+            }
+        } else {
+            // Pin the foreground so the faded block style does not bleed into the text
+            let pinned = base_block_style.patch(Style::default().fg(base_block_style.fg.unwrap_or(Color::Reset)));
+            contrast_content(state, pinned)
+        };
 
-        (
-            base_block_style.patch(frame_fade_override),
-            base_border_style.patch(frame_fade_override),
-            base_title_style.patch(frame_fade_override),
-            base_block_style.patch(content_fade_override),
-        )
+        (block_style, border_style, title_style, content_style)
     } else {
         (
             base_block_style,
             base_border_style,
             base_title_style,
-            base_block_style,
+            contrast_content(state, base_block_style),
         )
     }
 }
 
+/// Helper to recolor content for readability when auto-contrast is on and a
+/// background is set
+fn contrast_content<T: RenderableNotification>(state: &T, content_style: Style) -> Style {
+    match content_style.bg {
+        Some(bg) if state.auto_contrast() => content_style.fg(contrasting_fg(bg)),
+        _ => content_style,
+    }
+}
+
+/// Helper to add the title separator row to the notification's padding
+fn padding_with_separator<T: RenderableNotification>(state: &T) -> Padding {
+    let mut padding = state.padding();
+    if state.title_separator() {
+        padding.top += 1;
+    }
+    padding
+}
+
+/// Helper to truncate content lines to the settled rect's inner width
+fn fitted_content<T: RenderableNotification>(state: &T, settled: Rect) -> Text<'static> {
+    if state.skeleton() {
+        return skeleton_content(state, settled);
+    }
+    let content = state.content();
+    let padding = state.padding();
+    let inner_width = settled.width.saturating_sub(2 + padding.left + padding.right).max(1);
+    let content = match state.truncate_mode() {
+        Some(mode) => truncate_text(&content, inner_width, mode, state.ellipsis()),
+        None => content.into_owned(),
+    };
+    if state.tail_content() {
+        tail_lines(state, content, settled, inner_width)
+    } else {
+        content
+    }
+}
+
+/// Helper to keep only the newest lines whose wrapped rows fit the content area
+fn tail_lines<T: RenderableNotification>(
+    state: &T,
+    mut content: Text<'static>,
+    settled: Rect,
+    inner_width: u16,
+) -> Text<'static> {
+    let padding = padding_with_separator(state);
+    let inner_height = usize::from(settled.height.saturating_sub(2 + padding.top + padding.bottom));
+    let mut rows = 0;
+    let kept = content
+        .lines
+        .iter()
+        .rev()
+        .take_while(|line| {
+            rows += wrapped((*line).clone())
+                .line_count(inner_width)
+                .max(1);
+            rows <= inner_height
+        })
+        .count();
+    let skipped = content.lines.len() - kept;
+    content.lines.drain(..skipped);
+    content
+}
+
+/// Helper to fill the settled rect's content area with dim dashes
+fn skeleton_content<T: RenderableNotification>(state: &T, settled: Rect) -> Text<'static> {
+    let padding = padding_with_separator(state);
+    let inner_width = settled.width.saturating_sub(2 + padding.left + padding.right);
+    let inner_height = settled.height.saturating_sub(2 + padding.top + padding.bottom);
+    let row = Line::styled("─".repeat(usize::from(inner_width)), Modifier::DIM);
+    Text::from(vec![row; usize::from(inner_height.max(1))])
+}
+
+/// Helper to drop the lines a roll-down animation has not revealed yet
+fn visible_content<T: RenderableNotification>(state: &T, settled: Rect) -> Text<'static> {
+    use crate::notifications::types::Animation;
+
+    let mut content = fitted_content(state, settled);
+    if state.animation_type() == Animation::RollDown {
+        let shown = rolldown_visible_lines(
+            content.lines.len(),
+            state.current_phase(),
+            state.animation_progress(),
+        );
+        content.lines.truncate(shown);
+    }
+    content
+}
+
+/// Helper to push content down by the vertical alignment offset
+///
+/// The offset comes from the settled rect so content does not shift while
+/// the animated rect is clipped or resized.
+fn aligned_padding<T: RenderableNotification>(state: &T, settled: Rect) -> Padding {
+    let mut padding = padding_with_separator(state);
+    if state.vertical_align() == VerticalAlign::Top {
+        return padding;
+    }
+
+    let inner_width = settled
+        .width
+        .saturating_sub(2 + padding.left + padding.right)
+        .max(1);
+    let inner_height = settled.height.saturating_sub(2 + padding.top + padding.bottom);
+    let content_height = wrapped(fitted_content(state, settled))
+        .line_count(inner_width)
+        .min(u16::MAX as usize) as u16;
+    let spare = inner_height.saturating_sub(content_height);
+
+    padding.top += match state.vertical_align() {
+        VerticalAlign::Top => 0,
+        VerticalAlign::Center => spare / 2,
+        VerticalAlign::Bottom => spare,
+    };
+    padding
+}
+
+/// Helper to draw the title separator on the first row inside the top border
+fn draw_title_separator(buf: &mut Buffer, visible: Rect, rect: Rect, symbol: &str, style: Style) {
+    if rect.height < 3 || rect.width < 3 {
+        return;
+    }
+    let y = rect.y + 1;
+    for x in (rect.x + 1)..(rect.right() - 1) {
+        if visible.contains(Position::new(x, y)) {
+            buf[(x, y)].set_symbol(symbol).set_style(style);
+        }
+    }
+}
+
 /// Helper to get border set from border type
 fn get_border_set(border_type: BorderType) -> border::Set<'static> {
     match border_type {
@@ -220,6 +644,5 @@ fn get_border_set(border_type: BorderType) -> border::Set<'static> {
     }
 }
 
-
 // FILE: src/notifications/orc_render.rs - Orchestrates notification rendering
-// END OF VERSION: 1.1.0
+// END OF VERSION: 1.27.0
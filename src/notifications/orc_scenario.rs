@@ -0,0 +1,359 @@
+// FILE: src/notifications/orc_scenario.rs - TOML scenario model, player and headless runner
+// VERSION: 1.0.0
+// WCTX: TOML scenario player
+// CLOG: Initial creation with Scenario, ScenarioPlayer and FrameSnapshot
+
+use crate::notifications::classes::Notification;
+use crate::notifications::functions::fnc_parse_duration::parse_duration;
+use crate::notifications::orc_manager::Notifications;
+use crate::notifications::traits::TestClock;
+use crate::notifications::types::{NotificationConfig, NotificationError, Overflow, ScenarioError};
+use core::time::Duration;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::text::Span;
+
+/// Frame interval used when a scenario does not set one.
+const DEFAULT_FRAME_INTERVAL: &str = "100ms";
+
+/// Length used when a scenario does not set one.
+const DEFAULT_DURATION: &str = "5s";
+
+/// A timed sequence of notifications and the manager settings to play them
+/// with, loaded from TOML.
+///
+/// Drive it live with a [`ScenarioPlayer`], or render it frame by frame
+/// with [`run_headless`](Self::run_headless) for deterministic output.
+///
+/// Requires the `scenario` feature.
+///
+/// # Format
+///
+/// ```toml
+/// duration = "3s"          # how long the scenario runs (default 5s)
+/// frame_interval = "100ms" # headless frame spacing (default 100ms)
+///
+/// [manager]                # optional
+/// max_concurrent = 2
+/// overflow = "DiscardOldest"
+///
+/// [[events]]
+/// at = "0.5s"
+/// [events.notification]    # a NotificationConfig
+/// content = "Build finished"
+/// level = "Info"
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use ratatui_notifications::notifications::Scenario;
+///
+/// let scenario = Scenario::load(r#"
+///     duration = "1s"
+///
+///     [[events]]
+///     at = "0s"
+///     [events.notification]
+///     content = "Hello"
+/// "#).unwrap();
+///
+/// let frames = scenario.run_headless(40, 10);
+/// assert_eq!(frames.len(), 11);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    /// How long the scenario runs.
+    pub duration: Duration,
+
+    /// Time between headless frames.
+    pub frame_interval: Duration,
+
+    /// Manager settings applied before playing.
+    pub manager: ScenarioSettings,
+
+    /// The notifications to add, ordered by time.
+    pub events: Vec<ScenarioEvent>,
+}
+
+/// Manager settings a scenario plays with.
+///
+/// Unset fields keep the manager's defaults.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ScenarioSettings {
+    /// Maximum notifications per anchor.
+    pub max_concurrent: Option<usize>,
+
+    /// Maximum notifications across all anchors.
+    pub max_total: Option<usize>,
+
+    /// What happens when a limit is reached.
+    pub overflow: Option<Overflow>,
+}
+
+/// One notification added at a point in a scenario.
+#[derive(Debug, Clone)]
+pub struct ScenarioEvent {
+    /// Time since the scenario started.
+    pub at: Duration,
+
+    /// The notification as written in the file.
+    pub config: NotificationConfig,
+
+    /// The notification built from `config`.
+    pub notification: Notification,
+}
+
+/// One rendered frame of a headless run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameSnapshot {
+    /// Time since the scenario started.
+    pub at: Duration,
+
+    /// The rendered cells.
+    pub buffer: Buffer,
+}
+
+impl FrameSnapshot {
+    /// Returns the frame as text, one string per row.
+    ///
+    /// Styles are dropped, and wide graphemes appear once. Useful for golden
+    /// tests that compare frames as plain text.
+    pub fn lines(&self) -> Vec<String> {
+        let area = self.buffer.area;
+        (area.top()..area.bottom())
+            .map(|y| {
+                let mut row = String::new();
+                let mut skip = 0;
+                for x in area.left()..area.right() {
+                    if skip > 0 {
+                        skip -= 1;
+                        continue;
+                    }
+                    let symbol = self.buffer[(x, y)].symbol();
+                    row.push_str(symbol);
+                    skip = Span::raw(symbol).width().saturating_sub(1);
+                }
+                row
+            })
+            .collect()
+    }
+}
+
+/// The file layout, with times still as written.
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScenarioFile {
+    duration: Option<String>,
+    frame_interval: Option<String>,
+    #[serde(default)]
+    manager: ScenarioSettings,
+    #[serde(default)]
+    events: Vec<EventFile>,
+}
+
+/// One `[[events]]` entry as written.
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EventFile {
+    at: String,
+    notification: NotificationConfig,
+}
+
+impl Scenario {
+    /// Parses a scenario from TOML.
+    ///
+    /// Every notification is built up front, so configuration errors surface
+    /// here rather than partway through playback. Events are sorted by time;
+    /// events at the same time keep their file order.
+    ///
+    /// # Arguments
+    /// * `toml` - The scenario file's contents
+    ///
+    /// # Returns
+    /// * `Ok(Scenario)` - The parsed scenario
+    /// * `Err(ScenarioError::Parse)` - If the TOML is malformed or has
+    ///   unknown keys
+    /// * `Err(ScenarioError::InvalidDuration)` - If a time is malformed or
+    ///   `frame_interval` is zero
+    /// * `Err(ScenarioError::Notification)` - If a notification fails to build
+    pub fn load(toml: &str) -> Result<Self, ScenarioError> {
+        let file: ScenarioFile = toml::from_str(toml).map_err(|err| ScenarioError::Parse(err.to_string()))?;
+
+        let duration = time(file.duration.as_deref().unwrap_or(DEFAULT_DURATION))?;
+        let frame_interval = time(file.frame_interval.as_deref().unwrap_or(DEFAULT_FRAME_INTERVAL))?;
+        if frame_interval.is_zero() {
+            return Err(ScenarioError::InvalidDuration("frame_interval must be above zero".to_string()));
+        }
+
+        let mut events = file
+            .events
+            .into_iter()
+            .map(|event| {
+                Ok(ScenarioEvent {
+                    at: time(&event.at)?,
+                    notification: event.notification.build()?,
+                    config: event.notification,
+                })
+            })
+            .collect::<Result<Vec<_>, ScenarioError>>()?;
+        events.sort_by_key(|event| event.at);
+
+        Ok(Self {
+            duration,
+            frame_interval,
+            manager: file.manager,
+            events,
+        })
+    }
+
+    /// Applies the scenario's manager settings.
+    ///
+    /// # Arguments
+    /// * `manager` - The manager to configure
+    ///
+    /// # Returns
+    /// The manager with every set field of [`manager`](Self::manager) applied
+    pub fn configure(&self, mut manager: Notifications) -> Notifications {
+        let settings = &self.manager;
+        if settings.max_concurrent.is_some() {
+            manager = manager.max_concurrent(settings.max_concurrent);
+        }
+        if settings.max_total.is_some() {
+            manager = manager.max_total(settings.max_total);
+        }
+        if let Some(overflow) = settings.overflow {
+            manager = manager.overflow(overflow);
+        }
+        manager
+    }
+
+    /// Plays the scenario without a terminal and returns every frame.
+    ///
+    /// Time comes from a [`TestClock`] advanced one `frame_interval` per
+    /// frame, and frames are drawn with
+    /// [`Notifications::render_buffer`], so the output is the same on every
+    /// run. Frames are taken at `0`, `frame_interval`, ... up to and including
+    /// `duration`. A notification added at a frame's time is drawn from the
+    /// next frame on, once a tick has started its entry.
+    ///
+    /// # Arguments
+    /// * `width` - Frame width in cells
+    /// * `height` - Frame height in cells
+    ///
+    /// # Returns
+    /// The frames in order
+    pub fn run_headless(&self, width: u16, height: u16) -> Vec<FrameSnapshot> {
+        let clock = TestClock::new();
+        let mut manager = self.configure(Notifications::new().with_clock(clock.clone()));
+        let mut player = ScenarioPlayer::new(self);
+        let area = Rect::new(0, 0, width, height);
+        let mut frames = Vec::new();
+
+        loop {
+            // A fresh manager cannot run out of IDs within one scenario
+            let _ = player.add_due(&mut manager);
+            let mut buffer = Buffer::empty(area);
+            manager.render_buffer(area, &mut buffer);
+            frames.push(FrameSnapshot { at: player.elapsed(), buffer });
+
+            if player.elapsed() + self.frame_interval > self.duration {
+                return frames;
+            }
+            clock.advance(self.frame_interval);
+            manager.tick(self.frame_interval);
+            player.advance(self.frame_interval);
+        }
+    }
+}
+
+/// Steps through a scenario's events as time passes.
+///
+/// Keeps the scenario's clock separate from the manager's, so it can drive
+/// a live app as well as the headless runner: advance it by each frame's
+/// delta and add whatever became due.
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui_notifications::notifications::{Notifications, Scenario, ScenarioPlayer};
+/// use std::time::Duration;
+///
+/// let scenario = Scenario::load(&std::fs::read_to_string("demo.toml").unwrap()).unwrap();
+/// let mut manager = scenario.configure(Notifications::new());
+/// let mut player = ScenarioPlayer::new(&scenario);
+///
+/// // Each frame:
+/// let delta = Duration::from_millis(16);
+/// player.advance(delta);
+/// player.add_due(&mut manager).unwrap();
+/// manager.tick(delta);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScenarioPlayer<'a> {
+    scenario: &'a Scenario,
+    elapsed: Duration,
+    next: usize,
+}
+
+impl<'a> ScenarioPlayer<'a> {
+    /// Creates a player at the start of the scenario.
+    ///
+    /// # Arguments
+    /// * `scenario` - The scenario to play
+    pub fn new(scenario: &'a Scenario) -> Self {
+        Self {
+            scenario,
+            elapsed: Duration::ZERO,
+            next: 0,
+        }
+    }
+
+    /// Moves the scenario's time forward.
+    ///
+    /// # Arguments
+    /// * `delta` - Time since the last call
+    pub fn advance(&mut self, delta: Duration) {
+        self.elapsed = self.elapsed.saturating_add(delta);
+    }
+
+    /// Adds every event due by now that was not added yet.
+    ///
+    /// # Arguments
+    /// * `manager` - The manager to add to
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u64>)` - The IDs of the added notifications, in order
+    /// * `Err(NotificationError)` - If the manager rejected one; later events
+    ///   stay due
+    pub fn add_due(&mut self, manager: &mut Notifications) -> Result<Vec<u64>, NotificationError> {
+        let mut ids = Vec::new();
+        while let Some(event) = self.scenario.events.get(self.next) {
+            if event.at > self.elapsed {
+                break;
+            }
+            ids.push(manager.add(event.notification.clone())?);
+            self.next += 1;
+        }
+        Ok(ids)
+    }
+
+    /// Returns the time since the scenario started.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns whether the scenario's duration has passed.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.scenario.duration
+    }
+}
+
+/// Parses a time from the file.
+fn time(text: &str) -> Result<Duration, ScenarioError> {
+    parse_duration(text).ok_or_else(|| ScenarioError::InvalidDuration(text.to_string()))
+}
+
+// FILE: src/notifications/orc_scenario.rs - TOML scenario model, player and headless runner
+// END OF VERSION: 1.0.0
@@ -1,18 +1,20 @@
 // FILE: src/notifications/orc_stacking.rs - Orchestrates notification stacking logic
-// VERSION: 1.0.1
-// WCTX: TDD implementation of OFPF render orchestrators
-// CLOG: Fixed result ordering to maintain chronological order
+// VERSION: 1.5.0
+// WCTX: Horizontal stacking
+// CLOG: Added StackLayout for side-by-side stacks
 
 use crate::notifications::functions::fnc_calculate_anchor_position::calculate_anchor_position;
 use crate::notifications::functions::fnc_calculate_rect::calculate_rect;
-use crate::notifications::types::{Anchor, AnimationPhase};
+use crate::notifications::types::{Anchor, AnimationPhase, StackLayout, Timestamp};
 use ratatui::prelude::*;
 use std::collections::HashMap;
-use std::time::Instant;
 
 /// Vertical spacing between stacked notifications
 const STACKING_VERTICAL_SPACING: u16 = 1;
 
+/// Horizontal spacing between notifications stacked side by side
+const STACKING_HORIZONTAL_SPACING: u16 = 1;
+
 /// Represents a notification with its calculated stacked position
 #[derive(Debug, Clone)]
 pub struct StackedNotification {
@@ -27,7 +29,8 @@ pub struct StackedNotification {
 pub trait StackableNotification {
     fn id(&self) -> u64;
     fn current_phase(&self) -> AnimationPhase;
-    fn created_at(&self) -> Instant;
+    /// Creation timestamp, used only to order the stack (oldest first).
+    fn created_at(&self) -> Timestamp;
     fn full_rect(&self) -> Rect;
     fn exterior_padding(&self) -> u16;
     /// Calculate the notification's content size based on frame area.
@@ -66,21 +69,57 @@ pub fn calculate_stacking_positions<T: StackableNotification>(
     ids_at_anchor: &[u64],
     frame_area: Rect,
     max_concurrent: Option<usize>,
+) -> Vec<StackedNotification> {
+    calculate_stacking_positions_with_layout(
+        notifications,
+        anchor,
+        ids_at_anchor,
+        frame_area,
+        max_concurrent,
+        StackLayout::Vertical,
+    )
+}
+
+/// Calculate stacking positions for notifications at a given anchor,
+/// stacked vertically or side by side.
+///
+/// `StackLayout::Vertical` behaves exactly like
+/// [`calculate_stacking_positions`]. The horizontal layouts place the oldest
+/// notification at the anchor and each newer one beside the previous,
+/// separated by one column, until the frame edge is reached; each keeps its
+/// own vertical position at the anchor.
+///
+/// # Arguments
+///
+/// * `notifications` - HashMap of all notification states
+/// * `anchor` - The anchor position for this group
+/// * `ids_at_anchor` - List of notification IDs at this anchor
+/// * `frame_area` - The available frame area
+/// * `max_concurrent` - Optional limit on concurrent visible notifications
+/// * `layout` - Direction the notifications stack in
+///
+/// # Returns
+///
+/// Vec of StackedNotification with calculated positions
+pub fn calculate_stacking_positions_with_layout<T: StackableNotification>(
+    notifications: &HashMap<u64, T>,
+    anchor: Anchor,
+    ids_at_anchor: &[u64],
+    frame_area: Rect,
+    max_concurrent: Option<usize>,
+    layout: StackLayout,
 ) -> Vec<StackedNotification> {
     // 1. Filter to visible states and collect data (ID, Creation Time, Calculated Height, Width)
-    let mut visible_states_data: Vec<(u64, Instant, u16, u16)> = ids_at_anchor
+    let mut visible_states_data: Vec<(u64, Timestamp, u16, u16)> = ids_at_anchor
         .iter()
         .filter_map(|id| {
             notifications.get(id).and_then(|state| {
                 let phase = state.current_phase();
                 if phase != AnimationPhase::Finished && phase != AnimationPhase::Pending {
-                    let rect = state.full_rect();
-                    let (width, height) = if rect.height > 0 && rect.width > 0 {
-                        (rect.width, rect.height)
-                    } else {
-                        // Calculate size from content if not yet set
-                        state.calculate_content_size(frame_area)
-                    };
+                    // Measured from the content (or a measurement cached for this
+                    // frame), never from the last render, so layout depends only
+                    // on the state and the frame
+                    let (width, height) = state.calculate_content_size(frame_area);
                     if height > 0 {
                         Some((*id, state.created_at(), height, width))
                     } else {
@@ -101,6 +140,11 @@ pub fn calculate_stacking_positions<T: StackableNotification>(
     let num_to_render = visible_states_data.len().min(max_concurrent);
     let candidate_data = &visible_states_data[visible_states_data.len() - num_to_render..];
 
+    if layout != StackLayout::Vertical {
+        let leftward = layout == StackLayout::RightToLeft;
+        return stack_horizontally(notifications, anchor, candidate_data, frame_area, leftward);
+    }
+
     // 4. Determine stacking direction & available height
     let is_stacking_up = matches!(
         anchor,
@@ -119,10 +163,13 @@ pub fn calculate_stacking_positions<T: StackableNotification>(
     // For bottom anchors (stacking up): iterate newest-to-oldest so newest appears at anchor
     // For top anchors (stacking down): iterate oldest-to-newest so oldest appears at anchor
     let mut accumulated_height: u16 = 0;
+    // Top of the first placed notification; later ones stack below it so
+    // middle anchors do not re-center each item on its own height
+    let mut stack_top: Option<u16> = None;
     let mut result_list: Vec<StackedNotification> = Vec::with_capacity(num_to_render);
 
     // Create iterator in correct order for visual stacking
-    let iter_order: Box<dyn Iterator<Item = &(u64, Instant, u16, u16)>> = if is_stacking_up {
+    let iter_order: Box<dyn Iterator<Item = &(u64, Timestamp, u16, u16)>> = if is_stacking_up {
         Box::new(candidate_data.iter().rev()) // Newest first visually appears at bottom
     } else {
         Box::new(candidate_data.iter()) // Oldest first visually appears at top
@@ -155,7 +202,9 @@ pub fn calculate_stacking_positions<T: StackableNotification>(
                 let stacked_y = if is_stacking_up {
                     base_full_rect.y.saturating_sub(accumulated_height)
                 } else {
-                    base_full_rect.y.saturating_add(accumulated_height)
+                    stack_top
+                        .unwrap_or(base_full_rect.y)
+                        .saturating_add(accumulated_height)
                 };
 
                 // Create the final Rect for this notification
@@ -175,6 +224,7 @@ pub fn calculate_stacking_positions<T: StackableNotification>(
                         rect: final_stacked_rect,
                     });
                     accumulated_height = accumulated_height.saturating_add(needed_height);
+                    stack_top.get_or_insert(final_stacked_rect.y);
                 } else {
                     break; // Break if clamping resulted in zero size
                 }
@@ -188,5 +238,56 @@ pub fn calculate_stacking_positions<T: StackableNotification>(
     result_list
 }
 
+/// Places notifications side by side, oldest at the anchor.
+///
+/// Stops at the first notification that would cross the frame edge.
+fn stack_horizontally<T: StackableNotification>(
+    notifications: &HashMap<u64, T>,
+    anchor: Anchor,
+    candidate_data: &[(u64, Timestamp, u16, u16)],
+    frame_area: Rect,
+    leftward: bool,
+) -> Vec<StackedNotification> {
+    let anchor_pos = calculate_anchor_position(anchor, frame_area);
+    let mut result_list: Vec<StackedNotification> = Vec::with_capacity(candidate_data.len());
+    // Column the next notification starts at (rightward) or ends at (leftward)
+    let mut next_edge: Option<u16> = None;
+
+    for &(id, _, height, width) in candidate_data {
+        let Some(state) = notifications.get(&id) else {
+            continue;
+        };
+        let base_full_rect =
+            calculate_rect(anchor, anchor_pos, width, height, frame_area, state.exterior_padding());
+        let x = match next_edge {
+            None => base_full_rect.x,
+            Some(edge) if leftward => match edge.checked_sub(base_full_rect.width) {
+                Some(x) if x >= frame_area.x => x,
+                _ => break,
+            },
+            Some(edge) => edge,
+        };
+        if x.saturating_add(base_full_rect.width) > frame_area.right() {
+            break;
+        }
+
+        let rect = Rect { x, ..base_full_rect }.intersection(frame_area);
+        if rect.width == 0 || rect.height == 0 {
+            break;
+        }
+        result_list.push(StackedNotification { id, rect });
+        next_edge = Some(if leftward {
+            match rect.x.checked_sub(STACKING_HORIZONTAL_SPACING) {
+                Some(edge) => edge,
+                None => break,
+            }
+        } else {
+            rect.right().saturating_add(STACKING_HORIZONTAL_SPACING)
+        });
+    }
+
+    result_list
+}
+
 // FILE: src/notifications/orc_stacking.rs - Orchestrates notification stacking logic
-// END OF VERSION: 1.0.1
+// END OF VERSION: 1.5.0
@@ -0,0 +1,89 @@
+// FILE: src/notifications/orc_ticker.rs - Background thread that ticks a shared manager
+// VERSION: 1.1.0
+// WCTX: Background ticker thread
+// CLOG: Ticker reads time from the manager's clock
+
+use crate::notifications::orc_manager::Notifications;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Handle to a background ticker started by [`Notifications::spawn_ticker`].
+///
+/// Stops the ticker and joins its thread when [`stop`](Self::stop) is called
+/// or when the handle is dropped.
+#[derive(Debug)]
+pub struct TickerHandle {
+    stop_tx: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl TickerHandle {
+    /// Stops the ticker and waits for its thread to exit.
+    ///
+    /// Any tick in progress completes first; no tick runs afterwards.
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    /// Returns whether the ticker thread is still running.
+    ///
+    /// The thread exits on its own if the manager's mutex is poisoned.
+    pub fn is_running(&self) -> bool {
+        self.thread.as_ref().is_some_and(|thread| !thread.is_finished())
+    }
+
+    /// Helper to signal the thread and join it
+    fn shutdown(&mut self) {
+        // Dropping the sender wakes the thread immediately
+        self.stop_tx.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for TickerHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Spawns a thread that ticks `manager` every `interval` with the time
+/// elapsed on the manager's clock.
+///
+/// # Arguments
+/// * `manager` - The shared manager to tick
+/// * `interval` - Time between ticks
+///
+/// # Returns
+/// A handle that stops the thread when stopped or dropped
+pub(crate) fn spawn_ticker(manager: Arc<Mutex<Notifications>>, interval: Duration) -> TickerHandle {
+    let (stop_tx, stop_rx) = mpsc::channel::<()>();
+
+    let thread = thread::spawn(move || {
+        let Ok(mut last) = manager.lock().map(|manager| manager.now()) else {
+            return;
+        };
+        // Runs until a stop is requested or the handle is dropped
+        while let Err(RecvTimeoutError::Timeout) = stop_rx.recv_timeout(interval) {
+            let Ok(mut manager) = manager.lock() else {
+                break;
+            };
+            // Time comes from the manager's clock, never from Instant directly
+            let now = manager.now();
+            let delta = now.saturating_duration_since(last);
+            last = now;
+            manager.tick(delta);
+        }
+    });
+
+    TickerHandle {
+        stop_tx: Some(stop_tx),
+        thread: Some(thread),
+    }
+}
+
+// FILE: src/notifications/orc_ticker.rs - Background thread that ticks a shared manager
+// END OF VERSION: 1.1.0
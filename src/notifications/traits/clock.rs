@@ -0,0 +1,127 @@
+// FILE: src/notifications/traits/clock.rs - Time source abstraction for the manager layer
+// VERSION: 1.2.0
+// WCTX: Deterministic test clock
+// CLOG: Added TestClock behind the test-clock feature
+
+use crate::notifications::types::Timestamp;
+use core::fmt::Debug;
+#[cfg(feature = "test-clock")]
+use std::sync::{Arc, Mutex, PoisonError};
+#[cfg(feature = "test-clock")]
+use std::time::Duration;
+
+/// Source of timestamps for the notification manager.
+///
+/// All reads of the current time go through this trait, so the layout and
+/// math modules never touch `Instant` directly. Swap the clock with
+/// `Notifications::with_clock` to run on targets where `Instant::now()` is
+/// unavailable or to drive time deterministically.
+///
+/// # Example
+/// ```no_run
+/// use ratatui_notifications::notifications::traits::{Clock, SystemClock};
+///
+/// let now = SystemClock.now();
+/// ```
+pub trait Clock: Debug + Send + Sync {
+    /// Returns the current point in time.
+    fn now(&self) -> Timestamp;
+}
+
+/// Clock backed by the platform monotonic clock (`std::time::Instant`).
+///
+/// This is the manager's default on native targets. It panics on
+/// `wasm32-unknown-unknown`; use [`PerformanceClock`] there.
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+impl Clock for SystemClock {
+    fn now(&self) -> Timestamp {
+        Timestamp::now()
+    }
+}
+
+/// Clock backed by `performance.now()` in the browser.
+///
+/// Available with the `wasm` feature and used as the manager's default on
+/// `wasm32` targets. On native targets it falls back to `std::time::Instant`,
+/// so it can be exercised by ordinary tests.
+#[cfg(feature = "wasm")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PerformanceClock;
+
+#[cfg(feature = "wasm")]
+impl Clock for PerformanceClock {
+    fn now(&self) -> Timestamp {
+        instant::Instant::now()
+    }
+}
+
+/// Clock that only moves when advanced, for deterministic tests.
+///
+/// Available with the `test-clock` feature. Clones share one time, so keep
+/// a clone after handing the clock to `Notifications::with_clock` and
+/// advance it alongside `tick`.
+///
+/// # Example
+/// ```no_run
+/// use ratatui_notifications::notifications::traits::TestClock;
+/// use ratatui_notifications::notifications::Notifications;
+/// use std::time::Duration;
+///
+/// let clock = TestClock::new();
+/// let mut manager = Notifications::new().with_clock(clock.clone());
+///
+/// clock.advance(Duration::from_secs(1));
+/// manager.tick(Duration::from_secs(1));
+/// ```
+#[cfg(feature = "test-clock")]
+#[derive(Debug, Clone)]
+pub struct TestClock(Arc<Mutex<Timestamp>>);
+
+#[cfg(feature = "test-clock")]
+impl TestClock {
+    /// Creates a clock stopped at the current time.
+    pub fn new() -> Self {
+        Self(Arc::new(Mutex::new(Timestamp::now())))
+    }
+
+    /// Moves the clock forward.
+    ///
+    /// # Arguments
+    /// * `by` - Time to advance by
+    pub fn advance(&self, by: Duration) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) += by;
+    }
+}
+
+#[cfg(feature = "test-clock")]
+impl Default for TestClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "test-clock")]
+impl Clock for TestClock {
+    fn now(&self) -> Timestamp {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Returns the default clock for the current target.
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+pub(crate) fn default_clock() -> Box<dyn Clock> {
+    Box::new(SystemClock)
+}
+
+/// Returns the default clock for the current target.
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub(crate) fn default_clock() -> Box<dyn Clock> {
+    Box::new(PerformanceClock)
+}
+
+// FILE: src/notifications/traits/clock.rs - Time source abstraction for the manager layer
+// END OF VERSION: 1.2.0
@@ -0,0 +1,23 @@
+// FILE: src/notifications/traits/mod.rs - Module declarations and re-exports for notification traits
+// VERSION: 1.3.0
+// WCTX: Pluggable notifier
+// CLOG: Added Notifier and set_notifier
+
+mod clock;
+mod notifier;
+
+pub use clock::Clock;
+#[cfg(feature = "wasm")]
+pub use clock::PerformanceClock;
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+pub use clock::SystemClock;
+#[cfg(feature = "test-clock")]
+pub use clock::TestClock;
+#[cfg(feature = "std")]
+pub(crate) use clock::default_clock;
+pub use notifier::Notifier;
+#[cfg(feature = "std")]
+pub use notifier::BellNotifier;
+
+// FILE: src/notifications/traits/mod.rs - Module declarations and re-exports for notification traits
+// END OF VERSION: 1.3.0
@@ -0,0 +1,85 @@
+// FILE: src/notifications/traits/notifier.rs - Hook for audible or external feedback on show
+// VERSION: 1.1.0
+// WCTX: Absolute TTL
+// CLOG: Added DismissReason to dismissals, snapshots, history and the notifier
+
+use crate::notifications::types::{DismissReason, Level};
+use core::fmt::Debug;
+#[cfg(feature = "std")]
+use std::io::Write;
+
+/// Receives each notification as it is shown.
+///
+/// Register one with `Notifications::set_notifier` to add feedback beyond
+/// the screen: a terminal bell, a desktop notification, a sound. The
+/// manager calls [`notify`](Self::notify) from `tick`, once per
+/// notification, when it leaves `Pending` and starts entering. Notifications
+/// added hidden are announced when they are shown. Implement
+/// [`dismissed`](Self::dismissed) to also hear when they leave.
+///
+/// # Example
+/// ```no_run
+/// use ratatui_notifications::notifications::traits::Notifier;
+/// use ratatui_notifications::notifications::Level;
+///
+/// #[derive(Debug, Default)]
+/// struct ErrorCounter(usize);
+///
+/// impl Notifier for ErrorCounter {
+///     fn notify(&mut self, level: Option<Level>, _id: u64) {
+///         if level == Some(Level::Error) {
+///             self.0 += 1;
+///         }
+///     }
+/// }
+/// ```
+pub trait Notifier: Debug + Send {
+    /// Called when a notification is shown.
+    ///
+    /// # Arguments
+    /// * `level` - The notification's level, `None` if it has none
+    /// * `id` - The notification's ID
+    fn notify(&mut self, level: Option<Level>, id: u64);
+
+    /// Called when a notification leaves the manager.
+    ///
+    /// Covers every way out, including notifications that finish without
+    /// ever being shown. Does nothing by default.
+    ///
+    /// # Arguments
+    /// * `_level` - The notification's level, `None` if it has none
+    /// * `_id` - The notification's ID
+    /// * `_reason` - Why it was dismissed
+    fn dismissed(&mut self, _level: Option<Level>, _id: u64, _reason: DismissReason) {}
+}
+
+/// Notifier that rings the terminal bell for warnings and errors.
+///
+/// Writes `BEL` (`\x07`) to stdout; most terminals beep or flash. Other
+/// levels stay silent. Write errors are ignored, since a missing beep
+/// should not disturb the app.
+///
+/// # Example
+/// ```no_run
+/// use ratatui_notifications::notifications::traits::BellNotifier;
+/// use ratatui_notifications::notifications::Notifications;
+///
+/// let mut manager = Notifications::new();
+/// manager.set_notifier(Box::new(BellNotifier));
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BellNotifier;
+
+#[cfg(feature = "std")]
+impl Notifier for BellNotifier {
+    fn notify(&mut self, level: Option<Level>, _id: u64) {
+        if matches!(level, Some(Level::Warn | Level::Error)) {
+            let mut stdout = std::io::stdout();
+            let _ = stdout.write_all(b"\x07").and_then(|()| stdout.flush());
+        }
+    }
+}
+
+// FILE: src/notifications/traits/notifier.rs - Hook for audible or external feedback on show
+// END OF VERSION: 1.1.0
@@ -1,14 +1,20 @@
 // FILE: src/notifications/types/anchor.rs - Screen anchor position enum
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.2.0
+// WCTX: Save and restore manager state
+// CLOG: Optional serde derive
+
+use super::AnchorCoord;
 
 /// Screen position from which notifications expand.
 ///
 /// Notifications are anchored to a corner or edge of the screen and expand
 /// outward from that anchor point. For example, `BottomRight` means notifications
 /// appear in the bottom-right corner and stack upward/leftward.
+///
+/// `Custom` places the notification's top-left corner at an arbitrary point;
+/// such notifications stack downward and slide in from the top by default.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Anchor {
     TopLeft,
@@ -22,7 +28,10 @@ pub enum Anchor {
     /// Default anchor position. Notifications expand from bottom-right.
     #[default]
     BottomRight,
+    /// Fixed point for the notification's top-left corner. The box is
+    /// shifted back inside the frame if it would overflow.
+    Custom { x: AnchorCoord, y: AnchorCoord },
 }
 
 // FILE: src/notifications/types/anchor.rs - Screen anchor position enum
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.2.0
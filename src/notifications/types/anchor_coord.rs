@@ -0,0 +1,68 @@
+// FILE: src/notifications/types/anchor_coord.rs - Coordinate for custom anchor positions
+// VERSION: 1.1.0
+// WCTX: Save and restore manager state
+// CLOG: Optional serde derive
+
+/// One axis of an [`Anchor::Custom`](super::Anchor::Custom) position.
+///
+/// Percentages are stored as integer basis points (hundredths of a percent)
+/// so the coordinate stays `Eq` and `Hash` and anchors group consistently.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AnchorCoord {
+    /// Cells from the frame's left or top edge.
+    Absolute(u16),
+
+    /// Fraction of the frame's width or height in basis points
+    /// (`0` = start, `10_000` = end).
+    BasisPoints(u16),
+}
+
+impl AnchorCoord {
+    /// Creates a coordinate at `percent` of the frame, quantized to basis points.
+    ///
+    /// Values are clamped to `0.0..=100.0`; NaN is treated as `0.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_notifications::notifications::AnchorCoord;
+    ///
+    /// assert_eq!(AnchorCoord::percent(25.0), AnchorCoord::BasisPoints(2_500));
+    /// assert_eq!(AnchorCoord::percent(150.0), AnchorCoord::BasisPoints(10_000));
+    /// ```
+    pub fn percent(percent: f32) -> Self {
+        let percent = if percent.is_nan() { 0.0 } else { percent.clamp(0.0, 100.0) };
+        AnchorCoord::BasisPoints((percent * 100.0).round() as u16)
+    }
+
+    /// Resolves the coordinate to a cell along an axis.
+    ///
+    /// The result always lies within `origin..origin + length` (or equals
+    /// `origin` when `length` is zero).
+    ///
+    /// # Arguments
+    /// * `origin` - Frame x or y
+    /// * `length` - Frame width or height
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_notifications::notifications::AnchorCoord;
+    ///
+    /// assert_eq!(AnchorCoord::percent(25.0).resolve(10, 100), 35);
+    /// assert_eq!(AnchorCoord::Absolute(500).resolve(0, 80), 79);
+    /// ```
+    pub fn resolve(self, origin: u16, length: u16) -> u16 {
+        let offset = match self {
+            AnchorCoord::Absolute(cells) => cells,
+            AnchorCoord::BasisPoints(bp) => {
+                (u32::from(length) * u32::from(bp.min(10_000)) / 10_000) as u16
+            }
+        };
+        origin.saturating_add(offset.min(length.saturating_sub(1)))
+    }
+}
+
+// FILE: src/notifications/types/anchor_coord.rs - Coordinate for custom anchor positions
+// END OF VERSION: 1.1.0
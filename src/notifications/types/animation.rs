@@ -1,10 +1,11 @@
 // FILE: src/notifications/types/animation.rs - Animation type enum
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.2.0
+// WCTX: Save and restore manager state
+// CLOG: Optional serde derive
 
 /// Animation style for notification entry and exit.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum Animation {
     /// Slide animation from a direction (default).
@@ -25,7 +26,14 @@ pub enum Animation {
     /// Notification fades in when appearing and fades out when dismissed.
     /// Subtle and non-intrusive.
     Fade,
+
+    /// Roll-down animation.
+    ///
+    /// The box appears at full size and content lines are revealed one by
+    /// one during entry, then roll back up on exit. Suits multi-line log
+    /// toasts. Uses the `SlidingIn`/`SlidingOut` phases and slide timings.
+    RollDown,
 }
 
 // FILE: src/notifications/types/animation.rs - Animation type enum
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.2.0
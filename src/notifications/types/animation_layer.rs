@@ -0,0 +1,28 @@
+// FILE: src/notifications/types/animation_layer.rs - Composable animation transform enum
+// VERSION: 1.0.0
+// WCTX: Composed animation layers
+// CLOG: Initial creation
+
+/// One transform in a stack of animations played together.
+///
+/// Set with `NotificationBuilder::animation_layers`. Each layer contributes
+/// a [`LayerTransform`](super::LayerTransform) and the renderer composes
+/// them, so `[Slide, Scale]` slides in while growing. The primary
+/// `Animation` still picks the phases and timings; the layers replace its
+/// rect and fade.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum AnimationLayer {
+    /// Moves in from offscreen and back out, like `Animation::Slide`.
+    Slide,
+
+    /// Grows from and shrinks toward the center of the box.
+    Scale,
+
+    /// Blends the colors in and out, like `fade(true)`.
+    Fade,
+}
+
+// FILE: src/notifications/types/animation_layer.rs - Composable animation transform enum
+// END OF VERSION: 1.0.0
@@ -1,11 +1,22 @@
 // FILE: src/notifications/types/animation_phase.rs - Animation phase enum
-// VERSION: 1.1.0
-// WCTX: OFPF migration
-// CLOG: Made public for animation function testing
+// VERSION: 1.2.0
+// WCTX: Phase transition table
+// CLOG: Added the legal transition table and entry/exit phase lookups
+
+use crate::notifications::types::Animation;
 
 /// Animation phase tracking.
 ///
-/// Represents the current stage of a notification's lifecycle.
+/// Represents the current stage of a notification's lifecycle. Phases only
+/// change along the edges allowed by [`can_transition_to`](Self::can_transition_to):
+///
+/// ```text
+/// Pending ──> entry ──> Dwelling ──> exit ──> Finished
+///    │          └───────────────────>─┘          ^
+///    └──────────────────────────────────────────┘
+/// ```
+///
+/// The entry and exit phases depend on the [`Animation`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum AnimationPhase {
     #[default]
@@ -20,5 +31,68 @@ pub enum AnimationPhase {
     Finished,
 }
 
+impl AnimationPhase {
+    /// All phases, in lifecycle order.
+    pub const ALL: [AnimationPhase; 9] = [
+        AnimationPhase::Pending,
+        AnimationPhase::SlidingIn,
+        AnimationPhase::Expanding,
+        AnimationPhase::FadingIn,
+        AnimationPhase::Dwelling,
+        AnimationPhase::SlidingOut,
+        AnimationPhase::Collapsing,
+        AnimationPhase::FadingOut,
+        AnimationPhase::Finished,
+    ];
+
+    /// Returns the entry phase an animation plays.
+    pub fn entry(animation: Animation) -> Self {
+        match animation {
+            Animation::Slide | Animation::RollDown => AnimationPhase::SlidingIn,
+            Animation::ExpandCollapse => AnimationPhase::Expanding,
+            Animation::Fade => AnimationPhase::FadingIn,
+        }
+    }
+
+    /// Returns the exit phase an animation plays.
+    pub fn exit(animation: Animation) -> Self {
+        match animation {
+            Animation::Slide | Animation::RollDown => AnimationPhase::SlidingOut,
+            Animation::ExpandCollapse => AnimationPhase::Collapsing,
+            Animation::Fade => AnimationPhase::FadingOut,
+        }
+    }
+
+    /// Whether a notification with this animation may move from `self` to `next`.
+    ///
+    /// The legal edges are:
+    /// - `Pending` to the entry phase (shown), or to `Finished` (dismissed unseen)
+    /// - entry to `Dwelling` (entry complete), or to the exit phase (dismissed
+    ///   while entering)
+    /// - `Dwelling` to the exit phase
+    /// - exit to `Finished`
+    ///
+    /// Staying in the same phase is not a transition and returns `false`.
+    ///
+    /// # Arguments
+    /// * `next` - The phase to move to
+    /// * `animation` - The notification's animation
+    pub fn can_transition_to(self, next: AnimationPhase, animation: Animation) -> bool {
+        let entry = Self::entry(animation);
+        let exit = Self::exit(animation);
+        if self == entry {
+            return next == AnimationPhase::Dwelling || next == exit;
+        }
+        if self == exit {
+            return next == AnimationPhase::Finished;
+        }
+        match self {
+            AnimationPhase::Pending => next == entry || next == AnimationPhase::Finished,
+            AnimationPhase::Dwelling => next == exit,
+            _ => false,
+        }
+    }
+}
+
 // FILE: src/notifications/types/animation_phase.rs - Animation phase enum
-// END OF VERSION: 1.1.0
+// END OF VERSION: 1.2.0
@@ -1,21 +1,35 @@
 // FILE: src/notifications/types/auto_dismiss.rs - Auto-dismiss behavior enum
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.3.0
+// WCTX: Save and restore manager state
+// CLOG: Optional serde derive
 
-use std::time::Duration;
+use core::time::Duration;
+
+use super::Timestamp;
 
 /// Controls automatic dismissal of notifications.
 ///
 /// Determines whether a notification will automatically dismiss after
 /// a specified duration or remain visible until manually dismissed.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AutoDismiss {
     /// Notification remains visible until manually dismissed.
     Never,
 
     /// Notification automatically dismisses after the specified duration.
     After(Duration),
+
+    /// Notification dismisses once the manager's clock reaches this point,
+    /// however long it has been dwelling. Useful for time-boxed alerts.
+    ///
+    /// The deadline is checked on every `tick()`; if it passes during the entry
+    /// animation the exit starts as soon as the entry completes.
+    ///
+    /// Clock readings cannot be serialized; saved manager state stores the
+    /// time left as `After` instead.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    AtDeadline(Timestamp),
 }
 
 impl Default for AutoDismiss {
@@ -25,4 +39,4 @@ impl Default for AutoDismiss {
 }
 
 // FILE: src/notifications/types/auto_dismiss.rs - Auto-dismiss behavior enum
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.3.0
@@ -0,0 +1,50 @@
+// FILE: src/notifications/types/build_warning.rs - Non-fatal build lint types
+// VERSION: 1.1.0
+// WCTX: Countdown display
+// CLOG: Added CountdownWithoutTimer
+
+use core::fmt;
+
+/// Kind of a [`BuildWarning`], for matching in code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BuildWarningKind {
+    /// A slide enters from the side of the screen opposite the anchor, so it
+    /// crosses the whole frame, and no custom entry position is set.
+    SlideCrossesScreen,
+
+    /// An absolute max size is smaller than one cell of content plus the
+    /// border and padding, so no content can ever be shown.
+    MaxSizeBelowMinimum,
+
+    /// A fixed dwell is shorter than the fixed entry and exit animations
+    /// combined.
+    DwellShorterThanAnimations,
+
+    /// `fade(true)` on `Animation::Fade`, which fades anyway.
+    RedundantFade,
+
+    /// `show_countdown(true)` without `AutoDismiss::After`, so there is no
+    /// display time to count down.
+    CountdownWithoutTimer,
+}
+
+/// A legal but probably unintended configuration found by
+/// `NotificationBuilder::build_with_warnings`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildWarning {
+    /// What was detected.
+    pub kind: BuildWarningKind,
+
+    /// Human-readable explanation.
+    pub message: String,
+}
+
+impl fmt::Display for BuildWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+// FILE: src/notifications/types/build_warning.rs - Non-fatal build lint types
+// END OF VERSION: 1.1.0
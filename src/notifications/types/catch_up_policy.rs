@@ -0,0 +1,24 @@
+// FILE: src/notifications/types/catch_up_policy.rs - Catch-up policy enum for clamped tick deltas
+// VERSION: 1.0.0
+// WCTX: Tick catch-up budget
+// CLOG: Initial creation
+
+/// What `Notifications::tick` does with time cut off by `max_delta`.
+///
+/// After a stall (a blocking file dialog, a suspended terminal) the next tick
+/// receives a large delta. Clamping it keeps entry and exit animations from
+/// being skipped in a single frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CatchUpPolicy {
+    /// Drops the excess: the stall is as if it never happened (default).
+    #[default]
+    Clamp,
+
+    /// Carries the excess over to later ticks, each advancing by at most
+    /// `max_delta`, until animations have caught up with real time.
+    Spread,
+}
+
+// FILE: src/notifications/types/catch_up_policy.rs - Catch-up policy enum for clamped tick deltas
+// END OF VERSION: 1.0.0
@@ -0,0 +1,23 @@
+// FILE: src/notifications/types/color_clamp.rs - Color interpolation clamping enum
+// VERSION: 1.0.0
+// WCTX: Color interpolation clamping
+// CLOG: Initial creation
+
+/// How eased color interpolation handles easings that overshoot.
+///
+/// Bounce, elastic and back easings briefly return progress outside
+/// `0.0..=1.0`. This decides whether that overshoot shows up in the color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorClamp {
+    /// Clamps the eased progress to `0.0..=1.0` before interpolating, so
+    /// every channel stays between the start and end colors (default).
+    #[default]
+    Range,
+
+    /// Uses the eased progress as is, so colors may overshoot past the end
+    /// color; channels are only clamped to the valid `0..=255` range.
+    Channel,
+}
+
+// FILE: src/notifications/types/color_clamp.rs - Color interpolation clamping enum
+// END OF VERSION: 1.0.0
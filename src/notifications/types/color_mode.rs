@@ -0,0 +1,29 @@
+// FILE: src/notifications/types/color_mode.rs - Terminal color capability enum
+// VERSION: 1.0.0
+// WCTX: Color output degradation for limited terminals
+// CLOG: Initial creation
+
+/// Color capability of the target terminal.
+///
+/// Rendered notifications are post-processed so every emitted color can be
+/// displayed: RGB values from fades and custom styles are quantized, or
+/// removed entirely in `Mono`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorMode {
+    /// 24-bit color; colors pass through unchanged (default).
+    #[default]
+    TrueColor,
+
+    /// xterm 256-color palette; RGB values are quantized to palette indices.
+    Ansi256,
+
+    /// The 16 named ANSI colors; every other color maps to the nearest one.
+    Ansi16,
+
+    /// No color. Colors are stripped and levels are distinguished by
+    /// modifiers instead (bold for errors, dim for trace).
+    Mono,
+}
+
+// FILE: src/notifications/types/color_mode.rs - Terminal color capability enum
+// END OF VERSION: 1.0.0
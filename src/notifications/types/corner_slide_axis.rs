@@ -0,0 +1,25 @@
+// FILE: src/notifications/types/corner_slide_axis.rs - Axis corner anchors slide along by default
+// VERSION: 1.0.0
+// WCTX: Corner slide axis
+// CLOG: Initial creation
+
+/// Axis along which notifications at corner anchors slide when their
+/// direction is `SlideDirection::Default`.
+///
+/// Explicit slide directions and non-corner anchors are not affected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CornerSlideAxis {
+    /// Slide in from the corner, e.g. `TopLeft` uses `FromTopLeft` (default).
+    #[default]
+    Diagonal,
+
+    /// Slide in from the nearer side edge, e.g. `TopLeft` uses `FromLeft`.
+    Horizontal,
+
+    /// Slide in from the top or bottom edge, e.g. `TopLeft` uses `FromTop`.
+    Vertical,
+}
+
+// FILE: src/notifications/types/corner_slide_axis.rs - Axis corner anchors slide along by default
+// END OF VERSION: 1.0.0
@@ -0,0 +1,33 @@
+// FILE: src/notifications/types/dismiss_condition.rs - Predicate that dismisses a notification when it holds
+// VERSION: 1.0.0
+// WCTX: Conditional dismissal
+// CLOG: Initial creation
+
+use std::fmt;
+
+/// Predicate checked by the manager on every tick.
+type Predicate = Box<dyn FnMut() -> bool + Send + Sync>;
+
+/// Condition registered by `Notifications::dismiss_when`.
+pub(crate) struct DismissCondition(Predicate);
+
+impl DismissCondition {
+    /// Wraps a predicate.
+    pub(crate) fn new(predicate: Predicate) -> Self {
+        Self(predicate)
+    }
+
+    /// Calls the predicate.
+    pub(crate) fn holds(&mut self) -> bool {
+        (self.0)()
+    }
+}
+
+impl fmt::Debug for DismissCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DismissCondition(..)")
+    }
+}
+
+// FILE: src/notifications/types/dismiss_condition.rs - Predicate that dismisses a notification when it holds
+// END OF VERSION: 1.0.0
@@ -0,0 +1,40 @@
+// FILE: src/notifications/types/dismiss_on_input.rs - Which key presses dismiss visible notifications
+// VERSION: 1.1.0
+// WCTX: Dismiss on input
+// CLOG: Noted the require_ack exemption from Any
+
+use crossterm::event::KeyCode;
+
+/// Which key presses `Notifications::handle_event` answers by dismissing
+/// every visible notification.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum DismissOnInput {
+    /// Key presses are never consumed (default).
+    #[default]
+    Off,
+
+    /// Any key press dismisses, except notifications built with
+    /// `require_ack(true)`.
+    Any,
+
+    /// Only presses of the listed keys dismiss.
+    Keys(Vec<KeyCode>),
+}
+
+impl DismissOnInput {
+    /// Returns whether a press of `code` dismisses under this policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The pressed key
+    pub fn dismisses(&self, code: KeyCode) -> bool {
+        match self {
+            Self::Off => false,
+            Self::Any => true,
+            Self::Keys(keys) => keys.contains(&code),
+        }
+    }
+}
+
+// FILE: src/notifications/types/dismiss_on_input.rs - Which key presses dismiss visible notifications
+// END OF VERSION: 1.1.0
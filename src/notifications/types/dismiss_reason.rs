@@ -0,0 +1,46 @@
+// FILE: src/notifications/types/dismiss_reason.rs - Why a notification left the manager
+// VERSION: 1.2.0
+// WCTX: Dismiss on input
+// CLOG: Added DismissReason::UserInput
+
+/// Why a notification was dismissed.
+///
+/// Set when the notification starts leaving, and reported in
+/// `NotificationInfo::dismiss_reason`, in its history record, and to the
+/// notifier's `dismissed` callback once it is gone. The first reason
+/// sticks: removing a notification that is already exiting keeps the
+/// reason its exit started with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum DismissReason {
+    /// Its `auto_dismiss` display time or deadline ran out.
+    Timeout,
+
+    /// The app removed or dismissed it: `remove`, `clear`, `drain` or
+    /// `dismiss_toward`.
+    Manual,
+
+    /// Its `ttl` passed while it was on screen.
+    Expired,
+
+    /// Its `ttl` passed before it was shown, so it never appeared.
+    Stale,
+
+    /// It made room under `max_concurrent` or `max_total`.
+    Evicted,
+
+    /// Its `dismiss_when` condition held.
+    Condition,
+
+    /// A `retain` predicate rejected it, or a `for_each_mut` closure
+    /// dismissed it.
+    Retained,
+
+    /// A key press covered by `dismiss_on_input` dismissed it through
+    /// `handle_event`.
+    UserInput,
+}
+
+// FILE: src/notifications/types/dismiss_reason.rs - Why a notification left the manager
+// END OF VERSION: 1.2.0
@@ -0,0 +1,68 @@
+// FILE: src/notifications/types/easing.rs - Easing curve enum
+// VERSION: 1.1.0
+// WCTX: Eased expand
+// CLOG: Optional serde derive
+
+use crate::shared_utils::math::{ease_in_out_quad, ease_in_quad, ease_out_quad};
+
+/// Easing curve mapping linear progress to eased progress.
+///
+/// Every curve clamps its input to `0.0..=1.0`, so it starts at `0.0` and
+/// ends at `1.0`. Use [`easing::sample`](crate::easing::sample) to draw a
+/// preview of a curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Easing {
+    /// Constant speed.
+    #[default]
+    Linear,
+
+    /// Starts slowly and accelerates.
+    EaseInQuad,
+
+    /// Starts quickly and decelerates.
+    EaseOutQuad,
+
+    /// Accelerates through the first half, decelerates through the second.
+    EaseInOutQuad,
+}
+
+impl Easing {
+    /// All curves, in declaration order.
+    pub const ALL: [Easing; 4] = [
+        Easing::Linear,
+        Easing::EaseInQuad,
+        Easing::EaseOutQuad,
+        Easing::EaseInOutQuad,
+    ];
+
+    /// Applies the curve to a linear progress value.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - Linear progress; clamped to `0.0..=1.0`
+    ///
+    /// # Returns
+    ///
+    /// The eased progress, in `0.0..=1.0`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_notifications::easing::Easing;
+    ///
+    /// assert_eq!(Easing::EaseOutQuad.apply(0.5), 0.75);
+    /// assert_eq!(Easing::Linear.apply(1.5), 1.0);
+    /// ```
+    pub fn apply(self, t: f32) -> f32 {
+        match self {
+            Easing::Linear => t.clamp(0.0, 1.0),
+            Easing::EaseInQuad => ease_in_quad(t),
+            Easing::EaseOutQuad => ease_out_quad(t),
+            Easing::EaseInOutQuad => ease_in_out_quad(t),
+        }
+    }
+}
+
+// FILE: src/notifications/types/easing.rs - Easing curve enum
+// END OF VERSION: 1.1.0
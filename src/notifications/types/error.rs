@@ -1,7 +1,7 @@
 // FILE: src/notifications/types/error.rs - Notification error type
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.5.0
+// WCTX: Per-notification evictability
+// CLOG: Added Evictability
 
 use thiserror::Error;
 
@@ -14,9 +14,34 @@ pub enum NotificationError {
     InvalidConfig(String),
 
     /// Content exceeds size limits.
-    #[error("Content too large: {0} bytes exceeds limit of {1} bytes")]
+    ///
+    /// Holds `(required, allowed)`: characters for the content length limit,
+    /// rows when a `strict_fit` notification does not fit its `max_size`.
+    #[error("Content too large: {0} exceeds limit of {1}")]
     ContentTooLarge(usize, usize),
+
+    /// A strict template referenced a field that was not supplied.
+    #[error("Missing template field: {0}")]
+    MissingTemplateField(String),
+
+    /// `add_with_id` asked for an ID at or below one the manager already
+    /// assigned.
+    #[error("Notification ID {0} was already used")]
+    IdAlreadyUsed(u64),
+
+    /// Every `u64` ID has been assigned; IDs are never reused.
+    #[error("Notification IDs exhausted")]
+    IdsExhausted,
+
+    /// `add_preset` named a preset that was never registered.
+    #[error("Unknown notification preset: {0}")]
+    UnknownPreset(String),
+
+    /// A limit was reached and every notification holding a slot is
+    /// `Evictability::Never`.
+    #[error("Notification limit reached with nothing evictable")]
+    LimitReached,
 }
 
 // FILE: src/notifications/types/error.rs - Notification error type
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.5.0
@@ -0,0 +1,40 @@
+// FILE: src/notifications/types/evictability.rs - Per-notification overflow eviction class
+// VERSION: 1.1.0
+// WCTX: Per-notification evictability
+// CLOG: Gated rank on std
+
+/// How willingly a notification gives up its slot when a limit is reached.
+///
+/// Evictability takes precedence over the manager's `Overflow` policy: the
+/// victim is picked among `First` notifications if there are any, otherwise
+/// among `Normal` ones, and the policy (oldest, newest, lowest priority)
+/// only decides within that class. `Never` notifications are not evicted;
+/// when nothing else holds a slot, the add is rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Evictability {
+    /// Evicted per the overflow policy (default).
+    #[default]
+    Normal,
+
+    /// Never evicted, e.g. an unsaved-changes warning.
+    Never,
+
+    /// Best effort: evicted before any `Normal` notification.
+    First,
+}
+
+impl Evictability {
+    /// Rank in eviction order; lower ranks go first.
+    #[cfg(feature = "std")] // Only the manager evicts
+    pub(crate) fn rank(self) -> u8 {
+        match self {
+            Evictability::First => 0,
+            Evictability::Normal => 1,
+            Evictability::Never => 2,
+        }
+    }
+}
+
+// FILE: src/notifications/types/evictability.rs - Per-notification overflow eviction class
+// END OF VERSION: 1.1.0
@@ -0,0 +1,30 @@
+// FILE: src/notifications/types/expand_origin.rs - Expand/collapse origin enum
+// VERSION: 1.1.0
+// WCTX: Save and restore manager state
+// CLOG: Optional serde derive
+
+use ratatui::layout::Position;
+
+/// Point that stays fixed while an `ExpandCollapse` animation resizes.
+///
+/// The notification grows out of (and shrinks back into) this point while
+/// its width and height interpolate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExpandOrigin {
+    /// Grow from the center of the notification (default).
+    #[default]
+    Center,
+
+    /// Grow from the notification's anchor point, e.g. the bottom-right
+    /// corner for `Anchor::BottomRight` or the top edge's midpoint for
+    /// `Anchor::TopCenter`.
+    Anchor,
+
+    /// Grow from an absolute screen position, clamped to the notification's
+    /// bounds.
+    Position(Position),
+}
+
+// FILE: src/notifications/types/expand_origin.rs - Expand/collapse origin enum
+// END OF VERSION: 1.1.0
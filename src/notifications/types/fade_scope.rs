@@ -0,0 +1,38 @@
+// FILE: src/notifications/types/fade_scope.rs - Fade scope enum
+// VERSION: 1.1.0
+// WCTX: Save and restore manager state
+// CLOG: Optional serde derive
+
+/// Which parts of a notification a fade affects.
+///
+/// Applies to the standalone `Fade` animation and to `fade(true)` on the
+/// other animations. Parts outside the scope keep their normal colors for the
+/// whole animation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FadeScope {
+    /// Fade both the frame (border, title) and the content (default).
+    #[default]
+    All,
+
+    /// Fade only the content; the frame stays solid.
+    ContentOnly,
+
+    /// Fade only the frame; the content stays solid.
+    FrameOnly,
+}
+
+impl FadeScope {
+    /// Returns whether the border and title fade.
+    pub const fn fades_frame(self) -> bool {
+        matches!(self, FadeScope::All | FadeScope::FrameOnly)
+    }
+
+    /// Returns whether the content text fades.
+    pub const fn fades_content(self) -> bool {
+        matches!(self, FadeScope::All | FadeScope::ContentOnly)
+    }
+}
+
+// FILE: src/notifications/types/fade_scope.rs - Fade scope enum
+// END OF VERSION: 1.1.0
@@ -0,0 +1,23 @@
+// FILE: src/notifications/types/layer.rs - Draw layer relative to the app's own overlays
+// VERSION: 1.0.0
+// WCTX: Render layers
+// CLOG: Initial creation
+
+/// Which `Notifications::render_layer` pass draws a notification.
+///
+/// Apps render the `Below` layer before their own modals and the `Above`
+/// layer after them, so critical notifications stay on top of dialogs.
+/// `Notifications::render` draws both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Layer {
+    /// Drawn with the app's content, under its overlays (default).
+    #[default]
+    Below,
+
+    /// Drawn over the app's overlays.
+    Above,
+}
+
+// FILE: src/notifications/types/layer.rs - Draw layer relative to the app's own overlays
+// END OF VERSION: 1.0.0
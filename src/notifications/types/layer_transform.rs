@@ -0,0 +1,48 @@
+// FILE: src/notifications/types/layer_transform.rs - Rect and opacity transform from an animation layer
+// VERSION: 1.0.0
+// WCTX: Composed animation layers
+// CLOG: Initial creation
+
+/// Rect and opacity change one [`AnimationLayer`](super::AnimationLayer)
+/// contributes at the current progress.
+///
+/// Transforms compose with [`then`](Self::then): offsets add, scale and
+/// opacity multiply.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayerTransform {
+    /// Horizontal offset from the settled position, in cells.
+    pub offset_x: f32,
+
+    /// Vertical offset from the settled position, in cells.
+    pub offset_y: f32,
+
+    /// Size factor applied around the box's center; 1.0 is full size.
+    pub scale: f32,
+
+    /// Color blend factor; 1.0 is fully visible.
+    pub opacity: f32,
+}
+
+impl LayerTransform {
+    /// No change: the settled rect at full opacity.
+    pub const IDENTITY: Self = Self { offset_x: 0.0, offset_y: 0.0, scale: 1.0, opacity: 1.0 };
+
+    /// Returns the transform applying both `self` and `other`.
+    pub fn then(self, other: Self) -> Self {
+        Self {
+            offset_x: self.offset_x + other.offset_x,
+            offset_y: self.offset_y + other.offset_y,
+            scale: self.scale * other.scale,
+            opacity: self.opacity * other.opacity,
+        }
+    }
+}
+
+impl Default for LayerTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+// FILE: src/notifications/types/layer_transform.rs - Rect and opacity transform from an animation layer
+// END OF VERSION: 1.0.0
@@ -1,13 +1,17 @@
 // FILE: src/notifications/types/level.rs - Notification severity level enum
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.2.0
+// WCTX: Severity floor filter
+// CLOG: Ord by severity
 
 /// Severity level of a notification.
 ///
 /// Affects the visual styling of the notification (colors, borders).
 /// Higher severity levels typically use more prominent colors to draw attention.
+///
+/// Levels are ordered by severity, `Trace < Debug < Info < Warn < Error`,
+/// which differs from the declaration order below.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Level {
     /// Informational message (default).
     #[default]
@@ -26,5 +30,30 @@ pub enum Level {
     Trace,
 }
 
+impl Level {
+    /// Position in the severity order, from 0 (`Trace`) to 4 (`Error`).
+    fn rank(self) -> u8 {
+        match self {
+            Level::Trace => 0,
+            Level::Debug => 1,
+            Level::Info => 2,
+            Level::Warn => 3,
+            Level::Error => 4,
+        }
+    }
+}
+
+impl PartialOrd for Level {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Level {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
 // FILE: src/notifications/types/level.rs - Notification severity level enum
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.2.0
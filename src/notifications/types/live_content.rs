@@ -0,0 +1,40 @@
+// FILE: src/notifications/types/live_content.rs - Shared closure producing live notification content
+// VERSION: 1.0.0
+// WCTX: Live content
+// CLOG: Initial creation
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// Closure producing the current content of a live notification.
+type Producer = Box<dyn FnMut() -> String + Send>;
+
+/// Content producer polled by the manager on every tick.
+///
+/// Shared behind a mutex so notifications stay `Clone` and the manager stays
+/// `Send`; clones of a notification call the same closure.
+#[derive(Clone)]
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
+pub(crate) struct LiveContent(Arc<Mutex<Producer>>);
+
+impl LiveContent {
+    /// Wraps a content producer.
+    pub(crate) fn new(producer: Producer) -> Self {
+        Self(Arc::new(Mutex::new(producer)))
+    }
+
+    /// Calls the producer, returning `None` if a previous call panicked.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))] // Only the manager polls
+    pub(crate) fn poll(&self) -> Option<String> {
+        self.0.lock().ok().map(|mut producer| producer())
+    }
+}
+
+impl fmt::Debug for LiveContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LiveContent(..)")
+    }
+}
+
+// FILE: src/notifications/types/live_content.rs - Shared closure producing live notification content
+// END OF VERSION: 1.0.0
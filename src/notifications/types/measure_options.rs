@@ -0,0 +1,100 @@
+// FILE: src/notifications/types/measure_options.rs - Layout inputs for measuring text as a notification
+// VERSION: 1.0.0
+// WCTX: Public measure API
+// CLOG: Initial creation
+
+use crate::notifications::classes::Notification;
+use crate::notifications::types::{SizeConstraint, TruncateMode};
+use ratatui::layout::Rect;
+use ratatui::text::Line;
+use ratatui::widgets::{BorderType, Padding};
+
+/// Layout settings `measure` sizes text with, without building a notification.
+///
+/// `MeasureOptions::new` starts from the same defaults as
+/// `NotificationBuilder`; set the fields that differ.
+///
+/// # Example
+///
+/// ```no_run
+/// use ratatui::layout::Rect;
+/// use ratatui_notifications::notifications::{MeasureOptions, SizeConstraint};
+///
+/// let options = MeasureOptions {
+///     max_width: Some(SizeConstraint::Absolute(40)),
+///     title: Some("Deploy".into()),
+///     ..MeasureOptions::new(Rect::new(0, 0, 120, 40))
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeasureOptions {
+    /// Frame the notification would be shown in; percentage constraints
+    /// resolve against it.
+    pub frame_area: Rect,
+
+    /// Maximum width (default 40% of the frame).
+    pub max_width: Option<SizeConstraint>,
+
+    /// Maximum height (default 20% of the frame).
+    pub max_height: Option<SizeConstraint>,
+
+    /// Minimum width (default none).
+    pub min_width: Option<SizeConstraint>,
+
+    /// Minimum height (default none).
+    pub min_height: Option<SizeConstraint>,
+
+    /// Border type, or `None` for no border (default rounded).
+    pub border_type: Option<BorderType>,
+
+    /// Interior padding (default one column left and right).
+    pub padding: Padding,
+
+    /// Title drawn on the top border (default none).
+    pub title: Option<Line<'static>>,
+
+    /// Whether a rule separates the title from the content (default `false`).
+    pub title_separator: bool,
+
+    /// Truncation mode, or `None` to wrap (default).
+    pub truncate_mode: Option<TruncateMode>,
+
+    /// Symbol marking truncated text (default `"…"`).
+    pub ellipsis: String,
+}
+
+impl MeasureOptions {
+    /// Creates options with the `NotificationBuilder` defaults.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame_area` - Frame the notification would be shown in
+    pub fn new(frame_area: Rect) -> Self {
+        Self::of(&Notification::default(), frame_area)
+    }
+
+    /// Creates options with a notification's layout settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `notification` - Notification whose settings to copy
+    /// * `frame_area` - Frame the notification is shown in
+    pub(crate) fn of(notification: &Notification, frame_area: Rect) -> Self {
+        Self {
+            frame_area,
+            max_width: notification.max_width,
+            max_height: notification.max_height,
+            min_width: notification.min_width,
+            min_height: notification.min_height,
+            border_type: notification.border_type,
+            padding: notification.padding,
+            title: notification.title.clone(),
+            title_separator: notification.title_separator,
+            truncate_mode: notification.truncate_mode,
+            ellipsis: notification.ellipsis.clone(),
+        }
+    }
+}
+
+// FILE: src/notifications/types/measure_options.rs - Layout inputs for measuring text as a notification
+// END OF VERSION: 1.0.0
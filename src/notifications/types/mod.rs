@@ -1,29 +1,107 @@
 // FILE: src/notifications/types/mod.rs - Module declarations and re-exports for notification types
-// VERSION: 1.1.0
-// WCTX: OFPF migration
-// CLOG: Made AnimationPhase public for animation function testing
+// VERSION: 1.32.0
+// WCTX: Absolute TTL
+// CLOG: Exported DismissReason
 
 mod anchor;
+mod anchor_coord;
 mod animation;
+mod animation_layer;
 mod animation_phase;
 mod auto_dismiss;
+mod build_warning;
+mod catch_up_policy;
+mod color_clamp;
+mod color_mode;
+mod corner_slide_axis;
+#[cfg(feature = "std")]
+mod dismiss_condition;
+#[cfg(feature = "crossterm")]
+mod dismiss_on_input;
+mod dismiss_reason;
+mod easing;
 mod error;
+mod evictability;
+mod expand_origin;
+mod fade_scope;
+mod layer;
+mod layer_transform;
 mod level;
+mod live_content;
+mod measure_options;
+#[cfg(feature = "scenario")]
+mod notification_config;
+mod notification_info;
+mod notification_record;
 mod overflow;
+mod path_point;
+mod placeholder_style;
+#[cfg(feature = "std")]
+mod preset;
+mod resolved_styles;
+#[cfg(feature = "scenario")]
+mod scenario_error;
 mod size_constraint;
 mod slide_direction;
+mod sort_by;
+mod stack_layout;
+mod state_update;
+mod string_args;
+mod string_key;
 mod timing;
+mod timestamp;
+mod truncate_mode;
+mod vertical_align;
 
 pub use anchor::Anchor;
+pub use anchor_coord::AnchorCoord;
 pub use animation::Animation;
+pub use animation_layer::AnimationLayer;
 pub use animation_phase::AnimationPhase;
 pub use auto_dismiss::AutoDismiss;
+pub use build_warning::{BuildWarning, BuildWarningKind};
+pub use catch_up_policy::CatchUpPolicy;
+pub use color_clamp::ColorClamp;
+pub use color_mode::ColorMode;
+pub use corner_slide_axis::CornerSlideAxis;
+#[cfg(feature = "std")]
+pub(crate) use dismiss_condition::DismissCondition;
+#[cfg(feature = "crossterm")]
+pub use dismiss_on_input::DismissOnInput;
+pub use dismiss_reason::DismissReason;
+pub use easing::Easing;
 pub use error::NotificationError;
+pub use evictability::Evictability;
+pub use expand_origin::ExpandOrigin;
+pub use fade_scope::FadeScope;
+pub use layer::Layer;
+pub use layer_transform::LayerTransform;
 pub use level::Level;
+pub(crate) use live_content::LiveContent;
+pub use measure_options::MeasureOptions;
+#[cfg(feature = "scenario")]
+pub use notification_config::NotificationConfig;
+pub use notification_info::NotificationInfo;
+pub use notification_record::NotificationRecord;
 pub use overflow::Overflow;
+pub use path_point::PathPoint;
+pub use placeholder_style::PlaceholderStyle;
+#[cfg(feature = "std")]
+pub(crate) use preset::Preset;
+pub use resolved_styles::ResolvedStyles;
+#[cfg(feature = "scenario")]
+pub use scenario_error::ScenarioError;
 pub use size_constraint::SizeConstraint;
 pub use slide_direction::SlideDirection;
+pub use sort_by::SortBy;
+pub use stack_layout::StackLayout;
+pub use state_update::StateUpdate;
+pub use string_args::StringArgs;
+pub use string_key::StringKey;
 pub use timing::Timing;
+pub use timestamp::Timestamp;
+pub use truncate_mode::TruncateMode;
+pub use vertical_align::VerticalAlign;
 
 // FILE: src/notifications/types/mod.rs - Module declarations and re-exports for notification types
-// END OF VERSION: 1.1.0
+// END OF VERSION: 1.32.0
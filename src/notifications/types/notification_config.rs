@@ -0,0 +1,106 @@
+// FILE: src/notifications/types/notification_config.rs - Plain-data notification description for config files
+// VERSION: 1.0.0
+// WCTX: TOML scenario player
+// CLOG: Initial creation
+
+use super::{Anchor, Animation, AutoDismiss, Level, ScenarioError, SlideDirection};
+use crate::notifications::classes::{Notification, NotificationBuilder};
+use crate::notifications::functions::fnc_parse_duration::parse_duration;
+
+/// A notification described as plain data, for config and scenario files.
+///
+/// Every field but `content` is optional and falls back to the builder's
+/// default. Durations are strings with an `s` or `ms` suffix. Unknown keys
+/// are rejected so typos surface at load time.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_notifications::notifications::{Level, NotificationConfig};
+///
+/// let config: NotificationConfig = toml::from_str(r#"
+///     content = "Disk almost full"
+///     title = "Storage"
+///     level = "Warn"
+///     auto_dismiss = "3s"
+/// "#).unwrap();
+///
+/// let notification = config.build().unwrap();
+/// assert_eq!(notification.level(), Some(Level::Warn));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NotificationConfig {
+    /// The body text.
+    pub content: String,
+
+    /// The title, if any.
+    pub title: Option<String>,
+
+    /// The severity level.
+    pub level: Option<Level>,
+
+    /// The screen anchor.
+    pub anchor: Option<Anchor>,
+
+    /// The entry and exit animation.
+    pub animation: Option<Animation>,
+
+    /// The slide direction for `Animation::Slide`.
+    pub slide_direction: Option<SlideDirection>,
+
+    /// `"never"`, or how long the notification dwells, e.g. `"4s"`.
+    pub auto_dismiss: Option<String>,
+}
+
+impl NotificationConfig {
+    /// Applies the configuration to a builder.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(NotificationBuilder)` - A builder with every set field applied
+    /// * `Err(ScenarioError::InvalidDuration)` - If `auto_dismiss` is not
+    ///   `"never"` or a duration
+    pub fn builder(&self) -> Result<NotificationBuilder, ScenarioError> {
+        let mut builder = NotificationBuilder::new(self.content.clone());
+        if let Some(title) = &self.title {
+            builder = builder.title(title.clone());
+        }
+        if let Some(level) = self.level {
+            builder = builder.level(level);
+        }
+        if let Some(anchor) = self.anchor {
+            builder = builder.anchor(anchor);
+        }
+        if let Some(animation) = self.animation {
+            builder = builder.animation(animation);
+        }
+        if let Some(direction) = self.slide_direction {
+            builder = builder.slide_direction(direction);
+        }
+        if let Some(auto_dismiss) = &self.auto_dismiss {
+            let auto_dismiss = match auto_dismiss.as_str() {
+                "never" => AutoDismiss::Never,
+                text => AutoDismiss::After(
+                    parse_duration(text).ok_or_else(|| ScenarioError::InvalidDuration(text.to_string()))?,
+                ),
+            };
+            builder = builder.auto_dismiss(auto_dismiss);
+        }
+        Ok(builder)
+    }
+
+    /// Builds the notification.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Notification)` - The configured notification
+    /// * `Err(ScenarioError)` - If a duration is invalid or the builder
+    ///   rejects the configuration
+    pub fn build(&self) -> Result<Notification, ScenarioError> {
+        Ok(self.builder()?.build()?)
+    }
+}
+
+// FILE: src/notifications/types/notification_config.rs - Plain-data notification description for config files
+// END OF VERSION: 1.0.0
@@ -0,0 +1,54 @@
+// FILE: src/notifications/types/notification_info.rs - Read-only snapshot of a managed notification
+// VERSION: 1.2.0
+// WCTX: Absolute TTL
+// CLOG: Added DismissReason to dismissals, snapshots, history and the notifier
+
+use crate::notifications::types::{Anchor, AnimationPhase, DismissReason, Level};
+use core::time::Duration;
+use ratatui::layout::Rect;
+use std::sync::Arc;
+
+/// Snapshot of a managed notification.
+///
+/// Returned by `Notifications::get` and `Notifications::infos`, and passed to
+/// `Notifications::retain`. The snapshot owns its data: changing it never
+/// affects the manager. Title and content are shared `Arc<str>`s cached by
+/// the notification, so taking a snapshot does not copy the text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationInfo {
+    /// The notification's ID.
+    pub id: u64,
+
+    /// The title as plain text, if one was set.
+    pub title: Option<Arc<str>>,
+
+    /// The current content as plain text, lines joined with `\n`.
+    pub content: Arc<str>,
+
+    /// The notification's level, if one was set.
+    pub level: Option<Level>,
+
+    /// The anchor the notification is stacked at.
+    pub anchor: Anchor,
+
+    /// The current animation phase.
+    pub phase: AnimationPhase,
+
+    /// Progress through the current entry or exit animation (`0.0..=1.0`).
+    pub progress: f32,
+
+    /// Time since the notification was added, read from the manager's clock.
+    pub age: Duration,
+
+    /// Display time left before the exit starts, if it counts down.
+    pub remaining: Option<Duration>,
+
+    /// Where the notification was laid out by the last render, if rendered.
+    pub rect: Option<Rect>,
+
+    /// Why the notification is leaving, or `None` while it stays.
+    pub dismiss_reason: Option<DismissReason>,
+}
+
+// FILE: src/notifications/types/notification_info.rs - Read-only snapshot of a managed notification
+// END OF VERSION: 1.2.0
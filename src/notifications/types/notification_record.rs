@@ -0,0 +1,50 @@
+// FILE: src/notifications/types/notification_record.rs - History entry for a notification
+// VERSION: 1.2.0
+// WCTX: Absolute TTL
+// CLOG: Added DismissReason to dismissals, snapshots, history and the notifier
+
+use crate::notifications::types::{DismissReason, Level, Timestamp};
+use ratatui::text::{Line, Text};
+
+/// Entry in the manager's notification history.
+///
+/// Recorded when a notification is added to a manager with a non-zero
+/// `history_limit`, and kept after the notification is gone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationRecord {
+    /// The notification's ID.
+    pub id: u64,
+
+    /// The notification's level, if one was set.
+    pub level: Option<Level>,
+
+    /// The notification's title, if one was set.
+    pub title: Option<Line<'static>>,
+
+    /// The notification's content when it was added.
+    pub content: Text<'static>,
+
+    /// When the notification was added, read from the manager's clock.
+    pub added_at: Timestamp,
+
+    /// When the notification was removed, or `None` while it is still shown.
+    pub dismissed_at: Option<Timestamp>,
+
+    /// Whether the notification was dropped by `Notifications::min_level`
+    /// and never shown. Filtered records are already dismissed.
+    pub filtered: bool,
+
+    /// Why the notification was removed, or `None` while it is still
+    /// shown and for filtered records.
+    pub dismiss_reason: Option<DismissReason>,
+}
+
+impl NotificationRecord {
+    /// Returns `true` once the notification has left the manager.
+    pub fn is_dismissed(&self) -> bool {
+        self.dismissed_at.is_some()
+    }
+}
+
+// FILE: src/notifications/types/notification_record.rs - History entry for a notification
+// END OF VERSION: 1.2.0
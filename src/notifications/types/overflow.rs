@@ -1,13 +1,14 @@
 // FILE: src/notifications/types/overflow.rs - Notification overflow behavior enum
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.3.0
+// WCTX: TOML scenario player
+// CLOG: Optional serde derive
 
 /// Behavior when notification limit is reached.
 ///
 /// Determines which notification to discard when the maximum number
 /// of concurrent notifications is exceeded.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Overflow {
     /// Discard the oldest notification when limit is reached (default).
     #[default]
@@ -15,7 +16,18 @@ pub enum Overflow {
 
     /// Discard the newest notification when limit is reached.
     DiscardNewest,
+
+    /// Merge a notification into an active one with identical content at the
+    /// same anchor, showing a count suffix such as "(x3)" and restarting its
+    /// display time. Falls back to discarding the oldest when the limit is
+    /// reached without a duplicate.
+    MergeDuplicates,
+
+    /// Discard the notification with the lowest effective priority: its
+    /// level's severity (`Error` highest), lowered over time by
+    /// `NotificationBuilder::decay`. Ties discard the oldest.
+    DiscardLowestPriority,
 }
 
 // FILE: src/notifications/types/overflow.rs - Notification overflow behavior enum
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.3.0
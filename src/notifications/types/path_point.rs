@@ -0,0 +1,59 @@
+// FILE: src/notifications/types/path_point.rs - Custom slide entry/exit point
+// VERSION: 1.1.0
+// WCTX: Save and restore manager state
+// CLOG: Optional serde derive
+
+use ratatui::layout::{Position, Rect};
+
+/// Custom start point of a slide-in or end point of a slide-out.
+///
+/// Set with `entry_position`/`exit_position` (absolute) or
+/// `entry_position_pct`/`exit_position_pct` (percent). Percent points are
+/// resolved against the frame on every render, so the path stays
+/// proportional when the terminal is resized mid-animation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PathPoint {
+    /// Fixed cell position of the notification's top-left corner.
+    Absolute(Position),
+
+    /// Top-left corner as percentages of the frame's width and height.
+    /// Values outside `0.0..=100.0` place the point off screen.
+    Percent { x: f32, y: f32 },
+}
+
+impl PathPoint {
+    /// Resolves the point to cell coordinates within `frame_area`.
+    ///
+    /// # Arguments
+    /// * `frame_area` - The frame the notification is rendered in
+    ///
+    /// # Returns
+    /// The `(x, y)` position of the notification's top-left corner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::layout::Rect;
+    /// use ratatui_notifications::notifications::PathPoint;
+    ///
+    /// let point = PathPoint::Percent { x: 50.0, y: 110.0 };
+    /// assert_eq!(point.resolve(Rect::new(0, 0, 80, 20)), (40.0, 22.0));
+    /// assert_eq!(point.resolve(Rect::new(0, 0, 120, 40)), (60.0, 44.0));
+    /// ```
+    pub fn resolve(self, frame_area: Rect) -> (f32, f32) {
+        match self {
+            PathPoint::Absolute(position) => (position.x as f32, position.y as f32),
+            PathPoint::Percent { x, y } => {
+                let fraction = |percent: f32| if percent.is_nan() { 0.0 } else { percent / 100.0 };
+                (
+                    frame_area.x as f32 + frame_area.width as f32 * fraction(x),
+                    frame_area.y as f32 + frame_area.height as f32 * fraction(y),
+                )
+            }
+        }
+    }
+}
+
+// FILE: src/notifications/types/path_point.rs - Custom slide entry/exit point
+// END OF VERSION: 1.1.0
@@ -0,0 +1,25 @@
+// FILE: src/notifications/types/placeholder_style.rs - Placeholder shown until a notification's content arrives
+// VERSION: 1.0.0
+// WCTX: Content placeholders
+// CLOG: Initial creation
+
+/// What a notification shows in place of its content until
+/// `Notifications::update_content` supplies it.
+///
+/// The box is sized for the placeholder, so pair `Skeleton` with `min_size`
+/// to reserve room for the real content.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlaceholderStyle {
+    /// Dim dashes filling the content area.
+    Skeleton,
+
+    /// A braille spinner that turns with the manager's clock.
+    Spinner,
+
+    /// Fixed text, e.g. "Resolving…".
+    Text(String),
+}
+
+// FILE: src/notifications/types/placeholder_style.rs - Placeholder shown until a notification's content arrives
+// END OF VERSION: 1.0.0
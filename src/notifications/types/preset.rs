@@ -0,0 +1,34 @@
+// FILE: src/notifications/types/preset.rs - Named notification style registered with the manager
+// VERSION: 1.0.0
+// WCTX: Notification presets
+// CLOG: Initial creation
+
+use crate::notifications::classes::NotificationBuilder;
+use std::fmt;
+
+/// Function applying a preset's styling and timing to a builder.
+type Apply = Box<dyn Fn(NotificationBuilder) -> NotificationBuilder + Send + Sync>;
+
+/// Styling and timing registered under a name by `Notifications::register_preset`.
+pub(crate) struct Preset(Apply);
+
+impl Preset {
+    /// Wraps a preset function.
+    pub(crate) fn new(apply: Apply) -> Self {
+        Self(apply)
+    }
+
+    /// Applies the preset to a builder holding the call site's content.
+    pub(crate) fn apply(&self, builder: NotificationBuilder) -> NotificationBuilder {
+        (self.0)(builder)
+    }
+}
+
+impl fmt::Debug for Preset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Preset(..)")
+    }
+}
+
+// FILE: src/notifications/types/preset.rs - Named notification style registered with the manager
+// END OF VERSION: 1.0.0
@@ -0,0 +1,33 @@
+// FILE: src/notifications/types/resolved_styles.rs - Final styles for one notification
+// VERSION: 1.1.0
+// WCTX: Public level styles
+// CLOG: Added icon
+
+use ratatui::style::Style;
+use ratatui::widgets::BorderType;
+
+/// Styles for one notification after theme and per-notification overrides
+/// have been applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResolvedStyles {
+    /// Style of the whole block (background, content text).
+    pub block: Style,
+
+    /// Border style.
+    pub border: Style,
+
+    /// Title style.
+    pub title: Style,
+
+    /// Border type imposed by the theme, if any.
+    pub border_type: Option<BorderType>,
+
+    /// Title prefix imposed by the theme, if any.
+    pub title_prefix: Option<&'static str>,
+
+    /// Icon drawn before the title, if the notification has a level.
+    pub icon: Option<&'static str>,
+}
+
+// FILE: src/notifications/types/resolved_styles.rs - Final styles for one notification
+// END OF VERSION: 1.1.0
@@ -0,0 +1,28 @@
+// FILE: src/notifications/types/scenario_error.rs - Scenario loading error type
+// VERSION: 1.0.0
+// WCTX: TOML scenario player
+// CLOG: Initial creation
+
+use super::NotificationError;
+use thiserror::Error;
+
+/// Errors from loading a scenario file.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ScenarioError {
+    /// The file is not valid TOML or does not match the scenario format.
+    #[error("Invalid scenario: {0}")]
+    Parse(String),
+
+    /// A time was not a number with an `s` or `ms` suffix, or a frame
+    /// interval was zero.
+    #[error("Invalid duration: {0}")]
+    InvalidDuration(String),
+
+    /// A notification in the scenario failed to build.
+    #[error("Invalid notification: {0}")]
+    Notification(#[from] NotificationError),
+}
+
+// FILE: src/notifications/types/scenario_error.rs - Scenario loading error type
+// END OF VERSION: 1.0.0
@@ -1,12 +1,16 @@
 // FILE: src/notifications/types/size_constraint.rs - Size constraint enum
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.2.0
+// WCTX: Percentage rounding rule
+// CLOG: Added SizeConstraint::resolve with round-half-up percentages
+
+use crate::shared_utils::math::{clamp_progress, round_to_u16};
 
 /// Constraint on notification dimensions.
 ///
 /// Allows specifying sizes as absolute values or percentages of available space.
+/// See [`SizeConstraint::resolve`] for how percentages become cells.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SizeConstraint {
     /// Absolute size in terminal cells/characters.
     Absolute(u16),
@@ -15,5 +19,40 @@ pub enum SizeConstraint {
     Percentage(f32),
 }
 
+impl SizeConstraint {
+    /// Resolves the constraint to a length in cells.
+    ///
+    /// Every percentage in the crate goes through here, so a notification
+    /// and its stacked siblings always agree on their size. The rule:
+    ///
+    /// - `Absolute(n)` is capped at `available`.
+    /// - `Percentage(p)` clamps `p` to `0.0..=1.0` (NaN as `0.0`), multiplies
+    ///   it by `available` in `f32`, and rounds half up; the result is at
+    ///   least one cell.
+    ///
+    /// # Arguments
+    /// * `available` - Frame width or height
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_notifications::notifications::SizeConstraint;
+    ///
+    /// // 0.35 * 121 = 42.35
+    /// assert_eq!(SizeConstraint::Percentage(0.35).resolve(121), 42);
+    /// // 0.5 * 79 = 39.5 rounds up
+    /// assert_eq!(SizeConstraint::Percentage(0.5).resolve(79), 40);
+    /// assert_eq!(SizeConstraint::Absolute(200).resolve(80), 80);
+    /// ```
+    pub fn resolve(self, available: u16) -> u16 {
+        match self {
+            SizeConstraint::Absolute(n) => n.min(available),
+            SizeConstraint::Percentage(p) => {
+                round_to_u16(f32::from(available) * clamp_progress(p)).max(1)
+            }
+        }
+    }
+}
+
 // FILE: src/notifications/types/size_constraint.rs - Size constraint enum
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.2.0
@@ -1,12 +1,13 @@
 // FILE: src/notifications/types/slide_direction.rs - Slide direction enum
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.1.0
+// WCTX: Save and restore manager state
+// CLOG: Optional serde derive
 
 /// Direction from which a notification slides in.
 ///
 /// Used with the `Slide` animation type to control the entry direction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum SlideDirection {
     /// Auto-select direction based on anchor point (default).
@@ -42,4 +43,4 @@ pub enum SlideDirection {
 }
 
 // FILE: src/notifications/types/slide_direction.rs - Slide direction enum
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.1.0
@@ -0,0 +1,22 @@
+// FILE: src/notifications/types/sort_by.rs - Render draw-order enum
+// VERSION: 1.0.0
+// WCTX: Severity-ordered rendering across anchors
+// CLOG: Initial creation
+
+/// Order in which notifications are drawn across all anchors.
+///
+/// Later draws land on top, so this decides which notification wins where
+/// notifications from different anchors overlap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SortBy {
+    /// Oldest first, so newer notifications are drawn on top (default).
+    #[default]
+    Age,
+
+    /// Lowest severity first, so errors are drawn above warnings above
+    /// infos. Ties are broken by age.
+    Level,
+}
+
+// FILE: src/notifications/types/sort_by.rs - Render draw-order enum
+// END OF VERSION: 1.0.0
@@ -0,0 +1,25 @@
+// FILE: src/notifications/types/stack_layout.rs - Direction notifications at one anchor stack in
+// VERSION: 1.0.0
+// WCTX: Horizontal stacking
+// CLOG: Initial creation
+
+/// How notifications sharing an anchor are laid out relative to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StackLayout {
+    /// One above the other, growing away from the anchor's top or bottom
+    /// edge (default).
+    #[default]
+    Vertical,
+
+    /// Side by side; the oldest sits at the anchor and newer ones are
+    /// placed to its right.
+    LeftToRight,
+
+    /// Side by side; the oldest sits at the anchor and newer ones are
+    /// placed to its left. Suits right-hand anchors such as `BottomRight`.
+    RightToLeft,
+}
+
+// FILE: src/notifications/types/stack_layout.rs - Direction notifications at one anchor stack in
+// END OF VERSION: 1.0.0
@@ -0,0 +1,17 @@
+// FILE: src/notifications/types/state_update.rs - Result of advancing one notification state
+// VERSION: 1.0.0
+// WCTX: Public single-state stepping
+// CLOG: Initial creation
+
+/// What changed when a `NotificationState` was advanced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StateUpdate {
+    /// The animation phase differs from before the update.
+    pub phase_changed: bool,
+
+    /// The notification reached `AnimationPhase::Finished` and can be removed.
+    pub finished: bool,
+}
+
+// FILE: src/notifications/types/state_update.rs - Result of advancing one notification state
+// END OF VERSION: 1.0.0
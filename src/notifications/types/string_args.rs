@@ -0,0 +1,43 @@
+// FILE: src/notifications/types/string_args.rs - Named arguments for built-in strings
+// VERSION: 1.0.0
+// WCTX: Localizable built-in strings
+// CLOG: Initial creation
+
+/// Named arguments supplied when formatting a built-in string.
+///
+/// # Example
+///
+/// ```
+/// use ratatui_notifications::notifications::types::StringArgs;
+///
+/// let args = StringArgs::new(&[("n", "3")]);
+/// assert_eq!(args.get("n"), Some("3"));
+/// assert_eq!(args.get("missing"), None);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct StringArgs<'a> {
+    pairs: &'a [(&'a str, &'a str)],
+}
+
+impl<'a> StringArgs<'a> {
+    /// Wraps `(name, value)` pairs.
+    pub fn new(pairs: &'a [(&'a str, &'a str)]) -> Self {
+        Self { pairs }
+    }
+
+    /// Returns the value of an argument, if supplied.
+    pub fn get(&self, name: &str) -> Option<&'a str> {
+        self.pairs
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| *value)
+    }
+
+    /// Returns all `(name, value)` pairs.
+    pub fn pairs(&self) -> &'a [(&'a str, &'a str)] {
+        self.pairs
+    }
+}
+
+// FILE: src/notifications/types/string_args.rs - Named arguments for built-in strings
+// END OF VERSION: 1.0.0
@@ -0,0 +1,68 @@
+// FILE: src/notifications/types/string_key.rs - Identifiers for user-visible built-in strings
+// VERSION: 1.5.0
+// WCTX: Suppressed notifications
+// CLOG: Added Delayed key
+
+use crate::notifications::types::{Anchor, Level};
+
+/// Identifies a user-visible string the crate injects.
+///
+/// Passed to the resolver given to `Strings::from_fn`, together with the
+/// string's arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StringKey {
+    /// Name of a level in sentences (e.g. "Warning").
+    Level(Level),
+
+    /// Spoken name of an anchor (e.g. "top right").
+    Anchor(Anchor),
+
+    /// Opening of a `describe()` sentence for a levelled notification.
+    /// Args: `level`, `anchor`.
+    DescribeHeading,
+
+    /// Opening of a `describe()` sentence for a notification without a level.
+    /// Args: `anchor`.
+    DescribeHeadingNoLevel,
+
+    /// Countdown sentence in `describe()`. Args: `n` (whole seconds).
+    DismissesIn,
+
+    /// Sentence in `describe()` for a notification that is leaving.
+    Dismissing,
+
+    /// Theme title prefix for a level. Args: `prefix` (the theme's prefix).
+    TitlePrefix(Level),
+
+    /// Suffix on a notification merged by `Overflow::MergeDuplicates`.
+    /// Args: `n` (number of merged notifications).
+    MergeCount,
+
+    /// Suffix on a notification held back by `Notifications::suppress`, with
+    /// `annotate_delayed` on. Args: `n` (whole seconds it waited).
+    Delayed,
+
+    /// Seconds left before auto-dismiss, shown by `show_countdown`.
+    /// Args: `n` (whole seconds, rounded up).
+    Countdown,
+
+    /// Page indicator in the bottom border of a notification with pages.
+    /// Args: `page` (from 1), `count`.
+    PageIndicator,
+
+    /// Relative timestamp under a minute in the notification center.
+    /// Args: `n` (whole seconds).
+    AgeSeconds,
+
+    /// Relative timestamp under an hour in the notification center.
+    /// Args: `n` (whole minutes).
+    AgeMinutes,
+
+    /// Relative timestamp of an hour or more in the notification center.
+    /// Args: `n` (whole hours).
+    AgeHours,
+}
+
+// FILE: src/notifications/types/string_key.rs - Identifiers for user-visible built-in strings
+// END OF VERSION: 1.5.0
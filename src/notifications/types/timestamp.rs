@@ -0,0 +1,31 @@
+// FILE: src/notifications/types/timestamp.rs - Timestamp type used for notification ordering
+// VERSION: 1.1.0
+// WCTX: Web/wasm clock support
+// CLOG: Timestamp is instant::Instant under the wasm feature
+
+/// Point in time used to order notifications.
+///
+/// With the `std` feature (default) this is [`std::time::Instant`], produced by a
+/// [`Clock`](crate::notifications::traits::Clock). With the `wasm` feature it is
+/// `instant::Instant`, which is the same type as `std::time::Instant` on native
+/// targets and a `performance.now()` reading on `wasm32-unknown-unknown`.
+#[cfg(all(feature = "std", not(feature = "wasm")))]
+pub type Timestamp = std::time::Instant;
+
+/// Point in time used to order notifications.
+///
+/// With the `wasm` feature this is `instant::Instant`, which is the same type
+/// as `std::time::Instant` on native targets and a `performance.now()` reading
+/// on `wasm32-unknown-unknown`.
+#[cfg(feature = "wasm")]
+pub type Timestamp = instant::Instant;
+
+/// Point in time used to order notifications.
+///
+/// Without `std` the layout core has no access to a monotonic clock, so
+/// timestamps are plain offsets from an arbitrary epoch chosen by the caller.
+#[cfg(not(feature = "std"))]
+pub type Timestamp = core::time::Duration;
+
+// FILE: src/notifications/types/timestamp.rs - Timestamp type used for notification ordering
+// END OF VERSION: 1.1.0
@@ -1,17 +1,21 @@
 // FILE: src/notifications/types/timing.rs - Animation timing enum
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.3.0
+// WCTX: Non-finite progress guards
+// CLOG: Document extreme fixed durations
 
-use std::time::Duration;
+use core::time::Duration;
 
 /// Animation duration specification.
 ///
 /// Controls whether animation durations are explicitly specified or
 /// automatically calculated based on content or system defaults.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Timing {
     /// Fixed duration specified by user.
+    ///
+    /// Extreme values are safe: a `Duration::MAX` phase never completes at
+    /// frame-rate ticks and its progress stays finite near 0.0.
     Fixed(Duration),
 
     /// Automatically calculated duration.
@@ -23,4 +27,4 @@ pub enum Timing {
 }
 
 // FILE: src/notifications/types/timing.rs - Animation timing enum
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.3.0
@@ -0,0 +1,25 @@
+// FILE: src/notifications/types/truncate_mode.rs - Content truncation mode enum
+// VERSION: 1.0.0
+// WCTX: Content truncation
+// CLOG: Initial creation
+
+/// Where a content line too wide for the box is cut.
+///
+/// The removed part is replaced with the notification's ellipsis. Without a
+/// truncation mode long lines wrap instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TruncateMode {
+    /// Keeps the end of the line: `…/src/main.rs`.
+    Start,
+
+    /// Keeps both ends of the line: `/home/…/main.rs`. Suits file paths.
+    Middle,
+
+    /// Keeps the start of the line: `/home/user/…` (default).
+    #[default]
+    End,
+}
+
+// FILE: src/notifications/types/truncate_mode.rs - Content truncation mode enum
+// END OF VERSION: 1.0.0
@@ -0,0 +1,25 @@
+// FILE: src/notifications/types/vertical_align.rs - Vertical content alignment enum
+// VERSION: 1.1.0
+// WCTX: Save and restore manager state
+// CLOG: Optional serde derive
+
+/// Vertical placement of content inside a box taller than the content.
+///
+/// Only visible when the box has spare rows, e.g. when `min_size` makes it
+/// taller than its content needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VerticalAlign {
+    /// Content starts on the first inner row (default).
+    #[default]
+    Top,
+
+    /// Content is centered; an odd spare row goes below it.
+    Center,
+
+    /// Content ends on the last inner row.
+    Bottom,
+}
+
+// FILE: src/notifications/types/vertical_align.rs - Vertical content alignment enum
+// END OF VERSION: 1.1.0
@@ -0,0 +1,64 @@
+// FILE: src/shared_utils/math/fnc_ansi256_to_rgb.rs - ANSI 256-color palette index to RGB
+// VERSION: 1.0.0
+// WCTX: Color output degradation for limited terminals
+// CLOG: Initial creation
+
+/// Levels used by each axis of the xterm 6x6x6 color cube (indices 16-231).
+pub(crate) const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// RGB values of the 16 base colors (indices 0-15) as rendered by xterm.
+const BASE_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Converts an xterm 256-color palette index to an RGB tuple.
+///
+/// # Arguments
+///
+/// * `index` - Palette index (0-15 base colors, 16-231 color cube, 232-255 grayscale)
+///
+/// # Returns
+///
+/// The (r, g, b) value xterm uses for that index
+///
+/// # Examples
+///
+/// ```ignore
+/// // Internal function
+/// assert_eq!(ansi256_to_rgb(196), (255, 0, 0));
+/// ```
+pub fn ansi256_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => BASE_COLORS[index as usize],
+        16..=231 => {
+            let i = index - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[((i / 6) % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+// FILE: src/shared_utils/math/fnc_ansi256_to_rgb.rs - ANSI 256-color palette index to RGB
+// END OF VERSION: 1.0.0
@@ -0,0 +1,88 @@
+// FILE: src/shared_utils/math/fnc_clamp_progress.rs - NaN-safe progress and cell conversions
+// VERSION: 1.0.0
+// WCTX: Non-finite progress guards
+// CLOG: Initial creation with unit tests
+
+/// Clamps an animation progress into `0.0..=1.0`, treating NaN as `0.0`.
+///
+/// `f32::clamp` passes NaN through, which would then poison every lerp
+/// downstream.
+///
+/// # Arguments
+///
+/// * `progress` - The progress to clamp
+///
+/// # Returns
+///
+/// The progress within `0.0..=1.0`
+///
+/// # Examples
+///
+/// ```ignore
+/// // Internal function
+/// assert_eq!(clamp_progress(f32::NAN), 0.0);
+/// assert_eq!(clamp_progress(f32::INFINITY), 1.0);
+/// ```
+#[inline]
+pub fn clamp_progress(progress: f32) -> f32 {
+    if progress.is_nan() {
+        0.0
+    } else {
+        progress.clamp(0.0, 1.0)
+    }
+}
+
+/// Rounds a cell coordinate or length to `u16`, clamped to its range.
+///
+/// NaN becomes `0`, so a malformed value can never produce a rect at an
+/// absurd position.
+///
+/// # Arguments
+///
+/// * `value` - The coordinate or length
+///
+/// # Returns
+///
+/// The rounded value within `0..=u16::MAX`
+///
+/// # Examples
+///
+/// ```ignore
+/// // Internal function
+/// assert_eq!(round_to_u16(2.5), 3);
+/// assert_eq!(round_to_u16(-4.0), 0);
+/// assert_eq!(round_to_u16(f32::NAN), 0);
+/// ```
+#[inline]
+pub fn round_to_u16(value: f32) -> u16 {
+    if value.is_nan() {
+        0
+    } else {
+        value.round().clamp(0.0, f32::from(u16::MAX)) as u16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clamp_progress_handles_non_finite_values() {
+        assert_eq!(clamp_progress(f32::NAN), 0.0);
+        assert_eq!(clamp_progress(f32::INFINITY), 1.0);
+        assert_eq!(clamp_progress(f32::NEG_INFINITY), 0.0);
+        assert_eq!(clamp_progress(0.25), 0.25);
+    }
+
+    #[test]
+    fn test_round_to_u16_stays_in_range() {
+        assert_eq!(round_to_u16(2.5), 3);
+        assert_eq!(round_to_u16(-4.0), 0);
+        assert_eq!(round_to_u16(1e9), u16::MAX);
+        assert_eq!(round_to_u16(f32::NAN), 0);
+        assert_eq!(round_to_u16(f32::INFINITY), u16::MAX);
+    }
+}
+
+// FILE: src/shared_utils/math/fnc_clamp_progress.rs - NaN-safe progress and cell conversions
+// END OF VERSION: 1.0.0
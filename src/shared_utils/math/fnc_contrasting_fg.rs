@@ -0,0 +1,79 @@
+// FILE: src/shared_utils/math/fnc_contrasting_fg.rs - Readable foreground for a background color
+// VERSION: 1.0.0
+// WCTX: Auto-contrast content
+// CLOG: Initial creation with unit tests
+
+use super::fnc_ansi256_to_rgb::ansi256_to_rgb;
+use super::fnc_color_to_rgb::color_to_rgb;
+use ratatui::style::Color;
+
+/// Relative luminance above which a background counts as light.
+const LIGHT_THRESHOLD: f32 = 0.5;
+
+/// Picks black or white text, whichever reads better on `bg`.
+///
+/// Uses the Rec. 709 luminance of the background. Colors without a known
+/// RGB value (such as `Color::Reset`) return `Color::Reset`, leaving the
+/// terminal's default foreground.
+///
+/// # Arguments
+///
+/// * `bg` - The background color
+///
+/// # Returns
+///
+/// `Color::Black` for light backgrounds, `Color::White` for dark ones
+///
+/// # Examples
+///
+/// ```ignore
+/// // Internal function
+/// use ratatui::style::Color;
+/// assert_eq!(contrasting_fg(Color::Yellow), Color::Black);
+/// assert_eq!(contrasting_fg(Color::Blue), Color::White);
+/// ```
+pub fn contrasting_fg(bg: Color) -> Color {
+    let rgb = match bg {
+        Color::Indexed(index) => Some(ansi256_to_rgb(index)),
+        other => color_to_rgb(Some(other)),
+    };
+    let Some((r, g, b)) = rgb else {
+        return Color::Reset;
+    };
+
+    let luminance = (0.2126 * r as f32 + 0.7152 * g as f32 + 0.0722 * b as f32) / 255.0;
+    if luminance > LIGHT_THRESHOLD {
+        Color::Black
+    } else {
+        Color::White
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_light_backgrounds_get_dark_text() {
+        assert_eq!(contrasting_fg(Color::White), Color::Black);
+        assert_eq!(contrasting_fg(Color::Yellow), Color::Black);
+        assert_eq!(contrasting_fg(Color::Rgb(240, 230, 200)), Color::Black);
+        assert_eq!(contrasting_fg(Color::Indexed(231)), Color::Black);
+    }
+
+    #[test]
+    fn test_dark_backgrounds_get_light_text() {
+        assert_eq!(contrasting_fg(Color::Black), Color::White);
+        assert_eq!(contrasting_fg(Color::Blue), Color::White);
+        assert_eq!(contrasting_fg(Color::Rgb(30, 30, 60)), Color::White);
+        assert_eq!(contrasting_fg(Color::Indexed(16)), Color::White);
+    }
+
+    #[test]
+    fn test_unknown_background_keeps_default_text() {
+        assert_eq!(contrasting_fg(Color::Reset), Color::Reset);
+    }
+}
+
+// FILE: src/shared_utils/math/fnc_contrasting_fg.rs - Readable foreground for a background color
+// END OF VERSION: 1.0.0
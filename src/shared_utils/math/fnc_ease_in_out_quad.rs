@@ -0,0 +1,39 @@
+// FILE: src/shared_utils/math/fnc_ease_in_out_quad.rs - Quadratic ease-in-out easing function
+// VERSION: 1.0.0
+// WCTX: Public easing module
+// CLOG: Initial creation
+
+/// Applies quadratic ease-in-out easing to a linear progress value.
+///
+/// The ease-in-out function accelerates through the first half and
+/// decelerates through the second.
+///
+/// # Arguments
+///
+/// * `t` - The linear progress value; clamped to `0.0..=1.0`
+///
+/// # Returns
+///
+/// The eased progress value, in `0.0..=1.0`
+///
+/// # Examples
+///
+/// ```
+/// use ratatui_notifications::easing::ease_in_out_quad;
+///
+/// assert_eq!(ease_in_out_quad(0.25), 0.125);
+/// assert_eq!(ease_in_out_quad(0.5), 0.5);
+/// assert_eq!(ease_in_out_quad(-1.0), 0.0);
+/// ```
+#[inline]
+pub fn ease_in_out_quad(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    if t < 0.5 {
+        2.0 * t * t
+    } else {
+        1.0 - 2.0 * (1.0 - t) * (1.0 - t)
+    }
+}
+
+// FILE: src/shared_utils/math/fnc_ease_in_out_quad.rs - Quadratic ease-in-out easing function
+// END OF VERSION: 1.0.0
@@ -1,7 +1,7 @@
 // FILE: src/shared_utils/math/fnc_ease_in_quad.rs - Quadratic ease-in easing function
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Extracted from fade.rs
+// VERSION: 1.1.0
+// WCTX: Public easing module
+// CLOG: Clamp t and enable doctests
 
 /// Applies quadratic ease-in easing to a linear progress value.
 ///
@@ -9,23 +9,27 @@
 ///
 /// # Arguments
 ///
-/// * `t` - The linear progress value (typically 0.0 to 1.0)
+/// * `t` - The linear progress value; clamped to `0.0..=1.0`
 ///
 /// # Returns
 ///
-/// The eased progress value
+/// The eased progress value, in `0.0..=1.0`
 ///
 /// # Examples
 ///
-/// ```ignore
-/// // Internal function
-/// let result = ease_in_quad(0.5);
-/// assert_eq!(result, 0.25);
+/// ```
+/// use ratatui_notifications::easing::ease_in_quad;
+///
+/// assert_eq!(ease_in_quad(0.5), 0.25);
+/// // Out-of-range progress clamps to the ends
+/// assert_eq!(ease_in_quad(-0.5), 0.0);
+/// assert_eq!(ease_in_quad(1.5), 1.0);
 /// ```
 #[inline]
 pub fn ease_in_quad(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
     t * t
 }
 
 // FILE: src/shared_utils/math/fnc_ease_in_quad.rs - Quadratic ease-in easing function
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.1.0
@@ -1,7 +1,7 @@
 // FILE: src/shared_utils/math/fnc_ease_out_quad.rs - Quadratic ease-out easing function
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Extracted from fade.rs
+// VERSION: 1.1.0
+// WCTX: Public easing module
+// CLOG: Clamp t and enable doctests
 
 /// Applies quadratic ease-out easing to a linear progress value.
 ///
@@ -9,23 +9,27 @@
 ///
 /// # Arguments
 ///
-/// * `t` - The linear progress value (typically 0.0 to 1.0)
+/// * `t` - The linear progress value; clamped to `0.0..=1.0`
 ///
 /// # Returns
 ///
-/// The eased progress value
+/// The eased progress value, in `0.0..=1.0`
 ///
 /// # Examples
 ///
-/// ```ignore
-/// // Internal function
-/// let result = ease_out_quad(0.5);
-/// assert_eq!(result, 0.75);
+/// ```
+/// use ratatui_notifications::easing::ease_out_quad;
+///
+/// assert_eq!(ease_out_quad(0.5), 0.75);
+/// // Out-of-range progress clamps to the ends
+/// assert_eq!(ease_out_quad(-0.5), 0.0);
+/// assert_eq!(ease_out_quad(1.5), 1.0);
 /// ```
 #[inline]
 pub fn ease_out_quad(t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
     t * (2.0 - t)
 }
 
 // FILE: src/shared_utils/math/fnc_ease_out_quad.rs - Quadratic ease-out easing function
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.1.0
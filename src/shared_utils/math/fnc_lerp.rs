@@ -1,7 +1,7 @@
 // FILE: src/shared_utils/math/fnc_lerp.rs - Linear interpolation function
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Extracted from multiple animation files (slide.rs, expand_collapse.rs, fade.rs)
+// VERSION: 1.1.0
+// WCTX: Public easing module
+// CLOG: Clamp t and enable doctests
 
 /// Performs linear interpolation between two values.
 ///
@@ -9,23 +9,26 @@
 ///
 /// * `start` - The starting value
 /// * `end` - The ending value
-/// * `t` - The interpolation parameter (typically 0.0 to 1.0)
+/// * `t` - The interpolation parameter; clamped to `0.0..=1.0`
 ///
 /// # Returns
 ///
-/// The interpolated value at parameter `t`
+/// The interpolated value at parameter `t`, never beyond `start` or `end`
 ///
 /// # Examples
 ///
-/// ```ignore
-/// // Internal function
-/// let result = lerp(0.0, 10.0, 0.5);
-/// assert_eq!(result, 5.0);
+/// ```
+/// use ratatui_notifications::easing::lerp;
+///
+/// assert_eq!(lerp(0.0, 10.0, 0.5), 5.0);
+/// // Out-of-range parameters clamp rather than extrapolate
+/// assert_eq!(lerp(0.0, 10.0, -0.5), 0.0);
+/// assert_eq!(lerp(0.0, 10.0, 1.5), 10.0);
 /// ```
 #[inline]
 pub fn lerp(start: f32, end: f32, t: f32) -> f32 {
-    start + t * (end - start)
+    start + t.clamp(0.0, 1.0) * (end - start)
 }
 
 // FILE: src/shared_utils/math/fnc_lerp.rs - Linear interpolation function
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.1.0
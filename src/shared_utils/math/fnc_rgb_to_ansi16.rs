@@ -0,0 +1,80 @@
+// FILE: src/shared_utils/math/fnc_rgb_to_ansi16.rs - Map RGB to the nearest named ANSI color
+// VERSION: 1.0.0
+// WCTX: Color output degradation for limited terminals
+// CLOG: Initial creation with unit tests
+
+use super::fnc_color_to_rgb::color_to_rgb;
+use super::fnc_rgb_to_ansi256::distance_sq;
+use ratatui::style::Color;
+
+/// The 16 named colors a basic terminal can display.
+const NAMED_COLORS: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+/// Maps an RGB color to the nearest of the 16 named ANSI colors.
+///
+/// Distance is measured against the reference values used by `color_to_rgb`.
+///
+/// # Arguments
+///
+/// * `r`, `g`, `b` - The color channels
+///
+/// # Returns
+///
+/// The closest named `Color`
+///
+/// # Examples
+///
+/// ```ignore
+/// // Internal function
+/// assert_eq!(rgb_to_ansi16(250, 10, 10), Color::Red);
+/// ```
+pub fn rgb_to_ansi16(r: u8, g: u8, b: u8) -> Color {
+    NAMED_COLORS
+        .iter()
+        .copied()
+        .min_by_key(|named| {
+            color_to_rgb(Some(*named)).map_or(u32::MAX, |rgb| distance_sq((r, g, b), rgb))
+        })
+        .unwrap_or(Color::White)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_named_values_round_trip() {
+        for named in NAMED_COLORS {
+            let (r, g, b) = color_to_rgb(Some(named)).unwrap();
+            assert_eq!(rgb_to_ansi16(r, g, b), named);
+        }
+    }
+
+    #[test]
+    fn test_near_colors_map_to_named() {
+        assert_eq!(rgb_to_ansi16(250, 10, 10), Color::Red);
+        assert_eq!(rgb_to_ansi16(10, 10, 10), Color::Black);
+        assert_eq!(rgb_to_ansi16(240, 240, 250), Color::White);
+        assert_eq!(rgb_to_ansi16(70, 60, 70), Color::DarkGray);
+    }
+}
+
+// FILE: src/shared_utils/math/fnc_rgb_to_ansi16.rs - Map RGB to the nearest named ANSI color
+// END OF VERSION: 1.0.0
@@ -0,0 +1,84 @@
+// FILE: src/shared_utils/math/fnc_rgb_to_ansi256.rs - Quantize RGB to the ANSI 256-color palette
+// VERSION: 1.0.0
+// WCTX: Color output degradation for limited terminals
+// CLOG: Initial creation with unit tests
+
+use super::fnc_ansi256_to_rgb::{ansi256_to_rgb, CUBE_LEVELS};
+
+/// Quantizes an RGB color to the closest xterm 256-color palette index.
+///
+/// Considers both the 6x6x6 color cube (16-231) and the grayscale ramp
+/// (232-255) and returns whichever is nearer. The 16 base colors are skipped
+/// because terminals theme them freely.
+///
+/// # Arguments
+///
+/// * `r`, `g`, `b` - The color channels
+///
+/// # Returns
+///
+/// A palette index in the range 16-255
+///
+/// # Examples
+///
+/// ```ignore
+/// // Internal function
+/// assert_eq!(rgb_to_ansi256(255, 0, 0), 196);
+/// ```
+pub fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let cube_index = |c: u8| -> u8 {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, level)| (c as i32 - **level as i32).abs())
+            .map_or(0, |(i, _)| i as u8)
+    };
+    let cube = 16 + 36 * cube_index(r) + 6 * cube_index(g) + cube_index(b);
+
+    let average = (r as u16 + g as u16 + b as u16) / 3;
+    let gray_step = (average.saturating_sub(8) / 10).min(23) as u8;
+    let gray = 232 + gray_step;
+
+    if distance_sq((r, g, b), ansi256_to_rgb(gray)) < distance_sq((r, g, b), ansi256_to_rgb(cube)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+/// Squared Euclidean distance between two RGB colors.
+pub(crate) fn distance_sq(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let dr = a.0 as i32 - b.0 as i32;
+    let dg = a.1 as i32 - b.1 as i32;
+    let db = a.2 as i32 - b.2 as i32;
+    (dr * dr + dg * dg + db * db) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primary_colors_map_to_cube_corners() {
+        assert_eq!(rgb_to_ansi256(255, 0, 0), 196);
+        assert_eq!(rgb_to_ansi256(0, 255, 0), 46);
+        assert_eq!(rgb_to_ansi256(0, 0, 255), 21);
+        assert_eq!(rgb_to_ansi256(255, 255, 255), 231);
+        assert_eq!(rgb_to_ansi256(0, 0, 0), 16);
+    }
+
+    #[test]
+    fn test_mid_gray_prefers_grayscale_ramp() {
+        assert_eq!(rgb_to_ansi256(128, 128, 128), 244);
+        assert_eq!(rgb_to_ansi256(18, 18, 18), 233);
+    }
+
+    #[test]
+    fn test_off_cube_color_rounds_to_nearest_level() {
+        // 100 -> 95 (level 1), 140 -> 135 (level 2), 210 -> 215 (level 4)
+        assert_eq!(rgb_to_ansi256(100, 140, 210), 16 + 36 + 12 + 4);
+    }
+}
+
+// FILE: src/shared_utils/math/fnc_rgb_to_ansi256.rs - Quantize RGB to the ANSI 256-color palette
+// END OF VERSION: 1.0.0
@@ -1,17 +1,29 @@
 // FILE: src/shared_utils/math/mod.rs - Mathematical utility functions
-// VERSION: 1.0.0
-// WCTX: OFPF migration
-// CLOG: Initial creation
+// VERSION: 1.4.0
+// WCTX: Non-finite progress guards
+// CLOG: Added clamp_progress and round_to_u16
 
 mod fnc_lerp;
 mod fnc_ease_in_quad;
 mod fnc_ease_out_quad;
+mod fnc_ease_in_out_quad;
 mod fnc_color_to_rgb;
+mod fnc_ansi256_to_rgb;
+mod fnc_rgb_to_ansi256;
+mod fnc_rgb_to_ansi16;
+mod fnc_contrasting_fg;
+mod fnc_clamp_progress;
 
 pub use fnc_lerp::lerp;
 pub use fnc_ease_in_quad::ease_in_quad;
 pub use fnc_ease_out_quad::ease_out_quad;
+pub use fnc_ease_in_out_quad::ease_in_out_quad;
 pub use fnc_color_to_rgb::color_to_rgb;
+pub use fnc_ansi256_to_rgb::ansi256_to_rgb;
+pub use fnc_rgb_to_ansi256::rgb_to_ansi256;
+pub use fnc_rgb_to_ansi16::rgb_to_ansi16;
+pub use fnc_contrasting_fg::contrasting_fg;
+pub use fnc_clamp_progress::{clamp_progress, round_to_u16};
 
 // FILE: src/shared_utils/math/mod.rs - Mathematical utility functions
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.4.0
@@ -0,0 +1,29 @@
+// FILE: src/styles/fnc_for_level.rs - Styles a theme gives a notification level
+// VERSION: 1.0.0
+// WCTX: Public level styles
+// CLOG: Initial creation
+
+use crate::notifications::functions::fnc_resolve_styles::resolve_themed_styles;
+use crate::notifications::types::{Level, ResolvedStyles};
+use crate::notifications::Theme;
+
+/// Returns the styles notifications of a level are drawn with.
+///
+/// The renderer resolves exactly these styles for every notification, then
+/// applies the notification's own `block_style`, `border_style` and
+/// `title_style` if it set any. Fades and the manager's color mode are
+/// applied after that.
+///
+/// # Arguments
+/// * `level` - The notification level (`None` for notifications without one)
+/// * `theme` - The theme the manager renders with
+///
+/// # Returns
+/// Block, border and title styles, the theme's border type and title
+/// prefix, and the level's icon
+pub fn for_level(level: Option<Level>, theme: &Theme) -> ResolvedStyles {
+    resolve_themed_styles(level, theme, None, None, None)
+}
+
+// FILE: src/styles/fnc_for_level.rs - Styles a theme gives a notification level
+// END OF VERSION: 1.0.0
@@ -0,0 +1,26 @@
+// FILE: src/styles/mod.rs - Public level styling lookups
+// VERSION: 1.0.0
+// WCTX: Public level styles
+// CLOG: Initial creation
+
+//! The styles notifications are drawn with, for matching them elsewhere.
+//!
+//! [`for_level`] is what the renderer resolves before applying a
+//! notification's own style overrides, so a badge colored from it always
+//! matches the notifications of that level.
+//!
+//! ```no_run
+//! use ratatui_notifications::styles::for_level;
+//! use ratatui_notifications::{Level, Theme};
+//!
+//! let styles = for_level(Some(Level::Error), &Theme::default());
+//! let badge_color = styles.border.fg;
+//! ```
+
+mod fnc_for_level;
+
+pub use crate::notifications::ResolvedStyles;
+pub use fnc_for_level::for_level;
+
+// FILE: src/styles/mod.rs - Public level styling lookups
+// END OF VERSION: 1.0.0
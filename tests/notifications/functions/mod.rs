@@ -1,15 +1,14 @@
 // FILE: tests/notifications/functions/mod.rs - Test module declarations for notification functions
-// VERSION: 1.1.0
-// WCTX: TDD implementation of OFPF notification functions
-// CLOG: Added test_fnc_update_states module
+// VERSION: 1.2.0
+// WCTX: Public single-state stepping
+// CLOG: Removed update_states tests (ported to tests/test_fnc_update_states_integration.rs)
 
 mod test_fnc_calculate_anchor_position;
 mod test_fnc_fade_interpolate_color;
 mod test_fnc_resolve_styles;
 mod test_fnc_slide_resolve_direction;
-mod test_fnc_update_states;
 
 // Note: test_fnc_generate_code is in tests/test_fnc_generate_code_integration.rs
 
 // FILE: tests/notifications/functions/mod.rs - Test module declarations for notification functions
-// END OF VERSION: 1.1.0
+// END OF VERSION: 1.2.0
@@ -0,0 +1,85 @@
+// FILE: tests/test_add_hidden_integration.rs - Integration tests for manually shown notifications
+// VERSION: 1.0.0
+// WCTX: Manual show
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    AnimationPhase, NotificationBuilder, Notifications, Overflow,
+};
+use std::time::Duration;
+
+fn add_hidden(manager: &mut Notifications, content: &'static str) -> u64 {
+    manager
+        .add_hidden(NotificationBuilder::new(content).build().unwrap())
+        .unwrap()
+}
+
+fn drawn_text(manager: &mut Notifications) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(60, 20)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let buffer = terminal.backend().buffer();
+    buffer.content().iter().map(|cell| cell.symbol()).collect()
+}
+
+#[test]
+fn test_hidden_notification_stays_pending_across_ticks() {
+    let mut manager = Notifications::new();
+    let id = add_hidden(&mut manager, "Later");
+
+    for _ in 0..10 {
+        manager.tick(Duration::from_secs(1));
+    }
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Pending));
+    assert!(!drawn_text(&mut manager).contains("Later"));
+}
+
+#[test]
+fn test_show_starts_entry_animation() {
+    let mut manager = Notifications::new();
+    let id = add_hidden(&mut manager, "Later");
+    manager.tick(Duration::from_secs(10));
+
+    assert!(manager.show(id));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Pending));
+
+    manager.tick(Duration::from_millis(16));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingIn));
+
+    // The display countdown only starts once shown
+    manager.tick(Duration::from_millis(600));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    assert!(drawn_text(&mut manager).contains("Later"));
+}
+
+#[test]
+fn test_show_returns_false_unless_hidden() {
+    let mut manager = Notifications::new();
+    let visible = manager
+        .add(NotificationBuilder::new("Now").build().unwrap())
+        .unwrap();
+    let hidden = add_hidden(&mut manager, "Later");
+
+    assert!(!manager.show(visible));
+    assert!(!manager.show(999));
+    assert!(manager.show(hidden));
+    assert!(!manager.show(hidden));
+}
+
+#[test]
+fn test_hidden_notification_does_not_absorb_duplicates() {
+    let mut manager = Notifications::new().overflow(Overflow::MergeDuplicates);
+    let hidden = add_hidden(&mut manager, "Same");
+    let visible = manager
+        .add(NotificationBuilder::new("Same").build().unwrap())
+        .unwrap();
+
+    assert_ne!(hidden, visible);
+    manager.tick(Duration::from_millis(16));
+    assert_eq!(manager.phase(hidden), Some(AnimationPhase::Pending));
+    assert_eq!(manager.phase(visible), Some(AnimationPhase::SlidingIn));
+}
+
+// FILE: tests/test_add_hidden_integration.rs - Integration tests for manually shown notifications
+// END OF VERSION: 1.0.0
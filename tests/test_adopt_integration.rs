@@ -0,0 +1,137 @@
+// FILE: tests/test_adopt_integration.rs - Integration tests for adopting externally built states
+// VERSION: 1.0.0
+// WCTX: Adopting externally built states
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::traits::{Clock, SystemClock};
+use ratatui_notifications::notifications::{
+    Anchor, Animation, AnimationPhase, AutoDismiss, NotificationBuilder, NotificationError,
+    NotificationState, Notifications, Timing,
+};
+use std::time::Duration;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+/// 400ms exit sliding out from the top left
+fn replayed() -> NotificationBuilder {
+    NotificationBuilder::new("Replayed")
+        .anchor(Anchor::TopLeft)
+        .timing(Timing::Fixed(ms(400)), Timing::Auto, Timing::Fixed(ms(400)))
+}
+
+fn screen(manager: &mut Notifications) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(40, 6)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..6)
+        .map(|y| (0..40).map(|x| buffer[(x, y)].symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn test_adopted_exit_finishes_and_is_reaped() {
+    let state = NotificationState::builder(replayed().build().unwrap())
+        .phase(AnimationPhase::SlidingOut)
+        .progress(0.5)
+        .build()
+        .unwrap();
+    let mut manager = Notifications::new();
+    let id = manager.adopt(state).unwrap();
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+    let rows = screen(&mut manager);
+    assert!(!rows.trim().is_empty(), "partly slid out, still drawn");
+
+    // Half of the 400ms exit is left
+    manager.tick(ms(199));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+    manager.tick(ms(1));
+    assert_eq!(manager.phase(id), None);
+    assert!(!manager.has_notification());
+}
+
+#[test]
+fn test_adopted_state_matches_organic_rendering() {
+    let mut organic = Notifications::new();
+    let organic_id = organic.add(replayed().build().unwrap()).unwrap();
+    organic.tick(ms(400));
+    assert_eq!(organic.phase(organic_id), Some(AnimationPhase::Dwelling));
+
+    let state = NotificationState::builder(replayed().build().unwrap())
+        .phase(AnimationPhase::Dwelling)
+        .build()
+        .unwrap();
+    let mut adopted = Notifications::new();
+    adopted.adopt(state).unwrap();
+
+    assert_eq!(screen(&mut adopted), screen(&mut organic));
+}
+
+#[test]
+fn test_remaining_display_time_drives_the_exit() {
+    let notification = replayed().auto_dismiss(AutoDismiss::After(ms(5000))).build().unwrap();
+    let state = NotificationState::builder(notification)
+        .phase(AnimationPhase::Dwelling)
+        .remaining_display_time(Some(ms(100)))
+        .build()
+        .unwrap();
+    let mut manager = Notifications::new();
+    let id = manager.adopt(state).unwrap();
+
+    manager.tick(ms(99));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    manager.tick(ms(1));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+}
+
+#[test]
+fn test_adopt_assigns_fresh_ids() {
+    let mut manager = Notifications::new();
+    let added = manager.add(replayed().build().unwrap()).unwrap();
+    let state = NotificationState::builder(replayed().build().unwrap())
+        .created_at(SystemClock.now())
+        .build()
+        .unwrap();
+
+    let adopted = manager.adopt(state).unwrap();
+    assert!(adopted > added);
+    assert_eq!(manager.peek_next_id(), Some(adopted + 1));
+    let reused = manager.add_with_id(adopted, replayed().build().unwrap());
+    assert_eq!(reused, Err(NotificationError::IdAlreadyUsed(adopted)));
+}
+
+#[test]
+fn test_adopt_skips_limits() {
+    let mut manager = Notifications::new().max_concurrent(Some(1));
+    let first = manager.add(replayed().build().unwrap()).unwrap();
+    let state = NotificationState::builder(replayed().build().unwrap()).build().unwrap();
+    let second = manager.adopt(state).unwrap();
+
+    assert!(manager.phase(first).is_some());
+    assert!(manager.phase(second).is_some());
+}
+
+#[test]
+fn test_phase_outside_the_animation_is_rejected() {
+    let notification = replayed().animation(Animation::Fade).build().unwrap();
+    let result = NotificationState::builder(notification).phase(AnimationPhase::SlidingOut).build();
+    assert!(matches!(result, Err(NotificationError::InvalidConfig(_))));
+}
+
+#[test]
+fn test_progress_out_of_range_is_rejected() {
+    for progress in [-0.1, 1.5, f32::NAN] {
+        let result = NotificationState::builder(replayed().build().unwrap())
+            .phase(AnimationPhase::SlidingIn)
+            .progress(progress)
+            .build();
+        assert!(matches!(result, Err(NotificationError::InvalidConfig(_))), "{progress}");
+    }
+}
+
+// FILE: tests/test_adopt_integration.rs - Integration tests for adopting externally built states
+// END OF VERSION: 1.0.0
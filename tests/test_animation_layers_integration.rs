@@ -0,0 +1,147 @@
+// FILE: tests/test_animation_layers_integration.rs - Integration tests for composed animation layers
+// VERSION: 1.0.0
+// WCTX: Composed animation layers
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::layout::{Position, Rect};
+use ratatui::Terminal;
+use ratatui_notifications::generate_code;
+use ratatui_notifications::notifications::functions::fnc_layer_transform_rect::layer_transform_rect;
+use ratatui_notifications::notifications::{
+    Anchor, AnimationLayer, LayerTransform, NotificationBuilder, Notifications, Timing,
+};
+use std::time::Duration;
+
+const ENTRY: Duration = Duration::from_millis(1000);
+
+/// Renders the manager and returns the bounding box of every drawn cell
+fn drawn_bounds(manager: &mut Notifications) -> Option<Rect> {
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let buffer = terminal.backend().buffer();
+    let area = buffer.area;
+    (area.top()..area.bottom())
+        .flat_map(|y| (area.left()..area.right()).map(move |x| Position::new(x, y)))
+        .filter(|&pos| buffer[pos].symbol() != " ")
+        .map(|pos| Rect::new(pos.x, pos.y, 1, 1))
+        .reduce(Rect::union)
+}
+
+/// Adds a right-anchored notification with the given layers and a 1s entry
+fn layered(layers: &[AnimationLayer]) -> (Notifications, u64) {
+    let mut manager = Notifications::new();
+    let notification = NotificationBuilder::new("Composed entry\nwith a second line\nand a third")
+        .title("Layers")
+        .anchor(Anchor::MiddleRight)
+        .animation_layers(layers.iter().copied())
+        .timing(Timing::Fixed(ENTRY), Timing::Auto, Timing::Fixed(ENTRY))
+        .build()
+        .unwrap();
+    let id = manager.add(notification).unwrap();
+    (manager, id)
+}
+
+/// Bounds once the entry has finished
+fn settled_bounds(layers: &[AnimationLayer]) -> Rect {
+    let (mut manager, _) = layered(layers);
+    manager.tick(ENTRY);
+    drawn_bounds(&mut manager).unwrap()
+}
+
+fn center(rect: Rect) -> (u32, u32) {
+    (u32::from(rect.x) * 2 + u32::from(rect.width), u32::from(rect.y) * 2 + u32::from(rect.height))
+}
+
+#[test]
+fn test_transforms_compose() {
+    let slide = LayerTransform { offset_x: 10.0, opacity: 0.5, ..LayerTransform::IDENTITY };
+    let scale = LayerTransform { offset_y: -2.0, scale: 0.5, opacity: 0.5, ..LayerTransform::IDENTITY };
+    let composed = slide.then(scale);
+    assert_eq!(composed.offset_x, 10.0);
+    assert_eq!(composed.offset_y, -2.0);
+    assert_eq!(composed.scale, 0.5);
+    assert_eq!(composed.opacity, 0.25);
+    assert_eq!(LayerTransform::IDENTITY.then(slide), slide);
+}
+
+#[test]
+fn test_layer_transform_rect_scales_around_center_then_offsets() {
+    let rect = Rect::new(20, 10, 20, 6);
+    let frame_area = Rect::new(0, 0, 80, 24);
+    assert_eq!(layer_transform_rect(rect, frame_area, LayerTransform::IDENTITY), rect);
+
+    let transform = LayerTransform { offset_x: 4.0, offset_y: -1.0, scale: 0.5, ..LayerTransform::IDENTITY };
+    assert_eq!(layer_transform_rect(rect, frame_area, transform), Rect::new(29, 11, 10, 3));
+
+    // Clipped to the frame, and nothing left once fully offscreen or scaled away
+    let partly = LayerTransform { offset_x: 50.0, ..LayerTransform::IDENTITY };
+    assert_eq!(layer_transform_rect(rect, frame_area, partly), Rect::new(70, 10, 10, 6));
+    let offscreen = LayerTransform { offset_x: 70.0, ..LayerTransform::IDENTITY };
+    assert_eq!(layer_transform_rect(rect, frame_area, offscreen), Rect::default());
+    let gone = LayerTransform { scale: 0.0, ..LayerTransform::IDENTITY };
+    assert_eq!(layer_transform_rect(rect, frame_area, gone), Rect::default());
+}
+
+#[test]
+fn test_slide_and_scale_translate_and_scale_at_mid_progress() {
+    let settled = settled_bounds(&[AnimationLayer::Slide, AnimationLayer::Scale]);
+    let (mut manager, _) = layered(&[AnimationLayer::Slide, AnimationLayer::Scale]);
+    manager.tick(ENTRY / 2);
+    let mid = drawn_bounds(&mut manager).unwrap();
+
+    // Smaller than the settled box in both directions
+    assert!(mid.width < settled.width && mid.height < settled.height, "{mid:?} vs {settled:?}");
+    // And still on its way in from the right edge
+    assert!(center(mid).0 > center(settled).0, "{mid:?} vs {settled:?}");
+}
+
+#[test]
+fn test_scale_alone_keeps_the_center() {
+    let settled = settled_bounds(&[AnimationLayer::Scale]);
+    let (mut manager, _) = layered(&[AnimationLayer::Scale]);
+    manager.tick(ENTRY / 2);
+    let mid = drawn_bounds(&mut manager).unwrap();
+
+    assert!(mid.width < settled.width && mid.height < settled.height, "{mid:?} vs {settled:?}");
+    let (mid_x, mid_y) = center(mid);
+    let (settled_x, settled_y) = center(settled);
+    assert!(mid_x.abs_diff(settled_x) <= 2 && mid_y.abs_diff(settled_y) <= 2, "{mid:?} vs {settled:?}");
+}
+
+#[test]
+fn test_fade_layer_keeps_the_settled_rect() {
+    let settled = settled_bounds(&[AnimationLayer::Fade]);
+    let (mut manager, _) = layered(&[AnimationLayer::Fade]);
+    manager.tick(ENTRY / 2);
+    assert_eq!(drawn_bounds(&mut manager), Some(settled));
+}
+
+#[test]
+fn test_layers_play_the_exit_in_reverse() {
+    let settled = settled_bounds(&[AnimationLayer::Slide, AnimationLayer::Scale]);
+    let (mut manager, _) = layered(&[AnimationLayer::Slide, AnimationLayer::Scale]);
+    manager.tick(ENTRY);
+    manager.retain(|_| false);
+    manager.tick(ENTRY / 2);
+    let mid = drawn_bounds(&mut manager).unwrap();
+
+    assert!(mid.width < settled.width && mid.height < settled.height, "{mid:?} vs {settled:?}");
+    assert!(center(mid).0 > center(settled).0, "{mid:?} vs {settled:?}");
+}
+
+#[test]
+fn test_generate_code_emits_animation_layers() {
+    let notification = NotificationBuilder::new("Zoom")
+        .animation_layers([AnimationLayer::Slide, AnimationLayer::Scale])
+        .build()
+        .unwrap();
+    assert!(generate_code(&notification)
+        .contains(".animation_layers([AnimationLayer::Slide, AnimationLayer::Scale])"));
+
+    let plain = NotificationBuilder::new("Plain").build().unwrap();
+    assert!(!generate_code(&plain).contains("animation_layers"));
+}
+
+// FILE: tests/test_animation_layers_integration.rs - Integration tests for composed animation layers
+// END OF VERSION: 1.0.0
@@ -0,0 +1,58 @@
+// FILE: tests/test_animation_of_integration.rs - Integration tests for animation introspection
+// VERSION: 1.0.0
+// WCTX: Animation introspection
+// CLOG: Initial creation
+
+use ratatui_notifications::notifications::{Animation, AnimationPhase, NotificationBuilder, Notifications};
+use std::time::Duration;
+
+const ANIMATIONS: [Animation; 4] = [
+    Animation::Slide,
+    Animation::ExpandCollapse,
+    Animation::Fade,
+    Animation::RollDown,
+];
+
+#[test]
+fn test_animation_of_returns_configured_animation() {
+    let mut manager = Notifications::new();
+    for animation in ANIMATIONS {
+        let notification = NotificationBuilder::new("Inspect").animation(animation).build().unwrap();
+        let id = manager.add(notification).unwrap();
+        assert_eq!(manager.animation_of(id), Some(animation));
+    }
+}
+
+#[test]
+fn test_animation_of_defaults_to_slide() {
+    let mut manager = Notifications::new();
+    let id = manager.add(NotificationBuilder::new("Default").build().unwrap()).unwrap();
+    assert_eq!(manager.animation_of(id), Some(Animation::Slide));
+}
+
+#[test]
+fn test_animation_of_unknown_id_is_none() {
+    let mut manager = Notifications::new();
+    assert_eq!(manager.animation_of(0), None);
+
+    let id = manager.add(NotificationBuilder::new("Gone").build().unwrap()).unwrap();
+    manager.remove(id);
+    assert_eq!(manager.animation_of(id), None);
+    assert_eq!(manager.animation_of(id + 1), None);
+}
+
+#[test]
+fn test_animation_of_matches_entry_phase() {
+    for animation in ANIMATIONS {
+        let mut manager = Notifications::new();
+        let notification = NotificationBuilder::new("Entering").animation(animation).build().unwrap();
+        let id = manager.add(notification).unwrap();
+        manager.tick(Duration::from_millis(10));
+
+        let shown = manager.animation_of(id).unwrap();
+        assert_eq!(manager.phase(id), Some(AnimationPhase::entry(shown)), "{animation:?}");
+    }
+}
+
+// FILE: tests/test_animation_of_integration.rs - Integration tests for animation introspection
+// END OF VERSION: 1.0.0
@@ -0,0 +1,76 @@
+// FILE: tests/test_auto_contrast_integration.rs - Integration tests for auto-contrast content
+// VERSION: 1.0.0
+// WCTX: Auto-contrast content
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::style::{Color, Style};
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, NotificationBuilder, Notifications,
+};
+use ratatui_notifications::generate_code;
+use std::time::Duration;
+
+/// Renders a dwelling notification and returns the foreground of its
+/// first content cell
+fn content_fg(builder: NotificationBuilder) -> Color {
+    let mut manager = Notifications::new();
+    let id = manager
+        .add(builder.anchor(Anchor::TopLeft).build().unwrap())
+        .unwrap();
+    manager.tick(Duration::from_millis(600));
+
+    let mut terminal = Terminal::new(TestBackend::new(60, 20)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let inner = manager.content_rect(id).unwrap();
+    terminal.backend().buffer()[(inner.x, inner.y)].fg
+}
+
+#[test]
+fn test_light_background_gets_dark_content() {
+    let builder = NotificationBuilder::new("Hello")
+        .style(Style::new().bg(Color::Yellow))
+        .auto_contrast(true);
+    assert_eq!(content_fg(builder), Color::Black);
+}
+
+#[test]
+fn test_dark_background_gets_light_content() {
+    let builder = NotificationBuilder::new("Hello")
+        .style(Style::new().fg(Color::Black).bg(Color::Rgb(20, 20, 80)))
+        .auto_contrast(true);
+    assert_eq!(content_fg(builder), Color::White);
+}
+
+#[test]
+fn test_auto_contrast_applies_after_fade_in() {
+    let builder = NotificationBuilder::new("Hello")
+        .style(Style::new().bg(Color::White))
+        .animation(Animation::Fade)
+        .auto_contrast(true);
+    assert_eq!(content_fg(builder), Color::Black);
+}
+
+#[test]
+fn test_content_untouched_without_opt_in_or_background() {
+    let without_opt_in = NotificationBuilder::new("Hello")
+        .style(Style::new().fg(Color::Yellow).bg(Color::Yellow));
+    assert_eq!(content_fg(without_opt_in), Color::Yellow);
+
+    let without_background = NotificationBuilder::new("Hello")
+        .style(Style::new().fg(Color::Cyan))
+        .auto_contrast(true);
+    assert_eq!(content_fg(without_background), Color::Cyan);
+}
+
+#[test]
+fn test_generate_code_emits_auto_contrast() {
+    let notif = NotificationBuilder::new("Hello").auto_contrast(true).build().unwrap();
+    assert!(generate_code(&notif).contains(".auto_contrast(true)"));
+    let plain = NotificationBuilder::new("Hello").build().unwrap();
+    assert!(!generate_code(&plain).contains("auto_contrast"));
+}
+
+// FILE: tests/test_auto_contrast_integration.rs - Integration tests for auto-contrast content
+// END OF VERSION: 1.0.0
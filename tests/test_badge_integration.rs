@@ -0,0 +1,71 @@
+// FILE: tests/test_badge_integration.rs - Integration tests for the badge preset
+// VERSION: 1.0.0
+// WCTX: Badge notifications
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+use ratatui::widgets::{BorderType, Padding};
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    calculate_size, Anchor, Animation, NotificationBuilder, Notifications,
+};
+use std::time::Duration;
+
+#[test]
+fn test_badge_presets() {
+    let badge = NotificationBuilder::badge("3").build().unwrap();
+
+    assert_eq!(badge.anchor(), Anchor::TopRight);
+    assert_eq!(badge.padding(), Padding::ZERO);
+    assert_eq!(badge.border_type(), Some(BorderType::Plain));
+    assert!(badge.title().is_none());
+}
+
+#[test]
+fn test_badge_size_is_text_plus_border() {
+    let frame_area = Rect::new(0, 0, 80, 24);
+
+    for text in ["3", "12", "99+ unread"] {
+        let badge = NotificationBuilder::badge(text).build().unwrap();
+        assert_eq!(
+            calculate_size(&badge, frame_area),
+            (text.len() as u16 + 2, 3),
+            "badge {text:?}"
+        );
+    }
+
+    // Tiny frames do not shrink the badge below its text
+    let badge = NotificationBuilder::badge("12").build().unwrap();
+    assert_eq!(calculate_size(&badge, Rect::new(0, 0, 20, 5)), (4, 3));
+}
+
+#[test]
+fn test_badge_renders_exact_glyphs() {
+    let mut manager = Notifications::new();
+    let badge = NotificationBuilder::badge("12")
+        .animation(Animation::Fade)
+        .build()
+        .unwrap();
+    manager.add(badge).unwrap();
+    manager.tick(Duration::from_millis(600));
+
+    let mut terminal = Terminal::new(TestBackend::new(20, 6)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let rows: Vec<String> = (0..3)
+        .map(|y| (16..20).map(|x| buffer[(x, y)].symbol().to_string()).collect())
+        .collect();
+    assert_eq!(rows, ["┌──┐", "│12│", "└──┘"]);
+
+    // Nothing else is drawn
+    let blank = (0..6)
+        .flat_map(|y| (0..20).map(move |x| (x, y)))
+        .filter(|&(x, y)| !(x >= 16 && y < 3))
+        .all(|pos| buffer[pos].symbol() == " ");
+    assert!(blank);
+}
+
+// FILE: tests/test_badge_integration.rs - Integration tests for the badge preset
+// END OF VERSION: 1.0.0
@@ -0,0 +1,124 @@
+// FILE: tests/test_big_text_integration.rs - Integration tests for block-font big text
+// VERSION: 1.0.0
+// WCTX: Big block-font text
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+use ratatui::text::Text;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::functions::fnc_big_text::{big_glyph, big_text};
+use ratatui_notifications::notifications::{
+    calculate_size, generate_code, Anchor, Animation, NotificationBuilder, Notifications,
+    SizeConstraint,
+};
+use std::time::Duration;
+
+/// Glyph rows with non-breaking blanks shown as spaces
+fn plain_rows(c: char) -> Vec<String> {
+    big_glyph(c).iter().map(|row| row.replace('\u{a0}', " ")).collect()
+}
+
+#[test]
+fn test_glyphs_are_three_by_three() {
+    for c in "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789!.,:-'? ".chars() {
+        let rows = big_glyph(c);
+        assert!(rows.iter().all(|row| row.chars().count() == 3), "{c:?}");
+    }
+    assert_eq!(plain_rows('O'), ["▄▀▄", "█ █", " ▀ "]);
+    assert_eq!(plain_rows('!'), [" █ ", " ▀ ", " ▀ "]);
+    // Lowercase shares glyphs; unknown characters fall back to '?'
+    assert_eq!(big_glyph('e'), big_glyph('E'));
+    assert_eq!(big_glyph('€'), big_glyph('?'));
+}
+
+#[test]
+fn test_big_text_dimensions() {
+    let big = big_text(&Text::from("DONE!\nOK"));
+    assert_eq!(big.lines.len(), 6);
+    // Five glyphs of three cells plus four gaps
+    assert_eq!(big.width(), 19);
+}
+
+#[test]
+fn test_big_text_sizing_is_taller_and_wider() {
+    let frame_area = Rect::new(0, 0, 100, 50);
+    let normal = NotificationBuilder::new("DONE!").build().unwrap();
+    let big = NotificationBuilder::new("DONE!").big_text(true).build().unwrap();
+
+    let (normal_width, normal_height) = calculate_size(&normal, frame_area);
+    let (big_width, big_height) = calculate_size(&big, frame_area);
+
+    assert_eq!((normal_width, normal_height), (5 + 4, 3));
+    assert_eq!((big_width, big_height), (19 + 4, 3 + 2));
+    assert!(big_height > normal_height);
+}
+
+#[test]
+fn test_big_text_renders_block_font() {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("HI")
+        .big_text(true)
+        .anchor(Anchor::TopLeft)
+        .animation(Animation::Fade)
+        .max_size(SizeConstraint::Absolute(40), SizeConstraint::Absolute(10))
+        .build()
+        .unwrap();
+    let id = manager.add(notif).unwrap();
+    manager.tick(Duration::from_millis(600));
+
+    let mut terminal = Terminal::new(TestBackend::new(40, 12)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+
+    let inner = manager.content_rect(id).unwrap();
+    assert_eq!(inner.height, 3);
+    let buffer = terminal.backend().buffer();
+    let rows: Vec<String> = (inner.y..inner.y + 3)
+        .map(|y| {
+            (inner.x..inner.right())
+                .map(|x| buffer[(x, y)].symbol().replace('\u{a0}', " "))
+                .collect()
+        })
+        .collect();
+    assert_eq!(rows, ["█ █ ▀█▀", "█▀█  █ ", "▀ ▀ ▀▀▀"]);
+}
+
+#[test]
+fn test_big_text_centered_in_wider_box() {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("I")
+        .title("Celebration time")
+        .big_text(true)
+        .anchor(Anchor::TopLeft)
+        .animation(Animation::Fade)
+        .max_size(SizeConstraint::Absolute(40), SizeConstraint::Absolute(10))
+        .build()
+        .unwrap();
+    let id = manager.add(notif).unwrap();
+    manager.tick(Duration::from_millis(600));
+
+    let mut terminal = Terminal::new(TestBackend::new(40, 12)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+
+    // The title makes the box 16 cells wide inside; the glyph sits in the middle
+    let inner = manager.content_rect(id).unwrap();
+    let buffer = terminal.backend().buffer();
+    let top: String = (inner.x..inner.right())
+        .map(|x| buffer[(x, inner.y)].symbol().replace('\u{a0}', " "))
+        .collect();
+    let glyph_start = top.find('▀').unwrap();
+    let glyph_end = inner.width as usize - (glyph_start + 3);
+    assert!(glyph_start.abs_diff(glyph_end) <= 1, "{top:?}");
+}
+
+#[test]
+fn test_big_text_in_generated_code() {
+    let notif = NotificationBuilder::new("DONE").big_text(true).build().unwrap();
+    assert!(notif.big_text());
+    assert!(generate_code(&notif).contains("    .big_text(true)"));
+    let plain = NotificationBuilder::new("DONE").build().unwrap();
+    assert!(!generate_code(&plain).contains("big_text"));
+}
+
+// FILE: tests/test_big_text_integration.rs - Integration tests for block-font big text
+// END OF VERSION: 1.0.0
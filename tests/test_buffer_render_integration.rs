@@ -0,0 +1,98 @@
+// FILE: tests/test_buffer_render_integration.rs - Integration tests for Frame-free rendering
+// VERSION: 1.0.0
+// WCTX: Buffer-only rendering
+// CLOG: Initial creation
+//
+// Uses nothing from ratatui but Buffer and Rect, so the buffer path keeps
+// compiling without Frame, Terminal or a backend.
+// Run with: cargo test --test test_buffer_render_integration
+
+#![cfg(feature = "std")]
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui_notifications::notifications::{
+    Anchor, AutoDismiss, Layer, Level, NotificationBuilder, Notifications,
+};
+use std::time::Duration;
+
+fn add(manager: &mut Notifications, title: &'static str, level: Level, layer: Layer) -> u64 {
+    let notification = NotificationBuilder::new("Rendered without a frame")
+        .title(title)
+        .level(level)
+        .layer(layer)
+        .anchor(Anchor::TopLeft)
+        .auto_dismiss(AutoDismiss::Never);
+    manager.add(notification.build().unwrap()).unwrap()
+}
+
+fn settled(titles: &[(&'static str, Level, Layer)]) -> Notifications {
+    let mut manager = Notifications::new();
+    for &(title, level, layer) in titles {
+        add(&mut manager, title, level, layer);
+    }
+    manager.tick(Duration::from_secs(2));
+    manager
+}
+
+fn text(buf: &Buffer) -> String {
+    buf.content.iter().map(|cell| cell.symbol()).collect()
+}
+
+#[test]
+fn test_render_buffer_draws_into_plain_buffer() {
+    let mut manager = settled(&[("Plain", Level::Info, Layer::Below)]);
+    let area = Rect::new(0, 0, 60, 20);
+    let mut buf = Buffer::empty(area);
+    manager.render_buffer(area, &mut buf);
+    assert!(text(&buf).contains("Plain"));
+}
+
+#[test]
+fn test_render_buffer_stays_within_area() {
+    let mut manager = settled(&[("Inside", Level::Info, Layer::Below)]);
+    let full = Rect::new(0, 0, 80, 24);
+    let area = Rect::new(40, 10, 40, 14);
+    let mut buf = Buffer::empty(full);
+    manager.render_buffer(area, &mut buf);
+
+    assert!(text(&buf).contains("Inside"));
+    for y in full.top()..full.bottom() {
+        for x in full.left()..full.right() {
+            if !area.contains((x, y).into()) {
+                assert_eq!(buf[(x, y)].symbol(), " ", "cell ({x}, {y}) outside the area");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_render_layer_buffer_draws_one_layer() {
+    let mut manager = settled(&[("Under", Level::Info, Layer::Below), ("Over", Level::Info, Layer::Above)]);
+    let area = Rect::new(0, 0, 60, 20);
+    let mut buf = Buffer::empty(area);
+    manager.render_layer_buffer(area, &mut buf, Layer::Above);
+    assert!(text(&buf).contains("Over"));
+    assert!(!text(&buf).contains("Under"));
+}
+
+#[test]
+fn test_render_filtered_buffer_draws_matching() {
+    let mut manager = settled(&[("Broken", Level::Error, Layer::Below), ("Fine", Level::Info, Layer::Below)]);
+    let area = Rect::new(0, 0, 60, 20);
+    let mut buf = Buffer::empty(area);
+    manager.render_filtered_buffer(area, &mut buf, |info| info.level == Some(Level::Error));
+    assert!(text(&buf).contains("Broken"));
+    assert!(!text(&buf).contains("Fine"));
+}
+
+#[test]
+fn test_render_buffer_into_empty_area_draws_nothing() {
+    let mut manager = settled(&[("Hidden", Level::Info, Layer::Below)]);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 60, 20));
+    manager.render_buffer(Rect::new(0, 0, 0, 20), &mut buf);
+    assert_eq!(buf, Buffer::empty(Rect::new(0, 0, 60, 20)));
+}
+
+// FILE: tests/test_buffer_render_integration.rs - Integration tests for Frame-free rendering
+// END OF VERSION: 1.0.0
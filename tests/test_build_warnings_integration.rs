@@ -0,0 +1,125 @@
+// FILE: tests/test_build_warnings_integration.rs - Integration tests for non-fatal build lints
+// VERSION: 1.0.0
+// WCTX: Build warnings
+// CLOG: Initial creation
+
+use ratatui::layout::Position;
+use ratatui::widgets::Padding;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, BuildWarningKind, NotificationBuilder, NotificationError, SizeConstraint,
+    SlideDirection, Timing,
+};
+use std::time::Duration;
+
+fn kinds(builder: NotificationBuilder) -> Vec<BuildWarningKind> {
+    let (_, warnings) = builder.build_with_warnings().unwrap();
+    warnings.iter().map(|warning| warning.kind).collect()
+}
+
+fn fixed(ms: u64) -> Timing {
+    Timing::Fixed(Duration::from_millis(ms))
+}
+
+#[test]
+fn test_clean_configs_have_no_warnings() {
+    assert!(kinds(NotificationBuilder::new("Saved")).is_empty());
+    assert!(kinds(
+        NotificationBuilder::new("Saved")
+            .anchor(Anchor::TopRight)
+            .slide_direction(SlideDirection::FromRight)
+            .timing(fixed(200), fixed(3000), fixed(200))
+            .max_size(SizeConstraint::Absolute(20), SizeConstraint::Absolute(3))
+            .padding(Padding::ZERO)
+    )
+    .is_empty());
+    assert!(kinds(NotificationBuilder::new("Saved").animation(Animation::Fade)).is_empty());
+    assert!(kinds(NotificationBuilder::new("Saved").fade(true)).is_empty());
+    assert!(kinds(NotificationBuilder::badge("3")).is_empty());
+}
+
+#[test]
+fn test_slide_crossing_screen_warns() {
+    let builder = NotificationBuilder::new("Saved")
+        .anchor(Anchor::TopRight)
+        .slide_direction(SlideDirection::FromLeft);
+    assert_eq!(kinds(builder), [BuildWarningKind::SlideCrossesScreen]);
+
+    let builder = NotificationBuilder::new("Saved")
+        .anchor(Anchor::TopCenter)
+        .slide_direction(SlideDirection::FromBottomLeft);
+    assert_eq!(kinds(builder), [BuildWarningKind::SlideCrossesScreen]);
+}
+
+#[test]
+fn test_slide_crossing_screen_silenced_by_custom_path_or_animation() {
+    let custom = NotificationBuilder::new("Saved")
+        .anchor(Anchor::TopRight)
+        .slide_direction(SlideDirection::FromLeft)
+        .entry_position(Position::new(0, 0));
+    assert!(kinds(custom).is_empty());
+
+    let fade = NotificationBuilder::new("Saved")
+        .anchor(Anchor::TopRight)
+        .slide_direction(SlideDirection::FromLeft)
+        .animation(Animation::Fade);
+    assert!(kinds(fade).is_empty());
+}
+
+#[test]
+fn test_max_size_below_minimum_warns_per_axis() {
+    // Border (2) + horizontal padding (2) + one cell needs 5 columns
+    let builder = NotificationBuilder::new("Saved")
+        .max_size(SizeConstraint::Absolute(4), SizeConstraint::Absolute(2));
+    let (_, warnings) = builder.build_with_warnings().unwrap();
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings.iter().all(|w| w.kind == BuildWarningKind::MaxSizeBelowMinimum));
+    assert!(warnings[0].message.contains("width 4"));
+    assert!(warnings[1].message.contains("height 2"));
+
+    // Exactly the minimum is fine
+    let builder = NotificationBuilder::new("Saved")
+        .max_size(SizeConstraint::Absolute(5), SizeConstraint::Absolute(3));
+    assert!(kinds(builder).is_empty());
+}
+
+#[test]
+fn test_dwell_shorter_than_animations_warns() {
+    let builder = NotificationBuilder::new("Saved").timing(fixed(300), fixed(500), fixed(300));
+    assert_eq!(kinds(builder), [BuildWarningKind::DwellShorterThanAnimations]);
+
+    // Auto timings depend on the manager and are not checked
+    let builder = NotificationBuilder::new("Saved").timing(Timing::Auto, fixed(100), Timing::Auto);
+    assert!(kinds(builder).is_empty());
+}
+
+#[test]
+fn test_redundant_fade_warns() {
+    let builder = NotificationBuilder::new("Saved")
+        .animation(Animation::Fade)
+        .fade(true);
+    let (_, warnings) = builder.build_with_warnings().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].kind, BuildWarningKind::RedundantFade);
+    assert_eq!(warnings[0].to_string(), warnings[0].message);
+}
+
+#[test]
+fn test_build_ignores_warnings_and_errors_match() {
+    let builder = || {
+        NotificationBuilder::new("Saved")
+            .animation(Animation::Fade)
+            .fade(true)
+    };
+    let plain = builder().build().unwrap();
+    let (linted, _) = builder().build_with_warnings().unwrap();
+    assert_eq!(plain.fade_effect(), linted.fade_effect());
+
+    let too_long = "x".repeat(1001);
+    assert!(matches!(
+        NotificationBuilder::new(too_long).build_with_warnings(),
+        Err(NotificationError::ContentTooLarge(1001, 1000))
+    ));
+}
+
+// FILE: tests/test_build_warnings_integration.rs - Integration tests for non-fatal build lints
+// END OF VERSION: 1.0.0
@@ -0,0 +1,151 @@
+// FILE: tests/test_catch_up_integration.rs - Integration tests for the tick delta budget
+// VERSION: 1.1.0
+// WCTX: Exact dwell timing
+// CLOG: Display time starts when the entry ends
+
+use ratatui_notifications::notifications::{
+    AnimationPhase, AutoDismiss, CatchUpPolicy, NotificationBuilder, Notifications, Timing,
+};
+use std::collections::HashMap;
+use std::time::Duration;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+/// 200ms entry and exit, shown for `display_ms`
+fn timed(display_ms: u64) -> NotificationBuilder {
+    NotificationBuilder::new("Saved")
+        .timing(Timing::Fixed(ms(200)), Timing::Auto, Timing::Fixed(ms(200)))
+        .auto_dismiss(AutoDismiss::After(ms(display_ms)))
+}
+
+/// Manager with three settled notifications finishing 500ms apart
+fn staggered(manager: Notifications) -> (Notifications, Vec<u64>) {
+    let mut manager = manager;
+    let ids = [500, 1000, 1500]
+        .into_iter()
+        .map(|display| manager.add(timed(display).build().unwrap()).unwrap())
+        .collect();
+    manager.tick(ms(200));
+    (manager, ids)
+}
+
+/// Ticks through a 5s stall then 16ms frames, returning the phases each
+/// notification went through and the most notifications finished by one tick
+fn run_after_stall(
+    manager: &mut Notifications,
+    ids: &[u64],
+) -> (HashMap<u64, Vec<AnimationPhase>>, usize) {
+    let mut seen: HashMap<u64, Vec<AnimationPhase>> =
+        ids.iter().map(|&id| (id, vec![manager.phase(id).unwrap()])).collect();
+    let mut most_finished = 0;
+    let mut delta = ms(5000);
+    for _ in 0..1000 {
+        let alive_before = ids.iter().filter(|&&id| manager.phase(id).is_some()).count();
+        manager.tick(delta);
+        delta = ms(16);
+        let alive_after = ids.iter().filter(|&&id| manager.phase(id).is_some()).count();
+        most_finished = most_finished.max(alive_before - alive_after);
+        for &id in ids {
+            if let Some(phase) = manager.phase(id) {
+                let phases = seen.get_mut(&id).unwrap();
+                if phases.last() != Some(&phase) {
+                    phases.push(phase);
+                }
+            }
+        }
+        if alive_after == 0 {
+            break;
+        }
+    }
+    (seen, most_finished)
+}
+
+#[test]
+fn test_unlimited_delta_finishes_everything_in_one_tick() {
+    let (mut manager, ids) = staggered(Notifications::new());
+    let (_, most_finished) = run_after_stall(&mut manager, &ids);
+
+    // The stall ends every display time at once, so all exits run together
+    assert_eq!(most_finished, 3);
+}
+
+#[test]
+fn test_clamped_stall_plays_every_exit() {
+    for policy in [CatchUpPolicy::Clamp, CatchUpPolicy::Spread] {
+        let manager = Notifications::new().max_delta(Some(ms(250))).catch_up(policy);
+        let (mut manager, ids) = staggered(manager);
+        let (seen, most_finished) = run_after_stall(&mut manager, &ids);
+
+        assert_eq!(most_finished, 1, "{policy:?}");
+        for phases in seen.values() {
+            assert_eq!(phases, &[AnimationPhase::Dwelling, AnimationPhase::SlidingOut]);
+        }
+    }
+}
+
+#[test]
+fn test_clamp_drops_the_excess() {
+    let mut manager = Notifications::new().max_delta(Some(ms(250)));
+    let id = manager.add(timed(1000).build().unwrap()).unwrap();
+    manager.tick(ms(200));
+
+    // Only 250ms of the stall counts; the 750ms left are still to come
+    manager.tick(ms(5000));
+    for _ in 0..10 {
+        manager.tick(Duration::ZERO);
+    }
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    for delta in [249, 250, 250] {
+        manager.tick(ms(delta));
+    }
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    manager.tick(ms(1));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+}
+
+#[test]
+fn test_spread_applies_the_excess_over_later_ticks() {
+    let mut manager =
+        Notifications::new().max_delta(Some(ms(250))).catch_up(CatchUpPolicy::Spread);
+    let id = manager.add(timed(1000).build().unwrap()).unwrap();
+    manager.tick(ms(200));
+
+    // 1000ms of display time left: 250 + 250 + 250 + 250
+    manager.tick(ms(5000));
+    manager.tick(Duration::ZERO);
+    manager.tick(Duration::ZERO);
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    manager.tick(Duration::ZERO);
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+}
+
+#[test]
+fn test_spread_forgets_the_excess_once_idle() {
+    let mut manager =
+        Notifications::new().max_delta(Some(ms(250))).catch_up(CatchUpPolicy::Spread);
+    manager.tick(ms(5000));
+
+    let id = manager.add(timed(1000).build().unwrap()).unwrap();
+    for _ in 0..5 {
+        manager.tick(Duration::ZERO);
+    }
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingIn));
+}
+
+#[test]
+fn test_small_deltas_are_unchanged() {
+    let mut manager = Notifications::new().max_delta(Some(ms(250)));
+    let id = manager.add(timed(1000).build().unwrap()).unwrap();
+
+    for delta in [200, 250, 250, 250, 249] {
+        manager.tick(ms(delta));
+    }
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    manager.tick(ms(1));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+}
+
+// FILE: tests/test_catch_up_integration.rs - Integration tests for the tick delta budget
+// END OF VERSION: 1.1.0
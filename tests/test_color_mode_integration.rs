@@ -0,0 +1,92 @@
+// FILE: tests/test_color_mode_integration.rs - Integration tests for ColorMode output degradation
+// VERSION: 1.0.0
+// WCTX: Color output degradation for limited terminals
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::prelude::*;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Animation, ColorMode, Level, NotificationBuilder, Notifications,
+};
+use std::time::Duration;
+
+fn render_mid_fade(mode: ColorMode, level: Level) -> Buffer {
+    let mut manager = Notifications::new().color_mode(mode);
+    let notif = NotificationBuilder::new(Line::from(vec![
+        Span::styled("custom ", Style::new().fg(Color::Rgb(200, 40, 120))),
+        Span::raw("body"),
+    ]))
+    .title("Fading")
+    .level(level)
+    .animation(Animation::Fade)
+    .style(Style::new().bg(Color::Rgb(30, 30, 60)))
+    .build()
+    .unwrap();
+    manager.add(notif).unwrap();
+
+    // Halfway through the default 500ms entry so fade colors are RGB blends
+    manager.tick(Duration::from_millis(250));
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal
+        .draw(|frame| manager.render(frame, frame.area()))
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn has_rgb(buffer: &Buffer) -> bool {
+    buffer
+        .content
+        .iter()
+        .any(|cell| matches!(cell.fg, Color::Rgb(..)) || matches!(cell.bg, Color::Rgb(..)))
+}
+
+#[test]
+fn test_truecolor_passes_rgb_through() {
+    let buffer = render_mid_fade(ColorMode::TrueColor, Level::Info);
+    assert!(has_rgb(&buffer));
+}
+
+#[test]
+fn test_ansi16_leaks_no_rgb() {
+    let buffer = render_mid_fade(ColorMode::Ansi16, Level::Info);
+    assert!(!has_rgb(&buffer));
+    assert!(!buffer
+        .content
+        .iter()
+        .any(|cell| matches!(cell.fg, Color::Indexed(_)) || matches!(cell.bg, Color::Indexed(_))));
+}
+
+#[test]
+fn test_ansi256_quantizes_to_indexed() {
+    let buffer = render_mid_fade(ColorMode::Ansi256, Level::Info);
+    assert!(!has_rgb(&buffer));
+    assert!(buffer.content.iter().any(|cell| matches!(cell.bg, Color::Indexed(_))));
+}
+
+#[test]
+fn test_mono_strips_colors_and_bolds_errors() {
+    let buffer = render_mid_fade(ColorMode::Mono, Level::Error);
+    assert!(buffer
+        .content
+        .iter()
+        .all(|cell| cell.fg == Color::Reset && cell.bg == Color::Reset));
+    assert!(buffer
+        .content
+        .iter()
+        .any(|cell| cell.modifier.contains(Modifier::BOLD)));
+}
+
+#[test]
+fn test_mono_dims_trace() {
+    let buffer = render_mid_fade(ColorMode::Mono, Level::Trace);
+    assert!(buffer
+        .content
+        .iter()
+        .any(|cell| cell.modifier.contains(Modifier::DIM)));
+}
+
+// FILE: tests/test_color_mode_integration.rs - Integration tests for ColorMode output degradation
+// END OF VERSION: 1.0.0
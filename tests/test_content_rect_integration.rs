@@ -0,0 +1,94 @@
+// FILE: tests/test_content_rect_integration.rs - Integration tests for the content rect accessor
+// VERSION: 1.0.0
+// WCTX: Content rect accessor
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+use ratatui::widgets::Padding;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::orc_render::RenderableNotification;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, ManagerDefaults, NotificationBuilder, NotificationState, Notifications,
+};
+use std::time::Duration;
+
+fn state_with(padding: Padding, separator: bool) -> NotificationState {
+    let notif = NotificationBuilder::new("Body")
+        .title("Title")
+        .title_separator(separator)
+        .padding(padding)
+        .build()
+        .unwrap();
+    NotificationState::new(1, notif, &ManagerDefaults::default())
+}
+
+#[test]
+fn test_content_rect_none_before_render() {
+    let state = state_with(Padding::ZERO, false);
+    assert_eq!(state.content_rect(), None);
+
+    let mut manager = Notifications::new();
+    let id = manager.add(NotificationBuilder::new("Body").build().unwrap()).unwrap();
+    manager.tick(Duration::from_millis(16));
+    assert_eq!(manager.content_rect(id), None);
+}
+
+#[test]
+fn test_content_rect_none_for_unknown_id() {
+    let manager = Notifications::new();
+    assert_eq!(manager.content_rect(42), None);
+}
+
+#[test]
+fn test_content_rect_inset_by_border_and_padding() {
+    let mut state = state_with(Padding::new(2, 1, 1, 0), false);
+    let full = Rect::new(10, 5, 30, 8);
+    state.set_full_rect(full);
+
+    let inner = state.content_rect().unwrap();
+    assert_eq!(inner, Rect::new(10 + 1 + 2, 5 + 1 + 1, 30 - 2 - 3, 8 - 2 - 1));
+    assert!(inner.x > full.x && inner.y > full.y);
+    assert!(inner.right() < full.right() && inner.bottom() < full.bottom());
+}
+
+#[test]
+fn test_content_rect_excludes_title_separator_row() {
+    let full = Rect::new(0, 0, 20, 6);
+
+    let mut plain = state_with(Padding::ZERO, false);
+    plain.set_full_rect(full);
+    let mut separated = state_with(Padding::ZERO, true);
+    separated.set_full_rect(full);
+
+    let plain = plain.content_rect().unwrap();
+    let separated = separated.content_rect().unwrap();
+    assert_eq!(separated.y, plain.y + 1);
+    assert_eq!(separated.height, plain.height - 1);
+}
+
+#[test]
+fn test_content_rect_matches_drawn_content() {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("Body")
+        .anchor(Anchor::TopLeft)
+        .animation(Animation::Fade)
+        .padding(Padding::new(1, 1, 1, 1))
+        .build()
+        .unwrap();
+    let id = manager.add(notif).unwrap();
+    manager.tick(Duration::from_millis(600));
+
+    let mut terminal = Terminal::new(TestBackend::new(60, 20)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+
+    let inner = manager.content_rect(id).unwrap();
+    let buffer = terminal.backend().buffer();
+    let drawn: String = (inner.x..inner.x + 4)
+        .map(|x| buffer[(x, inner.y)].symbol().to_string())
+        .collect();
+    assert_eq!(drawn, "Body");
+}
+
+// FILE: tests/test_content_rect_integration.rs - Integration tests for the content rect accessor
+// END OF VERSION: 1.0.0
@@ -0,0 +1,107 @@
+// FILE: tests/test_corner_slide_axis_integration.rs - Integration tests for the corner slide axis
+// VERSION: 1.1.0
+// WCTX: Corner slide axis
+// CLOG: Moved resolve_slide_direction_on_axis tests here so they run
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::functions::fnc_slide_resolve_direction::resolve_slide_direction_on_axis;
+use ratatui_notifications::notifications::{
+    Anchor, CornerSlideAxis, NotificationBuilder, Notifications, SlideDirection, Timing,
+};
+use std::time::Duration;
+
+/// Screen with a TopLeft notification halfway through its 1s entry
+fn halfway(manager: Notifications, direction: SlideDirection) -> Buffer {
+    let mut manager = manager;
+    let notification = NotificationBuilder::new("Corner")
+        .anchor(Anchor::TopLeft)
+        .slide_direction(direction)
+        .timing(Timing::Fixed(Duration::from_secs(1)), Timing::Auto, Timing::Auto)
+        .build()
+        .unwrap();
+    manager.add(notification).unwrap();
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    manager.tick(Duration::from_millis(500));
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+#[test]
+fn test_horizontal_axis_slides_top_left_from_the_left() {
+    let manager = Notifications::new().corner_slide_axis(CornerSlideAxis::Horizontal);
+    let explicit = halfway(Notifications::new(), SlideDirection::FromLeft);
+
+    assert_eq!(halfway(manager, SlideDirection::Default), explicit);
+}
+
+#[test]
+fn test_vertical_axis_slides_top_left_from_the_top() {
+    let manager = Notifications::new().corner_slide_axis(CornerSlideAxis::Vertical);
+    let explicit = halfway(Notifications::new(), SlideDirection::FromTop);
+
+    assert_eq!(halfway(manager, SlideDirection::Default), explicit);
+}
+
+#[test]
+fn test_diagonal_axis_is_the_default() {
+    let diagonal = Notifications::new().corner_slide_axis(CornerSlideAxis::Diagonal);
+    let explicit = halfway(Notifications::new(), SlideDirection::FromTopLeft);
+
+    assert_eq!(halfway(Notifications::new(), SlideDirection::Default), explicit);
+    assert_eq!(halfway(diagonal, SlideDirection::Default), explicit);
+    assert_ne!(explicit, halfway(Notifications::new(), SlideDirection::FromLeft));
+}
+
+#[test]
+fn test_explicit_direction_ignores_the_axis() {
+    let manager = Notifications::new().corner_slide_axis(CornerSlideAxis::Horizontal);
+    let explicit = halfway(Notifications::new(), SlideDirection::FromTopLeft);
+
+    assert_eq!(halfway(manager, SlideDirection::FromTopLeft), explicit);
+}
+
+#[test]
+fn test_horizontal_axis_slides_corners_from_the_side() {
+    let resolve = |anchor| {
+        resolve_slide_direction_on_axis(SlideDirection::Default, anchor, CornerSlideAxis::Horizontal)
+    };
+    assert_eq!(resolve(Anchor::TopLeft), SlideDirection::FromLeft);
+    assert_eq!(resolve(Anchor::BottomLeft), SlideDirection::FromLeft);
+    assert_eq!(resolve(Anchor::TopRight), SlideDirection::FromRight);
+    assert_eq!(resolve(Anchor::BottomRight), SlideDirection::FromRight);
+}
+
+#[test]
+fn test_vertical_axis_slides_corners_from_top_or_bottom() {
+    let resolve = |anchor| {
+        resolve_slide_direction_on_axis(SlideDirection::Default, anchor, CornerSlideAxis::Vertical)
+    };
+    assert_eq!(resolve(Anchor::TopLeft), SlideDirection::FromTop);
+    assert_eq!(resolve(Anchor::TopRight), SlideDirection::FromTop);
+    assert_eq!(resolve(Anchor::BottomLeft), SlideDirection::FromBottom);
+    assert_eq!(resolve(Anchor::BottomRight), SlideDirection::FromBottom);
+}
+
+#[test]
+fn test_axis_leaves_edges_and_explicit_directions_alone() {
+    for axis in [CornerSlideAxis::Diagonal, CornerSlideAxis::Horizontal, CornerSlideAxis::Vertical] {
+        assert_eq!(
+            resolve_slide_direction_on_axis(SlideDirection::Default, Anchor::TopCenter, axis),
+            SlideDirection::FromTop
+        );
+        assert_eq!(
+            resolve_slide_direction_on_axis(SlideDirection::FromBottomRight, Anchor::TopLeft, axis),
+            SlideDirection::FromBottomRight
+        );
+    }
+    assert_eq!(
+        resolve_slide_direction_on_axis(SlideDirection::Default, Anchor::TopLeft, CornerSlideAxis::Diagonal),
+        SlideDirection::FromTopLeft
+    );
+}
+
+// FILE: tests/test_corner_slide_axis_integration.rs - Integration tests for the corner slide axis
+// END OF VERSION: 1.1.0
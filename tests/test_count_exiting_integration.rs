@@ -0,0 +1,112 @@
+// FILE: tests/test_count_exiting_integration.rs - Integration tests for exiting notifications and limits
+// VERSION: 1.1.0
+// WCTX: Exiting notifications free their slot
+// CLOG: enforce_limits counts slots like add
+
+use ratatui_notifications::notifications::{
+    Anchor, AnimationPhase, AutoDismiss, NotificationBuilder, Notifications,
+};
+use std::time::Duration;
+
+/// Adds `count` settled notifications at the top right, oldest first
+fn fill(manager: &mut Notifications, count: usize) -> Vec<u64> {
+    let ids = (0..count).map(|n| add(manager, n)).collect();
+    manager.tick(Duration::from_secs(1));
+    ids
+}
+
+fn add(manager: &mut Notifications, n: usize) -> u64 {
+    let notification = NotificationBuilder::new(format!("Job {n} done"))
+        .anchor(Anchor::TopRight)
+        .auto_dismiss(AutoDismiss::Never);
+    manager.add(notification.build().unwrap()).unwrap()
+}
+
+/// Starts the exit of one notification
+fn dismiss(manager: &mut Notifications, id: u64) {
+    manager.for_each_mut(|mut notification| {
+        if notification.id() == id {
+            notification.dismiss();
+        }
+    });
+    manager.tick(Duration::from_millis(16));
+    assert_eq!(manager.phase(id), OUT);
+}
+
+fn phases(manager: &Notifications, ids: &[u64]) -> Vec<Option<AnimationPhase>> {
+    ids.iter().map(|&id| manager.phase(id)).collect()
+}
+
+const OUT: Option<AnimationPhase> = Some(AnimationPhase::SlidingOut);
+const SHOWN: Option<AnimationPhase> = Some(AnimationPhase::Dwelling);
+
+#[test]
+fn test_add_takes_the_slot_of_an_exiting_notification() {
+    let mut manager = Notifications::new().max_concurrent(Some(3));
+    let ids = fill(&mut manager, 3);
+    dismiss(&mut manager, ids[2]);
+
+    let added = add(&mut manager, 3);
+    assert_eq!(phases(&manager, &ids), vec![SHOWN, SHOWN, OUT]);
+    assert!(manager.phase(added).is_some());
+
+    // Once the exit finishes the stack is back at the limit
+    manager.tick(Duration::from_secs(1));
+    assert_eq!(phases(&manager, &ids), vec![SHOWN, SHOWN, None]);
+    assert_eq!(manager.phase(added), SHOWN);
+}
+
+#[test]
+fn test_count_exiting_evicts_a_showing_notification() {
+    let mut manager = Notifications::new().max_concurrent(Some(3)).count_exiting(true);
+    let ids = fill(&mut manager, 3);
+    dismiss(&mut manager, ids[2]);
+
+    add(&mut manager, 3);
+    assert_eq!(phases(&manager, &ids), vec![None, SHOWN, OUT]);
+}
+
+#[test]
+fn test_full_stack_without_exits_still_evicts() {
+    let mut manager = Notifications::new().max_concurrent(Some(3));
+    let ids = fill(&mut manager, 3);
+
+    add(&mut manager, 3);
+    assert_eq!(phases(&manager, &ids), vec![None, SHOWN, SHOWN]);
+}
+
+#[test]
+fn test_max_total_ignores_exiting_notifications() {
+    let mut manager = Notifications::new().max_total(Some(3));
+    let ids = fill(&mut manager, 3);
+    dismiss(&mut manager, ids[0]);
+
+    add(&mut manager, 3);
+    assert_eq!(phases(&manager, &ids), vec![OUT, SHOWN, SHOWN]);
+
+    // A second add finds the showing slots full again
+    add(&mut manager, 4);
+    assert_eq!(phases(&manager, &ids), vec![OUT, None, SHOWN]);
+}
+
+#[test]
+fn test_enforce_limits_counts_exiting_notifications_when_asked() {
+    // Lowered to 2 with one of three exiting: the exiting toast keeps its slot
+    let mut manager = Notifications::new().count_exiting(true);
+    let ids = fill(&mut manager, 3);
+    dismiss(&mut manager, ids[2]);
+    manager = manager.max_concurrent(Some(2));
+    manager.enforce_limits();
+    assert_eq!(phases(&manager, &ids), vec![OUT, SHOWN, OUT]);
+
+    // Without count_exiting the two showing ones already fit
+    let mut manager = Notifications::new();
+    let ids = fill(&mut manager, 3);
+    dismiss(&mut manager, ids[2]);
+    manager = manager.max_concurrent(Some(2));
+    manager.enforce_limits();
+    assert_eq!(phases(&manager, &ids), vec![SHOWN, SHOWN, OUT]);
+}
+
+// FILE: tests/test_count_exiting_integration.rs - Integration tests for exiting notifications and limits
+// END OF VERSION: 1.1.0
@@ -0,0 +1,141 @@
+// FILE: tests/test_countdown_integration.rs - Integration tests for the title countdown
+// VERSION: 1.0.0
+// WCTX: Countdown display
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    generate_code, Anchor, AnimationPhase, AutoDismiss, BuildWarningKind, NotificationBuilder,
+    Notifications, Strings,
+};
+use std::time::Duration;
+
+/// Renders the manager and returns the whole screen as one string
+fn screen(manager: &mut Notifications) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(80, 10)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..10)
+        .map(|y| (0..80).map(|x| buffer[(x, y)].symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Ticks until the notification has finished entering
+fn enter(manager: &mut Notifications, id: u64) {
+    while manager.phase(id) != Some(AnimationPhase::Dwelling) {
+        manager.tick(Duration::from_millis(10));
+    }
+}
+
+fn countdown(title: Option<&'static str>) -> NotificationBuilder {
+    let mut builder = NotificationBuilder::new("Connection lost")
+        .anchor(Anchor::TopLeft)
+        .auto_dismiss(AutoDismiss::After(Duration::from_secs(3)))
+        .show_countdown(true);
+    if let Some(title) = title {
+        builder = builder.title(title);
+    }
+    builder
+}
+
+#[test]
+fn test_countdown_decrements_across_ticks() {
+    let mut manager = Notifications::new();
+    let id = manager.add(countdown(Some("Retrying in")).build().unwrap()).unwrap();
+    enter(&mut manager, id);
+
+    assert!(screen(&mut manager).contains("Retrying in 3s"));
+    manager.tick(Duration::from_millis(1000));
+    assert!(screen(&mut manager).contains("Retrying in 2s"));
+    manager.tick(Duration::from_millis(1000));
+    assert!(screen(&mut manager).contains("Retrying in 1s"));
+}
+
+#[test]
+fn test_countdown_rounds_partial_seconds_up() {
+    let mut manager = Notifications::new();
+    let id = manager.add(countdown(Some("Retrying in")).build().unwrap()).unwrap();
+    enter(&mut manager, id);
+
+    manager.tick(Duration::from_millis(2500));
+    assert!(screen(&mut manager).contains("Retrying in 1s"));
+}
+
+#[test]
+fn test_countdown_disappears_at_dismissal() {
+    let mut manager = Notifications::new();
+    let id = manager.add(countdown(Some("Retrying in")).build().unwrap()).unwrap();
+    enter(&mut manager, id);
+
+    manager.tick(Duration::from_millis(3010));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+    let screen = screen(&mut manager);
+    assert!(!screen.contains("0s") && !screen.contains("1s"), "{screen}");
+}
+
+#[test]
+fn test_countdown_without_title_becomes_the_title() {
+    let mut manager = Notifications::new();
+    let id = manager.add(countdown(None).build().unwrap()).unwrap();
+    enter(&mut manager, id);
+
+    assert!(screen(&mut manager).contains(" 3s "));
+}
+
+#[test]
+fn test_countdown_uses_strings() {
+    let mut strings = Strings::default();
+    strings.countdown = "noch {n} s".into();
+    let mut manager = Notifications::new().strings(strings);
+    let notif = NotificationBuilder::new("Verbindung zum Server verloren")
+        .title("Neuer Versuch")
+        .anchor(Anchor::TopLeft)
+        .auto_dismiss(AutoDismiss::After(Duration::from_secs(3)))
+        .show_countdown(true)
+        .build()
+        .unwrap();
+    let id = manager.add(notif).unwrap();
+    enter(&mut manager, id);
+
+    assert!(screen(&mut manager).contains("Neuer Versuch noch 3 s"));
+}
+
+#[test]
+fn test_countdown_is_off_by_default() {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("Connection lost")
+        .title("Retrying in")
+        .auto_dismiss(AutoDismiss::After(Duration::from_secs(3)))
+        .build()
+        .unwrap();
+    let id = manager.add(notif).unwrap();
+    enter(&mut manager, id);
+
+    assert!(!screen(&mut manager).contains("3s"));
+}
+
+#[test]
+fn test_countdown_without_after_is_linted() {
+    let (_, warnings) = NotificationBuilder::new("Sticky")
+        .auto_dismiss(AutoDismiss::Never)
+        .show_countdown(true)
+        .build_with_warnings()
+        .unwrap();
+    assert!(warnings
+        .iter()
+        .any(|w| w.kind == BuildWarningKind::CountdownWithoutTimer));
+
+    let (_, warnings) = countdown(None).build_with_warnings().unwrap();
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_generate_code_emits_show_countdown() {
+    let code = generate_code(&countdown(None).build().unwrap());
+    assert!(code.contains(".show_countdown(true)"));
+}
+
+// FILE: tests/test_countdown_integration.rs - Integration tests for the title countdown
+// END OF VERSION: 1.0.0
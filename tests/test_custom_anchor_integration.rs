@@ -0,0 +1,115 @@
+// FILE: tests/test_custom_anchor_integration.rs - Integration tests for custom anchor positions
+// VERSION: 1.0.0
+// WCTX: Custom anchors
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::layout::{Position, Rect};
+use ratatui::Terminal;
+use ratatui_notifications::notifications::functions::fnc_calculate_anchor_position::calculate_anchor_position;
+use ratatui_notifications::notifications::functions::fnc_slide_resolve_direction::resolve_slide_direction;
+use ratatui_notifications::notifications::{
+    Anchor, AnchorCoord, Animation, NotificationBuilder, Notifications, SlideDirection,
+};
+use std::collections::HashSet;
+use std::time::Duration;
+
+fn quarter_three_quarters() -> Anchor {
+    Anchor::Custom {
+        x: AnchorCoord::percent(25.0),
+        y: AnchorCoord::percent(75.0),
+    }
+}
+
+/// Renders the manager and returns the top-left corners of all drawn boxes
+fn corners(manager: &mut Notifications, terminal: &mut Terminal<TestBackend>) -> Vec<Position> {
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let buffer = terminal.backend().buffer();
+    let area = buffer.area;
+    (area.top()..area.bottom())
+        .flat_map(|y| (area.left()..area.right()).map(move |x| Position::new(x, y)))
+        .filter(|&pos| buffer[pos].symbol() == "╭")
+        .collect()
+}
+
+fn add_fading(manager: &mut Notifications, anchor: Anchor, content: &'static str) -> u64 {
+    let notif = NotificationBuilder::new(content)
+        .anchor(anchor)
+        .animation(Animation::Fade)
+        .build()
+        .unwrap();
+    manager.add(notif).unwrap()
+}
+
+#[test]
+fn test_custom_anchor_position_resolves_percent_and_absolute() {
+    let frame = Rect::new(0, 0, 100, 40);
+    assert_eq!(
+        calculate_anchor_position(quarter_three_quarters(), frame),
+        Position::new(25, 30)
+    );
+
+    let absolute = Anchor::Custom {
+        x: AnchorCoord::Absolute(7),
+        y: AnchorCoord::Absolute(500),
+    };
+    let offset = Rect::new(10, 5, 100, 40);
+    assert_eq!(calculate_anchor_position(absolute, offset), Position::new(17, 44));
+}
+
+#[test]
+fn test_custom_anchor_places_top_left_corner() {
+    let mut manager = Notifications::new();
+    add_fading(&mut manager, quarter_three_quarters(), "Custom");
+    manager.tick(Duration::from_millis(600));
+
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    assert_eq!(corners(&mut manager, &mut terminal), vec![Position::new(25, 30)]);
+}
+
+#[test]
+fn test_custom_anchor_stacks_downward() {
+    let mut manager = Notifications::new();
+    add_fading(&mut manager, quarter_three_quarters(), "First");
+    manager.tick(Duration::from_millis(10));
+    add_fading(&mut manager, quarter_three_quarters(), "Second");
+    manager.tick(Duration::from_millis(600));
+
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    let corners = corners(&mut manager, &mut terminal);
+    assert_eq!(corners.len(), 2);
+    assert_eq!(corners[0], Position::new(25, 30));
+    assert_eq!(corners[1].x, 25);
+    assert!(corners[1].y > corners[0].y);
+}
+
+#[test]
+fn test_custom_anchor_default_slide_is_from_top() {
+    assert_eq!(
+        resolve_slide_direction(SlideDirection::Default, quarter_three_quarters()),
+        SlideDirection::FromTop
+    );
+    assert_eq!(
+        resolve_slide_direction(SlideDirection::FromLeft, quarter_three_quarters()),
+        SlideDirection::FromLeft
+    );
+}
+
+#[test]
+fn test_custom_anchor_hashes_consistently() {
+    // Percentages that quantize to the same basis point are the same anchor
+    assert_eq!(AnchorCoord::percent(25.0), AnchorCoord::percent(25.000_01));
+    assert_eq!(AnchorCoord::percent(f32::NAN), AnchorCoord::BasisPoints(0));
+
+    let mut anchors = HashSet::new();
+    anchors.insert(quarter_three_quarters());
+    anchors.insert(Anchor::Custom {
+        x: AnchorCoord::BasisPoints(2_500),
+        y: AnchorCoord::BasisPoints(7_500),
+    });
+    anchors.insert(Anchor::TopLeft);
+    assert_eq!(anchors.len(), 2);
+}
+
+// FILE: tests/test_custom_anchor_integration.rs - Integration tests for custom anchor positions
+// END OF VERSION: 1.0.0
@@ -0,0 +1,99 @@
+// FILE: tests/test_delay_integration.rs - Integration tests for delayed notification entry
+// VERSION: 1.1.0
+// WCTX: Exact dwell timing
+// CLOG: Display time starts when the entry ends
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    generate_code, AnimationPhase, AutoDismiss, NotificationBuilder, Notifications, Timing,
+};
+use std::time::Duration;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+/// 500ms delay, 200ms entry and exit, shown for 1s
+fn delayed() -> NotificationBuilder {
+    NotificationBuilder::new("Still working...")
+        .delay(ms(500))
+        .timing(Timing::Fixed(ms(200)), Timing::Auto, Timing::Fixed(ms(200)))
+        .auto_dismiss(AutoDismiss::After(ms(1000)))
+}
+
+fn is_blank(manager: &mut Notifications) -> bool {
+    let mut terminal = Terminal::new(TestBackend::new(40, 10)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    terminal.backend().buffer().content().iter().all(|cell| cell.symbol() == " ")
+}
+
+#[test]
+fn test_delay_keeps_the_notification_pending() {
+    let mut manager = Notifications::new();
+    let id = manager.add(delayed().build().unwrap()).unwrap();
+
+    for _ in 0..4 {
+        manager.tick(ms(100));
+        assert_eq!(manager.phase(id), Some(AnimationPhase::Pending));
+        assert!(is_blank(&mut manager));
+    }
+    manager.tick(ms(99));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Pending));
+
+    manager.tick(ms(1));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingIn));
+}
+
+#[test]
+fn test_delay_postpones_the_whole_lifecycle() {
+    let mut manager = Notifications::new();
+    let id = manager.add(delayed().build().unwrap()).unwrap();
+    manager.tick(ms(500));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingIn));
+
+    manager.tick(ms(200));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    manager.tick(ms(999));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    manager.tick(ms(1));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+}
+
+#[test]
+fn test_hidden_notifications_wait_after_show() {
+    let mut manager = Notifications::new();
+    let id = manager.add_hidden(delayed().build().unwrap()).unwrap();
+    manager.tick(ms(1000));
+
+    manager.show(id);
+    manager.tick(ms(499));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Pending));
+    manager.tick(ms(1));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingIn));
+}
+
+#[test]
+fn test_frozen_delay_does_not_elapse() {
+    let mut manager = Notifications::new();
+    let id = manager.add(delayed().build().unwrap()).unwrap();
+    manager.freeze(id);
+    manager.tick(ms(1000));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Pending));
+
+    manager.unfreeze(id);
+    manager.tick(ms(500));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingIn));
+}
+
+#[test]
+fn test_generate_code_emits_delay() {
+    let code = generate_code(&delayed().build().unwrap());
+    assert!(code.contains(".delay(Duration::from_millis(500))"), "{code}");
+
+    let plain = generate_code(&NotificationBuilder::new("x").build().unwrap());
+    assert!(!plain.contains(".delay("));
+}
+
+// FILE: tests/test_delay_integration.rs - Integration tests for delayed notification entry
+// END OF VERSION: 1.1.0
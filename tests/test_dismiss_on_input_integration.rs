@@ -0,0 +1,164 @@
+// FILE: tests/test_dismiss_on_input_integration.rs - Integration tests for dismissing notifications on key presses
+// VERSION: 1.1.0
+// WCTX: Dismiss on input
+// CLOG: Test UserInput reasons and require_ack
+
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEvent, MouseEventKind,
+};
+use ratatui_notifications::notifications::{
+    generate_code, AnimationPhase, AutoDismiss, DismissOnInput, DismissReason, NotificationBuilder,
+    Notifications,
+};
+use std::time::Duration;
+
+fn press(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn settled(manager: &mut Notifications) -> u64 {
+    let notification = NotificationBuilder::new("Saved").auto_dismiss(AutoDismiss::Never);
+    let id = manager.add(notification.build().unwrap()).unwrap();
+    manager.tick(Duration::from_secs(1));
+    id
+}
+
+#[test]
+fn test_off_never_consumes() {
+    let mut manager = Notifications::new();
+    let id = settled(&mut manager);
+
+    assert!(!manager.handle_event(&press(KeyCode::Esc)));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+}
+
+#[test]
+fn test_any_key_dismisses_every_visible_notification() {
+    let mut manager = Notifications::new().dismiss_on_input(DismissOnInput::Any);
+    let first = settled(&mut manager);
+    let second = settled(&mut manager);
+
+    assert!(manager.handle_event(&press(KeyCode::Char('q'))));
+    assert_eq!(manager.phase(first), Some(AnimationPhase::SlidingOut));
+    assert_eq!(manager.phase(second), Some(AnimationPhase::SlidingOut));
+
+    // The exits are animated, and already-leaving notifications don't consume
+    assert!(!manager.handle_event(&press(KeyCode::Enter)));
+    manager.tick(Duration::from_secs(1));
+    assert!(!manager.has_notification());
+}
+
+#[test]
+fn test_keys_only_dismisses_on_listed_keys() {
+    let policy = DismissOnInput::Keys(vec![KeyCode::Esc, KeyCode::Char('x')]);
+    let mut manager = Notifications::new().dismiss_on_input(policy);
+    let id = settled(&mut manager);
+
+    assert!(!manager.handle_event(&press(KeyCode::Char('q'))));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    assert!(manager.handle_event(&press(KeyCode::Char('x'))));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+}
+
+#[test]
+fn test_nothing_visible_is_not_consumed() {
+    let mut manager = Notifications::new().dismiss_on_input(DismissOnInput::Any);
+    assert!(!manager.handle_event(&press(KeyCode::Esc)));
+
+    // Hidden notifications wait in Pending and are not on screen
+    let hidden = manager.add_hidden(NotificationBuilder::new("Later").build().unwrap()).unwrap();
+    assert!(!manager.handle_event(&press(KeyCode::Esc)));
+    assert_eq!(manager.phase(hidden), Some(AnimationPhase::Pending));
+}
+
+#[test]
+fn test_entering_notification_reverses() {
+    let mut manager = Notifications::new().dismiss_on_input(DismissOnInput::Any);
+    let id = manager.add(NotificationBuilder::new("Saved").build().unwrap()).unwrap();
+    manager.tick(Duration::from_millis(50));
+
+    assert!(manager.handle_event(&press(KeyCode::Esc)));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+}
+
+#[test]
+fn test_releases_and_other_events_are_ignored() {
+    let mut manager = Notifications::new().dismiss_on_input(DismissOnInput::Any);
+    let id = settled(&mut manager);
+
+    let mut release = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+    release.kind = KeyEventKind::Release;
+    let mouse = Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Moved,
+        column: 0,
+        row: 0,
+        modifiers: KeyModifiers::NONE,
+    });
+    for event in [Event::Key(release), mouse, Event::FocusGained, Event::Resize(80, 24)] {
+        assert!(!manager.handle_event(&event), "{event:?}");
+    }
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+}
+
+fn settled_ack(manager: &mut Notifications) -> u64 {
+    let notification = NotificationBuilder::new("Unsaved changes were lost")
+        .auto_dismiss(AutoDismiss::Never)
+        .require_ack(true);
+    let id = manager.add(notification.build().unwrap()).unwrap();
+    manager.tick(Duration::from_secs(1));
+    id
+}
+
+#[test]
+fn test_input_dismissal_is_user_input() {
+    let mut manager = Notifications::new()
+        .dismiss_on_input(DismissOnInput::Any)
+        .history_limit(10);
+    let id = settled(&mut manager);
+
+    assert!(manager.handle_event(&press(KeyCode::Enter)));
+    assert_eq!(manager.get(id).unwrap().dismiss_reason, Some(DismissReason::UserInput));
+
+    manager.tick(Duration::from_secs(1));
+    let record = manager.history().find(|record| record.id == id).unwrap();
+    assert_eq!(record.dismiss_reason, Some(DismissReason::UserInput));
+}
+
+#[test]
+fn test_any_key_skips_require_ack() {
+    let mut manager = Notifications::new().dismiss_on_input(DismissOnInput::Any);
+    let ack = settled_ack(&mut manager);
+    let plain = settled(&mut manager);
+
+    assert!(manager.handle_event(&press(KeyCode::Char('x'))));
+    assert_eq!(manager.phase(ack), Some(AnimationPhase::Dwelling));
+    assert_ne!(manager.phase(plain), Some(AnimationPhase::Dwelling));
+
+    // Only the acknowledged notification is left, so nothing is consumed
+    manager.tick(Duration::from_secs(1));
+    assert!(!manager.handle_event(&press(KeyCode::Char('x'))));
+    assert_eq!(manager.phase(ack), Some(AnimationPhase::Dwelling));
+}
+
+#[test]
+fn test_listed_key_dismisses_require_ack() {
+    let mut manager =
+        Notifications::new().dismiss_on_input(DismissOnInput::Keys(vec![KeyCode::Esc]));
+    let ack = settled_ack(&mut manager);
+
+    assert!(manager.handle_event(&press(KeyCode::Esc)));
+    assert_ne!(manager.phase(ack), Some(AnimationPhase::Dwelling));
+    assert_eq!(manager.get(ack).unwrap().dismiss_reason, Some(DismissReason::UserInput));
+}
+
+#[test]
+fn test_require_ack_in_generated_code() {
+    let notification = NotificationBuilder::new("Read me").require_ack(true).build().unwrap();
+    assert!(generate_code(&notification).contains(".require_ack(true)"));
+
+    let plain = NotificationBuilder::new("Read me").build().unwrap();
+    assert!(!generate_code(&plain).contains("require_ack"));
+}
+
+// FILE: tests/test_dismiss_on_input_integration.rs - Integration tests for dismissing notifications on key presses
+// END OF VERSION: 1.1.0
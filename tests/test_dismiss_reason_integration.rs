@@ -0,0 +1,165 @@
+// FILE: tests/test_dismiss_reason_integration.rs - Integration tests for dismiss reasons
+// VERSION: 1.0.0
+// WCTX: Absolute TTL
+// CLOG: Initial creation
+
+use ratatui_notifications::notifications::traits::{Notifier, TestClock};
+use ratatui_notifications::notifications::{
+    Animation, AnimationPhase, AutoDismiss, DismissReason, Level, NotificationBuilder, Notifications,
+};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+type Call = (Option<Level>, u64, DismissReason);
+
+/// Records every dismissal, shared with the test through an `Arc`
+#[derive(Debug, Clone, Default)]
+struct Recording(Arc<Mutex<Vec<Call>>>);
+
+impl Notifier for Recording {
+    fn notify(&mut self, _level: Option<Level>, _id: u64) {}
+
+    fn dismissed(&mut self, level: Option<Level>, id: u64, reason: DismissReason) {
+        self.0.lock().unwrap().push((level, id, reason));
+    }
+}
+
+impl Recording {
+    fn calls(&self) -> Vec<Call> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+/// Advances the clock and the manager together
+fn step(manager: &mut Notifications, clock: &TestClock, by: Duration) {
+    clock.advance(by);
+    manager.tick(by);
+}
+
+fn add(manager: &mut Notifications, auto_dismiss: AutoDismiss, ttl: Option<Duration>) -> u64 {
+    let mut notification = NotificationBuilder::new("Quote")
+        .animation(Animation::Fade)
+        .auto_dismiss(auto_dismiss);
+    if let Some(ttl) = ttl {
+        notification = notification.ttl(ttl);
+    }
+    manager.add(notification.build().unwrap()).unwrap()
+}
+
+fn last_reason(manager: &Notifications, id: u64) -> Option<DismissReason> {
+    manager
+        .history()
+        .find(|record| record.id == id)
+        .and_then(|record| record.dismiss_reason)
+}
+
+#[test]
+fn test_ttl_while_shown_is_expired() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone()).history_limit(10);
+    let id = add(&mut manager, AutoDismiss::Never, Some(Duration::from_secs(2)));
+
+    step(&mut manager, &clock, Duration::from_millis(1900));
+    assert_eq!(manager.get(id).unwrap().dismiss_reason, None);
+
+    step(&mut manager, &clock, Duration::from_millis(100));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::FadingOut));
+    assert_eq!(manager.get(id).unwrap().dismiss_reason, Some(DismissReason::Expired));
+
+    step(&mut manager, &clock, Duration::from_secs(1));
+    assert_eq!(manager.phase(id), None);
+    assert_eq!(last_reason(&manager, id), Some(DismissReason::Expired));
+}
+
+#[test]
+fn test_ttl_before_shown_is_stale() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone()).history_limit(10);
+    let id = add(&mut manager, AutoDismiss::Never, Some(Duration::from_secs(1)));
+
+    clock.advance(Duration::from_secs(5));
+    manager.tick(Duration::from_millis(16));
+    assert_eq!(manager.phase(id), None);
+    assert_eq!(last_reason(&manager, id), Some(DismissReason::Stale));
+}
+
+#[test]
+fn test_ttl_passed_while_frozen_fires_on_unfreeze() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    let id = add(&mut manager, AutoDismiss::Never, Some(Duration::from_secs(2)));
+    step(&mut manager, &clock, Duration::from_secs(1));
+    manager.freeze(id);
+
+    step(&mut manager, &clock, Duration::from_secs(5));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    assert_eq!(manager.get(id).unwrap().dismiss_reason, None);
+
+    manager.unfreeze(id);
+    step(&mut manager, &clock, Duration::from_millis(16));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::FadingOut));
+    assert_eq!(manager.get(id).unwrap().dismiss_reason, Some(DismissReason::Expired));
+}
+
+#[test]
+fn test_display_time_running_out_is_timeout() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone()).history_limit(10);
+    let id = add(&mut manager, AutoDismiss::After(Duration::from_secs(1)), None);
+
+    for _ in 0..10 {
+        step(&mut manager, &clock, Duration::from_millis(500));
+    }
+    assert_eq!(manager.phase(id), None);
+    assert_eq!(last_reason(&manager, id), Some(DismissReason::Timeout));
+}
+
+#[test]
+fn test_remove_is_manual_and_keeps_earlier_reason() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone()).history_limit(10);
+    let removed = add(&mut manager, AutoDismiss::Never, None);
+    let expiring = add(&mut manager, AutoDismiss::Never, Some(Duration::from_secs(1)));
+    step(&mut manager, &clock, Duration::from_millis(600));
+    step(&mut manager, &clock, Duration::from_millis(400));
+    assert_eq!(manager.phase(expiring), Some(AnimationPhase::FadingOut));
+
+    manager.remove(removed);
+    manager.remove(expiring);
+    assert_eq!(last_reason(&manager, removed), Some(DismissReason::Manual));
+    assert_eq!(last_reason(&manager, expiring), Some(DismissReason::Expired));
+}
+
+#[test]
+fn test_eviction_is_reported() {
+    let mut manager = Notifications::new().max_concurrent(Some(1)).history_limit(10);
+    let first = add(&mut manager, AutoDismiss::Never, None);
+    add(&mut manager, AutoDismiss::Never, None);
+    assert_eq!(last_reason(&manager, first), Some(DismissReason::Evicted));
+}
+
+#[test]
+fn test_notifier_hears_each_dismissal_with_its_reason() {
+    let clock = TestClock::new();
+    let recording = Recording::default();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    manager.set_notifier(Box::new(recording.clone()));
+    let stale = add(&mut manager, AutoDismiss::Never, Some(Duration::from_secs(1)));
+    let kept = add(&mut manager, AutoDismiss::Never, None);
+
+    clock.advance(Duration::from_secs(2));
+    manager.tick(Duration::from_millis(16));
+    assert_eq!(recording.calls(), vec![(Some(Level::Info), stale, DismissReason::Stale)]);
+
+    manager.clear();
+    assert_eq!(
+        recording.calls(),
+        vec![
+            (Some(Level::Info), stale, DismissReason::Stale),
+            (Some(Level::Info), kept, DismissReason::Manual),
+        ]
+    );
+}
+
+// FILE: tests/test_dismiss_reason_integration.rs - Integration tests for dismiss reasons
+// END OF VERSION: 1.0.0
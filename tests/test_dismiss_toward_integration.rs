@@ -0,0 +1,114 @@
+// FILE: tests/test_dismiss_toward_integration.rs - Integration tests for collapsing into a point
+// VERSION: 1.0.0
+// WCTX: Dismiss toward a point
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::layout::{Position, Rect};
+use ratatui::Terminal;
+use ratatui_notifications::notifications::functions::fnc_expand_calculate_rect::calculate_rect_toward;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, AnimationPhase, NotificationBuilder, Notifications, Timing,
+};
+use std::time::Duration;
+
+const BELL: Position = Position::new(79, 0);
+
+/// Renders the manager and returns the bounding box of every drawn cell
+fn drawn_bounds(manager: &mut Notifications, terminal: &mut Terminal<TestBackend>) -> Option<Rect> {
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let buffer = terminal.backend().buffer();
+    let area = buffer.area;
+    (area.top()..area.bottom())
+        .flat_map(|y| (area.left()..area.right()).map(move |x| Position::new(x, y)))
+        .filter(|&pos| buffer[pos].symbol() != " ")
+        .map(|pos| Rect::new(pos.x, pos.y, 1, 1))
+        .reduce(Rect::union)
+}
+
+/// A notification dwelling at the bottom-left, rendered once
+fn dwelling(animation: Animation) -> (Notifications, u64, Terminal<TestBackend>) {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("You have mail")
+        .anchor(Anchor::BottomLeft)
+        .animation(animation)
+        .timing(Timing::Auto, Timing::Auto, Timing::Fixed(Duration::from_millis(1000)))
+        .build()
+        .unwrap();
+    let id = manager.add(notif).unwrap();
+    manager.tick(Duration::from_millis(600));
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    drawn_bounds(&mut manager, &mut terminal).unwrap();
+    (manager, id, terminal)
+}
+
+fn distance_to_bell(rect: Rect) -> u32 {
+    u32::from(BELL.x.abs_diff(rect.x)) + u32::from(BELL.y.abs_diff(rect.y))
+}
+
+#[test]
+fn test_calculate_rect_toward_endpoints() {
+    let full_rect = Rect::new(0, 18, 30, 6);
+    let frame_area = Rect::new(0, 0, 80, 24);
+    assert_eq!(calculate_rect_toward(full_rect, frame_area, BELL, 0.0), full_rect);
+    assert_eq!(calculate_rect_toward(full_rect, frame_area, BELL, 1.0), Rect::new(79, 0, 1, 1));
+
+    let halfway = calculate_rect_toward(full_rect, frame_area, BELL, 0.5);
+    assert!(halfway.x > full_rect.x && halfway.y < full_rect.y);
+    assert!(halfway.width < full_rect.width && halfway.height < full_rect.height);
+}
+
+#[test]
+fn test_dismiss_toward_unknown_id() {
+    let mut manager = Notifications::new();
+    assert!(!manager.dismiss_toward(42, BELL));
+}
+
+#[test]
+fn test_dismiss_toward_shrinks_toward_target() {
+    for animation in [Animation::Slide, Animation::Fade, Animation::ExpandCollapse] {
+        let (mut manager, id, mut terminal) = dwelling(animation);
+        let start = drawn_bounds(&mut manager, &mut terminal).unwrap();
+
+        assert!(manager.dismiss_toward(id, BELL));
+        assert_eq!(manager.phase(id), Some(AnimationPhase::exit(animation)));
+
+        let mut previous = start;
+        for _ in 0..4 {
+            manager.tick(Duration::from_millis(200));
+            let Some(bounds) = drawn_bounds(&mut manager, &mut terminal) else {
+                continue;
+            };
+            let area = |r: Rect| u32::from(r.width) * u32::from(r.height);
+            assert!(area(bounds) < area(previous), "{animation:?}: {bounds:?} not smaller than {previous:?}");
+            assert!(
+                distance_to_bell(bounds) < distance_to_bell(previous),
+                "{animation:?}: {bounds:?} not closer to the bell than {previous:?}"
+            );
+            previous = bounds;
+        }
+    }
+}
+
+#[test]
+fn test_dismiss_toward_finishes_normally() {
+    let (mut manager, id, _) = dwelling(Animation::Slide);
+    manager.dismiss_toward(id, BELL);
+
+    manager.tick(Duration::from_millis(1100));
+    assert_eq!(manager.phase(id), None);
+    assert!(!manager.has_notification());
+}
+
+#[test]
+fn test_dismiss_toward_pending_finishes_unseen() {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("Queued").delay(Duration::from_secs(5)).build().unwrap();
+    let id = manager.add(notif).unwrap();
+
+    assert!(manager.dismiss_toward(id, BELL));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Finished));
+}
+
+// FILE: tests/test_dismiss_toward_integration.rs - Integration tests for collapsing into a point
+// END OF VERSION: 1.0.0
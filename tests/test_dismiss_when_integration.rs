@@ -0,0 +1,104 @@
+// FILE: tests/test_dismiss_when_integration.rs - Integration tests for predicate-based dismissal
+// VERSION: 1.0.0
+// WCTX: Conditional dismissal
+// CLOG: Initial creation
+
+use ratatui_notifications::notifications::{
+    AnimationPhase, AutoDismiss, NotificationBuilder, Notifications, Timing,
+};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+/// Never auto-dismissed, 200ms entry and exit
+fn waiting() -> NotificationBuilder {
+    NotificationBuilder::new("Connection lost")
+        .timing(Timing::Fixed(ms(200)), Timing::Auto, Timing::Fixed(ms(200)))
+        .auto_dismiss(AutoDismiss::Never)
+}
+
+#[test]
+fn test_dwells_until_the_predicate_flips() {
+    let connected = Arc::new(AtomicBool::new(false));
+    let mut manager = Notifications::new();
+    let id = manager.add(waiting().build().unwrap()).unwrap();
+    let flag = Arc::clone(&connected);
+    assert!(manager.dismiss_when(id, move || flag.load(Ordering::Relaxed)));
+
+    for _ in 0..50 {
+        manager.tick(ms(100));
+    }
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+
+    connected.store(true, Ordering::Relaxed);
+    manager.tick(ms(16));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+    manager.tick(ms(200));
+    assert_eq!(manager.phase(id), None);
+}
+
+#[test]
+fn test_predicate_can_end_the_entry_early() {
+    let mut manager = Notifications::new();
+    let id = manager.add(waiting().build().unwrap()).unwrap();
+    manager.tick(ms(100));
+    manager.dismiss_when(id, || true);
+
+    manager.tick(ms(16));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+}
+
+#[test]
+fn test_time_based_dismissal_still_applies() {
+    let mut manager = Notifications::new();
+    let notification = waiting().auto_dismiss(AutoDismiss::After(ms(500))).build().unwrap();
+    let id = manager.add(notification).unwrap();
+    manager.dismiss_when(id, || false);
+
+    manager.tick(ms(200));
+    manager.tick(ms(500));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+}
+
+#[test]
+fn test_predicate_is_dropped_after_firing() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut manager = Notifications::new();
+    let id = manager.add(waiting().build().unwrap()).unwrap();
+    manager.tick(ms(200));
+    let counter = Arc::clone(&calls);
+    manager.dismiss_when(id, move || counter.fetch_add(1, Ordering::Relaxed) >= 2);
+
+    for _ in 0..5 {
+        manager.tick(ms(16));
+    }
+    assert_eq!(calls.load(Ordering::Relaxed), 3);
+}
+
+#[test]
+fn test_frozen_notification_ignores_the_predicate() {
+    let mut manager = Notifications::new();
+    let id = manager.add(waiting().build().unwrap()).unwrap();
+    manager.tick(ms(200));
+    manager.dismiss_when(id, || true);
+    manager.freeze(id);
+
+    manager.tick(ms(16));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    manager.unfreeze(id);
+    manager.tick(ms(16));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+}
+
+#[test]
+fn test_unknown_id_is_reported() {
+    let mut manager = Notifications::new();
+    assert!(!manager.dismiss_when(7, || true));
+}
+
+// FILE: tests/test_dismiss_when_integration.rs - Integration tests for predicate-based dismissal
+// END OF VERSION: 1.0.0
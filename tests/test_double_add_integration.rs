@@ -0,0 +1,108 @@
+// FILE: tests/test_double_add_integration.rs - Integration tests for adding the same notification twice
+// VERSION: 1.0.0
+// WCTX: Double-add semantics
+// CLOG: Initial creation
+
+use ratatui_notifications::notifications::{
+    AnimationPhase, AutoDismiss, NotificationBuilder, Notifications, Overflow, Timing,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+/// 200ms entry and exit, shown for 1s
+fn saved() -> NotificationBuilder {
+    NotificationBuilder::new("Saved")
+        .timing(Timing::Fixed(ms(200)), Timing::Auto, Timing::Fixed(ms(200)))
+        .auto_dismiss(AutoDismiss::After(ms(1000)))
+}
+
+#[test]
+fn test_each_add_is_an_independent_instance() {
+    let notification = saved().build().unwrap();
+    let mut manager = Notifications::new();
+    let first = manager.add(notification.clone()).unwrap();
+    manager.tick(ms(500));
+    let second = manager.add(notification).unwrap();
+
+    assert_ne!(first, second);
+    // Each instance keeps its own clock
+    manager.tick(ms(700));
+    assert_eq!(manager.phase(first), Some(AnimationPhase::SlidingOut));
+    assert_eq!(manager.phase(second), Some(AnimationPhase::Dwelling));
+
+    assert!(manager.remove(second));
+    assert!(manager.phase(first).is_some());
+}
+
+#[test]
+fn test_merge_duplicates_folds_the_second_add() {
+    let notification = saved().build().unwrap();
+    let mut manager = Notifications::new().overflow(Overflow::MergeDuplicates);
+    let first = manager.add(notification.clone()).unwrap();
+    let second = manager.add(notification).unwrap();
+
+    assert_eq!(first, second);
+    assert_eq!(manager.drain().len(), 1);
+}
+
+#[test]
+fn test_merge_duplicates_adds_again_once_the_first_is_gone() {
+    let notification = saved().build().unwrap();
+    let mut manager = Notifications::new().overflow(Overflow::MergeDuplicates);
+    let first = manager.add(notification.clone()).unwrap();
+    manager.tick(ms(1200));
+    // Exiting notifications do not take merges
+    assert_eq!(manager.phase(first), Some(AnimationPhase::SlidingOut));
+
+    let second = manager.add(notification).unwrap();
+    assert_ne!(first, second);
+}
+
+#[test]
+fn test_custom_ids_stay_per_add() {
+    let notification = saved().build().unwrap();
+    let mut manager = Notifications::new();
+    let chosen = manager.add_with_id(10, notification.clone()).unwrap();
+    let next = manager.add(notification).unwrap();
+
+    assert_eq!((chosen, next), (10, 11));
+}
+
+#[test]
+fn test_custom_id_is_never_merged() {
+    let notification = saved().build().unwrap();
+    let mut manager = Notifications::new().overflow(Overflow::MergeDuplicates);
+    manager.add(notification.clone()).unwrap();
+
+    assert_eq!(manager.add_with_id(10, notification), Ok(10));
+    assert_eq!(manager.drain().len(), 2);
+}
+
+#[test]
+fn test_shared_live_content_runs_once_per_instance() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let notification = saved()
+        .live_content(Box::new(move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+            "Saving".to_string()
+        }))
+        .build()
+        .unwrap();
+    let mut manager = Notifications::new();
+    manager.add(notification.clone()).unwrap();
+    manager.add(notification).unwrap();
+
+    manager.tick(ms(16));
+    assert_eq!(calls.load(Ordering::Relaxed), 2);
+    manager.tick(ms(16));
+    assert_eq!(calls.load(Ordering::Relaxed), 4);
+}
+
+// FILE: tests/test_double_add_integration.rs - Integration tests for adding the same notification twice
+// END OF VERSION: 1.0.0
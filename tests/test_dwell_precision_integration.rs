@@ -0,0 +1,110 @@
+// FILE: tests/test_dwell_precision_integration.rs - Integration tests for exact dwell timing
+// VERSION: 1.0.0
+// WCTX: Exact dwell timing
+// CLOG: Initial creation
+
+use ratatui_notifications::notifications::{
+    AnimationPhase, AutoDismiss, NotificationBuilder, Notifications, Timing,
+};
+use std::time::Duration;
+
+const ENTRY: Duration = Duration::from_millis(500);
+const DISPLAY: Duration = Duration::from_secs(1);
+
+/// Frame intervals that do not divide the entry or display time evenly
+/// as well as ones that do
+fn tick_sizes() -> [Duration; 6] {
+    [
+        Duration::from_millis(1),
+        Duration::from_millis(7),
+        Duration::from_millis(16),
+        Duration::from_nanos(16_666_667),
+        Duration::from_millis(33),
+        Duration::from_millis(100),
+    ]
+}
+
+fn timed() -> NotificationBuilder {
+    NotificationBuilder::new("Saved")
+        .timing(Timing::Fixed(ENTRY), Timing::Auto, Timing::Fixed(ENTRY))
+        .auto_dismiss(AutoDismiss::After(DISPLAY))
+}
+
+/// Ticks `tick` until the notification leaves `phase`, returning the total
+/// time ticked
+fn tick_through(manager: &mut Notifications, id: u64, phase: AnimationPhase, tick: Duration) -> Duration {
+    let mut total = Duration::ZERO;
+    while manager.phase(id) == Some(phase) {
+        manager.tick(tick);
+        total += tick;
+        assert!(total < Duration::from_secs(10), "stuck in {phase:?}");
+    }
+    total
+}
+
+#[test]
+fn test_exit_starts_on_the_first_tick_past_entry_and_display() {
+    for tick in tick_sizes() {
+        let mut manager = Notifications::new();
+        let id = manager.add(timed().build().unwrap()).unwrap();
+
+        let total = tick_through(&mut manager, id, AnimationPhase::Pending, tick)
+            + tick_through(&mut manager, id, AnimationPhase::SlidingIn, tick)
+            + tick_through(&mut manager, id, AnimationPhase::Dwelling, tick);
+
+        // Neither early nor a tick late
+        assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut), "{tick:?}");
+        assert!(total >= ENTRY + DISPLAY, "{tick:?}: exit began early at {total:?}");
+        assert!(total < ENTRY + DISPLAY + tick, "{tick:?}: exit began late at {total:?}");
+    }
+}
+
+#[test]
+fn test_small_ticks_summing_to_the_dwell_dismiss_exactly() {
+    for tick in [Duration::from_millis(1), Duration::from_millis(4), Duration::from_millis(20)] {
+        let mut manager = Notifications::new();
+        let id = manager.add(timed().build().unwrap()).unwrap();
+        manager.tick(ENTRY);
+        assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+
+        let ticks = DISPLAY.as_nanos() / tick.as_nanos();
+        for _ in 1..ticks {
+            manager.tick(tick);
+        }
+        assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling), "{tick:?}: dismissed early");
+        manager.tick(tick);
+        assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut), "{tick:?}: dismissed late");
+    }
+}
+
+#[test]
+fn test_entry_overshoot_counts_towards_display_time() {
+    let mut manager = Notifications::new();
+    let id = manager.add(timed().build().unwrap()).unwrap();
+
+    // 300ms past the entry already count
+    manager.tick(Duration::from_millis(800));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    manager.tick(Duration::from_millis(699));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    manager.tick(Duration::from_millis(1));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+}
+
+#[test]
+fn test_exit_completes_on_the_exact_tick() {
+    for tick in tick_sizes() {
+        let mut manager = Notifications::new();
+        let id = manager.add(timed().build().unwrap()).unwrap();
+        tick_through(&mut manager, id, AnimationPhase::Pending, tick);
+        tick_through(&mut manager, id, AnimationPhase::SlidingIn, tick);
+        tick_through(&mut manager, id, AnimationPhase::Dwelling, tick);
+
+        let exit = tick_through(&mut manager, id, AnimationPhase::SlidingOut, tick);
+        assert_eq!(manager.phase(id), None, "{tick:?}");
+        assert!(exit >= ENTRY && exit < ENTRY + tick, "{tick:?}: exit took {exit:?}");
+    }
+}
+
+// FILE: tests/test_dwell_precision_integration.rs - Integration tests for exact dwell timing
+// END OF VERSION: 1.0.0
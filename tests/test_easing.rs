@@ -0,0 +1,70 @@
+// FILE: tests/test_easing.rs - Integration tests for the public easing module
+// VERSION: 1.0.0
+// WCTX: Public easing module
+// CLOG: Initial creation
+
+use ratatui_notifications::easing::{
+    ease_in_out_quad, ease_in_quad, ease_out_quad, lerp, sample, Easing,
+};
+
+#[test]
+fn test_curves_clamp_out_of_range_progress() {
+    for t in [-1.0, -0.01, f32::NEG_INFINITY] {
+        assert_eq!(ease_in_quad(t), 0.0);
+        assert_eq!(ease_out_quad(t), 0.0);
+        assert_eq!(ease_in_out_quad(t), 0.0);
+    }
+    for t in [1.01, 2.0, f32::INFINITY] {
+        assert_eq!(ease_in_quad(t), 1.0);
+        assert_eq!(ease_out_quad(t), 1.0);
+        assert_eq!(ease_in_out_quad(t), 1.0);
+    }
+}
+
+#[test]
+fn test_lerp_clamps_instead_of_extrapolating() {
+    assert_eq!(lerp(10.0, 20.0, -1.0), 10.0);
+    assert_eq!(lerp(10.0, 20.0, 3.0), 20.0);
+    // Also holds for descending ranges
+    assert_eq!(lerp(20.0, 10.0, 2.0), 10.0);
+    assert_eq!(lerp(20.0, 10.0, 0.25), 17.5);
+}
+
+#[test]
+fn test_every_easing_spans_zero_to_one() {
+    for easing in Easing::ALL {
+        assert_eq!(easing.apply(0.0), 0.0, "{easing:?}");
+        assert_eq!(easing.apply(1.0), 1.0, "{easing:?}");
+    }
+}
+
+#[test]
+fn test_sample_is_monotonic_and_inclusive() {
+    for easing in Easing::ALL {
+        let points = sample(easing, 11);
+        assert_eq!(points.len(), 11);
+        assert_eq!(points[0], 0.0);
+        assert_eq!(points[10], 1.0);
+        assert!(points.windows(2).all(|pair| pair[0] <= pair[1]), "{easing:?}");
+    }
+}
+
+#[test]
+fn test_sample_edge_step_counts() {
+    assert!(sample(Easing::EaseOutQuad, 0).is_empty());
+    assert_eq!(sample(Easing::EaseOutQuad, 1), vec![1.0]);
+    assert_eq!(sample(Easing::EaseOutQuad, 2), vec![0.0, 1.0]);
+}
+
+#[test]
+fn test_sample_shapes_differ() {
+    let linear = sample(Easing::Linear, 5);
+    let ease_in = sample(Easing::EaseInQuad, 5);
+    let ease_out = sample(Easing::EaseOutQuad, 5);
+
+    assert!(ease_in[2] < linear[2] && linear[2] < ease_out[2]);
+    assert_eq!(sample(Easing::EaseInOutQuad, 3)[1], 0.5);
+}
+
+// FILE: tests/test_easing.rs - Integration tests for the public easing module
+// END OF VERSION: 1.0.0
@@ -0,0 +1,105 @@
+// FILE: tests/test_elapsed_integration.rs - Integration tests for the elapsed time display
+// VERSION: 1.1.0
+// WCTX: Deterministic test clock
+// CLOG: Replaced the local ManualClock with TestClock
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::traits::TestClock;
+use ratatui_notifications::notifications::{
+    generate_code, Anchor, AutoDismiss, NotificationBuilder, Notifications,
+};
+use std::time::Duration;
+
+/// Advances the clock and the manager together
+fn step(manager: &mut Notifications, clock: &TestClock, by: Duration) {
+    clock.advance(by);
+    manager.tick(by);
+}
+
+fn running() -> NotificationBuilder {
+    NotificationBuilder::new("Running for")
+        .anchor(Anchor::TopLeft)
+        .auto_dismiss(AutoDismiss::Never)
+        .show_elapsed(true)
+}
+
+fn screen(manager: &mut Notifications) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(60, 8)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..8)
+        .map(|y| (0..60).map(|x| buffer[(x, y)].symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn test_elapsed_time_counts_up_across_ticks() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    manager.add(running().build().unwrap()).unwrap();
+
+    step(&mut manager, &clock, Duration::from_secs(1));
+    let first = screen(&mut manager);
+    assert!(first.contains("Running for 00:01"), "{first}");
+
+    step(&mut manager, &clock, Duration::from_secs(41));
+    let later = screen(&mut manager);
+    assert!(later.contains("Running for 00:42"), "{later}");
+
+    step(&mut manager, &clock, Duration::from_secs(60));
+    let rows = screen(&mut manager);
+    assert!(rows.contains("Running for 01:42"), "{rows}");
+}
+
+#[test]
+fn test_elapsed_time_is_wall_clock_not_tick_time() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    manager.add(running().build().unwrap()).unwrap();
+    step(&mut manager, &clock, Duration::from_secs(1));
+
+    // A stall: the clock moved on while ticks reported little time
+    clock.advance(Duration::from_secs(74));
+    manager.tick(Duration::from_millis(16));
+
+    let rows = screen(&mut manager);
+    assert!(rows.contains("Running for 01:15"), "{rows}");
+}
+
+#[test]
+fn test_elapsed_time_is_off_by_default() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    let notification = running().show_elapsed(false).build().unwrap();
+    manager.add(notification).unwrap();
+    step(&mut manager, &clock, Duration::from_secs(5));
+
+    let rows = screen(&mut manager);
+    assert!(rows.contains("Running for") && !rows.contains("00:05"), "{rows}");
+}
+
+#[test]
+fn test_remove_ends_elapsed_notification() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    let id = manager.add(running().build().unwrap()).unwrap();
+    step(&mut manager, &clock, Duration::from_secs(600));
+    assert!(manager.phase(id).is_some());
+
+    assert!(manager.remove(id));
+    assert!(!screen(&mut manager).contains("Running for"));
+}
+
+#[test]
+fn test_generate_code_emits_show_elapsed() {
+    let code = generate_code(&running().build().unwrap());
+    assert!(code.contains(".show_elapsed(true)"), "{code}");
+
+    let plain = generate_code(&NotificationBuilder::new("x").build().unwrap());
+    assert!(!plain.contains("show_elapsed"));
+}
+
+// FILE: tests/test_elapsed_integration.rs - Integration tests for the elapsed time display
+// END OF VERSION: 1.1.0
@@ -0,0 +1,117 @@
+// FILE: tests/test_enforce_limits_integration.rs - Integration tests for enforcing lowered limits
+// VERSION: 1.0.0
+// WCTX: Runtime limit enforcement
+// CLOG: Initial creation
+
+use ratatui_notifications::notifications::{
+    Anchor, AnimationPhase, AutoDismiss, NotificationBuilder, Notifications, Overflow,
+};
+use std::time::Duration;
+
+/// Adds `count` settled notifications at `anchor`, oldest first
+fn fill(manager: &mut Notifications, anchor: Anchor, count: usize) -> Vec<u64> {
+    let ids = (0..count)
+        .map(|n| {
+            let notification = NotificationBuilder::new(format!("Job {n} done"))
+                .anchor(anchor)
+                .auto_dismiss(AutoDismiss::Never);
+            manager.add(notification.build().unwrap()).unwrap()
+        })
+        .collect();
+    manager.tick(Duration::from_secs(1));
+    ids
+}
+
+fn phases(manager: &Notifications, ids: &[u64]) -> Vec<Option<AnimationPhase>> {
+    ids.iter().map(|&id| manager.phase(id)).collect()
+}
+
+const OUT: Option<AnimationPhase> = Some(AnimationPhase::SlidingOut);
+const SHOWN: Option<AnimationPhase> = Some(AnimationPhase::Dwelling);
+
+#[test]
+fn test_lowering_the_limit_alone_changes_nothing() {
+    let mut manager = Notifications::new();
+    let ids = fill(&mut manager, Anchor::TopRight, 5);
+
+    manager = manager.max_concurrent(Some(2));
+    manager.tick(Duration::from_millis(16));
+    assert!(phases(&manager, &ids).iter().all(|&phase| phase == SHOWN));
+}
+
+#[test]
+fn test_discard_oldest_exits_the_oldest() {
+    let mut manager = Notifications::new();
+    let ids = fill(&mut manager, Anchor::TopRight, 5);
+
+    manager = manager.max_concurrent(Some(2));
+    manager.enforce_limits();
+    assert_eq!(phases(&manager, &ids), vec![OUT, OUT, OUT, SHOWN, SHOWN]);
+
+    // The exits are animated, then the excess is gone
+    manager.tick(Duration::from_secs(1));
+    assert_eq!(phases(&manager, &ids), vec![None, None, None, SHOWN, SHOWN]);
+}
+
+#[test]
+fn test_discard_newest_exits_the_newest() {
+    let mut manager = Notifications::new().overflow(Overflow::DiscardNewest);
+    let ids = fill(&mut manager, Anchor::TopRight, 5);
+
+    manager = manager.max_concurrent(Some(2));
+    manager.enforce_limits();
+    assert_eq!(phases(&manager, &ids), vec![SHOWN, SHOWN, OUT, OUT, OUT]);
+}
+
+#[test]
+fn test_limit_applies_per_anchor() {
+    let mut manager = Notifications::new();
+    let top = fill(&mut manager, Anchor::TopRight, 3);
+    let bottom = fill(&mut manager, Anchor::BottomLeft, 2);
+
+    manager = manager.max_concurrent(Some(2));
+    manager.enforce_limits();
+    assert_eq!(phases(&manager, &top), vec![OUT, SHOWN, SHOWN]);
+    assert_eq!(phases(&manager, &bottom), vec![SHOWN, SHOWN]);
+}
+
+#[test]
+fn test_total_limit_spans_anchors() {
+    let mut manager = Notifications::new();
+    let top = fill(&mut manager, Anchor::TopRight, 3);
+    let bottom = fill(&mut manager, Anchor::BottomLeft, 2);
+
+    manager = manager.max_total(Some(3));
+    manager.enforce_limits();
+    assert_eq!(phases(&manager, &top), vec![OUT, OUT, SHOWN]);
+    assert_eq!(phases(&manager, &bottom), vec![SHOWN, SHOWN]);
+}
+
+#[test]
+fn test_leaving_notifications_do_not_count() {
+    let mut manager = Notifications::new();
+    let ids = fill(&mut manager, Anchor::TopRight, 4);
+    let first = ids[0];
+    manager.retain(|info| info.id != first);
+
+    manager = manager.max_concurrent(Some(3));
+    manager.enforce_limits();
+    assert_eq!(phases(&manager, &ids), vec![OUT, SHOWN, SHOWN, SHOWN]);
+}
+
+#[test]
+fn test_entering_excess_reverses() {
+    let mut manager = Notifications::new();
+    let old = fill(&mut manager, Anchor::TopRight, 2);
+    let entering = NotificationBuilder::new("New").anchor(Anchor::TopRight).build().unwrap();
+    let entering = manager.add(entering).unwrap();
+    manager.tick(Duration::from_millis(50));
+
+    manager = manager.max_concurrent(Some(1)).overflow(Overflow::DiscardNewest);
+    manager.enforce_limits();
+    assert_eq!(manager.phase(entering), OUT);
+    assert_eq!(phases(&manager, &old), vec![SHOWN, OUT]);
+}
+
+// FILE: tests/test_enforce_limits_integration.rs - Integration tests for enforcing lowered limits
+// END OF VERSION: 1.0.0
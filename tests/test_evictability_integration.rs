@@ -0,0 +1,163 @@
+// FILE: tests/test_evictability_integration.rs - Integration tests for per-notification evictability
+// VERSION: 1.1.0
+// WCTX: Suppressed notifications
+// CLOG: Parked notifications without room wait instead of being dropped
+
+use ratatui_notifications::notifications::{
+    Anchor, AnimationPhase, AutoDismiss, Evictability, Level, NotificationBuilder,
+    NotificationError, Notifications, Overflow,
+};
+use std::time::Duration;
+
+use Evictability::{First, Never, Normal};
+
+const POLICIES: [Overflow; 4] = [
+    Overflow::DiscardOldest,
+    Overflow::DiscardNewest,
+    Overflow::MergeDuplicates,
+    Overflow::DiscardLowestPriority,
+];
+
+fn add(manager: &mut Notifications, evictable: Evictability, level: Level) -> Result<u64, NotificationError> {
+    let notification = NotificationBuilder::new(format!("{evictable:?} {level:?} #{}", manager.infos().len()))
+        .level(level)
+        .anchor(Anchor::TopRight)
+        .evictable(evictable)
+        .auto_dismiss(AutoDismiss::Never);
+    manager.add(notification.build().unwrap())
+}
+
+/// A manager with max_concurrent 2 holding the given notifications, oldest first
+fn full(overflow: Overflow, existing: [(Evictability, Level); 2]) -> (Notifications, Vec<u64>) {
+    let mut manager = Notifications::new().max_concurrent(Some(2)).overflow(overflow);
+    let ids = existing
+        .iter()
+        .map(|&(evictable, level)| {
+            let id = add(&mut manager, evictable, level).unwrap();
+            manager.tick(Duration::from_millis(100));
+            id
+        })
+        .collect();
+    manager.tick(Duration::from_secs(1));
+    (manager, ids)
+}
+
+fn managed(manager: &Notifications) -> Vec<u64> {
+    manager.infos().iter().map(|info| info.id).collect()
+}
+
+#[test]
+fn test_first_is_evicted_before_normal_under_every_policy() {
+    for overflow in POLICIES {
+        for existing in [[(Normal, Level::Info), (First, Level::Info)], [(First, Level::Info), (Normal, Level::Info)]] {
+            let (mut manager, ids) = full(overflow, existing);
+            let first = ids[existing.iter().position(|&(class, _)| class == First).unwrap()];
+            let normal = ids[existing.iter().position(|&(class, _)| class == Normal).unwrap()];
+
+            let new = add(&mut manager, Normal, Level::Info).unwrap();
+            assert_eq!(managed(&manager), vec![normal, new], "{overflow:?} with {existing:?}");
+            assert!(!managed(&manager).contains(&first));
+        }
+    }
+}
+
+#[test]
+fn test_never_is_passed_over_for_normal_and_first() {
+    for overflow in POLICIES {
+        for other in [Normal, First] {
+            let (mut manager, ids) = full(overflow, [(Never, Level::Info), (other, Level::Info)]);
+            let new = add(&mut manager, First, Level::Info).unwrap();
+            assert_eq!(managed(&manager), vec![ids[0], new], "{overflow:?} with {other:?}");
+        }
+    }
+}
+
+#[test]
+fn test_only_never_rejects_the_add() {
+    for overflow in POLICIES {
+        let (mut manager, ids) = full(overflow, [(Never, Level::Info), (Never, Level::Info)]);
+        for evictable in [Normal, Never, First] {
+            assert_eq!(add(&mut manager, evictable, Level::Error), Err(NotificationError::LimitReached));
+        }
+        assert_eq!(managed(&manager), ids, "{overflow:?}");
+    }
+}
+
+#[test]
+fn test_evictability_trumps_priority() {
+    // The First error outranks the Normal info, but goes first anyway
+    let (mut manager, ids) = full(
+        Overflow::DiscardLowestPriority,
+        [(Normal, Level::Info), (First, Level::Error)],
+    );
+    let new = add(&mut manager, Normal, Level::Warn).unwrap();
+    assert_eq!(managed(&manager), vec![ids[0], new]);
+
+    // Within a class, priority still decides
+    let (mut manager, ids) = full(
+        Overflow::DiscardLowestPriority,
+        [(Normal, Level::Error), (Normal, Level::Info)],
+    );
+    let new = add(&mut manager, Normal, Level::Warn).unwrap();
+    assert_eq!(managed(&manager), vec![ids[0], new]);
+}
+
+#[test]
+fn test_max_total_respects_evictability() {
+    let mut manager = Notifications::new().max_total(Some(2));
+    let never = add(&mut manager, Never, Level::Info).unwrap();
+    let first = add(&mut manager, First, Level::Info).unwrap();
+    let normal = add(&mut manager, Normal, Level::Info).unwrap();
+    assert_eq!(managed(&manager), vec![never, normal]);
+    assert!(!managed(&manager).contains(&first));
+
+    let mut manager = Notifications::new().max_total(Some(1));
+    add(&mut manager, Never, Level::Info).unwrap();
+    assert_eq!(add(&mut manager, Normal, Level::Info), Err(NotificationError::LimitReached));
+}
+
+#[test]
+fn test_enforce_limits_keeps_never() {
+    let mut manager = Notifications::new();
+    let never = add(&mut manager, Never, Level::Info).unwrap();
+    let normal = add(&mut manager, Normal, Level::Info).unwrap();
+    let first = add(&mut manager, First, Level::Info).unwrap();
+    manager.tick(Duration::from_secs(1));
+
+    manager = manager.max_concurrent(Some(2));
+    manager.enforce_limits();
+    let out = Some(AnimationPhase::SlidingOut);
+    let shown = Some(AnimationPhase::Dwelling);
+    assert_eq!(
+        [never, normal, first].map(|id| manager.phase(id)),
+        [shown, shown, out]
+    );
+
+    // Nothing but Never left to cut
+    manager = manager.max_concurrent(Some(0));
+    manager.enforce_limits();
+    assert_eq!(manager.phase(never), shown);
+    assert_eq!(manager.phase(normal), out);
+}
+
+#[test]
+fn test_parked_notification_without_room_stays_parked() {
+    let mut manager = Notifications::new().max_concurrent(Some(1));
+    let never = add(&mut manager, Never, Level::Info).unwrap();
+    manager.suppress(true);
+    let parked = add(&mut manager, Normal, Level::Info).unwrap();
+    assert_eq!(manager.parked_count(), 1);
+
+    manager.suppress(false);
+    manager.tick(Duration::from_secs(1));
+    assert_eq!(manager.parked_count(), 1);
+    assert_eq!(managed(&manager), vec![never]);
+
+    manager.remove(never);
+    manager.tick(Duration::from_millis(100));
+    assert_eq!(manager.parked_count(), 0);
+    assert_eq!(managed(&manager), vec![parked]);
+}
+
+// FILE: tests/test_evictability_integration.rs - Integration tests for per-notification evictability
+// END OF VERSION: 1.1.0
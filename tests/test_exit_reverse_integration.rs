@@ -0,0 +1,131 @@
+// FILE: tests/test_exit_reverse_integration.rs - Integration tests for exit_reverse
+// VERSION: 1.0.0
+// WCTX: Reverse-out exits
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::layout::{Position, Rect};
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    generate_code, Anchor, Animation, AnimationPhase, AutoDismiss, NotificationBuilder, NotificationError, Notifications,
+    SlideDirection, Timing,
+};
+use std::time::Duration;
+
+const WIDTH: u16 = 80;
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+fn builder(anchor: Anchor) -> NotificationBuilder {
+    NotificationBuilder::new("Reversible")
+        .anchor(anchor)
+        .animation(Animation::Slide)
+        .timing(Timing::Fixed(ms(200)), Timing::Auto, Timing::Fixed(ms(200)))
+        .auto_dismiss(AutoDismiss::After(ms(1000)))
+}
+
+/// Columns spanned by drawn cells, or `None` when nothing is drawn.
+fn drawn_columns(manager: &mut Notifications) -> Option<(u16, u16)> {
+    let mut terminal = Terminal::new(TestBackend::new(WIDTH, 24)).unwrap();
+    terminal
+        .draw(|frame| manager.render(frame, frame.area()))
+        .unwrap();
+    let buffer = terminal.backend().buffer();
+    let columns: Vec<u16> = (0..buffer.area.height)
+        .flat_map(|y| (0..buffer.area.width).map(move |x| (x, y)))
+        .filter(|&(x, y)| buffer[(x, y)].symbol() != " ")
+        .map(|(x, _)| x)
+        .collect();
+    Some((*columns.iter().min()?, *columns.iter().max()?))
+}
+
+/// Settles the notification, then renders it halfway through its exit.
+fn mid_exit(manager: &mut Notifications, id: u64, settle: impl FnOnce(&mut Notifications)) -> (Rect, (u16, u16)) {
+    manager.tick(ms(300));
+    drawn_columns(manager);
+    settle(manager);
+    manager.tick(ms(400));
+    drawn_columns(manager);
+    let settled = manager.get(id).unwrap().rect.unwrap();
+
+    // The exit starts once the 1000ms display time is used up
+    manager.tick(ms(600));
+    assert_eq!(manager.get(id).unwrap().phase, AnimationPhase::SlidingOut);
+    manager.tick(ms(100));
+    let columns = drawn_columns(manager).unwrap();
+    (settled, columns)
+}
+
+#[test]
+fn test_from_left_entry_reverses_out_to_the_left() {
+    let mut manager = Notifications::new();
+    let notif = builder(Anchor::MiddleCenter)
+        .slide_direction(SlideDirection::FromLeft)
+        .exit_reverse(true)
+        .build()
+        .unwrap();
+    let id = manager.add(notif).unwrap();
+
+    let (settled, (left, right)) = mid_exit(&mut manager, id, |_| {});
+    assert!(right < settled.right() - 1, "exit moved right: {left}..{right} vs {settled:?}");
+    assert!(left < settled.x);
+}
+
+#[test]
+fn test_reverse_exit_survives_anchor_move() {
+    let run = |reverse: bool| {
+        let mut manager = Notifications::new().move_duration(Duration::ZERO);
+        let notif = builder(Anchor::MiddleLeft).exit_reverse(reverse).build().unwrap();
+        let id = manager.add(notif).unwrap();
+        mid_exit(&mut manager, id, |manager| {
+            manager.move_to_anchor(id, Anchor::MiddleRight);
+        })
+    };
+
+    // Without reversing, the exit follows the new anchor's side
+    let (settled, (left, _)) = run(false);
+    assert!(left > settled.x);
+
+    // Reversed, it leaves through the left, where it entered
+    let (settled, (left, right)) = run(true);
+    assert!(left < settled.x);
+    assert!(right < WIDTH - 1);
+}
+
+#[test]
+fn test_reverse_exit_returns_to_entry_position() {
+    let mut manager = Notifications::new();
+    let notif = builder(Anchor::MiddleRight)
+        .entry_position(Position::new(0, 10))
+        .exit_reverse(true)
+        .build()
+        .unwrap();
+    let id = manager.add(notif).unwrap();
+
+    let (settled, (left, _)) = mid_exit(&mut manager, id, |_| {});
+    assert!(left < settled.x);
+}
+
+#[test]
+fn test_exit_reverse_conflicts_with_exit_position() {
+    let result = builder(Anchor::TopLeft)
+        .exit_position(Position::new(0, 0))
+        .exit_reverse(true)
+        .build();
+    assert!(matches!(result, Err(NotificationError::InvalidConfig(_))));
+}
+
+#[test]
+fn test_exit_reverse_in_generated_code() {
+    let notif = builder(Anchor::TopLeft).exit_reverse(true).build().unwrap();
+    assert!(notif.exit_reverse());
+    assert!(generate_code(&notif).contains(".exit_reverse(true)"));
+
+    let plain = builder(Anchor::TopLeft).build().unwrap();
+    assert!(!generate_code(&plain).contains("exit_reverse"));
+}
+
+// FILE: tests/test_exit_reverse_integration.rs - Integration tests for exit_reverse
+// END OF VERSION: 1.0.0
@@ -0,0 +1,98 @@
+// FILE: tests/test_exit_style_integration.rs - Integration tests for the exit-phase style
+// VERSION: 1.1.0
+// WCTX: Exact dwell timing
+// CLOG: Display time starts when the entry ends
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Cell;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, AnimationPhase, AutoDismiss, NotificationBuilder, Notifications, Timing,
+};
+use std::time::Duration;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+fn receding() -> Style {
+    Style::new().fg(Color::DarkGray).add_modifier(Modifier::DIM)
+}
+
+/// Cyan-bordered notification, 1s entry/exit, dismissed after 2s; the
+/// tests look at the first frame of the exit, before the box has moved
+fn timed() -> NotificationBuilder {
+    NotificationBuilder::new("Saved")
+        .anchor(Anchor::TopLeft)
+        .border_style(Style::new().fg(Color::Cyan))
+        .timing(Timing::Fixed(ms(1000)), Timing::Auto, Timing::Fixed(ms(1000)))
+        .auto_dismiss(AutoDismiss::After(ms(2000)))
+}
+
+/// Top-left border cell of the notification as drawn now
+fn corner(manager: &mut Notifications) -> Cell {
+    let mut terminal = Terminal::new(TestBackend::new(40, 6)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    terminal.backend().buffer()[(0, 0)].clone()
+}
+
+#[test]
+fn test_exit_phase_uses_the_exit_style() {
+    let mut manager = Notifications::new();
+    let id = manager.add(timed().exit_style(receding()).build().unwrap()).unwrap();
+    manager.tick(ms(1000));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    let dwelling = corner(&mut manager);
+
+    manager.tick(ms(2000));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+    let exiting = corner(&mut manager);
+
+    assert_eq!(dwelling.fg, Color::Cyan);
+    assert!(!dwelling.modifier.contains(Modifier::DIM));
+    assert_eq!(exiting.fg, Color::DarkGray);
+    assert!(exiting.modifier.contains(Modifier::DIM));
+}
+
+#[test]
+fn test_without_exit_style_the_exit_looks_the_same() {
+    let mut manager = Notifications::new();
+    let id = manager.add(timed().build().unwrap()).unwrap();
+    manager.tick(ms(1000));
+    let dwelling = corner(&mut manager);
+    manager.tick(ms(2000));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+
+    let exiting = corner(&mut manager);
+    assert_eq!((exiting.fg, exiting.modifier), (dwelling.fg, dwelling.modifier));
+}
+
+#[test]
+fn test_fading_blends_the_exit_color_in() {
+    let cyan = Style::new().fg(Color::Rgb(0, 200, 200));
+    let gray = Style::new().fg(Color::Rgb(100, 100, 100));
+    let notification = timed()
+        .animation(Animation::Fade)
+        .border_style(cyan)
+        .exit_style(gray)
+        .build()
+        .unwrap();
+    let mut manager = Notifications::new();
+    let id = manager.add(notification).unwrap();
+    manager.tick(ms(1000));
+    let dwelling = corner(&mut manager).fg;
+
+    // 10% into the exit: halfway through blending in the exit color
+    manager.tick(ms(2000));
+    manager.tick(ms(100));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::FadingOut));
+    let blending = corner(&mut manager).fg;
+
+    assert_eq!(dwelling, Color::Rgb(0, 200, 200));
+    assert_ne!(blending, dwelling);
+    assert_ne!(blending, Color::Rgb(100, 100, 100));
+}
+
+// FILE: tests/test_exit_style_integration.rs - Integration tests for the exit-phase style
+// END OF VERSION: 1.1.0
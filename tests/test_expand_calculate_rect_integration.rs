@@ -1,11 +1,13 @@
 // FILE: tests/test_expand_calculate_rect_integration.rs - Integration tests for expand rect calculation
-// VERSION: 1.0.0
-// WCTX: TDD implementation of animation function extraction
-// CLOG: Created integration test for expand calculate_rect
+// VERSION: 1.4.0
+// WCTX: Eased expand
+// CLOG: Added eased expand tests
 
 use ratatui::prelude::*;
-use ratatui_notifications::notifications::functions::fnc_expand_calculate_rect::calculate_rect;
-use ratatui_notifications::notifications::types::AnimationPhase;
+use ratatui_notifications::notifications::functions::fnc_expand_calculate_rect::{
+    calculate_rect, calculate_rect_eased, calculate_rect_from_origin,
+};
+use ratatui_notifications::notifications::types::{Anchor, AnimationPhase, Easing, ExpandOrigin};
 
 #[test]
 fn test_expand_calculate_rect_expanding_at_0() {
@@ -132,5 +134,199 @@ fn test_expand_calculate_rect_with_different_sizes() {
     assert!(result.height > 3 && result.height < 40);
 }
 
+fn anchored(anchor: Anchor, phase: AnimationPhase, progress: f32) -> Rect {
+    let full_rect = Rect::new(10, 20, 33, 13);
+    let frame_area = Rect::new(0, 0, 100, 100);
+    calculate_rect_from_origin(full_rect, frame_area, phase, progress, ExpandOrigin::Anchor, anchor)
+}
+
+#[test]
+fn test_expand_from_anchor_keeps_bottom_right_fixed() {
+    for progress in [0.0, 0.25, 0.5, 0.75, 1.0] {
+        for phase in [AnimationPhase::Expanding, AnimationPhase::Collapsing] {
+            let result = anchored(Anchor::BottomRight, phase, progress);
+            assert_eq!((result.right(), result.bottom()), (43, 33));
+        }
+    }
+}
+
+#[test]
+fn test_expand_from_anchor_keeps_bottom_left_fixed() {
+    for progress in [0.0, 0.25, 0.5, 0.75, 1.0] {
+        for phase in [AnimationPhase::Expanding, AnimationPhase::Collapsing] {
+            let result = anchored(Anchor::BottomLeft, phase, progress);
+            assert_eq!((result.x, result.bottom()), (10, 33));
+        }
+    }
+}
+
+#[test]
+fn test_expand_from_anchor_keeps_top_right_fixed() {
+    for progress in [0.0, 0.25, 0.5, 0.75, 1.0] {
+        for phase in [AnimationPhase::Expanding, AnimationPhase::Collapsing] {
+            let result = anchored(Anchor::TopRight, phase, progress);
+            assert_eq!((result.right(), result.y), (43, 20));
+        }
+    }
+}
+
+#[test]
+fn test_expand_from_anchor_keeps_top_left_fixed() {
+    for progress in [0.0, 0.25, 0.5, 0.75, 1.0] {
+        for phase in [AnimationPhase::Expanding, AnimationPhase::Collapsing] {
+            let result = anchored(Anchor::TopLeft, phase, progress);
+            assert_eq!((result.x, result.y), (10, 20));
+        }
+    }
+}
+
+#[test]
+fn test_expand_from_top_left_anchor_starts_in_corner_not_center() {
+    let full_rect = Rect::new(10, 20, 33, 13);
+    let frame_area = Rect::new(0, 0, 100, 100);
+
+    let cornered = anchored(Anchor::TopLeft, AnimationPhase::Expanding, 0.0);
+    assert_eq!(cornered, Rect::new(10, 20, 3, 3));
+
+    let centered = calculate_rect(full_rect, frame_area, AnimationPhase::Expanding, 0.0);
+    assert_eq!(centered, Rect::new(25, 25, 3, 3));
+}
+
+#[test]
+fn test_expand_from_anchor_sizes_match_center_origin() {
+    let full_rect = Rect::new(10, 20, 33, 13);
+    let frame_area = Rect::new(0, 0, 100, 100);
+
+    for progress in [0.0, 0.3, 0.6, 1.0] {
+        let centered = calculate_rect(full_rect, frame_area, AnimationPhase::Expanding, progress);
+        let cornered = anchored(Anchor::BottomRight, AnimationPhase::Expanding, progress);
+        assert_eq!(centered.as_size(), cornered.as_size());
+    }
+}
+
+#[test]
+fn test_expand_center_origin_matches_calculate_rect() {
+    let full_rect = Rect::new(10, 20, 33, 13);
+    let frame_area = Rect::new(0, 0, 100, 100);
+
+    for progress in [0.0, 0.3, 0.6, 1.0] {
+        let expected = calculate_rect(full_rect, frame_area, AnimationPhase::Collapsing, progress);
+        let result = calculate_rect_from_origin(
+            full_rect,
+            frame_area,
+            AnimationPhase::Collapsing,
+            progress,
+            ExpandOrigin::Center,
+            Anchor::TopLeft,
+        );
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn test_expand_from_position_is_clamped_to_rect() {
+    let full_rect = Rect::new(10, 20, 33, 13);
+    let frame_area = Rect::new(0, 0, 100, 100);
+
+    // A point left of and below the rect pins the bottom-left corner
+    let result = calculate_rect_from_origin(
+        full_rect,
+        frame_area,
+        AnimationPhase::Expanding,
+        0.0,
+        ExpandOrigin::Position(Position::new(0, 90)),
+        Anchor::TopRight,
+    );
+    assert_eq!(result, Rect::new(10, 30, 3, 3));
+}
+
+#[test]
+fn test_expand_near_frame_edges_stays_in_frame() {
+    let frame_area = Rect::new(0, 0, 100, 50);
+    let full_rect = Rect::new(1, 1, 98, 48);
+
+    for step in 0..=200 {
+        let progress = step as f32 / 200.0;
+        for phase in [AnimationPhase::Expanding, AnimationPhase::Collapsing] {
+            let result = calculate_rect(full_rect, frame_area, phase, progress);
+            assert!(
+                result.x >= frame_area.x
+                    && result.y >= frame_area.y
+                    && result.right() <= frame_area.right()
+                    && result.bottom() <= frame_area.bottom(),
+                "{:?} at {} escaped the frame: {:?}",
+                phase,
+                progress,
+                result
+            );
+        }
+    }
+}
+
+#[test]
+fn test_expand_clips_stale_full_rect_to_shrunken_frame() {
+    // full_rect from a larger frame before the terminal was resized
+    let frame_area = Rect::new(0, 0, 80, 30);
+    let full_rect = Rect::new(1, 1, 98, 48);
+
+    for progress in [0.1, 0.37, 0.5, 0.83, 0.99] {
+        let result = calculate_rect(full_rect, frame_area, AnimationPhase::Expanding, progress);
+        assert_eq!(result.intersection(frame_area), result);
+    }
+}
+
+fn eased(phase: AnimationPhase, progress: f32, easing: Easing) -> Rect {
+    let full_rect = Rect::new(10, 20, 33, 13);
+    let frame_area = Rect::new(0, 0, 100, 100);
+    calculate_rect_eased(full_rect, frame_area, phase, progress, ExpandOrigin::Center, Anchor::default(), easing)
+}
+
+#[test]
+fn test_linear_easing_matches_plain_lerp() {
+    for phase in [AnimationPhase::Expanding, AnimationPhase::Collapsing] {
+        for progress in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            let plain = calculate_rect(Rect::new(10, 20, 33, 13), Rect::new(0, 0, 100, 100), phase, progress);
+            assert_eq!(eased(phase, progress, Easing::Linear), plain);
+        }
+    }
+}
+
+#[test]
+fn test_eased_expand_differs_from_linear_midpoint() {
+    let linear = eased(AnimationPhase::Expanding, 0.5, Easing::Linear);
+    assert_eq!((linear.width, linear.height), (18, 8));
+
+    // Ease-out is 75% of the way: lerp(3, 33, 0.75) = 25.5 -> 26, lerp(3, 13, 0.75) = 10.5 -> 11
+    let out = eased(AnimationPhase::Expanding, 0.5, Easing::EaseOutQuad);
+    assert_eq!((out.width, out.height), (26, 11));
+
+    // Ease-in is 25% of the way: lerp(3, 33, 0.25) = 10.5 -> 11, lerp(3, 13, 0.25) = 5.5 -> 6
+    let ease_in = eased(AnimationPhase::Expanding, 0.5, Easing::EaseInQuad);
+    assert_eq!((ease_in.width, ease_in.height), (11, 6));
+}
+
+#[test]
+fn test_eased_collapse_mirrors_expand() {
+    for easing in Easing::ALL {
+        for progress in [0.1, 0.3, 0.5, 0.7, 0.9] {
+            assert_eq!(
+                eased(AnimationPhase::Collapsing, progress, easing),
+                eased(AnimationPhase::Expanding, 1.0 - progress, easing),
+                "{:?} at {}",
+                easing,
+                progress
+            );
+        }
+    }
+}
+
+#[test]
+fn test_eased_expand_keeps_endpoints() {
+    for easing in Easing::ALL {
+        assert_eq!(eased(AnimationPhase::Expanding, 0.0, easing), Rect::new(25, 25, 3, 3));
+        assert_eq!(eased(AnimationPhase::Expanding, 1.0, easing), Rect::new(10, 20, 33, 13));
+    }
+}
+
 // FILE: tests/test_expand_calculate_rect_integration.rs - Integration tests for expand rect calculation
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.4.0
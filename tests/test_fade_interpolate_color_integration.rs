@@ -1,13 +1,35 @@
 // FILE: tests/test_fade_interpolate_color_integration.rs - Integration tests for fade color interpolation
-// VERSION: 1.0.0
-// WCTX: TDD implementation of animation function extraction
-// CLOG: Created standalone integration test to verify fade color interpolation
+// VERSION: 1.4.0
+// WCTX: Baseline code kept as written
+// CLOG: Allowed clippy lints instead of rewriting
+
+#![allow(clippy::manual_range_contains)] // Kept as originally written
 
 use ratatui::style::Color;
 use ratatui_notifications::notifications::functions::fnc_fade_interpolate_color::{
-    interpolate_color, FadeHandler,
+    interpolate_color, interpolate_color_eased, FadeHandler,
 };
-use ratatui_notifications::notifications::types::AnimationPhase;
+use ratatui_notifications::notifications::types::{AnimationPhase, ColorClamp, FadeScope};
+
+/// Back-out easing: overshoots past 1.0 near the end, like bounce/elastic curves
+fn ease_out_back(t: f32) -> f32 {
+    let c1 = 1.70158;
+    let c3 = c1 + 1.0;
+    1.0 + c3 * (t - 1.0).powi(3) + c1 * (t - 1.0).powi(2)
+}
+
+/// Elastic-style easing that dips below 0.0 early on
+fn ease_in_back(t: f32) -> f32 {
+    let c1 = 1.70158;
+    (c1 + 1.0) * t * t * t - c1 * t * t
+}
+
+fn rgb(color: Option<Color>) -> (u8, u8, u8) {
+    match color {
+        Some(Color::Rgb(r, g, b)) => (r, g, b),
+        other => panic!("expected RGB, got {other:?}"),
+    }
+}
 
 #[test]
 fn test_interpolate_color_black_to_white_at_0() {
@@ -185,5 +207,128 @@ fn test_fade_handler_interpolate_content_foreground_other_phases() {
     assert_eq!(result_dwelling, Some(Color::White));
 }
 
+#[test]
+fn test_fade_handler_content_only_keeps_frame_solid() {
+    let handler = FadeHandler;
+    let base_fg = Some(Color::Rgb(200, 200, 200));
+
+    for progress in [0.0, 0.5, 1.0] {
+        for phase in [AnimationPhase::FadingIn, AnimationPhase::SlidingIn, AnimationPhase::FadingOut] {
+            let frame = handler.interpolate_frame_foreground_scoped(
+                FadeScope::ContentOnly,
+                base_fg,
+                phase,
+                progress,
+            );
+            assert_eq!(frame, base_fg);
+
+            let content =
+                handler.interpolate_content_foreground_scoped(FadeScope::ContentOnly, None, phase, progress);
+            assert_eq!(content, handler.interpolate_content_foreground(None, phase, progress));
+        }
+    }
+
+    // Content still fades: black at the start, 191 grey halfway, white at the end
+    let fade = |p| handler.interpolate_content_foreground_scoped(FadeScope::ContentOnly, None, AnimationPhase::FadingIn, p);
+    assert_eq!(fade(0.0), Some(Color::Rgb(0, 0, 0)));
+    assert_eq!(fade(0.5), Some(Color::Rgb(191, 191, 191)));
+    assert_eq!(fade(1.0), Some(Color::Rgb(255, 255, 255)));
+}
+
+#[test]
+fn test_fade_handler_frame_only_keeps_content_solid() {
+    let handler = FadeHandler;
+    let base_fg = Some(Color::Rgb(200, 200, 200));
+
+    for progress in [0.0, 0.5, 1.0] {
+        for phase in [AnimationPhase::FadingIn, AnimationPhase::SlidingIn, AnimationPhase::FadingOut] {
+            let content =
+                handler.interpolate_content_foreground_scoped(FadeScope::FrameOnly, base_fg, phase, progress);
+            assert_eq!(content, base_fg);
+
+            let frame =
+                handler.interpolate_frame_foreground_scoped(FadeScope::FrameOnly, base_fg, phase, progress);
+            assert_eq!(frame, handler.interpolate_frame_foreground(base_fg, phase, progress));
+        }
+    }
+
+    // Frame still fades from black to its base color
+    let fade = |p| handler.interpolate_frame_foreground_scoped(FadeScope::FrameOnly, base_fg, AnimationPhase::FadingIn, p);
+    assert_eq!(fade(0.0), Some(Color::Rgb(0, 0, 0)));
+    assert_eq!(fade(0.5), Some(Color::Rgb(150, 150, 150)));
+    assert_eq!(fade(1.0), base_fg);
+}
+
+#[test]
+fn test_fade_handler_scope_all_matches_unscoped() {
+    let handler = FadeHandler;
+    let base_fg = Some(Color::Rgb(200, 200, 200));
+
+    for progress in [0.0, 0.5, 1.0] {
+        assert_eq!(
+            handler.interpolate_frame_foreground_scoped(FadeScope::All, base_fg, AnimationPhase::FadingOut, progress),
+            handler.interpolate_frame_foreground(base_fg, AnimationPhase::FadingOut, progress)
+        );
+        assert_eq!(
+            handler.interpolate_content_foreground_scoped(FadeScope::All, None, AnimationPhase::FadingOut, progress),
+            handler.interpolate_content_foreground(None, AnimationPhase::FadingOut, progress)
+        );
+    }
+}
+
+#[test]
+fn test_range_clamp_keeps_overshooting_easing_in_range() {
+    let from = Some(Color::Rgb(40, 100, 220));
+    let to = Some(Color::Rgb(200, 60, 90));
+
+    for step in 0..=100 {
+        let progress = step as f32 / 100.0;
+        for easing in [ease_out_back as fn(f32) -> f32, ease_in_back] {
+            let (r, g, b) = rgb(interpolate_color_eased(from, to, progress, easing, ColorClamp::Range));
+            assert!((40..=200).contains(&r), "r={r} at {progress}");
+            assert!((60..=100).contains(&g), "g={g} at {progress}");
+            assert!((90..=220).contains(&b), "b={b} at {progress}");
+        }
+    }
+}
+
+#[test]
+fn test_channel_clamp_lets_overshoot_through() {
+    let from = Some(Color::Rgb(0, 0, 0));
+    let to = Some(Color::Rgb(200, 200, 200));
+
+    // ease_out_back peaks around 1.1 near t = 0.7
+    let (r, _, _) = rgb(interpolate_color_eased(from, to, 0.7, ease_out_back, ColorClamp::Channel));
+    assert!(r > 200, "expected overshoot past the end color, got {r}");
+
+    // Undershoot saturates at 0 instead of wrapping
+    let (r, _, _) = rgb(interpolate_color_eased(to, from, 0.7, ease_out_back, ColorClamp::Channel));
+    assert_eq!(r, 0);
+}
+
+#[test]
+fn test_channel_clamp_saturates_at_channel_bounds() {
+    let from = Some(Color::Rgb(100, 100, 100));
+    let to = Some(Color::Rgb(250, 250, 250));
+
+    let result = interpolate_color_eased(from, to, 1.0, |_| 3.0, ColorClamp::Channel);
+    assert_eq!(result, Some(Color::Rgb(255, 255, 255)));
+    let result = interpolate_color_eased(from, to, 0.0, |_| -3.0, ColorClamp::Channel);
+    assert_eq!(result, Some(Color::Rgb(0, 0, 0)));
+}
+
+#[test]
+fn test_interpolate_color_matches_eased_range() {
+    let from = Some(Color::Rgb(10, 20, 30));
+    let to = Some(Color::Rgb(250, 200, 100));
+
+    for progress in [0.0, 0.25, 0.5, 0.9, 1.0] {
+        assert_eq!(
+            interpolate_color(from, to, progress, true),
+            interpolate_color_eased(from, to, progress, |t| t * (2.0 - t), ColorClamp::Range)
+        );
+    }
+}
+
 // FILE: tests/test_fade_interpolate_color_integration.rs - Integration tests for fade color interpolation
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.4.0
@@ -0,0 +1,82 @@
+// FILE: tests/test_fade_scope_integration.rs - Integration tests for fade scope rendering
+// VERSION: 1.0.0
+// WCTX: Content-only fade
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::style::Color;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, FadeScope, Level, NotificationBuilder, Notifications,
+};
+use std::time::Duration;
+
+/// Renders a notification part-way through its 500ms entry
+fn render_mid_entry(animation: Animation, fade: bool, scope: FadeScope, elapsed_ms: u64) -> Buffer {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("Hello")
+        .anchor(Anchor::TopLeft)
+        .level(Level::Info)
+        .animation(animation)
+        .fade(fade)
+        .fade_scope(scope)
+        .build()
+        .unwrap();
+    manager.add(notif).unwrap();
+    manager.tick(Duration::from_millis(16));
+    manager.tick(Duration::from_millis(elapsed_ms));
+
+    let mut terminal = Terminal::new(TestBackend::new(40, 10)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn is_faded(color: Color) -> bool {
+    matches!(color, Color::Rgb(..))
+}
+
+#[test]
+fn test_fade_content_only_keeps_border_solid() {
+    let buffer = render_mid_entry(Animation::Fade, false, FadeScope::ContentOnly, 100);
+
+    assert!(!is_faded(buffer[(0, 0)].fg), "border faded: {:?}", buffer[(0, 0)].fg);
+    assert!(is_faded(buffer[(2, 1)].fg), "content solid: {:?}", buffer[(2, 1)].fg);
+}
+
+#[test]
+fn test_fade_frame_only_keeps_content_solid() {
+    let buffer = render_mid_entry(Animation::Fade, false, FadeScope::FrameOnly, 100);
+
+    assert!(is_faded(buffer[(0, 0)].fg), "border solid: {:?}", buffer[(0, 0)].fg);
+    assert_eq!(buffer[(2, 1)].fg, Color::Reset);
+}
+
+#[test]
+fn test_fade_scope_all_fades_both() {
+    let buffer = render_mid_entry(Animation::Fade, false, FadeScope::All, 100);
+
+    assert!(is_faded(buffer[(0, 0)].fg));
+    assert!(is_faded(buffer[(2, 1)].fg));
+}
+
+#[test]
+fn test_content_only_composes_with_slide_fade() {
+    // The slide is partly on screen by 300ms
+    let full = render_mid_entry(Animation::Slide, true, FadeScope::All, 300);
+    let scoped = render_mid_entry(Animation::Slide, true, FadeScope::ContentOnly, 300);
+
+    // Same geometry, but only the scoped frame keeps its level color
+    let border_fg = |buffer: &Buffer| {
+        buffer
+            .content
+            .iter()
+            .find(|cell| cell.symbol() == "─")
+            .map(|cell| cell.fg)
+    };
+    assert!(border_fg(&full).is_some_and(is_faded));
+    assert!(border_fg(&scoped).is_some_and(|fg| !is_faded(fg)));
+}
+
+// FILE: tests/test_fade_scope_integration.rs - Integration tests for fade scope rendering
+// END OF VERSION: 1.0.0
@@ -1,7 +1,7 @@
 // FILE: tests/test_fnc_calculate_rect_integration.rs - Integration tests for rect calculation
-// VERSION: 1.0.0
-// WCTX: Implementing layout functions for ratatui-notifications using TDD
-// CLOG: Initial creation with tests for rect placement and clamping
+// VERSION: 1.1.0
+// WCTX: Saturating rect placement
+// CLOG: Added degenerate padding, oversized and out-of-frame anchor tests
 
 use ratatui::layout::{Position, Rect};
 use ratatui_notifications::notifications::functions::fnc_calculate_rect::calculate_rect;
@@ -182,5 +182,77 @@ fn test_rect_stays_within_frame_bottom_edge() {
     assert_eq!(result, Rect::new(5, 10, 10, 10));
 }
 
+/// Anchor points of a 100x50 frame, as the stacking layout computes them
+const FRAME_ANCHORS: [(Anchor, Position); 9] = [
+    (Anchor::TopLeft, Position::new(0, 0)),
+    (Anchor::TopCenter, Position::new(50, 0)),
+    (Anchor::TopRight, Position::new(99, 0)),
+    (Anchor::MiddleLeft, Position::new(0, 25)),
+    (Anchor::MiddleCenter, Position::new(50, 25)),
+    (Anchor::MiddleRight, Position::new(99, 25)),
+    (Anchor::BottomLeft, Position::new(0, 49)),
+    (Anchor::BottomCenter, Position::new(50, 49)),
+    (Anchor::BottomRight, Position::new(99, 49)),
+];
+
+#[test]
+fn test_padding_larger_than_frame_stops_at_far_edge() {
+    let frame = Rect::new(0, 0, 100, 50);
+    let expected = [
+        Rect::new(80, 40, 20, 10),
+        Rect::new(40, 40, 20, 10),
+        Rect::new(0, 40, 20, 10),
+        Rect::new(80, 20, 20, 10),
+        Rect::new(40, 20, 20, 10),
+        Rect::new(0, 20, 20, 10),
+        Rect::new(80, 0, 20, 10),
+        Rect::new(40, 0, 20, 10),
+        Rect::new(0, 0, 20, 10),
+    ];
+
+    for ((anchor, anchor_pos), expected) in FRAME_ANCHORS.into_iter().zip(expected) {
+        let result = calculate_rect(anchor, anchor_pos, 20, 10, frame, 200);
+        assert_eq!(result, expected, "{anchor:?}");
+    }
+}
+
+#[test]
+fn test_width_larger_than_frame_fills_width_at_every_anchor() {
+    let frame = Rect::new(0, 0, 100, 50);
+    let expected_y = [0, 0, 0, 20, 20, 20, 40, 40, 40];
+
+    for ((anchor, anchor_pos), y) in FRAME_ANCHORS.into_iter().zip(expected_y) {
+        let result = calculate_rect(anchor, anchor_pos, 150, 10, frame, 0);
+        assert_eq!(result, Rect::new(0, y, 100, 10), "{anchor:?}");
+    }
+}
+
+#[test]
+fn test_size_larger_than_frame_covers_frame_at_every_anchor() {
+    let frame = Rect::new(5, 5, 100, 50);
+
+    for (anchor, anchor_pos) in FRAME_ANCHORS {
+        let result = calculate_rect(anchor, anchor_pos, u16::MAX, u16::MAX, frame, 3);
+        assert_eq!(result, frame, "{anchor:?}");
+    }
+}
+
+#[test]
+fn test_anchor_outside_frame_is_pulled_back_in() {
+    let frame = Rect::new(10, 10, 100, 50);
+
+    // Past the bottom-right: the rect hugs the bottom-right corner
+    let result = calculate_rect(Anchor::TopLeft, Position::new(500, 500), 20, 10, frame, 0);
+    assert_eq!(result, Rect::new(90, 50, 20, 10));
+
+    // Before the frame origin: right-aligned math goes negative, not wrapped
+    let result = calculate_rect(Anchor::BottomRight, Position::new(0, 0), 20, 10, frame, 0);
+    assert_eq!(result, Rect::new(10, 10, 20, 10));
+
+    // Far right but above the frame: only the out-of-range axis moves
+    let result = calculate_rect(Anchor::TopRight, Position::new(u16::MAX, 0), 20, 10, frame, 0);
+    assert_eq!(result, Rect::new(90, 10, 20, 10));
+}
+
 // FILE: tests/test_fnc_calculate_rect_integration.rs - Integration tests for rect calculation
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.1.0
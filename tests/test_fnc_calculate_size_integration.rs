@@ -1,13 +1,14 @@
 // FILE: tests/test_fnc_calculate_size_integration.rs - Integration tests for fnc_calculate_size
-// VERSION: 1.1.0
-// WCTX: TDD implementation of deferred functions
-// CLOG: Rewrote tests to use NotificationBuilder pattern instead of direct struct construction
+// VERSION: 1.4.0
+// WCTX: Countdown display
+// CLOG: Added countdown width test
 
 use ratatui::prelude::*;
 use ratatui::widgets::{BorderType, Padding};
-use ratatui_notifications::notifications::NotificationBuilder;
+use ratatui_notifications::notifications::{AutoDismiss, NotificationBuilder};
 use ratatui_notifications::notifications::functions::fnc_calculate_size::calculate_size;
 use ratatui_notifications::notifications::types::SizeConstraint;
+use std::time::Duration;
 
 #[test]
 fn test_empty_content_returns_minimum_size() {
@@ -190,5 +191,97 @@ fn test_wrapping_increases_height() {
     assert!(height > 5); // Should be significantly taller due to wrapping
 }
 
+#[test]
+fn test_height_fits_content_not_max_height() {
+    // A one-line bordered notification should not grow to fill max_height
+    let notification = NotificationBuilder::new("Short")
+        .border_type(BorderType::Plain)
+        .padding(Padding::horizontal(1))
+        .max_size(SizeConstraint::Absolute(40), SizeConstraint::Absolute(20))
+        .build()
+        .unwrap();
+    let frame_area = Rect::new(0, 0, 100, 100);
+
+    let (_width, height) = calculate_size(&notification, frame_area);
+
+    assert_eq!(height, 3); // 1 line + 2 border rows
+}
+
+#[test]
+fn test_title_separator_adds_one_row() {
+    let build = |separator: bool| {
+        NotificationBuilder::new("Body text")
+            .title("Title")
+            .title_separator(separator)
+            .max_size(SizeConstraint::Absolute(40), SizeConstraint::Absolute(20))
+            .build()
+            .unwrap()
+    };
+    let frame_area = Rect::new(0, 0, 100, 100);
+
+    let (_, without) = calculate_size(&build(false), frame_area);
+    let (_, with) = calculate_size(&build(true), frame_area);
+
+    assert_eq!(with, without + 1);
+}
+
+#[test]
+fn test_title_separator_ignored_without_title() {
+    let build = |separator: bool| {
+        NotificationBuilder::new("Body text")
+            .title_separator(separator)
+            .build()
+            .unwrap()
+    };
+    let frame_area = Rect::new(0, 0, 100, 100);
+
+    assert_eq!(
+        calculate_size(&build(true), frame_area),
+        calculate_size(&build(false), frame_area)
+    );
+}
+
+#[test]
+fn test_min_size_grows_short_content() {
+    let notification = NotificationBuilder::new("Hi")
+        .min_size(SizeConstraint::Absolute(20), SizeConstraint::Absolute(7))
+        .max_size(SizeConstraint::Absolute(40), SizeConstraint::Absolute(20))
+        .build()
+        .unwrap();
+    let frame_area = Rect::new(0, 0, 100, 100);
+
+    assert_eq!(calculate_size(&notification, frame_area), (20, 7));
+}
+
+#[test]
+fn test_max_size_wins_over_min_size() {
+    let notification = NotificationBuilder::new("Hi")
+        .min_size(SizeConstraint::Absolute(50), SizeConstraint::Absolute(30))
+        .max_size(SizeConstraint::Absolute(30), SizeConstraint::Absolute(10))
+        .build()
+        .unwrap();
+    let frame_area = Rect::new(0, 0, 100, 100);
+
+    assert_eq!(calculate_size(&notification, frame_area), (30, 10));
+}
+
+#[test]
+fn test_countdown_widens_title() {
+    let build = |countdown: bool| {
+        NotificationBuilder::new("Hi")
+            .title("Retrying in")
+            .auto_dismiss(AutoDismiss::After(Duration::from_secs(30)))
+            .show_countdown(countdown)
+            .build()
+            .unwrap()
+    };
+    let frame_area = Rect::new(0, 0, 100, 100);
+
+    let (plain, _) = calculate_size(&build(false), frame_area);
+    let (with_countdown, _) = calculate_size(&build(true), frame_area);
+    // " 30s"
+    assert_eq!(with_countdown, plain + 4);
+}
+
 // FILE: tests/test_fnc_calculate_size_integration.rs - Integration tests for fnc_calculate_size
-// END OF VERSION: 1.1.0
+// END OF VERSION: 1.4.0
@@ -0,0 +1,101 @@
+// FILE: tests/test_fnc_describe_notification_integration.rs - Integration tests for plain-text notification descriptions
+// VERSION: 1.1.0
+// WCTX: Localizable built-in strings
+// CLOG: Pass default Strings
+
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span, Text};
+use ratatui_notifications::notifications::functions::fnc_describe_notification::describe_notification;
+use ratatui_notifications::notifications::types::{Anchor, Level};
+use ratatui_notifications::notifications::Strings;
+use std::time::Duration;
+
+#[test]
+fn test_error_with_countdown() {
+    let sentence = describe_notification(
+        Some(Level::Error),
+        Anchor::TopRight,
+        None,
+        &Text::from("Connection failed"),
+        Some(Duration::from_secs(4)),
+        &Strings::default(),
+    );
+    assert_eq!(sentence, "Error notification, top right: Connection failed. Dismisses in 4 seconds.");
+}
+
+#[test]
+fn test_title_precedes_content() {
+    let title = Line::from(" Network ");
+    let sentence = describe_notification(
+        Some(Level::Warn),
+        Anchor::BottomCenter,
+        Some(&title),
+        &Text::from("Retrying!"),
+        None,
+        &Strings::default(),
+    );
+    assert_eq!(sentence, "Warning notification, bottom center: Network. Retrying!");
+}
+
+#[test]
+fn test_no_level_and_never_dismissed() {
+    let sentence = describe_notification(
+        None,
+        Anchor::MiddleCenter,
+        None,
+        &Text::from("Saved."),
+        None,
+        &Strings::default(),
+    );
+    assert_eq!(sentence, "Notification, center: Saved.");
+}
+
+#[test]
+fn test_styled_multiline_content_is_flattened() {
+    let content = Text::from(vec![
+        Line::from(vec![
+            Span::styled("Build ", Style::new().fg(Color::Red)),
+            Span::raw("failed"),
+        ]),
+        Line::from(""),
+        Line::from("  see log  "),
+    ]);
+    let sentence = describe_notification(
+        Some(Level::Info),
+        Anchor::TopLeft,
+        None,
+        &content,
+        None,
+        &Strings::default(),
+    );
+    assert_eq!(sentence, "Info notification, top left: Build failed see log.");
+}
+
+#[test]
+fn test_countdown_rounds_up_and_singularizes() {
+    let sentence = describe_notification(
+        Some(Level::Debug),
+        Anchor::BottomRight,
+        None,
+        &Text::from("Tick"),
+        Some(Duration::from_millis(300)),
+        &Strings::default(),
+    );
+    assert!(sentence.ends_with("Dismisses in 1 second."), "{sentence}");
+}
+
+#[test]
+fn test_zero_remaining_reads_as_dismissing() {
+    let sentence = describe_notification(
+        Some(Level::Trace),
+        Anchor::MiddleLeft,
+        None,
+        &Text::from("Bye"),
+        Some(Duration::ZERO),
+        &Strings::default(),
+    );
+    assert_eq!(sentence, "Trace notification, middle left: Bye. Dismissing.");
+}
+
+// FILE: tests/test_fnc_describe_notification_integration.rs - Integration tests for plain-text notification descriptions
+// END OF VERSION: 1.1.0
@@ -0,0 +1,55 @@
+// FILE: tests/test_fnc_fill_template_integration.rs - Integration tests for template substitution
+// VERSION: 1.0.0
+// WCTX: Content templates with field substitution
+// CLOG: Initial creation
+
+use ratatui_notifications::notifications::functions::fnc_fill_template::fill_template;
+use ratatui_notifications::notifications::types::NotificationError;
+
+const NO_FIELDS: &[(&str, &str)] = &[];
+
+#[test]
+fn test_substitutes_all_fields() {
+    let text = fill_template("{a}-{b}-{a}", &[("a", "x"), ("b", "y")], true).unwrap();
+    assert_eq!(text, "x-y-x");
+}
+
+#[test]
+fn test_values_are_not_rescanned() {
+    let text = fill_template("{a}", &[("a", "{b}"), ("b", "boom")], true).unwrap();
+    assert_eq!(text, "{b}");
+}
+
+#[test]
+fn test_brace_escapes() {
+    let text = fill_template("{{x}} and }} and {{", NO_FIELDS, true).unwrap();
+    assert_eq!(text, "{x} and } and {");
+}
+
+#[test]
+fn test_unterminated_and_lone_braces_are_literal() {
+    assert_eq!(fill_template("50% {done", NO_FIELDS, true).unwrap(), "50% {done");
+    assert_eq!(fill_template("a } b", NO_FIELDS, true).unwrap(), "a } b");
+    assert_eq!(fill_template("{ {a}", &[("a", "1")], true).unwrap(), "{ 1");
+}
+
+#[test]
+fn test_missing_field_lenient_keeps_placeholder() {
+    let text = fill_template("Hello {user}!", NO_FIELDS, false).unwrap();
+    assert_eq!(text, "Hello {user}!");
+}
+
+#[test]
+fn test_missing_field_strict_errors_with_name() {
+    let err = fill_template("Hello {user}!", NO_FIELDS, true).unwrap_err();
+    assert_eq!(err, NotificationError::MissingTemplateField("user".to_string()));
+}
+
+#[test]
+fn test_unicode_around_placeholders() {
+    let text = fill_template("→ {name} ✓", &[("name", "Zoë")], true).unwrap();
+    assert_eq!(text, "→ Zoë ✓");
+}
+
+// FILE: tests/test_fnc_fill_template_integration.rs - Integration tests for template substitution
+// END OF VERSION: 1.0.0
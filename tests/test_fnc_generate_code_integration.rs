@@ -1,7 +1,7 @@
 // FILE: tests/test_fnc_generate_code_integration.rs - Integration tests for code generation function
-// VERSION: 1.0.0
-// WCTX: Adding "show code" feature to demo
-// CLOG: Initial creation
+// VERSION: 1.9.0
+// WCTX: Deadline auto-dismiss
+// CLOG: Emit todo!() for deadlines instead of Instant::now()
 
 use std::time::Duration;
 
@@ -9,8 +9,8 @@ use ratatui::prelude::*;
 use ratatui::widgets::{BorderType, Padding};
 
 use ratatui_notifications::{
-    generate_code, Anchor, Animation, AutoDismiss, Level, Notification, SlideDirection,
-    SizeConstraint, Timing,
+    generate_code, generate_code_with_context, generate_scene_code, Anchor, AnchorCoord, Animation, AutoDismiss, Easing, Evictability, ExpandOrigin, Level, Notification,
+    ManagerDefaults, Notifications, Overflow, SlideDirection, SizeConstraint, Timing, VerticalAlign,
 };
 
 #[test]
@@ -102,6 +102,20 @@ fn test_auto_dismiss_custom_duration_appears_in_code() {
     assert!(code.contains("Duration::from_secs(10)"));
 }
 
+#[test]
+fn test_auto_dismiss_deadline_is_left_for_the_user() {
+    let deadline = std::time::Instant::now() + Duration::from_secs(30);
+    let notification = Notification::new("Test")
+        .auto_dismiss(AutoDismiss::AtDeadline(deadline))
+        .build()
+        .unwrap();
+    let code = generate_code(&notification);
+
+    // A deadline pasted as now() would dismiss at once
+    assert!(code.contains(".auto_dismiss(AutoDismiss::AtDeadline(todo!(\"deadline\")))"));
+    assert!(!code.contains("Instant::now()"));
+}
+
 #[test]
 fn test_default_auto_dismiss_not_in_code() {
     // Default is After(4 secs)
@@ -149,6 +163,18 @@ fn test_fade_effect_not_in_code_when_false() {
     assert!(!code.contains(".fade("));
 }
 
+#[test]
+fn test_title_separator_appears_when_true() {
+    let notification = Notification::new("Test")
+        .title("Title")
+        .title_separator(true)
+        .build()
+        .unwrap();
+    let code = generate_code(&notification);
+
+    assert!(code.contains(".title_separator(true)"));
+}
+
 #[test]
 fn test_border_type_appears_when_not_default() {
     let notification = Notification::new("Test")
@@ -302,5 +328,174 @@ fn test_code_is_syntactically_structured() {
     assert!(code.contains("\n    ."));
 }
 
+#[test]
+fn test_expand_origin_appears_when_not_center() {
+    let notification = Notification::new("Test")
+        .expand_origin(ExpandOrigin::Position(Position::new(4, 7)))
+        .build()
+        .unwrap();
+    let code = generate_code(&notification);
+
+    assert!(code.contains(".expand_origin(ExpandOrigin::Position(Position::new(4, 7)))"));
+
+    let default_code = generate_code(&Notification::new("Test").build().unwrap());
+    assert!(!default_code.contains("expand_origin"));
+}
+
+#[test]
+fn test_expand_easing_appears_when_not_default() {
+    let notification = Notification::new("Test").expand_easing(Easing::Linear).build().unwrap();
+    assert!(generate_code(&notification).contains(".expand_easing(Easing::Linear)"));
+
+    let default_code = generate_code(&Notification::new("Test").build().unwrap());
+    assert!(!default_code.contains("expand_easing"));
+}
+
+#[test]
+fn test_evictable_appears_when_not_default() {
+    let notification = Notification::new("Test").evictable(Evictability::Never).build().unwrap();
+    assert!(generate_code(&notification).contains(".evictable(Evictability::Never)"));
+
+    let default_code = generate_code(&Notification::new("Test").build().unwrap());
+    assert!(!default_code.contains("evictable"));
+}
+
+#[test]
+fn test_min_size_and_vertical_align_appear_when_set() {
+    let notification = Notification::new("Test")
+        .min_size(SizeConstraint::Absolute(20), SizeConstraint::Absolute(7))
+        .vertical_align(VerticalAlign::Center)
+        .build()
+        .unwrap();
+    let code = generate_code(&notification);
+
+    assert!(code.contains(".min_size(SizeConstraint::Absolute(20), SizeConstraint::Absolute(7))"));
+    assert!(code.contains(".vertical_align(VerticalAlign::Center)"));
+}
+
+#[test]
+fn test_custom_anchor_generates_valid_code() {
+    let notification = Notification::new("Test")
+        .anchor(Anchor::Custom {
+            x: AnchorCoord::percent(25.0),
+            y: AnchorCoord::Absolute(3),
+        })
+        .build()
+        .unwrap();
+    let code = generate_code(&notification);
+
+    assert!(code.contains(
+        ".anchor(Anchor::Custom { x: AnchorCoord::BasisPoints(2500), y: AnchorCoord::Absolute(3) })"
+    ));
+}
+
+#[test]
+fn test_context_emits_manager_default_anchor() {
+    let defaults = ManagerDefaults { default_anchor: Anchor::TopRight, ..ManagerDefaults::default() };
+    let notification = Notification::new("Hello").build().unwrap();
+
+    assert!(!generate_code(&notification).contains(".anchor("));
+    let code = generate_code_with_context(&notification, &defaults);
+    assert!(code.contains(".anchor(Anchor::TopRight)"), "{code}");
+
+    // An explicit anchor wins over the manager default
+    let explicit = Notification::new("Hello").anchor(Anchor::TopLeft).build().unwrap();
+    let code = generate_code_with_context(&explicit, &defaults);
+    assert!(code.contains(".anchor(Anchor::TopLeft)"), "{code}");
+}
+
+#[test]
+fn test_context_resolves_auto_timings_and_zero_display_time() {
+    let defaults = ManagerDefaults {
+        default_entry_duration: Duration::from_millis(200),
+        default_display_time: Duration::from_secs(9),
+        ..ManagerDefaults::default()
+    };
+    let notification = Notification::new("Hello")
+        .auto_dismiss(AutoDismiss::After(Duration::ZERO))
+        .build()
+        .unwrap();
+
+    let code = generate_code_with_context(&notification, &defaults);
+    assert!(
+        code.contains(".timing(Timing::Fixed(Duration::from_millis(200)), Timing::Auto, Timing::Auto)"),
+        "{code}"
+    );
+    assert!(code.contains(".auto_dismiss(AutoDismiss::After(Duration::from_secs(9)))"), "{code}");
+}
+
+#[test]
+fn test_context_with_plain_defaults_matches_generate_code() {
+    let notification = Notification::new("Hello")
+        .level(Level::Warn)
+        .timing(Timing::Auto, Timing::Fixed(Duration::from_secs(2)), Timing::Auto)
+        .build()
+        .unwrap();
+    assert_eq!(
+        generate_code_with_context(&notification, &ManagerDefaults::default()),
+        generate_code(&notification)
+    );
+}
+
+#[test]
+fn test_manager_applies_default_anchor() {
+    let mut manager = Notifications::new()
+        .defaults(ManagerDefaults { default_anchor: Anchor::TopRight, ..ManagerDefaults::default() });
+    let unset = manager.add(Notification::new("Unset").build().unwrap()).unwrap();
+    let explicit = manager
+        .add(Notification::new("Explicit").anchor(Anchor::BottomRight).build().unwrap())
+        .unwrap();
+
+    assert_eq!(manager.anchor(unset), Some(Anchor::TopRight));
+    assert_eq!(manager.anchor(explicit), Some(Anchor::BottomRight));
+    assert_eq!(manager.manager_defaults().default_anchor, Anchor::TopRight);
+}
+
+#[test]
+fn test_scene_code_adds_each_active_notification() {
+    let mut manager = Notifications::new()
+        .max_concurrent(Some(3))
+        .overflow(Overflow::DiscardNewest);
+    manager.add(Notification::new("First").build().unwrap()).unwrap();
+    manager
+        .add(Notification::new("Second").level(Level::Error).build().unwrap())
+        .unwrap();
+    manager.add_hidden(Notification::new("Hidden").build().unwrap()).unwrap();
+
+    let code = generate_scene_code(&manager);
+    assert!(code.starts_with(
+        "let mut manager = Notifications::new()\n    .max_concurrent(Some(3))\n    .overflow(Overflow::DiscardNewest);"
+    ));
+    assert_eq!(code.matches("manager.add(").count(), 2);
+    assert!(code.find("First").unwrap() < code.find("Second").unwrap());
+    assert!(code.contains("        .level(Level::Error)"));
+    assert!(!code.contains("Hidden"));
+}
+
+#[test]
+fn test_scene_code_for_plain_empty_manager() {
+    let manager = Notifications::new();
+    assert_eq!(generate_scene_code(&manager), "let mut manager = Notifications::new();");
+}
+
+#[test]
+fn test_scene_code_wraps_each_builder_in_add() {
+    let mut manager = Notifications::new();
+    manager.add(Notification::new("Only").build().unwrap()).unwrap();
+
+    let code = generate_scene_code(&manager);
+    let expected = "manager.add(\n    Notification::builder(\"Only\")\n        .build()?,\n)?;";
+    assert!(code.ends_with(expected), "{code}");
+}
+
+#[test]
+fn test_scene_code_folds_manager_defaults_into_notifications() {
+    let mut manager = Notifications::new()
+        .defaults(ManagerDefaults { default_anchor: Anchor::TopLeft, ..ManagerDefaults::default() });
+    manager.add(Notification::new("Here").build().unwrap()).unwrap();
+
+    assert!(generate_scene_code(&manager).contains(".anchor(Anchor::TopLeft)"));
+}
+
 // FILE: tests/test_fnc_generate_code_integration.rs - Integration tests for code generation function
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.9.0
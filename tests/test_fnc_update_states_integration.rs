@@ -1,25 +1,29 @@
-// FILE: tests/notifications/functions/test_fnc_update_states.rs - Tests for fnc_update_states
+// FILE: tests/test_fnc_update_states_integration.rs - Integration tests for update_states and single-state stepping
 // VERSION: 1.0.0
-// WCTX: TDD implementation of update_states function
-// CLOG: Initial creation with comprehensive state machine tests
+// WCTX: Public single-state stepping
+// CLOG: Ported update_states tests to the public API; added NotificationState::update tests
 
-use ratatui_notifications::notifications::classes::cls_notification_state::{NotificationState, ManagerDefaults};
-use ratatui_notifications::notifications::classes::cls_notification::Notification;
 use ratatui_notifications::notifications::functions::fnc_update_states::update_states;
-use ratatui_notifications::notifications::types::{Animation, AnimationPhase, Timing, AutoDismiss};
-use ratatui::prelude::*;
+use ratatui_notifications::notifications::types::{Animation, AnimationPhase, AutoDismiss, StateUpdate, Timing};
+use ratatui_notifications::notifications::{
+    ManagerDefaults, Notification, NotificationBuilder, NotificationState,
+};
 use std::collections::HashMap;
 use std::time::Duration;
 
+fn builder(animation: Animation) -> NotificationBuilder {
+    NotificationBuilder::new("Test notification")
+        .animation(animation)
+        .timing(
+            Timing::Fixed(Duration::from_millis(100)),
+            Timing::Auto,
+            Timing::Fixed(Duration::from_millis(100)),
+        )
+        .auto_dismiss(AutoDismiss::After(Duration::from_millis(200)))
+}
+
 fn create_test_notification(animation: Animation) -> Notification {
-    Notification {
-        content: Text::raw("Test notification"),
-        animation,
-        slide_in_timing: Timing::Fixed(Duration::from_millis(100)),
-        slide_out_timing: Timing::Fixed(Duration::from_millis(100)),
-        auto_dismiss: AutoDismiss::After(Duration::from_millis(200)),
-        ..Default::default()
-    }
+    builder(animation).build().unwrap()
 }
 
 #[test]
@@ -29,12 +33,12 @@ fn test_pending_to_sliding_in_for_slide_animation() {
     let mut states = HashMap::new();
     states.insert(1, NotificationState::new(1, notification, &defaults));
 
-    assert_eq!(states[&1].current_phase, AnimationPhase::Pending);
+    assert_eq!(states[&1].phase(), AnimationPhase::Pending);
 
     update_states(&mut states, Duration::from_millis(10));
 
-    assert_eq!(states[&1].current_phase, AnimationPhase::SlidingIn);
-    assert!(states[&1].animation_progress > 0.0);
+    assert_eq!(states[&1].phase(), AnimationPhase::SlidingIn);
+    assert!(states[&1].progress() > 0.0);
 }
 
 #[test]
@@ -44,12 +48,12 @@ fn test_pending_to_expanding_for_expand_collapse_animation() {
     let mut states = HashMap::new();
     states.insert(1, NotificationState::new(1, notification, &defaults));
 
-    assert_eq!(states[&1].current_phase, AnimationPhase::Pending);
+    assert_eq!(states[&1].phase(), AnimationPhase::Pending);
 
     update_states(&mut states, Duration::from_millis(10));
 
-    assert_eq!(states[&1].current_phase, AnimationPhase::Expanding);
-    assert!(states[&1].animation_progress > 0.0);
+    assert_eq!(states[&1].phase(), AnimationPhase::Expanding);
+    assert!(states[&1].progress() > 0.0);
 }
 
 #[test]
@@ -59,12 +63,12 @@ fn test_pending_to_fading_in_for_fade_animation() {
     let mut states = HashMap::new();
     states.insert(1, NotificationState::new(1, notification, &defaults));
 
-    assert_eq!(states[&1].current_phase, AnimationPhase::Pending);
+    assert_eq!(states[&1].phase(), AnimationPhase::Pending);
 
     update_states(&mut states, Duration::from_millis(10));
 
-    assert_eq!(states[&1].current_phase, AnimationPhase::FadingIn);
-    assert!(states[&1].animation_progress > 0.0);
+    assert_eq!(states[&1].phase(), AnimationPhase::FadingIn);
+    assert!(states[&1].progress() > 0.0);
 }
 
 #[test]
@@ -76,14 +80,14 @@ fn test_progress_increases_correctly_with_delta_time() {
 
     // First update: Pending -> SlidingIn
     update_states(&mut states, Duration::from_millis(10));
-    assert_eq!(states[&1].current_phase, AnimationPhase::SlidingIn);
+    assert_eq!(states[&1].phase(), AnimationPhase::SlidingIn);
 
-    let initial_progress = states[&1].animation_progress;
+    let initial_progress = states[&1].progress();
     assert!(initial_progress > 0.0);
 
     // Second update: Progress should increase
     update_states(&mut states, Duration::from_millis(10));
-    assert!(states[&1].animation_progress > initial_progress);
+    assert!(states[&1].progress() > initial_progress);
 }
 
 #[test]
@@ -96,8 +100,9 @@ fn test_entry_animation_completes_at_progress_one() {
     // Advance through entire entry animation
     update_states(&mut states, Duration::from_millis(100));
 
-    assert_eq!(states[&1].current_phase, AnimationPhase::Dwelling);
-    assert_eq!(states[&1].animation_progress, 1.0);
+    assert_eq!(states[&1].phase(), AnimationPhase::Dwelling);
+    // Progress restarts for the dwelling phase, which counts down display time instead
+    assert_eq!(states[&1].progress(), 0.0);
 }
 
 #[test]
@@ -114,7 +119,7 @@ fn test_transitions_to_dwelling_after_entry_complete() {
         update_states(&mut states, Duration::from_millis(100));
 
         assert_eq!(
-            states[&1].current_phase,
+            states[&1].phase(),
             AnimationPhase::Dwelling,
             "Failed for {:?}", animation
         );
@@ -130,15 +135,15 @@ fn test_display_timer_counts_down_during_dwelling() {
 
     // Complete entry animation to reach Dwelling
     update_states(&mut states, Duration::from_millis(100));
-    assert_eq!(states[&1].current_phase, AnimationPhase::Dwelling);
+    assert_eq!(states[&1].phase(), AnimationPhase::Dwelling);
 
-    let initial_time = states[&1].remaining_display_time;
+    let initial_time = states[&1].remaining_display_time();
     assert!(initial_time.is_some());
 
     // Count down timer
     update_states(&mut states, Duration::from_millis(50));
 
-    let new_time = states[&1].remaining_display_time;
+    let new_time = states[&1].remaining_display_time();
     assert!(new_time.is_some());
     assert!(new_time.unwrap() < initial_time.unwrap());
 }
@@ -161,17 +166,17 @@ fn test_timer_expiry_triggers_exit_animation() {
 
         // Complete entry animation
         update_states(&mut states, Duration::from_millis(100));
-        assert_eq!(states[&1].current_phase, AnimationPhase::Dwelling);
+        assert_eq!(states[&1].phase(), AnimationPhase::Dwelling);
 
         // Complete display timer (200ms total, already spent 100ms)
         update_states(&mut states, Duration::from_millis(200));
 
         assert_eq!(
-            states[&1].current_phase,
+            states[&1].phase(),
             expected_exit_phase,
             "Failed for {:?}", animation
         );
-        assert_eq!(states[&1].animation_progress, 0.0);
+        assert_eq!(states[&1].progress(), 0.0);
     }
 }
 
@@ -187,13 +192,13 @@ fn test_exit_animation_completes_to_finished() {
 
     // Complete display timer
     update_states(&mut states, Duration::from_millis(200));
-    assert_eq!(states[&1].current_phase, AnimationPhase::SlidingOut);
+    assert_eq!(states[&1].phase(), AnimationPhase::SlidingOut);
 
     // Complete exit animation
     update_states(&mut states, Duration::from_millis(100));
 
-    assert_eq!(states[&1].current_phase, AnimationPhase::Finished);
-    assert_eq!(states[&1].animation_progress, 1.0);
+    assert_eq!(states[&1].phase(), AnimationPhase::Finished);
+    assert_eq!(states[&1].progress(), 1.0);
 }
 
 #[test]
@@ -230,8 +235,8 @@ fn test_multiple_notifications_at_different_phases() {
     update_states(&mut states, Duration::from_millis(10));
 
     // Both should have progressed
-    assert_eq!(states[&1].current_phase, AnimationPhase::SlidingIn);
-    assert_eq!(states[&2].current_phase, AnimationPhase::FadingIn);
+    assert_eq!(states[&1].phase(), AnimationPhase::SlidingIn);
+    assert_eq!(states[&2].phase(), AnimationPhase::FadingIn);
 }
 
 #[test]
@@ -270,20 +275,97 @@ fn test_multiple_notifications_finish_simultaneously() {
 #[test]
 fn test_dwelling_without_auto_dismiss() {
     let defaults = ManagerDefaults::default();
-    let mut notification = create_test_notification(Animation::Slide);
-    notification.auto_dismiss = AutoDismiss::Never;
+    let notification = builder(Animation::Slide)
+        .auto_dismiss(AutoDismiss::Never)
+        .build()
+        .unwrap();
 
     let mut states = HashMap::new();
     states.insert(1, NotificationState::new(1, notification, &defaults));
 
     // Complete entry animation
     update_states(&mut states, Duration::from_millis(100));
-    assert_eq!(states[&1].current_phase, AnimationPhase::Dwelling);
+    assert_eq!(states[&1].phase(), AnimationPhase::Dwelling);
 
     // Should stay in Dwelling indefinitely
     update_states(&mut states, Duration::from_millis(1000));
-    assert_eq!(states[&1].current_phase, AnimationPhase::Dwelling);
+    assert_eq!(states[&1].phase(), AnimationPhase::Dwelling);
+}
+
+// --- Single-state API ---
+
+fn single_state(animation: Animation) -> NotificationState {
+    NotificationState::new(1, create_test_notification(animation), &ManagerDefaults::default())
+}
+
+#[test]
+fn test_single_update_reports_start_as_phase_change() {
+    let mut state = single_state(Animation::Slide);
+
+    let update = state.update(Duration::from_millis(10));
+
+    assert_eq!(update, StateUpdate { phase_changed: true, finished: false });
+    assert_eq!(state.phase(), AnimationPhase::SlidingIn);
+}
+
+#[test]
+fn test_single_update_without_transition_reports_no_change() {
+    let mut state = single_state(Animation::Slide);
+    state.update(Duration::from_millis(10));
+
+    let update = state.update(Duration::from_millis(10));
+
+    assert_eq!(update, StateUpdate::default());
+}
+
+#[test]
+fn test_single_update_timer_expiry_picks_exit_phase() {
+    for (animation, expected_exit_phase) in [
+        (Animation::Slide, AnimationPhase::SlidingOut),
+        (Animation::ExpandCollapse, AnimationPhase::Collapsing),
+        (Animation::Fade, AnimationPhase::FadingOut),
+    ] {
+        let mut state = single_state(animation);
+        state.update(Duration::from_millis(100));
+        assert_eq!(state.phase(), AnimationPhase::Dwelling);
+
+        let update = state.update(Duration::from_millis(200));
+
+        assert!(update.phase_changed);
+        assert!(!update.finished);
+        assert_eq!(state.phase(), expected_exit_phase, "Failed for {:?}", animation);
+    }
+}
+
+#[test]
+fn test_single_update_reports_finished() {
+    let mut state = single_state(Animation::Fade);
+    state.update(Duration::from_millis(100)); // Entry
+    state.update(Duration::from_millis(200)); // Dwell
+
+    let update = state.update(Duration::from_millis(100)); // Exit
+
+    assert_eq!(update, StateUpdate { phase_changed: true, finished: true });
+    assert_eq!(state.phase(), AnimationPhase::Finished);
+}
+
+#[test]
+fn test_single_update_matches_update_states() {
+    let deltas = [10, 40, 50, 100, 150, 60, 40].map(Duration::from_millis);
+
+    let mut single = single_state(Animation::ExpandCollapse);
+    let mut states = HashMap::new();
+    states.insert(1, single_state(Animation::ExpandCollapse));
+
+    for delta in deltas {
+        let update = single.update(delta);
+        let finished = update_states(&mut states, delta);
+
+        assert_eq!(single.phase(), states[&1].phase());
+        assert_eq!(single.progress(), states[&1].progress());
+        assert_eq!(update.finished, finished.contains(&1));
+    }
 }
 
-// FILE: tests/notifications/functions/test_fnc_update_states.rs - Tests for fnc_update_states
+// FILE: tests/test_fnc_update_states_integration.rs - Integration tests for update_states and single-state stepping
 // END OF VERSION: 1.0.0
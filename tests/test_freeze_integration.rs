@@ -0,0 +1,104 @@
+// FILE: tests/test_freeze_integration.rs - Integration tests for freezing single notifications
+// VERSION: 1.1.0
+// WCTX: Deterministic test clock
+// CLOG: Replaced the local ManualClock with TestClock
+
+use ratatui_notifications::notifications::traits::TestClock;
+use ratatui_notifications::notifications::{
+    AnimationPhase, AutoDismiss, NotificationBuilder, Notifications,
+};
+use std::time::Duration;
+
+fn timed(secs: u64) -> ratatui_notifications::notifications::Notification {
+    NotificationBuilder::new("Timed")
+        .auto_dismiss(AutoDismiss::After(Duration::from_secs(secs)))
+        .build()
+        .unwrap()
+}
+
+/// Ticks in 16ms frames until both notifications dwell
+fn enter_both(manager: &mut Notifications, a: u64, b: u64) {
+    while manager.phase(a) != Some(AnimationPhase::Dwelling)
+        || manager.phase(b) != Some(AnimationPhase::Dwelling)
+    {
+        manager.tick(Duration::from_millis(16));
+    }
+}
+
+#[test]
+fn test_frozen_notification_keeps_remaining_time_while_sibling_counts_down() {
+    let mut manager = Notifications::new();
+    let frozen = manager.add(timed(10)).unwrap();
+    let sibling = manager.add(timed(10)).unwrap();
+    enter_both(&mut manager, frozen, sibling);
+
+    assert!(manager.freeze(frozen));
+    for _ in 0..120 {
+        manager.tick(Duration::from_millis(100));
+    }
+
+    // Twelve seconds later the sibling has gone and the frozen one still dwells
+    assert_eq!(manager.phase(sibling), None);
+    assert_eq!(manager.phase(frozen), Some(AnimationPhase::Dwelling));
+}
+
+#[test]
+fn test_unfreeze_resumes_the_countdown_where_it_stopped() {
+    let mut manager = Notifications::new();
+    let id = manager.add(timed(2)).unwrap();
+    let other = manager.add(timed(60)).unwrap();
+    enter_both(&mut manager, id, other);
+
+    manager.tick(Duration::from_millis(1000));
+    manager.freeze(id);
+    manager.tick(Duration::from_secs(30));
+    assert!(manager.unfreeze(id));
+
+    manager.tick(Duration::from_millis(900));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    manager.tick(Duration::from_millis(200));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+}
+
+#[test]
+fn test_freeze_holds_entry_animation() {
+    let mut manager = Notifications::new();
+    let id = manager.add(timed(5)).unwrap();
+    manager.tick(Duration::from_millis(16));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingIn));
+
+    manager.freeze(id);
+    manager.tick(Duration::from_secs(5));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingIn));
+}
+
+#[test]
+fn test_frozen_notification_ignores_ttl_until_unfrozen() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    let notif = NotificationBuilder::new("Expiring")
+        .auto_dismiss(AutoDismiss::Never)
+        .ttl(Duration::from_secs(1))
+        .build()
+        .unwrap();
+    let id = manager.add(notif).unwrap();
+    manager.freeze(id);
+
+    clock.advance(Duration::from_secs(2));
+    manager.tick(Duration::from_millis(16));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Pending));
+
+    manager.unfreeze(id);
+    manager.tick(Duration::from_millis(16));
+    assert_eq!(manager.phase(id), None);
+}
+
+#[test]
+fn test_freeze_unknown_id_returns_false() {
+    let mut manager = Notifications::new();
+    assert!(!manager.freeze(7));
+    assert!(!manager.unfreeze(7));
+}
+
+// FILE: tests/test_freeze_integration.rs - Integration tests for freezing single notifications
+// END OF VERSION: 1.1.0
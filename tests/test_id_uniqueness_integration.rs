@@ -0,0 +1,149 @@
+// FILE: tests/test_id_uniqueness_integration.rs - Integration tests for lifetime-unique notification IDs
+// VERSION: 1.2.0
+// WCTX: Peek next ID
+// CLOG: Added peek_next_id tests
+
+use ratatui_notifications::notifications::{
+    AutoDismiss, Level, NotificationBuilder, NotificationError, Notifications, Overflow,
+};
+use std::collections::HashSet;
+use std::time::Duration;
+
+fn notif(text: &str) -> ratatui_notifications::notifications::Notification {
+    NotificationBuilder::new(text.to_string())
+        .level(Level::Info)
+        .auto_dismiss(AutoDismiss::After(Duration::from_millis(100)))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_ids_never_repeat_across_thousands_of_adds_and_removals() {
+    let mut manager = Notifications::new().max_concurrent(Some(3));
+    let mut seen = HashSet::new();
+
+    for n in 0..5_000 {
+        let id = manager.add(notif(&n.to_string())).unwrap();
+        assert!(seen.insert(id), "ID {id} was reused");
+        match n % 3 {
+            0 => {
+                manager.remove(id);
+            }
+            1 => manager.tick(Duration::from_millis(400)),
+            _ => {}
+        }
+        if n % 500 == 0 {
+            manager.clear();
+        }
+    }
+}
+
+#[test]
+fn test_ids_are_not_reused_after_drain() {
+    let mut manager = Notifications::new();
+    let first = manager.add(notif("a")).unwrap();
+    manager.drain();
+
+    let second = manager.add(notif("b")).unwrap();
+    assert!(second > first);
+}
+
+#[test]
+fn test_add_with_id_rejects_previously_used_ids() {
+    let mut manager = Notifications::new();
+    let id = manager.add(notif("a")).unwrap();
+    manager.remove(id);
+
+    assert_eq!(manager.add_with_id(id, notif("b")), Err(NotificationError::IdAlreadyUsed(id)));
+}
+
+#[test]
+fn test_add_with_id_moves_the_high_water_mark() {
+    let mut manager = Notifications::new();
+    assert_eq!(manager.add_with_id(100, notif("a")), Ok(100));
+
+    // IDs below the mark count as used, even if never assigned
+    assert_eq!(manager.add_with_id(50, notif("b")), Err(NotificationError::IdAlreadyUsed(50)));
+    assert_eq!(manager.add_with_id(100, notif("c")), Err(NotificationError::IdAlreadyUsed(100)));
+    assert_eq!(manager.add(notif("d")), Ok(101));
+    assert_eq!(manager.add_with_id(200, notif("e")), Ok(200));
+}
+
+#[test]
+fn test_add_with_id_is_never_merged() {
+    let mut manager = Notifications::new().overflow(Overflow::MergeDuplicates);
+    manager.add(notif("same")).unwrap();
+
+    assert_eq!(manager.add_with_id(10, notif("same")), Ok(10));
+    assert!(manager.remove(10));
+}
+
+#[test]
+fn test_ids_exhausted_after_the_last_id() {
+    let mut manager = Notifications::new();
+    assert_eq!(manager.add_with_id(u64::MAX, notif("last")), Ok(u64::MAX));
+
+    assert_eq!(manager.add(notif("more")), Err(NotificationError::IdsExhausted));
+    assert_eq!(manager.add_hidden(notif("more")), Err(NotificationError::IdsExhausted));
+}
+
+#[test]
+fn test_ids_near_the_limit_never_wrap_onto_active_ids() {
+    let mut manager = Notifications::new();
+    assert_eq!(manager.add(notif("first")), Ok(0));
+    assert_eq!(manager.add_with_id(u64::MAX - 1, notif("near the end")), Ok(u64::MAX - 1));
+
+    assert_eq!(manager.add(notif("last")), Ok(u64::MAX));
+    // Wrapping would hand out 0, which is still active
+    assert_eq!(manager.add(notif("wrapped")), Err(NotificationError::IdsExhausted));
+    assert!(manager.phase(0).is_some());
+    assert!(manager.remove(0));
+    assert_eq!(manager.add(notif("wrapped")), Err(NotificationError::IdsExhausted));
+}
+
+#[test]
+fn test_peek_next_id_predicts_add() {
+    let mut manager = Notifications::new();
+    assert_eq!(manager.peek_next_id(), Some(0));
+    // Peeking does not consume the ID
+    assert_eq!(manager.peek_next_id(), Some(0));
+
+    for n in 0..5 {
+        let peeked = manager.peek_next_id().unwrap();
+        assert_eq!(manager.add(notif(&n.to_string())), Ok(peeked));
+    }
+    manager.add_with_id(100, notif("chosen")).unwrap();
+    assert_eq!(manager.peek_next_id(), Some(101));
+    assert_eq!(manager.add_hidden(notif("hidden")), Ok(101));
+}
+
+#[test]
+fn test_peek_next_id_after_exhaustion() {
+    let mut manager = Notifications::new();
+    manager.add_with_id(u64::MAX - 1, notif("a")).unwrap();
+    assert_eq!(manager.peek_next_id(), Some(u64::MAX));
+    assert_eq!(manager.add(notif("b")), Ok(u64::MAX));
+    assert_eq!(manager.peek_next_id(), None);
+}
+
+#[test]
+fn test_old_ids_never_resolve_to_new_notifications() {
+    let mut manager = Notifications::new().history_limit(1_000);
+    let mut finished = Vec::new();
+    for n in 0..200 {
+        let id = manager.add(notif(&format!("message {n}"))).unwrap();
+        manager.tick(Duration::from_millis(16));
+        manager.remove(id);
+        finished.push(id);
+    }
+
+    for id in finished {
+        assert_eq!(manager.phase(id), None);
+        assert!(!manager.remove(id));
+        // Each ID has exactly one history entry
+        assert_eq!(manager.history().filter(|record| record.id == id).count(), 1);
+    }
+}
+
+// FILE: tests/test_id_uniqueness_integration.rs - Integration tests for lifetime-unique notification IDs
+// END OF VERSION: 1.2.0
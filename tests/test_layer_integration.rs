@@ -0,0 +1,105 @@
+// FILE: tests/test_layer_integration.rs - Integration tests for render layers
+// VERSION: 1.0.0
+// WCTX: Render layers
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    generate_code, Anchor, Animation, Layer, NotificationBuilder, Notifications,
+};
+use std::time::Duration;
+
+/// Adds a "B" notification below and then an "A" notification above at the
+/// same anchor, both dwelling.
+fn layered_manager() -> Notifications {
+    let mut manager = Notifications::new();
+    for (glyph, layer) in [("B", Layer::Below), ("A", Layer::Above)] {
+        let notif = NotificationBuilder::new(glyph)
+            .anchor(Anchor::TopLeft)
+            .animation(Animation::Fade)
+            .layer(layer)
+            .build()
+            .unwrap();
+        manager.add(notif).unwrap();
+    }
+
+    // Past the default 500ms entry so both are dwelling at their full rects
+    manager.tick(Duration::from_millis(600));
+    manager
+}
+
+fn render_with(draw: impl FnOnce(&mut ratatui::Frame<'_>)) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(40, 20)).unwrap();
+    terminal.draw(draw).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn symbols(buffer: &Buffer) -> String {
+    buffer.content().iter().map(|cell| cell.symbol()).collect()
+}
+
+/// Row of the first cell showing `glyph`, if any.
+fn row_of(buffer: &Buffer, glyph: &str) -> Option<u16> {
+    let width = buffer.area.width;
+    buffer
+        .content()
+        .iter()
+        .position(|cell| cell.symbol() == glyph)
+        .map(|index| index as u16 / width)
+}
+
+#[test]
+fn test_default_layer_is_below() {
+    assert_eq!(Layer::default(), Layer::Below);
+    let notif = NotificationBuilder::new("x").build().unwrap();
+    assert_eq!(notif.layer(), Layer::Below);
+}
+
+#[test]
+fn test_render_layer_draws_only_requested_layer() {
+    let mut manager = layered_manager();
+    let below = render_with(|frame| manager.render_layer(frame, frame.area(), Layer::Below));
+    assert!(symbols(&below).contains('B'));
+    assert!(!symbols(&below).contains('A'));
+
+    let above = render_with(|frame| manager.render_layer(frame, frame.area(), Layer::Above));
+    assert!(symbols(&above).contains('A'));
+    assert!(!symbols(&above).contains('B'));
+}
+
+#[test]
+fn test_layers_stack_without_overlapping() {
+    let mut manager = layered_manager();
+    let below = render_with(|frame| manager.render_layer(frame, frame.area(), Layer::Below));
+    let above = render_with(|frame| manager.render_layer(frame, frame.area(), Layer::Above));
+
+    // The newer "A" stacks below "B" even though they render separately
+    let b_row = row_of(&below, "B").unwrap();
+    let a_row = row_of(&above, "A").unwrap();
+    assert!(a_row > b_row, "A at row {a_row} should stack under B at row {b_row}");
+}
+
+#[test]
+fn test_render_layer_matches_full_render_positions() {
+    let mut manager = layered_manager();
+    let full = render_with(|frame| manager.render(frame, frame.area()));
+    let layered = render_with(|frame| {
+        manager.render_layer(frame, frame.area(), Layer::Below);
+        manager.render_layer(frame, frame.area(), Layer::Above);
+    });
+    assert_eq!(full, layered);
+}
+
+#[test]
+fn test_generate_code_includes_non_default_layer() {
+    let notif = NotificationBuilder::new("x").layer(Layer::Above).build().unwrap();
+    assert!(generate_code(&notif).contains(".layer(Layer::Above)"));
+
+    let notif = NotificationBuilder::new("x").build().unwrap();
+    assert!(!generate_code(&notif).contains(".layer("));
+}
+
+// FILE: tests/test_layer_integration.rs - Integration tests for render layers
+// END OF VERSION: 1.0.0
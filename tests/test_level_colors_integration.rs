@@ -0,0 +1,81 @@
+// FILE: tests/test_level_colors_integration.rs - Integration tests for per-level color overrides
+// VERSION: 1.0.0
+// WCTX: Per-level color overrides
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Anchor, AnimationPhase, Level, NotificationBuilder, Notifications, Theme,
+};
+use ratatui_notifications::styles::for_level;
+use std::time::Duration;
+
+const LEVELS: [Level; 5] = [Level::Info, Level::Warn, Level::Error, Level::Debug, Level::Trace];
+
+/// Renders one settled, titled notification of `level` in the top-left corner
+fn render(manager: &mut Notifications, level: Level) -> Buffer {
+    manager.clear();
+    let notification =
+        NotificationBuilder::new("Body").title("Title").level(level).anchor(Anchor::TopLeft);
+    let id = manager.add(notification.build().unwrap()).unwrap();
+    while manager.phase(id) != Some(AnimationPhase::Dwelling) {
+        manager.tick(Duration::from_millis(10));
+    }
+    let mut terminal = Terminal::new(TestBackend::new(40, 6)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+#[test]
+fn test_override_changes_only_that_level() {
+    let mut manager = Notifications::new();
+    manager.set_level_colors(Level::Debug, Color::LightMagenta, Color::Magenta);
+
+    assert_eq!(render(&mut manager, Level::Debug)[(0, 0)].fg, Color::LightMagenta);
+    for level in LEVELS.into_iter().filter(|&level| level != Level::Debug) {
+        let expected = for_level(Some(level), &Theme::default()).border.fg;
+        let buffer = render(&mut manager, level);
+        assert_eq!(buffer[(0, 0)].fg, expected.unwrap_or(Color::Reset), "{level:?}");
+    }
+}
+
+#[test]
+fn test_override_recolors_the_title() {
+    let mut manager = Notifications::new();
+    manager.set_level_colors(Level::Debug, Color::LightMagenta, Color::Magenta);
+
+    let buffer = render(&mut manager, Level::Debug);
+    let x = (0..40).find(|&x| buffer[(x, 0)].symbol() == "T").unwrap();
+    assert_eq!(buffer[(x, 0)].fg, Color::Magenta);
+}
+
+#[test]
+fn test_override_keeps_level_modifiers() {
+    let mut manager = Notifications::new().theme(Theme::high_contrast());
+    manager.set_level_colors(Level::Error, Color::Red, Color::LightRed);
+
+    let buffer = render(&mut manager, Level::Error);
+    let styles = for_level(Some(Level::Error), &Theme::high_contrast());
+    assert_eq!(buffer[(0, 0)].fg, Color::Red);
+    assert_eq!(buffer[(0, 0)].symbol(), "╔");
+    assert!(buffer[(0, 0)].modifier.contains(styles.border.add_modifier));
+    assert!(styles.title.add_modifier.contains(Modifier::BOLD));
+}
+
+#[test]
+fn test_override_applies_to_shown_notifications() {
+    let mut manager = Notifications::new();
+    let before = render(&mut manager, Level::Warn);
+    assert_ne!(before[(0, 0)].fg, Color::Cyan);
+
+    manager.set_level_colors(Level::Warn, Color::Cyan, Color::Cyan);
+    let mut terminal = Terminal::new(TestBackend::new(40, 6)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    assert_eq!(terminal.backend().buffer()[(0, 0)].fg, Color::Cyan);
+}
+
+// FILE: tests/test_level_colors_integration.rs - Integration tests for per-level color overrides
+// END OF VERSION: 1.0.0
@@ -0,0 +1,116 @@
+// FILE: tests/test_live_content_integration.rs - Integration tests for live notification content
+// VERSION: 1.0.0
+// WCTX: Live content
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, NotificationBuilder, Notifications,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+fn add_live(
+    manager: &mut Notifications,
+    producer: impl FnMut() -> String + Send + 'static,
+) -> u64 {
+    let notif = NotificationBuilder::new("Initial")
+        .anchor(Anchor::TopLeft)
+        .animation(Animation::Fade)
+        .live_content(Box::new(producer))
+        .build()
+        .unwrap();
+    manager.add(notif).unwrap()
+}
+
+/// Renders the manager and returns the buffer rows as strings
+fn render_rows(manager: &mut Notifications, terminal: &mut Terminal<TestBackend>) -> Vec<String> {
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let buffer = terminal.backend().buffer();
+    let area = buffer.area;
+    (area.top()..area.bottom())
+        .map(|y| (area.left()..area.right()).map(|x| buffer[(x, y)].symbol()).collect())
+        .collect()
+}
+
+fn shows(rows: &[String], text: &str) -> bool {
+    rows.iter().any(|row| row.contains(text))
+}
+
+#[test]
+fn test_live_content_updates_each_tick() {
+    let mut manager = Notifications::new();
+    let mut count = 0;
+    add_live(&mut manager, move || {
+        count += 1;
+        format!("Count {count}")
+    });
+    let mut terminal = Terminal::new(TestBackend::new(60, 20)).unwrap();
+
+    manager.tick(Duration::from_millis(600));
+    assert!(shows(&render_rows(&mut manager, &mut terminal), "Count 1"));
+
+    manager.tick(Duration::from_millis(16));
+    let rows = render_rows(&mut manager, &mut terminal);
+    assert!(shows(&rows, "Count 2"));
+    assert!(!shows(&rows, "Count 1"));
+}
+
+#[test]
+fn test_live_content_called_once_per_tick() {
+    let mut manager = Notifications::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    add_live(&mut manager, move || {
+        counter.fetch_add(1, Ordering::SeqCst);
+        "Steady".to_string()
+    });
+
+    for _ in 0..3 {
+        manager.tick(Duration::from_millis(16));
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn test_live_content_resizes_when_width_changes() {
+    let mut manager = Notifications::new();
+    let mut count = 0;
+    let id = add_live(&mut manager, move || {
+        count += 1;
+        "#".repeat(count * 5)
+    });
+    let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+
+    manager.tick(Duration::from_millis(600));
+    render_rows(&mut manager, &mut terminal);
+    let narrow = manager.content_rect(id).unwrap();
+
+    manager.tick(Duration::from_millis(16));
+    let rows = render_rows(&mut manager, &mut terminal);
+    let wide = manager.content_rect(id).unwrap();
+
+    assert_eq!(wide.width, narrow.width + 5);
+    assert!(shows(&rows, &"#".repeat(10)));
+}
+
+#[test]
+fn test_live_content_stops_after_removal() {
+    let mut manager = Notifications::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let id = add_live(&mut manager, move || {
+        counter.fetch_add(1, Ordering::SeqCst);
+        "Gone soon".to_string()
+    });
+
+    manager.tick(Duration::from_millis(16));
+    assert!(manager.remove(id));
+    manager.tick(Duration::from_millis(16));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+// FILE: tests/test_live_content_integration.rs - Integration tests for live notification content
+// END OF VERSION: 1.0.0
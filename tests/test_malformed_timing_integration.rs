@@ -0,0 +1,171 @@
+// FILE: tests/test_malformed_timing_integration.rs - Integration tests for extreme timings and speeds
+// VERSION: 1.0.0
+// WCTX: Non-finite progress guards
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::layout::{Position, Rect};
+use ratatui::Terminal;
+use ratatui_notifications::notifications::functions::fnc_expand_calculate_rect::{
+    calculate_rect_from_origin, calculate_rect_toward,
+};
+use ratatui_notifications::notifications::functions::fnc_move_calculate_rect::move_calculate_rect;
+use ratatui_notifications::notifications::functions::fnc_slide_calculate_rect::slide_calculate_rect;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, AnimationPhase, AutoDismiss, ExpandOrigin, ManagerDefaults,
+    NotificationBuilder, NotificationState, Notifications, SlideDirection, Timing,
+};
+use std::time::Duration;
+
+const FRAME: Rect = Rect::new(0, 0, 80, 24);
+const FULL: Rect = Rect::new(50, 18, 30, 6);
+const MALFORMED: [f32; 5] = [f32::NAN, f32::INFINITY, f32::NEG_INFINITY, 1e30, -1e30];
+
+fn in_frame(rect: Rect) -> bool {
+    rect.intersection(FRAME) == rect || rect.is_empty()
+}
+
+fn frame_tick() -> Duration {
+    Duration::from_millis(16)
+}
+
+/// Renders the current frame, running the debug assertions and overflow
+/// checks of the render path
+fn render(manager: &mut Notifications) {
+    let mut terminal = Terminal::new(TestBackend::new(FRAME.width, FRAME.height)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+}
+
+fn with_timing(entry: Duration, exit: Duration) -> NotificationBuilder {
+    NotificationBuilder::new("Extreme")
+        .anchor(Anchor::BottomRight)
+        .timing(Timing::Fixed(entry), Timing::Auto, Timing::Fixed(exit))
+        .auto_dismiss(AutoDismiss::After(Duration::from_millis(100)))
+}
+
+#[test]
+fn test_rect_functions_stay_in_frame_for_malformed_progress() {
+    for progress in MALFORMED {
+        for phase in [AnimationPhase::SlidingIn, AnimationPhase::SlidingOut] {
+            let rect = slide_calculate_rect(
+                FULL,
+                FRAME,
+                progress,
+                phase,
+                Anchor::BottomRight,
+                SlideDirection::Default,
+                None,
+                None,
+            );
+            assert!(in_frame(rect), "slide {phase:?} at {progress}: {rect:?}");
+        }
+        for phase in [AnimationPhase::Expanding, AnimationPhase::Collapsing] {
+            let rect = calculate_rect_from_origin(
+                FULL,
+                FRAME,
+                phase,
+                progress,
+                ExpandOrigin::Anchor,
+                Anchor::BottomRight,
+            );
+            assert!(in_frame(rect), "expand {phase:?} at {progress}: {rect:?}");
+        }
+        let rect = calculate_rect_toward(FULL, FRAME, Position::new(79, 0), progress);
+        assert!(in_frame(rect), "toward at {progress}: {rect:?}");
+        let rect = move_calculate_rect(Rect::new(0, 0, 30, 6), FULL, FRAME, progress);
+        assert!(in_frame(rect), "move at {progress}: {rect:?}");
+    }
+}
+
+#[test]
+fn test_nan_progress_is_treated_as_the_start() {
+    let rect = calculate_rect_toward(FULL, FRAME, Position::new(79, 0), f32::NAN);
+    assert_eq!(rect, FULL);
+}
+
+#[test]
+fn test_max_entry_keeps_progress_finite() {
+    let mut manager = Notifications::new();
+    let id = manager.add(with_timing(Duration::MAX, Duration::MAX).build().unwrap()).unwrap();
+
+    for frame in 0..200 {
+        manager.tick(frame_tick());
+        if frame % 50 == 0 {
+            render(&mut manager);
+        }
+    }
+    // Documented: a Duration::MAX entry never completes at frame rate
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingIn));
+    assert!(!manager.describe().is_empty());
+
+    // Dismissing mid-entry reverses into a Duration::MAX exit
+    manager.retain(|_| false);
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+    manager.tick(frame_tick());
+    render(&mut manager);
+
+    // One saturating tick still ends it
+    manager.tick(Duration::MAX);
+    assert_eq!(manager.phase(id), None);
+}
+
+#[test]
+fn test_max_tick_completes_every_phase() {
+    let mut manager = Notifications::new();
+    let id = manager.add(with_timing(Duration::MAX, Duration::MAX).build().unwrap()).unwrap();
+
+    manager.tick(Duration::MAX);
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    render(&mut manager);
+    manager.tick(Duration::MAX);
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+    manager.tick(Duration::MAX);
+    assert_eq!(manager.phase(id), None);
+}
+
+#[test]
+fn test_zero_timings_complete_immediately() {
+    for animation in [Animation::Slide, Animation::Fade, Animation::ExpandCollapse, Animation::RollDown] {
+        let mut manager = Notifications::new();
+        let notification = with_timing(Duration::ZERO, Duration::ZERO)
+            .animation(animation)
+            .auto_dismiss(AutoDismiss::After(Duration::from_millis(1)))
+            .build()
+            .unwrap();
+        let id = manager.add(notification).unwrap();
+
+        manager.tick(Duration::ZERO);
+        assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling), "{animation:?}");
+        render(&mut manager);
+        manager.tick(frame_tick());
+        assert_eq!(manager.phase(id), Some(AnimationPhase::exit(animation)), "{animation:?}");
+        render(&mut manager);
+        manager.tick(Duration::ZERO);
+        assert_eq!(manager.phase(id), None, "{animation:?}");
+    }
+}
+
+#[test]
+fn test_tiny_speed_keeps_progress_finite_and_completes_eventually() {
+    let notification = with_timing(Duration::from_millis(500), Duration::from_millis(500))
+        .speed(1e-9)
+        .build()
+        .unwrap();
+    let mut state = NotificationState::new(1, notification.clone(), &ManagerDefaults::default());
+    for _ in 0..1000 {
+        state.update(frame_tick());
+    }
+    assert_eq!(state.phase(), AnimationPhase::SlidingIn);
+    assert!(state.progress().is_finite() && state.progress() < 1e-3, "{}", state.progress());
+
+    let mut manager = Notifications::new();
+    let id = manager.add(notification).unwrap();
+    manager.tick(frame_tick());
+    render(&mut manager);
+    // 1e-9 speed: the 500ms entry needs 5e8 seconds of ticks
+    manager.tick(Duration::from_secs(500_000_000));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+}
+
+// FILE: tests/test_malformed_timing_integration.rs - Integration tests for extreme timings and speeds
+// END OF VERSION: 1.0.0
@@ -0,0 +1,145 @@
+// FILE: tests/test_measure_integration.rs - Integration tests for measuring text without a notification
+// VERSION: 1.0.0
+// WCTX: Public measure API
+// CLOG: Initial creation
+
+use ratatui::layout::Rect;
+use ratatui::widgets::{BorderType, Padding};
+use ratatui_notifications::notifications::{
+    calculate_size, measure, MeasureOptions, NotificationBuilder, SizeConstraint, TruncateMode,
+};
+
+const FRAME: Rect = Rect::new(0, 0, 120, 40);
+const TEXTS: [&str; 4] = [
+    "Saved",
+    "The nightly build finished with three warnings and no errors at all",
+    "first line\nsecond, somewhat longer line\nthird",
+    "",
+];
+
+/// Asserts `measure` agrees with `calculate_size` of the built notification
+fn assert_parity(builder: impl Fn(&'static str) -> NotificationBuilder, options: MeasureOptions) {
+    for text in TEXTS {
+        let notification = builder(text).build().unwrap();
+        assert_eq!(
+            measure(text, &options),
+            calculate_size(&notification, FRAME),
+            "{text:?} with {options:?}"
+        );
+    }
+}
+
+#[test]
+fn test_defaults_match_builder_defaults() {
+    assert_parity(NotificationBuilder::new, MeasureOptions::new(FRAME));
+}
+
+#[test]
+fn test_title_and_separator_match() {
+    let options = MeasureOptions {
+        title: Some("Nightly build".into()),
+        title_separator: true,
+        ..MeasureOptions::new(FRAME)
+    };
+    assert_parity(
+        |text| {
+            NotificationBuilder::new(text)
+                .title("Nightly build")
+                .title_separator(true)
+        },
+        options,
+    );
+}
+
+#[test]
+fn test_padding_and_border_match() {
+    let options = MeasureOptions {
+        padding: Padding::uniform(2),
+        border_type: Some(BorderType::Double),
+        ..MeasureOptions::new(FRAME)
+    };
+    assert_parity(
+        |text| {
+            NotificationBuilder::new(text)
+                .padding(Padding::uniform(2))
+                .border_type(BorderType::Double)
+        },
+        options,
+    );
+}
+
+#[test]
+fn test_borderless_drops_the_border_cells() {
+    let bordered = MeasureOptions::new(FRAME);
+    let borderless = MeasureOptions {
+        border_type: None,
+        ..bordered.clone()
+    };
+
+    assert_eq!(measure("Saved", &bordered), (9, 3));
+    // Padding still applies; the 3x3 minimum keeps the height
+    assert_eq!(measure("Saved", &borderless), (7, 3));
+}
+
+#[test]
+fn test_constraints_match() {
+    let options = MeasureOptions {
+        max_width: Some(SizeConstraint::Absolute(20)),
+        max_height: Some(SizeConstraint::Percentage(0.5)),
+        min_width: Some(SizeConstraint::Absolute(12)),
+        min_height: Some(SizeConstraint::Absolute(4)),
+        ..MeasureOptions::new(FRAME)
+    };
+    assert_parity(
+        |text| {
+            NotificationBuilder::new(text)
+                .max_size(
+                    SizeConstraint::Absolute(20),
+                    SizeConstraint::Percentage(0.5),
+                )
+                .min_size(SizeConstraint::Absolute(12), SizeConstraint::Absolute(4))
+        },
+        options,
+    );
+}
+
+#[test]
+fn test_truncation_matches() {
+    let options = MeasureOptions {
+        max_width: Some(SizeConstraint::Absolute(20)),
+        truncate_mode: Some(TruncateMode::End),
+        ellipsis: "...".into(),
+        ..MeasureOptions::new(FRAME)
+    };
+    assert_parity(
+        |text| {
+            NotificationBuilder::new(text)
+                .max_size(
+                    SizeConstraint::Absolute(20),
+                    SizeConstraint::Percentage(0.2),
+                )
+                .truncate_mode(TruncateMode::End)
+                .ellipsis("...")
+        },
+        options,
+    );
+}
+
+#[test]
+fn test_truncation_keeps_lines_on_one_row() {
+    let wrap = MeasureOptions {
+        max_width: Some(SizeConstraint::Absolute(20)),
+        ..MeasureOptions::new(FRAME)
+    };
+    let truncate = MeasureOptions {
+        truncate_mode: Some(TruncateMode::End),
+        ..wrap.clone()
+    };
+
+    let (_, wrapped) = measure(TEXTS[1], &wrap);
+    assert_eq!(measure(TEXTS[1], &truncate), (20, 3));
+    assert!(wrapped > 3);
+}
+
+// FILE: tests/test_measure_integration.rs - Integration tests for measuring text without a notification
+// END OF VERSION: 1.0.0
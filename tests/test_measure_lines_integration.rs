@@ -0,0 +1,140 @@
+// FILE: tests/test_measure_lines_integration.rs - Integration tests for wrapped line measurement
+// VERSION: 1.0.0
+// WCTX: Wrapped line measurement
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+use ratatui::text::Span;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    measure, measure_lines, split_to_fit, Anchor, Animation, MeasureOptions, NotificationBuilder,
+    Notifications, SizeConstraint, TruncateMode,
+};
+use std::time::Duration;
+
+const FRAME: Rect = Rect::new(0, 0, 60, 20);
+
+fn options(width: u16) -> MeasureOptions {
+    MeasureOptions {
+        max_width: Some(SizeConstraint::Absolute(width)),
+        max_height: Some(SizeConstraint::Absolute(FRAME.height)),
+        ..MeasureOptions::new(FRAME)
+    }
+}
+
+fn builder(text: &str, width: u16) -> NotificationBuilder {
+    NotificationBuilder::new(text.to_string())
+        .anchor(Anchor::TopLeft)
+        .animation(Animation::Fade)
+        .fade(false)
+        .max_size(SizeConstraint::Absolute(width), SizeConstraint::Absolute(FRAME.height))
+}
+
+/// Renders the notification and reads back its content rows as drawn
+fn rendered_rows(builder: NotificationBuilder) -> Vec<String> {
+    let mut manager = Notifications::new();
+    let id = manager.add(builder.build().unwrap()).unwrap();
+    manager.tick(Duration::from_secs(1));
+
+    let mut terminal = Terminal::new(TestBackend::new(FRAME.width, FRAME.height)).unwrap();
+    terminal
+        .draw(|frame| manager.render(frame, frame.area()))
+        .unwrap();
+    let buffer = terminal.backend().buffer();
+    let area = manager.content_rect(id).unwrap();
+
+    let mut rows: Vec<String> = (area.top()..area.bottom())
+        .map(|y| {
+            let mut row = String::new();
+            let mut x = area.left();
+            while x < area.right() {
+                let symbol = buffer[(x, y)].symbol();
+                row.push_str(symbol);
+                x += Span::raw(symbol).width().max(1) as u16;
+            }
+            row.trim_end().to_string()
+        })
+        .collect();
+    while rows.last().is_some_and(String::is_empty) {
+        rows.pop();
+    }
+    rows
+}
+
+fn assert_matches_render(text: &str, width: u16) {
+    assert_eq!(
+        measure_lines(text, &options(width)),
+        rendered_rows(builder(text, width)),
+        "{text:?} at width {width}"
+    );
+}
+
+#[test]
+fn test_lines_match_render_for_word_wrap() {
+    assert_matches_render("The nightly build finished with three warnings and no errors", 20);
+}
+
+#[test]
+fn test_lines_match_render_for_explicit_newlines() {
+    assert_matches_render("first line\nsecond, somewhat longer line\nthird", 16);
+}
+
+#[test]
+fn test_lines_match_render_for_word_longer_than_width() {
+    assert_matches_render("see /var/log/builds/nightly-2024-06-01.log now", 14);
+}
+
+#[test]
+fn test_lines_match_render_for_wide_graphemes() {
+    assert_matches_render("ビルドが完了しました 警告が三件あります", 14);
+}
+
+#[test]
+fn test_lines_match_render_for_truncation() {
+    let text = "/home/user/projects/app/src/main.rs\nshort";
+    let options = MeasureOptions { truncate_mode: Some(TruncateMode::Middle), ..options(20) };
+    let rendered = rendered_rows(builder(text, 20).truncate_mode(TruncateMode::Middle));
+    assert_eq!(measure_lines(text, &options), rendered);
+    assert!(rendered[0].contains('…'));
+}
+
+#[test]
+fn test_line_count_matches_measured_height() {
+    let text = "The nightly build finished with three warnings and no errors";
+    let options = options(20);
+    let (_, height) = measure(text, &options);
+    // No vertical padding by default, so only the border adds rows
+    assert_eq!(measure_lines(text, &options).len() as u16 + 2, height);
+}
+
+#[test]
+fn test_split_to_fit_pieces_fit_the_height() {
+    let text = "one two three four five six seven eight nine ten eleven twelve";
+    let options = options(12);
+    let pieces = split_to_fit(text, &options, 4);
+
+    assert!(pieces.len() > 1);
+    for piece in &pieces {
+        assert!(measure(piece, &options).1 <= 4, "{piece:?}");
+    }
+    let words: Vec<&str> = pieces.iter().flat_map(|piece| piece.split_whitespace()).collect();
+    assert_eq!(words, text.split_whitespace().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_split_to_fit_keeps_short_content_whole() {
+    let pieces = split_to_fit("Saved", &options(20), 10);
+    assert_eq!(pieces, vec!["Saved"]);
+    // Empty content still draws one blank row
+    assert_eq!(split_to_fit("", &options(20), 10), vec![""]);
+}
+
+#[test]
+fn test_split_to_fit_puts_one_row_per_piece_when_too_short() {
+    let pieces = split_to_fit("alpha beta gamma", &options(9), 1);
+    assert_eq!(pieces, vec!["alpha", "beta", "gamma"]);
+}
+
+// FILE: tests/test_measure_lines_integration.rs - Integration tests for wrapped line measurement
+// END OF VERSION: 1.0.0
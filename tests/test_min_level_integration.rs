@@ -0,0 +1,102 @@
+// FILE: tests/test_min_level_integration.rs - Integration tests for the manager's severity floor
+// VERSION: 1.0.0
+// WCTX: Severity floor filter
+// CLOG: Initial creation
+
+use ratatui_notifications::notifications::{Level, NotificationBuilder, Notifications};
+
+fn leveled(level: Level) -> NotificationBuilder {
+    NotificationBuilder::new(format!("{level:?} message")).level(level)
+}
+
+#[test]
+fn test_levels_are_ordered_by_severity() {
+    let mut levels = [Level::Error, Level::Trace, Level::Warn, Level::Info, Level::Debug];
+    levels.sort();
+
+    assert_eq!(levels, [Level::Trace, Level::Debug, Level::Info, Level::Warn, Level::Error]);
+    assert!(Level::Warn > Level::Info);
+    assert_eq!(Level::Error.max(Level::Debug), Level::Error);
+}
+
+#[test]
+fn test_notifications_below_the_floor_are_dropped() {
+    let mut manager = Notifications::new();
+    manager.min_level(Level::Warn);
+
+    let ids: Vec<(Level, u64)> = [Level::Trace, Level::Debug, Level::Info, Level::Warn, Level::Error]
+        .into_iter()
+        .map(|level| (level, manager.add(leveled(level).build().unwrap()).unwrap()))
+        .collect();
+
+    for (level, id) in ids {
+        assert_eq!(manager.phase(id).is_some(), level >= Level::Warn, "{level:?}");
+    }
+}
+
+#[test]
+fn test_dropped_notifications_still_take_an_id() {
+    let mut manager = Notifications::new();
+    manager.min_level(Level::Error);
+
+    let dropped = manager.add(leveled(Level::Info).build().unwrap()).unwrap();
+    let shown = manager.add(leveled(Level::Error).build().unwrap()).unwrap();
+    assert_ne!(dropped, shown);
+}
+
+#[test]
+fn test_builder_default_level_is_filtered_as_info() {
+    let mut manager = Notifications::new();
+    manager.min_level(Level::Info);
+    let shown = manager.add(NotificationBuilder::new("Plain").build().unwrap()).unwrap();
+    manager.min_level(Level::Warn);
+    let dropped = manager.add(NotificationBuilder::new("Plain").build().unwrap()).unwrap();
+
+    assert!(manager.phase(shown).is_some());
+    assert!(manager.phase(dropped).is_none());
+}
+
+#[test]
+fn test_runtime_change_affects_only_later_adds() {
+    let mut manager = Notifications::new();
+    let before = manager.add(leveled(Level::Info).build().unwrap()).unwrap();
+
+    manager.min_level(Level::Warn);
+    let after = manager.add(leveled(Level::Info).build().unwrap()).unwrap();
+    assert!(manager.phase(before).is_some());
+    assert!(manager.phase(after).is_none());
+
+    manager.min_level(Level::Trace);
+    let restored = manager.add(leveled(Level::Debug).build().unwrap()).unwrap();
+    assert!(manager.phase(restored).is_some());
+}
+
+#[test]
+fn test_filtered_notifications_are_not_recorded_by_default() {
+    let mut manager = Notifications::new().history_limit(10);
+    manager.min_level(Level::Warn);
+    manager.add(leveled(Level::Info).build().unwrap()).unwrap();
+    manager.add(leveled(Level::Error).build().unwrap()).unwrap();
+
+    let levels: Vec<Option<Level>> = manager.history().map(|record| record.level).collect();
+    assert_eq!(levels, vec![Some(Level::Error)]);
+}
+
+#[test]
+fn test_record_filtered_keeps_a_dismissed_history_entry() {
+    let mut manager = Notifications::new().history_limit(10).record_filtered(true);
+    manager.min_level(Level::Warn);
+    let dropped = manager.add(leveled(Level::Debug).build().unwrap()).unwrap();
+    let shown = manager.add(leveled(Level::Warn).build().unwrap()).unwrap();
+
+    let records: Vec<_> = manager.history().collect();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].id, dropped);
+    assert!(records[0].filtered && records[0].is_dismissed());
+    assert_eq!(records[0].content.to_string(), "Debug message");
+    assert_eq!(records[1].id, shown);
+    assert!(!records[1].filtered && !records[1].is_dismissed());
+}
+
+// FILE: tests/test_min_level_integration.rs - Integration tests for the manager's severity floor
+// END OF VERSION: 1.0.0
@@ -0,0 +1,111 @@
+// FILE: tests/test_move_to_anchor_integration.rs - Integration tests for animated anchor moves
+// VERSION: 1.0.0
+// WCTX: Animated anchor moves
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::layout::{Position, Rect};
+use ratatui::Terminal;
+use ratatui_notifications::notifications::functions::fnc_move_calculate_rect::move_calculate_rect;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, NotificationBuilder, Notifications,
+};
+use std::time::Duration;
+
+/// Renders the manager and returns the top-left corner of the drawn box
+fn top_left_corner(manager: &mut Notifications, terminal: &mut Terminal<TestBackend>) -> Position {
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let buffer = terminal.backend().buffer();
+    let area = buffer.area;
+    (area.top()..area.bottom())
+        .flat_map(|y| (area.left()..area.right()).map(move |x| Position::new(x, y)))
+        .find(|&pos| buffer[pos].symbol() == "╭")
+        .expect("no notification drawn")
+}
+
+fn dwelling_manager() -> (Notifications, u64, Terminal<TestBackend>) {
+    let mut manager = Notifications::new().move_duration(Duration::from_millis(400));
+    let notif = NotificationBuilder::new("Moving")
+        .anchor(Anchor::BottomRight)
+        .animation(Animation::Fade)
+        .build()
+        .unwrap();
+    let id = manager.add(notif).unwrap();
+    manager.tick(Duration::from_millis(600));
+    let terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    (manager, id, terminal)
+}
+
+#[test]
+fn test_move_updates_stored_anchor() {
+    let (mut manager, id, _) = dwelling_manager();
+
+    assert_eq!(manager.anchor(id), Some(Anchor::BottomRight));
+    assert!(manager.move_to_anchor(id, Anchor::TopLeft));
+    assert_eq!(manager.anchor(id), Some(Anchor::TopLeft));
+}
+
+#[test]
+fn test_move_unknown_id_returns_false() {
+    let mut manager = Notifications::new();
+    assert!(!manager.move_to_anchor(7, Anchor::TopLeft));
+    assert_eq!(manager.anchor(7), None);
+}
+
+#[test]
+fn test_move_eases_toward_new_anchor() {
+    let (mut manager, id, mut terminal) = dwelling_manager();
+    let start = top_left_corner(&mut manager, &mut terminal);
+
+    manager.move_to_anchor(id, Anchor::TopLeft);
+    let first = top_left_corner(&mut manager, &mut terminal);
+    assert_eq!(first, start, "move should start from the current position");
+
+    manager.tick(Duration::from_millis(100));
+    let early = top_left_corner(&mut manager, &mut terminal);
+    manager.tick(Duration::from_millis(100));
+    let later = top_left_corner(&mut manager, &mut terminal);
+
+    assert!(early.x < start.x && early.y < start.y);
+    assert!(later.x < early.x && later.y < early.y);
+    assert!(later.x > 0 && later.y > 0, "should still be in transit");
+
+    manager.tick(Duration::from_millis(250));
+    assert_eq!(top_left_corner(&mut manager, &mut terminal), Position::new(0, 0));
+}
+
+#[test]
+fn test_moved_notification_stacks_at_new_anchor() {
+    let (mut manager, id, mut terminal) = dwelling_manager();
+    let other = NotificationBuilder::new("Already here")
+        .anchor(Anchor::TopLeft)
+        .animation(Animation::Fade)
+        .build()
+        .unwrap();
+    manager.add(other).unwrap();
+    manager.tick(Duration::from_millis(600));
+    top_left_corner(&mut manager, &mut terminal);
+
+    manager.move_to_anchor(id, Anchor::TopLeft);
+    manager.tick(Duration::from_millis(500));
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+
+    // Two boxes now stack down from the top-left corner
+    let buffer = terminal.backend().buffer();
+    let corners = (0..24).filter(|&y| buffer[(0, y)].symbol() == "╭").count();
+    assert_eq!(corners, 2);
+    assert!(manager.content_rect(id).is_some_and(|rect| rect.x < 40));
+}
+
+#[test]
+fn test_move_calculate_rect_eases_out() {
+    let from = Rect::new(60, 20, 20, 4);
+    let to = Rect::new(0, 0, 20, 4);
+    let frame_area = Rect::new(0, 0, 80, 24);
+
+    // Ease-out covers 75% of the distance at half time
+    assert_eq!(move_calculate_rect(from, to, frame_area, 0.5), Rect::new(15, 5, 20, 4));
+}
+
+// FILE: tests/test_move_to_anchor_integration.rs - Integration tests for animated anchor moves
+// END OF VERSION: 1.0.0
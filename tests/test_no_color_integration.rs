@@ -0,0 +1,134 @@
+// FILE: tests/test_no_color_integration.rs - Integration tests for NO_COLOR support
+// VERSION: 1.0.0
+// WCTX: NO_COLOR support
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::prelude::*;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Animation, Level, NotificationBuilder, Notifications, Theme,
+};
+use std::ffi::OsString;
+use std::time::Duration;
+
+fn no_color(key: &str) -> Option<OsString> {
+    (key == "NO_COLOR").then(|| "1".into())
+}
+
+fn no_env(_key: &str) -> Option<OsString> {
+    None
+}
+
+fn render(mut manager: Notifications, user_color: Option<Color>) -> Buffer {
+    let mut builder = NotificationBuilder::new("body")
+        .title("Colors")
+        .level(Level::Error)
+        .animation(Animation::Fade);
+    if let Some(color) = user_color {
+        builder = builder.border_style(Style::new().fg(color));
+    }
+    manager.add(builder.build().unwrap()).unwrap();
+    // Mid fade-in, so fade colors would be RGB blends
+    manager.tick(Duration::from_millis(250));
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal
+        .draw(|frame| manager.render(frame, frame.area()))
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn has_color(buffer: &Buffer) -> bool {
+    buffer
+        .content
+        .iter()
+        .any(|cell| cell.fg != Color::Reset || cell.bg != Color::Reset)
+}
+
+fn has_fg(buffer: &Buffer, color: Color) -> bool {
+    buffer.content.iter().any(|cell| cell.fg == color)
+}
+
+#[test]
+fn test_no_color_ignored_by_default() {
+    let manager = Notifications::new().with_env_lookup(no_color);
+    assert!(!manager.colors_stripped());
+    assert!(has_color(&render(manager, None)));
+}
+
+#[test]
+fn test_respect_no_color_strips_crate_colors() {
+    let manager = Notifications::new().respect_no_color(true).with_env_lookup(no_color);
+    assert!(manager.colors_stripped());
+    assert!(!has_color(&render(manager, None)));
+}
+
+#[test]
+fn test_respect_no_color_without_env_keeps_colors() {
+    let manager = Notifications::new().respect_no_color(true).with_env_lookup(no_env);
+    assert!(!manager.colors_stripped());
+    assert!(has_color(&render(manager, None)));
+}
+
+#[test]
+fn test_empty_no_color_does_not_strip() {
+    let manager = Notifications::new()
+        .respect_no_color(true)
+        .with_env_lookup(|key| (key == "NO_COLOR").then(OsString::new));
+    assert!(!manager.colors_stripped());
+}
+
+#[test]
+fn test_force_colors_overrides_no_color() {
+    let manager = Notifications::new()
+        .respect_no_color(true)
+        .force_colors(true)
+        .with_env_lookup(no_color);
+    assert!(!manager.colors_stripped());
+    assert!(has_color(&render(manager, None)));
+}
+
+#[test]
+fn test_user_colors_pass_through() {
+    let manager = Notifications::new().respect_no_color(true).with_env_lookup(no_color);
+    let buffer = render(manager, Some(Color::Cyan));
+    assert!(has_fg(&buffer, Color::Cyan));
+    assert!(!has_fg(&buffer, Color::Red));
+}
+
+#[test]
+fn test_strip_user_colors_strips_everything() {
+    let manager = Notifications::new()
+        .respect_no_color(true)
+        .strip_user_colors(true)
+        .with_env_lookup(no_color);
+    let buffer = render(manager, Some(Color::Cyan));
+    assert!(!has_color(&buffer));
+}
+
+#[test]
+fn test_stripping_keeps_theme_modifiers() {
+    let manager = Notifications::new()
+        .theme(Theme::high_contrast())
+        .respect_no_color(true)
+        .with_env_lookup(no_color);
+    let buffer = render(manager, None);
+    assert!(!has_color(&buffer));
+    assert!(buffer
+        .content
+        .iter()
+        .any(|cell| cell.modifier.contains(Modifier::BOLD)));
+}
+
+#[test]
+fn test_theme_without_colors() {
+    let theme = Theme::high_contrast().without_colors();
+    assert_eq!(theme.error.border_style.fg, None);
+    assert_eq!(theme.default_border_style.fg, None);
+    assert!(theme.error.title_style.add_modifier.contains(Modifier::BOLD));
+}
+
+// FILE: tests/test_no_color_integration.rs - Integration tests for NO_COLOR support
+// END OF VERSION: 1.0.0
@@ -0,0 +1,163 @@
+// FILE: tests/test_notification_center_integration.rs - Integration tests for the notification center widget
+// VERSION: 1.1.0
+// WCTX: Deterministic test clock
+// CLOG: Replaced the local ManualClock with TestClock
+
+use ratatui::backend::TestBackend;
+use ratatui::widgets::Block;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::traits::TestClock;
+use ratatui_notifications::notifications::{
+    Level, NotificationBuilder, NotificationCenterState, Notifications,
+};
+use std::time::Duration;
+
+fn manager(clock: &TestClock) -> Notifications {
+    Notifications::new()
+        .with_clock(clock.clone())
+        .history_limit(10)
+}
+
+fn add(manager: &mut Notifications, title: &str, content: &str) -> u64 {
+    let notif = NotificationBuilder::new(content.to_string())
+        .title(title.to_string())
+        .build()
+        .unwrap();
+    manager.add(notif).unwrap()
+}
+
+/// Renders the center into a 30x`height` buffer and returns its rows
+fn draw(manager: &Notifications, height: u16, state: &mut NotificationCenterState) -> Vec<String> {
+    let mut terminal = Terminal::new(TestBackend::new(30, height)).unwrap();
+    terminal
+        .draw(|frame| {
+            let center = manager.notification_center().block(Block::bordered().title("History"));
+            frame.render_stateful_widget(center, frame.area(), state);
+        })
+        .unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| (0..30).map(|x| buffer[(x, y)].symbol()).collect())
+        .collect()
+}
+
+#[test]
+fn test_empty_history_renders_only_the_block() {
+    let clock = TestClock::new();
+    let manager = manager(&clock);
+
+    let rows = draw(&manager, 4, &mut NotificationCenterState::default());
+    assert_eq!(
+        rows,
+        [
+            "┌History─────────────────────┐",
+            "│                            │",
+            "│                            │",
+            "└────────────────────────────┘",
+        ]
+    );
+}
+
+#[test]
+fn test_short_history_lists_newest_first_with_ages() {
+    let clock = TestClock::new();
+    let mut manager = manager(&clock);
+    add(&mut manager, "Build", "Compiled");
+    clock.advance(Duration::from_secs(90));
+    add(&mut manager, "Sync", "Up to date");
+    clock.advance(Duration::from_secs(5));
+
+    let rows = draw(&manager, 5, &mut NotificationCenterState::default());
+    assert_eq!(
+        rows,
+        [
+            "┌History─────────────────────┐",
+            "│ℹ Sync Up to date · 5s ago  │",
+            "│ℹ Build Compiled · 1m ago   │",
+            "│                            │",
+            "└────────────────────────────┘",
+        ]
+    );
+}
+
+#[test]
+fn test_overflowing_history_scrolls_to_selection() {
+    let clock = TestClock::new();
+    let mut manager = manager(&clock);
+    for n in 1..=5 {
+        add(&mut manager, &format!("T{n}"), "msg");
+    }
+
+    let mut state = NotificationCenterState::default();
+    state.select(Some(4));
+    let rows = draw(&manager, 4, &mut state);
+    assert_eq!(
+        rows,
+        [
+            "┌History─────────────────────┐",
+            "│ℹ T2 msg · 0s ago           │",
+            "│ℹ T1 msg · 0s ago           │",
+            "└────────────────────────────┘",
+        ]
+    );
+    assert_eq!(state.offset(), 3);
+
+    // Selecting past the end clamps to the oldest row
+    state.select_next();
+    draw(&manager, 4, &mut state);
+    assert_eq!(state.selected(), Some(4));
+}
+
+#[test]
+fn test_level_icon_is_shown() {
+    let clock = TestClock::new();
+    let mut manager = manager(&clock);
+    let notif = NotificationBuilder::new("Disk full")
+        .level(Level::Error)
+        .build()
+        .unwrap();
+    manager.add(notif).unwrap();
+
+    let rows = draw(&manager, 3, &mut NotificationCenterState::default());
+    assert!(rows[1].starts_with("│✖ Disk full · 0s ago"), "{:?}", rows[1]);
+}
+
+#[test]
+fn test_dismissed_and_shown_filters() {
+    let clock = TestClock::new();
+    let mut manager = manager(&clock);
+    let gone = add(&mut manager, "Gone", "a");
+    let kept = add(&mut manager, "Kept", "b");
+    manager.remove(gone);
+
+    let history: Vec<_> = manager.history().collect();
+    assert!(history[0].is_dismissed());
+    assert!(!history[1].is_dismissed());
+
+    let state = NotificationCenterState::default();
+    let mut selected = state.clone();
+    selected.select(Some(0));
+    let only_shown = manager.notification_center().dismissed(false);
+    assert_eq!(only_shown.selected_record(&selected).map(|r| r.id), Some(kept));
+    let only_dismissed = manager.notification_center().shown(false);
+    assert_eq!(only_dismissed.selected_record(&selected).map(|r| r.id), Some(gone));
+    assert_eq!(manager.notification_center().selected_record(&state), None);
+}
+
+#[test]
+fn test_history_is_bounded_and_disabled_by_default() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone()).history_limit(2);
+    for n in 0..3 {
+        add(&mut manager, "T", &n.to_string());
+    }
+    let ids: Vec<u64> = manager.history().map(|record| record.id).collect();
+    assert_eq!(ids, [1, 2]);
+
+    let mut plain = Notifications::new();
+    add(&mut plain, "T", "x");
+    assert_eq!(plain.history().count(), 0);
+}
+
+// FILE: tests/test_notification_center_integration.rs - Integration tests for the notification center widget
+// END OF VERSION: 1.1.0
@@ -0,0 +1,133 @@
+// FILE: tests/test_notification_info_integration.rs - Integration tests for NotificationInfo snapshots
+// VERSION: 1.0.0
+// WCTX: Notification snapshots
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::traits::TestClock;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, AnimationPhase, AutoDismiss, Level, NotificationBuilder, Notifications, Timing,
+};
+use std::sync::Arc;
+use std::time::Duration;
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+fn setup() -> (Notifications, TestClock, u64) {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    let notif = NotificationBuilder::new("Line one\nLine two")
+        .title("Upload")
+        .level(Level::Warn)
+        .anchor(Anchor::TopLeft)
+        .animation(Animation::Fade)
+        .timing(Timing::Fixed(ms(200)), Timing::Auto, Timing::Fixed(ms(200)))
+        .auto_dismiss(AutoDismiss::After(ms(1000)))
+        .build()
+        .unwrap();
+    let id = manager.add(notif).unwrap();
+    (manager, clock, id)
+}
+
+fn render(manager: &mut Notifications) {
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal
+        .draw(|frame| manager.render(frame, frame.area()))
+        .unwrap();
+}
+
+#[test]
+fn test_snapshot_describes_the_notification() {
+    let (manager, _clock, id) = setup();
+    let info = manager.get(id).unwrap();
+
+    assert_eq!(info.id, id);
+    assert_eq!(info.title.as_deref(), Some("Upload"));
+    assert_eq!(&*info.content, "Line one\nLine two");
+    assert_eq!(info.level, Some(Level::Warn));
+    assert_eq!(info.anchor, Anchor::TopLeft);
+    assert_eq!(info.rect, None);
+    assert!(manager.get(id + 1).is_none());
+}
+
+#[test]
+fn test_snapshot_follows_the_lifecycle() {
+    let (mut manager, clock, id) = setup();
+    manager.tick(ms(0));
+    assert_eq!(manager.get(id).unwrap().phase, AnimationPhase::FadingIn);
+
+    clock.advance(ms(100));
+    manager.tick(ms(100));
+    let entering = manager.get(id).unwrap();
+    assert_eq!(entering.phase, AnimationPhase::FadingIn);
+    assert!((entering.progress - 0.5).abs() < 1e-3);
+    assert_eq!(entering.age, ms(100));
+
+    clock.advance(ms(400));
+    manager.tick(ms(400));
+    render(&mut manager);
+    let dwelling = manager.get(id).unwrap();
+    assert_eq!(dwelling.phase, AnimationPhase::Dwelling);
+    assert_eq!(dwelling.remaining, Some(ms(700)));
+    assert_eq!(dwelling.age, ms(500));
+    let rect = dwelling.rect.unwrap();
+    assert_eq!((rect.x, rect.y), (0, 0));
+    assert!(manager.content_rect(id).is_some_and(|inner| rect.contains(inner.as_position())));
+
+    clock.advance(ms(800));
+    manager.tick(ms(800));
+    assert_eq!(manager.get(id).unwrap().phase, AnimationPhase::FadingOut);
+
+    manager.tick(ms(300));
+    assert!(manager.get(id).is_none());
+}
+
+#[test]
+fn test_snapshot_tracks_content_changes() {
+    let (mut manager, _clock, id) = setup();
+    let before = manager.get(id).unwrap();
+    manager.update_content(id, "Done");
+
+    assert_eq!(&*manager.get(id).unwrap().content, "Done");
+    assert_eq!(&*before.content, "Line one\nLine two");
+}
+
+#[test]
+fn test_snapshots_share_text() {
+    let (manager, _clock, id) = setup();
+    let first = manager.get(id).unwrap();
+    let second = manager.get(id).unwrap();
+    assert!(Arc::ptr_eq(&first.content, &second.content));
+}
+
+#[test]
+fn test_mutating_snapshot_leaves_manager_alone() {
+    let (manager, _clock, id) = setup();
+    let mut info = manager.get(id).unwrap();
+    info.level = Some(Level::Error);
+    info.anchor = Anchor::BottomRight;
+    info.content = "changed".into();
+    info.title = None;
+
+    let fresh = manager.get(id).unwrap();
+    assert_eq!(fresh.level, Some(Level::Warn));
+    assert_eq!(fresh.anchor, Anchor::TopLeft);
+    assert_eq!(&*fresh.content, "Line one\nLine two");
+    assert_eq!(fresh.title.as_deref(), Some("Upload"));
+}
+
+#[test]
+fn test_infos_sorted_oldest_first() {
+    let (mut manager, _clock, first) = setup();
+    let second = manager.add(NotificationBuilder::new("b").build().unwrap()).unwrap();
+    let third = manager.add(NotificationBuilder::new("c").build().unwrap()).unwrap();
+
+    let ids: Vec<u64> = manager.infos().iter().map(|info| info.id).collect();
+    assert_eq!(ids, vec![first, second, third]);
+}
+
+// FILE: tests/test_notification_info_integration.rs - Integration tests for NotificationInfo snapshots
+// END OF VERSION: 1.0.0
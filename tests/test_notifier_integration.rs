@@ -0,0 +1,139 @@
+// FILE: tests/test_notifier_integration.rs - Integration tests for the pluggable notifier
+// VERSION: 1.1.0
+// WCTX: Pluggable notifier
+// CLOG: Announce every notification that left Pending during the tick
+
+use ratatui_notifications::notifications::traits::Notifier;
+use ratatui_notifications::notifications::{
+    AnimationPhase, AutoDismiss, BellNotifier, Level, NotificationBuilder, Notifications, Timing,
+};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+type Call = (Option<Level>, u64);
+
+/// Records every call, shared with the test through an `Arc`
+#[derive(Debug, Clone, Default)]
+struct Recording(Arc<Mutex<Vec<Call>>>);
+
+impl Notifier for Recording {
+    fn notify(&mut self, level: Option<Level>, id: u64) {
+        self.0.lock().unwrap().push((level, id));
+    }
+}
+
+impl Recording {
+    fn calls(&self) -> Vec<Call> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+fn add(manager: &mut Notifications, level: Level) -> u64 {
+    let notification = NotificationBuilder::new("Disk almost full")
+        .level(level)
+        .auto_dismiss(AutoDismiss::Never);
+    manager.add(notification.build().unwrap()).unwrap()
+}
+
+fn recording_manager() -> (Notifications, Recording) {
+    let recording = Recording::default();
+    let mut manager = Notifications::new();
+    manager.set_notifier(Box::new(recording.clone()));
+    (manager, recording)
+}
+
+#[test]
+fn test_notifier_hears_each_notification_on_show() {
+    let (mut manager, recording) = recording_manager();
+    let warn = add(&mut manager, Level::Warn);
+    let error = add(&mut manager, Level::Error);
+    assert!(
+        recording.calls().is_empty(),
+        "nothing is shown before a tick"
+    );
+
+    manager.tick(Duration::from_millis(16));
+    assert_eq!(
+        recording.calls(),
+        vec![(Some(Level::Warn), warn), (Some(Level::Error), error)]
+    );
+}
+
+#[test]
+fn test_notifier_is_called_once_per_notification() {
+    let (mut manager, recording) = recording_manager();
+    let id = add(&mut manager, Level::Info);
+    for _ in 0..100 {
+        manager.tick(Duration::from_millis(50));
+    }
+    assert_eq!(recording.calls(), vec![(Some(Level::Info), id)]);
+}
+
+#[test]
+fn test_zero_duration_notification_is_announced() {
+    let (mut manager, recording) = recording_manager();
+    let notification = NotificationBuilder::new("Blink")
+        .level(Level::Warn)
+        .timing(Timing::Fixed(Duration::ZERO), Timing::Auto, Timing::Fixed(Duration::ZERO))
+        // After(ZERO) means the default display time, so dwell for 1ms
+        .auto_dismiss(AutoDismiss::After(Duration::from_millis(1)))
+        .build()
+        .unwrap();
+    let id = manager.add(notification).unwrap();
+
+    // Announced on the first tick, however soon it finishes
+    manager.tick(Duration::from_millis(16));
+    assert_eq!(recording.calls(), vec![(Some(Level::Warn), id)]);
+    for _ in 0..5 {
+        manager.tick(Duration::from_millis(16));
+    }
+    assert_eq!(manager.phase(id), None);
+    assert_eq!(recording.calls(), vec![(Some(Level::Warn), id)]);
+}
+
+#[test]
+fn test_large_delta_still_announces() {
+    let (mut manager, recording) = recording_manager();
+    let notification = NotificationBuilder::new("Brief")
+        .level(Level::Error)
+        .auto_dismiss(AutoDismiss::After(Duration::from_secs(1)));
+    let id = manager.add(notification.build().unwrap()).unwrap();
+
+    // One tick covers the whole entry and display time
+    manager.tick(Duration::from_secs(60));
+    assert_ne!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    assert_eq!(recording.calls(), vec![(Some(Level::Error), id)]);
+    manager.tick(Duration::from_secs(60));
+    assert_eq!(manager.phase(id), None);
+    assert_eq!(recording.calls(), vec![(Some(Level::Error), id)]);
+}
+
+#[test]
+fn test_hidden_notification_is_announced_when_shown() {
+    let (mut manager, recording) = recording_manager();
+    let id = manager
+        .add_hidden(
+            NotificationBuilder::new("Later")
+                .level(Level::Error)
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+    manager.tick(Duration::from_millis(16));
+    assert!(recording.calls().is_empty());
+
+    manager.show(id);
+    manager.tick(Duration::from_millis(16));
+    assert_eq!(recording.calls(), vec![(Some(Level::Error), id)]);
+}
+
+#[test]
+fn test_bell_notifier_can_be_registered() {
+    let mut manager = Notifications::new();
+    manager.set_notifier(Box::new(BellNotifier));
+    add(&mut manager, Level::Info);
+    manager.tick(Duration::from_millis(16));
+}
+
+// FILE: tests/test_notifier_integration.rs - Integration tests for the pluggable notifier
+// END OF VERSION: 1.1.0
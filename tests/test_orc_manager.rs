@@ -1,13 +1,14 @@
 // FILE: tests/notifications/test_orc_manager.rs - Tests for Notifications manager orchestrator
-// VERSION: 1.0.0
-// WCTX: Implementing Notifications manager orchestrator using TDD
-// CLOG: Initial creation with comprehensive test coverage
+// VERSION: 1.6.0
+// WCTX: Deterministic test clock
+// CLOG: Replaced sleeps with a TestClock
 
 #[cfg(test)]
 mod tests {
     use ratatui_notifications::notifications::{
         Notification, NotificationBuilder, Anchor, Overflow,
     };
+    use ratatui_notifications::notifications::traits::TestClock;
     use std::time::Duration;
 
     // Helper to create a simple notification for testing
@@ -134,7 +135,9 @@ mod tests {
     fn test_overflow_discard_oldest_removes_oldest_when_full() {
         use ratatui_notifications::notifications::Notifications;
 
+        let clock = TestClock::new();
         let mut manager = Notifications::new()
+            .with_clock(clock.clone())
             .max_concurrent(Some(2))
             .overflow(Overflow::DiscardOldest);
 
@@ -142,15 +145,14 @@ mod tests {
         let notif1 = create_test_notification(Anchor::BottomRight);
         let id1 = manager.add(notif1).unwrap();
 
-        // Small delay to ensure different timestamps
-        std::thread::sleep(Duration::from_millis(10));
+        // Advance the clock to ensure different timestamps
+        clock.advance(Duration::from_millis(10));
 
         // Add second notification
         let notif2 = create_test_notification(Anchor::BottomRight);
         let id2 = manager.add(notif2).unwrap();
 
-        // Small delay
-        std::thread::sleep(Duration::from_millis(10));
+        clock.advance(Duration::from_millis(10));
 
         // Add third notification - should discard id1
         let notif3 = create_test_notification(Anchor::BottomRight);
@@ -166,7 +168,9 @@ mod tests {
     fn test_overflow_discard_newest_removes_newest_when_full() {
         use ratatui_notifications::notifications::Notifications;
 
+        let clock = TestClock::new();
         let mut manager = Notifications::new()
+            .with_clock(clock.clone())
             .max_concurrent(Some(2))
             .overflow(Overflow::DiscardNewest);
 
@@ -174,15 +178,13 @@ mod tests {
         let notif1 = create_test_notification(Anchor::TopLeft);
         let id1 = manager.add(notif1).unwrap();
 
-        // Small delay
-        std::thread::sleep(Duration::from_millis(10));
+        clock.advance(Duration::from_millis(10));
 
         // Add second notification
         let notif2 = create_test_notification(Anchor::TopLeft);
         let id2 = manager.add(notif2).unwrap();
 
-        // Small delay
-        std::thread::sleep(Duration::from_millis(10));
+        clock.advance(Duration::from_millis(10));
 
         // Add third notification - should discard id2 (newest existing)
         let notif3 = create_test_notification(Anchor::TopLeft);
@@ -306,7 +308,299 @@ mod tests {
             manager.render(frame, frame.area());
         }).unwrap();
     }
+
+    fn create_deadline_notification(deadline: std::time::Instant) -> Notification {
+        use ratatui_notifications::notifications::{AutoDismiss, Timing};
+
+        NotificationBuilder::new("Time-boxed alert")
+            .timing(
+                Timing::Fixed(Duration::from_millis(10)),
+                Timing::Auto,
+                Timing::Fixed(Duration::from_millis(10)),
+            )
+            .auto_dismiss(AutoDismiss::AtDeadline(deadline))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_near_deadline_exits_when_ticked_past_it() {
+        use ratatui_notifications::notifications::traits::Clock;
+        use ratatui_notifications::notifications::{AnimationPhase, Notifications};
+
+        let clock = TestClock::new();
+        let mut manager = Notifications::new().with_clock(clock.clone());
+        let deadline = clock.now() + Duration::from_millis(30);
+        let id = manager.add(create_deadline_notification(deadline)).unwrap();
+
+        // Finish the entry animation well before the deadline
+        manager.tick(Duration::from_millis(20));
+        assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+
+        clock.advance(Duration::from_millis(40));
+        manager.tick(Duration::from_millis(1));
+        assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+    }
+
+    #[test]
+    fn test_far_deadline_keeps_dwelling() {
+        use ratatui_notifications::notifications::{AnimationPhase, Notifications};
+        use std::time::Instant;
+
+        let mut manager = Notifications::new();
+        let deadline = Instant::now() + Duration::from_secs(3600);
+        let id = manager.add(create_deadline_notification(deadline)).unwrap();
+
+        // Far longer than any default dwell
+        for _ in 0..100 {
+            manager.tick(Duration::from_millis(100));
+        }
+        assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    }
+
+    #[test]
+    fn test_describe_orders_by_severity_then_recency() {
+        use ratatui_notifications::notifications::{Level, Notifications};
+
+        let clock = TestClock::new();
+        let mut manager = Notifications::new().with_clock(clock.clone());
+        for (text, level) in [
+            ("Old info", Level::Info),
+            ("Disk failed", Level::Error),
+            ("New info", Level::Info),
+        ] {
+            let notif = NotificationBuilder::new(text)
+                .level(level)
+                .anchor(Anchor::TopRight)
+                .build()
+                .unwrap();
+            manager.add(notif).unwrap();
+            clock.advance(Duration::from_millis(2));
+        }
+
+        // Pending notifications are not yet visible
+        assert!(manager.describe().is_empty());
+
+        manager.tick(Duration::from_millis(16));
+        let lines = manager.describe();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("Error notification, top right: Disk failed."));
+        assert!(lines[1].contains("New info"));
+        assert!(lines[2].contains("Old info"));
+    }
+
+    #[test]
+    fn test_describe_changes_reports_each_notification_once() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        manager.tick(Duration::from_millis(16));
+
+        assert_eq!(manager.describe_changes().len(), 1);
+        assert!(manager.describe_changes().is_empty());
+
+        manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        manager.tick(Duration::from_millis(16));
+        let changes = manager.describe_changes();
+        assert_eq!(changes.len(), 1);
+        assert!(changes[0].contains("top left"));
+
+        // describe() still reports everything
+        assert_eq!(manager.describe().len(), 2);
+    }
+
+    fn render_text(manager: &mut ratatui_notifications::notifications::Notifications) -> String {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+        terminal.backend().buffer().content.iter().map(|cell| cell.symbol()).collect()
+    }
+
+    #[test]
+    fn test_merge_duplicates_three_adds_show_count() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().overflow(Overflow::MergeDuplicates);
+        let first = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        let second = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        let third = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first, third);
+
+        manager.tick(Duration::from_millis(600));
+        let lines = manager.describe();
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].contains("Test notification (x3)"), "{}", lines[0]);
+        assert!(render_text(&mut manager).contains("(x3)"));
+    }
+
+    #[test]
+    fn test_merge_duplicates_resets_dwell() {
+        use ratatui_notifications::notifications::{AnimationPhase, AutoDismiss, Notifications};
+
+        let build = || {
+            NotificationBuilder::new("Repeat")
+                .auto_dismiss(AutoDismiss::After(Duration::from_millis(1000)))
+                .build()
+                .unwrap()
+        };
+        let mut manager = Notifications::new().overflow(Overflow::MergeDuplicates);
+        let id = manager.add(build()).unwrap();
+
+        // Entry (500ms default) completes and its tick counts toward the
+        // display time, leaving 200ms
+        manager.tick(Duration::from_millis(500));
+        manager.tick(Duration::from_millis(300));
+        manager.add(build()).unwrap();
+
+        // Would have expired without the merge
+        manager.tick(Duration::from_millis(500));
+        assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    }
+
+    #[test]
+    fn test_merge_duplicates_requires_same_content_and_anchor() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().overflow(Overflow::MergeDuplicates);
+        let a = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        let b = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        let c = manager
+            .add(NotificationBuilder::new("Other").anchor(Anchor::BottomRight).build().unwrap())
+            .unwrap();
+
+        assert_ne!(a, b);
+        assert_ne!(a, c);
+
+        manager.tick(Duration::from_millis(16));
+        assert_eq!(manager.describe().len(), 3);
+        assert!(!render_text(&mut manager).contains("(x"));
+    }
+
+    #[test]
+    fn test_discard_oldest_does_not_merge() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let a = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+        let b = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+
+        assert_ne!(a, b);
+    }
+    #[test]
+    fn test_drain_returns_owned_notifications_and_empties_manager() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new();
+        let contents = ["First", "Second", "Third"];
+        let anchors = [Anchor::BottomRight, Anchor::TopLeft, Anchor::BottomRight];
+        let mut ids = Vec::new();
+        for (content, anchor) in contents.iter().zip(anchors) {
+            let notif = NotificationBuilder::new(*content)
+                .title("Saved")
+                .anchor(anchor)
+                .build()
+                .unwrap();
+            ids.push(manager.add(notif).unwrap());
+        }
+        manager.tick(Duration::from_millis(100));
+
+        let drained = manager.drain();
+
+        // One notification per state, oldest first, with config intact
+        assert_eq!(drained.len(), 3);
+        for ((notif, content), anchor) in drained.iter().zip(contents).zip(anchors) {
+            assert_eq!(notif.content().to_string(), content);
+            assert_eq!(notif.anchor(), anchor);
+            assert_eq!(notif.title().map(|title| title.to_string()), Some("Saved".to_string()));
+        }
+
+        assert!(!manager.has_notification());
+        assert!(ids.iter().all(|&id| manager.phase(id).is_none()));
+        assert!(manager.drain().is_empty());
+    }
+
+    #[test]
+    fn test_drained_notifications_can_be_re_added() {
+        use ratatui_notifications::notifications::{AnimationPhase, Notifications};
+
+        let mut old_manager = Notifications::new();
+        old_manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        old_manager.add(create_test_notification(Anchor::TopRight)).unwrap();
+        old_manager.tick(Duration::from_secs(1));
+
+        let mut new_manager = Notifications::new();
+        for notif in old_manager.drain() {
+            let id = new_manager.add(notif).unwrap();
+            // Transient state is not carried over
+            assert_eq!(new_manager.phase(id), Some(AnimationPhase::Pending));
+        }
+        assert!(new_manager.has_notification());
+    }
+
+    #[test]
+    fn test_max_total_evicts_oldest_across_anchors() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new().max_total(Some(3));
+
+        let id1 = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        let id2 = manager.add(create_test_notification(Anchor::TopRight)).unwrap();
+        let id3 = manager.add(create_test_notification(Anchor::BottomLeft)).unwrap();
+
+        // Fourth at yet another anchor still exceeds the global cap
+        let id4 = manager.add(create_test_notification(Anchor::BottomRight)).unwrap();
+
+        assert!(!manager.remove(id1));
+        assert!(manager.remove(id2));
+        assert!(manager.remove(id3));
+        assert!(manager.remove(id4));
+    }
+
+    #[test]
+    fn test_max_total_discard_newest_across_anchors() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new()
+            .max_total(Some(3))
+            .overflow(Overflow::DiscardNewest);
+
+        let id1 = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        let id2 = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        let id3 = manager.add(create_test_notification(Anchor::TopRight)).unwrap();
+        let id4 = manager.add(create_test_notification(Anchor::BottomCenter)).unwrap();
+
+        assert!(manager.remove(id1));
+        assert!(manager.remove(id2));
+        assert!(!manager.remove(id3));
+        assert!(manager.remove(id4));
+    }
+
+    #[test]
+    fn test_max_total_combines_with_max_concurrent() {
+        use ratatui_notifications::notifications::Notifications;
+
+        let mut manager = Notifications::new()
+            .max_concurrent(Some(2))
+            .max_total(Some(3));
+
+        let id1 = manager.add(create_test_notification(Anchor::TopLeft)).unwrap();
+        let id2 = manager.add(create_test_notification(Anchor::TopRight)).unwrap();
+        let id3 = manager.add(create_test_notification(Anchor::TopRight)).unwrap();
+
+        // Per-anchor limit evicts id2 first, leaving room under the global cap
+        let id4 = manager.add(create_test_notification(Anchor::TopRight)).unwrap();
+
+        assert!(manager.remove(id1));
+        assert!(!manager.remove(id2));
+        assert!(manager.remove(id3));
+        assert!(manager.remove(id4));
+    }
 }
 
 // FILE: tests/notifications/test_orc_manager.rs - Tests for Notifications manager orchestrator
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.6.0
@@ -1,15 +1,19 @@
 // FILE: tests/notifications/test_orc_stacking.rs - Tests for stacking orchestrator
-// VERSION: 1.0.0
-// WCTX: TDD implementation of OFPF render orchestrators
-// CLOG: Initial creation with comprehensive stacking tests
+// VERSION: 1.3.0
+// WCTX: Baseline code kept as written
+// CLOG: Allowed clippy lints instead of rewriting
+
+#![allow(clippy::unnecessary_cast, clippy::len_zero)] // Kept as originally written
 
 use ratatui::prelude::*;
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
 // Import types and structures we'll need
-use ratatui_notifications::notifications::types::{Anchor, AnimationPhase};
-use ratatui_notifications::notifications::orc_stacking::calculate_stacking_positions;
+use ratatui_notifications::notifications::types::{Anchor, AnimationPhase, StackLayout};
+use ratatui_notifications::notifications::orc_stacking::{
+    calculate_stacking_positions, calculate_stacking_positions_with_layout, StackedNotification,
+};
 
 // Helper struct to simulate NotificationState for testing
 #[derive(Clone)]
@@ -308,5 +312,102 @@ fn test_pending_and_finished_notifications_excluded() {
     assert_eq!(result[0].id, 2, "Should only include the Dwelling notification");
 }
 
+/// Three 20x5 notifications created 100ms apart, oldest first
+fn three_in_a_row() -> HashMap<u64, MockNotificationState> {
+    let now = Instant::now();
+    (1..=3)
+        .map(|id| {
+            let created_at = now + Duration::from_millis(100 * id);
+            let state = MockNotificationState::new(id, AnimationPhase::Dwelling, 20, 5)
+                .with_created_at(created_at);
+            (id, state)
+        })
+        .collect()
+}
+
+#[test]
+fn test_right_to_left_grows_leftward_from_bottom_right() {
+    let notifications = three_in_a_row();
+    let result = calculate_stacking_positions_with_layout(
+        &notifications,
+        Anchor::BottomRight,
+        &[1, 2, 3],
+        Rect::new(0, 0, 100, 30),
+        None,
+        StackLayout::RightToLeft,
+    );
+
+    // Oldest at the anchor, newer ones to its left with one column between
+    let placed: Vec<(u64, u16, u16)> = result.iter().map(|s| (s.id, s.rect.x, s.rect.y)).collect();
+    assert_eq!(placed, vec![(1, 80, 25), (2, 59, 25), (3, 38, 25)]);
+}
+
+#[test]
+fn test_left_to_right_grows_rightward_from_top_left() {
+    let notifications = three_in_a_row();
+    let result = calculate_stacking_positions_with_layout(
+        &notifications,
+        Anchor::TopLeft,
+        &[1, 2, 3],
+        Rect::new(0, 0, 100, 30),
+        None,
+        StackLayout::LeftToRight,
+    );
+
+    let placed: Vec<(u64, u16, u16)> = result.iter().map(|s| (s.id, s.rect.x, s.rect.y)).collect();
+    assert_eq!(placed, vec![(1, 0, 0), (2, 21, 0), (3, 42, 0)]);
+}
+
+#[test]
+fn test_horizontal_stack_stops_at_the_frame_edge() {
+    let notifications = three_in_a_row();
+    let frame_area = Rect::new(0, 0, 50, 30);
+
+    // Only two 20-wide notifications and a gap fit in 50 columns
+    let leftward = calculate_stacking_positions_with_layout(
+        &notifications,
+        Anchor::BottomRight,
+        &[1, 2, 3],
+        frame_area,
+        None,
+        StackLayout::RightToLeft,
+    );
+    let ids: Vec<u64> = leftward.iter().map(|s| s.id).collect();
+    assert_eq!(ids, vec![1, 2]);
+
+    // Growing away from the frame leaves room for the oldest only
+    let rightward = calculate_stacking_positions_with_layout(
+        &notifications,
+        Anchor::BottomRight,
+        &[1, 2, 3],
+        frame_area,
+        None,
+        StackLayout::LeftToRight,
+    );
+    assert_eq!(rightward.len(), 1);
+    assert_eq!(rightward[0].rect.x, 30);
+}
+
+#[test]
+fn test_vertical_layout_matches_default_stacking() {
+    let notifications = three_in_a_row();
+    let frame_area = Rect::new(0, 0, 100, 30);
+    let default =
+        calculate_stacking_positions(&notifications, Anchor::TopRight, &[1, 2, 3], frame_area, None);
+    let vertical = calculate_stacking_positions_with_layout(
+        &notifications,
+        Anchor::TopRight,
+        &[1, 2, 3],
+        frame_area,
+        None,
+        StackLayout::Vertical,
+    );
+
+    let rects = |stacked: &[StackedNotification]| {
+        stacked.iter().map(|s| (s.id, s.rect)).collect::<Vec<_>>()
+    };
+    assert_eq!(rects(&default), rects(&vertical));
+}
+
 // FILE: tests/notifications/test_orc_stacking.rs - Tests for stacking orchestrator
-// END OF VERSION: 1.0.0
+// END OF VERSION: 1.3.0
@@ -0,0 +1,115 @@
+// FILE: tests/test_orc_ticker.rs - Integration tests for the background ticker
+// VERSION: 1.1.0
+// WCTX: Background ticker thread
+// CLOG: Ticker reads time from the manager's clock
+
+use ratatui_notifications::notifications::traits::TestClock;
+use ratatui_notifications::notifications::{
+    AnimationPhase, AutoDismiss, NotificationBuilder, Notifications, Timing,
+};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+fn shared_with_notification(dismiss_after: Duration) -> (Arc<Mutex<Notifications>>, u64) {
+    shared_with_clock(Notifications::new(), dismiss_after)
+}
+
+fn shared_with_clock(mut manager: Notifications, dismiss_after: Duration) -> (Arc<Mutex<Notifications>>, u64) {
+    let notif = NotificationBuilder::new("Background")
+        .timing(
+            Timing::Fixed(Duration::from_millis(50)),
+            Timing::Auto,
+            Timing::Fixed(Duration::from_millis(50)),
+        )
+        .auto_dismiss(AutoDismiss::After(dismiss_after))
+        .build()
+        .unwrap();
+    let id = manager.add(notif).unwrap();
+    (Arc::new(Mutex::new(manager)), id)
+}
+
+fn phase(manager: &Arc<Mutex<Notifications>>, id: u64) -> Option<AnimationPhase> {
+    manager.lock().unwrap().phase(id)
+}
+
+/// Polls until `done` holds or the timeout elapses
+fn wait_for(timeout: Duration, mut done: impl FnMut() -> bool) -> bool {
+    let start = Instant::now();
+    while start.elapsed() < timeout {
+        if done() {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(5));
+    }
+    done()
+}
+
+#[test]
+fn test_ticker_advances_states_over_real_time() {
+    let (manager, id) = shared_with_notification(Duration::from_millis(100));
+    assert_eq!(phase(&manager, id), Some(AnimationPhase::Pending));
+
+    let ticker = Notifications::spawn_ticker(&manager, Duration::from_millis(5));
+
+    assert!(wait_for(Duration::from_secs(2), || {
+        phase(&manager, id) != Some(AnimationPhase::Pending)
+    }));
+    // Entry, dwell and exit total 200ms; the notification is then removed
+    assert!(wait_for(Duration::from_secs(5), || phase(&manager, id).is_none()));
+
+    ticker.stop();
+}
+
+#[test]
+fn test_ticker_follows_the_managers_clock() {
+    let clock = TestClock::new();
+    let manager = Notifications::new().with_clock(clock.clone());
+    let (manager, id) = shared_with_clock(manager, Duration::from_secs(60));
+    let ticker = Notifications::spawn_ticker(&manager, Duration::from_millis(5));
+
+    // Real time passes but the clock stands still, so the entry never ends
+    thread::sleep(Duration::from_millis(200));
+    assert_ne!(phase(&manager, id), Some(AnimationPhase::Dwelling));
+
+    clock.advance(Duration::from_millis(100));
+    assert!(wait_for(Duration::from_secs(2), || {
+        phase(&manager, id) == Some(AnimationPhase::Dwelling)
+    }));
+
+    ticker.stop();
+}
+
+#[test]
+fn test_ticker_stops_cleanly() {
+    let (manager, id) = shared_with_notification(Duration::from_secs(60));
+    let ticker = Notifications::spawn_ticker(&manager, Duration::from_millis(5));
+    assert!(ticker.is_running());
+
+    assert!(wait_for(Duration::from_secs(2), || {
+        phase(&manager, id) == Some(AnimationPhase::Dwelling)
+    }));
+    ticker.stop();
+
+    // No ticks after stop: a fresh notification stays pending
+    let late = manager
+        .lock()
+        .unwrap()
+        .add(NotificationBuilder::new("Late").build().unwrap())
+        .unwrap();
+    thread::sleep(Duration::from_millis(50));
+    assert_eq!(phase(&manager, late), Some(AnimationPhase::Pending));
+}
+
+#[test]
+fn test_dropping_handle_stops_ticker() {
+    let (manager, _) = shared_with_notification(Duration::from_secs(60));
+    let ticker = Notifications::spawn_ticker(&manager, Duration::from_millis(5));
+    drop(ticker);
+
+    // The thread has been joined, so only this handle holds the manager
+    assert_eq!(Arc::strong_count(&manager), 1);
+}
+
+// FILE: tests/test_orc_ticker.rs - Integration tests for the background ticker
+// END OF VERSION: 1.1.0
@@ -0,0 +1,214 @@
+// FILE: tests/test_pages_integration.rs - Integration tests for notifications with cycling pages
+// VERSION: 1.1.0
+// WCTX: Exact dwell timing
+// CLOG: Page time starts when the entry ends
+
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    calculate_size, generate_code, Anchor, AnimationPhase, AutoDismiss, NotificationBuilder,
+    NotificationError, Notifications, SizeConstraint, Strings, Timing,
+};
+use std::time::Duration;
+
+const MESSAGES: [&str; 3] = ["alice: lunch?", "bob: the build is green again", "carol: PTO"];
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+/// Three pages, one second each, 200ms entry and exit
+fn paged() -> NotificationBuilder {
+    NotificationBuilder::new("")
+        .title("3 new messages")
+        .anchor(Anchor::TopLeft)
+        .max_size(SizeConstraint::Absolute(40), SizeConstraint::Absolute(6))
+        .pages(MESSAGES)
+        .page_interval(ms(1000))
+        .timing(Timing::Fixed(ms(200)), Timing::Auto, Timing::Fixed(ms(200)))
+        .auto_dismiss(AutoDismiss::Never)
+}
+
+fn screen(manager: &mut Notifications) -> Vec<String> {
+    let mut terminal = Terminal::new(TestBackend::new(60, 8)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..8)
+        .map(|y| (0..60).map(|x| buffer[(x, y)].symbol()).collect::<String>())
+        .collect()
+}
+
+fn shows(rows: &[String], text: &str) -> bool {
+    rows.iter().any(|row| row.contains(text))
+}
+
+#[test]
+fn test_box_fits_the_largest_page() {
+    let frame = Rect::new(0, 0, 120, 40);
+    let notification = paged().build().unwrap();
+    let widest = NotificationBuilder::new(MESSAGES[1])
+        .title("3 new messages")
+        .max_size(SizeConstraint::Absolute(40), SizeConstraint::Absolute(6))
+        .build()
+        .unwrap();
+
+    assert_eq!(notification.content().to_string(), MESSAGES[0]);
+    assert_eq!(calculate_size(&notification, frame), calculate_size(&widest, frame));
+}
+
+#[test]
+fn test_box_keeps_its_size_while_cycling() {
+    let mut manager = Notifications::new();
+    let id = manager.add(paged().build().unwrap()).unwrap();
+    manager.tick(ms(200));
+
+    let mut rects = Vec::new();
+    for _ in 0..3 {
+        screen(&mut manager);
+        rects.push(manager.content_rect(id).unwrap());
+        manager.tick(ms(1000));
+    }
+    assert!(rects.windows(2).all(|pair| pair[0] == pair[1]), "{rects:?}");
+}
+
+#[test]
+fn test_pages_cycle_with_an_indicator() {
+    let mut manager = Notifications::new();
+    manager.add(paged().build().unwrap()).unwrap();
+    manager.tick(ms(200));
+
+    let rows = screen(&mut manager);
+    assert!(shows(&rows, MESSAGES[0]) && shows(&rows, " 1/3 "), "{rows:#?}");
+    // Only time after the entry ends counts towards the first page
+    manager.tick(ms(999));
+    assert!(shows(&screen(&mut manager), " 1/3 "));
+
+    manager.tick(ms(1));
+    let rows = screen(&mut manager);
+    assert!(shows(&rows, MESSAGES[1]) && shows(&rows, " 2/3 "), "{rows:#?}");
+    manager.tick(ms(1000));
+    assert!(shows(&screen(&mut manager), " 3/3 "));
+    // Wraps around
+    manager.tick(ms(1000));
+    let rows = screen(&mut manager);
+    assert!(shows(&rows, MESSAGES[0]) && shows(&rows, " 1/3 "), "{rows:#?}");
+}
+
+#[test]
+fn test_pages_wait_for_the_entry_to_finish() {
+    let mut manager = Notifications::new();
+    let id = manager.add(paged().page_interval(ms(100)).build().unwrap()).unwrap();
+    manager.tick(ms(150));
+
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingIn));
+    assert!(shows(&screen(&mut manager), " 1/3 "));
+}
+
+#[test]
+fn test_indicator_is_localized() {
+    let mut strings = Strings::default();
+    strings.page_indicator = "Seite {page} von {count}".into();
+    let mut manager = Notifications::new().strings(strings);
+    manager.add(paged().build().unwrap()).unwrap();
+    manager.tick(ms(200));
+
+    assert!(shows(&screen(&mut manager), "Seite 1 von 3"));
+}
+
+#[test]
+fn test_dismiss_after_cycle_shows_every_page_once() {
+    let mut manager = Notifications::new();
+    let id = manager.add(paged().dismiss_after_cycle(true).build().unwrap()).unwrap();
+    manager.tick(ms(200));
+
+    manager.tick(ms(2999));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    assert!(shows(&screen(&mut manager), " 3/3 "));
+
+    manager.tick(ms(1));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+    // The last page stays up during the exit
+    assert!(shows(&screen(&mut manager), MESSAGES[2]));
+    manager.tick(ms(200));
+    assert_eq!(manager.phase(id), None);
+}
+
+#[test]
+fn test_dismiss_after_cycle_replaces_auto_dismiss() {
+    let mut manager = Notifications::new();
+    let notification = paged()
+        .auto_dismiss(AutoDismiss::After(ms(500)))
+        .dismiss_after_cycle(true)
+        .build()
+        .unwrap();
+    let id = manager.add(notification).unwrap();
+    manager.tick(ms(200));
+    manager.tick(ms(1000));
+
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+}
+
+#[test]
+fn test_next_page_flips_and_restarts_the_interval() {
+    let mut manager = Notifications::new();
+    let id = manager.add(paged().build().unwrap()).unwrap();
+    manager.tick(ms(200));
+    manager.tick(ms(700));
+
+    assert!(manager.next_page(id));
+    assert!(shows(&screen(&mut manager), " 2/3 "));
+    manager.tick(ms(999));
+    assert!(shows(&screen(&mut manager), " 2/3 "));
+    manager.tick(ms(1));
+    assert!(shows(&screen(&mut manager), " 3/3 "));
+
+    assert!(manager.next_page(id));
+    assert!(shows(&screen(&mut manager), " 1/3 "));
+}
+
+#[test]
+fn test_next_page_needs_several_pages() {
+    let mut manager = Notifications::new();
+    let single = manager.add(NotificationBuilder::new("One").build().unwrap()).unwrap();
+    let one_page = manager.add(NotificationBuilder::new("").pages(["Only"]).build().unwrap());
+
+    assert!(!manager.next_page(single));
+    assert!(!manager.next_page(one_page.unwrap()));
+    assert!(!manager.next_page(999));
+}
+
+#[test]
+fn test_single_page_has_no_indicator() {
+    let mut manager = Notifications::new();
+    let notification = NotificationBuilder::new("").anchor(Anchor::TopLeft).pages(["Only"]);
+    manager.add(notification.build().unwrap()).unwrap();
+    manager.tick(ms(1000));
+
+    let rows = screen(&mut manager);
+    assert!(shows(&rows, "Only"));
+    assert!(!shows(&rows, "1/1"));
+}
+
+#[test]
+fn test_zero_page_interval_is_rejected() {
+    let result = paged().page_interval(Duration::ZERO).build();
+    assert!(matches!(result, Err(NotificationError::InvalidConfig(_))));
+}
+
+#[test]
+fn test_generate_code_emits_pages() {
+    let notification = paged().page_interval(ms(1500)).dismiss_after_cycle(true).build().unwrap();
+    let code = generate_code(&notification);
+
+    let pages = r#".pages(["alice: lunch?", "bob: the build is green again", "carol: PTO"])"#;
+    assert!(code.contains(pages), "{code}");
+    assert!(code.contains(".page_interval(Duration::from_millis(1500))"), "{code}");
+    assert!(code.contains(".dismiss_after_cycle(true)"), "{code}");
+
+    let plain = generate_code(&NotificationBuilder::new("x").build().unwrap());
+    assert!(!plain.contains("page"));
+}
+
+// FILE: tests/test_pages_integration.rs - Integration tests for notifications with cycling pages
+// END OF VERSION: 1.1.0
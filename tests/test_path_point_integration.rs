@@ -0,0 +1,102 @@
+// FILE: tests/test_path_point_integration.rs - Integration tests for percent-based entry/exit positions
+// VERSION: 1.0.0
+// WCTX: Percent-based entry/exit positions
+// CLOG: Initial creation
+
+use ratatui::layout::{Position, Rect};
+use ratatui_notifications::notifications::orc_render::RenderableNotification;
+use ratatui_notifications::notifications::{
+    generate_code, AnimationPhase, ManagerDefaults, NotificationBuilder, NotificationState,
+    PathPoint,
+};
+use std::time::Duration;
+
+const SMALL: Rect = Rect::new(0, 0, 80, 20);
+const LARGE: Rect = Rect::new(0, 0, 160, 40);
+
+/// State of a notification with the given custom path, at the given phase
+fn state_at(
+    builder: NotificationBuilder,
+    full_rect: Rect,
+    phase: AnimationPhase,
+) -> NotificationState {
+    let notif = builder.build().unwrap();
+    let mut state = NotificationState::new(1, notif, &ManagerDefaults::default());
+    state.set_full_rect(full_rect);
+    // Just past the start of the slide-in, so the rect sits at the entry point
+    state.update(Duration::from_millis(1));
+    if phase == AnimationPhase::SlidingOut {
+        state.update(Duration::from_millis(600));
+        state.update(Duration::from_secs(5));
+        state.update(Duration::from_millis(749));
+    }
+    assert_eq!(state.phase(), phase);
+    state
+}
+
+#[test]
+fn test_percent_point_resolves_against_frame() {
+    let point = PathPoint::Percent { x: 25.0, y: 50.0 };
+    assert_eq!(point.resolve(SMALL), (20.0, 10.0));
+    assert_eq!(point.resolve(LARGE), (40.0, 20.0));
+    assert_eq!(point.resolve(Rect::new(5, 3, 80, 20)), (25.0, 13.0));
+
+    let absolute = PathPoint::Absolute(Position::new(7, 9));
+    assert_eq!(absolute.resolve(SMALL), (7.0, 9.0));
+    assert_eq!(absolute.resolve(LARGE), (7.0, 9.0));
+}
+
+#[test]
+fn test_builder_stores_percent_points() {
+    let notif = NotificationBuilder::new("Hi")
+        .entry_position_pct(10.0, 90.0)
+        .exit_position_pct(90.0, 10.0)
+        .build()
+        .unwrap();
+
+    assert_eq!(notif.entry_point(), Some(PathPoint::Percent { x: 10.0, y: 90.0 }));
+    assert_eq!(notif.exit_point(), Some(PathPoint::Percent { x: 90.0, y: 10.0 }));
+    // The absolute accessors only report absolute positions
+    assert_eq!(notif.custom_entry_position(), None);
+    assert_eq!(notif.custom_exit_position(), None);
+}
+
+#[test]
+fn test_slide_in_starts_at_percent_point_for_each_frame_size() {
+    let full_rect = Rect::new(10, 5, 20, 5);
+    let builder = || NotificationBuilder::new("Hi").entry_position_pct(50.0, 0.0);
+    let state = state_at(builder(), full_rect, AnimationPhase::SlidingIn);
+
+    let small = state.calculate_animation_rect(SMALL);
+    let large = state.calculate_animation_rect(LARGE);
+    assert_eq!((small.x, small.y), (40, 0));
+    assert_eq!((large.x, large.y), (80, 0));
+}
+
+#[test]
+fn test_slide_out_ends_at_percent_point_for_each_frame_size() {
+    let full_rect = Rect::new(10, 5, 20, 5);
+    let builder = || NotificationBuilder::new("Hi").exit_position_pct(25.0, 50.0);
+    let state = state_at(builder(), full_rect, AnimationPhase::SlidingOut);
+
+    let small = state.calculate_animation_rect(SMALL);
+    let large = state.calculate_animation_rect(LARGE);
+    assert_eq!((small.x, small.y), (20, 10));
+    assert_eq!((large.x, large.y), (40, 20));
+}
+
+#[test]
+fn test_generate_code_emits_percent_form() {
+    let notif = NotificationBuilder::new("Hi")
+        .entry_position_pct(12.5, 110.0)
+        .exit_position(Position::new(3, 4))
+        .build()
+        .unwrap();
+    let code = generate_code(&notif);
+
+    assert!(code.contains(".entry_position_pct(12.5, 110.0)"));
+    assert!(code.contains(".exit_position(Position::new(3, 4))"));
+}
+
+// FILE: tests/test_path_point_integration.rs - Integration tests for percent-based entry/exit positions
+// END OF VERSION: 1.0.0
@@ -0,0 +1,89 @@
+// FILE: tests/test_percentage_rounding_integration.rs - Integration tests for percentage size rounding
+// VERSION: 1.0.0
+// WCTX: Percentage rounding rule
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    measure, Anchor, Animation, MeasureOptions, NotificationBuilder, Notifications, SizeConstraint,
+};
+use std::time::Duration;
+
+const LONG_TEXT: &str = "a long message that wraps at any of the widths under test here, \
+                         repeated so that it always fills the percentage of the frame width";
+
+#[test]
+fn test_percentage_resolves_round_half_up() {
+    let cases = [
+        (79, 0.333, 26),
+        (79, 0.5, 40),
+        (79, 0.999, 79),
+        (121, 0.333, 40),
+        (121, 0.35, 42),
+        (121, 0.5, 61),
+        (121, 0.999, 121),
+        (1, 0.333, 1),
+        (1, 0.5, 1),
+        (1, 0.999, 1),
+    ];
+    for (available, percentage, expected) in cases {
+        assert_eq!(
+            SizeConstraint::Percentage(percentage).resolve(available),
+            expected,
+            "{percentage} of {available}"
+        );
+    }
+}
+
+#[test]
+fn test_percentage_out_of_range_values() {
+    assert_eq!(SizeConstraint::Percentage(1.5).resolve(80), 80);
+    assert_eq!(SizeConstraint::Percentage(0.0).resolve(80), 1);
+    assert_eq!(SizeConstraint::Percentage(f32::NAN).resolve(80), 1);
+    assert_eq!(SizeConstraint::Absolute(90).resolve(80), 80);
+}
+
+#[test]
+fn test_measure_uses_the_rounding_rule() {
+    for (width, percentage, expected) in [(79, 0.333, 26), (79, 0.5, 40), (121, 0.333, 40), (121, 0.5, 61)] {
+        let options = MeasureOptions {
+            max_width: Some(SizeConstraint::Percentage(percentage)),
+            ..MeasureOptions::new(Rect::new(0, 0, width, 60))
+        };
+        assert_eq!(measure(LONG_TEXT, &options).0, expected, "{percentage} of {width}");
+    }
+}
+
+#[test]
+fn test_stacked_siblings_align() {
+    for (width, percentage) in [(79, 0.333), (121, 0.5), (121, 0.999)] {
+        let mut manager = Notifications::new();
+        let ids: Vec<u64> = (0..2)
+            .map(|_| {
+                let notif = NotificationBuilder::new(LONG_TEXT)
+                    .anchor(Anchor::BottomRight)
+                    .animation(Animation::Fade)
+                    .max_size(SizeConstraint::Percentage(percentage), SizeConstraint::Percentage(0.4))
+                    .build()
+                    .unwrap();
+                manager.add(notif).unwrap()
+            })
+            .collect();
+        manager.tick(Duration::from_secs(1));
+
+        let mut terminal = Terminal::new(TestBackend::new(width, 60)).unwrap();
+        terminal
+            .draw(|frame| manager.render(frame, frame.area()))
+            .unwrap();
+
+        let first = manager.content_rect(ids[0]).unwrap();
+        let second = manager.content_rect(ids[1]).unwrap();
+        assert_eq!(first.x, second.x, "{percentage} of {width}");
+        assert_eq!(first.width, second.width, "{percentage} of {width}");
+    }
+}
+
+// FILE: tests/test_percentage_rounding_integration.rs - Integration tests for percentage size rounding
+// END OF VERSION: 1.0.0
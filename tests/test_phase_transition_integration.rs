@@ -0,0 +1,147 @@
+// FILE: tests/test_phase_transition_integration.rs - Integration tests for the animation phase transition table
+// VERSION: 1.0.0
+// WCTX: Phase transition table
+// CLOG: Initial creation
+
+use ratatui_notifications::notifications::{
+    Animation, AnimationPhase, AutoDismiss, NotificationBuilder, Notifications,
+};
+use std::time::Duration;
+
+const ANIMATIONS: [Animation; 4] =
+    [Animation::Slide, Animation::ExpandCollapse, Animation::Fade, Animation::RollDown];
+
+/// Whether any animation allows the edge
+fn legal_for_some(from: AnimationPhase, to: AnimationPhase) -> bool {
+    ANIMATIONS.iter().any(|&animation| from.can_transition_to(to, animation))
+}
+
+/// Markdown table of the transition table, as in docs/API.md
+fn markdown_table() -> String {
+    let mut table = String::from("| From | To |\n|------|----|\n");
+    for from in AnimationPhase::ALL {
+        let targets: Vec<String> = AnimationPhase::ALL
+            .into_iter()
+            .filter(|&to| legal_for_some(from, to))
+            .map(|to| format!("`{to:?}`"))
+            .collect();
+        let targets = targets.join(", ");
+        let cell = if targets.is_empty() { String::new() } else { format!("{targets} ") };
+        table.push_str(&format!("| `{from:?}` | {cell}|\n"));
+    }
+    table
+}
+
+/// DOT graph of the transition table, as in docs/API.md
+fn dot_graph() -> String {
+    let mut dot = String::from("digraph AnimationPhase {\n");
+    for from in AnimationPhase::ALL {
+        for to in AnimationPhase::ALL.into_iter().filter(|&to| legal_for_some(from, to)) {
+            dot.push_str(&format!("    {from:?} -> {to:?};\n"));
+        }
+    }
+    dot.push('}');
+    dot
+}
+
+/// Ticks in small steps, asserting every observed phase change is a legal edge
+fn assert_legal_path(manager: &mut Notifications, id: u64, animation: Animation, ticks: usize) {
+    let mut last = manager.phase(id).unwrap();
+    for _ in 0..ticks {
+        manager.tick(Duration::from_millis(10));
+        let Some(phase) = manager.phase(id) else {
+            return;
+        };
+        if phase != last {
+            assert!(last.can_transition_to(phase, animation), "{last:?} -> {phase:?}");
+            last = phase;
+        }
+    }
+}
+
+#[test]
+fn test_docs_match_transition_table() {
+    let api = include_str!("../docs/API.md");
+    let table = markdown_table();
+    let dot = dot_graph();
+    assert!(api.contains(&table), "docs/API.md is out of date; expected:\n{table}");
+    assert!(api.contains(&dot), "docs/API.md is out of date; expected:\n{dot}");
+}
+
+#[test]
+fn test_animations_only_use_their_own_entry_and_exit() {
+    for animation in ANIMATIONS {
+        let entry = AnimationPhase::entry(animation);
+        let exit = AnimationPhase::exit(animation);
+        for other in ANIMATIONS {
+            let other_exit = AnimationPhase::exit(other);
+            if other_exit != exit {
+                assert!(!AnimationPhase::Dwelling.can_transition_to(other_exit, animation));
+                assert!(!entry.can_transition_to(other_exit, animation));
+            }
+        }
+        assert!(AnimationPhase::Pending.can_transition_to(entry, animation));
+        assert!(entry.can_transition_to(AnimationPhase::Dwelling, animation));
+        assert!(AnimationPhase::Dwelling.can_transition_to(exit, animation));
+        assert!(exit.can_transition_to(AnimationPhase::Finished, animation));
+    }
+}
+
+#[test]
+fn test_phases_never_move_backwards_or_leave_finished() {
+    for animation in ANIMATIONS {
+        for phase in AnimationPhase::ALL {
+            assert!(!phase.can_transition_to(phase, animation), "{phase:?} to itself");
+            assert!(!phase.can_transition_to(AnimationPhase::Pending, animation));
+            assert!(!AnimationPhase::Finished.can_transition_to(phase, animation));
+        }
+        let exit = AnimationPhase::exit(animation);
+        assert!(!exit.can_transition_to(AnimationPhase::Dwelling, animation));
+        assert!(!AnimationPhase::Pending.can_transition_to(AnimationPhase::Dwelling, animation));
+    }
+}
+
+#[test]
+fn test_full_lifecycle_follows_the_table() {
+    for animation in ANIMATIONS {
+        let mut manager = Notifications::new();
+        let notif = NotificationBuilder::new("Saved")
+            .animation(animation)
+            .auto_dismiss(AutoDismiss::After(Duration::from_millis(200)))
+            .build()
+            .unwrap();
+        let id = manager.add(notif).unwrap();
+        assert_legal_path(&mut manager, id, animation, 300);
+        assert_eq!(manager.phase(id), None, "{animation:?} never finished");
+    }
+}
+
+#[test]
+fn test_dismiss_while_entering_follows_the_table() {
+    for animation in ANIMATIONS {
+        let mut manager = Notifications::new();
+        let notif = NotificationBuilder::new("Saved").animation(animation).build().unwrap();
+        let id = manager.add(notif).unwrap();
+        manager.tick(Duration::from_millis(100));
+        assert_eq!(manager.phase(id), Some(AnimationPhase::entry(animation)));
+
+        manager.retain(|info| info.id != id);
+        assert_eq!(manager.phase(id), Some(AnimationPhase::exit(animation)));
+        assert_legal_path(&mut manager, id, animation, 300);
+    }
+}
+
+#[test]
+fn test_dismiss_while_hidden_finishes_from_pending() {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("Later").build().unwrap();
+    let id = manager.add_hidden(notif).unwrap();
+    manager.tick(Duration::from_millis(100));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Pending));
+
+    manager.retain(|info| info.id != id);
+    assert_eq!(manager.phase(id), None);
+}
+
+// FILE: tests/test_phase_transition_integration.rs - Integration tests for the animation phase transition table
+// END OF VERSION: 1.0.0
@@ -0,0 +1,138 @@
+// FILE: tests/test_placeholder_integration.rs - Integration tests for content placeholders
+// VERSION: 1.1.0
+// WCTX: Deterministic test clock
+// CLOG: Replaced the local ManualClock with TestClock
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::style::Modifier;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::traits::TestClock;
+use ratatui_notifications::notifications::{
+    generate_code, Anchor, AutoDismiss, NotificationBuilder, Notifications, PlaceholderStyle,
+    SizeConstraint, Timing,
+};
+use std::time::Duration;
+
+const RESOLVED: &str = "example.org → 93.184.216.34";
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+/// 24x4 skeleton box in the top-left corner, entering over 100ms
+fn lookup(style: PlaceholderStyle) -> NotificationBuilder {
+    NotificationBuilder::new("")
+        .title("Host")
+        .anchor(Anchor::TopLeft)
+        .placeholder(style)
+        .min_size(SizeConstraint::Absolute(24), SizeConstraint::Absolute(4))
+        .max_size(SizeConstraint::Absolute(60), SizeConstraint::Absolute(8))
+        .timing(Timing::Fixed(ms(100)), Timing::Auto, Timing::Auto)
+        .auto_dismiss(AutoDismiss::Never)
+}
+
+fn draw(manager: &mut Notifications) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(80, 10)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn row(buffer: &Buffer, y: u16) -> String {
+    (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect()
+}
+
+fn screen(buffer: &Buffer) -> String {
+    (0..buffer.area.height).map(|y| row(buffer, y)).collect::<Vec<_>>().join("\n")
+}
+
+#[test]
+fn test_skeleton_until_content_arrives() {
+    let mut manager = Notifications::new().move_duration(ms(200));
+    let id = manager.add(lookup(PlaceholderStyle::Skeleton).build().unwrap()).unwrap();
+    manager.tick(ms(100));
+    draw(&mut manager);
+    manager.tick(ms(400));
+
+    // Skeleton frame: dim dashes across both content rows of the 24x4 box
+    let skeleton = draw(&mut manager);
+    assert_eq!(manager.content_rect(id).unwrap().width, 20);
+    for y in [1, 2] {
+        assert_eq!(row(&skeleton, y).trim_end(), format!("│ {} │", "─".repeat(20)));
+        assert!(skeleton[(2, y)].modifier.contains(Modifier::DIM));
+    }
+
+    // The lookup resolves at 500ms; the box eases to its new size
+    assert!(manager.update_content(id, RESOLVED));
+    manager.tick(ms(100));
+    let easing = draw(&mut manager);
+    assert!(!row(&easing, 1).contains("───"), "{}", screen(&easing));
+    manager.tick(ms(100));
+
+    let last = draw(&mut manager);
+    let text = screen(&last);
+    assert!(text.contains(RESOLVED), "{text}");
+    assert!(!last[(2, 1)].modifier.contains(Modifier::DIM));
+    assert_eq!(manager.content_rect(id).unwrap().width, RESOLVED.chars().count() as u16);
+}
+
+#[test]
+fn test_text_placeholder_is_shown_verbatim() {
+    let mut manager = Notifications::new();
+    let placeholder = PlaceholderStyle::Text("Resolving…".to_string());
+    let id = manager.add(lookup(placeholder).build().unwrap()).unwrap();
+    manager.tick(ms(500));
+    assert!(screen(&draw(&mut manager)).contains("Resolving…"));
+
+    manager.update_content(id, "Resolved");
+    let text = screen(&draw(&mut manager));
+    assert!(text.contains("Resolved") && !text.contains("Resolving"), "{text}");
+}
+
+#[test]
+fn test_spinner_turns_with_the_clock() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    manager.add(lookup(PlaceholderStyle::Spinner).build().unwrap()).unwrap();
+    manager.tick(ms(100));
+    let first = row(&draw(&mut manager), 1);
+
+    clock.advance(ms(100));
+    manager.tick(ms(100));
+    let second = row(&draw(&mut manager), 1);
+
+    assert!(first.contains('⠋'), "{first}");
+    assert!(second.contains('⠙'), "{second}");
+}
+
+#[test]
+fn test_set_content_also_ends_the_placeholder() {
+    let mut manager = Notifications::new();
+    let placeholder = PlaceholderStyle::Text("Waiting".to_string());
+    manager.add(lookup(placeholder).build().unwrap()).unwrap();
+    manager.tick(ms(100));
+
+    manager.for_each_mut(|mut notification| notification.set_content("Done"));
+    let text = screen(&draw(&mut manager));
+    assert!(text.contains("Done") && !text.contains("Waiting"), "{text}");
+}
+
+#[test]
+fn test_update_content_unknown_id() {
+    let mut manager = Notifications::new();
+    assert!(!manager.update_content(3, "Nobody"));
+}
+
+#[test]
+fn test_generate_code_emits_placeholder() {
+    let placeholder = PlaceholderStyle::Text("Resolving \"host\"".to_string());
+    let code = generate_code(&lookup(placeholder).build().unwrap());
+    let expected = r#".placeholder(PlaceholderStyle::Text("Resolving \"host\"".to_string()))"#;
+    assert!(code.contains(expected), "{code}");
+
+    let code = generate_code(&lookup(PlaceholderStyle::Skeleton).build().unwrap());
+    assert!(code.contains(".placeholder(PlaceholderStyle::Skeleton)"), "{code}");
+}
+
+// FILE: tests/test_placeholder_integration.rs - Integration tests for content placeholders
+// END OF VERSION: 1.1.0
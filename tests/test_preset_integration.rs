@@ -0,0 +1,105 @@
+// FILE: tests/test_preset_integration.rs - Integration tests for named notification presets
+// VERSION: 1.1.0
+// WCTX: Exact dwell timing
+// CLOG: Display time starts when the entry ends
+
+use ratatui::text::Text;
+use ratatui_notifications::notifications::{
+    Anchor, AnimationPhase, AutoDismiss, Level, NotificationBuilder, NotificationError,
+    Notifications, Timing,
+};
+use std::time::Duration;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+/// Error style: top center, 200ms slides, dismissed after 1s
+fn error_preset(builder: NotificationBuilder) -> NotificationBuilder {
+    builder
+        .title("Error")
+        .level(Level::Error)
+        .anchor(Anchor::TopCenter)
+        .timing(Timing::Fixed(ms(200)), Timing::Auto, Timing::Fixed(ms(200)))
+        .auto_dismiss(AutoDismiss::After(ms(1000)))
+}
+
+#[test]
+fn test_preset_supplies_style_and_call_site_supplies_content() {
+    let mut manager = Notifications::new();
+    manager.register_preset("error", error_preset);
+
+    let id = manager.add_preset("error", "Connection lost").unwrap();
+    assert_eq!(manager.anchor(id), Some(Anchor::TopCenter));
+
+    let notification = manager.drain().pop().unwrap();
+    assert_eq!(notification.content(), &Text::from("Connection lost"));
+    assert_eq!(notification.title().unwrap().to_string(), "Error");
+    assert_eq!(notification.level(), Some(Level::Error));
+    assert_eq!(notification.slide_in_timing(), Timing::Fixed(ms(200)));
+    assert_eq!(notification.slide_out_timing(), Timing::Fixed(ms(200)));
+    assert_eq!(notification.auto_dismiss(), AutoDismiss::After(ms(1000)));
+}
+
+#[test]
+fn test_preset_timing_drives_the_lifecycle() {
+    let mut manager = Notifications::new();
+    manager.register_preset("error", error_preset);
+    let id = manager.add_preset("error", "Disk full").unwrap();
+
+    manager.tick(ms(200));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    // Display time starts once the entry ends
+    manager.tick(ms(999));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+    manager.tick(ms(1));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::SlidingOut));
+}
+
+#[test]
+fn test_each_call_gets_its_own_notification() {
+    let mut manager = Notifications::new();
+    manager.register_preset("error", error_preset);
+    let first = manager.add_preset("error", "First").unwrap();
+    let second = manager.add_preset("error", "Second").unwrap();
+
+    assert_ne!(first, second);
+    let contents: Vec<Text> = manager.drain().iter().map(|n| n.content().clone()).collect();
+    assert_eq!(contents, [Text::from("First"), Text::from("Second")]);
+}
+
+#[test]
+fn test_registering_again_replaces_the_preset() {
+    let mut manager = Notifications::new();
+    manager.register_preset("toast", |builder| builder.anchor(Anchor::TopLeft));
+    manager.register_preset("toast", |builder| builder.anchor(Anchor::BottomRight));
+
+    let id = manager.add_preset("toast", "Hi").unwrap();
+    assert_eq!(manager.anchor(id), Some(Anchor::BottomRight));
+}
+
+#[test]
+fn test_unknown_preset_is_an_error() {
+    let mut manager = Notifications::new();
+    manager.register_preset("error", error_preset);
+
+    assert_eq!(
+        manager.add_preset("eror", "Typo"),
+        Err(NotificationError::UnknownPreset("eror".to_string()))
+    );
+    assert!(!manager.has_notification());
+}
+
+#[test]
+fn test_invalid_preset_configuration_is_reported() {
+    let mut manager = Notifications::new();
+    manager.register_preset("broken", |builder| builder.speed(0.0));
+
+    assert!(matches!(
+        manager.add_preset("broken", "Never shown"),
+        Err(NotificationError::InvalidConfig(_))
+    ));
+}
+
+// FILE: tests/test_preset_integration.rs - Integration tests for named notification presets
+// END OF VERSION: 1.1.0
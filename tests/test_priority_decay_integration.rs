@@ -0,0 +1,121 @@
+// FILE: tests/test_priority_decay_integration.rs - Integration tests for priority overflow and decay
+// VERSION: 1.0.0
+// WCTX: Priority decay
+// CLOG: Initial creation
+
+use ratatui_notifications::notifications::{
+    AutoDismiss, Level, ManagerDefaults, NotificationBuilder, NotificationState, Notifications,
+    Overflow, Timing,
+};
+use std::time::Duration;
+
+const ENTRY: Duration = Duration::from_millis(100);
+
+fn notification(level: Level, decay: f32) -> NotificationBuilder {
+    NotificationBuilder::new(format!("{level:?}"))
+        .level(level)
+        .decay(decay)
+        .timing(Timing::Fixed(ENTRY), Timing::Auto, Timing::Fixed(ENTRY))
+        .auto_dismiss(AutoDismiss::Never)
+}
+
+fn manager(max_concurrent: usize) -> Notifications {
+    Notifications::new()
+        .max_concurrent(Some(max_concurrent))
+        .overflow(Overflow::DiscardLowestPriority)
+}
+
+#[test]
+fn test_lowest_priority_is_discarded() {
+    let mut manager = manager(2);
+    let error = manager.add(notification(Level::Error, 0.0).build().unwrap()).unwrap();
+    let info = manager.add(notification(Level::Info, 0.0).build().unwrap()).unwrap();
+    manager.tick(ENTRY);
+
+    // The newer info outranks nothing, but the older error outranks it
+    let warn = manager.add(notification(Level::Warn, 0.0).build().unwrap()).unwrap();
+    assert!(manager.phase(error).is_some());
+    assert_eq!(manager.phase(info), None);
+    assert!(manager.phase(warn).is_some());
+}
+
+#[test]
+fn test_equal_priorities_discard_the_oldest() {
+    let mut manager = manager(2);
+    let first = manager.add(notification(Level::Info, 0.0).build().unwrap()).unwrap();
+    let second = manager.add(notification(Level::Info, 0.0).build().unwrap()).unwrap();
+    manager.add(notification(Level::Info, 0.0).build().unwrap()).unwrap();
+    assert_eq!(manager.phase(first), None);
+    assert!(manager.phase(second).is_some());
+}
+
+#[test]
+fn test_decayed_error_gives_way_to_newer_info() {
+    let mut manager = manager(2);
+    let error = manager.add(notification(Level::Error, 1.0).build().unwrap()).unwrap();
+    manager.tick(ENTRY);
+
+    // Fresh: the error (5) outranks an info (3)
+    let info = manager.add(notification(Level::Info, 0.0).build().unwrap()).unwrap();
+    manager.add(notification(Level::Info, 0.0).build().unwrap()).unwrap();
+    assert!(manager.phase(error).is_some());
+    assert_eq!(manager.phase(info), None);
+
+    // After 3s of dwelling it ranks 2, below the infos
+    manager.tick(Duration::from_secs(3));
+    let newest = manager.add(notification(Level::Info, 0.0).build().unwrap()).unwrap();
+    assert_eq!(manager.phase(error), None);
+    assert!(manager.phase(newest).is_some());
+}
+
+#[test]
+fn test_decay_counts_only_dwell_time() {
+    let notif = notification(Level::Error, 2.0).build().unwrap();
+    let mut state = NotificationState::new(1, notif, &ManagerDefaults::default());
+    assert_eq!(state.effective_priority(), 5.0);
+
+    // The entry does not decay; the 500ms after it does
+    state.update(ENTRY + Duration::from_millis(500));
+    assert!((state.effective_priority() - 4.0).abs() < 1e-4, "{}", state.effective_priority());
+}
+
+#[test]
+fn test_merging_a_duplicate_restarts_decay() {
+    let mut manager = Notifications::new().overflow(Overflow::MergeDuplicates);
+    let notif = notification(Level::Warn, 1.0).build().unwrap();
+    let warn = manager.add(notif.clone()).unwrap();
+    manager.tick(ENTRY + Duration::from_secs(2));
+    let info = manager.add(notification(Level::Info, 0.0).build().unwrap()).unwrap();
+    assert_eq!(manager.add(notif).unwrap(), warn);
+
+    // Merged, the warning ranks 4 again rather than 2, so the info makes way
+    let mut manager = manager.max_concurrent(Some(2)).overflow(Overflow::DiscardLowestPriority);
+    manager.add(notification(Level::Info, 0.0).build().unwrap()).unwrap();
+    assert!(manager.phase(warn).is_some());
+    assert_eq!(manager.phase(info), None);
+}
+
+#[test]
+fn test_enforce_limits_dismisses_lowest_priority() {
+    let mut manager = Notifications::new().overflow(Overflow::DiscardLowestPriority);
+    let error = manager.add(notification(Level::Error, 0.0).build().unwrap()).unwrap();
+    let debug = manager.add(notification(Level::Debug, 0.0).build().unwrap()).unwrap();
+    let warn = manager.add(notification(Level::Warn, 0.0).build().unwrap()).unwrap();
+    manager.tick(ENTRY);
+
+    let mut manager = manager.max_concurrent(Some(2));
+    manager.enforce_limits();
+    manager.tick(ENTRY);
+    assert_eq!(manager.phase(debug), None);
+    assert!(manager.phase(error).is_some() && manager.phase(warn).is_some());
+}
+
+#[test]
+fn test_invalid_decay_is_rejected() {
+    for rate in [-1.0, f32::NAN, f32::INFINITY] {
+        assert!(NotificationBuilder::new("Bad").decay(rate).build().is_err(), "{rate}");
+    }
+}
+
+// FILE: tests/test_priority_decay_integration.rs - Integration tests for priority overflow and decay
+// END OF VERSION: 1.0.0
@@ -0,0 +1,107 @@
+// FILE: tests/test_push_line_integration.rs - Integration tests for push_line live tails
+// VERSION: 1.0.0
+// WCTX: Live tail notifications
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Animation, AutoDismiss, NotificationBuilder, Notifications, SizeConstraint,
+};
+use std::time::Duration;
+
+fn tail(max_height: SizeConstraint) -> (Notifications, u64) {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("")
+        .title("tail")
+        .animation(Animation::Fade)
+        .auto_dismiss(AutoDismiss::Never)
+        .max_size(SizeConstraint::Absolute(40), max_height)
+        .build()
+        .unwrap();
+    let id = manager.add(notif).unwrap();
+    (manager, id)
+}
+
+fn render(manager: &mut Notifications) -> String {
+    manager.tick(Duration::from_secs(1));
+    let mut terminal = Terminal::new(TestBackend::new(80, 40)).unwrap();
+    terminal
+        .draw(|frame| manager.render(frame, frame.area()))
+        .unwrap();
+    terminal
+        .backend()
+        .buffer()
+        .content
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect()
+}
+
+#[test]
+fn test_push_line_shows_only_the_newest_lines() {
+    let (mut manager, id) = tail(SizeConstraint::Absolute(6));
+    for n in 0..10 {
+        assert!(manager.push_line(id, format!("line-{n}")));
+    }
+
+    // Four content rows inside the border
+    let text = render(&mut manager);
+    for n in 6..10 {
+        assert!(text.contains(&format!("line-{n}")), "line-{n} missing");
+    }
+    for n in 0..6 {
+        assert!(!text.contains(&format!("line-{n}")), "line-{n} still shown");
+    }
+}
+
+#[test]
+fn test_push_line_keeps_following_after_render() {
+    let (mut manager, id) = tail(SizeConstraint::Percentage(0.15));
+    manager.push_line(id, "first");
+    assert!(render(&mut manager).contains("first"));
+
+    for n in 0..20 {
+        manager.push_line(id, format!("next-{n}"));
+    }
+    let text = render(&mut manager);
+    assert!(text.contains("next-19"));
+    assert!(!text.contains("first"));
+    assert!(!text.contains("next-0 "));
+}
+
+#[test]
+fn test_push_line_grows_until_max_height() {
+    let (mut manager, id) = tail(SizeConstraint::Absolute(6));
+    manager.push_line(id, "one");
+    render(&mut manager);
+    let small = manager.content_rect(id).unwrap();
+
+    manager.push_line(id, "two");
+    render(&mut manager);
+    let grown = manager.content_rect(id).unwrap();
+    assert_eq!(grown.height, small.height + 1);
+
+    for n in 0..10 {
+        manager.push_line(id, format!("more-{n}"));
+    }
+    render(&mut manager);
+    assert_eq!(manager.content_rect(id).unwrap().height, 4);
+}
+
+#[test]
+fn test_push_line_replaces_empty_content() {
+    let (mut manager, id) = tail(SizeConstraint::Absolute(6));
+    manager.push_line(id, "only");
+    render(&mut manager);
+    assert_eq!(manager.content_rect(id).unwrap().height, 1);
+}
+
+#[test]
+fn test_push_line_unknown_id() {
+    let (mut manager, id) = tail(SizeConstraint::Absolute(6));
+    assert!(!manager.push_line(id + 1, "nobody"));
+}
+
+// FILE: tests/test_push_line_integration.rs - Integration tests for push_line live tails
+// END OF VERSION: 1.0.0
@@ -0,0 +1,105 @@
+// FILE: tests/test_render_budget_integration.rs - Integration tests for the per-frame render budget
+// VERSION: 1.0.0
+// WCTX: Render budget
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Anchor, AutoDismiss, Level, NotificationBuilder, Notifications,
+};
+use std::time::Duration;
+
+fn add(manager: &mut Notifications, n: usize, anchor: Anchor, level: Level) -> u64 {
+    let notification = NotificationBuilder::new(format!("Log line {n}"))
+        .anchor(anchor)
+        .level(level)
+        .auto_dismiss(AutoDismiss::Never);
+    manager.add(notification.build().unwrap()).unwrap()
+}
+
+fn render(manager: &mut Notifications) {
+    manager.tick(Duration::from_secs(1));
+    // Tall enough to stack all of them
+    let mut terminal = Terminal::new(TestBackend::new(120, 400)).unwrap();
+    terminal
+        .draw(|frame| manager.render(frame, frame.area()))
+        .unwrap();
+}
+
+/// IDs that have been given a rect by layout
+fn laid_out(manager: &Notifications) -> Vec<u64> {
+    manager
+        .infos()
+        .into_iter()
+        .filter(|info| info.rect.is_some())
+        .map(|info| info.id)
+        .collect()
+}
+
+#[test]
+fn test_budget_limits_layout_to_the_newest() {
+    let mut manager = Notifications::new().render_budget(Some(10));
+    let ids: Vec<u64> = (0..100)
+        .map(|n| add(&mut manager, n, Anchor::BottomRight, Level::Info))
+        .collect();
+    render(&mut manager);
+
+    assert_eq!(laid_out(&manager), ids[90..].to_vec());
+    // Nothing was dismissed
+    assert_eq!(manager.infos().len(), 100);
+}
+
+#[test]
+fn test_no_budget_lays_out_everything() {
+    let mut manager = Notifications::new();
+    for n in 0..100 {
+        add(&mut manager, n, Anchor::BottomRight, Level::Info);
+    }
+    render(&mut manager);
+
+    assert_eq!(laid_out(&manager).len(), 100);
+}
+
+#[test]
+fn test_budget_prefers_higher_priority() {
+    let mut manager = Notifications::new().render_budget(Some(3));
+    let error = add(&mut manager, 0, Anchor::BottomRight, Level::Error);
+    let infos: Vec<u64> = (1..10)
+        .map(|n| add(&mut manager, n, Anchor::BottomRight, Level::Info))
+        .collect();
+    render(&mut manager);
+
+    assert_eq!(laid_out(&manager), vec![error, infos[7], infos[8]]);
+}
+
+#[test]
+fn test_budget_spans_all_anchors() {
+    let mut manager = Notifications::new().render_budget(Some(4));
+    for n in 0..6 {
+        add(&mut manager, n, Anchor::TopLeft, Level::Info);
+    }
+    let newest: Vec<u64> = (6..10)
+        .map(|n| add(&mut manager, n, Anchor::BottomRight, Level::Info))
+        .collect();
+    render(&mut manager);
+
+    assert_eq!(laid_out(&manager), newest);
+}
+
+#[test]
+fn test_budget_is_applied_each_frame() {
+    let mut manager = Notifications::new().render_budget(Some(2));
+    let ids: Vec<u64> = (0..3)
+        .map(|n| add(&mut manager, n, Anchor::BottomRight, Level::Info))
+        .collect();
+    render(&mut manager);
+    assert_eq!(laid_out(&manager), ids[1..].to_vec());
+
+    manager.remove(ids[2]);
+    render(&mut manager);
+    assert_eq!(laid_out(&manager), ids[..2].to_vec());
+}
+
+// FILE: tests/test_render_budget_integration.rs - Integration tests for the per-frame render budget
+// END OF VERSION: 1.0.0
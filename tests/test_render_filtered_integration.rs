@@ -0,0 +1,110 @@
+// FILE: tests/test_render_filtered_integration.rs - Integration tests for render_filtered
+// VERSION: 1.0.0
+// WCTX: Filtered rendering
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, Level, NotificationBuilder, Notifications,
+};
+use std::time::Duration;
+
+fn manager() -> Notifications {
+    let mut manager = Notifications::new();
+    for (text, level) in [("error-one", Level::Error), ("info-one", Level::Info), ("info-two", Level::Info)] {
+        let notif = NotificationBuilder::new(text)
+            .level(level)
+            .anchor(Anchor::TopLeft)
+            .animation(Animation::Fade)
+            .build()
+            .unwrap();
+        manager.add(notif).unwrap();
+    }
+    manager.tick(Duration::from_secs(1));
+    manager
+}
+
+fn buffer_text(buffer: &Buffer) -> String {
+    buffer.content.iter().map(|cell| cell.symbol()).collect()
+}
+
+/// Cell position of the first occurrence of `needle` within a row.
+fn position_of(buffer: &Buffer, needle: &str) -> Option<(u16, u16)> {
+    (0..buffer.area.height).find_map(|y| {
+        let row: String = (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect();
+        row.find(needle)
+            .map(|index| (row[..index].chars().count() as u16, y))
+    })
+}
+
+fn row_of(buffer: &Buffer, needle: &str) -> Option<u16> {
+    position_of(buffer, needle).map(|(_, y)| y)
+}
+
+#[test]
+fn test_error_filter_draws_only_errors() {
+    let mut manager = manager();
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal
+        .draw(|frame| manager.render_filtered(frame, frame.area(), |info| info.level == Some(Level::Error)))
+        .unwrap();
+
+    let text = buffer_text(terminal.backend().buffer());
+    assert!(text.contains("error-one"));
+    assert!(!text.contains("info-one"));
+    assert!(!text.contains("info-two"));
+}
+
+#[test]
+fn test_filtered_subset_stacks_without_gaps() {
+    let mut manager = manager();
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal
+        .draw(|frame| manager.render_filtered(frame, frame.area(), |info| info.level == Some(Level::Info)))
+        .unwrap();
+
+    // The first info takes the anchor slot the error would have used
+    let buffer = terminal.backend().buffer();
+    assert!(!buffer_text(buffer).contains("error-one"));
+    assert_eq!(row_of(buffer, "info-"), Some(1));
+}
+
+#[test]
+fn test_filtered_render_anchors_within_area() {
+    let mut manager = manager();
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    let pane = Rect::new(40, 10, 40, 14);
+    terminal
+        .draw(|frame| manager.render_filtered(frame, pane, |info| info.level == Some(Level::Error)))
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    assert_eq!(row_of(buffer, "error-one"), Some(11));
+    let rect = manager.content_rect(0).unwrap();
+    assert!(rect.x >= pane.x && rect.y >= pane.y);
+}
+
+#[test]
+fn test_split_panes_draw_disjoint_subsets() {
+    let mut manager = manager();
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    let (left, right) = (Rect::new(0, 0, 40, 24), Rect::new(40, 0, 40, 24));
+    terminal
+        .draw(|frame| {
+            manager.render_filtered(frame, left, |info| info.level == Some(Level::Error));
+            manager.render_filtered(frame, right, |info| info.level != Some(Level::Error));
+        })
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let column = |needle: &str| position_of(buffer, needle).unwrap().0;
+    assert!(column("error-one") < 40);
+    assert!(column("info-one") >= 40);
+    assert!(column("info-two") >= 40);
+}
+
+// FILE: tests/test_render_filtered_integration.rs - Integration tests for render_filtered
+// END OF VERSION: 1.0.0
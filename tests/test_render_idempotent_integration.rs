@@ -0,0 +1,77 @@
+// FILE: tests/test_render_idempotent_integration.rs - Integration tests for repeatable rendering
+// VERSION: 1.0.0
+// WCTX: Idempotent render
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, NotificationBuilder, Notifications,
+};
+use std::time::Duration;
+
+fn render_into(manager: &mut Notifications, width: u16, height: u16) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+/// A manager with notifications caught mid-animation in several styles
+fn busy_manager() -> Notifications {
+    let mut manager = Notifications::new();
+    let specs = [
+        (Anchor::TopLeft, Animation::Slide, "Sliding in from the corner of the screen"),
+        (Anchor::TopRight, Animation::Fade, "Fading in"),
+        (Anchor::BottomCenter, Animation::ExpandCollapse, "Expanding\nover two lines"),
+        (Anchor::BottomRight, Animation::RollDown, "Rolling\ndown\nslowly"),
+    ];
+    for (anchor, animation, content) in specs {
+        let notif = NotificationBuilder::new(content)
+            .title("Title")
+            .anchor(anchor)
+            .animation(animation)
+            .build()
+            .unwrap();
+        manager.add(notif).unwrap();
+    }
+    manager.tick(Duration::from_millis(230));
+    manager
+}
+
+#[test]
+fn test_consecutive_renders_are_identical() {
+    let mut manager = busy_manager();
+
+    let first = render_into(&mut manager, 80, 24);
+    let second = render_into(&mut manager, 80, 24);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_render_is_identical_during_anchor_move() {
+    let mut manager = busy_manager().move_duration(Duration::from_millis(400));
+    manager.tick(Duration::from_millis(400));
+    render_into(&mut manager, 80, 24);
+    assert!(manager.move_to_anchor(0, Anchor::MiddleCenter));
+    manager.tick(Duration::from_millis(100));
+
+    let first = render_into(&mut manager, 80, 24);
+    let second = render_into(&mut manager, 80, 24);
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_render_does_not_depend_on_previous_frame_size() {
+    let fresh_small = render_into(&mut busy_manager(), 60, 20);
+    let fresh_large = render_into(&mut busy_manager(), 200, 60);
+
+    // Alternate sizes on one manager; each render matches a fresh one
+    let mut manager = busy_manager();
+    assert_eq!(render_into(&mut manager, 60, 20), fresh_small);
+    assert_eq!(render_into(&mut manager, 200, 60), fresh_large);
+    assert_eq!(render_into(&mut manager, 60, 20), fresh_small);
+}
+
+// FILE: tests/test_render_idempotent_integration.rs - Integration tests for repeatable rendering
+// END OF VERSION: 1.0.0
@@ -0,0 +1,69 @@
+// FILE: tests/test_render_sort_integration.rs - Integration tests for render draw order
+// VERSION: 1.0.0
+// WCTX: Severity-ordered rendering across anchors
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, Level, NotificationBuilder, Notifications, SizeConstraint, SortBy,
+};
+use std::time::Duration;
+
+/// Renders an older error at TopLeft and a newer info at TopRight, each 30
+/// columns wide in a 40-column frame, so columns 10..30 overlap.
+fn render_overlapping(sort_by: SortBy) -> Buffer {
+    let mut manager = Notifications::new().render_sort(sort_by);
+    for (glyph, level, anchor) in [("E", Level::Error, Anchor::TopLeft), ("I", Level::Info, Anchor::TopRight)] {
+        let notif = NotificationBuilder::new(vec![glyph; 60].join(" "))
+            .level(level)
+            .anchor(anchor)
+            .animation(Animation::Fade)
+            .max_size(SizeConstraint::Absolute(30), SizeConstraint::Absolute(6))
+            .build()
+            .unwrap();
+        manager.add(notif).unwrap();
+    }
+
+    // Past the default 500ms entry so both are dwelling at their full rects
+    manager.tick(Duration::from_millis(600));
+
+    let mut terminal = Terminal::new(TestBackend::new(40, 8)).unwrap();
+    terminal
+        .draw(|frame| manager.render(frame, frame.area()))
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn overlap_symbols(buffer: &Buffer) -> String {
+    let mut out = String::new();
+    for y in 1..4 {
+        for x in 12..28 {
+            out.push_str(buffer[(x, y)].symbol());
+        }
+    }
+    out
+}
+
+#[test]
+fn test_sort_by_level_draws_error_on_top() {
+    let overlap = overlap_symbols(&render_overlapping(SortBy::Level));
+    assert!(overlap.contains('E'), "Error content should win the overlap: {overlap:?}");
+    assert!(!overlap.contains('I'), "Info content should be hidden: {overlap:?}");
+}
+
+#[test]
+fn test_sort_by_age_draws_newest_on_top() {
+    let overlap = overlap_symbols(&render_overlapping(SortBy::Age));
+    assert!(overlap.contains('I'), "Newer info should win the overlap: {overlap:?}");
+    assert!(!overlap.contains('E'), "Older error should be hidden: {overlap:?}");
+}
+
+#[test]
+fn test_default_sort_is_age() {
+    assert_eq!(SortBy::default(), SortBy::Age);
+}
+
+// FILE: tests/test_render_sort_integration.rs - Integration tests for render draw order
+// END OF VERSION: 1.0.0
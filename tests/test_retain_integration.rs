@@ -0,0 +1,214 @@
+// FILE: tests/test_retain_integration.rs - Integration tests for retain and for_each_mut
+// VERSION: 1.2.0
+// WCTX: Predicate pruning and bulk mutation
+// CLOG: Test the Retained dismiss reason
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::traits::{Notifier, TestClock};
+use ratatui_notifications::notifications::{
+    Anchor, Animation, AnimationPhase, AutoDismiss, DismissReason, Level, NotificationBuilder,
+    Notifications, SizeConstraint,
+};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Records each dismissal's ID and reason
+#[derive(Debug, Clone, Default)]
+struct Dismissals(Arc<Mutex<Vec<(u64, DismissReason)>>>);
+
+impl Notifier for Dismissals {
+    fn notify(&mut self, _level: Option<Level>, _id: u64) {}
+
+    fn dismissed(&mut self, _level: Option<Level>, id: u64, reason: DismissReason) {
+        self.0.lock().unwrap().push((id, reason));
+    }
+}
+
+fn add(manager: &mut Notifications, content: &'static str, level: Level) -> u64 {
+    let notif = NotificationBuilder::new(content)
+        .level(level)
+        .animation(Animation::Fade)
+        .auto_dismiss(AutoDismiss::Never)
+        .build()
+        .unwrap();
+    manager.add(notif).unwrap()
+}
+
+#[test]
+fn test_retain_by_level_dismisses_with_animation() {
+    let mut manager = Notifications::new();
+    let info = add(&mut manager, "Info", Level::Info);
+    let debug = add(&mut manager, "Debug", Level::Debug);
+    let trace = add(&mut manager, "Trace", Level::Trace);
+    let error = add(&mut manager, "Error", Level::Error);
+    manager.tick(Duration::from_millis(600));
+
+    manager.retain(|info| !matches!(info.level, Some(Level::Debug | Level::Trace)));
+
+    // Quiet-mode victims animate out rather than vanishing
+    assert_eq!(manager.phase(debug), Some(AnimationPhase::FadingOut));
+    assert_eq!(manager.phase(trace), Some(AnimationPhase::FadingOut));
+    assert_eq!(manager.phase(info), Some(AnimationPhase::Dwelling));
+    assert_eq!(manager.phase(error), Some(AnimationPhase::Dwelling));
+
+    manager.tick(Duration::from_secs(1));
+    assert_eq!(manager.phase(debug), None);
+    assert_eq!(manager.phase(trace), None);
+    assert_eq!(manager.phase(info), Some(AnimationPhase::Dwelling));
+}
+
+#[test]
+fn test_retain_by_age() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    let old = add(&mut manager, "Old", Level::Info);
+    manager.tick(Duration::from_millis(600));
+    clock.advance(Duration::from_secs(10));
+    let fresh = add(&mut manager, "Fresh", Level::Info);
+
+    let mut seen = Vec::new();
+    manager.retain(|info| {
+        seen.push((info.id, info.age));
+        info.age < Duration::from_secs(5)
+    });
+
+    assert_eq!(seen, vec![(old, Duration::from_secs(10)), (fresh, Duration::ZERO)]);
+    assert_eq!(manager.phase(old), Some(AnimationPhase::FadingOut));
+    assert_eq!(manager.phase(fresh), Some(AnimationPhase::Pending));
+}
+
+#[test]
+fn test_retain_removes_unshown_and_reverses_entering() {
+    let mut manager = Notifications::new();
+    let entering = add(&mut manager, "Entering", Level::Info);
+    manager.tick(Duration::from_millis(250));
+    let pending = add(&mut manager, "Pending", Level::Info);
+
+    manager.retain(|_| false);
+
+    // Never shown, so nothing to animate
+    assert_eq!(manager.phase(pending), None);
+    assert_eq!(manager.phase(entering), Some(AnimationPhase::FadingOut));
+
+    // Halfway in means halfway out: the rest of the exit is ~375ms
+    manager.tick(Duration::from_millis(300));
+    assert_eq!(manager.phase(entering), Some(AnimationPhase::FadingOut));
+    manager.tick(Duration::from_millis(100));
+    assert_eq!(manager.phase(entering), None);
+}
+
+#[test]
+fn test_for_each_mut_content_change_resizes_box() {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("Hi")
+        .anchor(Anchor::TopLeft)
+        .animation(Animation::Fade)
+        .max_size(SizeConstraint::Absolute(60), SizeConstraint::Absolute(10))
+        .build()
+        .unwrap();
+    let id = manager.add(notif).unwrap();
+    manager.tick(Duration::from_millis(600));
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let before = manager.content_rect(id).unwrap();
+
+    manager.for_each_mut(|mut notification| {
+        notification.set_content("A considerably longer message\nspanning two lines");
+    });
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let after = manager.content_rect(id).unwrap();
+
+    assert!(after.width > before.width);
+    assert_eq!(after.height, before.height + 1);
+    let buffer = terminal.backend().buffer();
+    let drawn: String = (after.x..after.x + 12)
+        .map(|x| buffer[(x, after.y)].symbol().to_string())
+        .collect();
+    assert_eq!(drawn, "A considerab");
+}
+
+#[test]
+fn test_for_each_mut_level_timer_and_dismiss() {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("Short-lived")
+        .animation(Animation::Fade)
+        .auto_dismiss(AutoDismiss::After(Duration::from_secs(1)))
+        .build()
+        .unwrap();
+    let extended = manager.add(notif).unwrap();
+    let dismissed = add(&mut manager, "Going", Level::Info);
+    manager.tick(Duration::from_millis(600));
+
+    manager.for_each_mut(|mut notification| {
+        if notification.id() == dismissed {
+            notification.dismiss();
+        } else {
+            notification.set_level(Level::Error);
+            notification.extend_display_time(Duration::from_secs(2));
+        }
+    });
+
+    assert_eq!(manager.phase(dismissed), Some(AnimationPhase::FadingOut));
+    let mut levels = Vec::new();
+    manager.retain(|info| {
+        levels.push(info.level);
+        true
+    });
+    assert_eq!(levels[0], Some(Level::Error));
+
+    // Without the extension this would be exiting by now
+    manager.tick(Duration::from_millis(1000));
+    assert_eq!(manager.phase(extended), Some(AnimationPhase::Dwelling));
+}
+
+#[test]
+fn test_retain_and_for_each_mut_dismissals_are_retained() {
+    let dismissals = Dismissals::default();
+    let mut manager = Notifications::new().history_limit(10);
+    manager.set_notifier(Box::new(dismissals.clone()));
+    let rejected = add(&mut manager, "Debug", Level::Debug);
+    let dismissed = add(&mut manager, "Going", Level::Info);
+    manager.tick(Duration::from_millis(600));
+    let pending = add(&mut manager, "Pending", Level::Trace);
+
+    manager.retain(|info| info.id != rejected && info.id != pending);
+    manager.for_each_mut(|mut notification| {
+        if notification.id() == dismissed {
+            notification.dismiss();
+        }
+    });
+    assert_eq!(
+        manager.get(rejected).unwrap().dismiss_reason,
+        Some(DismissReason::Retained)
+    );
+    assert_eq!(
+        manager.get(dismissed).unwrap().dismiss_reason,
+        Some(DismissReason::Retained)
+    );
+
+    manager.tick(Duration::from_secs(1));
+    let reason = |id: u64| {
+        manager
+            .history()
+            .find(|record| record.id == id)
+            .and_then(|record| record.dismiss_reason)
+    };
+    assert_eq!(reason(rejected), Some(DismissReason::Retained));
+    assert_eq!(reason(pending), Some(DismissReason::Retained));
+    assert_eq!(reason(dismissed), Some(DismissReason::Retained));
+    let mut heard = dismissals.0.lock().unwrap().clone();
+    heard.sort_unstable_by_key(|&(id, _)| id);
+    assert_eq!(
+        heard,
+        vec![
+            (rejected, DismissReason::Retained),
+            (dismissed, DismissReason::Retained),
+            (pending, DismissReason::Retained),
+        ]
+    );
+}
+
+// FILE: tests/test_retain_integration.rs - Integration tests for retain and for_each_mut
+// END OF VERSION: 1.2.0
@@ -0,0 +1,96 @@
+// FILE: tests/test_rolldown_integration.rs - Integration tests for the roll-down animation
+// VERSION: 1.0.0
+// WCTX: Roll-down animation
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::functions::fnc_rolldown_visible_lines::rolldown_visible_lines;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, AnimationPhase, NotificationBuilder, Notifications, SizeConstraint,
+};
+use std::time::Duration;
+
+#[test]
+fn test_visible_lines_during_entry() {
+    assert_eq!(rolldown_visible_lines(4, AnimationPhase::SlidingIn, 0.0), 0);
+    assert_eq!(rolldown_visible_lines(4, AnimationPhase::SlidingIn, 0.1), 1);
+    assert_eq!(rolldown_visible_lines(4, AnimationPhase::SlidingIn, 0.5), 2);
+    assert_eq!(rolldown_visible_lines(4, AnimationPhase::SlidingIn, 0.51), 3);
+    assert_eq!(rolldown_visible_lines(4, AnimationPhase::SlidingIn, 1.0), 4);
+}
+
+#[test]
+fn test_visible_lines_during_exit_roll_back_up() {
+    assert_eq!(rolldown_visible_lines(4, AnimationPhase::SlidingOut, 0.0), 4);
+    assert_eq!(rolldown_visible_lines(4, AnimationPhase::SlidingOut, 0.5), 2);
+    assert_eq!(rolldown_visible_lines(4, AnimationPhase::SlidingOut, 1.0), 0);
+}
+
+#[test]
+fn test_visible_lines_other_phases_and_bounds() {
+    assert_eq!(rolldown_visible_lines(4, AnimationPhase::Dwelling, 0.3), 4);
+    assert_eq!(rolldown_visible_lines(4, AnimationPhase::Pending, 0.0), 4);
+    assert_eq!(rolldown_visible_lines(4, AnimationPhase::SlidingIn, 7.0), 4);
+    assert_eq!(rolldown_visible_lines(0, AnimationPhase::SlidingIn, 0.5), 0);
+}
+
+/// Renders a 4-line roll-down notification after `elapsed` of its 500ms entry
+fn rendered_lines(elapsed: Duration) -> Vec<String> {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("line 1\nline 2\nline 3\nline 4")
+        .anchor(Anchor::TopLeft)
+        .animation(Animation::RollDown)
+        .max_size(SizeConstraint::Absolute(30), SizeConstraint::Absolute(10))
+        .build()
+        .unwrap();
+    manager.add(notif).unwrap();
+    manager.tick(elapsed);
+
+    let mut terminal = Terminal::new(TestBackend::new(40, 12)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let buffer = terminal.backend().buffer();
+
+    (0..12)
+        .map(|y| (0..40).map(|x| buffer[(x, y)].symbol().to_string()).collect::<String>())
+        .filter(|row| row.contains("line "))
+        .collect()
+}
+
+#[test]
+fn test_half_progress_shows_two_of_four_lines() {
+    let lines = rendered_lines(Duration::from_millis(250));
+
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].contains("line 1"));
+    assert!(lines[1].contains("line 2"));
+}
+
+#[test]
+fn test_full_progress_shows_all_lines() {
+    assert_eq!(rendered_lines(Duration::from_millis(500)).len(), 4);
+}
+
+#[test]
+fn test_box_is_full_size_during_entry() {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("line 1\nline 2\nline 3\nline 4")
+        .anchor(Anchor::TopLeft)
+        .animation(Animation::RollDown)
+        .max_size(SizeConstraint::Absolute(30), SizeConstraint::Absolute(10))
+        .build()
+        .unwrap();
+    manager.add(notif).unwrap();
+    manager.tick(Duration::from_millis(50));
+
+    let mut terminal = Terminal::new(TestBackend::new(40, 12)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let buffer = terminal.backend().buffer();
+
+    // Top-left corner at the anchor and bottom border below all four rows
+    assert_eq!(buffer[(0, 0)].symbol(), "╭");
+    assert_eq!(buffer[(0, 5)].symbol(), "╰");
+}
+
+// FILE: tests/test_rolldown_integration.rs - Integration tests for the roll-down animation
+// END OF VERSION: 1.0.0
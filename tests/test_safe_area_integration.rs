@@ -0,0 +1,98 @@
+// FILE: tests/test_safe_area_integration.rs - Integration tests for the safe inner margin
+// VERSION: 1.0.0
+// WCTX: Safe area inset
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Margin, Rect};
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, AutoDismiss, NotificationBuilder, Notifications,
+};
+use std::time::Duration;
+
+const FRAME: Rect = Rect::new(0, 0, 80, 24);
+
+fn add(manager: &mut Notifications, anchor: Anchor, animation: Animation) -> u64 {
+    let notification = NotificationBuilder::new("Inside the safe area")
+        .title("Inset")
+        .anchor(anchor)
+        .animation(animation)
+        .auto_dismiss(AutoDismiss::Never);
+    manager.add(notification.build().unwrap()).unwrap()
+}
+
+fn draw(manager: &mut Notifications) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(FRAME.width, FRAME.height)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+/// Asserts that nothing was drawn outside `inner`
+fn assert_blank_outside(buffer: &Buffer, inner: Rect) {
+    for y in FRAME.top()..FRAME.bottom() {
+        for x in FRAME.left()..FRAME.right() {
+            if !inner.contains((x, y).into()) {
+                assert_eq!(buffer[(x, y)].symbol(), " ", "cell ({x}, {y}) is in the margin");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_top_left_starts_at_inset() {
+    let mut manager = Notifications::new().safe_area(Margin::new(2, 2));
+    let id = add(&mut manager, Anchor::TopLeft, Animation::Fade);
+    manager.tick(Duration::from_secs(2));
+    let buffer = draw(&mut manager);
+
+    let rect = manager.get(id).unwrap().rect.unwrap();
+    assert_eq!((rect.x, rect.y), (2, 2));
+    assert_eq!(buffer[(2, 2)].symbol(), "╭");
+}
+
+#[test]
+fn test_bottom_right_keeps_clear_of_edges() {
+    let mut manager = Notifications::new().safe_area(Margin::new(2, 2));
+    let id = add(&mut manager, Anchor::BottomRight, Animation::Fade);
+    manager.tick(Duration::from_secs(2));
+    let buffer = draw(&mut manager);
+
+    let rect = manager.get(id).unwrap().rect.unwrap();
+    assert_eq!((rect.right(), rect.bottom()), (FRAME.right() - 2, FRAME.bottom() - 2));
+    assert_eq!(buffer[(FRAME.right() - 3, FRAME.bottom() - 3)].symbol(), "╯");
+    assert_blank_outside(&buffer, FRAME.inner(Margin::new(2, 2)));
+}
+
+#[test]
+fn test_slides_stay_inside_safe_area() {
+    let inner = FRAME.inner(Margin::new(3, 2));
+    for anchor in [Anchor::TopLeft, Anchor::TopRight, Anchor::BottomLeft, Anchor::BottomRight] {
+        let mut manager = Notifications::new().safe_area(Margin::new(3, 2));
+        add(&mut manager, anchor, Animation::Slide);
+        manager.tick(Duration::from_millis(150));
+        assert_blank_outside(&draw(&mut manager), inner);
+    }
+}
+
+#[test]
+fn test_default_has_no_inset() {
+    let mut manager = Notifications::new();
+    let id = add(&mut manager, Anchor::TopLeft, Animation::Fade);
+    manager.tick(Duration::from_secs(2));
+    draw(&mut manager);
+    let rect = manager.get(id).unwrap().rect.unwrap();
+    assert_eq!((rect.x, rect.y), (0, 0));
+}
+
+#[test]
+fn test_inset_larger_than_frame_draws_nothing() {
+    let mut manager = Notifications::new().safe_area(Margin::new(40, 12));
+    add(&mut manager, Anchor::MiddleCenter, Animation::Fade);
+    manager.tick(Duration::from_secs(2));
+    assert_eq!(draw(&mut manager), Buffer::empty(FRAME));
+}
+
+// FILE: tests/test_safe_area_integration.rs - Integration tests for the safe inner margin
+// END OF VERSION: 1.0.0
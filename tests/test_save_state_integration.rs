@@ -0,0 +1,156 @@
+// FILE: tests/test_save_state_integration.rs - Integration tests for saving and restoring manager state
+// VERSION: 1.1.0
+// WCTX: Suppressed notifications
+// CLOG: Test restore_state while suppressed
+
+use ratatui::style::{Color, Style, Stylize};
+use ratatui::text::Line;
+use ratatui_notifications::notifications::{
+    Anchor, AnimationPhase, AutoDismiss, Level, NotificationBuilder, Notifications,
+    SavedNotifications,
+};
+use std::time::Duration;
+
+fn round_trip(saved: &SavedNotifications) -> SavedNotifications {
+    let json = serde_json::to_string(saved).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+/// The entry frame may carry a few ms into the dwell
+fn assert_about_half_left(remaining: Option<Duration>) {
+    let remaining = remaining.expect("timed notification should count down");
+    assert!(
+        remaining > Duration::from_millis(4900) && remaining <= Duration::from_secs(5),
+        "expected about 5s left, got {remaining:?}"
+    );
+}
+
+/// A manager with a never-dismissing and a half-elapsed 10s notification
+fn session() -> (Notifications, u64, u64) {
+    let mut manager = Notifications::new();
+    let sticky = NotificationBuilder::new(Line::from(vec!["Unsaved ".into(), "changes".bold()]))
+        .title("Editor")
+        .level(Level::Warn)
+        .anchor(Anchor::TopRight)
+        .style(Style::new().bg(Color::Rgb(40, 40, 40)))
+        .auto_dismiss(AutoDismiss::Never)
+        .build()
+        .unwrap();
+    let timed = NotificationBuilder::new("Sync in progress")
+        .auto_dismiss(AutoDismiss::After(Duration::from_secs(10)))
+        .build()
+        .unwrap();
+    let sticky = manager.add(sticky).unwrap();
+    let timed = manager.add(timed).unwrap();
+
+    // Finish the entry animation, then run off half of the display time
+    while manager.phase(timed) != Some(AnimationPhase::Dwelling) {
+        manager.tick(Duration::from_millis(16));
+    }
+    manager.tick(Duration::from_secs(5));
+    (manager, sticky, timed)
+}
+
+#[test]
+fn test_save_state_records_remaining_time() {
+    let (manager, _, _) = session();
+    let saved = manager.save_state();
+
+    assert_eq!(saved.notifications.len(), 2);
+    assert_eq!(saved.notifications[0].remaining_display_time, None);
+    assert_about_half_left(saved.notifications[1].remaining_display_time);
+}
+
+#[test]
+fn test_json_round_trip_preserves_configuration() {
+    let (manager, _, _) = session();
+    let saved = manager.save_state();
+    let restored = round_trip(&saved);
+
+    assert_eq!(restored.notifications.len(), 2);
+    let (before, after) = (&saved.notifications[0], &restored.notifications[0]);
+    assert_eq!(after.notification.content(), before.notification.content());
+    assert_eq!(after.notification.title(), before.notification.title());
+    assert_eq!(after.notification.level(), Some(Level::Warn));
+    assert_eq!(after.notification.anchor(), Anchor::TopRight);
+    assert_eq!(after.notification.auto_dismiss(), AutoDismiss::Never);
+    // Styles and every other field survive as well
+    assert_eq!(format!("{:?}", after.notification), format!("{:?}", before.notification));
+    assert_about_half_left(restored.notifications[1].remaining_display_time);
+}
+
+#[test]
+fn test_restore_replays_entry_and_continues_timers() {
+    let (manager, _, _) = session();
+    let saved = round_trip(&manager.save_state());
+
+    let mut restored = Notifications::new();
+    let ids = restored.restore_state(saved);
+    assert_eq!(ids.len(), 2);
+    let (sticky, timed) = (ids[0], ids[1]);
+    assert_eq!(restored.phase(timed), Some(AnimationPhase::Pending));
+
+    // Fresh entry animation, then the 5s that were left
+    restored.tick(Duration::from_millis(16));
+    assert_eq!(restored.phase(timed), Some(AnimationPhase::SlidingIn));
+    while restored.phase(timed) != Some(AnimationPhase::Dwelling) {
+        restored.tick(Duration::from_millis(16));
+    }
+    restored.tick(Duration::from_millis(4900));
+    assert_eq!(restored.phase(timed), Some(AnimationPhase::Dwelling));
+    restored.tick(Duration::from_millis(200));
+    assert_eq!(restored.phase(timed), Some(AnimationPhase::SlidingOut));
+
+    // The never-dismissing notification stays
+    restored.tick(Duration::from_secs(60));
+    assert_eq!(restored.phase(sticky), Some(AnimationPhase::Dwelling));
+}
+
+#[test]
+fn test_exiting_notifications_are_not_saved() {
+    let (mut manager, _, timed) = session();
+    manager.tick(Duration::from_millis(5100));
+    assert_eq!(manager.phase(timed), Some(AnimationPhase::SlidingOut));
+
+    let saved = manager.save_state();
+    assert_eq!(saved.notifications.len(), 1);
+    assert_eq!(saved.notifications[0].notification.auto_dismiss(), AutoDismiss::Never);
+}
+
+#[test]
+fn test_hidden_notifications_restore_hidden() {
+    let mut manager = Notifications::new();
+    manager
+        .add_hidden(NotificationBuilder::new("Later").build().unwrap())
+        .unwrap();
+
+    let mut restored = Notifications::new();
+    let ids = restored.restore_state(round_trip(&manager.save_state()));
+    restored.tick(Duration::from_secs(1));
+    assert_eq!(restored.phase(ids[0]), Some(AnimationPhase::Pending));
+    assert!(restored.show(ids[0]));
+}
+
+#[test]
+fn test_restore_while_suppressed_keeps_remaining_time() {
+    let (manager, _, _) = session();
+    let saved = manager.save_state();
+
+    let mut restored = Notifications::new();
+    restored.suppress(true);
+    let ids = restored.restore_state(saved);
+    assert_eq!(restored.parked_count(), 2);
+    restored.suppress(false);
+    let timed = ids[1];
+
+    // Released with the 5s that were left, not a fresh 10s
+    while restored.phase(timed) != Some(AnimationPhase::Dwelling) {
+        restored.tick(Duration::from_millis(16));
+    }
+    assert_about_half_left(restored.get(timed).unwrap().remaining);
+    restored.tick(Duration::from_millis(5100));
+    assert_eq!(restored.phase(timed), Some(AnimationPhase::SlidingOut));
+}
+
+// FILE: tests/test_save_state_integration.rs - Integration tests for saving and restoring manager state
+// END OF VERSION: 1.1.0
@@ -0,0 +1,205 @@
+// FILE: tests/test_scenario_integration.rs - Integration tests for the TOML scenario player
+// VERSION: 1.0.0
+// WCTX: TOML scenario player
+// CLOG: Initial creation
+//
+// Run with: cargo test --features scenario --test test_scenario_integration
+
+#![cfg(feature = "scenario")]
+
+use ratatui_notifications::notifications::{
+    FrameSnapshot, Level, Notifications, Overflow, Scenario, ScenarioError, ScenarioPlayer,
+};
+use std::time::Duration;
+
+const DEPLOY: &str = include_str!("../examples/scenarios/deploy.toml");
+const OVERFLOW: &str = include_str!("../examples/scenarios/overflow.toml");
+
+// Golden frames, rendered at 50x12
+
+const DEPLOY_AT_1_2S: [&str; 12] = [
+    "                                ╭────── ℹCI──────╮",
+    "                                │ Deploy started │",
+    "                                ╰────────────────╯",
+    "                              ╭──── ℹDatabase────╮",
+    "                              │ Running          │",
+    "                              ╰──────────────────╯",
+    "                                                  ",
+    "                                                  ",
+    "                                                  ",
+    "                                                  ",
+    "                                                  ",
+    "                                                  ",
+];
+
+const DEPLOY_AT_3S: [&str; 12] = [
+    "                              │     ℹDatabase    ─",
+    "                              │ Running           ",
+    "                              ╰───────────────────",
+    "                              ╭──── ⚠Database────╮",
+    "                              │ Migration 0042   │",
+    "                              ╰──────────────────╯",
+    "                                                  ",
+    "                                                  ",
+    "                                                  ",
+    "                ╭────── ℹCI───────╮               ",
+    "                │ Deploy finished │               ",
+    "                ╰─────────────────╯               ",
+];
+
+const OVERFLOW_AT_1S: [&str; 12] = [
+    "                                                  ",
+    "                                                  ",
+    "                                                  ",
+    "                                                  ",
+    "                                                  ",
+    "                                                  ",
+    "╭────────────────╮                                ",
+    "│ Retry 1 failed │                                ",
+    "╰────────────────╯                                ",
+    "╭────────────────╮                                ",
+    "│ Retry 2 failed │                                ",
+    "╰────────────────╯                                ",
+];
+
+const OVERFLOW_AT_2S: [&str; 12] = [
+    "                                                  ",
+    "                                                  ",
+    "                                                  ",
+    "                                                  ",
+    "                                                  ",
+    "                                                  ",
+    "╭────────────────╮                                ",
+    "│ Retry 2 failed │                                ",
+    "╰────────────────╯                                ",
+    "╭─ ✖Network─╮                                     ",
+    "│ Giving up │                                     ",
+    "╰───────────╯                                     ",
+];
+
+fn frame_at(frames: &[FrameSnapshot], at: Duration) -> Vec<String> {
+    frames.iter().find(|frame| frame.at == at).expect("no frame at that time").lines()
+}
+
+#[test]
+fn test_deploy_golden_frames() {
+    let frames = Scenario::load(DEPLOY).unwrap().run_headless(50, 12);
+    assert_eq!(frames.len(), 41);
+    assert_eq!(frame_at(&frames, Duration::from_millis(1200)), DEPLOY_AT_1_2S);
+    assert_eq!(frame_at(&frames, Duration::from_secs(3)), DEPLOY_AT_3S);
+}
+
+#[test]
+fn test_overflow_golden_frames() {
+    let frames = Scenario::load(OVERFLOW).unwrap().run_headless(50, 12);
+    assert_eq!(frames.len(), 31);
+    assert_eq!(frame_at(&frames, Duration::from_secs(1)), OVERFLOW_AT_1S);
+    assert_eq!(frame_at(&frames, Duration::from_secs(2)), OVERFLOW_AT_2S);
+}
+
+#[test]
+fn test_headless_run_is_deterministic() {
+    let scenario = Scenario::load(DEPLOY).unwrap();
+    assert_eq!(scenario.run_headless(50, 12), scenario.run_headless(50, 12));
+}
+
+#[test]
+fn test_first_frame_is_empty() {
+    let frames = Scenario::load(DEPLOY).unwrap().run_headless(50, 12);
+    assert_eq!(frames[0].at, Duration::ZERO);
+    assert!(frames[0].lines().iter().all(|line| line.trim().is_empty()));
+}
+
+#[test]
+fn test_load_reads_settings_and_sorts_events() {
+    let scenario = Scenario::load(
+        r#"
+        duration = "2s"
+        frame_interval = "250ms"
+
+        [manager]
+        max_total = 3
+        overflow = "DiscardNewest"
+
+        [[events]]
+        at = "1s"
+        [events.notification]
+        content = "Second"
+
+        [[events]]
+        at = "500ms"
+        [events.notification]
+        content = "First"
+        level = "Error"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(scenario.duration, Duration::from_secs(2));
+    assert_eq!(scenario.frame_interval, Duration::from_millis(250));
+    assert_eq!(scenario.manager.max_total, Some(3));
+    assert_eq!(scenario.manager.overflow, Some(Overflow::DiscardNewest));
+    let contents: Vec<&str> = scenario.events.iter().map(|event| event.config.content.as_str()).collect();
+    assert_eq!(contents, ["First", "Second"]);
+    assert_eq!(scenario.events[0].notification.level(), Some(Level::Error));
+}
+
+#[test]
+fn test_load_defaults() {
+    let scenario = Scenario::load("").unwrap();
+    assert_eq!(scenario.duration, Duration::from_secs(5));
+    assert_eq!(scenario.frame_interval, Duration::from_millis(100));
+    assert!(scenario.events.is_empty());
+}
+
+#[test]
+fn test_load_rejects_bad_input() {
+    assert!(matches!(Scenario::load("durration = \"1s\""), Err(ScenarioError::Parse(_))));
+    assert!(matches!(
+        Scenario::load("duration = \"soon\""),
+        Err(ScenarioError::InvalidDuration(_))
+    ));
+    assert!(matches!(
+        Scenario::load("frame_interval = \"0ms\""),
+        Err(ScenarioError::InvalidDuration(_))
+    ));
+    let bad_dismiss = r#"
+        [[events]]
+        at = "0s"
+        [events.notification]
+        content = "x"
+        auto_dismiss = "later"
+    "#;
+    assert!(matches!(Scenario::load(bad_dismiss), Err(ScenarioError::InvalidDuration(_))));
+    let unknown_field = r#"
+        [[events]]
+        at = "0s"
+        [events.notification]
+        content = "x"
+        colour = "red"
+    "#;
+    assert!(matches!(Scenario::load(unknown_field), Err(ScenarioError::Parse(_))));
+}
+
+#[test]
+fn test_player_adds_events_as_they_fall_due() {
+    let scenario = Scenario::load(OVERFLOW).unwrap();
+    let mut manager = scenario.configure(Notifications::new());
+    let mut player = ScenarioPlayer::new(&scenario);
+
+    assert_eq!(player.add_due(&mut manager).unwrap().len(), 1);
+    player.advance(Duration::from_millis(300));
+    assert!(player.add_due(&mut manager).unwrap().is_empty());
+    player.advance(Duration::from_millis(600));
+    assert_eq!(player.add_due(&mut manager).unwrap().len(), 2);
+    assert!(!player.is_finished());
+
+    player.advance(Duration::from_secs(5));
+    assert_eq!(player.add_due(&mut manager).unwrap().len(), 1);
+    assert!(player.is_finished());
+    // max_concurrent = 2 at one anchor
+    assert_eq!(manager.infos().len(), 2);
+}
+
+// FILE: tests/test_scenario_integration.rs - Integration tests for the TOML scenario player
+// END OF VERSION: 1.0.0
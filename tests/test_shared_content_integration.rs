@@ -0,0 +1,88 @@
+// FILE: tests/test_shared_content_integration.rs - Integration tests for content shared between notifications
+// VERSION: 1.0.0
+// WCTX: Shared notification content
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::text::Text;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{Anchor, NotificationBuilder, Notifications, Overflow};
+use std::sync::Arc;
+use std::time::Duration;
+
+fn heartbeat() -> Arc<Text<'static>> {
+    Arc::new(Text::from("heartbeat OK"))
+}
+
+fn render(manager: &mut Notifications) -> Vec<String> {
+    let mut terminal = Terminal::new(TestBackend::new(40, 6)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..6).map(|y| (0..40).map(|x| buffer[(x, y)].symbol()).collect()).collect()
+}
+
+#[test]
+fn test_many_notifications_share_one_allocation() {
+    let shared = heartbeat();
+    let mut manager = Notifications::new();
+    for _ in 0..1000 {
+        let notification = NotificationBuilder::content_arc(Arc::clone(&shared)).build().unwrap();
+        manager.add(notification).unwrap();
+    }
+    assert_eq!(Arc::strong_count(&shared), 1001);
+
+    // Ticking and rendering borrow the content rather than copying it
+    manager.tick(Duration::from_millis(500));
+    render(&mut manager);
+    assert_eq!(Arc::strong_count(&shared), 1001);
+
+    let drained = manager.drain();
+    assert_eq!(drained.len(), 1000);
+    assert!(drained.iter().all(|notification| notification.content() == &*shared));
+    assert_eq!(Arc::strong_count(&shared), 1001);
+    drop(drained);
+    assert_eq!(Arc::strong_count(&shared), 1);
+}
+
+#[test]
+fn test_shared_content_renders() {
+    let mut manager = Notifications::new();
+    let notification =
+        NotificationBuilder::content_arc(heartbeat()).anchor(Anchor::TopLeft).build().unwrap();
+    manager.add(notification).unwrap();
+    manager.tick(Duration::from_secs(1));
+
+    assert!(render(&mut manager).iter().any(|row| row.contains("heartbeat OK")));
+}
+
+#[test]
+fn test_shared_content_merges_as_duplicate() {
+    let shared = heartbeat();
+    let mut manager = Notifications::new().overflow(Overflow::MergeDuplicates);
+    let mut add = |builder: NotificationBuilder| manager.add(builder.build().unwrap()).unwrap();
+    let first = add(NotificationBuilder::content_arc(Arc::clone(&shared)));
+    let second = add(NotificationBuilder::content_arc(Arc::clone(&shared)));
+    let copied = add(NotificationBuilder::new("heartbeat OK"));
+
+    assert_eq!(first, second);
+    assert_eq!(first, copied);
+}
+
+#[test]
+fn test_updating_one_leaves_the_others_shared() {
+    let shared = heartbeat();
+    let mut manager = Notifications::new();
+    let ids: Vec<u64> = (0..3)
+        .map(|_| {
+            let notification = NotificationBuilder::content_arc(Arc::clone(&shared));
+            manager.add(notification.build().unwrap()).unwrap()
+        })
+        .collect();
+
+    assert!(manager.update_content(ids[0], "heartbeat LATE"));
+    assert_eq!(Arc::strong_count(&shared), 3);
+    assert_eq!(shared.to_string(), "heartbeat OK");
+}
+
+// FILE: tests/test_shared_content_integration.rs - Integration tests for content shared between notifications
+// END OF VERSION: 1.0.0
@@ -0,0 +1,118 @@
+// FILE: tests/test_speed_integration.rs - Integration tests for animation speed multipliers
+// VERSION: 1.1.0
+// WCTX: Exact dwell timing
+// CLOG: Display time starts when the entry ends
+
+use ratatui_notifications::notifications::{
+    generate_code, AnimationPhase, AutoDismiss, NotificationBuilder, NotificationError,
+    Notifications, Timing,
+};
+use std::time::Duration;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+/// 400ms entry, 1s display, 400ms exit
+fn timed() -> NotificationBuilder {
+    NotificationBuilder::new("Saved")
+        .timing(Timing::Fixed(ms(400)), Timing::Auto, Timing::Fixed(ms(400)))
+        .auto_dismiss(AutoDismiss::After(ms(1000)))
+}
+
+/// Ticks `delta` and returns the phase (`None` once removed)
+fn step(manager: &mut Notifications, id: u64, delta: Duration) -> Option<AnimationPhase> {
+    manager.tick(delta);
+    manager.phase(id)
+}
+
+#[test]
+fn test_double_speed_halves_motion_but_not_display_time() {
+    let mut manager = Notifications::new();
+    let id = manager.add(timed().speed(2.0).build().unwrap()).unwrap();
+
+    assert_eq!(step(&mut manager, id, ms(100)), Some(AnimationPhase::SlidingIn));
+    // Display time starts once the entry ends
+    assert_eq!(step(&mut manager, id, ms(100)), Some(AnimationPhase::Dwelling));
+    assert_eq!(step(&mut manager, id, ms(999)), Some(AnimationPhase::Dwelling));
+    assert_eq!(step(&mut manager, id, ms(1)), Some(AnimationPhase::SlidingOut));
+    assert_eq!(step(&mut manager, id, ms(100)), Some(AnimationPhase::SlidingOut));
+    assert_eq!(step(&mut manager, id, ms(100)), None);
+}
+
+#[test]
+fn test_speed_affects_dwell_scales_display_time() {
+    let mut manager = Notifications::new();
+    let notif = timed().speed(2.0).speed_affects_dwell(true).build().unwrap();
+    let id = manager.add(notif).unwrap();
+
+    // 500ms of ticks count as 1000ms of display time
+    assert_eq!(step(&mut manager, id, ms(200)), Some(AnimationPhase::Dwelling));
+    assert_eq!(step(&mut manager, id, ms(499)), Some(AnimationPhase::Dwelling));
+    assert_eq!(step(&mut manager, id, ms(1)), Some(AnimationPhase::SlidingOut));
+    assert_eq!(step(&mut manager, id, ms(200)), None);
+}
+
+#[test]
+fn test_half_speed_doubles_motion() {
+    let mut manager = Notifications::new();
+    let id = manager.add(timed().speed(0.5).build().unwrap()).unwrap();
+
+    assert_eq!(step(&mut manager, id, ms(400)), Some(AnimationPhase::SlidingIn));
+    assert_eq!(step(&mut manager, id, ms(400)), Some(AnimationPhase::Dwelling));
+    assert_eq!(step(&mut manager, id, ms(1000)), Some(AnimationPhase::SlidingOut));
+    assert_eq!(step(&mut manager, id, ms(400)), Some(AnimationPhase::SlidingOut));
+    assert_eq!(step(&mut manager, id, ms(400)), None);
+}
+
+#[test]
+fn test_global_speed_multiplies_with_notification_speed() {
+    let mut manager = Notifications::new();
+    manager.global_speed(0.5).unwrap();
+    let id = manager.add(timed().speed(2.0).build().unwrap()).unwrap();
+
+    // 2.0 * 0.5 = normal speed
+    assert_eq!(step(&mut manager, id, ms(200)), Some(AnimationPhase::SlidingIn));
+    assert_eq!(step(&mut manager, id, ms(200)), Some(AnimationPhase::Dwelling));
+}
+
+#[test]
+fn test_global_speed_applies_to_shown_notifications() {
+    let mut manager = Notifications::new();
+    let id = manager.add(timed().build().unwrap()).unwrap();
+    assert_eq!(step(&mut manager, id, ms(200)), Some(AnimationPhase::SlidingIn));
+
+    manager.global_speed(4.0).unwrap();
+    assert_eq!(step(&mut manager, id, ms(50)), Some(AnimationPhase::Dwelling));
+}
+
+#[test]
+fn test_non_positive_speeds_are_rejected() {
+    for speed in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+        assert!(
+            matches!(timed().speed(speed).build(), Err(NotificationError::InvalidConfig(_))),
+            "{speed}"
+        );
+
+        let mut manager = Notifications::new();
+        assert!(matches!(manager.global_speed(speed), Err(NotificationError::InvalidConfig(_))));
+        // The rejected value is not applied
+        let id = manager.add(timed().build().unwrap()).unwrap();
+        assert_eq!(step(&mut manager, id, ms(400)), Some(AnimationPhase::Dwelling));
+    }
+}
+
+#[test]
+fn test_generate_code_keeps_configured_timings() {
+    let plain = generate_code(&timed().build().unwrap());
+    let fast = generate_code(&timed().speed(2.0).build().unwrap());
+
+    assert!(fast.contains(".speed(2.0)"), "{fast}");
+    assert!(!plain.contains(".speed("));
+    let without_speed: String =
+        fast.lines().filter(|line| !line.contains(".speed(")).collect::<Vec<_>>().join("\n");
+    assert_eq!(without_speed, plain);
+}
+
+// FILE: tests/test_speed_integration.rs - Integration tests for animation speed multipliers
+// END OF VERSION: 1.1.0
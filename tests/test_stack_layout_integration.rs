@@ -0,0 +1,60 @@
+// FILE: tests/test_stack_layout_integration.rs - Integration tests for side-by-side stacking
+// VERSION: 1.0.0
+// WCTX: Horizontal stacking
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Anchor, AutoDismiss, NotificationBuilder, Notifications, StackLayout,
+};
+use std::time::Duration;
+
+/// Adds three settled notifications at `anchor` and returns their content rects
+fn layout(manager: Notifications, anchor: Anchor) -> Vec<(u16, u16)> {
+    let mut manager = manager;
+    let ids: Vec<u64> = ["one", "two", "three"]
+        .into_iter()
+        .map(|text| {
+            let notification =
+                NotificationBuilder::new(text).anchor(anchor).auto_dismiss(AutoDismiss::Never);
+            manager.add(notification.build().unwrap()).unwrap()
+        })
+        .collect();
+    manager.tick(Duration::from_secs(1));
+    let mut terminal = Terminal::new(TestBackend::new(100, 20)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    ids.iter()
+        .map(|&id| manager.content_rect(id).unwrap())
+        .map(|rect| (rect.x, rect.y))
+        .collect()
+}
+
+#[test]
+fn test_right_to_left_lays_out_in_a_row() {
+    let manager = Notifications::new().stack_layout(StackLayout::RightToLeft);
+    let positions = layout(manager, Anchor::BottomRight);
+
+    assert!(positions.iter().all(|&(_, y)| y == positions[0].1), "{positions:?}");
+    assert!(positions[0].0 > positions[1].0 && positions[1].0 > positions[2].0, "{positions:?}");
+}
+
+#[test]
+fn test_left_to_right_lays_out_in_a_row() {
+    let manager = Notifications::new().stack_layout(StackLayout::LeftToRight);
+    let positions = layout(manager, Anchor::TopLeft);
+
+    assert!(positions.iter().all(|&(_, y)| y == positions[0].1), "{positions:?}");
+    assert!(positions[0].0 < positions[1].0 && positions[1].0 < positions[2].0, "{positions:?}");
+}
+
+#[test]
+fn test_vertical_is_the_default() {
+    let positions = layout(Notifications::new(), Anchor::TopLeft);
+
+    assert!(positions.iter().all(|&(x, _)| x == positions[0].0), "{positions:?}");
+    assert!(positions[0].1 < positions[1].1 && positions[1].1 < positions[2].1, "{positions:?}");
+}
+
+// FILE: tests/test_stack_layout_integration.rs - Integration tests for side-by-side stacking
+// END OF VERSION: 1.0.0
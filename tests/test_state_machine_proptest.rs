@@ -0,0 +1,304 @@
+// FILE: tests/test_state_machine_proptest.rs - Property tests for the notification state machine
+// VERSION: 1.0.0
+// WCTX: State machine property tests
+// CLOG: Initial creation
+
+//! Random operation sequences checked against lifecycle invariants.
+//!
+//! Failures shrink to a minimal sequence; rerun with
+//! `PROPTEST_CASES=10000 cargo test --test test_state_machine_proptest` for a
+//! longer soak.
+
+use proptest::prelude::*;
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::orc_stacking::calculate_stacking_positions;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, AnimationPhase, AutoDismiss, ManagerDefaults, Notification,
+    NotificationBuilder, NotificationState, Notifications, Overflow, Timing,
+};
+use std::collections::HashMap;
+use std::time::Duration;
+
+const ANCHORS: [Anchor; 9] = [
+    Anchor::TopLeft,
+    Anchor::TopCenter,
+    Anchor::TopRight,
+    Anchor::MiddleLeft,
+    Anchor::MiddleCenter,
+    Anchor::MiddleRight,
+    Anchor::BottomLeft,
+    Anchor::BottomCenter,
+    Anchor::BottomRight,
+];
+
+const ANIMATIONS: [Animation; 4] =
+    [Animation::Slide, Animation::ExpandCollapse, Animation::Fade, Animation::RollDown];
+
+/// Random notification configuration
+#[derive(Debug, Clone)]
+struct Config {
+    content: String,
+    anchor: Anchor,
+    animation: Animation,
+    auto_dismiss: AutoDismiss,
+    entry: Timing,
+    exit: Timing,
+    ttl: Option<Duration>,
+}
+
+impl Config {
+    fn build(&self) -> Notification {
+        let mut builder = NotificationBuilder::new(self.content.clone())
+            .anchor(self.anchor)
+            .animation(self.animation)
+            .auto_dismiss(self.auto_dismiss)
+            .timing(self.entry, Timing::Auto, self.exit);
+        if let Some(ttl) = self.ttl {
+            builder = builder.ttl(ttl);
+        }
+        builder.build().unwrap()
+    }
+}
+
+fn timing() -> impl Strategy<Value = Timing> {
+    prop_oneof![
+        Just(Timing::Auto),
+        (0u64..2_000).prop_map(|ms| Timing::Fixed(Duration::from_millis(ms))),
+    ]
+}
+
+fn config() -> impl Strategy<Value = Config> {
+    (
+        "[a-z ]{0,40}",
+        0..ANCHORS.len(),
+        0..ANIMATIONS.len(),
+        prop_oneof![
+            Just(AutoDismiss::Never),
+            (0u64..5_000).prop_map(|ms| AutoDismiss::After(Duration::from_millis(ms))),
+        ],
+        timing(),
+        timing(),
+        proptest::option::of((0u64..10_000).prop_map(Duration::from_millis)),
+    )
+        .prop_map(|(content, anchor, animation, auto_dismiss, entry, exit, ttl)| Config {
+            content,
+            anchor: ANCHORS[anchor],
+            animation: ANIMATIONS[animation],
+            auto_dismiss,
+            entry,
+            exit,
+            ttl,
+        })
+}
+
+/// Tick deltas, weighted towards frame-sized steps with zero and stalls
+fn delta() -> impl Strategy<Value = Duration> {
+    prop_oneof![
+        4 => (0u64..50).prop_map(Duration::from_millis),
+        1 => Just(Duration::ZERO),
+        1 => (1_000u64..600_000).prop_map(Duration::from_millis),
+        1 => Just(Duration::from_secs(u32::MAX as u64)),
+    ]
+}
+
+#[derive(Debug, Clone)]
+enum Op {
+    Add(Config),
+    AddHidden(Config),
+    Show(usize),
+    Tick(Duration),
+    Remove(usize),
+    Dismiss(usize),
+    Freeze(usize),
+    Unfreeze(usize),
+    Move(usize, usize),
+    Clear,
+    Render(u16, u16),
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        4 => config().prop_map(Op::Add),
+        1 => config().prop_map(Op::AddHidden),
+        1 => any::<usize>().prop_map(Op::Show),
+        6 => delta().prop_map(Op::Tick),
+        1 => any::<usize>().prop_map(Op::Remove),
+        1 => any::<usize>().prop_map(Op::Dismiss),
+        1 => any::<usize>().prop_map(Op::Freeze),
+        1 => any::<usize>().prop_map(Op::Unfreeze),
+        1 => (any::<usize>(), 0..ANCHORS.len()).prop_map(|(i, a)| Op::Move(i, a)),
+        1 => Just(Op::Clear),
+        3 => (0u16..200, 0u16..80).prop_map(|(w, h)| Op::Render(w, h)),
+    ]
+}
+
+/// Position of a phase in the documented lifecycle:
+/// Pending -> entering -> Dwelling -> exiting -> Finished
+fn rank(phase: AnimationPhase) -> u8 {
+    match phase {
+        AnimationPhase::Pending => 0,
+        AnimationPhase::SlidingIn | AnimationPhase::Expanding | AnimationPhase::FadingIn => 1,
+        AnimationPhase::Dwelling => 2,
+        AnimationPhase::SlidingOut | AnimationPhase::Collapsing | AnimationPhase::FadingOut => 3,
+        AnimationPhase::Finished => 4,
+    }
+}
+
+/// Whether an entry or exit phase belongs to the animation
+fn matches_animation(phase: AnimationPhase, animation: Animation) -> bool {
+    match phase {
+        AnimationPhase::SlidingIn | AnimationPhase::SlidingOut => {
+            matches!(animation, Animation::Slide | Animation::RollDown)
+        }
+        AnimationPhase::Expanding | AnimationPhase::Collapsing => {
+            animation == Animation::ExpandCollapse
+        }
+        AnimationPhase::FadingIn | AnimationPhase::FadingOut => animation == Animation::Fade,
+        _ => true,
+    }
+}
+
+fn pick(ids: &[u64], index: usize) -> Option<u64> {
+    (!ids.is_empty()).then(|| ids[index % ids.len()])
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(128))]
+
+    #[test]
+    fn manager_operations_preserve_lifecycle_invariants(
+        ops in proptest::collection::vec(op(), 1..60),
+        max_concurrent in proptest::option::of(1usize..5),
+        merge in any::<bool>(),
+    ) {
+        let overflow = if merge { Overflow::MergeDuplicates } else { Overflow::DiscardOldest };
+        let mut manager = Notifications::new()
+            .max_concurrent(max_concurrent)
+            .overflow(overflow);
+        // id -> (animation, last seen phase)
+        let mut known: HashMap<u64, (Animation, AnimationPhase)> = HashMap::new();
+
+        for op in ops {
+            let ids: Vec<u64> = {
+                let mut ids: Vec<u64> = known.keys().copied().collect();
+                ids.sort_unstable();
+                ids
+            };
+            match op {
+                Op::Add(config) => {
+                    let id = manager.add(config.build()).unwrap();
+                    known.entry(id).or_insert((config.animation, AnimationPhase::Pending));
+                }
+                Op::AddHidden(config) => {
+                    let id = manager.add_hidden(config.build()).unwrap();
+                    known.insert(id, (config.animation, AnimationPhase::Pending));
+                }
+                Op::Show(i) => { pick(&ids, i).map(|id| manager.show(id)); }
+                Op::Tick(delta) => manager.tick(delta),
+                Op::Remove(i) => { pick(&ids, i).map(|id| manager.remove(id)); }
+                Op::Dismiss(i) => {
+                    if let Some(target) = pick(&ids, i) {
+                        manager.retain(|info| info.id != target);
+                    }
+                }
+                Op::Freeze(i) => { pick(&ids, i).map(|id| manager.freeze(id)); }
+                Op::Unfreeze(i) => { pick(&ids, i).map(|id| manager.unfreeze(id)); }
+                Op::Move(i, anchor) => {
+                    pick(&ids, i).map(|id| manager.move_to_anchor(id, ANCHORS[anchor]));
+                }
+                Op::Clear => manager.clear(),
+                Op::Render(width, height) => {
+                    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+                    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+                }
+            }
+
+            // Phases only move forward and match the animation; finished
+            // notifications are reaped by the tick that finishes them
+            known.retain(|&id, (animation, last)| {
+                let Some(phase) = manager.phase(id) else {
+                    return false;
+                };
+                assert_ne!(phase, AnimationPhase::Finished, "finished state {id} not reaped");
+                assert!(
+                    rank(phase) >= rank(*last),
+                    "state {id} went back from {last:?} to {phase:?}"
+                );
+                assert!(matches_animation(phase, *animation), "{phase:?} for {animation:?}");
+                *last = phase;
+                true
+            });
+        }
+    }
+
+    #[test]
+    fn state_progress_stays_in_range_and_finishes(
+        config in config(),
+        deltas in proptest::collection::vec(delta(), 1..200),
+    ) {
+        let mut state = NotificationState::new(0, config.build(), &ManagerDefaults::default());
+        let mut last = state.phase();
+
+        for delta in deltas {
+            let update = state.update(delta);
+            let phase = state.phase();
+            let progress = state.progress();
+            prop_assert!((0.0..=1.0).contains(&progress), "progress {progress} in {phase:?}");
+            prop_assert!(rank(phase) >= rank(last), "went back from {last:?} to {phase:?}");
+            prop_assert!(matches_animation(phase, config.animation));
+            prop_assert_eq!(update.finished, phase == AnimationPhase::Finished);
+            last = phase;
+        }
+
+        // A timed notification always finishes given enough time
+        if matches!(config.auto_dismiss, AutoDismiss::After(_)) {
+            for _ in 0..4 {
+                state.update(Duration::from_secs(3_600));
+            }
+            prop_assert_eq!(state.phase(), AnimationPhase::Finished);
+        }
+    }
+
+    #[test]
+    fn stacked_rects_stay_inside_the_frame_without_overlap(
+        configs in proptest::collection::vec(config(), 1..12),
+        anchor in 0..ANCHORS.len(),
+        width in 1u16..200,
+        height in 1u16..80,
+        max_concurrent in proptest::option::of(1usize..6),
+    ) {
+        let anchor = ANCHORS[anchor];
+        let defaults = ManagerDefaults::default();
+        let mut states: HashMap<u64, NotificationState> = HashMap::new();
+        for (id, config) in configs.iter().enumerate() {
+            let notification = NotificationBuilder::new(config.content.clone())
+                .anchor(anchor)
+                .auto_dismiss(AutoDismiss::Never)
+                .build()
+                .unwrap();
+            let mut state = NotificationState::new(id as u64, notification, &defaults);
+            state.update(Duration::from_millis(1));
+            state.update(Duration::from_secs(10));
+            states.insert(id as u64, state);
+        }
+        let ids: Vec<u64> = (0..configs.len() as u64).collect();
+        let frame = Rect::new(0, 0, width, height);
+
+        let stacked = calculate_stacking_positions(&states, anchor, &ids, frame, max_concurrent);
+
+        for (i, a) in stacked.iter().enumerate() {
+            prop_assert_eq!(a.rect.intersection(frame), a.rect, "{:?} leaves {:?}", a.rect, frame);
+            for b in &stacked[i + 1..] {
+                prop_assert!(
+                    !a.rect.intersects(b.rect),
+                    "{:?} overlaps {:?}", a.rect, b.rect
+                );
+            }
+        }
+    }
+}
+
+// FILE: tests/test_state_machine_proptest.rs - Property tests for the notification state machine
+// END OF VERSION: 1.0.0
@@ -0,0 +1,117 @@
+// FILE: tests/test_strings_integration.rs - Integration tests for localizable built-in strings
+// VERSION: 1.0.0
+// WCTX: Localizable built-in strings
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Anchor, Level, NotificationBuilder, Notifications, StringKey, Strings, Theme,
+};
+use std::time::Duration;
+
+fn german_fields() -> Strings {
+    let mut strings = Strings::default();
+    strings.level_error = "Fehler".into();
+    strings.anchor_top_right = "oben rechts".into();
+    strings.describe_heading = "{level}-Meldung, {anchor}:".into();
+    strings.dismisses_in_one = "Schließt in {n} Sekunde.".into();
+    strings.dismisses_in_other = "Schließt in {n} Sekunden.".into();
+    strings.title_prefix = "[{prefix}]".into();
+    strings
+}
+
+fn german_fn() -> Strings {
+    Strings::from_fn(|key, args| match key {
+        StringKey::Level(Level::Error) => "Fehler".to_string(),
+        StringKey::Anchor(Anchor::TopRight) => "oben rechts".to_string(),
+        StringKey::DescribeHeading => format!(
+            "{}-Meldung, {}:",
+            args.get("level").unwrap_or_default(),
+            args.get("anchor").unwrap_or_default()
+        ),
+        StringKey::DismissesIn => format!("Schließt in {} s.", args.get("n").unwrap_or_default()),
+        StringKey::TitlePrefix(Level::Error) => "FEHLER:".to_string(),
+        other => Strings::default().format(other, args.pairs()),
+    })
+}
+
+fn manager_with(strings: Strings) -> Notifications {
+    let mut manager = Notifications::new()
+        .theme(Theme::high_contrast())
+        .strings(strings);
+    let notif = NotificationBuilder::new("Verbindung fehlgeschlagen")
+        .title("Netz")
+        .level(Level::Error)
+        .anchor(Anchor::TopRight)
+        .build()
+        .unwrap();
+    manager.add(notif).unwrap();
+    manager.tick(Duration::from_millis(16));
+    manager
+}
+
+fn rendered_text(manager: &mut Notifications) -> String {
+    // Finish the slide-in so the title is on screen
+    manager.tick(Duration::from_millis(600));
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal
+        .draw(|frame| manager.render(frame, frame.area()))
+        .unwrap();
+    terminal
+        .backend()
+        .buffer()
+        .content
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect()
+}
+
+#[test]
+fn test_default_strings_are_english() {
+    let manager = manager_with(Strings::default());
+    let lines = manager.describe();
+    assert_eq!(lines.len(), 1);
+    assert!(lines[0].starts_with("Error notification, top right: Netz. Verbindung fehlgeschlagen."));
+    assert!(lines[0].contains("Dismisses in"));
+}
+
+#[test]
+fn test_template_fields_localize_describe() {
+    let manager = manager_with(german_fields());
+    let line = &manager.describe()[0];
+    assert!(
+        line.starts_with("Fehler-Meldung, oben rechts: Netz. Verbindung fehlgeschlagen. Schließt in "),
+        "{line}"
+    );
+    assert!(line.ends_with("Sekunden."), "{line}");
+}
+
+#[test]
+fn test_template_fields_localize_rendered_prefix() {
+    let mut manager = manager_with(german_fields());
+    let text = rendered_text(&mut manager);
+    assert!(text.contains("[ERROR:]"), "prefix template should wrap the theme prefix");
+}
+
+#[test]
+fn test_from_fn_localizes_describe_and_render() {
+    let mut manager = manager_with(german_fn());
+    let line = &manager.describe()[0];
+    assert!(line.starts_with("Fehler-Meldung, oben rechts:"), "{line}");
+    assert!(line.contains("Schließt in 5 s."), "{line}");
+
+    let text = rendered_text(&mut manager);
+    assert!(text.contains("FEHLER:"));
+    assert!(!text.contains("ERROR:"));
+}
+
+#[test]
+fn test_from_fn_falls_back_for_unhandled_keys() {
+    let strings = german_fn();
+    assert_eq!(strings.format(StringKey::Dismissing, &[]), "Dismissing.");
+    assert_eq!(strings.format(StringKey::Level(Level::Warn), &[]), "Warning");
+}
+
+// FILE: tests/test_strings_integration.rs - Integration tests for localizable built-in strings
+// END OF VERSION: 1.0.0
@@ -0,0 +1,73 @@
+// FILE: tests/test_styles_integration.rs - Integration tests for the public level styles
+// VERSION: 1.0.0
+// WCTX: Public level styles
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Style};
+use ratatui::Terminal;
+use ratatui_notifications::notifications::AnimationPhase;
+use ratatui_notifications::styles::for_level;
+use ratatui_notifications::{Anchor, Level, Notification, NotificationBuilder, Notifications, Theme};
+use std::time::Duration;
+
+const LEVELS: [Level; 5] = [Level::Info, Level::Warn, Level::Error, Level::Debug, Level::Trace];
+
+/// Renders one settled notification in the top-left corner
+fn render(manager: Notifications, notification: Notification) -> Buffer {
+    let mut manager = manager;
+    let id = manager.add(notification).unwrap();
+    while manager.phase(id) != Some(AnimationPhase::Dwelling) {
+        manager.tick(Duration::from_millis(10));
+    }
+    let mut terminal = Terminal::new(TestBackend::new(40, 6)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn titled(level: Level) -> NotificationBuilder {
+    NotificationBuilder::new("Body").title("Title").level(level).anchor(Anchor::TopLeft)
+}
+
+#[test]
+fn test_rendered_border_matches_for_level() {
+    for theme in [Theme::default(), Theme::high_contrast()] {
+        for level in LEVELS {
+            let manager = Notifications::new().theme(theme);
+            let buffer = render(manager, titled(level).build().unwrap());
+            let styles = for_level(Some(level), &theme);
+
+            // Top-left corner and left border
+            for cell in [&buffer[(0, 0)], &buffer[(0, 1)]] {
+                assert_eq!(cell.fg, styles.border.fg.unwrap_or(Color::Reset), "{level:?}");
+                assert!(cell.modifier.contains(styles.border.add_modifier), "{level:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rendered_icon_matches_for_level() {
+    for level in LEVELS {
+        let buffer = render(Notifications::new(), titled(level).build().unwrap());
+        let top: String = (0..40).map(|x| buffer[(x, 0)].symbol()).collect();
+        let icon = for_level(Some(level), &Theme::default()).icon.unwrap();
+
+        assert!(top.contains(icon.trim()), "{level:?}: {top}");
+    }
+    assert_eq!(for_level(None, &Theme::default()).icon, None);
+}
+
+#[test]
+fn test_border_style_override_replaces_for_level() {
+    let custom = Style::new().fg(Color::Magenta);
+    let notification = titled(Level::Error).border_style(custom).build().unwrap();
+    let buffer = render(Notifications::new(), notification);
+
+    assert_eq!(buffer[(0, 0)].fg, Color::Magenta);
+    assert_ne!(for_level(Some(Level::Error), &Theme::default()).border.fg, Some(Color::Magenta));
+}
+
+// FILE: tests/test_styles_integration.rs - Integration tests for the public level styles
+// END OF VERSION: 1.0.0
@@ -0,0 +1,246 @@
+// FILE: tests/test_suppress_integration.rs - Integration tests for suppressing notifications
+// VERSION: 1.2.0
+// WCTX: Suppressed notifications
+// CLOG: Test the TTL of parked notifications
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::traits::TestClock;
+use ratatui_notifications::notifications::{
+    Animation, AnimationPhase, AutoDismiss, DismissReason, Evictability, Notification,
+    NotificationBuilder, Notifications, Overflow, Timing,
+};
+use std::time::Duration;
+
+fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+fn toast(text: &'static str) -> ratatui_notifications::notifications::Notification {
+    NotificationBuilder::new(text)
+        .animation(Animation::Fade)
+        .timing(Timing::Fixed(ms(200)), Timing::Auto, Timing::Fixed(ms(200)))
+        .auto_dismiss(AutoDismiss::After(ms(1000)))
+        .build()
+        .unwrap()
+}
+
+fn screen(manager: &mut Notifications) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal
+        .draw(|frame| manager.render(frame, frame.area()))
+        .unwrap();
+    terminal
+        .backend()
+        .buffer()
+        .content
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect()
+}
+
+#[test]
+fn test_suppressed_notifications_wait_and_start_on_release() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    manager.suppress(true);
+    let ids: Vec<u64> = ["one", "two", "three"]
+        .into_iter()
+        .map(|text| manager.add(toast(text)).unwrap())
+        .collect();
+
+    // Nothing is managed or drawn while parked, however long it waits
+    clock.advance(ms(5000));
+    manager.tick(ms(5000));
+    assert_eq!(manager.parked_count(), 3);
+    assert!(!manager.has_notification());
+    assert!(ids.iter().all(|&id| manager.get(id).is_none()));
+    assert!(!screen(&mut manager).contains("one"));
+
+    manager.suppress(false);
+    assert_eq!(manager.parked_count(), 0);
+    assert_eq!(manager.infos().iter().map(|info| info.id).collect::<Vec<_>>(), ids);
+
+    // Entry animations start on the first tick after release; the age
+    // still counts from add()
+    manager.tick(ms(100));
+    for &id in &ids {
+        let info = manager.get(id).unwrap();
+        assert_eq!(info.phase, AnimationPhase::FadingIn);
+        assert_eq!(info.age, ms(5000));
+    }
+
+    // The full display time runs from the release
+    manager.tick(ms(100));
+    manager.tick(ms(900));
+    for &id in &ids {
+        let info = manager.get(id).unwrap();
+        assert_eq!(info.phase, AnimationPhase::Dwelling);
+        assert_eq!(info.remaining, Some(ms(100)));
+    }
+    assert!(screen(&mut manager).contains("three"));
+}
+
+#[test]
+fn test_release_honors_max_concurrent() {
+    let mut manager = Notifications::new()
+        .max_concurrent(Some(2))
+        .overflow(Overflow::DiscardOldest);
+    manager.suppress(true);
+    let ids: Vec<u64> = ["one", "two", "three"]
+        .into_iter()
+        .map(|text| manager.add(toast(text)).unwrap())
+        .collect();
+    manager.suppress(false);
+    manager.tick(ms(2000));
+
+    // The oldest makes way for the last one released
+    assert!(manager.get(ids[0]).is_none());
+    assert!(manager.get(ids[1]).is_some());
+    assert!(manager.get(ids[2]).is_some());
+}
+
+#[test]
+fn test_release_without_room_keeps_notifications_parked() {
+    let pinned = |text: &'static str| {
+        NotificationBuilder::new(text)
+            .animation(Animation::Fade)
+            .timing(Timing::Fixed(ms(200)), Timing::Auto, Timing::Fixed(ms(200)))
+            .auto_dismiss(AutoDismiss::After(ms(1000)))
+            .evictable(Evictability::Never)
+    };
+    let mut manager = Notifications::new().max_concurrent(Some(2));
+    manager.suppress(true);
+    let ids: Vec<u64> = ["one", "two", "three"]
+        .into_iter()
+        .map(|text| manager.add(pinned(text).build().unwrap()).unwrap())
+        .collect();
+
+    // The stack fills with pinned notifications; the third waits its turn
+    manager.suppress(false);
+    assert_eq!(manager.parked_count(), 1);
+    assert_eq!(manager.infos().iter().map(|info| info.id).collect::<Vec<_>>(), ids[..2]);
+    manager.tick(ms(200));
+    assert!(manager.get(ids[2]).is_none());
+    assert_eq!(manager.parked_count(), 1);
+
+    // Exiting notifications give up their slots, so it follows them in
+    while manager.parked_count() == 1 {
+        assert_eq!(manager.get(ids[0]).unwrap().phase, AnimationPhase::Dwelling);
+        manager.tick(ms(50));
+    }
+    assert_eq!(manager.get(ids[0]).unwrap().phase, AnimationPhase::FadingOut);
+    assert_eq!(manager.get(ids[2]).unwrap().phase, AnimationPhase::Pending);
+    manager.tick(ms(50));
+    assert_eq!(manager.get(ids[2]).unwrap().phase, AnimationPhase::FadingIn);
+}
+
+#[test]
+fn test_visible_notifications_keep_running_while_suppressed() {
+    let mut manager = Notifications::new();
+    let visible = manager.add(toast("visible")).unwrap();
+    manager.suppress(true);
+    manager.tick(ms(100));
+    manager.tick(ms(200));
+    assert_eq!(manager.get(visible).unwrap().phase, AnimationPhase::Dwelling);
+    assert!(manager.is_suppressed());
+}
+
+#[test]
+fn test_delayed_annotation() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone()).annotate_delayed(true);
+    manager.suppress(true);
+    let id = manager.add(toast("Saved")).unwrap();
+    clock.advance(ms(12_400));
+    manager.suppress(false);
+    manager.tick(ms(300));
+
+    assert!(screen(&mut manager).contains("Saved (delayed 12s)"));
+    assert_eq!(&*manager.get(id).unwrap().content, "Saved");
+}
+
+#[test]
+fn test_remove_and_clear_drop_parked() {
+    let mut manager = Notifications::new();
+    manager.suppress(true);
+    let first = manager.add(toast("one")).unwrap();
+    manager.add(toast("two")).unwrap();
+
+    assert!(manager.remove(first));
+    assert!(!manager.remove(first));
+    assert_eq!(manager.parked_count(), 1);
+
+    manager.clear();
+    manager.suppress(false);
+    assert!(manager.infos().is_empty());
+}
+
+#[test]
+fn test_hidden_notifications_are_not_parked() {
+    let mut manager = Notifications::new();
+    manager.suppress(true);
+    let id = manager.add_hidden(toast("hidden")).unwrap();
+    assert_eq!(manager.parked_count(), 0);
+    assert!(manager.get(id).is_some());
+}
+
+fn quote(ttl: Duration) -> Notification {
+    NotificationBuilder::new("BTC 64,210")
+        .animation(Animation::Fade)
+        .auto_dismiss(AutoDismiss::Never)
+        .ttl(ttl)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_parked_ttl_runs_from_add() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    manager.suppress(true);
+    let id = manager.add(quote(ms(3000))).unwrap();
+    clock.advance(ms(2000));
+    manager.suppress(false);
+    assert_eq!(manager.get(id).unwrap().age, ms(2000));
+
+    clock.advance(ms(600));
+    manager.tick(ms(600));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+
+    // 1s after the release, but 3s after add
+    clock.advance(ms(400));
+    manager.tick(ms(400));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::FadingOut));
+    assert_eq!(manager.get(id).unwrap().dismiss_reason, Some(DismissReason::Expired));
+}
+
+#[test]
+fn test_parked_past_ttl_is_dropped_as_stale() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone()).history_limit(10);
+    manager.suppress(true);
+    let dropped_while_suppressed = manager.add(quote(ms(1000))).unwrap();
+    let dropped_on_release = manager.add(quote(ms(3000))).unwrap();
+    let kept = manager.add(toast("kept")).unwrap();
+
+    clock.advance(ms(2000));
+    manager.tick(ms(16));
+    assert_eq!(manager.parked_count(), 2);
+
+    clock.advance(ms(1000));
+    manager.suppress(false);
+    assert_eq!(manager.parked_count(), 0);
+    assert!(manager.get(dropped_while_suppressed).is_none());
+    assert!(manager.get(dropped_on_release).is_none());
+    assert!(manager.get(kept).is_some());
+
+    for id in [dropped_while_suppressed, dropped_on_release] {
+        let record = manager.history().find(|record| record.id == id).unwrap();
+        assert!(record.is_dismissed());
+        assert_eq!(record.dismiss_reason, Some(DismissReason::Stale));
+    }
+}
+
+// FILE: tests/test_suppress_integration.rs - Integration tests for suppressing notifications
+// END OF VERSION: 1.2.0
@@ -0,0 +1,68 @@
+// FILE: tests/test_test_clock_integration.rs - Integration tests for the manually advanced TestClock
+// VERSION: 1.0.0
+// WCTX: Deterministic test clock
+// CLOG: Initial creation
+
+use ratatui_notifications::notifications::traits::{Clock, TestClock};
+use ratatui_notifications::notifications::{NotificationBuilder, Notifications};
+use std::time::Duration;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+fn add(manager: &mut Notifications, text: &'static str) -> u64 {
+    manager.add(NotificationBuilder::new(text).build().unwrap()).unwrap()
+}
+
+#[test]
+fn test_clock_only_moves_when_advanced() {
+    let clock = TestClock::new();
+    let start = clock.now();
+
+    assert_eq!(clock.now(), start);
+    clock.advance(ms(250));
+    assert_eq!(clock.now() - start, ms(250));
+}
+
+#[test]
+fn test_clones_share_one_time() {
+    let clock = TestClock::new();
+    let handle = clock.clone();
+
+    handle.advance(ms(40));
+    assert_eq!(clock.now(), handle.now());
+}
+
+#[test]
+fn test_adds_get_controlled_timestamps() {
+    let clock = TestClock::new();
+    let start = clock.now();
+    let mut manager = Notifications::new().with_clock(clock.clone()).history_limit(10);
+
+    let first = add(&mut manager, "First");
+    clock.advance(ms(10));
+    let second = add(&mut manager, "Second");
+    let third = add(&mut manager, "Third");
+
+    let added: Vec<_> = manager.history().map(|record| (record.id, record.added_at)).collect();
+    assert_eq!(added, [(first, start), (second, start + ms(10)), (third, start + ms(10))]);
+}
+
+#[test]
+fn test_age_follows_the_clock() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    let id = add(&mut manager, "Aging");
+
+    clock.advance(ms(1500));
+    let mut ages = Vec::new();
+    manager.retain(|info| {
+        ages.push((info.id, info.age));
+        true
+    });
+    assert_eq!(ages, [(id, ms(1500))]);
+}
+
+// FILE: tests/test_test_clock_integration.rs - Integration tests for the manually advanced TestClock
+// END OF VERSION: 1.0.0
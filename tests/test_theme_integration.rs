@@ -0,0 +1,109 @@
+// FILE: tests/test_theme_integration.rs - Integration tests for level themes
+// VERSION: 1.0.0
+// WCTX: High-contrast accessibility theme
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::prelude::*;
+use ratatui::widgets::BorderType;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::functions::fnc_resolve_styles::{
+    resolve_styles, resolve_themed_styles,
+};
+use ratatui_notifications::notifications::{
+    Animation, ColorMode, Level, NotificationBuilder, Notifications, Theme,
+};
+use std::time::Duration;
+
+const LEVELS: [Level; 5] = [Level::Info, Level::Warn, Level::Error, Level::Debug, Level::Trace];
+
+fn render_level(theme: Theme, mode: ColorMode, level: Level) -> Buffer {
+    let mut manager = Notifications::new().color_mode(mode).theme(theme);
+    let notif = NotificationBuilder::new("Disk almost full")
+        .title("Storage")
+        .level(level)
+        .animation(Animation::Fade)
+        .build()
+        .unwrap();
+    manager.add(notif).unwrap();
+
+    // Past the default 500ms entry so the notification is dwelling
+    manager.tick(Duration::from_millis(600));
+
+    let mut terminal = Terminal::new(TestBackend::new(60, 12)).unwrap();
+    terminal
+        .draw(|frame| manager.render(frame, frame.area()))
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn symbols(buffer: &Buffer) -> String {
+    buffer.content.iter().map(|cell| cell.symbol()).collect()
+}
+
+#[test]
+fn test_high_contrast_levels_distinct_without_color() {
+    let grids: Vec<String> = LEVELS
+        .iter()
+        .map(|&level| symbols(&render_level(Theme::high_contrast(), ColorMode::Mono, level)))
+        .collect();
+
+    for i in 0..grids.len() {
+        for j in (i + 1)..grids.len() {
+            assert_ne!(
+                grids[i], grids[j],
+                "{:?} and {:?} render identically in Mono",
+                LEVELS[i], LEVELS[j]
+            );
+        }
+    }
+}
+
+#[test]
+fn test_high_contrast_border_types_and_prefixes() {
+    let error = symbols(&render_level(Theme::high_contrast(), ColorMode::Mono, Level::Error));
+    assert!(error.contains('╔'), "Error should use a double border");
+    assert!(error.contains("ERROR:"));
+
+    let warn = symbols(&render_level(Theme::high_contrast(), ColorMode::Mono, Level::Warn));
+    assert!(warn.contains('┏'), "Warn should use a thick border");
+    assert!(warn.contains("WARN:"));
+}
+
+#[test]
+fn test_high_contrast_title_modifiers_survive_mono() {
+    let buffer = render_level(Theme::high_contrast(), ColorMode::Mono, Level::Debug);
+    assert!(buffer
+        .content
+        .iter()
+        .all(|cell| cell.fg == Color::Reset && cell.bg == Color::Reset));
+    assert!(buffer
+        .content
+        .iter()
+        .any(|cell| cell.modifier.contains(Modifier::ITALIC)));
+}
+
+#[test]
+fn test_default_theme_matches_resolve_styles() {
+    for level in LEVELS.iter().copied().map(Some).chain([None]) {
+        let (block, border, title) = resolve_styles(level, None, None, None);
+        let resolved = resolve_themed_styles(level, &Theme::default(), None, None, None);
+        assert_eq!((resolved.block, resolved.border, resolved.title), (block, border, title));
+        assert_eq!(resolved.border_type, None);
+        assert_eq!(resolved.title_prefix, None);
+    }
+}
+
+#[test]
+fn test_custom_border_style_overrides_theme() {
+    let custom = Style::new().fg(Color::Cyan);
+    let resolved =
+        resolve_themed_styles(Some(Level::Error), &Theme::high_contrast(), None, Some(custom), None);
+    assert_eq!(resolved.border, custom);
+    assert_eq!(resolved.border_type, Some(BorderType::Double));
+    assert!(resolved.title.add_modifier.contains(Modifier::BOLD));
+}
+
+// FILE: tests/test_theme_integration.rs - Integration tests for level themes
+// END OF VERSION: 1.0.0
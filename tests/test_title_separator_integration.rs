@@ -0,0 +1,86 @@
+// FILE: tests/test_title_separator_integration.rs - Integration tests for the title separator rule
+// VERSION: 1.0.0
+// WCTX: Title/content separator
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Position;
+use ratatui::widgets::BorderType;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, NotificationBuilder, Notifications, SizeConstraint,
+};
+use std::time::Duration;
+
+fn render(separator: bool) -> Buffer {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("Body")
+        .title("Title")
+        .title_separator(separator)
+        .border_type(BorderType::Double)
+        .anchor(Anchor::TopLeft)
+        .animation(Animation::Fade)
+        .max_size(SizeConstraint::Absolute(20), SizeConstraint::Absolute(10))
+        .build()
+        .unwrap();
+    manager.add(notif).unwrap();
+    manager.tick(Duration::from_millis(600));
+
+    let mut terminal = Terminal::new(TestBackend::new(40, 12)).unwrap();
+    terminal
+        .draw(|frame| manager.render(frame, frame.area()))
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+/// Returns the top-left corner and width of the double-bordered box.
+fn find_box(buffer: &Buffer) -> (Position, u16) {
+    let area = buffer.area;
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            if buffer[(x, y)].symbol() == "╔" {
+                let right = (x..area.right())
+                    .find(|&rx| buffer[(rx, y)].symbol() == "╗")
+                    .expect("box should have a top-right corner");
+                return (Position::new(x, y), right - x + 1);
+            }
+        }
+    }
+    panic!("no notification box rendered");
+}
+
+fn row(buffer: &Buffer, y: u16, from: u16, to: u16) -> String {
+    (from..to).map(|x| buffer[(x, y)].symbol()).collect()
+}
+
+#[test]
+fn test_separator_spans_inner_width() {
+    let buffer = render(true);
+    let (corner, width) = find_box(&buffer);
+    let inner = row(&buffer, corner.y + 1, corner.x + 1, corner.x + width - 1);
+
+    assert_eq!(inner, "═".repeat(width as usize - 2));
+}
+
+#[test]
+fn test_content_moves_below_separator() {
+    let buffer = render(true);
+    let (corner, width) = find_box(&buffer);
+    let content = row(&buffer, corner.y + 2, corner.x + 1, corner.x + width - 1);
+
+    assert!(content.contains("Body"), "{content:?}");
+}
+
+#[test]
+fn test_no_separator_by_default() {
+    let buffer = render(false);
+    let (corner, width) = find_box(&buffer);
+    let first = row(&buffer, corner.y + 1, corner.x + 1, corner.x + width - 1);
+
+    assert!(first.contains("Body"), "{first:?}");
+    assert!(!first.contains('═'));
+}
+
+// FILE: tests/test_title_separator_integration.rs - Integration tests for the title separator rule
+// END OF VERSION: 1.0.0
@@ -0,0 +1,79 @@
+// FILE: tests/test_title_truncation_integration.rs - Integration tests for title truncation
+// VERSION: 1.0.0
+// WCTX: Title truncation
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, NotificationBuilder, Notifications, SizeConstraint,
+};
+use std::time::Duration;
+
+fn render(title: &'static str, width: u16) -> Buffer {
+    let mut manager = Notifications::new();
+    manager
+        .add(
+            NotificationBuilder::new("a body wide enough to fill the box")
+                .title(title)
+                .anchor(Anchor::TopLeft)
+                .animation(Animation::Fade)
+                .fade(false)
+                .max_size(SizeConstraint::Absolute(width), SizeConstraint::Absolute(5))
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+    manager.tick(Duration::from_secs(1));
+
+    let mut terminal = Terminal::new(TestBackend::new(30, 6)).unwrap();
+    terminal
+        .draw(|frame| manager.render(frame, frame.area()))
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn box_top(buffer: &Buffer) -> Vec<String> {
+    let row: Vec<String> = (0..buffer.area.width)
+        .map(|col| buffer[(col, 0)].symbol().to_string())
+        .collect();
+    let end = row.iter().position(|symbol| symbol == "╮").expect("right corner drawn");
+    row[..=end].to_vec()
+}
+
+#[test]
+fn test_long_title_leaves_corners_intact() {
+    let top = box_top(&render("A very long title that does not fit 日本語", 16));
+    assert_eq!(top.len(), 16);
+    assert_eq!(top[0], "╭");
+    assert_eq!(top[15], "╮");
+    assert_eq!(top[1], " ");
+    assert_eq!(top[14], " ");
+    assert!(top.contains(&"…".to_string()));
+    assert!(top.concat().contains("A very"));
+}
+
+#[test]
+fn test_wide_graphemes_are_not_split() {
+    let top = box_top(&render("日本語日本語日本語日本語", 12));
+    assert_eq!(top.len(), 12);
+    assert_eq!(top[0], "╭");
+    assert_eq!(top[11], "╮");
+    assert!(top.contains(&"本".to_string()));
+    // The wide 語 would overrun the room, so the ellipsis follows 本 instead
+    assert!(!top.contains(&"語".to_string()));
+    let ellipsis = top.iter().position(|symbol| symbol == "…").expect("ellipsis drawn");
+    assert_eq!(top[ellipsis - 2], "本");
+    assert_eq!(top[ellipsis + 1], " ");
+}
+
+#[test]
+fn test_fitting_title_is_untouched() {
+    let top = box_top(&render("Fits", 16)).concat();
+    assert!(top.contains("Fits"));
+    assert!(!top.contains('…'));
+}
+
+// FILE: tests/test_title_truncation_integration.rs - Integration tests for title truncation
+// END OF VERSION: 1.0.0
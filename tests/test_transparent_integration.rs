@@ -0,0 +1,86 @@
+// FILE: tests/test_transparent_integration.rs - Integration tests for transparent notifications
+// VERSION: 1.0.0
+// WCTX: Transparent notifications
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::widgets::Paragraph;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    generate_code, Anchor, Animation, NotificationBuilder, Notifications,
+};
+use std::time::Duration;
+
+/// Fills a 40x10 frame with `x`, then renders one dwelling notification
+/// at the top-left over it.
+fn render_over_background(transparent: bool) -> Buffer {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("Hi")
+        .anchor(Anchor::TopLeft)
+        .animation(Animation::Fade)
+        .transparent(transparent)
+        .build()
+        .unwrap();
+    manager.add(notif).unwrap();
+
+    // Past the default 500ms entry so it is dwelling at its full rect
+    manager.tick(Duration::from_millis(600));
+
+    let mut terminal = Terminal::new(TestBackend::new(40, 10)).unwrap();
+    terminal
+        .draw(|frame| {
+            let background = vec!["x".repeat(40); 10].join("\n");
+            frame.render_widget(Paragraph::new(background), frame.area());
+            manager.render(frame, frame.area());
+        })
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+/// The first interior row: `│`, left padding, content
+fn interior_row(buffer: &Buffer) -> Vec<String> {
+    (0..6).map(|x| buffer[(x, 1)].symbol().to_string()).collect()
+}
+
+#[test]
+fn test_default_is_not_transparent() {
+    let notif = NotificationBuilder::new("x").build().unwrap();
+    assert!(!notif.transparent());
+}
+
+#[test]
+fn test_opaque_notification_clears_interior() {
+    let buffer = render_over_background(false);
+    let row = interior_row(&buffer);
+    assert_eq!(row[0], "│");
+    assert_eq!(row[1], " ", "Padding should be cleared: {row:?}");
+    assert_eq!(&row[2..4], ["H", "i"]);
+}
+
+#[test]
+fn test_transparent_notification_keeps_uncovered_cells() {
+    let buffer = render_over_background(true);
+    let row = interior_row(&buffer);
+    assert_eq!(row[0], "│", "Border is still drawn: {row:?}");
+    assert_eq!(row[1], "x", "Padding should show the background: {row:?}");
+    assert_eq!(&row[2..4], ["H", "i"], "Content is still drawn: {row:?}");
+}
+
+#[test]
+fn test_transparent_keeps_background_outside_notification() {
+    let buffer = render_over_background(true);
+    assert_eq!(buffer[(39, 9)].symbol(), "x");
+}
+
+#[test]
+fn test_generate_code_includes_transparent() {
+    let notif = NotificationBuilder::new("x").transparent(true).build().unwrap();
+    assert!(generate_code(&notif).contains(".transparent(true)"));
+
+    let notif = NotificationBuilder::new("x").build().unwrap();
+    assert!(!generate_code(&notif).contains(".transparent("));
+}
+
+// FILE: tests/test_transparent_integration.rs - Integration tests for transparent notifications
+// END OF VERSION: 1.0.0
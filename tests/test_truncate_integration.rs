@@ -0,0 +1,130 @@
+// FILE: tests/test_truncate_integration.rs - Integration tests for content truncation
+// VERSION: 1.0.0
+// WCTX: Content truncation
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::Terminal;
+use ratatui_notifications::notifications::functions::fnc_truncate_text::truncate_line;
+use ratatui_notifications::notifications::{
+    calculate_size, generate_code, Anchor, AnimationPhase, NotificationBuilder, Notifications,
+    SizeConstraint, TruncateMode,
+};
+use std::time::Duration;
+
+const PATH: &str = "/home/user/projects/ratatui-notifications/examples/cookbook.rs";
+
+/// Builder for a path notification in a 30-cell box
+fn path(mode: TruncateMode) -> NotificationBuilder {
+    NotificationBuilder::new(PATH)
+        .title("Opened")
+        .anchor(Anchor::TopLeft)
+        .max_size(SizeConstraint::Absolute(30), SizeConstraint::Absolute(10))
+        .truncate_mode(mode)
+}
+
+/// Renders a settled notification and returns the screen rows
+fn screen(builder: NotificationBuilder) -> Vec<String> {
+    let mut manager = Notifications::new();
+    let id = manager.add(builder.build().unwrap()).unwrap();
+    while manager.phase(id) != Some(AnimationPhase::Dwelling) {
+        manager.tick(Duration::from_millis(10));
+    }
+    let mut terminal = Terminal::new(TestBackend::new(60, 8)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..8)
+        .map(|y| (0..60).map(|x| buffer[(x, y)].symbol()).collect::<String>())
+        .collect()
+}
+
+#[test]
+fn test_middle_truncation_keeps_the_file_name() {
+    let rows = screen(path(TruncateMode::Middle));
+
+    // 30 wide minus borders and padding leaves 26 cells: 12 + "…" + 13
+    assert_eq!(rows[1], format!("│ {} │{}", "/home/user/p…s/cookbook.rs", " ".repeat(30)));
+    assert_eq!(rows[2], format!("╰{}╯{}", "─".repeat(28), " ".repeat(30)));
+}
+
+#[test]
+fn test_start_and_end_truncation() {
+    let start = screen(path(TruncateMode::Start));
+    assert!(start[1].starts_with("│ …ions/examples/cookbook.rs │"), "{}", start[1]);
+
+    let end = screen(path(TruncateMode::End));
+    assert!(end[1].starts_with("│ /home/user/projects/ratat… │"), "{}", end[1]);
+}
+
+#[test]
+fn test_custom_ellipsis_is_used() {
+    let rows = screen(path(TruncateMode::Middle).ellipsis("..."));
+
+    assert!(rows[1].contains("/home/user/..."), "{}", rows[1]);
+    assert!(rows[1].contains("cookbook.rs"));
+    assert!(!rows.iter().any(|row| row.contains('…')));
+}
+
+#[test]
+fn test_lines_wrap_without_truncate_mode() {
+    let rows = screen(
+        NotificationBuilder::new(PATH)
+            .anchor(Anchor::TopLeft)
+            .max_size(SizeConstraint::Absolute(30), SizeConstraint::Absolute(10)),
+    );
+    assert!(!rows.iter().any(|row| row.contains('…')));
+    assert!(rows[1].starts_with("│ /home/user/projects/ratatu │"), "{rows:?}");
+    assert!(rows[3].starts_with("│ ookbook.rs "), "{rows:?}");
+}
+
+#[test]
+fn test_truncated_lines_take_one_row_each() {
+    let frame = Rect::new(0, 0, 80, 24);
+    let truncated = NotificationBuilder::new(format!("{PATH}\n{PATH}"))
+        .max_size(SizeConstraint::Absolute(30), SizeConstraint::Absolute(10))
+        .truncate_mode(TruncateMode::End)
+        .build()
+        .unwrap();
+    let wrapped = NotificationBuilder::new(format!("{PATH}\n{PATH}"))
+        .max_size(SizeConstraint::Absolute(30), SizeConstraint::Absolute(10))
+        .build()
+        .unwrap();
+
+    assert_eq!(calculate_size(&truncated, frame), (30, 4));
+    assert!(calculate_size(&wrapped, frame).1 > 4);
+}
+
+#[test]
+fn test_truncate_line_keeps_span_styles() {
+    let red = Style::new().fg(Color::Red);
+    let line = Line::from(vec![Span::styled("error: ", red), Span::raw("file not found")]);
+
+    let truncated = truncate_line(&line, 12, TruncateMode::End, "…");
+    assert_eq!(truncated.to_string(), "error: file…");
+    assert_eq!(truncated.spans[0], Span::styled("error: ", red));
+    assert_eq!(truncated.spans[1], Span::raw("file…"));
+    assert_eq!(truncate_line(&line, 40, TruncateMode::End, "…").to_string(), line.to_string());
+}
+
+#[test]
+fn test_ellipsis_wider_than_the_line_is_clipped() {
+    let line = Line::from("abcdef");
+    assert_eq!(truncate_line(&line, 2, TruncateMode::Middle, "...").to_string(), "..");
+    assert_eq!(truncate_line(&line, 0, TruncateMode::End, "…").to_string(), "");
+}
+
+#[test]
+fn test_generate_code_emits_truncation() {
+    let code = generate_code(&path(TruncateMode::Middle).ellipsis("...").build().unwrap());
+    assert!(code.contains(".truncate_mode(TruncateMode::Middle)"), "{code}");
+    assert!(code.contains(".ellipsis(\"...\")"), "{code}");
+
+    let plain = generate_code(&NotificationBuilder::new("x").build().unwrap());
+    assert!(!plain.contains("truncate_mode") && !plain.contains("ellipsis"));
+}
+
+// FILE: tests/test_truncate_integration.rs - Integration tests for content truncation
+// END OF VERSION: 1.0.0
@@ -0,0 +1,121 @@
+// FILE: tests/test_ttl_integration.rs - Integration tests for age-based expiry
+// VERSION: 1.1.0
+// WCTX: Deterministic test clock
+// CLOG: Replaced the local ManualClock with TestClock
+
+use ratatui_notifications::notifications::traits::TestClock;
+use ratatui_notifications::notifications::{
+    generate_code, Animation, AnimationPhase, AutoDismiss, NotificationBuilder, Notifications,
+};
+use std::time::Duration;
+
+/// Advances the clock and the manager together
+fn step(manager: &mut Notifications, clock: &TestClock, by: Duration) {
+    clock.advance(by);
+    manager.tick(by);
+}
+
+fn add_with_ttl(manager: &mut Notifications, auto_dismiss: AutoDismiss, ttl: Duration) -> u64 {
+    let notif = NotificationBuilder::new("Quote")
+        .animation(Animation::Fade)
+        .auto_dismiss(auto_dismiss)
+        .ttl(ttl)
+        .build()
+        .unwrap();
+    manager.add(notif).unwrap()
+}
+
+#[test]
+fn test_ttl_dismisses_dwelling_notification() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    let id = add_with_ttl(&mut manager, AutoDismiss::Never, Duration::from_secs(2));
+
+    step(&mut manager, &clock, Duration::from_millis(1900));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+
+    step(&mut manager, &clock, Duration::from_millis(100));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::FadingOut));
+
+    step(&mut manager, &clock, Duration::from_secs(1));
+    assert_eq!(manager.phase(id), None);
+}
+
+#[test]
+fn test_ttl_runs_from_add_not_dwell() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    let id = add_with_ttl(
+        &mut manager,
+        AutoDismiss::After(Duration::from_secs(60)),
+        Duration::from_secs(3),
+    );
+
+    // Time passes before the first tick, e.g. while the app is busy
+    clock.advance(Duration::from_millis(2500));
+    manager.tick(Duration::from_millis(600));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Dwelling));
+
+    // Only 500ms of dwell, but 3s since add
+    step(&mut manager, &clock, Duration::from_millis(500));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::FadingOut));
+}
+
+#[test]
+fn test_ttl_expired_before_shown_never_appears() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    let id = add_with_ttl(&mut manager, AutoDismiss::Never, Duration::from_secs(1));
+
+    clock.advance(Duration::from_secs(5));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::Pending));
+
+    manager.tick(Duration::from_millis(16));
+    assert_eq!(manager.phase(id), None);
+    assert!(!manager.has_notification());
+}
+
+#[test]
+fn test_ttl_during_entry_reverses() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    let id = add_with_ttl(&mut manager, AutoDismiss::Never, Duration::from_millis(200));
+
+    step(&mut manager, &clock, Duration::from_millis(100));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::FadingIn));
+
+    step(&mut manager, &clock, Duration::from_millis(100));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::FadingOut));
+}
+
+#[test]
+fn test_auto_dismiss_before_ttl_still_applies() {
+    let clock = TestClock::new();
+    let mut manager = Notifications::new().with_clock(clock.clone());
+    let id = add_with_ttl(
+        &mut manager,
+        AutoDismiss::After(Duration::from_secs(1)),
+        Duration::from_secs(60),
+    );
+
+    step(&mut manager, &clock, Duration::from_millis(600));
+    step(&mut manager, &clock, Duration::from_secs(1));
+    assert_eq!(manager.phase(id), Some(AnimationPhase::FadingOut));
+}
+
+#[test]
+fn test_ttl_in_generated_code() {
+    let notif = NotificationBuilder::new("Quote")
+        .ttl(Duration::from_secs(10))
+        .build()
+        .unwrap();
+    assert_eq!(notif.ttl(), Some(Duration::from_secs(10)));
+    assert!(generate_code(&notif).contains("    .ttl(Duration::from_secs(10))"));
+
+    let plain = NotificationBuilder::new("Quote").build().unwrap();
+    assert_eq!(plain.ttl(), None);
+    assert!(!generate_code(&plain).contains(".ttl("));
+}
+
+// FILE: tests/test_ttl_integration.rs - Integration tests for age-based expiry
+// END OF VERSION: 1.1.0
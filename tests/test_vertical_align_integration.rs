@@ -0,0 +1,77 @@
+// FILE: tests/test_vertical_align_integration.rs - Integration tests for vertical content alignment
+// VERSION: 1.0.0
+// WCTX: Vertical content alignment
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, NotificationBuilder, Notifications, SizeConstraint, VerticalAlign,
+};
+use std::time::Duration;
+
+/// Renders a 20x9 box at the top-left holding one line of content
+fn render(align: VerticalAlign) -> Buffer {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("Hi")
+        .anchor(Anchor::TopLeft)
+        .animation(Animation::Fade)
+        .min_size(SizeConstraint::Absolute(20), SizeConstraint::Absolute(9))
+        .max_size(SizeConstraint::Absolute(40), SizeConstraint::Absolute(20))
+        .vertical_align(align)
+        .build()
+        .unwrap();
+    manager.add(notif).unwrap();
+    manager.tick(Duration::from_millis(600));
+
+    let mut terminal = Terminal::new(TestBackend::new(60, 20)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+/// Returns the row holding "Hi" inside the box
+fn content_row(buffer: &Buffer) -> u16 {
+    (0..9)
+        .find(|&y| {
+            let row: String = (0..20).map(|x| buffer[(x, y)].symbol().to_string()).collect();
+            row.contains("Hi")
+        })
+        .expect("content not drawn")
+}
+
+#[test]
+fn test_top_align_is_default_placement() {
+    assert_eq!(content_row(&render(VerticalAlign::Top)), 1);
+}
+
+#[test]
+fn test_center_align_places_content_in_middle_row() {
+    // Rows 1..=7 are inside the border; row 4 is the middle
+    assert_eq!(content_row(&render(VerticalAlign::Center)), 4);
+}
+
+#[test]
+fn test_bottom_align_places_content_on_last_inner_row() {
+    assert_eq!(content_row(&render(VerticalAlign::Bottom)), 7);
+}
+
+#[test]
+fn test_alignment_without_spare_rows_is_unchanged() {
+    let mut manager = Notifications::new();
+    let notif = NotificationBuilder::new("Hi")
+        .anchor(Anchor::TopLeft)
+        .animation(Animation::Fade)
+        .vertical_align(VerticalAlign::Bottom)
+        .build()
+        .unwrap();
+    manager.add(notif).unwrap();
+    manager.tick(Duration::from_millis(600));
+
+    let mut terminal = Terminal::new(TestBackend::new(60, 20)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    assert_eq!(content_row(terminal.backend().buffer()), 1);
+}
+
+// FILE: tests/test_vertical_align_integration.rs - Integration tests for vertical content alignment
+// END OF VERSION: 1.0.0
@@ -0,0 +1,41 @@
+// FILE: tests/test_wasm_clock.rs - Native tests for the wasm PerformanceClock
+// VERSION: 1.0.0
+// WCTX: Web/wasm clock support
+// CLOG: Initial creation
+//
+// Run with: cargo test --features wasm --test test_wasm_clock
+
+#![cfg(feature = "wasm")]
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use ratatui_notifications::{Clock, Notification, Notifications, PerformanceClock};
+use std::time::Duration;
+
+#[test]
+fn test_performance_clock_is_monotonic() {
+    let clock = PerformanceClock;
+    let first = clock.now();
+    let second = clock.now();
+    assert!(second >= first);
+}
+
+#[test]
+fn test_manager_runs_full_lifecycle_with_performance_clock() {
+    let mut manager = Notifications::new().with_clock(PerformanceClock);
+    let notif = Notification::new("wasm").build().unwrap();
+    manager.add(notif).unwrap();
+
+    let backend = TestBackend::new(80, 24);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    for _ in 0..600 {
+        manager.tick(Duration::from_millis(16));
+        terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    }
+
+    assert!(!manager.has_notification());
+}
+
+// FILE: tests/test_wasm_clock.rs - Native tests for the wasm PerformanceClock
+// END OF VERSION: 1.0.0
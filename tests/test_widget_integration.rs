@@ -0,0 +1,128 @@
+// FILE: tests/test_widget_integration.rs - Integration tests for rendering through NotificationsWidget
+// VERSION: 1.0.0
+// WCTX: Stateful widget rendering
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    Anchor, Animation, AutoDismiss, Level, NotificationBuilder, Notifications, NotificationsWidget,
+};
+use std::time::Duration;
+
+const ANIMATIONS: [Animation; 4] =
+    [Animation::Slide, Animation::ExpandCollapse, Animation::Fade, Animation::RollDown];
+
+/// Manager with one notification per corner, each using a different animation
+fn populated() -> Notifications {
+    let mut manager = Notifications::new();
+    let anchors = [Anchor::TopLeft, Anchor::TopRight, Anchor::BottomLeft, Anchor::BottomRight];
+    for (anchor, animation) in anchors.into_iter().zip(ANIMATIONS) {
+        let notif = NotificationBuilder::new(format!("{animation:?} body"))
+            .title(format!("{anchor:?}"))
+            .level(Level::Warn)
+            .anchor(anchor)
+            .animation(animation)
+            .auto_dismiss(AutoDismiss::After(Duration::from_millis(500)))
+            .build()
+            .unwrap();
+        manager.add(notif).unwrap();
+    }
+    manager
+}
+
+fn draw_with_render(manager: &mut Notifications) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(60, 16)).unwrap();
+    terminal.draw(|frame| manager.render(frame, frame.area())).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn draw_with_widget(manager: &mut Notifications) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(60, 16)).unwrap();
+    terminal
+        .draw(|frame| frame.render_stateful_widget(NotificationsWidget, frame.area(), manager))
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+#[test]
+fn test_widget_matches_render_through_the_lifecycle() {
+    let mut manager = populated();
+
+    // Entry, dwell and exit frames for every animation
+    for _ in 0..80 {
+        manager.tick(Duration::from_millis(25));
+        assert_eq!(draw_with_widget(&mut manager), draw_with_render(&mut manager));
+    }
+}
+
+#[test]
+fn test_widget_records_content_rects() {
+    let mut manager = Notifications::new();
+    let id = manager.add(NotificationBuilder::new("Saved").build().unwrap()).unwrap();
+    manager.tick(Duration::from_secs(1));
+    assert_eq!(manager.content_rect(id), None);
+
+    draw_with_widget(&mut manager);
+    let widget_rect = manager.content_rect(id);
+    draw_with_render(&mut manager);
+    assert!(widget_rect.is_some());
+    assert_eq!(widget_rect, manager.content_rect(id));
+}
+
+#[test]
+fn test_widget_lays_out_within_its_area() {
+    let area = Rect::new(20, 4, 30, 10);
+    let mut manager = Notifications::new();
+    let mut ids = Vec::new();
+    for anchor in [Anchor::TopLeft, Anchor::BottomRight] {
+        let notif = NotificationBuilder::new("Inside the panel")
+            .anchor(anchor)
+            .animation(Animation::Slide)
+            .auto_dismiss(AutoDismiss::Never)
+            .build()
+            .unwrap();
+        ids.push(manager.add(notif).unwrap());
+    }
+
+    // Nothing leaks outside the area, including while sliding in
+    for _ in 0..40 {
+        manager.tick(Duration::from_millis(25));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 60, 16));
+        NotificationsWidget.render(area, &mut buf, &mut manager);
+        for y in 0..buf.area.height {
+            for x in 0..buf.area.width {
+                if !area.contains((x, y).into()) {
+                    assert_eq!(buf[(x, y)].symbol(), " ", "drew outside the area at ({x}, {y})");
+                }
+            }
+        }
+
+        // Same picture as a full frame of the area's size, shifted
+        let mut alone = Buffer::empty(Rect::new(0, 0, area.width, area.height));
+        NotificationsWidget.render(alone.area, &mut alone, &mut manager);
+        for y in 0..area.height {
+            for x in 0..area.width {
+                assert_eq!(buf[(area.x + x, area.y + y)], alone[(x, y)], "at ({x}, {y})");
+            }
+        }
+        // Leave the content rects from the area render
+        NotificationsWidget.render(area, &mut buf, &mut manager);
+    }
+
+    // Settled notifications sit in the area's corners
+    let top_left = manager.content_rect(ids[0]).unwrap();
+    let bottom_right = manager.content_rect(ids[1]).unwrap();
+    assert_eq!(area.intersection(top_left), top_left);
+    assert_eq!(area.intersection(bottom_right), bottom_right);
+    // Within the border and padding of the area's edges
+    assert!(top_left.x <= area.x + 2 && top_left.y <= area.y + 2, "{top_left:?}");
+    assert!(bottom_right.right() + 2 >= area.right(), "{bottom_right:?}");
+    assert!(bottom_right.bottom() + 2 >= area.bottom(), "{bottom_right:?}");
+}
+
+// FILE: tests/test_widget_integration.rs - Integration tests for rendering through NotificationsWidget
+// END OF VERSION: 1.0.0
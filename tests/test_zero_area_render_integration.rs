@@ -0,0 +1,135 @@
+// FILE: tests/test_zero_area_render_integration.rs - Integration tests for rendering into empty areas
+// VERSION: 1.0.0
+// WCTX: Zero-sized render areas
+// CLOG: Initial creation
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::Terminal;
+use ratatui_notifications::notifications::{
+    calculate_anchor_position, calculate_rect, Anchor, AnimationPhase, AutoDismiss, Layer,
+    NotificationBuilder, Notifications, NotificationsWidget,
+};
+use std::time::Duration;
+
+const EMPTY_AREAS: [Rect; 3] = [Rect::new(0, 0, 0, 0), Rect::new(0, 0, 0, 10), Rect::new(0, 0, 10, 0)];
+
+fn add(manager: &mut Notifications, anchor: Anchor) -> u64 {
+    let notification = NotificationBuilder::new("Pane collapsed")
+        .title("Layout")
+        .anchor(anchor)
+        .auto_dismiss(AutoDismiss::Never);
+    manager.add(notification.build().unwrap()).unwrap()
+}
+
+/// A manager holding one notification in each phase: dwelling, exiting,
+/// entering and pending
+fn every_phase() -> (Notifications, Vec<u64>) {
+    let mut manager = Notifications::new();
+    let dwelling = add(&mut manager, Anchor::TopLeft);
+    manager.tick(Duration::from_secs(1));
+    let exiting = add(&mut manager, Anchor::TopRight);
+    manager.tick(Duration::from_secs(1));
+    manager.for_each_mut(|mut notification| {
+        if notification.id() == exiting {
+            notification.dismiss();
+        }
+    });
+    let entering = add(&mut manager, Anchor::BottomLeft);
+    manager.tick(Duration::from_millis(50));
+    let pending = add(&mut manager, Anchor::MiddleCenter);
+
+    let ids = vec![dwelling, exiting, entering, pending];
+    let phases: Vec<_> = ids.iter().map(|&id| manager.phase(id)).collect();
+    assert_eq!(
+        phases,
+        vec![
+            Some(AnimationPhase::Dwelling),
+            Some(AnimationPhase::SlidingOut),
+            Some(AnimationPhase::SlidingIn),
+            Some(AnimationPhase::Pending),
+        ]
+    );
+    (manager, ids)
+}
+
+#[test]
+fn test_render_into_empty_area_writes_nothing() {
+    let (mut manager, _) = every_phase();
+    let mut terminal = Terminal::new(TestBackend::new(40, 20)).unwrap();
+
+    for area in EMPTY_AREAS {
+        terminal
+            .draw(|frame| {
+                manager.render(frame, area);
+                manager.render_layer(frame, area, Layer::Above);
+                frame.render_stateful_widget(NotificationsWidget, area, &mut manager);
+            })
+            .unwrap();
+        assert_eq!(terminal.backend().buffer(), &Buffer::empty(Rect::new(0, 0, 40, 20)), "{area:?}");
+    }
+}
+
+#[test]
+fn test_render_into_empty_frame_does_not_panic() {
+    for area in EMPTY_AREAS {
+        let (mut manager, _) = every_phase();
+        let mut terminal = Terminal::new(TestBackend::new(area.width, area.height)).unwrap();
+        terminal
+            .draw(|frame| manager.render(frame, frame.area()))
+            .unwrap();
+        assert!(manager.infos().iter().all(|info| info.rect.is_none()));
+    }
+}
+
+#[test]
+fn test_tick_progresses_while_area_is_empty() {
+    let (mut manager, ids) = every_phase();
+    let mut terminal = Terminal::new(TestBackend::new(40, 20)).unwrap();
+
+    for _ in 0..10 {
+        manager.tick(Duration::from_millis(200));
+        terminal
+            .draw(|frame| manager.render(frame, Rect::default()))
+            .unwrap();
+    }
+    let phases: Vec<_> = ids.iter().map(|&id| manager.phase(id)).collect();
+    assert_eq!(
+        phases,
+        vec![Some(AnimationPhase::Dwelling), None, Some(AnimationPhase::Dwelling), Some(AnimationPhase::Dwelling)]
+    );
+
+    // Rendering into a real area again draws them
+    terminal
+        .draw(|frame| manager.render(frame, frame.area()))
+        .unwrap();
+    assert_ne!(terminal.backend().buffer(), &Buffer::empty(Rect::new(0, 0, 40, 20)));
+}
+
+#[test]
+fn test_layout_math_returns_empty_rects_for_empty_frames() {
+    let anchors = [
+        Anchor::TopLeft,
+        Anchor::TopCenter,
+        Anchor::TopRight,
+        Anchor::MiddleLeft,
+        Anchor::MiddleCenter,
+        Anchor::MiddleRight,
+        Anchor::BottomLeft,
+        Anchor::BottomCenter,
+        Anchor::BottomRight,
+    ];
+    for frame in [Rect::new(5, 5, 0, 0), Rect::new(5, 5, 0, 10), Rect::new(5, 5, 10, 0)] {
+        for anchor in anchors {
+            let position = calculate_anchor_position(anchor, frame);
+            assert_eq!(position, Position::new(5, 5), "{anchor:?} in {frame:?}");
+
+            let rect = calculate_rect(anchor, position, 20, 5, frame, 1);
+            assert_eq!(rect, Rect::new(5, 5, 0, 0), "{anchor:?} in {frame:?}");
+        }
+    }
+}
+
+// FILE: tests/test_zero_area_render_integration.rs - Integration tests for rendering into empty areas
+// END OF VERSION: 1.0.0